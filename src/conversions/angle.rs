@@ -0,0 +1,904 @@
+//! Plane angle conversion functions organized by unit type
+//! All conversions use degrees as the base unit, so that every unit except
+//! the radian-based ones has an exact factor
+//!
+//! Besides unit conversion, the module parses and formats
+//! degrees-minutes-seconds notation ([`parse_dms`], [`format_dms`]) and wraps
+//! angles into a single turn ([`normalize_angle`]). Solid angles live in the
+//! [`solid_angle`](super::solid_angle) module.
+
+use super::category::Category;
+use super::data::MAX_FORMAT_PRECISION;
+use super::error::ConversionError;
+use super::registry::{UnitDef, UnitRegistry};
+
+/// Degrees in one full turn
+pub const DEGREES_PER_TURN: f64 = 360.0;
+
+/// NATO mils in one full turn
+pub const NATO_MILS_PER_TURN: f64 = 6400.0;
+
+/// Degrees conversion functions
+pub mod degrees {
+    use crate::conversions::quantity::{Angle, Quantity, Unit};
+
+    /// Converts degrees to radians.
+    /// # Arguments
+    /// * `value` - The angle in degrees to convert
+    pub fn to_radians(value: f64) -> f64 {
+        value.to_radians()
+    }
+
+    /// Converts degrees to gradians.
+    /// # Arguments
+    /// * `value` - The angle in degrees to convert
+    pub fn to_gradians(value: f64) -> f64 {
+        value / 0.9
+    }
+
+    /// Converts degrees to turns.
+    /// # Arguments
+    /// * `value` - The angle in degrees to convert
+    pub fn to_turns(value: f64) -> f64 {
+        value / super::DEGREES_PER_TURN
+    }
+
+    /// Converts degrees to arcminutes.
+    /// # Arguments
+    /// * `value` - The angle in degrees to convert
+    pub fn to_arcminutes(value: f64) -> f64 {
+        value * 60.0
+    }
+
+    /// Converts degrees to arcseconds.
+    /// # Arguments
+    /// * `value` - The angle in degrees to convert
+    pub fn to_arcseconds(value: f64) -> f64 {
+        value * 3600.0
+    }
+
+    /// Converts degrees to milliradians.
+    /// # Arguments
+    /// * `value` - The angle in degrees to convert
+    pub fn to_milliradians(value: f64) -> f64 {
+        value.to_radians() * 1000.0
+    }
+
+    /// Converts degrees to NATO mils.
+    /// # Arguments
+    /// * `value` - The angle in degrees to convert
+    pub fn to_nato_mils(value: f64) -> f64 {
+        value * super::NATO_MILS_PER_TURN / super::DEGREES_PER_TURN
+    }
+
+    /// The degree as a typed unit of angle.
+    pub const UNIT: Unit<Angle> = Unit::new("°", "degree", |value| value, |value| value);
+
+    /// Creates a typed angle quantity expressed in degrees.
+    /// # Arguments
+    /// * `value` - The angle in degrees
+    pub fn new(value: f64) -> Quantity<Angle> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed angle quantity in degrees.
+    /// # Arguments
+    /// * `quantity` - The angle quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Angle>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Radians conversion functions
+pub mod radians {
+    use crate::conversions::quantity::{Angle, Quantity, Unit};
+
+    /// Converts radians to degrees.
+    /// # Arguments
+    /// * `value` - The angle in radians to convert
+    pub fn to_degrees(value: f64) -> f64 {
+        value.to_degrees()
+    }
+
+    /// Converts radians to gradians.
+    /// # Arguments
+    /// * `value` - The angle in radians to convert
+    pub fn to_gradians(value: f64) -> f64 {
+        super::degrees::to_gradians(to_degrees(value))
+    }
+
+    /// Converts radians to turns.
+    /// # Arguments
+    /// * `value` - The angle in radians to convert
+    pub fn to_turns(value: f64) -> f64 {
+        super::degrees::to_turns(to_degrees(value))
+    }
+
+    /// Converts radians to arcminutes.
+    /// # Arguments
+    /// * `value` - The angle in radians to convert
+    pub fn to_arcminutes(value: f64) -> f64 {
+        super::degrees::to_arcminutes(to_degrees(value))
+    }
+
+    /// Converts radians to arcseconds.
+    /// # Arguments
+    /// * `value` - The angle in radians to convert
+    pub fn to_arcseconds(value: f64) -> f64 {
+        super::degrees::to_arcseconds(to_degrees(value))
+    }
+
+    /// Converts radians to milliradians.
+    /// # Arguments
+    /// * `value` - The angle in radians to convert
+    pub fn to_milliradians(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// Converts radians to NATO mils.
+    /// # Arguments
+    /// * `value` - The angle in radians to convert
+    pub fn to_nato_mils(value: f64) -> f64 {
+        super::degrees::to_nato_mils(to_degrees(value))
+    }
+
+    /// The radian as a typed unit of angle.
+    pub const UNIT: Unit<Angle> =
+        Unit::new("rad", "radian", to_degrees, super::degrees::to_radians);
+
+    /// Creates a typed angle quantity expressed in radians.
+    /// # Arguments
+    /// * `value` - The angle in radians
+    pub fn new(value: f64) -> Quantity<Angle> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed angle quantity in radians.
+    /// # Arguments
+    /// * `quantity` - The angle quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Angle>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Gradians (gons) conversion functions
+pub mod gradians {
+    use crate::conversions::quantity::{Angle, Quantity, Unit};
+
+    /// Converts gradians to degrees.
+    /// # Arguments
+    /// * `value` - The angle in gradians to convert
+    pub fn to_degrees(value: f64) -> f64 {
+        value * 0.9
+    }
+
+    /// Converts gradians to radians.
+    /// # Arguments
+    /// * `value` - The angle in gradians to convert
+    pub fn to_radians(value: f64) -> f64 {
+        super::degrees::to_radians(to_degrees(value))
+    }
+
+    /// Converts gradians to turns.
+    /// # Arguments
+    /// * `value` - The angle in gradians to convert
+    pub fn to_turns(value: f64) -> f64 {
+        super::degrees::to_turns(to_degrees(value))
+    }
+
+    /// Converts gradians to arcminutes.
+    /// # Arguments
+    /// * `value` - The angle in gradians to convert
+    pub fn to_arcminutes(value: f64) -> f64 {
+        super::degrees::to_arcminutes(to_degrees(value))
+    }
+
+    /// Converts gradians to arcseconds.
+    /// # Arguments
+    /// * `value` - The angle in gradians to convert
+    pub fn to_arcseconds(value: f64) -> f64 {
+        super::degrees::to_arcseconds(to_degrees(value))
+    }
+
+    /// Converts gradians to milliradians.
+    /// # Arguments
+    /// * `value` - The angle in gradians to convert
+    pub fn to_milliradians(value: f64) -> f64 {
+        super::degrees::to_milliradians(to_degrees(value))
+    }
+
+    /// Converts gradians to NATO mils.
+    /// # Arguments
+    /// * `value` - The angle in gradians to convert
+    pub fn to_nato_mils(value: f64) -> f64 {
+        super::degrees::to_nato_mils(to_degrees(value))
+    }
+
+    /// The gradian as a typed unit of angle.
+    pub const UNIT: Unit<Angle> =
+        Unit::new("gon", "gradian", to_degrees, super::degrees::to_gradians);
+
+    /// Creates a typed angle quantity expressed in gradians.
+    /// # Arguments
+    /// * `value` - The angle in gradians
+    pub fn new(value: f64) -> Quantity<Angle> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed angle quantity in gradians.
+    /// # Arguments
+    /// * `quantity` - The angle quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Angle>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Turns (full revolutions) conversion functions
+pub mod turns {
+    use crate::conversions::quantity::{Angle, Quantity, Unit};
+
+    /// Converts turns to degrees.
+    /// # Arguments
+    /// * `value` - The angle in turns to convert
+    pub fn to_degrees(value: f64) -> f64 {
+        value * super::DEGREES_PER_TURN
+    }
+
+    /// Converts turns to radians.
+    /// # Arguments
+    /// * `value` - The angle in turns to convert
+    pub fn to_radians(value: f64) -> f64 {
+        super::degrees::to_radians(to_degrees(value))
+    }
+
+    /// Converts turns to gradians.
+    /// # Arguments
+    /// * `value` - The angle in turns to convert
+    pub fn to_gradians(value: f64) -> f64 {
+        super::degrees::to_gradians(to_degrees(value))
+    }
+
+    /// Converts turns to arcminutes.
+    /// # Arguments
+    /// * `value` - The angle in turns to convert
+    pub fn to_arcminutes(value: f64) -> f64 {
+        super::degrees::to_arcminutes(to_degrees(value))
+    }
+
+    /// Converts turns to arcseconds.
+    /// # Arguments
+    /// * `value` - The angle in turns to convert
+    pub fn to_arcseconds(value: f64) -> f64 {
+        super::degrees::to_arcseconds(to_degrees(value))
+    }
+
+    /// Converts turns to milliradians.
+    /// # Arguments
+    /// * `value` - The angle in turns to convert
+    pub fn to_milliradians(value: f64) -> f64 {
+        super::degrees::to_milliradians(to_degrees(value))
+    }
+
+    /// Converts turns to NATO mils.
+    /// # Arguments
+    /// * `value` - The angle in turns to convert
+    pub fn to_nato_mils(value: f64) -> f64 {
+        value * super::NATO_MILS_PER_TURN
+    }
+
+    /// The turn as a typed unit of angle.
+    pub const UNIT: Unit<Angle> = Unit::new("tr", "turn", to_degrees, super::degrees::to_turns);
+
+    /// Creates a typed angle quantity expressed in turns.
+    /// # Arguments
+    /// * `value` - The angle in turns
+    pub fn new(value: f64) -> Quantity<Angle> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed angle quantity in turns.
+    /// # Arguments
+    /// * `quantity` - The angle quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Angle>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Arcminutes conversion functions
+pub mod arcminutes {
+    use crate::conversions::quantity::{Angle, Quantity, Unit};
+
+    /// Converts arcminutes to degrees.
+    /// # Arguments
+    /// * `value` - The angle in arcminutes to convert
+    pub fn to_degrees(value: f64) -> f64 {
+        value / 60.0
+    }
+
+    /// Converts arcminutes to radians.
+    /// # Arguments
+    /// * `value` - The angle in arcminutes to convert
+    pub fn to_radians(value: f64) -> f64 {
+        super::degrees::to_radians(to_degrees(value))
+    }
+
+    /// Converts arcminutes to gradians.
+    /// # Arguments
+    /// * `value` - The angle in arcminutes to convert
+    pub fn to_gradians(value: f64) -> f64 {
+        super::degrees::to_gradians(to_degrees(value))
+    }
+
+    /// Converts arcminutes to turns.
+    /// # Arguments
+    /// * `value` - The angle in arcminutes to convert
+    pub fn to_turns(value: f64) -> f64 {
+        super::degrees::to_turns(to_degrees(value))
+    }
+
+    /// Converts arcminutes to arcseconds.
+    /// # Arguments
+    /// * `value` - The angle in arcminutes to convert
+    pub fn to_arcseconds(value: f64) -> f64 {
+        value * 60.0
+    }
+
+    /// Converts arcminutes to milliradians.
+    /// # Arguments
+    /// * `value` - The angle in arcminutes to convert
+    pub fn to_milliradians(value: f64) -> f64 {
+        super::degrees::to_milliradians(to_degrees(value))
+    }
+
+    /// Converts arcminutes to NATO mils.
+    /// # Arguments
+    /// * `value` - The angle in arcminutes to convert
+    pub fn to_nato_mils(value: f64) -> f64 {
+        super::degrees::to_nato_mils(to_degrees(value))
+    }
+
+    /// The arcminute as a typed unit of angle.
+    pub const UNIT: Unit<Angle> = Unit::new(
+        "arcmin",
+        "arcminute",
+        to_degrees,
+        super::degrees::to_arcminutes,
+    );
+
+    /// Creates a typed angle quantity expressed in arcminutes.
+    /// # Arguments
+    /// * `value` - The angle in arcminutes
+    pub fn new(value: f64) -> Quantity<Angle> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed angle quantity in arcminutes.
+    /// # Arguments
+    /// * `quantity` - The angle quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Angle>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Arcseconds conversion functions
+pub mod arcseconds {
+    use crate::conversions::quantity::{Angle, Quantity, Unit};
+
+    /// Converts arcseconds to degrees.
+    /// # Arguments
+    /// * `value` - The angle in arcseconds to convert
+    pub fn to_degrees(value: f64) -> f64 {
+        value / 3600.0
+    }
+
+    /// Converts arcseconds to radians.
+    /// # Arguments
+    /// * `value` - The angle in arcseconds to convert
+    pub fn to_radians(value: f64) -> f64 {
+        super::degrees::to_radians(to_degrees(value))
+    }
+
+    /// Converts arcseconds to gradians.
+    /// # Arguments
+    /// * `value` - The angle in arcseconds to convert
+    pub fn to_gradians(value: f64) -> f64 {
+        super::degrees::to_gradians(to_degrees(value))
+    }
+
+    /// Converts arcseconds to turns.
+    /// # Arguments
+    /// * `value` - The angle in arcseconds to convert
+    pub fn to_turns(value: f64) -> f64 {
+        super::degrees::to_turns(to_degrees(value))
+    }
+
+    /// Converts arcseconds to arcminutes.
+    /// # Arguments
+    /// * `value` - The angle in arcseconds to convert
+    pub fn to_arcminutes(value: f64) -> f64 {
+        value / 60.0
+    }
+
+    /// Converts arcseconds to milliradians.
+    /// # Arguments
+    /// * `value` - The angle in arcseconds to convert
+    pub fn to_milliradians(value: f64) -> f64 {
+        super::degrees::to_milliradians(to_degrees(value))
+    }
+
+    /// Converts arcseconds to NATO mils.
+    /// # Arguments
+    /// * `value` - The angle in arcseconds to convert
+    pub fn to_nato_mils(value: f64) -> f64 {
+        super::degrees::to_nato_mils(to_degrees(value))
+    }
+
+    /// The arcsecond as a typed unit of angle.
+    pub const UNIT: Unit<Angle> = Unit::new(
+        "arcsec",
+        "arcsecond",
+        to_degrees,
+        super::degrees::to_arcseconds,
+    );
+
+    /// Creates a typed angle quantity expressed in arcseconds.
+    /// # Arguments
+    /// * `value` - The angle in arcseconds
+    pub fn new(value: f64) -> Quantity<Angle> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed angle quantity in arcseconds.
+    /// # Arguments
+    /// * `quantity` - The angle quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Angle>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Milliradians (true mils, 1/1000 rad) conversion functions
+pub mod milliradians {
+    use crate::conversions::quantity::{Angle, Quantity, Unit};
+
+    /// Converts milliradians to degrees.
+    /// # Arguments
+    /// * `value` - The angle in milliradians to convert
+    pub fn to_degrees(value: f64) -> f64 {
+        (value / 1000.0).to_degrees()
+    }
+
+    /// Converts milliradians to radians.
+    /// # Arguments
+    /// * `value` - The angle in milliradians to convert
+    pub fn to_radians(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// Converts milliradians to gradians.
+    /// # Arguments
+    /// * `value` - The angle in milliradians to convert
+    pub fn to_gradians(value: f64) -> f64 {
+        super::degrees::to_gradians(to_degrees(value))
+    }
+
+    /// Converts milliradians to turns.
+    /// # Arguments
+    /// * `value` - The angle in milliradians to convert
+    pub fn to_turns(value: f64) -> f64 {
+        super::degrees::to_turns(to_degrees(value))
+    }
+
+    /// Converts milliradians to arcminutes.
+    /// # Arguments
+    /// * `value` - The angle in milliradians to convert
+    pub fn to_arcminutes(value: f64) -> f64 {
+        super::degrees::to_arcminutes(to_degrees(value))
+    }
+
+    /// Converts milliradians to arcseconds.
+    /// # Arguments
+    /// * `value` - The angle in milliradians to convert
+    pub fn to_arcseconds(value: f64) -> f64 {
+        super::degrees::to_arcseconds(to_degrees(value))
+    }
+
+    /// Converts milliradians to NATO mils.
+    /// # Arguments
+    /// * `value` - The angle in milliradians to convert
+    pub fn to_nato_mils(value: f64) -> f64 {
+        super::degrees::to_nato_mils(to_degrees(value))
+    }
+
+    /// The milliradian as a typed unit of angle.
+    pub const UNIT: Unit<Angle> = Unit::new(
+        "mrad",
+        "milliradian",
+        to_degrees,
+        super::degrees::to_milliradians,
+    );
+
+    /// Creates a typed angle quantity expressed in milliradians.
+    /// # Arguments
+    /// * `value` - The angle in milliradians
+    pub fn new(value: f64) -> Quantity<Angle> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed angle quantity in milliradians.
+    /// # Arguments
+    /// * `quantity` - The angle quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Angle>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// NATO mils (1/6400 turn) conversion functions
+pub mod nato_mils {
+    use crate::conversions::quantity::{Angle, Quantity, Unit};
+
+    /// Converts NATO mils to degrees.
+    /// # Arguments
+    /// * `value` - The angle in NATO mils to convert
+    pub fn to_degrees(value: f64) -> f64 {
+        value * super::DEGREES_PER_TURN / super::NATO_MILS_PER_TURN
+    }
+
+    /// Converts NATO mils to radians.
+    /// # Arguments
+    /// * `value` - The angle in NATO mils to convert
+    pub fn to_radians(value: f64) -> f64 {
+        super::degrees::to_radians(to_degrees(value))
+    }
+
+    /// Converts NATO mils to gradians.
+    /// # Arguments
+    /// * `value` - The angle in NATO mils to convert
+    pub fn to_gradians(value: f64) -> f64 {
+        super::degrees::to_gradians(to_degrees(value))
+    }
+
+    /// Converts NATO mils to turns.
+    /// # Arguments
+    /// * `value` - The angle in NATO mils to convert
+    pub fn to_turns(value: f64) -> f64 {
+        value / super::NATO_MILS_PER_TURN
+    }
+
+    /// Converts NATO mils to arcminutes.
+    /// # Arguments
+    /// * `value` - The angle in NATO mils to convert
+    pub fn to_arcminutes(value: f64) -> f64 {
+        super::degrees::to_arcminutes(to_degrees(value))
+    }
+
+    /// Converts NATO mils to arcseconds.
+    /// # Arguments
+    /// * `value` - The angle in NATO mils to convert
+    pub fn to_arcseconds(value: f64) -> f64 {
+        super::degrees::to_arcseconds(to_degrees(value))
+    }
+
+    /// Converts NATO mils to milliradians.
+    /// # Arguments
+    /// * `value` - The angle in NATO mils to convert
+    pub fn to_milliradians(value: f64) -> f64 {
+        super::degrees::to_milliradians(to_degrees(value))
+    }
+
+    /// The NATO mil as a typed unit of angle.
+    pub const UNIT: Unit<Angle> =
+        Unit::new("mil", "NATO mil", to_degrees, super::degrees::to_nato_mils);
+
+    /// Creates a typed angle quantity expressed in NATO mils.
+    /// # Arguments
+    /// * `value` - The angle in NATO mils
+    pub fn new(value: f64) -> Quantity<Angle> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed angle quantity in NATO mils.
+    /// # Arguments
+    /// * `quantity` - The angle quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Angle>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// The range [`normalize_angle`] wraps an angle into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AngleRange {
+    /// One turn starting at zero: [0°, 360°)
+    Positive,
+    /// One turn centred on zero: (-180°, 180°]
+    Signed,
+}
+
+/// Wraps an angle into a single turn, in whatever unit it is expressed in.
+///
+/// # Arguments
+/// * `value` - The angle to wrap
+/// * `unit` - The unit of the angle (e.g., "°", "rad", "gon", "mil")
+/// * `range` - Whether to wrap into [0, 1 turn) or (-½ turn, ½ turn]
+///
+/// # Returns
+/// * `Ok(f64)` - The equivalent angle within `range`, in `unit`
+/// * `Err(ConversionError)` - If the unit is unknown or the value is not finite
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::{normalize_angle, AngleRange};
+///
+/// assert_eq!(normalize_angle(370.0, "deg", AngleRange::Positive).unwrap(), 10.0);
+/// assert_eq!(normalize_angle(270.0, "deg", AngleRange::Signed).unwrap(), -90.0);
+/// assert_eq!(normalize_angle(-180.0, "deg", AngleRange::Signed).unwrap(), 180.0);
+/// ```
+pub fn normalize_angle(value: f64, unit: &str, range: AngleRange) -> Result<f64, ConversionError> {
+    ConversionError::ensure_finite(value)?;
+    let turn = convert_angle(1.0, "tr", unit)?;
+
+    // `rem_euclid` can round up to a whole turn for tiny negative values
+    let positive = match value.rem_euclid(turn) {
+        wrapped if wrapped >= turn => 0.0,
+        wrapped => wrapped,
+    };
+    Ok(match range {
+        AngleRange::Positive => positive,
+        AngleRange::Signed if positive > turn / 2.0 => positive - turn,
+        AngleRange::Signed => positive,
+    })
+}
+
+/// Formats an angle in decimal degrees as degrees, minutes and seconds.
+///
+/// Minutes and seconds are zero-padded to two digits, and seconds are rounded
+/// to `precision` decimal places, carrying into the minutes and degrees.
+/// # Arguments
+/// * `degrees` - The angle in decimal degrees
+/// * `precision` - The number of decimal places of the seconds, at most
+///   [`MAX_FORMAT_PRECISION`] (larger values are clamped)
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::format_dms;
+///
+/// assert_eq!(format_dms(12.582_222_222, 0), "12°34'56\"");
+/// assert_eq!(format_dms(-0.5, 1), "-0°30'00.0\"");
+/// assert_eq!(format_dms(29.999_999, 2), "30°00'00.00\"");
+/// ```
+pub fn format_dms(degrees: f64, precision: usize) -> String {
+    if !degrees.is_finite() {
+        return degrees.to_string();
+    }
+
+    let precision = precision.min(MAX_FORMAT_PRECISION);
+
+    // Round once, in units of the last printed digit, so 59.999" cannot show as 60"
+    let scale = 10f64.powi(precision as i32);
+    let total = (degrees.abs() * 3600.0 * scale).round();
+    let whole_degrees = (total / (3600.0 * scale)).floor();
+    let remainder = total - whole_degrees * 3600.0 * scale;
+    let minutes = (remainder / (60.0 * scale)).floor();
+    let seconds = (remainder - minutes * 60.0 * scale) / scale;
+
+    let sign = if degrees < 0.0 && total > 0.0 {
+        "-"
+    } else {
+        ""
+    };
+    let width = if precision == 0 { 2 } else { precision + 3 };
+    format!(
+        "{}{}°{:02}'{:0width$.precision$}\"",
+        sign,
+        whole_degrees,
+        minutes,
+        seconds,
+        width = width,
+        precision = precision
+    )
+}
+
+/// Parses a degrees-minutes-seconds angle into decimal degrees.
+///
+/// Accepts the `°`/`'`/`"` marks (or `′`/`″`, `d`/`m`/`s`, or plain spaces
+/// and colons), a leading sign, and a trailing hemisphere letter where `S` and
+/// `W` are negative. Minutes and seconds may be omitted, only the last
+/// component may have a fraction, and minutes and seconds must be below 60.
+/// # Arguments
+/// * `input` - The angle to parse (e.g., `12°34'56"`, `-12 34 56`, `51°28.5'N`)
+///
+/// # Returns
+/// * `Ok(f64)` - The angle in decimal degrees
+/// * `Err(ConversionError)` - An [`ConversionError::InvalidAngle`] if the
+///   input is not a degrees-minutes-seconds angle
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::parse_dms;
+///
+/// let degrees = parse_dms("12°34'56\"").unwrap();
+/// assert!((degrees - 12.582_222).abs() < 1e-6);
+///
+/// assert_eq!(parse_dms("0°30'W").unwrap(), -0.5);
+/// assert!(parse_dms("12°75'").is_err());
+/// ```
+pub fn parse_dms(input: &str) -> Result<f64, ConversionError> {
+    let invalid = || ConversionError::InvalidAngle {
+        input: input.to_string(),
+    };
+
+    let mut rest = input.trim();
+    let mut negative = false;
+    if let Some(stripped) = rest.strip_suffix(['S', 'W']) {
+        negative = true;
+        rest = stripped.trim_end();
+    } else if let Some(stripped) = rest.strip_suffix(['N', 'E']) {
+        rest = stripped.trim_end();
+    }
+    if let Some(stripped) = rest.strip_prefix('-') {
+        negative = !negative;
+        rest = stripped;
+    } else if let Some(stripped) = rest.strip_prefix('+') {
+        rest = stripped;
+    }
+
+    let mut components: Vec<f64> = Vec::new();
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ':');
+        if rest.is_empty() {
+            break;
+        }
+
+        let end = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let number: f64 = rest[..end].parse().map_err(|_| invalid())?;
+        rest = rest[end..].trim_start();
+
+        if let Some((position, mark)) = DMS_MARKS
+            .iter()
+            .copied()
+            .find(|&(_, mark)| rest.starts_with(mark))
+        {
+            if position != components.len() {
+                return Err(invalid());
+            }
+            rest = &rest[mark.len()..];
+        }
+        if components.len() == 3 {
+            return Err(invalid());
+        }
+        components.push(number);
+    }
+
+    let (last, leading) = components.split_last().ok_or_else(invalid)?;
+    let fractional_leading = leading.iter().any(|component| component.fract() != 0.0);
+    let minutes_or_seconds_overflow = components[1..].iter().any(|&component| component >= 60.0);
+    if fractional_leading || minutes_or_seconds_overflow || !last.is_finite() {
+        return Err(invalid());
+    }
+
+    let degrees = components
+        .iter()
+        .zip([1.0, 60.0, 3600.0])
+        .map(|(component, divisor)| component / divisor)
+        .sum::<f64>();
+    Ok(if negative { -degrees } else { degrees })
+}
+
+/// The marks that may follow each component of a DMS angle, by position
+/// (0 = degrees, 1 = minutes, 2 = seconds). `''` is checked before `'`.
+const DMS_MARKS: [(usize, &str); 10] = [
+    (0, "°"),
+    (0, "º"),
+    (0, "d"),
+    (2, "''"),
+    (1, "'"),
+    (1, "′"),
+    (1, "m"),
+    (2, "\""),
+    (2, "″"),
+    (2, "s"),
+];
+
+/// General angle conversion function that accepts string unit names
+///
+/// Converts a plane angle from one unit to another using string identifiers.
+/// Symbols are case-sensitive, names are case-insensitive, and common
+/// abbreviations are supported.
+///
+/// # Arguments
+///
+/// * `value` - The numeric value to convert
+/// * `from_unit` - The source unit (e.g., "°", "deg", "rad", "gon", "tr", "arcmin",
+///   "arcsec", "mrad", "mil")
+/// * `to_unit` - The target unit using the same abbreviations
+///
+/// # Returns
+/// * `Ok(f64)` - The converted value
+/// * `Err(ConversionError)` - The reason the conversion is not supported
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::convert_angle;
+///
+/// let radians = convert_angle(180.0, "deg", "rad").unwrap();
+/// assert!((radians - std::f64::consts::PI).abs() < 1e-12);
+///
+/// let mils = convert_angle(90.0, "°", "mil").unwrap();
+/// assert!((mils - 1600.0).abs() < 1e-9);
+/// ```
+pub fn convert_angle(value: f64, from_unit: &str, to_unit: &str) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from_unit, to_unit, Category::Angle)
+}
+
+/// Plane angle units known to the [`UnitRegistry`], with degrees as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new(
+            "°",
+            "degree",
+            &["deg", "degs", "degrees", "º"],
+            Category::Angle,
+            1.0,
+        ),
+        UnitDef::new(
+            "rad",
+            "radian",
+            &["radians"],
+            Category::Angle,
+            radians::to_degrees(1.0),
+        )
+        .with_irrational_factor()
+        .with_prefixes(),
+        UnitDef::new(
+            "gon",
+            "gradian",
+            &["grad", "grads", "gradians", "gons"],
+            Category::Angle,
+            gradians::to_degrees(1.0),
+        ),
+        UnitDef::new(
+            "tr",
+            "turn",
+            &["turns", "rev", "revolution", "revolutions"],
+            Category::Angle,
+            turns::to_degrees(1.0),
+        ),
+        UnitDef::new(
+            "arcmin",
+            "arcminute",
+            &["′", "'", "arcminutes", "minute of arc", "minutes of arc"],
+            Category::Angle,
+            arcminutes::to_degrees(1.0),
+        )
+        .with_exact_factor("1/60"),
+        UnitDef::new(
+            "arcsec",
+            "arcsecond",
+            &["″", "\"", "arcseconds", "second of arc", "seconds of arc"],
+            Category::Angle,
+            arcseconds::to_degrees(1.0),
+        )
+        .with_exact_factor("1/3600"),
+        UnitDef::new(
+            "mrad",
+            "milliradian",
+            &["milliradians", "true mil", "true mils"],
+            Category::Angle,
+            milliradians::to_degrees(1.0),
+        )
+        .with_irrational_factor(),
+        UnitDef::new(
+            "mil",
+            "NATO mil",
+            &["mils", "NATO mils", "nato_mils"],
+            Category::Angle,
+            nato_mils::to_degrees(1.0),
+        )
+        .with_exact_factor("360/6400"),
+    ]
+}
@@ -1,8 +1,29 @@
 //! Area conversion functions organized by unit type
 //! All conversions use square meters as the base unit for accuracy and consistency
 
+use super::category::Category;
+use super::error::ConversionError;
+use super::registry::{UnitDef, UnitRegistry};
+
+/// Square meters in one square inch (the international inch squared)
+pub const SQUARE_METERS_PER_SQUARE_INCH: f64 = 0.000_645_16;
+
+/// Square meters in one square foot (the international foot squared)
+pub const SQUARE_METERS_PER_SQUARE_FOOT: f64 = 0.092_903_04;
+
+/// Square meters in one square yard (the international yard squared)
+pub const SQUARE_METERS_PER_SQUARE_YARD: f64 = 0.836_127_36;
+
+/// Square meters in one acre (43,560 international square feet)
+pub const SQUARE_METERS_PER_ACRE: f64 = 4_046.856_422_4;
+
+/// Square meters in one square mile (the international mile squared)
+pub const SQUARE_METERS_PER_SQUARE_MILE: f64 = 2_589_988.110_336;
+
 /// Square meters conversion functions
 pub mod square_meters {
+    use crate::conversions::quantity::{Area, Quantity, Unit};
+
     /// Converts square meters to square millimeters.
     /// # Arguments
     /// * `value` - The area in square meters to convert
@@ -28,28 +49,28 @@ pub mod square_meters {
     /// # Arguments
     /// * `value` - The area in square meters to convert
     pub fn to_square_inches(value: f64) -> f64 {
-        value * 1550.0031
+        value / super::SQUARE_METERS_PER_SQUARE_INCH
     }
 
     /// Converts square meters to square feet.
     /// # Arguments
     /// * `value` - The area in square meters to convert
     pub fn to_square_feet(value: f64) -> f64 {
-        value * 10.763910417
+        value / super::SQUARE_METERS_PER_SQUARE_FOOT
     }
 
     /// Converts square meters to square yards.
     /// # Arguments
     /// * `value` - The area in square meters to convert
     pub fn to_square_yards(value: f64) -> f64 {
-        value * 1.1959900463
+        value / super::SQUARE_METERS_PER_SQUARE_YARD
     }
 
     /// Converts square meters to acres.
     /// # Arguments
     /// * `value` - The area in square meters to convert
     pub fn to_acres(value: f64) -> f64 {
-        value / 4046.8564224
+        value / super::SQUARE_METERS_PER_ACRE
     }
 
     /// Converts square meters to hectares.
@@ -63,12 +84,31 @@ pub mod square_meters {
     /// # Arguments
     /// * `value` - The area in square meters to convert
     pub fn to_square_miles(value: f64) -> f64 {
-        value / 2_589_988.110336
+        value / super::SQUARE_METERS_PER_SQUARE_MILE
+    }
+
+    /// The square meter as a typed unit of area.
+    pub const UNIT: Unit<Area> = Unit::new("m²", "square meter", |value| value, |value| value);
+
+    /// Creates a typed area quantity expressed in square meters.
+    /// # Arguments
+    /// * `value` - The area in square meters
+    pub fn new(value: f64) -> Quantity<Area> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed area quantity in square meters.
+    /// # Arguments
+    /// * `quantity` - The area quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Area>) -> f64 {
+        quantity.value_in(UNIT)
     }
 }
 
 /// Square millimeters conversion functions
 pub mod square_millimeters {
+    use crate::conversions::quantity::{Area, Quantity, Unit};
+
     /// Converts square millimeters to square meters.
     /// # Arguments
     /// * `value` - The area in square millimeters to convert
@@ -131,10 +171,34 @@ pub mod square_millimeters {
     pub fn to_square_miles(value: f64) -> f64 {
         super::square_meters::to_square_miles(to_square_meters(value))
     }
+
+    /// The square millimeter as a typed unit of area.
+    pub const UNIT: Unit<Area> = Unit::new(
+        "mm²",
+        "square millimeter",
+        to_square_meters,
+        super::square_meters::to_square_millimeters,
+    );
+
+    /// Creates a typed area quantity expressed in square millimeters.
+    /// # Arguments
+    /// * `value` - The area in square millimeters
+    pub fn new(value: f64) -> Quantity<Area> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed area quantity in square millimeters.
+    /// # Arguments
+    /// * `quantity` - The area quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Area>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Square centimeters conversion functions
 pub mod square_centimeters {
+    use crate::conversions::quantity::{Area, Quantity, Unit};
+
     /// Converts square centimeters to square meters.
     /// # Arguments
     /// * `value` - The area in square centimeters to convert
@@ -160,7 +224,7 @@ pub mod square_centimeters {
     /// # Arguments
     /// * `value` - The area in square centimeters to convert
     pub fn to_square_inches(value: f64) -> f64 {
-        value * 0.15500031
+        super::square_meters::to_square_inches(to_square_meters(value))
     }
 
     /// Converts square centimeters to square feet.
@@ -197,10 +261,34 @@ pub mod square_centimeters {
     pub fn to_square_miles(value: f64) -> f64 {
         super::square_meters::to_square_miles(to_square_meters(value))
     }
+
+    /// The square centimeter as a typed unit of area.
+    pub const UNIT: Unit<Area> = Unit::new(
+        "cm²",
+        "square centimeter",
+        to_square_meters,
+        super::square_meters::to_square_centimeters,
+    );
+
+    /// Creates a typed area quantity expressed in square centimeters.
+    /// # Arguments
+    /// * `value` - The area in square centimeters
+    pub fn new(value: f64) -> Quantity<Area> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed area quantity in square centimeters.
+    /// # Arguments
+    /// * `quantity` - The area quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Area>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Square kilometers conversion functions
 pub mod square_kilometers {
+    use crate::conversions::quantity::{Area, Quantity, Unit};
+
     /// Converts square kilometers to square meters.
     /// # Arguments
     /// * `value` - The area in square kilometers to convert
@@ -247,7 +335,7 @@ pub mod square_kilometers {
     /// # Arguments
     /// * `value` - The area in square kilometers to convert
     pub fn to_acres(value: f64) -> f64 {
-        value * 247.10538147
+        super::square_meters::to_acres(to_square_meters(value))
     }
 
     /// Converts square kilometers to hectares.
@@ -261,17 +349,41 @@ pub mod square_kilometers {
     /// # Arguments
     /// * `value` - The area in square kilometers to convert
     pub fn to_square_miles(value: f64) -> f64 {
-        value * 0.3861021585
+        super::square_meters::to_square_miles(to_square_meters(value))
+    }
+
+    /// The square kilometer as a typed unit of area.
+    pub const UNIT: Unit<Area> = Unit::new(
+        "km²",
+        "square kilometer",
+        to_square_meters,
+        super::square_meters::to_square_kilometers,
+    );
+
+    /// Creates a typed area quantity expressed in square kilometers.
+    /// # Arguments
+    /// * `value` - The area in square kilometers
+    pub fn new(value: f64) -> Quantity<Area> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed area quantity in square kilometers.
+    /// # Arguments
+    /// * `quantity` - The area quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Area>) -> f64 {
+        quantity.value_in(UNIT)
     }
 }
 
 /// Square inches conversion functions
 pub mod square_inches {
+    use crate::conversions::quantity::{Area, Quantity, Unit};
+
     /// Converts square inches to square meters.
     /// # Arguments
     /// * `value` - The area in square inches to convert
     pub fn to_square_meters(value: f64) -> f64 {
-        value / 1550.0031
+        value * super::SQUARE_METERS_PER_SQUARE_INCH
     }
 
     /// Converts square inches to square millimeters.
@@ -285,7 +397,7 @@ pub mod square_inches {
     /// # Arguments
     /// * `value` - The area in square inches to convert
     pub fn to_square_centimeters(value: f64) -> f64 {
-        value / 0.15500031
+        super::square_meters::to_square_centimeters(to_square_meters(value))
     }
 
     /// Converts square inches to square kilometers.
@@ -329,15 +441,39 @@ pub mod square_inches {
     pub fn to_square_miles(value: f64) -> f64 {
         super::square_meters::to_square_miles(to_square_meters(value))
     }
+
+    /// The square inch as a typed unit of area.
+    pub const UNIT: Unit<Area> = Unit::new(
+        "in²",
+        "square inch",
+        to_square_meters,
+        super::square_meters::to_square_inches,
+    );
+
+    /// Creates a typed area quantity expressed in square inches.
+    /// # Arguments
+    /// * `value` - The area in square inches
+    pub fn new(value: f64) -> Quantity<Area> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed area quantity in square inches.
+    /// # Arguments
+    /// * `quantity` - The area quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Area>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Square feet conversion functions
 pub mod square_feet {
+    use crate::conversions::quantity::{Area, Quantity, Unit};
+
     /// Converts square feet to square meters.
     /// # Arguments
     /// * `value` - The area in square feet to convert
     pub fn to_square_meters(value: f64) -> f64 {
-        value / 10.763910417
+        value * super::SQUARE_METERS_PER_SQUARE_FOOT
     }
 
     /// Converts square feet to square millimeters.
@@ -395,15 +531,39 @@ pub mod square_feet {
     pub fn to_square_miles(value: f64) -> f64 {
         value / 27878400.0
     }
+
+    /// The square foot as a typed unit of area.
+    pub const UNIT: Unit<Area> = Unit::new(
+        "ft²",
+        "square foot",
+        to_square_meters,
+        super::square_meters::to_square_feet,
+    );
+
+    /// Creates a typed area quantity expressed in square feet.
+    /// # Arguments
+    /// * `value` - The area in square feet
+    pub fn new(value: f64) -> Quantity<Area> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed area quantity in square feet.
+    /// # Arguments
+    /// * `quantity` - The area quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Area>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Square yards conversion functions
 pub mod square_yards {
+    use crate::conversions::quantity::{Area, Quantity, Unit};
+
     /// Converts square yards to square meters.
     /// # Arguments
     /// * `value` - The area in square yards to convert
     pub fn to_square_meters(value: f64) -> f64 {
-        value / 1.1959900463
+        value * super::SQUARE_METERS_PER_SQUARE_YARD
     }
 
     /// Converts square yards to square millimeters.
@@ -461,15 +621,39 @@ pub mod square_yards {
     pub fn to_square_miles(value: f64) -> f64 {
         value / 3097600.0
     }
+
+    /// The square yard as a typed unit of area.
+    pub const UNIT: Unit<Area> = Unit::new(
+        "yd²",
+        "square yard",
+        to_square_meters,
+        super::square_meters::to_square_yards,
+    );
+
+    /// Creates a typed area quantity expressed in square yards.
+    /// # Arguments
+    /// * `value` - The area in square yards
+    pub fn new(value: f64) -> Quantity<Area> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed area quantity in square yards.
+    /// # Arguments
+    /// * `quantity` - The area quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Area>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Acres conversion functions
 pub mod acres {
+    use crate::conversions::quantity::{Area, Quantity, Unit};
+
     /// Converts acres to square meters.
     /// # Arguments
     /// * `value` - The area in acres to convert
     pub fn to_square_meters(value: f64) -> f64 {
-        value * 4046.8564224
+        value * super::SQUARE_METERS_PER_ACRE
     }
 
     /// Converts acres to square millimeters.
@@ -490,7 +674,7 @@ pub mod acres {
     /// # Arguments
     /// * `value` - The area in acres to convert
     pub fn to_square_kilometers(value: f64) -> f64 {
-        value / 247.10538147
+        super::square_meters::to_square_kilometers(to_square_meters(value))
     }
 
     /// Converts acres to square inches.
@@ -518,7 +702,7 @@ pub mod acres {
     /// # Arguments
     /// * `value` - The area in acres to convert
     pub fn to_hectares(value: f64) -> f64 {
-        value * 0.40468564224
+        super::square_meters::to_hectares(to_square_meters(value))
     }
 
     /// Converts acres to square miles.
@@ -527,10 +711,34 @@ pub mod acres {
     pub fn to_square_miles(value: f64) -> f64 {
         value / 640.0
     }
+
+    /// The acre as a typed unit of area.
+    pub const UNIT: Unit<Area> = Unit::new(
+        "ac",
+        "acre",
+        to_square_meters,
+        super::square_meters::to_acres,
+    );
+
+    /// Creates a typed area quantity expressed in acres.
+    /// # Arguments
+    /// * `value` - The area in acres
+    pub fn new(value: f64) -> Quantity<Area> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed area quantity in acres.
+    /// # Arguments
+    /// * `quantity` - The area quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Area>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Hectares conversion functions
 pub mod hectares {
+    use crate::conversions::quantity::{Area, Quantity, Unit};
+
     /// Converts hectares to square meters.
     /// # Arguments
     /// * `value` - The area in hectares to convert
@@ -584,7 +792,7 @@ pub mod hectares {
     /// # Arguments
     /// * `value` - The area in hectares to convert
     pub fn to_acres(value: f64) -> f64 {
-        value / 0.40468564224
+        super::square_meters::to_acres(to_square_meters(value))
     }
 
     /// Converts hectares to square miles.
@@ -593,15 +801,39 @@ pub mod hectares {
     pub fn to_square_miles(value: f64) -> f64 {
         super::square_meters::to_square_miles(to_square_meters(value))
     }
+
+    /// The hectare as a typed unit of area.
+    pub const UNIT: Unit<Area> = Unit::new(
+        "ha",
+        "hectare",
+        to_square_meters,
+        super::square_meters::to_hectares,
+    );
+
+    /// Creates a typed area quantity expressed in hectares.
+    /// # Arguments
+    /// * `value` - The area in hectares
+    pub fn new(value: f64) -> Quantity<Area> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed area quantity in hectares.
+    /// # Arguments
+    /// * `quantity` - The area quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Area>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Square miles conversion functions
 pub mod square_miles {
+    use crate::conversions::quantity::{Area, Quantity, Unit};
+
     /// Converts square miles to square meters.
     /// # Arguments
     /// * `value` - The area in square miles to convert
     pub fn to_square_meters(value: f64) -> f64 {
-        value * 2_589_988.110336
+        value * super::SQUARE_METERS_PER_SQUARE_MILE
     }
 
     /// Converts square miles to square millimeters.
@@ -622,7 +854,7 @@ pub mod square_miles {
     /// # Arguments
     /// * `value` - The area in square miles to convert
     pub fn to_square_kilometers(value: f64) -> f64 {
-        value / 0.3861021585
+        super::square_meters::to_square_kilometers(to_square_meters(value))
     }
 
     /// Converts square miles to square inches.
@@ -659,6 +891,28 @@ pub mod square_miles {
     pub fn to_hectares(value: f64) -> f64 {
         super::square_meters::to_hectares(to_square_meters(value))
     }
+
+    /// The square mile as a typed unit of area.
+    pub const UNIT: Unit<Area> = Unit::new(
+        "mi²",
+        "square mile",
+        to_square_meters,
+        super::square_meters::to_square_miles,
+    );
+
+    /// Creates a typed area quantity expressed in square miles.
+    /// # Arguments
+    /// * `value` - The area in square miles
+    pub fn new(value: f64) -> Quantity<Area> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed area quantity in square miles.
+    /// # Arguments
+    /// * `quantity` - The area quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Area>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 // Legacy function wrappers for backward compatibility
@@ -705,7 +959,8 @@ pub fn acres_to_square_meters(acres: f64) -> f64 {
 /// General area conversion function that accepts string unit names
 ///
 /// Converts an area value from one unit to another using string identifiers.
-/// This function is case-insensitive and supports common abbreviations.
+/// Symbols are case-sensitive, names are case-insensitive, and common
+/// abbreviations are supported.
 ///
 /// # Arguments
 ///
@@ -715,7 +970,7 @@ pub fn acres_to_square_meters(acres: f64) -> f64 {
 ///
 /// # Returns
 /// * `Ok(f64)` - The converted value
-/// * `Err(String)` - Error message if the conversion is not supported
+/// * `Err(ConversionError)` - The reason the conversion is not supported
 ///
 /// # Examples
 ///
@@ -728,53 +983,82 @@ pub fn acres_to_square_meters(acres: f64) -> f64 {
 /// let acres = convert_area(10000.0, "m²", "ac").unwrap();
 /// assert!((acres - 2.471).abs() < 0.001);
 /// ```
-pub fn convert_area(value: f64, from_unit: &str, to_unit: &str) -> Result<f64, String> {
-    let from_unit = from_unit.to_lowercase();
-    let to_unit = to_unit.to_lowercase();
-
-    // Convert input to square meters first
-    let square_meters = match from_unit.as_str() {
-        "m²" | "m2" | "sq_m" | "square_meters" => value,
-        "mm²" | "mm2" | "sq_mm" | "square_millimeters" => {
-            square_millimeters::to_square_meters(value)
-        }
-        "cm²" | "cm2" | "sq_cm" | "square_centimeters" => {
-            square_centimeters::to_square_meters(value)
-        }
-        "km²" | "km2" | "sq_km" | "square_kilometers" => {
-            square_kilometers::to_square_meters(value)
-        }
-        "in²" | "in2" | "sq_in" | "square_inches" => square_inches::to_square_meters(value),
-        "ft²" | "ft2" | "sq_ft" | "square_feet" => square_feet::to_square_meters(value),
-        "yd²" | "yd2" | "sq_yd" | "square_yards" => square_yards::to_square_meters(value),
-        "ac" | "acre" | "acres" => acres::to_square_meters(value),
-        "ha" | "hectare" | "hectares" => hectares::to_square_meters(value),
-        "mi²" | "mi2" | "sq_mi" | "square_miles" => square_miles::to_square_meters(value),
-        _ => return Err(format!("Unsupported area unit: {}", from_unit)),
-    };
-
-    // Convert square meters to target unit
-    let result = match to_unit.as_str() {
-        "m²" | "m2" | "sq_m" | "square_meters" => square_meters,
-        "mm²" | "mm2" | "sq_mm" | "square_millimeters" => {
-            square_meters::to_square_millimeters(square_meters)
-        }
-        "cm²" | "cm2" | "sq_cm" | "square_centimeters" => {
-            square_meters::to_square_centimeters(square_meters)
-        }
-        "km²" | "km2" | "sq_km" | "square_kilometers" => {
-            square_meters::to_square_kilometers(square_meters)
-        }
-        "in²" | "in2" | "sq_in" | "square_inches" => {
-            square_meters::to_square_inches(square_meters)
-        }
-        "ft²" | "ft2" | "sq_ft" | "square_feet" => square_meters::to_square_feet(square_meters),
-        "yd²" | "yd2" | "sq_yd" | "square_yards" => square_meters::to_square_yards(square_meters),
-        "ac" | "acre" | "acres" => square_meters::to_acres(square_meters),
-        "ha" | "hectare" | "hectares" => square_meters::to_hectares(square_meters),
-        "mi²" | "mi2" | "sq_mi" | "square_miles" => square_meters::to_square_miles(square_meters),
-        _ => return Err(format!("Unsupported area unit: {}", to_unit)),
-    };
-
-    Ok(result)
+pub fn convert_area(value: f64, from_unit: &str, to_unit: &str) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from_unit, to_unit, Category::Area)
+}
+
+/// Area units known to the [`UnitRegistry`], with square meters as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new(
+            "m²",
+            "square meter",
+            &["square_meters", "m2", "sq_m"],
+            Category::Area,
+            1.0,
+        ),
+        UnitDef::new(
+            "mm²",
+            "square millimeter",
+            &["square_millimeters", "mm2", "sq_mm"],
+            Category::Area,
+            square_millimeters::to_square_meters(1.0),
+        ),
+        UnitDef::new(
+            "cm²",
+            "square centimeter",
+            &["square_centimeters", "cm2", "sq_cm"],
+            Category::Area,
+            square_centimeters::to_square_meters(1.0),
+        ),
+        UnitDef::new(
+            "km²",
+            "square kilometer",
+            &["square_kilometers", "km2", "sq_km"],
+            Category::Area,
+            square_kilometers::to_square_meters(1.0),
+        ),
+        UnitDef::new(
+            "in²",
+            "square inch",
+            &["square_inches", "in2", "sq_in"],
+            Category::Area,
+            square_inches::to_square_meters(1.0),
+        ),
+        UnitDef::new(
+            "ft²",
+            "square foot",
+            &["square_feet", "ft2", "sq_ft"],
+            Category::Area,
+            square_feet::to_square_meters(1.0),
+        ),
+        UnitDef::new(
+            "yd²",
+            "square yard",
+            &["square_yards", "yd2", "sq_yd"],
+            Category::Area,
+            square_yards::to_square_meters(1.0),
+        ),
+        UnitDef::new(
+            "ac",
+            "acre",
+            &["acres"],
+            Category::Area,
+            acres::to_square_meters(1.0),
+        ),
+        UnitDef::new(
+            "ha",
+            "hectare",
+            &["hectares"],
+            Category::Area,
+            hectares::to_square_meters(1.0),
+        ),
+        UnitDef::new(
+            "mi²",
+            "square mile",
+            &["square_miles", "mi2", "sq_mi"],
+            Category::Area,
+            square_miles::to_square_meters(1.0),
+        ),
+    ]
 }
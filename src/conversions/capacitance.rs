@@ -0,0 +1,244 @@
+//! Capacitance conversion functions organized by unit type
+//! All conversions use farads as the base unit for accuracy and consistency
+
+use super::category::Category;
+use super::error::ConversionError;
+use super::registry::{UnitDef, UnitRegistry};
+
+/// Farads conversion functions
+pub mod farads {
+    use crate::conversions::quantity::{Capacitance, Quantity, Unit};
+
+    /// Converts farads to microfarads.
+    /// # Arguments
+    /// * `value` - The capacitance in farads to convert
+    pub fn to_microfarads(value: f64) -> f64 {
+        value * 1_000_000.0
+    }
+
+    /// Converts farads to nanofarads.
+    /// # Arguments
+    /// * `value` - The capacitance in farads to convert
+    pub fn to_nanofarads(value: f64) -> f64 {
+        value * 1_000_000_000.0
+    }
+
+    /// Converts farads to picofarads.
+    /// # Arguments
+    /// * `value` - The capacitance in farads to convert
+    pub fn to_picofarads(value: f64) -> f64 {
+        value * 1_000_000_000_000.0
+    }
+
+    /// The farad as a typed unit of capacitance.
+    pub const UNIT: Unit<Capacitance> = Unit::new("F", "farad", |value| value, |value| value);
+
+    /// Creates a typed capacitance quantity expressed in farads.
+    /// # Arguments
+    /// * `value` - The capacitance in farads
+    pub fn new(value: f64) -> Quantity<Capacitance> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed capacitance quantity in farads.
+    /// # Arguments
+    /// * `quantity` - The capacitance quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Capacitance>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Microfarads conversion functions
+pub mod microfarads {
+    use crate::conversions::quantity::{Capacitance, Quantity, Unit};
+
+    /// Converts microfarads to farads.
+    /// # Arguments
+    /// * `value` - The capacitance in microfarads to convert
+    pub fn to_farads(value: f64) -> f64 {
+        value / 1_000_000.0
+    }
+
+    /// Converts microfarads to nanofarads.
+    /// # Arguments
+    /// * `value` - The capacitance in microfarads to convert
+    pub fn to_nanofarads(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// Converts microfarads to picofarads.
+    /// # Arguments
+    /// * `value` - The capacitance in microfarads to convert
+    pub fn to_picofarads(value: f64) -> f64 {
+        value * 1_000_000.0
+    }
+
+    /// The microfarad as a typed unit of capacitance.
+    pub const UNIT: Unit<Capacitance> =
+        Unit::new("μF", "microfarad", to_farads, super::farads::to_microfarads);
+
+    /// Creates a typed capacitance quantity expressed in microfarads.
+    /// # Arguments
+    /// * `value` - The capacitance in microfarads
+    pub fn new(value: f64) -> Quantity<Capacitance> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed capacitance quantity in microfarads.
+    /// # Arguments
+    /// * `quantity` - The capacitance quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Capacitance>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Nanofarads conversion functions
+pub mod nanofarads {
+    use crate::conversions::quantity::{Capacitance, Quantity, Unit};
+
+    /// Converts nanofarads to farads.
+    /// # Arguments
+    /// * `value` - The capacitance in nanofarads to convert
+    pub fn to_farads(value: f64) -> f64 {
+        value / 1_000_000_000.0
+    }
+
+    /// Converts nanofarads to microfarads.
+    /// # Arguments
+    /// * `value` - The capacitance in nanofarads to convert
+    pub fn to_microfarads(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// Converts nanofarads to picofarads.
+    /// # Arguments
+    /// * `value` - The capacitance in nanofarads to convert
+    pub fn to_picofarads(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// The nanofarad as a typed unit of capacitance.
+    pub const UNIT: Unit<Capacitance> =
+        Unit::new("nF", "nanofarad", to_farads, super::farads::to_nanofarads);
+
+    /// Creates a typed capacitance quantity expressed in nanofarads.
+    /// # Arguments
+    /// * `value` - The capacitance in nanofarads
+    pub fn new(value: f64) -> Quantity<Capacitance> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed capacitance quantity in nanofarads.
+    /// # Arguments
+    /// * `quantity` - The capacitance quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Capacitance>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Picofarads conversion functions
+pub mod picofarads {
+    use crate::conversions::quantity::{Capacitance, Quantity, Unit};
+
+    /// Converts picofarads to farads.
+    /// # Arguments
+    /// * `value` - The capacitance in picofarads to convert
+    pub fn to_farads(value: f64) -> f64 {
+        value / 1_000_000_000_000.0
+    }
+
+    /// Converts picofarads to microfarads.
+    /// # Arguments
+    /// * `value` - The capacitance in picofarads to convert
+    pub fn to_microfarads(value: f64) -> f64 {
+        value / 1_000_000.0
+    }
+
+    /// Converts picofarads to nanofarads.
+    /// # Arguments
+    /// * `value` - The capacitance in picofarads to convert
+    pub fn to_nanofarads(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// The picofarad as a typed unit of capacitance.
+    pub const UNIT: Unit<Capacitance> =
+        Unit::new("pF", "picofarad", to_farads, super::farads::to_picofarads);
+
+    /// Creates a typed capacitance quantity expressed in picofarads.
+    /// # Arguments
+    /// * `value` - The capacitance in picofarads
+    pub fn new(value: f64) -> Quantity<Capacitance> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed capacitance quantity in picofarads.
+    /// # Arguments
+    /// * `quantity` - The capacitance quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Capacitance>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// General capacitance conversion function that accepts string unit names
+///
+/// Converts a capacitance from one unit to another using string identifiers.
+/// Symbols are case-sensitive, names are case-insensitive, and `uF` may be
+/// used for `μF`.
+///
+/// # Arguments
+///
+/// * `value` - The numeric value to convert
+/// * `from_unit` - The source unit (e.g., "F", "μF", "uF", "nF", "pF")
+/// * `to_unit` - The target unit using the same abbreviations
+///
+/// # Returns
+/// * `Ok(f64)` - The converted value
+/// * `Err(ConversionError)` - The reason the conversion is not supported
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::convert_capacitance;
+///
+/// let nanofarads = convert_capacitance(0.1, "uF", "nF").unwrap();
+/// assert!((nanofarads - 100.0).abs() < 1e-9);
+///
+/// let picofarads = convert_capacitance(4.7, "nF", "pF").unwrap();
+/// assert!((picofarads - 4700.0).abs() < 1e-9);
+/// ```
+pub fn convert_capacitance(
+    value: f64,
+    from_unit: &str,
+    to_unit: &str,
+) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from_unit, to_unit, Category::Capacitance)
+}
+
+/// Capacitance units known to the [`UnitRegistry`], with farads as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new("F", "farad", &["farads"], Category::Capacitance, 1.0).with_prefixes(),
+        UnitDef::new(
+            "μF",
+            "microfarad",
+            &["uF", "microfarads"],
+            Category::Capacitance,
+            microfarads::to_farads(1.0),
+        ),
+        UnitDef::new(
+            "nF",
+            "nanofarad",
+            &["nanofarads"],
+            Category::Capacitance,
+            nanofarads::to_farads(1.0),
+        ),
+        UnitDef::new(
+            "pF",
+            "picofarad",
+            &["picofarads"],
+            Category::Capacitance,
+            picofarads::to_farads(1.0),
+        ),
+    ]
+}
@@ -0,0 +1,212 @@
+//! Conversion categories
+//! Every unit belongs to exactly one category, and conversions are only
+//! possible between units of the same category
+
+use std::fmt;
+
+/// The kind of physical quantity a unit measures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Category {
+    /// Length and distance (base unit: meter)
+    Length,
+    /// Weight and mass (base unit: kilogram)
+    Mass,
+    /// Temperature (base unit: kelvin)
+    Temperature,
+    /// Volume (base unit: liter)
+    Volume,
+    /// Time (base unit: second)
+    Time,
+    /// Electric current (base unit: ampere)
+    Current,
+    /// Amount of substance (base unit: mole)
+    Amount,
+    /// Luminous intensity (base unit: candela)
+    LuminousIntensity,
+    /// Area (base unit: square meter)
+    Area,
+    /// Speed (base unit: meter per second)
+    Speed,
+    /// Pressure (base unit: pascal)
+    Pressure,
+    /// Energy (base unit: joule)
+    Energy,
+    /// Power (base unit: watt)
+    Power,
+    /// Force (base unit: newton)
+    Force,
+    /// Torque (base unit: newton-meter), kept apart from energy
+    Torque,
+    /// Frequency (base unit: hertz)
+    Frequency,
+    /// Plane angle (base unit: degree)
+    Angle,
+    /// Solid angle (base unit: steradian)
+    SolidAngle,
+    /// Digital storage (base unit: bit)
+    Data,
+    /// Data rate (base unit: bit per second)
+    DataRate,
+    /// Density (base unit: kilogram per cubic meter)
+    Density,
+    /// Volumetric flow rate (base unit: liter per second)
+    FlowRate,
+    /// Mass flow rate (base unit: kilogram per second)
+    MassFlowRate,
+    /// Voltage or electric potential (base unit: volt)
+    Voltage,
+    /// Electrical resistance (base unit: ohm)
+    Resistance,
+    /// Electrical conductance (base unit: siemens)
+    Conductance,
+    /// Electric charge (base unit: coulomb)
+    Charge,
+    /// Capacitance (base unit: farad)
+    Capacitance,
+    /// Inductance (base unit: henry)
+    Inductance,
+    /// Luminous flux (base unit: lumen)
+    LuminousFlux,
+    /// Illuminance (base unit: lux)
+    Illuminance,
+    /// Luminance (base unit: candela per square meter)
+    Luminance,
+    /// Temperature difference (base unit: kelvin)
+    TemperatureInterval,
+}
+
+impl Category {
+    /// Every supported category, in menu order.
+    pub const ALL: [Category; 33] = [
+        Category::Length,
+        Category::Mass,
+        Category::Temperature,
+        Category::Volume,
+        Category::Time,
+        Category::Current,
+        Category::Amount,
+        Category::LuminousIntensity,
+        Category::Area,
+        Category::Speed,
+        Category::Pressure,
+        Category::Energy,
+        Category::Power,
+        Category::Force,
+        Category::Torque,
+        Category::Frequency,
+        Category::Angle,
+        Category::SolidAngle,
+        Category::Data,
+        Category::DataRate,
+        Category::Density,
+        Category::FlowRate,
+        Category::MassFlowRate,
+        Category::Voltage,
+        Category::Resistance,
+        Category::Conductance,
+        Category::Charge,
+        Category::Capacitance,
+        Category::Inductance,
+        Category::LuminousFlux,
+        Category::Illuminance,
+        Category::Luminance,
+        Category::TemperatureInterval,
+    ];
+
+    /// Returns the human-readable name of the category (e.g. `"length"`).
+    pub fn name(&self) -> &'static str {
+        match self {
+            Category::Length => "length",
+            Category::Mass => "weight",
+            Category::Temperature => "temperature",
+            Category::Volume => "volume",
+            Category::Time => "time",
+            Category::Current => "current",
+            Category::Amount => "amount",
+            Category::LuminousIntensity => "luminous intensity",
+            Category::Area => "area",
+            Category::Speed => "speed",
+            Category::Pressure => "pressure",
+            Category::Energy => "energy",
+            Category::Power => "power",
+            Category::Force => "force",
+            Category::Torque => "torque",
+            Category::Frequency => "frequency",
+            Category::Angle => "angle",
+            Category::SolidAngle => "solid angle",
+            Category::Data => "data",
+            Category::DataRate => "data rate",
+            Category::Density => "density",
+            Category::FlowRate => "flow rate",
+            Category::MassFlowRate => "mass flow rate",
+            Category::Voltage => "voltage",
+            Category::Resistance => "resistance",
+            Category::Conductance => "conductance",
+            Category::Charge => "charge",
+            Category::Capacitance => "capacitance",
+            Category::Inductance => "inductance",
+            Category::LuminousFlux => "luminous flux",
+            Category::Illuminance => "illuminance",
+            Category::Luminance => "luminance",
+            Category::TemperatureInterval => "temperature interval",
+        }
+    }
+
+    /// Looks up a category by name (case-insensitive).
+    ///
+    /// Accepts the names returned by [`Category::name`] as well as the
+    /// identifiers used by the CLI and WASM bindings (e.g. `"mass"`,
+    /// `"substance"`, `"luminous_intensity"`).
+    /// # Arguments
+    /// * `name` - The category name
+    pub fn from_name(name: &str) -> Option<Category> {
+        match name.to_lowercase().as_str() {
+            "length" => Some(Category::Length),
+            "weight" | "mass" => Some(Category::Mass),
+            "temperature" => Some(Category::Temperature),
+            "volume" => Some(Category::Volume),
+            "time" => Some(Category::Time),
+            "current" => Some(Category::Current),
+            "amount" | "substance" => Some(Category::Amount),
+            "luminous intensity" | "luminous_intensity" | "luminosity" => {
+                Some(Category::LuminousIntensity)
+            }
+            "area" => Some(Category::Area),
+            "speed" | "velocity" => Some(Category::Speed),
+            "pressure" => Some(Category::Pressure),
+            "energy" => Some(Category::Energy),
+            "power" => Some(Category::Power),
+            "force" => Some(Category::Force),
+            "torque" | "moment" => Some(Category::Torque),
+            "frequency" | "frequencies" => Some(Category::Frequency),
+            "angle" | "angles" | "plane angle" => Some(Category::Angle),
+            "solid angle" | "solid_angle" | "solid angles" => Some(Category::SolidAngle),
+            "data" | "storage" | "digital storage" => Some(Category::Data),
+            "data rate" | "data_rate" | "bandwidth" | "bitrate" => Some(Category::DataRate),
+            "density" | "densities" | "specific gravity" => Some(Category::Density),
+            "flow rate" | "flow_rate" | "volumetric flow rate" | "flow" => Some(Category::FlowRate),
+            "mass flow rate" | "mass_flow_rate" | "mass flow" => Some(Category::MassFlowRate),
+            "voltage" | "potential" | "electric potential" | "emf" => Some(Category::Voltage),
+            "resistance" | "electrical resistance" => Some(Category::Resistance),
+            "conductance" | "electrical conductance" => Some(Category::Conductance),
+            "charge" | "electric charge" | "electric_charge" => Some(Category::Charge),
+            "capacitance" => Some(Category::Capacitance),
+            "inductance" => Some(Category::Inductance),
+            "luminous flux" | "luminous_flux" | "flux" => Some(Category::LuminousFlux),
+            "illuminance" | "illumination" => Some(Category::Illuminance),
+            "luminance" | "brightness" => Some(Category::Luminance),
+            "temperature interval"
+            | "temperature_interval"
+            | "temperature difference"
+            | "temperature_difference"
+            | "delta temperature" => Some(Category::TemperatureInterval),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
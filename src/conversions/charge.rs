@@ -0,0 +1,282 @@
+//! Electric charge conversion functions organized by unit type
+//! All conversions use coulombs as the base unit; battery capacities in
+//! milliampere-hours and ampere-hours are composed from the `current` and
+//! `time` definitions rather than repeating them
+
+use super::category::Category;
+use super::error::ConversionError;
+use super::registry::{UnitDef, UnitRegistry};
+use super::{current, time};
+
+/// The elementary charge in coulombs, exact since the 2019 SI redefinition
+pub const ELEMENTARY_CHARGE: f64 = 1.602_176_634e-19;
+
+/// Coulombs in one ampere-hour
+fn ampere_hours() -> f64 {
+    time::hours::to_seconds(1.0)
+}
+
+/// Coulombs in one milliampere-hour
+fn milliampere_hours() -> f64 {
+    current::milliamperes::to_amperes(1.0) * time::hours::to_seconds(1.0)
+}
+
+/// Coulombs conversion functions
+pub mod coulombs {
+    use crate::conversions::quantity::{Charge, Quantity, Unit};
+
+    /// Converts coulombs to milliampere-hours.
+    /// # Arguments
+    /// * `value` - The charge in coulombs to convert
+    pub fn to_milliampere_hours(value: f64) -> f64 {
+        value / super::milliampere_hours()
+    }
+
+    /// Converts coulombs to ampere-hours.
+    /// # Arguments
+    /// * `value` - The charge in coulombs to convert
+    pub fn to_ampere_hours(value: f64) -> f64 {
+        value / super::ampere_hours()
+    }
+
+    /// Converts coulombs to elementary charges.
+    /// # Arguments
+    /// * `value` - The charge in coulombs to convert
+    pub fn to_elementary_charges(value: f64) -> f64 {
+        value / super::ELEMENTARY_CHARGE
+    }
+
+    /// The coulomb as a typed unit of charge.
+    pub const UNIT: Unit<Charge> = Unit::new("C", "coulomb", |value| value, |value| value);
+
+    /// Creates a typed charge quantity expressed in coulombs.
+    /// # Arguments
+    /// * `value` - The charge in coulombs
+    pub fn new(value: f64) -> Quantity<Charge> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed charge quantity in coulombs.
+    /// # Arguments
+    /// * `quantity` - The charge quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Charge>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Milliampere-hours conversion functions
+pub mod milliampere_hours {
+    use crate::conversions::quantity::{Charge, Quantity, Unit};
+
+    /// Converts milliampere-hours to coulombs.
+    /// # Arguments
+    /// * `value` - The charge in milliampere-hours to convert
+    pub fn to_coulombs(value: f64) -> f64 {
+        value * super::milliampere_hours()
+    }
+
+    /// Converts milliampere-hours to ampere-hours.
+    /// # Arguments
+    /// * `value` - The charge in milliampere-hours to convert
+    pub fn to_ampere_hours(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// Converts milliampere-hours to elementary charges.
+    /// # Arguments
+    /// * `value` - The charge in milliampere-hours to convert
+    pub fn to_elementary_charges(value: f64) -> f64 {
+        super::coulombs::to_elementary_charges(to_coulombs(value))
+    }
+
+    /// The milliampere-hour as a typed unit of charge.
+    pub const UNIT: Unit<Charge> = Unit::new(
+        "mAh",
+        "milliampere-hour",
+        to_coulombs,
+        super::coulombs::to_milliampere_hours,
+    );
+
+    /// Creates a typed charge quantity expressed in milliampere-hours.
+    /// # Arguments
+    /// * `value` - The charge in milliampere-hours
+    pub fn new(value: f64) -> Quantity<Charge> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed charge quantity in milliampere-hours.
+    /// # Arguments
+    /// * `quantity` - The charge quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Charge>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Ampere-hours conversion functions
+pub mod ampere_hours {
+    use crate::conversions::quantity::{Charge, Quantity, Unit};
+
+    /// Converts ampere-hours to coulombs.
+    /// # Arguments
+    /// * `value` - The charge in ampere-hours to convert
+    pub fn to_coulombs(value: f64) -> f64 {
+        value * super::ampere_hours()
+    }
+
+    /// Converts ampere-hours to milliampere-hours.
+    /// # Arguments
+    /// * `value` - The charge in ampere-hours to convert
+    pub fn to_milliampere_hours(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// Converts ampere-hours to elementary charges.
+    /// # Arguments
+    /// * `value` - The charge in ampere-hours to convert
+    pub fn to_elementary_charges(value: f64) -> f64 {
+        super::coulombs::to_elementary_charges(to_coulombs(value))
+    }
+
+    /// The ampere-hour as a typed unit of charge.
+    pub const UNIT: Unit<Charge> = Unit::new(
+        "Ah",
+        "ampere-hour",
+        to_coulombs,
+        super::coulombs::to_ampere_hours,
+    );
+
+    /// Creates a typed charge quantity expressed in ampere-hours.
+    /// # Arguments
+    /// * `value` - The charge in ampere-hours
+    pub fn new(value: f64) -> Quantity<Charge> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed charge quantity in ampere-hours.
+    /// # Arguments
+    /// * `quantity` - The charge quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Charge>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Elementary charges conversion functions
+pub mod elementary_charges {
+    use crate::conversions::quantity::{Charge, Quantity, Unit};
+
+    /// Converts elementary charges to coulombs.
+    /// # Arguments
+    /// * `value` - The charge in elementary charges to convert
+    pub fn to_coulombs(value: f64) -> f64 {
+        value * super::ELEMENTARY_CHARGE
+    }
+
+    /// Converts elementary charges to milliampere-hours.
+    /// # Arguments
+    /// * `value` - The charge in elementary charges to convert
+    pub fn to_milliampere_hours(value: f64) -> f64 {
+        super::coulombs::to_milliampere_hours(to_coulombs(value))
+    }
+
+    /// Converts elementary charges to ampere-hours.
+    /// # Arguments
+    /// * `value` - The charge in elementary charges to convert
+    pub fn to_ampere_hours(value: f64) -> f64 {
+        super::coulombs::to_ampere_hours(to_coulombs(value))
+    }
+
+    /// The elementary charge as a typed unit of charge.
+    pub const UNIT: Unit<Charge> = Unit::new(
+        "e",
+        "elementary charge",
+        to_coulombs,
+        super::coulombs::to_elementary_charges,
+    );
+
+    /// Creates a typed charge quantity expressed in elementary charges.
+    /// # Arguments
+    /// * `value` - The charge in elementary charges
+    pub fn new(value: f64) -> Quantity<Charge> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed charge quantity in elementary charges.
+    /// # Arguments
+    /// * `quantity` - The charge quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Charge>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// General electric charge conversion function that accepts string unit names
+///
+/// Converts an electric charge from one unit to another using string
+/// identifiers. Symbols are case-sensitive, names are case-insensitive, and
+/// common abbreviations are supported.
+///
+/// # Arguments
+///
+/// * `value` - The numeric value to convert
+/// * `from_unit` - The source unit (e.g., "C", "mC", "mAh", "Ah", "e")
+/// * `to_unit` - The target unit using the same abbreviations
+///
+/// # Returns
+/// * `Ok(f64)` - The converted value
+/// * `Err(ConversionError)` - The reason the conversion is not supported
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::convert_charge;
+///
+/// // A 3000 mAh phone battery holds 10.8 kC
+/// let coulombs = convert_charge(3000.0, "mAh", "C").unwrap();
+/// assert!((coulombs - 10_800.0).abs() < 1e-9);
+///
+/// let electrons = convert_charge(1.0, "C", "e").unwrap();
+/// assert!((electrons - 6.241_509e18).abs() < 1e12);
+/// ```
+pub fn convert_charge(value: f64, from_unit: &str, to_unit: &str) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from_unit, to_unit, Category::Charge)
+}
+
+/// Electric charge units known to the [`UnitRegistry`], with coulombs as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new("C", "coulomb", &["coulombs"], Category::Charge, 1.0).with_prefixes(),
+        UnitDef::new(
+            "mAh",
+            "milliampere-hour",
+            &[
+                "mA·h",
+                "milliamp hours",
+                "milliampere hours",
+                "milliampere-hours",
+                "milliampere_hours",
+            ],
+            Category::Charge,
+            milliampere_hours::to_coulombs(1.0),
+        )
+        .with_exact_factor("3.6"),
+        UnitDef::new(
+            "Ah",
+            "ampere-hour",
+            &[
+                "A·h",
+                "amp hours",
+                "ampere hours",
+                "ampere-hours",
+                "ampere_hours",
+            ],
+            Category::Charge,
+            ampere_hours::to_coulombs(1.0),
+        ),
+        UnitDef::new(
+            "e",
+            "elementary charge",
+            &["elementary charges", "elementary_charges"],
+            Category::Charge,
+            elementary_charges::to_coulombs(1.0),
+        ),
+    ]
+}
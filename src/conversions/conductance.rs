@@ -0,0 +1,188 @@
+//! Electrical conductance conversion functions organized by unit type
+//! All conversions use siemens as the base unit; the older mho (`℧`) is
+//! accepted as another name for the siemens
+
+use super::category::Category;
+use super::error::ConversionError;
+use super::registry::{UnitDef, UnitRegistry};
+
+/// Siemens conversion functions
+pub mod siemens {
+    use crate::conversions::quantity::{Conductance, Quantity, Unit};
+
+    /// Converts siemens to millisiemens.
+    /// # Arguments
+    /// * `value` - The conductance in siemens to convert
+    pub fn to_millisiemens(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// Converts siemens to microsiemens.
+    /// # Arguments
+    /// * `value` - The conductance in siemens to convert
+    pub fn to_microsiemens(value: f64) -> f64 {
+        value * 1_000_000.0
+    }
+
+    /// The siemens as a typed unit of conductance.
+    pub const UNIT: Unit<Conductance> = Unit::new("S", "siemens", |value| value, |value| value);
+
+    /// Creates a typed conductance quantity expressed in siemens.
+    /// # Arguments
+    /// * `value` - The conductance in siemens
+    pub fn new(value: f64) -> Quantity<Conductance> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed conductance quantity in siemens.
+    /// # Arguments
+    /// * `quantity` - The conductance quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Conductance>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Millisiemens conversion functions
+pub mod millisiemens {
+    use crate::conversions::quantity::{Conductance, Quantity, Unit};
+
+    /// Converts millisiemens to siemens.
+    /// # Arguments
+    /// * `value` - The conductance in millisiemens to convert
+    pub fn to_siemens(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// Converts millisiemens to microsiemens.
+    /// # Arguments
+    /// * `value` - The conductance in millisiemens to convert
+    pub fn to_microsiemens(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// The millisiemens as a typed unit of conductance.
+    pub const UNIT: Unit<Conductance> = Unit::new(
+        "mS",
+        "millisiemens",
+        to_siemens,
+        super::siemens::to_millisiemens,
+    );
+
+    /// Creates a typed conductance quantity expressed in millisiemens.
+    /// # Arguments
+    /// * `value` - The conductance in millisiemens
+    pub fn new(value: f64) -> Quantity<Conductance> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed conductance quantity in millisiemens.
+    /// # Arguments
+    /// * `quantity` - The conductance quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Conductance>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Microsiemens conversion functions
+pub mod microsiemens {
+    use crate::conversions::quantity::{Conductance, Quantity, Unit};
+
+    /// Converts microsiemens to siemens.
+    /// # Arguments
+    /// * `value` - The conductance in microsiemens to convert
+    pub fn to_siemens(value: f64) -> f64 {
+        value / 1_000_000.0
+    }
+
+    /// Converts microsiemens to millisiemens.
+    /// # Arguments
+    /// * `value` - The conductance in microsiemens to convert
+    pub fn to_millisiemens(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// The microsiemens as a typed unit of conductance.
+    pub const UNIT: Unit<Conductance> = Unit::new(
+        "μS",
+        "microsiemens",
+        to_siemens,
+        super::siemens::to_microsiemens,
+    );
+
+    /// Creates a typed conductance quantity expressed in microsiemens.
+    /// # Arguments
+    /// * `value` - The conductance in microsiemens
+    pub fn new(value: f64) -> Quantity<Conductance> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed conductance quantity in microsiemens.
+    /// # Arguments
+    /// * `quantity` - The conductance quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Conductance>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// General conductance conversion function that accepts string unit names
+///
+/// Converts an electrical conductance from one unit to another using string
+/// identifiers. Symbols are case-sensitive, names are case-insensitive, and
+/// `mho` is read as the siemens it equals.
+///
+/// # Arguments
+///
+/// * `value` - The numeric value to convert
+/// * `from_unit` - The source unit (e.g., "S", "mS", "μS", "mho")
+/// * `to_unit` - The target unit using the same abbreviations
+///
+/// # Returns
+/// * `Ok(f64)` - The converted value
+/// * `Err(ConversionError)` - The reason the conversion is not supported
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::convert_conductance;
+///
+/// let microsiemens = convert_conductance(1.5, "mS", "μS").unwrap();
+/// assert!((microsiemens - 1500.0).abs() < 1e-9);
+///
+/// let siemens = convert_conductance(3.0, "mho", "S").unwrap();
+/// assert_eq!(siemens, 3.0);
+/// ```
+pub fn convert_conductance(
+    value: f64,
+    from_unit: &str,
+    to_unit: &str,
+) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from_unit, to_unit, Category::Conductance)
+}
+
+/// Conductance units known to the [`UnitRegistry`], with siemens as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new(
+            "S",
+            "siemens",
+            &["mho", "mhos", "℧"],
+            Category::Conductance,
+            1.0,
+        )
+        .with_prefixes(),
+        UnitDef::new(
+            "mS",
+            "millisiemens",
+            &["millimho", "millimhos"],
+            Category::Conductance,
+            millisiemens::to_siemens(1.0),
+        ),
+        UnitDef::new(
+            "μS",
+            "microsiemens",
+            &["uS", "micromho", "micromhos"],
+            Category::Conductance,
+            microsiemens::to_siemens(1.0),
+        ),
+    ]
+}
@@ -1,8 +1,14 @@
 //! Electric current conversion functions organized by unit type
 //! All conversions use amperes as the base unit for accuracy and consistency
 
+use super::category::Category;
+use super::error::ConversionError;
+use super::registry::{UnitDef, UnitRegistry};
+
 /// Amperes conversion functions
 pub mod amperes {
+    use crate::conversions::quantity::{Current, Quantity, Unit};
+
     /// Converts amperes to milliamperes.
     /// # Arguments
     /// * `value` - The current in amperes to convert
@@ -30,10 +36,36 @@ pub mod amperes {
     pub fn to_kiloamperes(value: f64) -> f64 {
         value / 1000.0
     }
+
+    /// Converts amperes to megaamperes.
+    /// # Arguments
+    /// * `value` - The current in amperes to convert
+    pub fn to_megaamperes(value: f64) -> f64 {
+        value / 1_000_000.0
+    }
+
+    /// The ampere as a typed unit of current.
+    pub const UNIT: Unit<Current> = Unit::new("A", "ampere", |value| value, |value| value);
+
+    /// Creates a typed current quantity expressed in amperes.
+    /// # Arguments
+    /// * `value` - The current in amperes
+    pub fn new(value: f64) -> Quantity<Current> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed current quantity in amperes.
+    /// # Arguments
+    /// * `quantity` - The current quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Current>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Milliamperes conversion functions
 pub mod milliamperes {
+    use crate::conversions::quantity::{Current, Quantity, Unit};
+
     /// Converts milliamperes to amperes.
     /// # Arguments
     /// * `value` - The current in milliamperes to convert
@@ -61,10 +93,34 @@ pub mod milliamperes {
     pub fn to_kiloamperes(value: f64) -> f64 {
         super::amperes::to_kiloamperes(to_amperes(value))
     }
+
+    /// The milliampere as a typed unit of current.
+    pub const UNIT: Unit<Current> = Unit::new(
+        "mA",
+        "milliampere",
+        to_amperes,
+        super::amperes::to_milliamperes,
+    );
+
+    /// Creates a typed current quantity expressed in milliamperes.
+    /// # Arguments
+    /// * `value` - The current in milliamperes
+    pub fn new(value: f64) -> Quantity<Current> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed current quantity in milliamperes.
+    /// # Arguments
+    /// * `quantity` - The current quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Current>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Microamperes conversion functions
 pub mod microamperes {
+    use crate::conversions::quantity::{Current, Quantity, Unit};
+
     /// Converts microamperes to amperes.
     /// # Arguments
     /// * `value` - The current in microamperes to convert
@@ -92,10 +148,34 @@ pub mod microamperes {
     pub fn to_kiloamperes(value: f64) -> f64 {
         super::amperes::to_kiloamperes(to_amperes(value))
     }
+
+    /// The microampere as a typed unit of current.
+    pub const UNIT: Unit<Current> = Unit::new(
+        "μA",
+        "microampere",
+        to_amperes,
+        super::amperes::to_microamperes,
+    );
+
+    /// Creates a typed current quantity expressed in microamperes.
+    /// # Arguments
+    /// * `value` - The current in microamperes
+    pub fn new(value: f64) -> Quantity<Current> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed current quantity in microamperes.
+    /// # Arguments
+    /// * `quantity` - The current quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Current>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Nanoamperes conversion functions
 pub mod nanoamperes {
+    use crate::conversions::quantity::{Current, Quantity, Unit};
+
     /// Converts nanoamperes to amperes.
     /// # Arguments
     /// * `value` - The current in nanoamperes to convert
@@ -123,10 +203,34 @@ pub mod nanoamperes {
     pub fn to_kiloamperes(value: f64) -> f64 {
         super::amperes::to_kiloamperes(to_amperes(value))
     }
+
+    /// The nanoampere as a typed unit of current.
+    pub const UNIT: Unit<Current> = Unit::new(
+        "nA",
+        "nanoampere",
+        to_amperes,
+        super::amperes::to_nanoamperes,
+    );
+
+    /// Creates a typed current quantity expressed in nanoamperes.
+    /// # Arguments
+    /// * `value` - The current in nanoamperes
+    pub fn new(value: f64) -> Quantity<Current> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed current quantity in nanoamperes.
+    /// # Arguments
+    /// * `quantity` - The current quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Current>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Kiloamperes conversion functions
 pub mod kiloamperes {
+    use crate::conversions::quantity::{Current, Quantity, Unit};
+
     /// Converts kiloamperes to amperes.
     /// # Arguments
     /// * `value` - The current in kiloamperes to convert
@@ -154,6 +258,76 @@ pub mod kiloamperes {
     pub fn to_nanoamperes(value: f64) -> f64 {
         super::amperes::to_nanoamperes(to_amperes(value))
     }
+
+    /// The kiloampere as a typed unit of current.
+    pub const UNIT: Unit<Current> = Unit::new(
+        "kA",
+        "kiloampere",
+        to_amperes,
+        super::amperes::to_kiloamperes,
+    );
+
+    /// Creates a typed current quantity expressed in kiloamperes.
+    /// # Arguments
+    /// * `value` - The current in kiloamperes
+    pub fn new(value: f64) -> Quantity<Current> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed current quantity in kiloamperes.
+    /// # Arguments
+    /// * `quantity` - The current quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Current>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Megaamperes conversion functions
+pub mod megaamperes {
+    use crate::conversions::quantity::{Current, Quantity, Unit};
+
+    /// Converts megaamperes to amperes.
+    /// # Arguments
+    /// * `value` - The current in megaamperes to convert
+    pub fn to_amperes(value: f64) -> f64 {
+        value * 1_000_000.0
+    }
+
+    /// Converts megaamperes to milliamperes.
+    /// # Arguments
+    /// * `value` - The current in megaamperes to convert
+    pub fn to_milliamperes(value: f64) -> f64 {
+        super::amperes::to_milliamperes(to_amperes(value))
+    }
+
+    /// Converts megaamperes to kiloamperes.
+    /// # Arguments
+    /// * `value` - The current in megaamperes to convert
+    pub fn to_kiloamperes(value: f64) -> f64 {
+        super::amperes::to_kiloamperes(to_amperes(value))
+    }
+
+    /// The megaampere as a typed unit of current.
+    pub const UNIT: Unit<Current> = Unit::new(
+        "MA",
+        "megaampere",
+        to_amperes,
+        super::amperes::to_megaamperes,
+    );
+
+    /// Creates a typed current quantity expressed in megaamperes.
+    /// # Arguments
+    /// * `value` - The current in megaamperes
+    pub fn new(value: f64) -> Quantity<Current> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed current quantity in megaamperes.
+    /// # Arguments
+    /// * `quantity` - The current quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Current>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 // Legacy function wrappers for backward compatibility
@@ -184,17 +358,18 @@ pub fn kiloamperes_to_amperes(kiloamperes: f64) -> f64 {
 /// General electric current conversion function that accepts string unit names
 ///
 /// Converts an electric current value from one unit to another using string identifiers.
-/// This function is case-insensitive and supports common abbreviations.
+/// Symbols are case-sensitive, so `mA` (milliampere) and `MA` (megaampere)
+/// are different units; names such as `"Milliampere"` are case-insensitive.
 ///
 /// # Arguments
 ///
 /// * `value` - The numeric value to convert
-/// * `from_unit` - The source unit (e.g., "A", "mA", "μA", "nA", "kA")
+/// * `from_unit` - The source unit (e.g., "A", "mA", "μA", "nA", "kA", "MA")
 /// * `to_unit` - The target unit using the same abbreviations
 ///
 /// # Returns
 /// * `Ok(f64)` - The converted value
-/// * `Err(String)` - Error message if the conversion is not supported
+/// * `Err(ConversionError)` - The reason the conversion is not supported
 ///
 /// # Examples
 ///
@@ -206,34 +381,55 @@ pub fn kiloamperes_to_amperes(kiloamperes: f64) -> f64 {
 ///
 /// let amps = convert_current(500.0, "mA", "A").unwrap();
 /// assert_eq!(amps, 0.5);
+///
+/// let amps = convert_current(2.0, "MA", "A").unwrap();
+/// assert_eq!(amps, 2_000_000.0);
+///
+/// // "ma" could be either, so it is rejected rather than guessed
+/// assert!(convert_current(1.0, "ma", "A").is_err());
 /// ```
-pub fn convert_current(value: f64, from_unit: &str, to_unit: &str) -> Result<f64, String> {
-    let from_unit = from_unit.to_lowercase();
-    let to_unit = to_unit.to_lowercase();
-
-    // Convert input to amperes first
-    let amperes = match from_unit.as_str() {
-        "a" | "amp" | "ampere" | "amperes" => value,
-        "ma" | "milliamp" | "milliampere" | "milliamperes" => milliamperes::to_amperes(value),
-        "μa" | "ua" | "microamp" | "microampere" | "microamperes" => {
-            microamperes::to_amperes(value)
-        }
-        "na" | "nanoamp" | "nanoampere" | "nanoamperes" => nanoamperes::to_amperes(value),
-        "ka" | "kiloamp" | "kiloampere" | "kiloamperes" => kiloamperes::to_amperes(value),
-        _ => return Err(format!("Unsupported current unit: {}", from_unit)),
-    };
-
-    // Convert amperes to target unit
-    let result = match to_unit.as_str() {
-        "a" | "amp" | "ampere" | "amperes" => amperes,
-        "ma" | "milliamp" | "milliampere" | "milliamperes" => amperes::to_milliamperes(amperes),
-        "μa" | "ua" | "microamp" | "microampere" | "microamperes" => {
-            amperes::to_microamperes(amperes)
-        }
-        "na" | "nanoamp" | "nanoampere" | "nanoamperes" => amperes::to_nanoamperes(amperes),
-        "ka" | "kiloamp" | "kiloampere" | "kiloamperes" => amperes::to_kiloamperes(amperes),
-        _ => return Err(format!("Unsupported current unit: {}", to_unit)),
-    };
-
-    Ok(result)
+pub fn convert_current(value: f64, from_unit: &str, to_unit: &str) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from_unit, to_unit, Category::Current)
+}
+
+/// Electric current units known to the [`UnitRegistry`], with amperes as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new("A", "ampere", &["amp", "amperes"], Category::Current, 1.0).with_prefixes(),
+        UnitDef::new(
+            "mA",
+            "milliampere",
+            &["milliamp", "milliamperes"],
+            Category::Current,
+            milliamperes::to_amperes(1.0),
+        ),
+        UnitDef::new(
+            "μA",
+            "microampere",
+            &["ua", "microamp", "microamperes"],
+            Category::Current,
+            microamperes::to_amperes(1.0),
+        ),
+        UnitDef::new(
+            "nA",
+            "nanoampere",
+            &["nanoamp", "nanoamperes"],
+            Category::Current,
+            nanoamperes::to_amperes(1.0),
+        ),
+        UnitDef::new(
+            "kA",
+            "kiloampere",
+            &["kiloamp", "kiloamperes"],
+            Category::Current,
+            kiloamperes::to_amperes(1.0),
+        ),
+        UnitDef::new(
+            "MA",
+            "megaampere",
+            &["megaamp", "megaamperes"],
+            Category::Current,
+            megaamperes::to_amperes(1.0),
+        ),
+    ]
 }
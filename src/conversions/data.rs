@@ -0,0 +1,3718 @@
+//! Digital storage conversion functions organized by unit type
+//! All conversions use bits as the base unit, so every factor is a whole number
+//!
+//! Decimal (SI) prefixes are powers of 1000 (kB, MB, GB) and binary (IEC)
+//! prefixes are powers of 1024 (KiB, MiB, GiB). The two are never conflated:
+//! `"MB"` is always 1000² bytes, and spellings that could mean either, such as
+//! `"KB"`, are rejected as ambiguous.
+
+use super::category::Category;
+use super::error::ConversionError;
+use super::registry::{UnitDef, UnitRegistry};
+
+/// Bits in one byte (octet)
+pub const BITS_PER_BYTE: f64 = 8.0;
+
+/// The IEC binary prefix kibi (Ki), 1024
+pub const KIBI: f64 = 1024.0;
+
+/// The IEC binary prefix mebi (Mi), 1024²
+pub const MEBI: f64 = KIBI * KIBI;
+
+/// The IEC binary prefix gibi (Gi), 1024³
+pub const GIBI: f64 = MEBI * KIBI;
+
+/// The IEC binary prefix tebi (Ti), 1024⁴
+pub const TEBI: f64 = GIBI * KIBI;
+
+/// The IEC binary prefix pebi (Pi), 1024⁵
+pub const PEBI: f64 = TEBI * KIBI;
+
+/// The IEC binary prefix exbi (Ei), 1024⁶
+pub const EXBI: f64 = PEBI * KIBI;
+
+/// The IEC binary prefix zebi (Zi), 1024⁷
+pub const ZEBI: f64 = EXBI * KIBI;
+
+/// The IEC binary prefix yobi (Yi), 1024⁸
+pub const YOBI: f64 = ZEBI * KIBI;
+
+/// Bits conversion functions
+pub mod bits {
+    use crate::conversions::quantity::{Data, Quantity, Unit};
+
+    /// Converts bits to kilobits.
+    /// # Arguments
+    /// * `value` - The amount of data in bits to convert
+    pub fn to_kilobits(value: f64) -> f64 {
+        value / 1e3
+    }
+
+    /// Converts bits to megabits.
+    /// # Arguments
+    /// * `value` - The amount of data in bits to convert
+    pub fn to_megabits(value: f64) -> f64 {
+        value / 1e6
+    }
+
+    /// Converts bits to gigabits.
+    /// # Arguments
+    /// * `value` - The amount of data in bits to convert
+    pub fn to_gigabits(value: f64) -> f64 {
+        value / 1e9
+    }
+
+    /// Converts bits to bytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bits to convert
+    pub fn to_bytes(value: f64) -> f64 {
+        value / super::BITS_PER_BYTE
+    }
+
+    /// Converts bits to kilobytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bits to convert
+    pub fn to_kilobytes(value: f64) -> f64 {
+        value / 1e3 / super::BITS_PER_BYTE
+    }
+
+    /// Converts bits to megabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bits to convert
+    pub fn to_megabytes(value: f64) -> f64 {
+        value / 1e6 / super::BITS_PER_BYTE
+    }
+
+    /// Converts bits to gigabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bits to convert
+    pub fn to_gigabytes(value: f64) -> f64 {
+        value / 1e9 / super::BITS_PER_BYTE
+    }
+
+    /// Converts bits to terabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bits to convert
+    pub fn to_terabytes(value: f64) -> f64 {
+        value / 1e12 / super::BITS_PER_BYTE
+    }
+
+    /// Converts bits to petabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bits to convert
+    pub fn to_petabytes(value: f64) -> f64 {
+        value / 1e15 / super::BITS_PER_BYTE
+    }
+
+    /// Converts bits to exabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bits to convert
+    pub fn to_exabytes(value: f64) -> f64 {
+        value / 1e18 / super::BITS_PER_BYTE
+    }
+
+    /// Converts bits to zettabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bits to convert
+    pub fn to_zettabytes(value: f64) -> f64 {
+        value / 1e21 / super::BITS_PER_BYTE
+    }
+
+    /// Converts bits to yottabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bits to convert
+    pub fn to_yottabytes(value: f64) -> f64 {
+        value / 1e24 / super::BITS_PER_BYTE
+    }
+
+    /// Converts bits to kibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bits to convert
+    pub fn to_kibibytes(value: f64) -> f64 {
+        value / super::KIBI / super::BITS_PER_BYTE
+    }
+
+    /// Converts bits to mebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bits to convert
+    pub fn to_mebibytes(value: f64) -> f64 {
+        value / super::MEBI / super::BITS_PER_BYTE
+    }
+
+    /// Converts bits to gibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bits to convert
+    pub fn to_gibibytes(value: f64) -> f64 {
+        value / super::GIBI / super::BITS_PER_BYTE
+    }
+
+    /// Converts bits to tebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bits to convert
+    pub fn to_tebibytes(value: f64) -> f64 {
+        value / super::TEBI / super::BITS_PER_BYTE
+    }
+
+    /// Converts bits to pebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bits to convert
+    pub fn to_pebibytes(value: f64) -> f64 {
+        value / super::PEBI / super::BITS_PER_BYTE
+    }
+
+    /// Converts bits to exbibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bits to convert
+    pub fn to_exbibytes(value: f64) -> f64 {
+        value / super::EXBI / super::BITS_PER_BYTE
+    }
+
+    /// Converts bits to zebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bits to convert
+    pub fn to_zebibytes(value: f64) -> f64 {
+        value / super::ZEBI / super::BITS_PER_BYTE
+    }
+
+    /// Converts bits to yobibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bits to convert
+    pub fn to_yobibytes(value: f64) -> f64 {
+        value / super::YOBI / super::BITS_PER_BYTE
+    }
+
+    /// The bit as a typed unit of amount of data.
+    pub const UNIT: Unit<Data> = Unit::new("bit", "bit", |value| value, |value| value);
+
+    /// Creates a typed amount of data quantity expressed in bits.
+    /// # Arguments
+    /// * `value` - The amount of data in bits
+    pub fn new(value: f64) -> Quantity<Data> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed amount of data quantity in bits.
+    /// # Arguments
+    /// * `quantity` - The amount of data quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Data>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Kilobits conversion functions
+pub mod kilobits {
+    use crate::conversions::quantity::{Data, Quantity, Unit};
+
+    /// Converts kilobits to bits.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobits to convert
+    pub fn to_bits(value: f64) -> f64 {
+        value * 1e3
+    }
+
+    /// Converts kilobits to megabits.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobits to convert
+    pub fn to_megabits(value: f64) -> f64 {
+        super::bits::to_megabits(to_bits(value))
+    }
+
+    /// Converts kilobits to gigabits.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobits to convert
+    pub fn to_gigabits(value: f64) -> f64 {
+        super::bits::to_gigabits(to_bits(value))
+    }
+
+    /// Converts kilobits to bytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobits to convert
+    pub fn to_bytes(value: f64) -> f64 {
+        super::bits::to_bytes(to_bits(value))
+    }
+
+    /// Converts kilobits to kilobytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobits to convert
+    pub fn to_kilobytes(value: f64) -> f64 {
+        super::bits::to_kilobytes(to_bits(value))
+    }
+
+    /// Converts kilobits to megabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobits to convert
+    pub fn to_megabytes(value: f64) -> f64 {
+        super::bits::to_megabytes(to_bits(value))
+    }
+
+    /// Converts kilobits to gigabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobits to convert
+    pub fn to_gigabytes(value: f64) -> f64 {
+        super::bits::to_gigabytes(to_bits(value))
+    }
+
+    /// Converts kilobits to terabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobits to convert
+    pub fn to_terabytes(value: f64) -> f64 {
+        super::bits::to_terabytes(to_bits(value))
+    }
+
+    /// Converts kilobits to petabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobits to convert
+    pub fn to_petabytes(value: f64) -> f64 {
+        super::bits::to_petabytes(to_bits(value))
+    }
+
+    /// Converts kilobits to exabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobits to convert
+    pub fn to_exabytes(value: f64) -> f64 {
+        super::bits::to_exabytes(to_bits(value))
+    }
+
+    /// Converts kilobits to zettabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobits to convert
+    pub fn to_zettabytes(value: f64) -> f64 {
+        super::bits::to_zettabytes(to_bits(value))
+    }
+
+    /// Converts kilobits to yottabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobits to convert
+    pub fn to_yottabytes(value: f64) -> f64 {
+        super::bits::to_yottabytes(to_bits(value))
+    }
+
+    /// Converts kilobits to kibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobits to convert
+    pub fn to_kibibytes(value: f64) -> f64 {
+        super::bits::to_kibibytes(to_bits(value))
+    }
+
+    /// Converts kilobits to mebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobits to convert
+    pub fn to_mebibytes(value: f64) -> f64 {
+        super::bits::to_mebibytes(to_bits(value))
+    }
+
+    /// Converts kilobits to gibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobits to convert
+    pub fn to_gibibytes(value: f64) -> f64 {
+        super::bits::to_gibibytes(to_bits(value))
+    }
+
+    /// Converts kilobits to tebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobits to convert
+    pub fn to_tebibytes(value: f64) -> f64 {
+        super::bits::to_tebibytes(to_bits(value))
+    }
+
+    /// Converts kilobits to pebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobits to convert
+    pub fn to_pebibytes(value: f64) -> f64 {
+        super::bits::to_pebibytes(to_bits(value))
+    }
+
+    /// Converts kilobits to exbibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobits to convert
+    pub fn to_exbibytes(value: f64) -> f64 {
+        super::bits::to_exbibytes(to_bits(value))
+    }
+
+    /// Converts kilobits to zebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobits to convert
+    pub fn to_zebibytes(value: f64) -> f64 {
+        super::bits::to_zebibytes(to_bits(value))
+    }
+
+    /// Converts kilobits to yobibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobits to convert
+    pub fn to_yobibytes(value: f64) -> f64 {
+        super::bits::to_yobibytes(to_bits(value))
+    }
+
+    /// The kilobit as a typed unit of amount of data.
+    pub const UNIT: Unit<Data> = Unit::new("kbit", "kilobit", to_bits, super::bits::to_kilobits);
+
+    /// Creates a typed amount of data quantity expressed in kilobits.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobits
+    pub fn new(value: f64) -> Quantity<Data> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed amount of data quantity in kilobits.
+    /// # Arguments
+    /// * `quantity` - The amount of data quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Data>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Megabits conversion functions
+pub mod megabits {
+    use crate::conversions::quantity::{Data, Quantity, Unit};
+
+    /// Converts megabits to bits.
+    /// # Arguments
+    /// * `value` - The amount of data in megabits to convert
+    pub fn to_bits(value: f64) -> f64 {
+        value * 1e6
+    }
+
+    /// Converts megabits to kilobits.
+    /// # Arguments
+    /// * `value` - The amount of data in megabits to convert
+    pub fn to_kilobits(value: f64) -> f64 {
+        super::bits::to_kilobits(to_bits(value))
+    }
+
+    /// Converts megabits to gigabits.
+    /// # Arguments
+    /// * `value` - The amount of data in megabits to convert
+    pub fn to_gigabits(value: f64) -> f64 {
+        super::bits::to_gigabits(to_bits(value))
+    }
+
+    /// Converts megabits to bytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabits to convert
+    pub fn to_bytes(value: f64) -> f64 {
+        super::bits::to_bytes(to_bits(value))
+    }
+
+    /// Converts megabits to kilobytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabits to convert
+    pub fn to_kilobytes(value: f64) -> f64 {
+        super::bits::to_kilobytes(to_bits(value))
+    }
+
+    /// Converts megabits to megabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabits to convert
+    pub fn to_megabytes(value: f64) -> f64 {
+        super::bits::to_megabytes(to_bits(value))
+    }
+
+    /// Converts megabits to gigabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabits to convert
+    pub fn to_gigabytes(value: f64) -> f64 {
+        super::bits::to_gigabytes(to_bits(value))
+    }
+
+    /// Converts megabits to terabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabits to convert
+    pub fn to_terabytes(value: f64) -> f64 {
+        super::bits::to_terabytes(to_bits(value))
+    }
+
+    /// Converts megabits to petabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabits to convert
+    pub fn to_petabytes(value: f64) -> f64 {
+        super::bits::to_petabytes(to_bits(value))
+    }
+
+    /// Converts megabits to exabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabits to convert
+    pub fn to_exabytes(value: f64) -> f64 {
+        super::bits::to_exabytes(to_bits(value))
+    }
+
+    /// Converts megabits to zettabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabits to convert
+    pub fn to_zettabytes(value: f64) -> f64 {
+        super::bits::to_zettabytes(to_bits(value))
+    }
+
+    /// Converts megabits to yottabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabits to convert
+    pub fn to_yottabytes(value: f64) -> f64 {
+        super::bits::to_yottabytes(to_bits(value))
+    }
+
+    /// Converts megabits to kibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabits to convert
+    pub fn to_kibibytes(value: f64) -> f64 {
+        super::bits::to_kibibytes(to_bits(value))
+    }
+
+    /// Converts megabits to mebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabits to convert
+    pub fn to_mebibytes(value: f64) -> f64 {
+        super::bits::to_mebibytes(to_bits(value))
+    }
+
+    /// Converts megabits to gibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabits to convert
+    pub fn to_gibibytes(value: f64) -> f64 {
+        super::bits::to_gibibytes(to_bits(value))
+    }
+
+    /// Converts megabits to tebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabits to convert
+    pub fn to_tebibytes(value: f64) -> f64 {
+        super::bits::to_tebibytes(to_bits(value))
+    }
+
+    /// Converts megabits to pebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabits to convert
+    pub fn to_pebibytes(value: f64) -> f64 {
+        super::bits::to_pebibytes(to_bits(value))
+    }
+
+    /// Converts megabits to exbibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabits to convert
+    pub fn to_exbibytes(value: f64) -> f64 {
+        super::bits::to_exbibytes(to_bits(value))
+    }
+
+    /// Converts megabits to zebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabits to convert
+    pub fn to_zebibytes(value: f64) -> f64 {
+        super::bits::to_zebibytes(to_bits(value))
+    }
+
+    /// Converts megabits to yobibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabits to convert
+    pub fn to_yobibytes(value: f64) -> f64 {
+        super::bits::to_yobibytes(to_bits(value))
+    }
+
+    /// The megabit as a typed unit of amount of data.
+    pub const UNIT: Unit<Data> = Unit::new("Mbit", "megabit", to_bits, super::bits::to_megabits);
+
+    /// Creates a typed amount of data quantity expressed in megabits.
+    /// # Arguments
+    /// * `value` - The amount of data in megabits
+    pub fn new(value: f64) -> Quantity<Data> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed amount of data quantity in megabits.
+    /// # Arguments
+    /// * `quantity` - The amount of data quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Data>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Gigabits conversion functions
+pub mod gigabits {
+    use crate::conversions::quantity::{Data, Quantity, Unit};
+
+    /// Converts gigabits to bits.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabits to convert
+    pub fn to_bits(value: f64) -> f64 {
+        value * 1e9
+    }
+
+    /// Converts gigabits to kilobits.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabits to convert
+    pub fn to_kilobits(value: f64) -> f64 {
+        super::bits::to_kilobits(to_bits(value))
+    }
+
+    /// Converts gigabits to megabits.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabits to convert
+    pub fn to_megabits(value: f64) -> f64 {
+        super::bits::to_megabits(to_bits(value))
+    }
+
+    /// Converts gigabits to bytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabits to convert
+    pub fn to_bytes(value: f64) -> f64 {
+        super::bits::to_bytes(to_bits(value))
+    }
+
+    /// Converts gigabits to kilobytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabits to convert
+    pub fn to_kilobytes(value: f64) -> f64 {
+        super::bits::to_kilobytes(to_bits(value))
+    }
+
+    /// Converts gigabits to megabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabits to convert
+    pub fn to_megabytes(value: f64) -> f64 {
+        super::bits::to_megabytes(to_bits(value))
+    }
+
+    /// Converts gigabits to gigabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabits to convert
+    pub fn to_gigabytes(value: f64) -> f64 {
+        super::bits::to_gigabytes(to_bits(value))
+    }
+
+    /// Converts gigabits to terabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabits to convert
+    pub fn to_terabytes(value: f64) -> f64 {
+        super::bits::to_terabytes(to_bits(value))
+    }
+
+    /// Converts gigabits to petabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabits to convert
+    pub fn to_petabytes(value: f64) -> f64 {
+        super::bits::to_petabytes(to_bits(value))
+    }
+
+    /// Converts gigabits to exabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabits to convert
+    pub fn to_exabytes(value: f64) -> f64 {
+        super::bits::to_exabytes(to_bits(value))
+    }
+
+    /// Converts gigabits to zettabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabits to convert
+    pub fn to_zettabytes(value: f64) -> f64 {
+        super::bits::to_zettabytes(to_bits(value))
+    }
+
+    /// Converts gigabits to yottabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabits to convert
+    pub fn to_yottabytes(value: f64) -> f64 {
+        super::bits::to_yottabytes(to_bits(value))
+    }
+
+    /// Converts gigabits to kibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabits to convert
+    pub fn to_kibibytes(value: f64) -> f64 {
+        super::bits::to_kibibytes(to_bits(value))
+    }
+
+    /// Converts gigabits to mebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabits to convert
+    pub fn to_mebibytes(value: f64) -> f64 {
+        super::bits::to_mebibytes(to_bits(value))
+    }
+
+    /// Converts gigabits to gibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabits to convert
+    pub fn to_gibibytes(value: f64) -> f64 {
+        super::bits::to_gibibytes(to_bits(value))
+    }
+
+    /// Converts gigabits to tebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabits to convert
+    pub fn to_tebibytes(value: f64) -> f64 {
+        super::bits::to_tebibytes(to_bits(value))
+    }
+
+    /// Converts gigabits to pebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabits to convert
+    pub fn to_pebibytes(value: f64) -> f64 {
+        super::bits::to_pebibytes(to_bits(value))
+    }
+
+    /// Converts gigabits to exbibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabits to convert
+    pub fn to_exbibytes(value: f64) -> f64 {
+        super::bits::to_exbibytes(to_bits(value))
+    }
+
+    /// Converts gigabits to zebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabits to convert
+    pub fn to_zebibytes(value: f64) -> f64 {
+        super::bits::to_zebibytes(to_bits(value))
+    }
+
+    /// Converts gigabits to yobibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabits to convert
+    pub fn to_yobibytes(value: f64) -> f64 {
+        super::bits::to_yobibytes(to_bits(value))
+    }
+
+    /// The gigabit as a typed unit of amount of data.
+    pub const UNIT: Unit<Data> = Unit::new("Gbit", "gigabit", to_bits, super::bits::to_gigabits);
+
+    /// Creates a typed amount of data quantity expressed in gigabits.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabits
+    pub fn new(value: f64) -> Quantity<Data> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed amount of data quantity in gigabits.
+    /// # Arguments
+    /// * `quantity` - The amount of data quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Data>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Bytes conversion functions
+pub mod bytes {
+    use crate::conversions::quantity::{Data, Quantity, Unit};
+
+    /// Converts bytes to bits.
+    /// # Arguments
+    /// * `value` - The amount of data in bytes to convert
+    pub fn to_bits(value: f64) -> f64 {
+        value * super::BITS_PER_BYTE
+    }
+
+    /// Converts bytes to kilobits.
+    /// # Arguments
+    /// * `value` - The amount of data in bytes to convert
+    pub fn to_kilobits(value: f64) -> f64 {
+        super::bits::to_kilobits(to_bits(value))
+    }
+
+    /// Converts bytes to megabits.
+    /// # Arguments
+    /// * `value` - The amount of data in bytes to convert
+    pub fn to_megabits(value: f64) -> f64 {
+        super::bits::to_megabits(to_bits(value))
+    }
+
+    /// Converts bytes to gigabits.
+    /// # Arguments
+    /// * `value` - The amount of data in bytes to convert
+    pub fn to_gigabits(value: f64) -> f64 {
+        super::bits::to_gigabits(to_bits(value))
+    }
+
+    /// Converts bytes to kilobytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bytes to convert
+    pub fn to_kilobytes(value: f64) -> f64 {
+        super::bits::to_kilobytes(to_bits(value))
+    }
+
+    /// Converts bytes to megabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bytes to convert
+    pub fn to_megabytes(value: f64) -> f64 {
+        super::bits::to_megabytes(to_bits(value))
+    }
+
+    /// Converts bytes to gigabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bytes to convert
+    pub fn to_gigabytes(value: f64) -> f64 {
+        super::bits::to_gigabytes(to_bits(value))
+    }
+
+    /// Converts bytes to terabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bytes to convert
+    pub fn to_terabytes(value: f64) -> f64 {
+        super::bits::to_terabytes(to_bits(value))
+    }
+
+    /// Converts bytes to petabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bytes to convert
+    pub fn to_petabytes(value: f64) -> f64 {
+        super::bits::to_petabytes(to_bits(value))
+    }
+
+    /// Converts bytes to exabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bytes to convert
+    pub fn to_exabytes(value: f64) -> f64 {
+        super::bits::to_exabytes(to_bits(value))
+    }
+
+    /// Converts bytes to zettabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bytes to convert
+    pub fn to_zettabytes(value: f64) -> f64 {
+        super::bits::to_zettabytes(to_bits(value))
+    }
+
+    /// Converts bytes to yottabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bytes to convert
+    pub fn to_yottabytes(value: f64) -> f64 {
+        super::bits::to_yottabytes(to_bits(value))
+    }
+
+    /// Converts bytes to kibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bytes to convert
+    pub fn to_kibibytes(value: f64) -> f64 {
+        super::bits::to_kibibytes(to_bits(value))
+    }
+
+    /// Converts bytes to mebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bytes to convert
+    pub fn to_mebibytes(value: f64) -> f64 {
+        super::bits::to_mebibytes(to_bits(value))
+    }
+
+    /// Converts bytes to gibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bytes to convert
+    pub fn to_gibibytes(value: f64) -> f64 {
+        super::bits::to_gibibytes(to_bits(value))
+    }
+
+    /// Converts bytes to tebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bytes to convert
+    pub fn to_tebibytes(value: f64) -> f64 {
+        super::bits::to_tebibytes(to_bits(value))
+    }
+
+    /// Converts bytes to pebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bytes to convert
+    pub fn to_pebibytes(value: f64) -> f64 {
+        super::bits::to_pebibytes(to_bits(value))
+    }
+
+    /// Converts bytes to exbibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bytes to convert
+    pub fn to_exbibytes(value: f64) -> f64 {
+        super::bits::to_exbibytes(to_bits(value))
+    }
+
+    /// Converts bytes to zebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bytes to convert
+    pub fn to_zebibytes(value: f64) -> f64 {
+        super::bits::to_zebibytes(to_bits(value))
+    }
+
+    /// Converts bytes to yobibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bytes to convert
+    pub fn to_yobibytes(value: f64) -> f64 {
+        super::bits::to_yobibytes(to_bits(value))
+    }
+
+    /// The byte as a typed unit of amount of data.
+    pub const UNIT: Unit<Data> = Unit::new("B", "byte", to_bits, super::bits::to_bytes);
+
+    /// Creates a typed amount of data quantity expressed in bytes.
+    /// # Arguments
+    /// * `value` - The amount of data in bytes
+    pub fn new(value: f64) -> Quantity<Data> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed amount of data quantity in bytes.
+    /// # Arguments
+    /// * `quantity` - The amount of data quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Data>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Kilobytes (SI, 1000 bytes) conversion functions
+pub mod kilobytes {
+    use crate::conversions::quantity::{Data, Quantity, Unit};
+
+    /// Converts kilobytes to bits.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobytes to convert
+    pub fn to_bits(value: f64) -> f64 {
+        value * 1e3 * super::BITS_PER_BYTE
+    }
+
+    /// Converts kilobytes to kilobits.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobytes to convert
+    pub fn to_kilobits(value: f64) -> f64 {
+        super::bits::to_kilobits(to_bits(value))
+    }
+
+    /// Converts kilobytes to megabits.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobytes to convert
+    pub fn to_megabits(value: f64) -> f64 {
+        super::bits::to_megabits(to_bits(value))
+    }
+
+    /// Converts kilobytes to gigabits.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobytes to convert
+    pub fn to_gigabits(value: f64) -> f64 {
+        super::bits::to_gigabits(to_bits(value))
+    }
+
+    /// Converts kilobytes to bytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobytes to convert
+    pub fn to_bytes(value: f64) -> f64 {
+        super::bits::to_bytes(to_bits(value))
+    }
+
+    /// Converts kilobytes to megabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobytes to convert
+    pub fn to_megabytes(value: f64) -> f64 {
+        super::bits::to_megabytes(to_bits(value))
+    }
+
+    /// Converts kilobytes to gigabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobytes to convert
+    pub fn to_gigabytes(value: f64) -> f64 {
+        super::bits::to_gigabytes(to_bits(value))
+    }
+
+    /// Converts kilobytes to terabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobytes to convert
+    pub fn to_terabytes(value: f64) -> f64 {
+        super::bits::to_terabytes(to_bits(value))
+    }
+
+    /// Converts kilobytes to petabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobytes to convert
+    pub fn to_petabytes(value: f64) -> f64 {
+        super::bits::to_petabytes(to_bits(value))
+    }
+
+    /// Converts kilobytes to exabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobytes to convert
+    pub fn to_exabytes(value: f64) -> f64 {
+        super::bits::to_exabytes(to_bits(value))
+    }
+
+    /// Converts kilobytes to zettabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobytes to convert
+    pub fn to_zettabytes(value: f64) -> f64 {
+        super::bits::to_zettabytes(to_bits(value))
+    }
+
+    /// Converts kilobytes to yottabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobytes to convert
+    pub fn to_yottabytes(value: f64) -> f64 {
+        super::bits::to_yottabytes(to_bits(value))
+    }
+
+    /// Converts kilobytes to kibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobytes to convert
+    pub fn to_kibibytes(value: f64) -> f64 {
+        super::bits::to_kibibytes(to_bits(value))
+    }
+
+    /// Converts kilobytes to mebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobytes to convert
+    pub fn to_mebibytes(value: f64) -> f64 {
+        super::bits::to_mebibytes(to_bits(value))
+    }
+
+    /// Converts kilobytes to gibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobytes to convert
+    pub fn to_gibibytes(value: f64) -> f64 {
+        super::bits::to_gibibytes(to_bits(value))
+    }
+
+    /// Converts kilobytes to tebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobytes to convert
+    pub fn to_tebibytes(value: f64) -> f64 {
+        super::bits::to_tebibytes(to_bits(value))
+    }
+
+    /// Converts kilobytes to pebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobytes to convert
+    pub fn to_pebibytes(value: f64) -> f64 {
+        super::bits::to_pebibytes(to_bits(value))
+    }
+
+    /// Converts kilobytes to exbibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobytes to convert
+    pub fn to_exbibytes(value: f64) -> f64 {
+        super::bits::to_exbibytes(to_bits(value))
+    }
+
+    /// Converts kilobytes to zebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobytes to convert
+    pub fn to_zebibytes(value: f64) -> f64 {
+        super::bits::to_zebibytes(to_bits(value))
+    }
+
+    /// Converts kilobytes to yobibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobytes to convert
+    pub fn to_yobibytes(value: f64) -> f64 {
+        super::bits::to_yobibytes(to_bits(value))
+    }
+
+    /// The kilobyte as a typed unit of amount of data.
+    pub const UNIT: Unit<Data> = Unit::new("kB", "kilobyte", to_bits, super::bits::to_kilobytes);
+
+    /// Creates a typed amount of data quantity expressed in kilobytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kilobytes
+    pub fn new(value: f64) -> Quantity<Data> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed amount of data quantity in kilobytes.
+    /// # Arguments
+    /// * `quantity` - The amount of data quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Data>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Megabytes (SI, 1000² bytes) conversion functions
+pub mod megabytes {
+    use crate::conversions::quantity::{Data, Quantity, Unit};
+
+    /// Converts megabytes to bits.
+    /// # Arguments
+    /// * `value` - The amount of data in megabytes to convert
+    pub fn to_bits(value: f64) -> f64 {
+        value * 1e6 * super::BITS_PER_BYTE
+    }
+
+    /// Converts megabytes to kilobits.
+    /// # Arguments
+    /// * `value` - The amount of data in megabytes to convert
+    pub fn to_kilobits(value: f64) -> f64 {
+        super::bits::to_kilobits(to_bits(value))
+    }
+
+    /// Converts megabytes to megabits.
+    /// # Arguments
+    /// * `value` - The amount of data in megabytes to convert
+    pub fn to_megabits(value: f64) -> f64 {
+        super::bits::to_megabits(to_bits(value))
+    }
+
+    /// Converts megabytes to gigabits.
+    /// # Arguments
+    /// * `value` - The amount of data in megabytes to convert
+    pub fn to_gigabits(value: f64) -> f64 {
+        super::bits::to_gigabits(to_bits(value))
+    }
+
+    /// Converts megabytes to bytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabytes to convert
+    pub fn to_bytes(value: f64) -> f64 {
+        super::bits::to_bytes(to_bits(value))
+    }
+
+    /// Converts megabytes to kilobytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabytes to convert
+    pub fn to_kilobytes(value: f64) -> f64 {
+        super::bits::to_kilobytes(to_bits(value))
+    }
+
+    /// Converts megabytes to gigabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabytes to convert
+    pub fn to_gigabytes(value: f64) -> f64 {
+        super::bits::to_gigabytes(to_bits(value))
+    }
+
+    /// Converts megabytes to terabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabytes to convert
+    pub fn to_terabytes(value: f64) -> f64 {
+        super::bits::to_terabytes(to_bits(value))
+    }
+
+    /// Converts megabytes to petabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabytes to convert
+    pub fn to_petabytes(value: f64) -> f64 {
+        super::bits::to_petabytes(to_bits(value))
+    }
+
+    /// Converts megabytes to exabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabytes to convert
+    pub fn to_exabytes(value: f64) -> f64 {
+        super::bits::to_exabytes(to_bits(value))
+    }
+
+    /// Converts megabytes to zettabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabytes to convert
+    pub fn to_zettabytes(value: f64) -> f64 {
+        super::bits::to_zettabytes(to_bits(value))
+    }
+
+    /// Converts megabytes to yottabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabytes to convert
+    pub fn to_yottabytes(value: f64) -> f64 {
+        super::bits::to_yottabytes(to_bits(value))
+    }
+
+    /// Converts megabytes to kibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabytes to convert
+    pub fn to_kibibytes(value: f64) -> f64 {
+        super::bits::to_kibibytes(to_bits(value))
+    }
+
+    /// Converts megabytes to mebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabytes to convert
+    pub fn to_mebibytes(value: f64) -> f64 {
+        super::bits::to_mebibytes(to_bits(value))
+    }
+
+    /// Converts megabytes to gibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabytes to convert
+    pub fn to_gibibytes(value: f64) -> f64 {
+        super::bits::to_gibibytes(to_bits(value))
+    }
+
+    /// Converts megabytes to tebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabytes to convert
+    pub fn to_tebibytes(value: f64) -> f64 {
+        super::bits::to_tebibytes(to_bits(value))
+    }
+
+    /// Converts megabytes to pebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabytes to convert
+    pub fn to_pebibytes(value: f64) -> f64 {
+        super::bits::to_pebibytes(to_bits(value))
+    }
+
+    /// Converts megabytes to exbibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabytes to convert
+    pub fn to_exbibytes(value: f64) -> f64 {
+        super::bits::to_exbibytes(to_bits(value))
+    }
+
+    /// Converts megabytes to zebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabytes to convert
+    pub fn to_zebibytes(value: f64) -> f64 {
+        super::bits::to_zebibytes(to_bits(value))
+    }
+
+    /// Converts megabytes to yobibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabytes to convert
+    pub fn to_yobibytes(value: f64) -> f64 {
+        super::bits::to_yobibytes(to_bits(value))
+    }
+
+    /// The megabyte as a typed unit of amount of data.
+    pub const UNIT: Unit<Data> = Unit::new("MB", "megabyte", to_bits, super::bits::to_megabytes);
+
+    /// Creates a typed amount of data quantity expressed in megabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in megabytes
+    pub fn new(value: f64) -> Quantity<Data> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed amount of data quantity in megabytes.
+    /// # Arguments
+    /// * `quantity` - The amount of data quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Data>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Gigabytes (SI, 1000³ bytes) conversion functions
+pub mod gigabytes {
+    use crate::conversions::quantity::{Data, Quantity, Unit};
+
+    /// Converts gigabytes to bits.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabytes to convert
+    pub fn to_bits(value: f64) -> f64 {
+        value * 1e9 * super::BITS_PER_BYTE
+    }
+
+    /// Converts gigabytes to kilobits.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabytes to convert
+    pub fn to_kilobits(value: f64) -> f64 {
+        super::bits::to_kilobits(to_bits(value))
+    }
+
+    /// Converts gigabytes to megabits.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabytes to convert
+    pub fn to_megabits(value: f64) -> f64 {
+        super::bits::to_megabits(to_bits(value))
+    }
+
+    /// Converts gigabytes to gigabits.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabytes to convert
+    pub fn to_gigabits(value: f64) -> f64 {
+        super::bits::to_gigabits(to_bits(value))
+    }
+
+    /// Converts gigabytes to bytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabytes to convert
+    pub fn to_bytes(value: f64) -> f64 {
+        super::bits::to_bytes(to_bits(value))
+    }
+
+    /// Converts gigabytes to kilobytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabytes to convert
+    pub fn to_kilobytes(value: f64) -> f64 {
+        super::bits::to_kilobytes(to_bits(value))
+    }
+
+    /// Converts gigabytes to megabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabytes to convert
+    pub fn to_megabytes(value: f64) -> f64 {
+        super::bits::to_megabytes(to_bits(value))
+    }
+
+    /// Converts gigabytes to terabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabytes to convert
+    pub fn to_terabytes(value: f64) -> f64 {
+        super::bits::to_terabytes(to_bits(value))
+    }
+
+    /// Converts gigabytes to petabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabytes to convert
+    pub fn to_petabytes(value: f64) -> f64 {
+        super::bits::to_petabytes(to_bits(value))
+    }
+
+    /// Converts gigabytes to exabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabytes to convert
+    pub fn to_exabytes(value: f64) -> f64 {
+        super::bits::to_exabytes(to_bits(value))
+    }
+
+    /// Converts gigabytes to zettabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabytes to convert
+    pub fn to_zettabytes(value: f64) -> f64 {
+        super::bits::to_zettabytes(to_bits(value))
+    }
+
+    /// Converts gigabytes to yottabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabytes to convert
+    pub fn to_yottabytes(value: f64) -> f64 {
+        super::bits::to_yottabytes(to_bits(value))
+    }
+
+    /// Converts gigabytes to kibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabytes to convert
+    pub fn to_kibibytes(value: f64) -> f64 {
+        super::bits::to_kibibytes(to_bits(value))
+    }
+
+    /// Converts gigabytes to mebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabytes to convert
+    pub fn to_mebibytes(value: f64) -> f64 {
+        super::bits::to_mebibytes(to_bits(value))
+    }
+
+    /// Converts gigabytes to gibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabytes to convert
+    pub fn to_gibibytes(value: f64) -> f64 {
+        super::bits::to_gibibytes(to_bits(value))
+    }
+
+    /// Converts gigabytes to tebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabytes to convert
+    pub fn to_tebibytes(value: f64) -> f64 {
+        super::bits::to_tebibytes(to_bits(value))
+    }
+
+    /// Converts gigabytes to pebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabytes to convert
+    pub fn to_pebibytes(value: f64) -> f64 {
+        super::bits::to_pebibytes(to_bits(value))
+    }
+
+    /// Converts gigabytes to exbibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabytes to convert
+    pub fn to_exbibytes(value: f64) -> f64 {
+        super::bits::to_exbibytes(to_bits(value))
+    }
+
+    /// Converts gigabytes to zebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabytes to convert
+    pub fn to_zebibytes(value: f64) -> f64 {
+        super::bits::to_zebibytes(to_bits(value))
+    }
+
+    /// Converts gigabytes to yobibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabytes to convert
+    pub fn to_yobibytes(value: f64) -> f64 {
+        super::bits::to_yobibytes(to_bits(value))
+    }
+
+    /// The gigabyte as a typed unit of amount of data.
+    pub const UNIT: Unit<Data> = Unit::new("GB", "gigabyte", to_bits, super::bits::to_gigabytes);
+
+    /// Creates a typed amount of data quantity expressed in gigabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gigabytes
+    pub fn new(value: f64) -> Quantity<Data> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed amount of data quantity in gigabytes.
+    /// # Arguments
+    /// * `quantity` - The amount of data quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Data>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Terabytes (SI, 1000⁴ bytes) conversion functions
+pub mod terabytes {
+    use crate::conversions::quantity::{Data, Quantity, Unit};
+
+    /// Converts terabytes to bits.
+    /// # Arguments
+    /// * `value` - The amount of data in terabytes to convert
+    pub fn to_bits(value: f64) -> f64 {
+        value * 1e12 * super::BITS_PER_BYTE
+    }
+
+    /// Converts terabytes to kilobits.
+    /// # Arguments
+    /// * `value` - The amount of data in terabytes to convert
+    pub fn to_kilobits(value: f64) -> f64 {
+        super::bits::to_kilobits(to_bits(value))
+    }
+
+    /// Converts terabytes to megabits.
+    /// # Arguments
+    /// * `value` - The amount of data in terabytes to convert
+    pub fn to_megabits(value: f64) -> f64 {
+        super::bits::to_megabits(to_bits(value))
+    }
+
+    /// Converts terabytes to gigabits.
+    /// # Arguments
+    /// * `value` - The amount of data in terabytes to convert
+    pub fn to_gigabits(value: f64) -> f64 {
+        super::bits::to_gigabits(to_bits(value))
+    }
+
+    /// Converts terabytes to bytes.
+    /// # Arguments
+    /// * `value` - The amount of data in terabytes to convert
+    pub fn to_bytes(value: f64) -> f64 {
+        super::bits::to_bytes(to_bits(value))
+    }
+
+    /// Converts terabytes to kilobytes.
+    /// # Arguments
+    /// * `value` - The amount of data in terabytes to convert
+    pub fn to_kilobytes(value: f64) -> f64 {
+        super::bits::to_kilobytes(to_bits(value))
+    }
+
+    /// Converts terabytes to megabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in terabytes to convert
+    pub fn to_megabytes(value: f64) -> f64 {
+        super::bits::to_megabytes(to_bits(value))
+    }
+
+    /// Converts terabytes to gigabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in terabytes to convert
+    pub fn to_gigabytes(value: f64) -> f64 {
+        super::bits::to_gigabytes(to_bits(value))
+    }
+
+    /// Converts terabytes to petabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in terabytes to convert
+    pub fn to_petabytes(value: f64) -> f64 {
+        super::bits::to_petabytes(to_bits(value))
+    }
+
+    /// Converts terabytes to exabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in terabytes to convert
+    pub fn to_exabytes(value: f64) -> f64 {
+        super::bits::to_exabytes(to_bits(value))
+    }
+
+    /// Converts terabytes to zettabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in terabytes to convert
+    pub fn to_zettabytes(value: f64) -> f64 {
+        super::bits::to_zettabytes(to_bits(value))
+    }
+
+    /// Converts terabytes to yottabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in terabytes to convert
+    pub fn to_yottabytes(value: f64) -> f64 {
+        super::bits::to_yottabytes(to_bits(value))
+    }
+
+    /// Converts terabytes to kibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in terabytes to convert
+    pub fn to_kibibytes(value: f64) -> f64 {
+        super::bits::to_kibibytes(to_bits(value))
+    }
+
+    /// Converts terabytes to mebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in terabytes to convert
+    pub fn to_mebibytes(value: f64) -> f64 {
+        super::bits::to_mebibytes(to_bits(value))
+    }
+
+    /// Converts terabytes to gibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in terabytes to convert
+    pub fn to_gibibytes(value: f64) -> f64 {
+        super::bits::to_gibibytes(to_bits(value))
+    }
+
+    /// Converts terabytes to tebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in terabytes to convert
+    pub fn to_tebibytes(value: f64) -> f64 {
+        super::bits::to_tebibytes(to_bits(value))
+    }
+
+    /// Converts terabytes to pebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in terabytes to convert
+    pub fn to_pebibytes(value: f64) -> f64 {
+        super::bits::to_pebibytes(to_bits(value))
+    }
+
+    /// Converts terabytes to exbibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in terabytes to convert
+    pub fn to_exbibytes(value: f64) -> f64 {
+        super::bits::to_exbibytes(to_bits(value))
+    }
+
+    /// Converts terabytes to zebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in terabytes to convert
+    pub fn to_zebibytes(value: f64) -> f64 {
+        super::bits::to_zebibytes(to_bits(value))
+    }
+
+    /// Converts terabytes to yobibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in terabytes to convert
+    pub fn to_yobibytes(value: f64) -> f64 {
+        super::bits::to_yobibytes(to_bits(value))
+    }
+
+    /// The terabyte as a typed unit of amount of data.
+    pub const UNIT: Unit<Data> = Unit::new("TB", "terabyte", to_bits, super::bits::to_terabytes);
+
+    /// Creates a typed amount of data quantity expressed in terabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in terabytes
+    pub fn new(value: f64) -> Quantity<Data> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed amount of data quantity in terabytes.
+    /// # Arguments
+    /// * `quantity` - The amount of data quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Data>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Petabytes (SI, 1000⁵ bytes) conversion functions
+pub mod petabytes {
+    use crate::conversions::quantity::{Data, Quantity, Unit};
+
+    /// Converts petabytes to bits.
+    /// # Arguments
+    /// * `value` - The amount of data in petabytes to convert
+    pub fn to_bits(value: f64) -> f64 {
+        value * 1e15 * super::BITS_PER_BYTE
+    }
+
+    /// Converts petabytes to kilobits.
+    /// # Arguments
+    /// * `value` - The amount of data in petabytes to convert
+    pub fn to_kilobits(value: f64) -> f64 {
+        super::bits::to_kilobits(to_bits(value))
+    }
+
+    /// Converts petabytes to megabits.
+    /// # Arguments
+    /// * `value` - The amount of data in petabytes to convert
+    pub fn to_megabits(value: f64) -> f64 {
+        super::bits::to_megabits(to_bits(value))
+    }
+
+    /// Converts petabytes to gigabits.
+    /// # Arguments
+    /// * `value` - The amount of data in petabytes to convert
+    pub fn to_gigabits(value: f64) -> f64 {
+        super::bits::to_gigabits(to_bits(value))
+    }
+
+    /// Converts petabytes to bytes.
+    /// # Arguments
+    /// * `value` - The amount of data in petabytes to convert
+    pub fn to_bytes(value: f64) -> f64 {
+        super::bits::to_bytes(to_bits(value))
+    }
+
+    /// Converts petabytes to kilobytes.
+    /// # Arguments
+    /// * `value` - The amount of data in petabytes to convert
+    pub fn to_kilobytes(value: f64) -> f64 {
+        super::bits::to_kilobytes(to_bits(value))
+    }
+
+    /// Converts petabytes to megabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in petabytes to convert
+    pub fn to_megabytes(value: f64) -> f64 {
+        super::bits::to_megabytes(to_bits(value))
+    }
+
+    /// Converts petabytes to gigabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in petabytes to convert
+    pub fn to_gigabytes(value: f64) -> f64 {
+        super::bits::to_gigabytes(to_bits(value))
+    }
+
+    /// Converts petabytes to terabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in petabytes to convert
+    pub fn to_terabytes(value: f64) -> f64 {
+        super::bits::to_terabytes(to_bits(value))
+    }
+
+    /// Converts petabytes to exabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in petabytes to convert
+    pub fn to_exabytes(value: f64) -> f64 {
+        super::bits::to_exabytes(to_bits(value))
+    }
+
+    /// Converts petabytes to zettabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in petabytes to convert
+    pub fn to_zettabytes(value: f64) -> f64 {
+        super::bits::to_zettabytes(to_bits(value))
+    }
+
+    /// Converts petabytes to yottabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in petabytes to convert
+    pub fn to_yottabytes(value: f64) -> f64 {
+        super::bits::to_yottabytes(to_bits(value))
+    }
+
+    /// Converts petabytes to kibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in petabytes to convert
+    pub fn to_kibibytes(value: f64) -> f64 {
+        super::bits::to_kibibytes(to_bits(value))
+    }
+
+    /// Converts petabytes to mebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in petabytes to convert
+    pub fn to_mebibytes(value: f64) -> f64 {
+        super::bits::to_mebibytes(to_bits(value))
+    }
+
+    /// Converts petabytes to gibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in petabytes to convert
+    pub fn to_gibibytes(value: f64) -> f64 {
+        super::bits::to_gibibytes(to_bits(value))
+    }
+
+    /// Converts petabytes to tebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in petabytes to convert
+    pub fn to_tebibytes(value: f64) -> f64 {
+        super::bits::to_tebibytes(to_bits(value))
+    }
+
+    /// Converts petabytes to pebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in petabytes to convert
+    pub fn to_pebibytes(value: f64) -> f64 {
+        super::bits::to_pebibytes(to_bits(value))
+    }
+
+    /// Converts petabytes to exbibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in petabytes to convert
+    pub fn to_exbibytes(value: f64) -> f64 {
+        super::bits::to_exbibytes(to_bits(value))
+    }
+
+    /// Converts petabytes to zebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in petabytes to convert
+    pub fn to_zebibytes(value: f64) -> f64 {
+        super::bits::to_zebibytes(to_bits(value))
+    }
+
+    /// Converts petabytes to yobibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in petabytes to convert
+    pub fn to_yobibytes(value: f64) -> f64 {
+        super::bits::to_yobibytes(to_bits(value))
+    }
+
+    /// The petabyte as a typed unit of amount of data.
+    pub const UNIT: Unit<Data> = Unit::new("PB", "petabyte", to_bits, super::bits::to_petabytes);
+
+    /// Creates a typed amount of data quantity expressed in petabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in petabytes
+    pub fn new(value: f64) -> Quantity<Data> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed amount of data quantity in petabytes.
+    /// # Arguments
+    /// * `quantity` - The amount of data quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Data>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Exabytes (SI, 1000⁶ bytes) conversion functions
+pub mod exabytes {
+    use crate::conversions::quantity::{Data, Quantity, Unit};
+
+    /// Converts exabytes to bits.
+    /// # Arguments
+    /// * `value` - The amount of data in exabytes to convert
+    pub fn to_bits(value: f64) -> f64 {
+        value * 1e18 * super::BITS_PER_BYTE
+    }
+
+    /// Converts exabytes to kilobits.
+    /// # Arguments
+    /// * `value` - The amount of data in exabytes to convert
+    pub fn to_kilobits(value: f64) -> f64 {
+        super::bits::to_kilobits(to_bits(value))
+    }
+
+    /// Converts exabytes to megabits.
+    /// # Arguments
+    /// * `value` - The amount of data in exabytes to convert
+    pub fn to_megabits(value: f64) -> f64 {
+        super::bits::to_megabits(to_bits(value))
+    }
+
+    /// Converts exabytes to gigabits.
+    /// # Arguments
+    /// * `value` - The amount of data in exabytes to convert
+    pub fn to_gigabits(value: f64) -> f64 {
+        super::bits::to_gigabits(to_bits(value))
+    }
+
+    /// Converts exabytes to bytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exabytes to convert
+    pub fn to_bytes(value: f64) -> f64 {
+        super::bits::to_bytes(to_bits(value))
+    }
+
+    /// Converts exabytes to kilobytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exabytes to convert
+    pub fn to_kilobytes(value: f64) -> f64 {
+        super::bits::to_kilobytes(to_bits(value))
+    }
+
+    /// Converts exabytes to megabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exabytes to convert
+    pub fn to_megabytes(value: f64) -> f64 {
+        super::bits::to_megabytes(to_bits(value))
+    }
+
+    /// Converts exabytes to gigabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exabytes to convert
+    pub fn to_gigabytes(value: f64) -> f64 {
+        super::bits::to_gigabytes(to_bits(value))
+    }
+
+    /// Converts exabytes to terabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exabytes to convert
+    pub fn to_terabytes(value: f64) -> f64 {
+        super::bits::to_terabytes(to_bits(value))
+    }
+
+    /// Converts exabytes to petabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exabytes to convert
+    pub fn to_petabytes(value: f64) -> f64 {
+        super::bits::to_petabytes(to_bits(value))
+    }
+
+    /// Converts exabytes to zettabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exabytes to convert
+    pub fn to_zettabytes(value: f64) -> f64 {
+        super::bits::to_zettabytes(to_bits(value))
+    }
+
+    /// Converts exabytes to yottabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exabytes to convert
+    pub fn to_yottabytes(value: f64) -> f64 {
+        super::bits::to_yottabytes(to_bits(value))
+    }
+
+    /// Converts exabytes to kibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exabytes to convert
+    pub fn to_kibibytes(value: f64) -> f64 {
+        super::bits::to_kibibytes(to_bits(value))
+    }
+
+    /// Converts exabytes to mebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exabytes to convert
+    pub fn to_mebibytes(value: f64) -> f64 {
+        super::bits::to_mebibytes(to_bits(value))
+    }
+
+    /// Converts exabytes to gibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exabytes to convert
+    pub fn to_gibibytes(value: f64) -> f64 {
+        super::bits::to_gibibytes(to_bits(value))
+    }
+
+    /// Converts exabytes to tebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exabytes to convert
+    pub fn to_tebibytes(value: f64) -> f64 {
+        super::bits::to_tebibytes(to_bits(value))
+    }
+
+    /// Converts exabytes to pebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exabytes to convert
+    pub fn to_pebibytes(value: f64) -> f64 {
+        super::bits::to_pebibytes(to_bits(value))
+    }
+
+    /// Converts exabytes to exbibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exabytes to convert
+    pub fn to_exbibytes(value: f64) -> f64 {
+        super::bits::to_exbibytes(to_bits(value))
+    }
+
+    /// Converts exabytes to zebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exabytes to convert
+    pub fn to_zebibytes(value: f64) -> f64 {
+        super::bits::to_zebibytes(to_bits(value))
+    }
+
+    /// Converts exabytes to yobibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exabytes to convert
+    pub fn to_yobibytes(value: f64) -> f64 {
+        super::bits::to_yobibytes(to_bits(value))
+    }
+
+    /// The exabyte as a typed unit of amount of data.
+    pub const UNIT: Unit<Data> = Unit::new("EB", "exabyte", to_bits, super::bits::to_exabytes);
+
+    /// Creates a typed amount of data quantity expressed in exabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exabytes
+    pub fn new(value: f64) -> Quantity<Data> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed amount of data quantity in exabytes.
+    /// # Arguments
+    /// * `quantity` - The amount of data quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Data>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Zettabytes (SI, 1000⁷ bytes) conversion functions
+pub mod zettabytes {
+    use crate::conversions::quantity::{Data, Quantity, Unit};
+
+    /// Converts zettabytes to bits.
+    /// # Arguments
+    /// * `value` - The amount of data in zettabytes to convert
+    pub fn to_bits(value: f64) -> f64 {
+        value * 1e21 * super::BITS_PER_BYTE
+    }
+
+    /// Converts zettabytes to kilobits.
+    /// # Arguments
+    /// * `value` - The amount of data in zettabytes to convert
+    pub fn to_kilobits(value: f64) -> f64 {
+        super::bits::to_kilobits(to_bits(value))
+    }
+
+    /// Converts zettabytes to megabits.
+    /// # Arguments
+    /// * `value` - The amount of data in zettabytes to convert
+    pub fn to_megabits(value: f64) -> f64 {
+        super::bits::to_megabits(to_bits(value))
+    }
+
+    /// Converts zettabytes to gigabits.
+    /// # Arguments
+    /// * `value` - The amount of data in zettabytes to convert
+    pub fn to_gigabits(value: f64) -> f64 {
+        super::bits::to_gigabits(to_bits(value))
+    }
+
+    /// Converts zettabytes to bytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zettabytes to convert
+    pub fn to_bytes(value: f64) -> f64 {
+        super::bits::to_bytes(to_bits(value))
+    }
+
+    /// Converts zettabytes to kilobytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zettabytes to convert
+    pub fn to_kilobytes(value: f64) -> f64 {
+        super::bits::to_kilobytes(to_bits(value))
+    }
+
+    /// Converts zettabytes to megabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zettabytes to convert
+    pub fn to_megabytes(value: f64) -> f64 {
+        super::bits::to_megabytes(to_bits(value))
+    }
+
+    /// Converts zettabytes to gigabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zettabytes to convert
+    pub fn to_gigabytes(value: f64) -> f64 {
+        super::bits::to_gigabytes(to_bits(value))
+    }
+
+    /// Converts zettabytes to terabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zettabytes to convert
+    pub fn to_terabytes(value: f64) -> f64 {
+        super::bits::to_terabytes(to_bits(value))
+    }
+
+    /// Converts zettabytes to petabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zettabytes to convert
+    pub fn to_petabytes(value: f64) -> f64 {
+        super::bits::to_petabytes(to_bits(value))
+    }
+
+    /// Converts zettabytes to exabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zettabytes to convert
+    pub fn to_exabytes(value: f64) -> f64 {
+        super::bits::to_exabytes(to_bits(value))
+    }
+
+    /// Converts zettabytes to yottabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zettabytes to convert
+    pub fn to_yottabytes(value: f64) -> f64 {
+        super::bits::to_yottabytes(to_bits(value))
+    }
+
+    /// Converts zettabytes to kibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zettabytes to convert
+    pub fn to_kibibytes(value: f64) -> f64 {
+        super::bits::to_kibibytes(to_bits(value))
+    }
+
+    /// Converts zettabytes to mebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zettabytes to convert
+    pub fn to_mebibytes(value: f64) -> f64 {
+        super::bits::to_mebibytes(to_bits(value))
+    }
+
+    /// Converts zettabytes to gibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zettabytes to convert
+    pub fn to_gibibytes(value: f64) -> f64 {
+        super::bits::to_gibibytes(to_bits(value))
+    }
+
+    /// Converts zettabytes to tebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zettabytes to convert
+    pub fn to_tebibytes(value: f64) -> f64 {
+        super::bits::to_tebibytes(to_bits(value))
+    }
+
+    /// Converts zettabytes to pebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zettabytes to convert
+    pub fn to_pebibytes(value: f64) -> f64 {
+        super::bits::to_pebibytes(to_bits(value))
+    }
+
+    /// Converts zettabytes to exbibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zettabytes to convert
+    pub fn to_exbibytes(value: f64) -> f64 {
+        super::bits::to_exbibytes(to_bits(value))
+    }
+
+    /// Converts zettabytes to zebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zettabytes to convert
+    pub fn to_zebibytes(value: f64) -> f64 {
+        super::bits::to_zebibytes(to_bits(value))
+    }
+
+    /// Converts zettabytes to yobibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zettabytes to convert
+    pub fn to_yobibytes(value: f64) -> f64 {
+        super::bits::to_yobibytes(to_bits(value))
+    }
+
+    /// The zettabyte as a typed unit of amount of data.
+    pub const UNIT: Unit<Data> = Unit::new("ZB", "zettabyte", to_bits, super::bits::to_zettabytes);
+
+    /// Creates a typed amount of data quantity expressed in zettabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zettabytes
+    pub fn new(value: f64) -> Quantity<Data> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed amount of data quantity in zettabytes.
+    /// # Arguments
+    /// * `quantity` - The amount of data quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Data>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Yottabytes (SI, 1000⁸ bytes) conversion functions
+pub mod yottabytes {
+    use crate::conversions::quantity::{Data, Quantity, Unit};
+
+    /// Converts yottabytes to bits.
+    /// # Arguments
+    /// * `value` - The amount of data in yottabytes to convert
+    pub fn to_bits(value: f64) -> f64 {
+        value * 1e24 * super::BITS_PER_BYTE
+    }
+
+    /// Converts yottabytes to kilobits.
+    /// # Arguments
+    /// * `value` - The amount of data in yottabytes to convert
+    pub fn to_kilobits(value: f64) -> f64 {
+        super::bits::to_kilobits(to_bits(value))
+    }
+
+    /// Converts yottabytes to megabits.
+    /// # Arguments
+    /// * `value` - The amount of data in yottabytes to convert
+    pub fn to_megabits(value: f64) -> f64 {
+        super::bits::to_megabits(to_bits(value))
+    }
+
+    /// Converts yottabytes to gigabits.
+    /// # Arguments
+    /// * `value` - The amount of data in yottabytes to convert
+    pub fn to_gigabits(value: f64) -> f64 {
+        super::bits::to_gigabits(to_bits(value))
+    }
+
+    /// Converts yottabytes to bytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yottabytes to convert
+    pub fn to_bytes(value: f64) -> f64 {
+        super::bits::to_bytes(to_bits(value))
+    }
+
+    /// Converts yottabytes to kilobytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yottabytes to convert
+    pub fn to_kilobytes(value: f64) -> f64 {
+        super::bits::to_kilobytes(to_bits(value))
+    }
+
+    /// Converts yottabytes to megabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yottabytes to convert
+    pub fn to_megabytes(value: f64) -> f64 {
+        super::bits::to_megabytes(to_bits(value))
+    }
+
+    /// Converts yottabytes to gigabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yottabytes to convert
+    pub fn to_gigabytes(value: f64) -> f64 {
+        super::bits::to_gigabytes(to_bits(value))
+    }
+
+    /// Converts yottabytes to terabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yottabytes to convert
+    pub fn to_terabytes(value: f64) -> f64 {
+        super::bits::to_terabytes(to_bits(value))
+    }
+
+    /// Converts yottabytes to petabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yottabytes to convert
+    pub fn to_petabytes(value: f64) -> f64 {
+        super::bits::to_petabytes(to_bits(value))
+    }
+
+    /// Converts yottabytes to exabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yottabytes to convert
+    pub fn to_exabytes(value: f64) -> f64 {
+        super::bits::to_exabytes(to_bits(value))
+    }
+
+    /// Converts yottabytes to zettabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yottabytes to convert
+    pub fn to_zettabytes(value: f64) -> f64 {
+        super::bits::to_zettabytes(to_bits(value))
+    }
+
+    /// Converts yottabytes to kibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yottabytes to convert
+    pub fn to_kibibytes(value: f64) -> f64 {
+        super::bits::to_kibibytes(to_bits(value))
+    }
+
+    /// Converts yottabytes to mebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yottabytes to convert
+    pub fn to_mebibytes(value: f64) -> f64 {
+        super::bits::to_mebibytes(to_bits(value))
+    }
+
+    /// Converts yottabytes to gibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yottabytes to convert
+    pub fn to_gibibytes(value: f64) -> f64 {
+        super::bits::to_gibibytes(to_bits(value))
+    }
+
+    /// Converts yottabytes to tebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yottabytes to convert
+    pub fn to_tebibytes(value: f64) -> f64 {
+        super::bits::to_tebibytes(to_bits(value))
+    }
+
+    /// Converts yottabytes to pebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yottabytes to convert
+    pub fn to_pebibytes(value: f64) -> f64 {
+        super::bits::to_pebibytes(to_bits(value))
+    }
+
+    /// Converts yottabytes to exbibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yottabytes to convert
+    pub fn to_exbibytes(value: f64) -> f64 {
+        super::bits::to_exbibytes(to_bits(value))
+    }
+
+    /// Converts yottabytes to zebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yottabytes to convert
+    pub fn to_zebibytes(value: f64) -> f64 {
+        super::bits::to_zebibytes(to_bits(value))
+    }
+
+    /// Converts yottabytes to yobibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yottabytes to convert
+    pub fn to_yobibytes(value: f64) -> f64 {
+        super::bits::to_yobibytes(to_bits(value))
+    }
+
+    /// The yottabyte as a typed unit of amount of data.
+    pub const UNIT: Unit<Data> = Unit::new("YB", "yottabyte", to_bits, super::bits::to_yottabytes);
+
+    /// Creates a typed amount of data quantity expressed in yottabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yottabytes
+    pub fn new(value: f64) -> Quantity<Data> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed amount of data quantity in yottabytes.
+    /// # Arguments
+    /// * `quantity` - The amount of data quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Data>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Kibibytes (IEC, 1024 bytes) conversion functions
+pub mod kibibytes {
+    use crate::conversions::quantity::{Data, Quantity, Unit};
+
+    /// Converts kibibytes to bits.
+    /// # Arguments
+    /// * `value` - The amount of data in kibibytes to convert
+    pub fn to_bits(value: f64) -> f64 {
+        value * super::KIBI * super::BITS_PER_BYTE
+    }
+
+    /// Converts kibibytes to kilobits.
+    /// # Arguments
+    /// * `value` - The amount of data in kibibytes to convert
+    pub fn to_kilobits(value: f64) -> f64 {
+        super::bits::to_kilobits(to_bits(value))
+    }
+
+    /// Converts kibibytes to megabits.
+    /// # Arguments
+    /// * `value` - The amount of data in kibibytes to convert
+    pub fn to_megabits(value: f64) -> f64 {
+        super::bits::to_megabits(to_bits(value))
+    }
+
+    /// Converts kibibytes to gigabits.
+    /// # Arguments
+    /// * `value` - The amount of data in kibibytes to convert
+    pub fn to_gigabits(value: f64) -> f64 {
+        super::bits::to_gigabits(to_bits(value))
+    }
+
+    /// Converts kibibytes to bytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kibibytes to convert
+    pub fn to_bytes(value: f64) -> f64 {
+        super::bits::to_bytes(to_bits(value))
+    }
+
+    /// Converts kibibytes to kilobytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kibibytes to convert
+    pub fn to_kilobytes(value: f64) -> f64 {
+        super::bits::to_kilobytes(to_bits(value))
+    }
+
+    /// Converts kibibytes to megabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kibibytes to convert
+    pub fn to_megabytes(value: f64) -> f64 {
+        super::bits::to_megabytes(to_bits(value))
+    }
+
+    /// Converts kibibytes to gigabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kibibytes to convert
+    pub fn to_gigabytes(value: f64) -> f64 {
+        super::bits::to_gigabytes(to_bits(value))
+    }
+
+    /// Converts kibibytes to terabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kibibytes to convert
+    pub fn to_terabytes(value: f64) -> f64 {
+        super::bits::to_terabytes(to_bits(value))
+    }
+
+    /// Converts kibibytes to petabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kibibytes to convert
+    pub fn to_petabytes(value: f64) -> f64 {
+        super::bits::to_petabytes(to_bits(value))
+    }
+
+    /// Converts kibibytes to exabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kibibytes to convert
+    pub fn to_exabytes(value: f64) -> f64 {
+        super::bits::to_exabytes(to_bits(value))
+    }
+
+    /// Converts kibibytes to zettabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kibibytes to convert
+    pub fn to_zettabytes(value: f64) -> f64 {
+        super::bits::to_zettabytes(to_bits(value))
+    }
+
+    /// Converts kibibytes to yottabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kibibytes to convert
+    pub fn to_yottabytes(value: f64) -> f64 {
+        super::bits::to_yottabytes(to_bits(value))
+    }
+
+    /// Converts kibibytes to mebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kibibytes to convert
+    pub fn to_mebibytes(value: f64) -> f64 {
+        super::bits::to_mebibytes(to_bits(value))
+    }
+
+    /// Converts kibibytes to gibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kibibytes to convert
+    pub fn to_gibibytes(value: f64) -> f64 {
+        super::bits::to_gibibytes(to_bits(value))
+    }
+
+    /// Converts kibibytes to tebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kibibytes to convert
+    pub fn to_tebibytes(value: f64) -> f64 {
+        super::bits::to_tebibytes(to_bits(value))
+    }
+
+    /// Converts kibibytes to pebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kibibytes to convert
+    pub fn to_pebibytes(value: f64) -> f64 {
+        super::bits::to_pebibytes(to_bits(value))
+    }
+
+    /// Converts kibibytes to exbibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kibibytes to convert
+    pub fn to_exbibytes(value: f64) -> f64 {
+        super::bits::to_exbibytes(to_bits(value))
+    }
+
+    /// Converts kibibytes to zebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kibibytes to convert
+    pub fn to_zebibytes(value: f64) -> f64 {
+        super::bits::to_zebibytes(to_bits(value))
+    }
+
+    /// Converts kibibytes to yobibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kibibytes to convert
+    pub fn to_yobibytes(value: f64) -> f64 {
+        super::bits::to_yobibytes(to_bits(value))
+    }
+
+    /// The kibibyte as a typed unit of amount of data.
+    pub const UNIT: Unit<Data> = Unit::new("KiB", "kibibyte", to_bits, super::bits::to_kibibytes);
+
+    /// Creates a typed amount of data quantity expressed in kibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in kibibytes
+    pub fn new(value: f64) -> Quantity<Data> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed amount of data quantity in kibibytes.
+    /// # Arguments
+    /// * `quantity` - The amount of data quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Data>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Mebibytes (IEC, 1024² bytes) conversion functions
+pub mod mebibytes {
+    use crate::conversions::quantity::{Data, Quantity, Unit};
+
+    /// Converts mebibytes to bits.
+    /// # Arguments
+    /// * `value` - The amount of data in mebibytes to convert
+    pub fn to_bits(value: f64) -> f64 {
+        value * super::MEBI * super::BITS_PER_BYTE
+    }
+
+    /// Converts mebibytes to kilobits.
+    /// # Arguments
+    /// * `value` - The amount of data in mebibytes to convert
+    pub fn to_kilobits(value: f64) -> f64 {
+        super::bits::to_kilobits(to_bits(value))
+    }
+
+    /// Converts mebibytes to megabits.
+    /// # Arguments
+    /// * `value` - The amount of data in mebibytes to convert
+    pub fn to_megabits(value: f64) -> f64 {
+        super::bits::to_megabits(to_bits(value))
+    }
+
+    /// Converts mebibytes to gigabits.
+    /// # Arguments
+    /// * `value` - The amount of data in mebibytes to convert
+    pub fn to_gigabits(value: f64) -> f64 {
+        super::bits::to_gigabits(to_bits(value))
+    }
+
+    /// Converts mebibytes to bytes.
+    /// # Arguments
+    /// * `value` - The amount of data in mebibytes to convert
+    pub fn to_bytes(value: f64) -> f64 {
+        super::bits::to_bytes(to_bits(value))
+    }
+
+    /// Converts mebibytes to kilobytes.
+    /// # Arguments
+    /// * `value` - The amount of data in mebibytes to convert
+    pub fn to_kilobytes(value: f64) -> f64 {
+        super::bits::to_kilobytes(to_bits(value))
+    }
+
+    /// Converts mebibytes to megabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in mebibytes to convert
+    pub fn to_megabytes(value: f64) -> f64 {
+        super::bits::to_megabytes(to_bits(value))
+    }
+
+    /// Converts mebibytes to gigabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in mebibytes to convert
+    pub fn to_gigabytes(value: f64) -> f64 {
+        super::bits::to_gigabytes(to_bits(value))
+    }
+
+    /// Converts mebibytes to terabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in mebibytes to convert
+    pub fn to_terabytes(value: f64) -> f64 {
+        super::bits::to_terabytes(to_bits(value))
+    }
+
+    /// Converts mebibytes to petabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in mebibytes to convert
+    pub fn to_petabytes(value: f64) -> f64 {
+        super::bits::to_petabytes(to_bits(value))
+    }
+
+    /// Converts mebibytes to exabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in mebibytes to convert
+    pub fn to_exabytes(value: f64) -> f64 {
+        super::bits::to_exabytes(to_bits(value))
+    }
+
+    /// Converts mebibytes to zettabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in mebibytes to convert
+    pub fn to_zettabytes(value: f64) -> f64 {
+        super::bits::to_zettabytes(to_bits(value))
+    }
+
+    /// Converts mebibytes to yottabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in mebibytes to convert
+    pub fn to_yottabytes(value: f64) -> f64 {
+        super::bits::to_yottabytes(to_bits(value))
+    }
+
+    /// Converts mebibytes to kibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in mebibytes to convert
+    pub fn to_kibibytes(value: f64) -> f64 {
+        super::bits::to_kibibytes(to_bits(value))
+    }
+
+    /// Converts mebibytes to gibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in mebibytes to convert
+    pub fn to_gibibytes(value: f64) -> f64 {
+        super::bits::to_gibibytes(to_bits(value))
+    }
+
+    /// Converts mebibytes to tebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in mebibytes to convert
+    pub fn to_tebibytes(value: f64) -> f64 {
+        super::bits::to_tebibytes(to_bits(value))
+    }
+
+    /// Converts mebibytes to pebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in mebibytes to convert
+    pub fn to_pebibytes(value: f64) -> f64 {
+        super::bits::to_pebibytes(to_bits(value))
+    }
+
+    /// Converts mebibytes to exbibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in mebibytes to convert
+    pub fn to_exbibytes(value: f64) -> f64 {
+        super::bits::to_exbibytes(to_bits(value))
+    }
+
+    /// Converts mebibytes to zebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in mebibytes to convert
+    pub fn to_zebibytes(value: f64) -> f64 {
+        super::bits::to_zebibytes(to_bits(value))
+    }
+
+    /// Converts mebibytes to yobibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in mebibytes to convert
+    pub fn to_yobibytes(value: f64) -> f64 {
+        super::bits::to_yobibytes(to_bits(value))
+    }
+
+    /// The mebibyte as a typed unit of amount of data.
+    pub const UNIT: Unit<Data> = Unit::new("MiB", "mebibyte", to_bits, super::bits::to_mebibytes);
+
+    /// Creates a typed amount of data quantity expressed in mebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in mebibytes
+    pub fn new(value: f64) -> Quantity<Data> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed amount of data quantity in mebibytes.
+    /// # Arguments
+    /// * `quantity` - The amount of data quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Data>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Gibibytes (IEC, 1024³ bytes) conversion functions
+pub mod gibibytes {
+    use crate::conversions::quantity::{Data, Quantity, Unit};
+
+    /// Converts gibibytes to bits.
+    /// # Arguments
+    /// * `value` - The amount of data in gibibytes to convert
+    pub fn to_bits(value: f64) -> f64 {
+        value * super::GIBI * super::BITS_PER_BYTE
+    }
+
+    /// Converts gibibytes to kilobits.
+    /// # Arguments
+    /// * `value` - The amount of data in gibibytes to convert
+    pub fn to_kilobits(value: f64) -> f64 {
+        super::bits::to_kilobits(to_bits(value))
+    }
+
+    /// Converts gibibytes to megabits.
+    /// # Arguments
+    /// * `value` - The amount of data in gibibytes to convert
+    pub fn to_megabits(value: f64) -> f64 {
+        super::bits::to_megabits(to_bits(value))
+    }
+
+    /// Converts gibibytes to gigabits.
+    /// # Arguments
+    /// * `value` - The amount of data in gibibytes to convert
+    pub fn to_gigabits(value: f64) -> f64 {
+        super::bits::to_gigabits(to_bits(value))
+    }
+
+    /// Converts gibibytes to bytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gibibytes to convert
+    pub fn to_bytes(value: f64) -> f64 {
+        super::bits::to_bytes(to_bits(value))
+    }
+
+    /// Converts gibibytes to kilobytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gibibytes to convert
+    pub fn to_kilobytes(value: f64) -> f64 {
+        super::bits::to_kilobytes(to_bits(value))
+    }
+
+    /// Converts gibibytes to megabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gibibytes to convert
+    pub fn to_megabytes(value: f64) -> f64 {
+        super::bits::to_megabytes(to_bits(value))
+    }
+
+    /// Converts gibibytes to gigabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gibibytes to convert
+    pub fn to_gigabytes(value: f64) -> f64 {
+        super::bits::to_gigabytes(to_bits(value))
+    }
+
+    /// Converts gibibytes to terabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gibibytes to convert
+    pub fn to_terabytes(value: f64) -> f64 {
+        super::bits::to_terabytes(to_bits(value))
+    }
+
+    /// Converts gibibytes to petabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gibibytes to convert
+    pub fn to_petabytes(value: f64) -> f64 {
+        super::bits::to_petabytes(to_bits(value))
+    }
+
+    /// Converts gibibytes to exabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gibibytes to convert
+    pub fn to_exabytes(value: f64) -> f64 {
+        super::bits::to_exabytes(to_bits(value))
+    }
+
+    /// Converts gibibytes to zettabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gibibytes to convert
+    pub fn to_zettabytes(value: f64) -> f64 {
+        super::bits::to_zettabytes(to_bits(value))
+    }
+
+    /// Converts gibibytes to yottabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gibibytes to convert
+    pub fn to_yottabytes(value: f64) -> f64 {
+        super::bits::to_yottabytes(to_bits(value))
+    }
+
+    /// Converts gibibytes to kibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gibibytes to convert
+    pub fn to_kibibytes(value: f64) -> f64 {
+        super::bits::to_kibibytes(to_bits(value))
+    }
+
+    /// Converts gibibytes to mebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gibibytes to convert
+    pub fn to_mebibytes(value: f64) -> f64 {
+        super::bits::to_mebibytes(to_bits(value))
+    }
+
+    /// Converts gibibytes to tebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gibibytes to convert
+    pub fn to_tebibytes(value: f64) -> f64 {
+        super::bits::to_tebibytes(to_bits(value))
+    }
+
+    /// Converts gibibytes to pebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gibibytes to convert
+    pub fn to_pebibytes(value: f64) -> f64 {
+        super::bits::to_pebibytes(to_bits(value))
+    }
+
+    /// Converts gibibytes to exbibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gibibytes to convert
+    pub fn to_exbibytes(value: f64) -> f64 {
+        super::bits::to_exbibytes(to_bits(value))
+    }
+
+    /// Converts gibibytes to zebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gibibytes to convert
+    pub fn to_zebibytes(value: f64) -> f64 {
+        super::bits::to_zebibytes(to_bits(value))
+    }
+
+    /// Converts gibibytes to yobibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gibibytes to convert
+    pub fn to_yobibytes(value: f64) -> f64 {
+        super::bits::to_yobibytes(to_bits(value))
+    }
+
+    /// The gibibyte as a typed unit of amount of data.
+    pub const UNIT: Unit<Data> = Unit::new("GiB", "gibibyte", to_bits, super::bits::to_gibibytes);
+
+    /// Creates a typed amount of data quantity expressed in gibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in gibibytes
+    pub fn new(value: f64) -> Quantity<Data> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed amount of data quantity in gibibytes.
+    /// # Arguments
+    /// * `quantity` - The amount of data quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Data>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Tebibytes (IEC, 1024⁴ bytes) conversion functions
+pub mod tebibytes {
+    use crate::conversions::quantity::{Data, Quantity, Unit};
+
+    /// Converts tebibytes to bits.
+    /// # Arguments
+    /// * `value` - The amount of data in tebibytes to convert
+    pub fn to_bits(value: f64) -> f64 {
+        value * super::TEBI * super::BITS_PER_BYTE
+    }
+
+    /// Converts tebibytes to kilobits.
+    /// # Arguments
+    /// * `value` - The amount of data in tebibytes to convert
+    pub fn to_kilobits(value: f64) -> f64 {
+        super::bits::to_kilobits(to_bits(value))
+    }
+
+    /// Converts tebibytes to megabits.
+    /// # Arguments
+    /// * `value` - The amount of data in tebibytes to convert
+    pub fn to_megabits(value: f64) -> f64 {
+        super::bits::to_megabits(to_bits(value))
+    }
+
+    /// Converts tebibytes to gigabits.
+    /// # Arguments
+    /// * `value` - The amount of data in tebibytes to convert
+    pub fn to_gigabits(value: f64) -> f64 {
+        super::bits::to_gigabits(to_bits(value))
+    }
+
+    /// Converts tebibytes to bytes.
+    /// # Arguments
+    /// * `value` - The amount of data in tebibytes to convert
+    pub fn to_bytes(value: f64) -> f64 {
+        super::bits::to_bytes(to_bits(value))
+    }
+
+    /// Converts tebibytes to kilobytes.
+    /// # Arguments
+    /// * `value` - The amount of data in tebibytes to convert
+    pub fn to_kilobytes(value: f64) -> f64 {
+        super::bits::to_kilobytes(to_bits(value))
+    }
+
+    /// Converts tebibytes to megabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in tebibytes to convert
+    pub fn to_megabytes(value: f64) -> f64 {
+        super::bits::to_megabytes(to_bits(value))
+    }
+
+    /// Converts tebibytes to gigabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in tebibytes to convert
+    pub fn to_gigabytes(value: f64) -> f64 {
+        super::bits::to_gigabytes(to_bits(value))
+    }
+
+    /// Converts tebibytes to terabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in tebibytes to convert
+    pub fn to_terabytes(value: f64) -> f64 {
+        super::bits::to_terabytes(to_bits(value))
+    }
+
+    /// Converts tebibytes to petabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in tebibytes to convert
+    pub fn to_petabytes(value: f64) -> f64 {
+        super::bits::to_petabytes(to_bits(value))
+    }
+
+    /// Converts tebibytes to exabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in tebibytes to convert
+    pub fn to_exabytes(value: f64) -> f64 {
+        super::bits::to_exabytes(to_bits(value))
+    }
+
+    /// Converts tebibytes to zettabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in tebibytes to convert
+    pub fn to_zettabytes(value: f64) -> f64 {
+        super::bits::to_zettabytes(to_bits(value))
+    }
+
+    /// Converts tebibytes to yottabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in tebibytes to convert
+    pub fn to_yottabytes(value: f64) -> f64 {
+        super::bits::to_yottabytes(to_bits(value))
+    }
+
+    /// Converts tebibytes to kibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in tebibytes to convert
+    pub fn to_kibibytes(value: f64) -> f64 {
+        super::bits::to_kibibytes(to_bits(value))
+    }
+
+    /// Converts tebibytes to mebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in tebibytes to convert
+    pub fn to_mebibytes(value: f64) -> f64 {
+        super::bits::to_mebibytes(to_bits(value))
+    }
+
+    /// Converts tebibytes to gibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in tebibytes to convert
+    pub fn to_gibibytes(value: f64) -> f64 {
+        super::bits::to_gibibytes(to_bits(value))
+    }
+
+    /// Converts tebibytes to pebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in tebibytes to convert
+    pub fn to_pebibytes(value: f64) -> f64 {
+        super::bits::to_pebibytes(to_bits(value))
+    }
+
+    /// Converts tebibytes to exbibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in tebibytes to convert
+    pub fn to_exbibytes(value: f64) -> f64 {
+        super::bits::to_exbibytes(to_bits(value))
+    }
+
+    /// Converts tebibytes to zebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in tebibytes to convert
+    pub fn to_zebibytes(value: f64) -> f64 {
+        super::bits::to_zebibytes(to_bits(value))
+    }
+
+    /// Converts tebibytes to yobibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in tebibytes to convert
+    pub fn to_yobibytes(value: f64) -> f64 {
+        super::bits::to_yobibytes(to_bits(value))
+    }
+
+    /// The tebibyte as a typed unit of amount of data.
+    pub const UNIT: Unit<Data> = Unit::new("TiB", "tebibyte", to_bits, super::bits::to_tebibytes);
+
+    /// Creates a typed amount of data quantity expressed in tebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in tebibytes
+    pub fn new(value: f64) -> Quantity<Data> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed amount of data quantity in tebibytes.
+    /// # Arguments
+    /// * `quantity` - The amount of data quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Data>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Pebibytes (IEC, 1024⁵ bytes) conversion functions
+pub mod pebibytes {
+    use crate::conversions::quantity::{Data, Quantity, Unit};
+
+    /// Converts pebibytes to bits.
+    /// # Arguments
+    /// * `value` - The amount of data in pebibytes to convert
+    pub fn to_bits(value: f64) -> f64 {
+        value * super::PEBI * super::BITS_PER_BYTE
+    }
+
+    /// Converts pebibytes to kilobits.
+    /// # Arguments
+    /// * `value` - The amount of data in pebibytes to convert
+    pub fn to_kilobits(value: f64) -> f64 {
+        super::bits::to_kilobits(to_bits(value))
+    }
+
+    /// Converts pebibytes to megabits.
+    /// # Arguments
+    /// * `value` - The amount of data in pebibytes to convert
+    pub fn to_megabits(value: f64) -> f64 {
+        super::bits::to_megabits(to_bits(value))
+    }
+
+    /// Converts pebibytes to gigabits.
+    /// # Arguments
+    /// * `value` - The amount of data in pebibytes to convert
+    pub fn to_gigabits(value: f64) -> f64 {
+        super::bits::to_gigabits(to_bits(value))
+    }
+
+    /// Converts pebibytes to bytes.
+    /// # Arguments
+    /// * `value` - The amount of data in pebibytes to convert
+    pub fn to_bytes(value: f64) -> f64 {
+        super::bits::to_bytes(to_bits(value))
+    }
+
+    /// Converts pebibytes to kilobytes.
+    /// # Arguments
+    /// * `value` - The amount of data in pebibytes to convert
+    pub fn to_kilobytes(value: f64) -> f64 {
+        super::bits::to_kilobytes(to_bits(value))
+    }
+
+    /// Converts pebibytes to megabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in pebibytes to convert
+    pub fn to_megabytes(value: f64) -> f64 {
+        super::bits::to_megabytes(to_bits(value))
+    }
+
+    /// Converts pebibytes to gigabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in pebibytes to convert
+    pub fn to_gigabytes(value: f64) -> f64 {
+        super::bits::to_gigabytes(to_bits(value))
+    }
+
+    /// Converts pebibytes to terabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in pebibytes to convert
+    pub fn to_terabytes(value: f64) -> f64 {
+        super::bits::to_terabytes(to_bits(value))
+    }
+
+    /// Converts pebibytes to petabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in pebibytes to convert
+    pub fn to_petabytes(value: f64) -> f64 {
+        super::bits::to_petabytes(to_bits(value))
+    }
+
+    /// Converts pebibytes to exabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in pebibytes to convert
+    pub fn to_exabytes(value: f64) -> f64 {
+        super::bits::to_exabytes(to_bits(value))
+    }
+
+    /// Converts pebibytes to zettabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in pebibytes to convert
+    pub fn to_zettabytes(value: f64) -> f64 {
+        super::bits::to_zettabytes(to_bits(value))
+    }
+
+    /// Converts pebibytes to yottabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in pebibytes to convert
+    pub fn to_yottabytes(value: f64) -> f64 {
+        super::bits::to_yottabytes(to_bits(value))
+    }
+
+    /// Converts pebibytes to kibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in pebibytes to convert
+    pub fn to_kibibytes(value: f64) -> f64 {
+        super::bits::to_kibibytes(to_bits(value))
+    }
+
+    /// Converts pebibytes to mebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in pebibytes to convert
+    pub fn to_mebibytes(value: f64) -> f64 {
+        super::bits::to_mebibytes(to_bits(value))
+    }
+
+    /// Converts pebibytes to gibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in pebibytes to convert
+    pub fn to_gibibytes(value: f64) -> f64 {
+        super::bits::to_gibibytes(to_bits(value))
+    }
+
+    /// Converts pebibytes to tebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in pebibytes to convert
+    pub fn to_tebibytes(value: f64) -> f64 {
+        super::bits::to_tebibytes(to_bits(value))
+    }
+
+    /// Converts pebibytes to exbibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in pebibytes to convert
+    pub fn to_exbibytes(value: f64) -> f64 {
+        super::bits::to_exbibytes(to_bits(value))
+    }
+
+    /// Converts pebibytes to zebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in pebibytes to convert
+    pub fn to_zebibytes(value: f64) -> f64 {
+        super::bits::to_zebibytes(to_bits(value))
+    }
+
+    /// Converts pebibytes to yobibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in pebibytes to convert
+    pub fn to_yobibytes(value: f64) -> f64 {
+        super::bits::to_yobibytes(to_bits(value))
+    }
+
+    /// The pebibyte as a typed unit of amount of data.
+    pub const UNIT: Unit<Data> = Unit::new("PiB", "pebibyte", to_bits, super::bits::to_pebibytes);
+
+    /// Creates a typed amount of data quantity expressed in pebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in pebibytes
+    pub fn new(value: f64) -> Quantity<Data> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed amount of data quantity in pebibytes.
+    /// # Arguments
+    /// * `quantity` - The amount of data quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Data>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Exbibytes (IEC, 1024⁶ bytes) conversion functions
+pub mod exbibytes {
+    use crate::conversions::quantity::{Data, Quantity, Unit};
+
+    /// Converts exbibytes to bits.
+    /// # Arguments
+    /// * `value` - The amount of data in exbibytes to convert
+    pub fn to_bits(value: f64) -> f64 {
+        value * super::EXBI * super::BITS_PER_BYTE
+    }
+
+    /// Converts exbibytes to kilobits.
+    /// # Arguments
+    /// * `value` - The amount of data in exbibytes to convert
+    pub fn to_kilobits(value: f64) -> f64 {
+        super::bits::to_kilobits(to_bits(value))
+    }
+
+    /// Converts exbibytes to megabits.
+    /// # Arguments
+    /// * `value` - The amount of data in exbibytes to convert
+    pub fn to_megabits(value: f64) -> f64 {
+        super::bits::to_megabits(to_bits(value))
+    }
+
+    /// Converts exbibytes to gigabits.
+    /// # Arguments
+    /// * `value` - The amount of data in exbibytes to convert
+    pub fn to_gigabits(value: f64) -> f64 {
+        super::bits::to_gigabits(to_bits(value))
+    }
+
+    /// Converts exbibytes to bytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exbibytes to convert
+    pub fn to_bytes(value: f64) -> f64 {
+        super::bits::to_bytes(to_bits(value))
+    }
+
+    /// Converts exbibytes to kilobytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exbibytes to convert
+    pub fn to_kilobytes(value: f64) -> f64 {
+        super::bits::to_kilobytes(to_bits(value))
+    }
+
+    /// Converts exbibytes to megabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exbibytes to convert
+    pub fn to_megabytes(value: f64) -> f64 {
+        super::bits::to_megabytes(to_bits(value))
+    }
+
+    /// Converts exbibytes to gigabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exbibytes to convert
+    pub fn to_gigabytes(value: f64) -> f64 {
+        super::bits::to_gigabytes(to_bits(value))
+    }
+
+    /// Converts exbibytes to terabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exbibytes to convert
+    pub fn to_terabytes(value: f64) -> f64 {
+        super::bits::to_terabytes(to_bits(value))
+    }
+
+    /// Converts exbibytes to petabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exbibytes to convert
+    pub fn to_petabytes(value: f64) -> f64 {
+        super::bits::to_petabytes(to_bits(value))
+    }
+
+    /// Converts exbibytes to exabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exbibytes to convert
+    pub fn to_exabytes(value: f64) -> f64 {
+        super::bits::to_exabytes(to_bits(value))
+    }
+
+    /// Converts exbibytes to zettabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exbibytes to convert
+    pub fn to_zettabytes(value: f64) -> f64 {
+        super::bits::to_zettabytes(to_bits(value))
+    }
+
+    /// Converts exbibytes to yottabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exbibytes to convert
+    pub fn to_yottabytes(value: f64) -> f64 {
+        super::bits::to_yottabytes(to_bits(value))
+    }
+
+    /// Converts exbibytes to kibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exbibytes to convert
+    pub fn to_kibibytes(value: f64) -> f64 {
+        super::bits::to_kibibytes(to_bits(value))
+    }
+
+    /// Converts exbibytes to mebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exbibytes to convert
+    pub fn to_mebibytes(value: f64) -> f64 {
+        super::bits::to_mebibytes(to_bits(value))
+    }
+
+    /// Converts exbibytes to gibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exbibytes to convert
+    pub fn to_gibibytes(value: f64) -> f64 {
+        super::bits::to_gibibytes(to_bits(value))
+    }
+
+    /// Converts exbibytes to tebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exbibytes to convert
+    pub fn to_tebibytes(value: f64) -> f64 {
+        super::bits::to_tebibytes(to_bits(value))
+    }
+
+    /// Converts exbibytes to pebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exbibytes to convert
+    pub fn to_pebibytes(value: f64) -> f64 {
+        super::bits::to_pebibytes(to_bits(value))
+    }
+
+    /// Converts exbibytes to zebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exbibytes to convert
+    pub fn to_zebibytes(value: f64) -> f64 {
+        super::bits::to_zebibytes(to_bits(value))
+    }
+
+    /// Converts exbibytes to yobibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exbibytes to convert
+    pub fn to_yobibytes(value: f64) -> f64 {
+        super::bits::to_yobibytes(to_bits(value))
+    }
+
+    /// The exbibyte as a typed unit of amount of data.
+    pub const UNIT: Unit<Data> = Unit::new("EiB", "exbibyte", to_bits, super::bits::to_exbibytes);
+
+    /// Creates a typed amount of data quantity expressed in exbibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in exbibytes
+    pub fn new(value: f64) -> Quantity<Data> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed amount of data quantity in exbibytes.
+    /// # Arguments
+    /// * `quantity` - The amount of data quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Data>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Zebibytes (IEC, 1024⁷ bytes) conversion functions
+pub mod zebibytes {
+    use crate::conversions::quantity::{Data, Quantity, Unit};
+
+    /// Converts zebibytes to bits.
+    /// # Arguments
+    /// * `value` - The amount of data in zebibytes to convert
+    pub fn to_bits(value: f64) -> f64 {
+        value * super::ZEBI * super::BITS_PER_BYTE
+    }
+
+    /// Converts zebibytes to kilobits.
+    /// # Arguments
+    /// * `value` - The amount of data in zebibytes to convert
+    pub fn to_kilobits(value: f64) -> f64 {
+        super::bits::to_kilobits(to_bits(value))
+    }
+
+    /// Converts zebibytes to megabits.
+    /// # Arguments
+    /// * `value` - The amount of data in zebibytes to convert
+    pub fn to_megabits(value: f64) -> f64 {
+        super::bits::to_megabits(to_bits(value))
+    }
+
+    /// Converts zebibytes to gigabits.
+    /// # Arguments
+    /// * `value` - The amount of data in zebibytes to convert
+    pub fn to_gigabits(value: f64) -> f64 {
+        super::bits::to_gigabits(to_bits(value))
+    }
+
+    /// Converts zebibytes to bytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zebibytes to convert
+    pub fn to_bytes(value: f64) -> f64 {
+        super::bits::to_bytes(to_bits(value))
+    }
+
+    /// Converts zebibytes to kilobytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zebibytes to convert
+    pub fn to_kilobytes(value: f64) -> f64 {
+        super::bits::to_kilobytes(to_bits(value))
+    }
+
+    /// Converts zebibytes to megabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zebibytes to convert
+    pub fn to_megabytes(value: f64) -> f64 {
+        super::bits::to_megabytes(to_bits(value))
+    }
+
+    /// Converts zebibytes to gigabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zebibytes to convert
+    pub fn to_gigabytes(value: f64) -> f64 {
+        super::bits::to_gigabytes(to_bits(value))
+    }
+
+    /// Converts zebibytes to terabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zebibytes to convert
+    pub fn to_terabytes(value: f64) -> f64 {
+        super::bits::to_terabytes(to_bits(value))
+    }
+
+    /// Converts zebibytes to petabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zebibytes to convert
+    pub fn to_petabytes(value: f64) -> f64 {
+        super::bits::to_petabytes(to_bits(value))
+    }
+
+    /// Converts zebibytes to exabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zebibytes to convert
+    pub fn to_exabytes(value: f64) -> f64 {
+        super::bits::to_exabytes(to_bits(value))
+    }
+
+    /// Converts zebibytes to zettabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zebibytes to convert
+    pub fn to_zettabytes(value: f64) -> f64 {
+        super::bits::to_zettabytes(to_bits(value))
+    }
+
+    /// Converts zebibytes to yottabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zebibytes to convert
+    pub fn to_yottabytes(value: f64) -> f64 {
+        super::bits::to_yottabytes(to_bits(value))
+    }
+
+    /// Converts zebibytes to kibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zebibytes to convert
+    pub fn to_kibibytes(value: f64) -> f64 {
+        super::bits::to_kibibytes(to_bits(value))
+    }
+
+    /// Converts zebibytes to mebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zebibytes to convert
+    pub fn to_mebibytes(value: f64) -> f64 {
+        super::bits::to_mebibytes(to_bits(value))
+    }
+
+    /// Converts zebibytes to gibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zebibytes to convert
+    pub fn to_gibibytes(value: f64) -> f64 {
+        super::bits::to_gibibytes(to_bits(value))
+    }
+
+    /// Converts zebibytes to tebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zebibytes to convert
+    pub fn to_tebibytes(value: f64) -> f64 {
+        super::bits::to_tebibytes(to_bits(value))
+    }
+
+    /// Converts zebibytes to pebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zebibytes to convert
+    pub fn to_pebibytes(value: f64) -> f64 {
+        super::bits::to_pebibytes(to_bits(value))
+    }
+
+    /// Converts zebibytes to exbibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zebibytes to convert
+    pub fn to_exbibytes(value: f64) -> f64 {
+        super::bits::to_exbibytes(to_bits(value))
+    }
+
+    /// Converts zebibytes to yobibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zebibytes to convert
+    pub fn to_yobibytes(value: f64) -> f64 {
+        super::bits::to_yobibytes(to_bits(value))
+    }
+
+    /// The zebibyte as a typed unit of amount of data.
+    pub const UNIT: Unit<Data> = Unit::new("ZiB", "zebibyte", to_bits, super::bits::to_zebibytes);
+
+    /// Creates a typed amount of data quantity expressed in zebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in zebibytes
+    pub fn new(value: f64) -> Quantity<Data> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed amount of data quantity in zebibytes.
+    /// # Arguments
+    /// * `quantity` - The amount of data quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Data>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Yobibytes (IEC, 1024⁸ bytes) conversion functions
+pub mod yobibytes {
+    use crate::conversions::quantity::{Data, Quantity, Unit};
+
+    /// Converts yobibytes to bits.
+    /// # Arguments
+    /// * `value` - The amount of data in yobibytes to convert
+    pub fn to_bits(value: f64) -> f64 {
+        value * super::YOBI * super::BITS_PER_BYTE
+    }
+
+    /// Converts yobibytes to kilobits.
+    /// # Arguments
+    /// * `value` - The amount of data in yobibytes to convert
+    pub fn to_kilobits(value: f64) -> f64 {
+        super::bits::to_kilobits(to_bits(value))
+    }
+
+    /// Converts yobibytes to megabits.
+    /// # Arguments
+    /// * `value` - The amount of data in yobibytes to convert
+    pub fn to_megabits(value: f64) -> f64 {
+        super::bits::to_megabits(to_bits(value))
+    }
+
+    /// Converts yobibytes to gigabits.
+    /// # Arguments
+    /// * `value` - The amount of data in yobibytes to convert
+    pub fn to_gigabits(value: f64) -> f64 {
+        super::bits::to_gigabits(to_bits(value))
+    }
+
+    /// Converts yobibytes to bytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yobibytes to convert
+    pub fn to_bytes(value: f64) -> f64 {
+        super::bits::to_bytes(to_bits(value))
+    }
+
+    /// Converts yobibytes to kilobytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yobibytes to convert
+    pub fn to_kilobytes(value: f64) -> f64 {
+        super::bits::to_kilobytes(to_bits(value))
+    }
+
+    /// Converts yobibytes to megabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yobibytes to convert
+    pub fn to_megabytes(value: f64) -> f64 {
+        super::bits::to_megabytes(to_bits(value))
+    }
+
+    /// Converts yobibytes to gigabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yobibytes to convert
+    pub fn to_gigabytes(value: f64) -> f64 {
+        super::bits::to_gigabytes(to_bits(value))
+    }
+
+    /// Converts yobibytes to terabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yobibytes to convert
+    pub fn to_terabytes(value: f64) -> f64 {
+        super::bits::to_terabytes(to_bits(value))
+    }
+
+    /// Converts yobibytes to petabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yobibytes to convert
+    pub fn to_petabytes(value: f64) -> f64 {
+        super::bits::to_petabytes(to_bits(value))
+    }
+
+    /// Converts yobibytes to exabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yobibytes to convert
+    pub fn to_exabytes(value: f64) -> f64 {
+        super::bits::to_exabytes(to_bits(value))
+    }
+
+    /// Converts yobibytes to zettabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yobibytes to convert
+    pub fn to_zettabytes(value: f64) -> f64 {
+        super::bits::to_zettabytes(to_bits(value))
+    }
+
+    /// Converts yobibytes to yottabytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yobibytes to convert
+    pub fn to_yottabytes(value: f64) -> f64 {
+        super::bits::to_yottabytes(to_bits(value))
+    }
+
+    /// Converts yobibytes to kibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yobibytes to convert
+    pub fn to_kibibytes(value: f64) -> f64 {
+        super::bits::to_kibibytes(to_bits(value))
+    }
+
+    /// Converts yobibytes to mebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yobibytes to convert
+    pub fn to_mebibytes(value: f64) -> f64 {
+        super::bits::to_mebibytes(to_bits(value))
+    }
+
+    /// Converts yobibytes to gibibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yobibytes to convert
+    pub fn to_gibibytes(value: f64) -> f64 {
+        super::bits::to_gibibytes(to_bits(value))
+    }
+
+    /// Converts yobibytes to tebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yobibytes to convert
+    pub fn to_tebibytes(value: f64) -> f64 {
+        super::bits::to_tebibytes(to_bits(value))
+    }
+
+    /// Converts yobibytes to pebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yobibytes to convert
+    pub fn to_pebibytes(value: f64) -> f64 {
+        super::bits::to_pebibytes(to_bits(value))
+    }
+
+    /// Converts yobibytes to exbibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yobibytes to convert
+    pub fn to_exbibytes(value: f64) -> f64 {
+        super::bits::to_exbibytes(to_bits(value))
+    }
+
+    /// Converts yobibytes to zebibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yobibytes to convert
+    pub fn to_zebibytes(value: f64) -> f64 {
+        super::bits::to_zebibytes(to_bits(value))
+    }
+
+    /// The yobibyte as a typed unit of amount of data.
+    pub const UNIT: Unit<Data> = Unit::new("YiB", "yobibyte", to_bits, super::bits::to_yobibytes);
+
+    /// Creates a typed amount of data quantity expressed in yobibytes.
+    /// # Arguments
+    /// * `value` - The amount of data in yobibytes
+    pub fn new(value: f64) -> Quantity<Data> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed amount of data quantity in yobibytes.
+    /// # Arguments
+    /// * `quantity` - The amount of data quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Data>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// The family of prefixes [`format_data`] picks the best unit from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrefixConvention {
+    /// SI prefixes, powers of 1000: B, kB, MB, GB, TB, PB, EB, ZB, YB
+    Decimal,
+    /// IEC prefixes, powers of 1024: B, KiB, MiB, GiB, TiB, PiB, EiB, ZiB, YiB
+    Binary,
+}
+
+impl PrefixConvention {
+    /// The byte units of the convention, from largest to smallest.
+    fn units(self) -> [&'static str; 9] {
+        match self {
+            PrefixConvention::Decimal => ["YB", "ZB", "EB", "PB", "TB", "GB", "MB", "kB", "B"],
+            PrefixConvention::Binary => {
+                ["YiB", "ZiB", "EiB", "PiB", "TiB", "GiB", "MiB", "KiB", "B"]
+            }
+        }
+    }
+}
+
+/// The most decimal places [`format_data`] and [`format_dms`] show; an `f64`
+/// has no meaningful digits beyond about 17.
+///
+/// [`format_dms`]: super::angle::format_dms
+pub const MAX_FORMAT_PRECISION: usize = 17;
+
+/// Formats an amount of data in the largest byte unit of a convention that
+/// keeps it at or above 1 (e.g. 1 536 000 B as `"1.46 MiB"` or `"1.54 MB"`).
+///
+/// # Arguments
+/// * `value` - The amount of data
+/// * `unit` - The unit of the amount (e.g., "B", "bit", "GiB")
+/// * `convention` - Whether to use decimal (kB, MB) or binary (KiB, MiB) prefixes
+/// * `precision` - The number of decimal places, at most [`MAX_FORMAT_PRECISION`]
+///   (larger values are clamped); whole bytes from 1 B up are shown without any
+///
+/// # Returns
+/// * `Ok(String)` - The amount and its unit, e.g. `"1.46 MiB"`
+/// * `Err(ConversionError)` - If the unit is unknown or the value is not finite
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::{format_data, PrefixConvention};
+///
+/// let binary = format_data(1_536_000.0, "B", PrefixConvention::Binary, 2).unwrap();
+/// assert_eq!(binary, "1.46 MiB");
+///
+/// let decimal = format_data(1_536_000.0, "B", PrefixConvention::Decimal, 2).unwrap();
+/// assert_eq!(decimal, "1.54 MB");
+///
+/// assert_eq!(format_data(512.0, "bit", PrefixConvention::Binary, 2).unwrap(), "64 B");
+/// ```
+pub fn format_data(
+    value: f64,
+    unit: &str,
+    convention: PrefixConvention,
+    precision: usize,
+) -> Result<String, ConversionError> {
+    let bytes = convert_data(value, unit, "B")?;
+    let precision = precision.min(MAX_FORMAT_PRECISION);
+    let scale = 10f64.powi(precision as i32);
+
+    // Pick the unit by the rounded amount, so 1023.999 KiB shows as 1.00 MiB
+    for symbol in convention.units() {
+        let amount = convert_data(bytes, "B", symbol)?;
+        if (amount.abs() * scale).round() / scale >= 1.0 {
+            return Ok(format_amount(amount, symbol, precision));
+        }
+    }
+    Ok(format_amount(bytes, "B", precision))
+}
+
+/// Formats an amount with its unit, leaving whole bytes without decimals.
+///
+/// Zero is not a whole number of bytes to leave bare, so it is shown with
+/// `precision` decimals like any amount under a byte.
+fn format_amount(amount: f64, symbol: &str, precision: usize) -> String {
+    if symbol == "B" && amount.fract() == 0.0 && amount != 0.0 {
+        format!("{} B", amount)
+    } else {
+        format!("{:.*} {}", precision, amount, symbol)
+    }
+}
+
+/// General data conversion function that accepts string unit names
+///
+/// Converts an amount of data from one unit to another using string
+/// identifiers. Symbols are case-sensitive, names are case-insensitive, and
+/// common abbreviations are supported. Decimal and binary prefixes are kept
+/// apart: spellings that could mean either (`"KB"`) or that only differ from
+/// another unit by case (`"mb"` for MB or Mb) fail with
+/// [`ConversionError::AmbiguousUnit`].
+///
+/// # Arguments
+///
+/// * `value` - The numeric value to convert
+/// * `from_unit` - The source unit (e.g., "bit", "kbit", "Mbit", "B", "kB", "MB", "GB",
+///   "TB", "PB", "EB", "ZB", "YB", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB", "ZiB", "YiB")
+/// * `to_unit` - The target unit using the same abbreviations
+///
+/// # Returns
+/// * `Ok(f64)` - The converted value
+/// * `Err(ConversionError)` - The reason the conversion is not supported
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::{convert_data, ConversionError};
+///
+/// let mebibytes = convert_data(1.0, "GiB", "MiB").unwrap();
+/// assert_eq!(mebibytes, 1024.0);
+///
+/// let megabytes = convert_data(1.0, "MiB", "MB").unwrap();
+/// assert!((megabytes - 1.048_576).abs() < 1e-12);
+///
+/// assert!(matches!(
+///     convert_data(1.0, "KB", "B"),
+///     Err(ConversionError::AmbiguousUnit { .. })
+/// ));
+/// ```
+pub fn convert_data(value: f64, from_unit: &str, to_unit: &str) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from_unit, to_unit, Category::Data)
+}
+
+/// Data units known to the [`UnitRegistry`], with bits as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new("bit", "bit", &["b", "bits"], Category::Data, 1.0),
+        UnitDef::new(
+            "kbit",
+            "kilobit",
+            &["kb", "kilobits"],
+            Category::Data,
+            kilobits::to_bits(1.0),
+        ),
+        UnitDef::new(
+            "Mbit",
+            "megabit",
+            &["Mb", "megabits"],
+            Category::Data,
+            megabits::to_bits(1.0),
+        ),
+        UnitDef::new(
+            "Gbit",
+            "gigabit",
+            &["Gb", "gigabits"],
+            Category::Data,
+            gigabits::to_bits(1.0),
+        ),
+        UnitDef::new(
+            "B",
+            "byte",
+            &["bytes", "octet", "octets"],
+            Category::Data,
+            bytes::to_bits(1.0),
+        ),
+        // "KB" is used for both 1000 and 1024 bytes, so it is registered as
+        // an alias of both and always reported as ambiguous
+        UnitDef::new(
+            "kB",
+            "kilobyte",
+            &["KB", "kilobytes"],
+            Category::Data,
+            kilobytes::to_bits(1.0),
+        ),
+        UnitDef::new(
+            "MB",
+            "megabyte",
+            &["megabytes"],
+            Category::Data,
+            megabytes::to_bits(1.0),
+        ),
+        UnitDef::new(
+            "GB",
+            "gigabyte",
+            &["gigabytes"],
+            Category::Data,
+            gigabytes::to_bits(1.0),
+        ),
+        UnitDef::new(
+            "TB",
+            "terabyte",
+            &["terabytes"],
+            Category::Data,
+            terabytes::to_bits(1.0),
+        ),
+        UnitDef::new(
+            "PB",
+            "petabyte",
+            &["petabytes"],
+            Category::Data,
+            petabytes::to_bits(1.0),
+        ),
+        UnitDef::new(
+            "EB",
+            "exabyte",
+            &["exabytes"],
+            Category::Data,
+            exabytes::to_bits(1.0),
+        ),
+        UnitDef::new(
+            "ZB",
+            "zettabyte",
+            &["zettabytes"],
+            Category::Data,
+            zettabytes::to_bits(1.0),
+        ),
+        UnitDef::new(
+            "YB",
+            "yottabyte",
+            &["yottabytes"],
+            Category::Data,
+            yottabytes::to_bits(1.0),
+        ),
+        UnitDef::new(
+            "KiB",
+            "kibibyte",
+            &["KB", "kibibytes"],
+            Category::Data,
+            kibibytes::to_bits(1.0),
+        ),
+        UnitDef::new(
+            "MiB",
+            "mebibyte",
+            &["mebibytes"],
+            Category::Data,
+            mebibytes::to_bits(1.0),
+        ),
+        UnitDef::new(
+            "GiB",
+            "gibibyte",
+            &["gibibytes"],
+            Category::Data,
+            gibibytes::to_bits(1.0),
+        ),
+        UnitDef::new(
+            "TiB",
+            "tebibyte",
+            &["tebibytes"],
+            Category::Data,
+            tebibytes::to_bits(1.0),
+        ),
+        UnitDef::new(
+            "PiB",
+            "pebibyte",
+            &["pebibytes"],
+            Category::Data,
+            pebibytes::to_bits(1.0),
+        ),
+        UnitDef::new(
+            "EiB",
+            "exbibyte",
+            &["exbibytes"],
+            Category::Data,
+            exbibytes::to_bits(1.0),
+        )
+        .with_exact_factor("1024*1024*1024*1024*1024*1024*8"),
+        UnitDef::new(
+            "ZiB",
+            "zebibyte",
+            &["zebibytes"],
+            Category::Data,
+            zebibytes::to_bits(1.0),
+        )
+        .with_exact_factor("1024*1024*1024*1024*1024*1024*1024*8"),
+        UnitDef::new(
+            "YiB",
+            "yobibyte",
+            &["yobibytes"],
+            Category::Data,
+            yobibytes::to_bits(1.0),
+        )
+        .with_exact_factor("1024*1024*1024*1024*1024*1024*1024*1024*8"),
+    ]
+}
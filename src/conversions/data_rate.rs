@@ -0,0 +1,1195 @@
+//! Data rate conversion functions organized by unit type
+//! All conversions use bits per second as the base unit, with every amount of
+//! data defined by the [`data`] module
+//!
+//! As in the `data` module, decimal rates (kB/s, MB/s) and binary rates
+//! (KiB/s, MiB/s) are never conflated.
+
+use super::category::Category;
+use super::data;
+use super::error::ConversionError;
+use super::registry::{UnitDef, UnitRegistry};
+
+/// Bits per second conversion functions
+pub mod bits_per_second {
+    use crate::conversions::quantity::{DataRate, Quantity, Unit};
+
+    /// Converts bits per second to kilobits per second.
+    /// # Arguments
+    /// * `value` - The data rate in bits per second to convert
+    pub fn to_kilobits_per_second(value: f64) -> f64 {
+        super::data::bits::to_kilobits(value)
+    }
+
+    /// Converts bits per second to megabits per second.
+    /// # Arguments
+    /// * `value` - The data rate in bits per second to convert
+    pub fn to_megabits_per_second(value: f64) -> f64 {
+        super::data::bits::to_megabits(value)
+    }
+
+    /// Converts bits per second to gigabits per second.
+    /// # Arguments
+    /// * `value` - The data rate in bits per second to convert
+    pub fn to_gigabits_per_second(value: f64) -> f64 {
+        super::data::bits::to_gigabits(value)
+    }
+
+    /// Converts bits per second to bytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in bits per second to convert
+    pub fn to_bytes_per_second(value: f64) -> f64 {
+        super::data::bits::to_bytes(value)
+    }
+
+    /// Converts bits per second to kilobytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in bits per second to convert
+    pub fn to_kilobytes_per_second(value: f64) -> f64 {
+        super::data::bits::to_kilobytes(value)
+    }
+
+    /// Converts bits per second to megabytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in bits per second to convert
+    pub fn to_megabytes_per_second(value: f64) -> f64 {
+        super::data::bits::to_megabytes(value)
+    }
+
+    /// Converts bits per second to gigabytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in bits per second to convert
+    pub fn to_gigabytes_per_second(value: f64) -> f64 {
+        super::data::bits::to_gigabytes(value)
+    }
+
+    /// Converts bits per second to kibibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in bits per second to convert
+    pub fn to_kibibytes_per_second(value: f64) -> f64 {
+        super::data::bits::to_kibibytes(value)
+    }
+
+    /// Converts bits per second to mebibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in bits per second to convert
+    pub fn to_mebibytes_per_second(value: f64) -> f64 {
+        super::data::bits::to_mebibytes(value)
+    }
+
+    /// Converts bits per second to gibibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in bits per second to convert
+    pub fn to_gibibytes_per_second(value: f64) -> f64 {
+        super::data::bits::to_gibibytes(value)
+    }
+
+    /// The bit per second as a typed unit of data rate.
+    pub const UNIT: Unit<DataRate> =
+        Unit::new("bit/s", "bit per second", |value| value, |value| value);
+
+    /// Creates a typed data rate quantity expressed in bits per second.
+    /// # Arguments
+    /// * `value` - The data rate in bits per second
+    pub fn new(value: f64) -> Quantity<DataRate> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed data rate quantity in bits per second.
+    /// # Arguments
+    /// * `quantity` - The data rate quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<DataRate>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Kilobits per second conversion functions
+pub mod kilobits_per_second {
+    use crate::conversions::quantity::{DataRate, Quantity, Unit};
+
+    /// Converts kilobits per second to bits per second.
+    /// # Arguments
+    /// * `value` - The data rate in kilobits per second to convert
+    pub fn to_bits_per_second(value: f64) -> f64 {
+        super::data::kilobits::to_bits(value)
+    }
+
+    /// Converts kilobits per second to megabits per second.
+    /// # Arguments
+    /// * `value` - The data rate in kilobits per second to convert
+    pub fn to_megabits_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_megabits_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts kilobits per second to gigabits per second.
+    /// # Arguments
+    /// * `value` - The data rate in kilobits per second to convert
+    pub fn to_gigabits_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_gigabits_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts kilobits per second to bytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in kilobits per second to convert
+    pub fn to_bytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_bytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts kilobits per second to kilobytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in kilobits per second to convert
+    pub fn to_kilobytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_kilobytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts kilobits per second to megabytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in kilobits per second to convert
+    pub fn to_megabytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_megabytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts kilobits per second to gigabytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in kilobits per second to convert
+    pub fn to_gigabytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_gigabytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts kilobits per second to kibibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in kilobits per second to convert
+    pub fn to_kibibytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_kibibytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts kilobits per second to mebibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in kilobits per second to convert
+    pub fn to_mebibytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_mebibytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts kilobits per second to gibibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in kilobits per second to convert
+    pub fn to_gibibytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_gibibytes_per_second(to_bits_per_second(value))
+    }
+
+    /// The kilobit per second as a typed unit of data rate.
+    pub const UNIT: Unit<DataRate> = Unit::new(
+        "kbit/s",
+        "kilobit per second",
+        to_bits_per_second,
+        super::bits_per_second::to_kilobits_per_second,
+    );
+
+    /// Creates a typed data rate quantity expressed in kilobits per second.
+    /// # Arguments
+    /// * `value` - The data rate in kilobits per second
+    pub fn new(value: f64) -> Quantity<DataRate> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed data rate quantity in kilobits per second.
+    /// # Arguments
+    /// * `quantity` - The data rate quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<DataRate>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Megabits per second conversion functions
+pub mod megabits_per_second {
+    use crate::conversions::quantity::{DataRate, Quantity, Unit};
+
+    /// Converts megabits per second to bits per second.
+    /// # Arguments
+    /// * `value` - The data rate in megabits per second to convert
+    pub fn to_bits_per_second(value: f64) -> f64 {
+        super::data::megabits::to_bits(value)
+    }
+
+    /// Converts megabits per second to kilobits per second.
+    /// # Arguments
+    /// * `value` - The data rate in megabits per second to convert
+    pub fn to_kilobits_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_kilobits_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts megabits per second to gigabits per second.
+    /// # Arguments
+    /// * `value` - The data rate in megabits per second to convert
+    pub fn to_gigabits_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_gigabits_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts megabits per second to bytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in megabits per second to convert
+    pub fn to_bytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_bytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts megabits per second to kilobytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in megabits per second to convert
+    pub fn to_kilobytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_kilobytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts megabits per second to megabytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in megabits per second to convert
+    pub fn to_megabytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_megabytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts megabits per second to gigabytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in megabits per second to convert
+    pub fn to_gigabytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_gigabytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts megabits per second to kibibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in megabits per second to convert
+    pub fn to_kibibytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_kibibytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts megabits per second to mebibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in megabits per second to convert
+    pub fn to_mebibytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_mebibytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts megabits per second to gibibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in megabits per second to convert
+    pub fn to_gibibytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_gibibytes_per_second(to_bits_per_second(value))
+    }
+
+    /// The megabit per second as a typed unit of data rate.
+    pub const UNIT: Unit<DataRate> = Unit::new(
+        "Mbit/s",
+        "megabit per second",
+        to_bits_per_second,
+        super::bits_per_second::to_megabits_per_second,
+    );
+
+    /// Creates a typed data rate quantity expressed in megabits per second.
+    /// # Arguments
+    /// * `value` - The data rate in megabits per second
+    pub fn new(value: f64) -> Quantity<DataRate> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed data rate quantity in megabits per second.
+    /// # Arguments
+    /// * `quantity` - The data rate quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<DataRate>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Gigabits per second conversion functions
+pub mod gigabits_per_second {
+    use crate::conversions::quantity::{DataRate, Quantity, Unit};
+
+    /// Converts gigabits per second to bits per second.
+    /// # Arguments
+    /// * `value` - The data rate in gigabits per second to convert
+    pub fn to_bits_per_second(value: f64) -> f64 {
+        super::data::gigabits::to_bits(value)
+    }
+
+    /// Converts gigabits per second to kilobits per second.
+    /// # Arguments
+    /// * `value` - The data rate in gigabits per second to convert
+    pub fn to_kilobits_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_kilobits_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts gigabits per second to megabits per second.
+    /// # Arguments
+    /// * `value` - The data rate in gigabits per second to convert
+    pub fn to_megabits_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_megabits_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts gigabits per second to bytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in gigabits per second to convert
+    pub fn to_bytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_bytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts gigabits per second to kilobytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in gigabits per second to convert
+    pub fn to_kilobytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_kilobytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts gigabits per second to megabytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in gigabits per second to convert
+    pub fn to_megabytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_megabytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts gigabits per second to gigabytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in gigabits per second to convert
+    pub fn to_gigabytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_gigabytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts gigabits per second to kibibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in gigabits per second to convert
+    pub fn to_kibibytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_kibibytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts gigabits per second to mebibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in gigabits per second to convert
+    pub fn to_mebibytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_mebibytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts gigabits per second to gibibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in gigabits per second to convert
+    pub fn to_gibibytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_gibibytes_per_second(to_bits_per_second(value))
+    }
+
+    /// The gigabit per second as a typed unit of data rate.
+    pub const UNIT: Unit<DataRate> = Unit::new(
+        "Gbit/s",
+        "gigabit per second",
+        to_bits_per_second,
+        super::bits_per_second::to_gigabits_per_second,
+    );
+
+    /// Creates a typed data rate quantity expressed in gigabits per second.
+    /// # Arguments
+    /// * `value` - The data rate in gigabits per second
+    pub fn new(value: f64) -> Quantity<DataRate> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed data rate quantity in gigabits per second.
+    /// # Arguments
+    /// * `quantity` - The data rate quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<DataRate>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Bytes per second conversion functions
+pub mod bytes_per_second {
+    use crate::conversions::quantity::{DataRate, Quantity, Unit};
+
+    /// Converts bytes per second to bits per second.
+    /// # Arguments
+    /// * `value` - The data rate in bytes per second to convert
+    pub fn to_bits_per_second(value: f64) -> f64 {
+        super::data::bytes::to_bits(value)
+    }
+
+    /// Converts bytes per second to kilobits per second.
+    /// # Arguments
+    /// * `value` - The data rate in bytes per second to convert
+    pub fn to_kilobits_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_kilobits_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts bytes per second to megabits per second.
+    /// # Arguments
+    /// * `value` - The data rate in bytes per second to convert
+    pub fn to_megabits_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_megabits_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts bytes per second to gigabits per second.
+    /// # Arguments
+    /// * `value` - The data rate in bytes per second to convert
+    pub fn to_gigabits_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_gigabits_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts bytes per second to kilobytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in bytes per second to convert
+    pub fn to_kilobytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_kilobytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts bytes per second to megabytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in bytes per second to convert
+    pub fn to_megabytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_megabytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts bytes per second to gigabytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in bytes per second to convert
+    pub fn to_gigabytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_gigabytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts bytes per second to kibibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in bytes per second to convert
+    pub fn to_kibibytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_kibibytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts bytes per second to mebibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in bytes per second to convert
+    pub fn to_mebibytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_mebibytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts bytes per second to gibibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in bytes per second to convert
+    pub fn to_gibibytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_gibibytes_per_second(to_bits_per_second(value))
+    }
+
+    /// The byte per second as a typed unit of data rate.
+    pub const UNIT: Unit<DataRate> = Unit::new(
+        "B/s",
+        "byte per second",
+        to_bits_per_second,
+        super::bits_per_second::to_bytes_per_second,
+    );
+
+    /// Creates a typed data rate quantity expressed in bytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in bytes per second
+    pub fn new(value: f64) -> Quantity<DataRate> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed data rate quantity in bytes per second.
+    /// # Arguments
+    /// * `quantity` - The data rate quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<DataRate>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Kilobytes (SI) per second conversion functions
+pub mod kilobytes_per_second {
+    use crate::conversions::quantity::{DataRate, Quantity, Unit};
+
+    /// Converts kilobytes per second to bits per second.
+    /// # Arguments
+    /// * `value` - The data rate in kilobytes per second to convert
+    pub fn to_bits_per_second(value: f64) -> f64 {
+        super::data::kilobytes::to_bits(value)
+    }
+
+    /// Converts kilobytes per second to kilobits per second.
+    /// # Arguments
+    /// * `value` - The data rate in kilobytes per second to convert
+    pub fn to_kilobits_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_kilobits_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts kilobytes per second to megabits per second.
+    /// # Arguments
+    /// * `value` - The data rate in kilobytes per second to convert
+    pub fn to_megabits_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_megabits_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts kilobytes per second to gigabits per second.
+    /// # Arguments
+    /// * `value` - The data rate in kilobytes per second to convert
+    pub fn to_gigabits_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_gigabits_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts kilobytes per second to bytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in kilobytes per second to convert
+    pub fn to_bytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_bytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts kilobytes per second to megabytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in kilobytes per second to convert
+    pub fn to_megabytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_megabytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts kilobytes per second to gigabytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in kilobytes per second to convert
+    pub fn to_gigabytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_gigabytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts kilobytes per second to kibibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in kilobytes per second to convert
+    pub fn to_kibibytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_kibibytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts kilobytes per second to mebibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in kilobytes per second to convert
+    pub fn to_mebibytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_mebibytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts kilobytes per second to gibibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in kilobytes per second to convert
+    pub fn to_gibibytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_gibibytes_per_second(to_bits_per_second(value))
+    }
+
+    /// The kilobyte per second as a typed unit of data rate.
+    pub const UNIT: Unit<DataRate> = Unit::new(
+        "kB/s",
+        "kilobyte per second",
+        to_bits_per_second,
+        super::bits_per_second::to_kilobytes_per_second,
+    );
+
+    /// Creates a typed data rate quantity expressed in kilobytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in kilobytes per second
+    pub fn new(value: f64) -> Quantity<DataRate> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed data rate quantity in kilobytes per second.
+    /// # Arguments
+    /// * `quantity` - The data rate quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<DataRate>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Megabytes (SI) per second conversion functions
+pub mod megabytes_per_second {
+    use crate::conversions::quantity::{DataRate, Quantity, Unit};
+
+    /// Converts megabytes per second to bits per second.
+    /// # Arguments
+    /// * `value` - The data rate in megabytes per second to convert
+    pub fn to_bits_per_second(value: f64) -> f64 {
+        super::data::megabytes::to_bits(value)
+    }
+
+    /// Converts megabytes per second to kilobits per second.
+    /// # Arguments
+    /// * `value` - The data rate in megabytes per second to convert
+    pub fn to_kilobits_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_kilobits_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts megabytes per second to megabits per second.
+    /// # Arguments
+    /// * `value` - The data rate in megabytes per second to convert
+    pub fn to_megabits_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_megabits_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts megabytes per second to gigabits per second.
+    /// # Arguments
+    /// * `value` - The data rate in megabytes per second to convert
+    pub fn to_gigabits_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_gigabits_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts megabytes per second to bytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in megabytes per second to convert
+    pub fn to_bytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_bytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts megabytes per second to kilobytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in megabytes per second to convert
+    pub fn to_kilobytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_kilobytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts megabytes per second to gigabytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in megabytes per second to convert
+    pub fn to_gigabytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_gigabytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts megabytes per second to kibibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in megabytes per second to convert
+    pub fn to_kibibytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_kibibytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts megabytes per second to mebibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in megabytes per second to convert
+    pub fn to_mebibytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_mebibytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts megabytes per second to gibibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in megabytes per second to convert
+    pub fn to_gibibytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_gibibytes_per_second(to_bits_per_second(value))
+    }
+
+    /// The megabyte per second as a typed unit of data rate.
+    pub const UNIT: Unit<DataRate> = Unit::new(
+        "MB/s",
+        "megabyte per second",
+        to_bits_per_second,
+        super::bits_per_second::to_megabytes_per_second,
+    );
+
+    /// Creates a typed data rate quantity expressed in megabytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in megabytes per second
+    pub fn new(value: f64) -> Quantity<DataRate> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed data rate quantity in megabytes per second.
+    /// # Arguments
+    /// * `quantity` - The data rate quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<DataRate>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Gigabytes (SI) per second conversion functions
+pub mod gigabytes_per_second {
+    use crate::conversions::quantity::{DataRate, Quantity, Unit};
+
+    /// Converts gigabytes per second to bits per second.
+    /// # Arguments
+    /// * `value` - The data rate in gigabytes per second to convert
+    pub fn to_bits_per_second(value: f64) -> f64 {
+        super::data::gigabytes::to_bits(value)
+    }
+
+    /// Converts gigabytes per second to kilobits per second.
+    /// # Arguments
+    /// * `value` - The data rate in gigabytes per second to convert
+    pub fn to_kilobits_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_kilobits_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts gigabytes per second to megabits per second.
+    /// # Arguments
+    /// * `value` - The data rate in gigabytes per second to convert
+    pub fn to_megabits_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_megabits_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts gigabytes per second to gigabits per second.
+    /// # Arguments
+    /// * `value` - The data rate in gigabytes per second to convert
+    pub fn to_gigabits_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_gigabits_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts gigabytes per second to bytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in gigabytes per second to convert
+    pub fn to_bytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_bytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts gigabytes per second to kilobytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in gigabytes per second to convert
+    pub fn to_kilobytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_kilobytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts gigabytes per second to megabytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in gigabytes per second to convert
+    pub fn to_megabytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_megabytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts gigabytes per second to kibibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in gigabytes per second to convert
+    pub fn to_kibibytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_kibibytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts gigabytes per second to mebibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in gigabytes per second to convert
+    pub fn to_mebibytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_mebibytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts gigabytes per second to gibibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in gigabytes per second to convert
+    pub fn to_gibibytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_gibibytes_per_second(to_bits_per_second(value))
+    }
+
+    /// The gigabyte per second as a typed unit of data rate.
+    pub const UNIT: Unit<DataRate> = Unit::new(
+        "GB/s",
+        "gigabyte per second",
+        to_bits_per_second,
+        super::bits_per_second::to_gigabytes_per_second,
+    );
+
+    /// Creates a typed data rate quantity expressed in gigabytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in gigabytes per second
+    pub fn new(value: f64) -> Quantity<DataRate> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed data rate quantity in gigabytes per second.
+    /// # Arguments
+    /// * `quantity` - The data rate quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<DataRate>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Kibibytes (IEC) per second conversion functions
+pub mod kibibytes_per_second {
+    use crate::conversions::quantity::{DataRate, Quantity, Unit};
+
+    /// Converts kibibytes per second to bits per second.
+    /// # Arguments
+    /// * `value` - The data rate in kibibytes per second to convert
+    pub fn to_bits_per_second(value: f64) -> f64 {
+        super::data::kibibytes::to_bits(value)
+    }
+
+    /// Converts kibibytes per second to kilobits per second.
+    /// # Arguments
+    /// * `value` - The data rate in kibibytes per second to convert
+    pub fn to_kilobits_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_kilobits_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts kibibytes per second to megabits per second.
+    /// # Arguments
+    /// * `value` - The data rate in kibibytes per second to convert
+    pub fn to_megabits_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_megabits_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts kibibytes per second to gigabits per second.
+    /// # Arguments
+    /// * `value` - The data rate in kibibytes per second to convert
+    pub fn to_gigabits_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_gigabits_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts kibibytes per second to bytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in kibibytes per second to convert
+    pub fn to_bytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_bytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts kibibytes per second to kilobytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in kibibytes per second to convert
+    pub fn to_kilobytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_kilobytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts kibibytes per second to megabytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in kibibytes per second to convert
+    pub fn to_megabytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_megabytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts kibibytes per second to gigabytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in kibibytes per second to convert
+    pub fn to_gigabytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_gigabytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts kibibytes per second to mebibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in kibibytes per second to convert
+    pub fn to_mebibytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_mebibytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts kibibytes per second to gibibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in kibibytes per second to convert
+    pub fn to_gibibytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_gibibytes_per_second(to_bits_per_second(value))
+    }
+
+    /// The kibibyte per second as a typed unit of data rate.
+    pub const UNIT: Unit<DataRate> = Unit::new(
+        "KiB/s",
+        "kibibyte per second",
+        to_bits_per_second,
+        super::bits_per_second::to_kibibytes_per_second,
+    );
+
+    /// Creates a typed data rate quantity expressed in kibibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in kibibytes per second
+    pub fn new(value: f64) -> Quantity<DataRate> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed data rate quantity in kibibytes per second.
+    /// # Arguments
+    /// * `quantity` - The data rate quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<DataRate>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Mebibytes (IEC) per second conversion functions
+pub mod mebibytes_per_second {
+    use crate::conversions::quantity::{DataRate, Quantity, Unit};
+
+    /// Converts mebibytes per second to bits per second.
+    /// # Arguments
+    /// * `value` - The data rate in mebibytes per second to convert
+    pub fn to_bits_per_second(value: f64) -> f64 {
+        super::data::mebibytes::to_bits(value)
+    }
+
+    /// Converts mebibytes per second to kilobits per second.
+    /// # Arguments
+    /// * `value` - The data rate in mebibytes per second to convert
+    pub fn to_kilobits_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_kilobits_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts mebibytes per second to megabits per second.
+    /// # Arguments
+    /// * `value` - The data rate in mebibytes per second to convert
+    pub fn to_megabits_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_megabits_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts mebibytes per second to gigabits per second.
+    /// # Arguments
+    /// * `value` - The data rate in mebibytes per second to convert
+    pub fn to_gigabits_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_gigabits_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts mebibytes per second to bytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in mebibytes per second to convert
+    pub fn to_bytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_bytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts mebibytes per second to kilobytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in mebibytes per second to convert
+    pub fn to_kilobytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_kilobytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts mebibytes per second to megabytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in mebibytes per second to convert
+    pub fn to_megabytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_megabytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts mebibytes per second to gigabytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in mebibytes per second to convert
+    pub fn to_gigabytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_gigabytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts mebibytes per second to kibibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in mebibytes per second to convert
+    pub fn to_kibibytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_kibibytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts mebibytes per second to gibibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in mebibytes per second to convert
+    pub fn to_gibibytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_gibibytes_per_second(to_bits_per_second(value))
+    }
+
+    /// The mebibyte per second as a typed unit of data rate.
+    pub const UNIT: Unit<DataRate> = Unit::new(
+        "MiB/s",
+        "mebibyte per second",
+        to_bits_per_second,
+        super::bits_per_second::to_mebibytes_per_second,
+    );
+
+    /// Creates a typed data rate quantity expressed in mebibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in mebibytes per second
+    pub fn new(value: f64) -> Quantity<DataRate> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed data rate quantity in mebibytes per second.
+    /// # Arguments
+    /// * `quantity` - The data rate quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<DataRate>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Gibibytes (IEC) per second conversion functions
+pub mod gibibytes_per_second {
+    use crate::conversions::quantity::{DataRate, Quantity, Unit};
+
+    /// Converts gibibytes per second to bits per second.
+    /// # Arguments
+    /// * `value` - The data rate in gibibytes per second to convert
+    pub fn to_bits_per_second(value: f64) -> f64 {
+        super::data::gibibytes::to_bits(value)
+    }
+
+    /// Converts gibibytes per second to kilobits per second.
+    /// # Arguments
+    /// * `value` - The data rate in gibibytes per second to convert
+    pub fn to_kilobits_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_kilobits_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts gibibytes per second to megabits per second.
+    /// # Arguments
+    /// * `value` - The data rate in gibibytes per second to convert
+    pub fn to_megabits_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_megabits_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts gibibytes per second to gigabits per second.
+    /// # Arguments
+    /// * `value` - The data rate in gibibytes per second to convert
+    pub fn to_gigabits_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_gigabits_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts gibibytes per second to bytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in gibibytes per second to convert
+    pub fn to_bytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_bytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts gibibytes per second to kilobytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in gibibytes per second to convert
+    pub fn to_kilobytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_kilobytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts gibibytes per second to megabytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in gibibytes per second to convert
+    pub fn to_megabytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_megabytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts gibibytes per second to gigabytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in gibibytes per second to convert
+    pub fn to_gigabytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_gigabytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts gibibytes per second to kibibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in gibibytes per second to convert
+    pub fn to_kibibytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_kibibytes_per_second(to_bits_per_second(value))
+    }
+
+    /// Converts gibibytes per second to mebibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in gibibytes per second to convert
+    pub fn to_mebibytes_per_second(value: f64) -> f64 {
+        super::bits_per_second::to_mebibytes_per_second(to_bits_per_second(value))
+    }
+
+    /// The gibibyte per second as a typed unit of data rate.
+    pub const UNIT: Unit<DataRate> = Unit::new(
+        "GiB/s",
+        "gibibyte per second",
+        to_bits_per_second,
+        super::bits_per_second::to_gibibytes_per_second,
+    );
+
+    /// Creates a typed data rate quantity expressed in gibibytes per second.
+    /// # Arguments
+    /// * `value` - The data rate in gibibytes per second
+    pub fn new(value: f64) -> Quantity<DataRate> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed data rate quantity in gibibytes per second.
+    /// # Arguments
+    /// * `quantity` - The data rate quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<DataRate>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// General data rate conversion function that accepts string unit names
+///
+/// Converts a data rate from one unit to another using string identifiers.
+/// Symbols are case-sensitive, names are case-insensitive, and common
+/// abbreviations are supported. As with [`convert_data`](super::convert_data),
+/// spellings that could mean a decimal or a binary rate (`"KB/s"`), or a bit
+/// or a byte rate (`"mbps"`), fail with [`ConversionError::AmbiguousUnit`].
+///
+/// # Arguments
+///
+/// * `value` - The numeric value to convert
+/// * `from_unit` - The source unit (e.g., "bit/s", "kbit/s", "Mbit/s", "Mbps", "Gbit/s",
+///   "B/s", "kB/s", "MB/s", "GB/s", "KiB/s", "MiB/s", "GiB/s")
+/// * `to_unit` - The target unit using the same abbreviations
+///
+/// # Returns
+/// * `Ok(f64)` - The converted value
+/// * `Err(ConversionError)` - The reason the conversion is not supported
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::convert_data_rate;
+///
+/// let megabytes_per_second = convert_data_rate(100.0, "Mbit/s", "MB/s").unwrap();
+/// assert!((megabytes_per_second - 12.5).abs() < 1e-12);
+///
+/// let gibibytes_per_second = convert_data_rate(10.0, "Gbps", "GiB/s").unwrap();
+/// assert!((gibibytes_per_second - 1.164_153).abs() < 1e-6);
+/// ```
+pub fn convert_data_rate(
+    value: f64,
+    from_unit: &str,
+    to_unit: &str,
+) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from_unit, to_unit, Category::DataRate)
+}
+
+/// Data rate units known to the [`UnitRegistry`], with bits per second as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new(
+            "bit/s",
+            "bit per second",
+            &["bps", "b/s", "bits per second"],
+            Category::DataRate,
+            1.0,
+        ),
+        UnitDef::new(
+            "kbit/s",
+            "kilobit per second",
+            &["kbps", "kb/s", "kilobits per second"],
+            Category::DataRate,
+            kilobits_per_second::to_bits_per_second(1.0),
+        ),
+        UnitDef::new(
+            "Mbit/s",
+            "megabit per second",
+            &["Mbps", "Mb/s", "megabits per second"],
+            Category::DataRate,
+            megabits_per_second::to_bits_per_second(1.0),
+        ),
+        UnitDef::new(
+            "Gbit/s",
+            "gigabit per second",
+            &["Gbps", "Gb/s", "gigabits per second"],
+            Category::DataRate,
+            gigabits_per_second::to_bits_per_second(1.0),
+        ),
+        UnitDef::new(
+            "B/s",
+            "byte per second",
+            &["Bps", "bytes per second"],
+            Category::DataRate,
+            bytes_per_second::to_bits_per_second(1.0),
+        ),
+        UnitDef::new(
+            "kB/s",
+            "kilobyte per second",
+            &["kBps", "KB/s", "kilobytes per second"],
+            Category::DataRate,
+            kilobytes_per_second::to_bits_per_second(1.0),
+        ),
+        UnitDef::new(
+            "MB/s",
+            "megabyte per second",
+            &["MBps", "megabytes per second"],
+            Category::DataRate,
+            megabytes_per_second::to_bits_per_second(1.0),
+        ),
+        UnitDef::new(
+            "GB/s",
+            "gigabyte per second",
+            &["GBps", "gigabytes per second"],
+            Category::DataRate,
+            gigabytes_per_second::to_bits_per_second(1.0),
+        ),
+        UnitDef::new(
+            "KiB/s",
+            "kibibyte per second",
+            &["KB/s", "kibibytes per second"],
+            Category::DataRate,
+            kibibytes_per_second::to_bits_per_second(1.0),
+        ),
+        UnitDef::new(
+            "MiB/s",
+            "mebibyte per second",
+            &["mebibytes per second"],
+            Category::DataRate,
+            mebibytes_per_second::to_bits_per_second(1.0),
+        ),
+        UnitDef::new(
+            "GiB/s",
+            "gibibyte per second",
+            &["gibibytes per second"],
+            Category::DataRate,
+            gibibytes_per_second::to_bits_per_second(1.0),
+        ),
+    ]
+}
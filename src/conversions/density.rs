@@ -0,0 +1,1460 @@
+//! Density conversion functions organized by unit type
+//! All conversions use kilograms per cubic meter as the base unit, with every
+//! mass and volume taken from the `weight`, `volume` and `length` modules
+//!
+//! Specific gravity is relative to water at 60 °F ([`WATER_DENSITY_60F`]), the
+//! reference of the petroleum industry. The API gravity and Baumé hydrometer
+//! scales are reciprocal, not linear, in density: they are converted through
+//! specific gravity with their defining formulas.
+
+use super::category::Category;
+use super::error::ConversionError;
+use super::registry::{UnitDef, UnitRegistry};
+use super::{length, volume, weight};
+
+/// Density of pure water at 60 °F in kilograms per cubic meter, the reference
+/// for specific gravity 60/60 °F
+pub const WATER_DENSITY_60F: f64 = 999.016;
+
+/// Numerator of the API gravity scale, °API = 141.5 / SG − 131.5
+pub const API_NUMERATOR: f64 = 141.5;
+
+/// Offset of the API gravity scale, °API = 141.5 / SG − 131.5
+pub const API_OFFSET: f64 = 131.5;
+
+/// Modulus of the Baumé scale for heavy liquids, °Bé = 145 − 145 / SG
+pub const BAUME_HEAVY_MODULUS: f64 = 145.0;
+
+/// Numerator of the Baumé scale for light liquids, °Bé = 140 / SG − 130
+pub const BAUME_LIGHT_NUMERATOR: f64 = 140.0;
+
+/// Offset of the Baumé scale for light liquids, °Bé = 140 / SG − 130
+pub const BAUME_LIGHT_OFFSET: f64 = 130.0;
+
+/// Cubic meters in one liter
+const CUBIC_METERS_PER_LITER: f64 = 0.001;
+
+/// Kilograms per cubic meter in one gram per cubic centimeter, exactly
+/// 10⁻³ kg / 10⁻⁶ m³; written as a literal because dividing the two in `f64`
+/// gives 1000.0000000000001
+fn grams_per_cubic_centimeter() -> f64 {
+    1000.0
+}
+
+/// Kilograms per cubic meter in one pound per cubic foot
+fn pounds_per_cubic_foot() -> f64 {
+    weight::pounds::to_kilograms(1.0) / length::feet::to_meters(1.0).powi(3)
+}
+
+/// Kilograms per cubic meter in one pound per cubic inch
+fn pounds_per_cubic_inch() -> f64 {
+    weight::pounds::to_kilograms(1.0) / length::inches::to_meters(1.0).powi(3)
+}
+
+/// Kilograms per cubic meter in one pound per US gallon
+fn pounds_per_gallon_us() -> f64 {
+    weight::pounds::to_kilograms(1.0)
+        / (volume::gallons_us::to_liters(1.0) * CUBIC_METERS_PER_LITER)
+}
+
+/// Kilograms per cubic meter in one pound per imperial gallon
+fn pounds_per_gallon_uk() -> f64 {
+    weight::pounds::to_kilograms(1.0)
+        / (volume::gallons_uk::to_liters(1.0) * CUBIC_METERS_PER_LITER)
+}
+
+/// Kilograms per cubic meter in one ounce per cubic inch
+fn ounces_per_cubic_inch() -> f64 {
+    weight::ounces::to_kilograms(1.0) / length::inches::to_meters(1.0).powi(3)
+}
+
+/// Kilograms per cubic meter conversion functions
+pub mod kilograms_per_cubic_meter {
+    use crate::conversions::quantity::{Density, Quantity, Unit};
+
+    /// Converts kilograms per cubic meter to grams per cubic centimeter.
+    /// # Arguments
+    /// * `value` - The density in kilograms per cubic meter to convert
+    pub fn to_grams_per_cubic_centimeter(value: f64) -> f64 {
+        value / super::grams_per_cubic_centimeter()
+    }
+
+    /// Converts kilograms per cubic meter to pounds per cubic foot.
+    /// # Arguments
+    /// * `value` - The density in kilograms per cubic meter to convert
+    pub fn to_pounds_per_cubic_foot(value: f64) -> f64 {
+        value / super::pounds_per_cubic_foot()
+    }
+
+    /// Converts kilograms per cubic meter to pounds per cubic inch.
+    /// # Arguments
+    /// * `value` - The density in kilograms per cubic meter to convert
+    pub fn to_pounds_per_cubic_inch(value: f64) -> f64 {
+        value / super::pounds_per_cubic_inch()
+    }
+
+    /// Converts kilograms per cubic meter to pounds per US gallon.
+    /// # Arguments
+    /// * `value` - The density in kilograms per cubic meter to convert
+    pub fn to_pounds_per_gallon_us(value: f64) -> f64 {
+        value / super::pounds_per_gallon_us()
+    }
+
+    /// Converts kilograms per cubic meter to pounds per imperial gallon.
+    /// # Arguments
+    /// * `value` - The density in kilograms per cubic meter to convert
+    pub fn to_pounds_per_gallon_uk(value: f64) -> f64 {
+        value / super::pounds_per_gallon_uk()
+    }
+
+    /// Converts kilograms per cubic meter to ounces per cubic inch.
+    /// # Arguments
+    /// * `value` - The density in kilograms per cubic meter to convert
+    pub fn to_ounces_per_cubic_inch(value: f64) -> f64 {
+        value / super::ounces_per_cubic_inch()
+    }
+
+    /// Converts kilograms per cubic meter to specific gravity.
+    /// # Arguments
+    /// * `value` - The density in kilograms per cubic meter to convert
+    pub fn to_specific_gravity(value: f64) -> f64 {
+        value / super::WATER_DENSITY_60F
+    }
+
+    /// Converts kilograms per cubic meter to degrees API.
+    /// # Arguments
+    /// * `value` - The density in kilograms per cubic meter to convert
+    pub fn to_degrees_api(value: f64) -> f64 {
+        super::specific_gravity::to_degrees_api(to_specific_gravity(value))
+    }
+
+    /// Converts kilograms per cubic meter to degrees Baumé (heavy).
+    /// # Arguments
+    /// * `value` - The density in kilograms per cubic meter to convert
+    pub fn to_degrees_baume_heavy(value: f64) -> f64 {
+        super::specific_gravity::to_degrees_baume_heavy(to_specific_gravity(value))
+    }
+
+    /// Converts kilograms per cubic meter to degrees Baumé (light).
+    /// # Arguments
+    /// * `value` - The density in kilograms per cubic meter to convert
+    pub fn to_degrees_baume_light(value: f64) -> f64 {
+        super::specific_gravity::to_degrees_baume_light(to_specific_gravity(value))
+    }
+
+    /// The kilogram per cubic meter as a typed unit of density.
+    pub const UNIT: Unit<Density> = Unit::new(
+        "kg/m³",
+        "kilogram per cubic meter",
+        |value| value,
+        |value| value,
+    );
+
+    /// Creates a typed density quantity expressed in kilograms per cubic meter.
+    /// # Arguments
+    /// * `value` - The density in kilograms per cubic meter
+    pub fn new(value: f64) -> Quantity<Density> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed density quantity in kilograms per cubic meter.
+    /// # Arguments
+    /// * `quantity` - The density quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Density>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Grams per cubic centimeter (grams per milliliter) conversion functions
+pub mod grams_per_cubic_centimeter {
+    use crate::conversions::quantity::{Density, Quantity, Unit};
+
+    /// Converts grams per cubic centimeter to kilograms per cubic meter.
+    /// # Arguments
+    /// * `value` - The density in grams per cubic centimeter to convert
+    pub fn to_kilograms_per_cubic_meter(value: f64) -> f64 {
+        value * super::grams_per_cubic_centimeter()
+    }
+
+    /// Converts grams per cubic centimeter to pounds per cubic foot.
+    /// # Arguments
+    /// * `value` - The density in grams per cubic centimeter to convert
+    pub fn to_pounds_per_cubic_foot(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_cubic_foot(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts grams per cubic centimeter to pounds per cubic inch.
+    /// # Arguments
+    /// * `value` - The density in grams per cubic centimeter to convert
+    pub fn to_pounds_per_cubic_inch(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_cubic_inch(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts grams per cubic centimeter to pounds per US gallon.
+    /// # Arguments
+    /// * `value` - The density in grams per cubic centimeter to convert
+    pub fn to_pounds_per_gallon_us(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_gallon_us(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts grams per cubic centimeter to pounds per imperial gallon.
+    /// # Arguments
+    /// * `value` - The density in grams per cubic centimeter to convert
+    pub fn to_pounds_per_gallon_uk(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_gallon_uk(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts grams per cubic centimeter to ounces per cubic inch.
+    /// # Arguments
+    /// * `value` - The density in grams per cubic centimeter to convert
+    pub fn to_ounces_per_cubic_inch(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_ounces_per_cubic_inch(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts grams per cubic centimeter to specific gravity.
+    /// # Arguments
+    /// * `value` - The density in grams per cubic centimeter to convert
+    pub fn to_specific_gravity(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_specific_gravity(to_kilograms_per_cubic_meter(value))
+    }
+
+    /// Converts grams per cubic centimeter to degrees API.
+    /// # Arguments
+    /// * `value` - The density in grams per cubic centimeter to convert
+    pub fn to_degrees_api(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_degrees_api(to_kilograms_per_cubic_meter(value))
+    }
+
+    /// Converts grams per cubic centimeter to degrees Baumé (heavy).
+    /// # Arguments
+    /// * `value` - The density in grams per cubic centimeter to convert
+    pub fn to_degrees_baume_heavy(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_degrees_baume_heavy(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts grams per cubic centimeter to degrees Baumé (light).
+    /// # Arguments
+    /// * `value` - The density in grams per cubic centimeter to convert
+    pub fn to_degrees_baume_light(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_degrees_baume_light(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// The gram per cubic centimeter as a typed unit of density.
+    pub const UNIT: Unit<Density> = Unit::new(
+        "g/cm³",
+        "gram per cubic centimeter",
+        to_kilograms_per_cubic_meter,
+        super::kilograms_per_cubic_meter::to_grams_per_cubic_centimeter,
+    );
+
+    /// Creates a typed density quantity expressed in grams per cubic centimeter.
+    /// # Arguments
+    /// * `value` - The density in grams per cubic centimeter
+    pub fn new(value: f64) -> Quantity<Density> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed density quantity in grams per cubic centimeter.
+    /// # Arguments
+    /// * `quantity` - The density quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Density>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Pounds per cubic foot conversion functions
+pub mod pounds_per_cubic_foot {
+    use crate::conversions::quantity::{Density, Quantity, Unit};
+
+    /// Converts pounds per cubic foot to kilograms per cubic meter.
+    /// # Arguments
+    /// * `value` - The density in pounds per cubic foot to convert
+    pub fn to_kilograms_per_cubic_meter(value: f64) -> f64 {
+        value * super::pounds_per_cubic_foot()
+    }
+
+    /// Converts pounds per cubic foot to grams per cubic centimeter.
+    /// # Arguments
+    /// * `value` - The density in pounds per cubic foot to convert
+    pub fn to_grams_per_cubic_centimeter(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_grams_per_cubic_centimeter(
+            to_kilograms_per_cubic_meter(value),
+        )
+    }
+
+    /// Converts pounds per cubic foot to pounds per cubic inch.
+    /// # Arguments
+    /// * `value` - The density in pounds per cubic foot to convert
+    pub fn to_pounds_per_cubic_inch(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_cubic_inch(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts pounds per cubic foot to pounds per US gallon.
+    /// # Arguments
+    /// * `value` - The density in pounds per cubic foot to convert
+    pub fn to_pounds_per_gallon_us(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_gallon_us(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts pounds per cubic foot to pounds per imperial gallon.
+    /// # Arguments
+    /// * `value` - The density in pounds per cubic foot to convert
+    pub fn to_pounds_per_gallon_uk(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_gallon_uk(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts pounds per cubic foot to ounces per cubic inch.
+    /// # Arguments
+    /// * `value` - The density in pounds per cubic foot to convert
+    pub fn to_ounces_per_cubic_inch(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_ounces_per_cubic_inch(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts pounds per cubic foot to specific gravity.
+    /// # Arguments
+    /// * `value` - The density in pounds per cubic foot to convert
+    pub fn to_specific_gravity(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_specific_gravity(to_kilograms_per_cubic_meter(value))
+    }
+
+    /// Converts pounds per cubic foot to degrees API.
+    /// # Arguments
+    /// * `value` - The density in pounds per cubic foot to convert
+    pub fn to_degrees_api(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_degrees_api(to_kilograms_per_cubic_meter(value))
+    }
+
+    /// Converts pounds per cubic foot to degrees Baumé (heavy).
+    /// # Arguments
+    /// * `value` - The density in pounds per cubic foot to convert
+    pub fn to_degrees_baume_heavy(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_degrees_baume_heavy(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts pounds per cubic foot to degrees Baumé (light).
+    /// # Arguments
+    /// * `value` - The density in pounds per cubic foot to convert
+    pub fn to_degrees_baume_light(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_degrees_baume_light(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// The pound per cubic foot as a typed unit of density.
+    pub const UNIT: Unit<Density> = Unit::new(
+        "lb/ft³",
+        "pound per cubic foot",
+        to_kilograms_per_cubic_meter,
+        super::kilograms_per_cubic_meter::to_pounds_per_cubic_foot,
+    );
+
+    /// Creates a typed density quantity expressed in pounds per cubic foot.
+    /// # Arguments
+    /// * `value` - The density in pounds per cubic foot
+    pub fn new(value: f64) -> Quantity<Density> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed density quantity in pounds per cubic foot.
+    /// # Arguments
+    /// * `quantity` - The density quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Density>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Pounds per cubic inch conversion functions
+pub mod pounds_per_cubic_inch {
+    use crate::conversions::quantity::{Density, Quantity, Unit};
+
+    /// Converts pounds per cubic inch to kilograms per cubic meter.
+    /// # Arguments
+    /// * `value` - The density in pounds per cubic inch to convert
+    pub fn to_kilograms_per_cubic_meter(value: f64) -> f64 {
+        value * super::pounds_per_cubic_inch()
+    }
+
+    /// Converts pounds per cubic inch to grams per cubic centimeter.
+    /// # Arguments
+    /// * `value` - The density in pounds per cubic inch to convert
+    pub fn to_grams_per_cubic_centimeter(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_grams_per_cubic_centimeter(
+            to_kilograms_per_cubic_meter(value),
+        )
+    }
+
+    /// Converts pounds per cubic inch to pounds per cubic foot.
+    /// # Arguments
+    /// * `value` - The density in pounds per cubic inch to convert
+    pub fn to_pounds_per_cubic_foot(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_cubic_foot(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts pounds per cubic inch to pounds per US gallon.
+    /// # Arguments
+    /// * `value` - The density in pounds per cubic inch to convert
+    pub fn to_pounds_per_gallon_us(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_gallon_us(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts pounds per cubic inch to pounds per imperial gallon.
+    /// # Arguments
+    /// * `value` - The density in pounds per cubic inch to convert
+    pub fn to_pounds_per_gallon_uk(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_gallon_uk(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts pounds per cubic inch to ounces per cubic inch.
+    /// # Arguments
+    /// * `value` - The density in pounds per cubic inch to convert
+    pub fn to_ounces_per_cubic_inch(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_ounces_per_cubic_inch(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts pounds per cubic inch to specific gravity.
+    /// # Arguments
+    /// * `value` - The density in pounds per cubic inch to convert
+    pub fn to_specific_gravity(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_specific_gravity(to_kilograms_per_cubic_meter(value))
+    }
+
+    /// Converts pounds per cubic inch to degrees API.
+    /// # Arguments
+    /// * `value` - The density in pounds per cubic inch to convert
+    pub fn to_degrees_api(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_degrees_api(to_kilograms_per_cubic_meter(value))
+    }
+
+    /// Converts pounds per cubic inch to degrees Baumé (heavy).
+    /// # Arguments
+    /// * `value` - The density in pounds per cubic inch to convert
+    pub fn to_degrees_baume_heavy(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_degrees_baume_heavy(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts pounds per cubic inch to degrees Baumé (light).
+    /// # Arguments
+    /// * `value` - The density in pounds per cubic inch to convert
+    pub fn to_degrees_baume_light(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_degrees_baume_light(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// The pound per cubic inch as a typed unit of density.
+    pub const UNIT: Unit<Density> = Unit::new(
+        "lb/in³",
+        "pound per cubic inch",
+        to_kilograms_per_cubic_meter,
+        super::kilograms_per_cubic_meter::to_pounds_per_cubic_inch,
+    );
+
+    /// Creates a typed density quantity expressed in pounds per cubic inch.
+    /// # Arguments
+    /// * `value` - The density in pounds per cubic inch
+    pub fn new(value: f64) -> Quantity<Density> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed density quantity in pounds per cubic inch.
+    /// # Arguments
+    /// * `quantity` - The density quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Density>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Pounds per US gallon conversion functions
+pub mod pounds_per_gallon_us {
+    use crate::conversions::quantity::{Density, Quantity, Unit};
+
+    /// Converts pounds per US gallon to kilograms per cubic meter.
+    /// # Arguments
+    /// * `value` - The density in pounds per US gallon to convert
+    pub fn to_kilograms_per_cubic_meter(value: f64) -> f64 {
+        value * super::pounds_per_gallon_us()
+    }
+
+    /// Converts pounds per US gallon to grams per cubic centimeter.
+    /// # Arguments
+    /// * `value` - The density in pounds per US gallon to convert
+    pub fn to_grams_per_cubic_centimeter(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_grams_per_cubic_centimeter(
+            to_kilograms_per_cubic_meter(value),
+        )
+    }
+
+    /// Converts pounds per US gallon to pounds per cubic foot.
+    /// # Arguments
+    /// * `value` - The density in pounds per US gallon to convert
+    pub fn to_pounds_per_cubic_foot(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_cubic_foot(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts pounds per US gallon to pounds per cubic inch.
+    /// # Arguments
+    /// * `value` - The density in pounds per US gallon to convert
+    pub fn to_pounds_per_cubic_inch(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_cubic_inch(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts pounds per US gallon to pounds per imperial gallon.
+    /// # Arguments
+    /// * `value` - The density in pounds per US gallon to convert
+    pub fn to_pounds_per_gallon_uk(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_gallon_uk(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts pounds per US gallon to ounces per cubic inch.
+    /// # Arguments
+    /// * `value` - The density in pounds per US gallon to convert
+    pub fn to_ounces_per_cubic_inch(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_ounces_per_cubic_inch(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts pounds per US gallon to specific gravity.
+    /// # Arguments
+    /// * `value` - The density in pounds per US gallon to convert
+    pub fn to_specific_gravity(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_specific_gravity(to_kilograms_per_cubic_meter(value))
+    }
+
+    /// Converts pounds per US gallon to degrees API.
+    /// # Arguments
+    /// * `value` - The density in pounds per US gallon to convert
+    pub fn to_degrees_api(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_degrees_api(to_kilograms_per_cubic_meter(value))
+    }
+
+    /// Converts pounds per US gallon to degrees Baumé (heavy).
+    /// # Arguments
+    /// * `value` - The density in pounds per US gallon to convert
+    pub fn to_degrees_baume_heavy(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_degrees_baume_heavy(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts pounds per US gallon to degrees Baumé (light).
+    /// # Arguments
+    /// * `value` - The density in pounds per US gallon to convert
+    pub fn to_degrees_baume_light(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_degrees_baume_light(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// The pound per US gallon as a typed unit of density.
+    pub const UNIT: Unit<Density> = Unit::new(
+        "lb/gal",
+        "pound per US gallon",
+        to_kilograms_per_cubic_meter,
+        super::kilograms_per_cubic_meter::to_pounds_per_gallon_us,
+    );
+
+    /// Creates a typed density quantity expressed in pounds per US gallon.
+    /// # Arguments
+    /// * `value` - The density in pounds per US gallon
+    pub fn new(value: f64) -> Quantity<Density> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed density quantity in pounds per US gallon.
+    /// # Arguments
+    /// * `quantity` - The density quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Density>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Pounds per imperial gallon conversion functions
+pub mod pounds_per_gallon_uk {
+    use crate::conversions::quantity::{Density, Quantity, Unit};
+
+    /// Converts pounds per imperial gallon to kilograms per cubic meter.
+    /// # Arguments
+    /// * `value` - The density in pounds per imperial gallon to convert
+    pub fn to_kilograms_per_cubic_meter(value: f64) -> f64 {
+        value * super::pounds_per_gallon_uk()
+    }
+
+    /// Converts pounds per imperial gallon to grams per cubic centimeter.
+    /// # Arguments
+    /// * `value` - The density in pounds per imperial gallon to convert
+    pub fn to_grams_per_cubic_centimeter(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_grams_per_cubic_centimeter(
+            to_kilograms_per_cubic_meter(value),
+        )
+    }
+
+    /// Converts pounds per imperial gallon to pounds per cubic foot.
+    /// # Arguments
+    /// * `value` - The density in pounds per imperial gallon to convert
+    pub fn to_pounds_per_cubic_foot(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_cubic_foot(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts pounds per imperial gallon to pounds per cubic inch.
+    /// # Arguments
+    /// * `value` - The density in pounds per imperial gallon to convert
+    pub fn to_pounds_per_cubic_inch(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_cubic_inch(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts pounds per imperial gallon to pounds per US gallon.
+    /// # Arguments
+    /// * `value` - The density in pounds per imperial gallon to convert
+    pub fn to_pounds_per_gallon_us(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_gallon_us(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts pounds per imperial gallon to ounces per cubic inch.
+    /// # Arguments
+    /// * `value` - The density in pounds per imperial gallon to convert
+    pub fn to_ounces_per_cubic_inch(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_ounces_per_cubic_inch(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts pounds per imperial gallon to specific gravity.
+    /// # Arguments
+    /// * `value` - The density in pounds per imperial gallon to convert
+    pub fn to_specific_gravity(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_specific_gravity(to_kilograms_per_cubic_meter(value))
+    }
+
+    /// Converts pounds per imperial gallon to degrees API.
+    /// # Arguments
+    /// * `value` - The density in pounds per imperial gallon to convert
+    pub fn to_degrees_api(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_degrees_api(to_kilograms_per_cubic_meter(value))
+    }
+
+    /// Converts pounds per imperial gallon to degrees Baumé (heavy).
+    /// # Arguments
+    /// * `value` - The density in pounds per imperial gallon to convert
+    pub fn to_degrees_baume_heavy(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_degrees_baume_heavy(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts pounds per imperial gallon to degrees Baumé (light).
+    /// # Arguments
+    /// * `value` - The density in pounds per imperial gallon to convert
+    pub fn to_degrees_baume_light(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_degrees_baume_light(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// The pound per imperial gallon as a typed unit of density.
+    pub const UNIT: Unit<Density> = Unit::new(
+        "lb/gal_uk",
+        "pound per imperial gallon",
+        to_kilograms_per_cubic_meter,
+        super::kilograms_per_cubic_meter::to_pounds_per_gallon_uk,
+    );
+
+    /// Creates a typed density quantity expressed in pounds per imperial gallon.
+    /// # Arguments
+    /// * `value` - The density in pounds per imperial gallon
+    pub fn new(value: f64) -> Quantity<Density> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed density quantity in pounds per imperial gallon.
+    /// # Arguments
+    /// * `quantity` - The density quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Density>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Ounces per cubic inch conversion functions
+pub mod ounces_per_cubic_inch {
+    use crate::conversions::quantity::{Density, Quantity, Unit};
+
+    /// Converts ounces per cubic inch to kilograms per cubic meter.
+    /// # Arguments
+    /// * `value` - The density in ounces per cubic inch to convert
+    pub fn to_kilograms_per_cubic_meter(value: f64) -> f64 {
+        value * super::ounces_per_cubic_inch()
+    }
+
+    /// Converts ounces per cubic inch to grams per cubic centimeter.
+    /// # Arguments
+    /// * `value` - The density in ounces per cubic inch to convert
+    pub fn to_grams_per_cubic_centimeter(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_grams_per_cubic_centimeter(
+            to_kilograms_per_cubic_meter(value),
+        )
+    }
+
+    /// Converts ounces per cubic inch to pounds per cubic foot.
+    /// # Arguments
+    /// * `value` - The density in ounces per cubic inch to convert
+    pub fn to_pounds_per_cubic_foot(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_cubic_foot(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts ounces per cubic inch to pounds per cubic inch.
+    /// # Arguments
+    /// * `value` - The density in ounces per cubic inch to convert
+    pub fn to_pounds_per_cubic_inch(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_cubic_inch(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts ounces per cubic inch to pounds per US gallon.
+    /// # Arguments
+    /// * `value` - The density in ounces per cubic inch to convert
+    pub fn to_pounds_per_gallon_us(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_gallon_us(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts ounces per cubic inch to pounds per imperial gallon.
+    /// # Arguments
+    /// * `value` - The density in ounces per cubic inch to convert
+    pub fn to_pounds_per_gallon_uk(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_gallon_uk(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts ounces per cubic inch to specific gravity.
+    /// # Arguments
+    /// * `value` - The density in ounces per cubic inch to convert
+    pub fn to_specific_gravity(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_specific_gravity(to_kilograms_per_cubic_meter(value))
+    }
+
+    /// Converts ounces per cubic inch to degrees API.
+    /// # Arguments
+    /// * `value` - The density in ounces per cubic inch to convert
+    pub fn to_degrees_api(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_degrees_api(to_kilograms_per_cubic_meter(value))
+    }
+
+    /// Converts ounces per cubic inch to degrees Baumé (heavy).
+    /// # Arguments
+    /// * `value` - The density in ounces per cubic inch to convert
+    pub fn to_degrees_baume_heavy(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_degrees_baume_heavy(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts ounces per cubic inch to degrees Baumé (light).
+    /// # Arguments
+    /// * `value` - The density in ounces per cubic inch to convert
+    pub fn to_degrees_baume_light(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_degrees_baume_light(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// The ounce per cubic inch as a typed unit of density.
+    pub const UNIT: Unit<Density> = Unit::new(
+        "oz/in³",
+        "ounce per cubic inch",
+        to_kilograms_per_cubic_meter,
+        super::kilograms_per_cubic_meter::to_ounces_per_cubic_inch,
+    );
+
+    /// Creates a typed density quantity expressed in ounces per cubic inch.
+    /// # Arguments
+    /// * `value` - The density in ounces per cubic inch
+    pub fn new(value: f64) -> Quantity<Density> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed density quantity in ounces per cubic inch.
+    /// # Arguments
+    /// * `quantity` - The density quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Density>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Specific gravity (relative to water at 60 °F) conversion functions
+pub mod specific_gravity {
+    use crate::conversions::quantity::{Density, Quantity, Unit};
+
+    /// Converts specific gravity to kilograms per cubic meter.
+    /// # Arguments
+    /// * `value` - The density in specific gravity to convert
+    pub fn to_kilograms_per_cubic_meter(value: f64) -> f64 {
+        value * super::WATER_DENSITY_60F
+    }
+
+    /// Converts specific gravity to grams per cubic centimeter.
+    /// # Arguments
+    /// * `value` - The density in specific gravity to convert
+    pub fn to_grams_per_cubic_centimeter(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_grams_per_cubic_centimeter(
+            to_kilograms_per_cubic_meter(value),
+        )
+    }
+
+    /// Converts specific gravity to pounds per cubic foot.
+    /// # Arguments
+    /// * `value` - The density in specific gravity to convert
+    pub fn to_pounds_per_cubic_foot(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_cubic_foot(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts specific gravity to pounds per cubic inch.
+    /// # Arguments
+    /// * `value` - The density in specific gravity to convert
+    pub fn to_pounds_per_cubic_inch(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_cubic_inch(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts specific gravity to pounds per US gallon.
+    /// # Arguments
+    /// * `value` - The density in specific gravity to convert
+    pub fn to_pounds_per_gallon_us(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_gallon_us(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts specific gravity to pounds per imperial gallon.
+    /// # Arguments
+    /// * `value` - The density in specific gravity to convert
+    pub fn to_pounds_per_gallon_uk(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_gallon_uk(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts specific gravity to ounces per cubic inch.
+    /// # Arguments
+    /// * `value` - The density in specific gravity to convert
+    pub fn to_ounces_per_cubic_inch(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_ounces_per_cubic_inch(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts specific gravity to degrees API.
+    /// # Arguments
+    /// * `value` - The density in specific gravity to convert
+    pub fn to_degrees_api(value: f64) -> f64 {
+        super::API_NUMERATOR / value - super::API_OFFSET
+    }
+
+    /// Converts specific gravity to degrees Baumé (heavy).
+    /// # Arguments
+    /// * `value` - The density in specific gravity to convert
+    pub fn to_degrees_baume_heavy(value: f64) -> f64 {
+        super::BAUME_HEAVY_MODULUS - super::BAUME_HEAVY_MODULUS / value
+    }
+
+    /// Converts specific gravity to degrees Baumé (light).
+    /// # Arguments
+    /// * `value` - The density in specific gravity to convert
+    pub fn to_degrees_baume_light(value: f64) -> f64 {
+        super::BAUME_LIGHT_NUMERATOR / value - super::BAUME_LIGHT_OFFSET
+    }
+
+    /// The specific gravity as a typed unit of density.
+    pub const UNIT: Unit<Density> = Unit::new(
+        "SG",
+        "specific gravity",
+        to_kilograms_per_cubic_meter,
+        super::kilograms_per_cubic_meter::to_specific_gravity,
+    );
+
+    /// Creates a typed density quantity expressed in specific gravity.
+    /// # Arguments
+    /// * `value` - The density in specific gravity
+    pub fn new(value: f64) -> Quantity<Density> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed density quantity in specific gravity.
+    /// # Arguments
+    /// * `quantity` - The density quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Density>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// API gravity conversion functions
+///
+/// °API = 141.5 / SG − 131.5, so lighter oils have higher readings.
+pub mod degrees_api {
+    use crate::conversions::quantity::{Density, Quantity, Unit};
+
+    /// Converts degrees API to kilograms per cubic meter.
+    /// # Arguments
+    /// * `value` - The density in degrees API to convert
+    pub fn to_kilograms_per_cubic_meter(value: f64) -> f64 {
+        super::specific_gravity::to_kilograms_per_cubic_meter(to_specific_gravity(value))
+    }
+
+    /// Converts degrees API to grams per cubic centimeter.
+    /// # Arguments
+    /// * `value` - The density in degrees API to convert
+    pub fn to_grams_per_cubic_centimeter(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_grams_per_cubic_centimeter(
+            to_kilograms_per_cubic_meter(value),
+        )
+    }
+
+    /// Converts degrees API to pounds per cubic foot.
+    /// # Arguments
+    /// * `value` - The density in degrees API to convert
+    pub fn to_pounds_per_cubic_foot(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_cubic_foot(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts degrees API to pounds per cubic inch.
+    /// # Arguments
+    /// * `value` - The density in degrees API to convert
+    pub fn to_pounds_per_cubic_inch(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_cubic_inch(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts degrees API to pounds per US gallon.
+    /// # Arguments
+    /// * `value` - The density in degrees API to convert
+    pub fn to_pounds_per_gallon_us(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_gallon_us(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts degrees API to pounds per imperial gallon.
+    /// # Arguments
+    /// * `value` - The density in degrees API to convert
+    pub fn to_pounds_per_gallon_uk(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_gallon_uk(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts degrees API to ounces per cubic inch.
+    /// # Arguments
+    /// * `value` - The density in degrees API to convert
+    pub fn to_ounces_per_cubic_inch(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_ounces_per_cubic_inch(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts degrees API to specific gravity.
+    /// # Arguments
+    /// * `value` - The density in degrees API to convert
+    pub fn to_specific_gravity(value: f64) -> f64 {
+        super::API_NUMERATOR / (value + super::API_OFFSET)
+    }
+
+    /// Converts degrees API to degrees Baumé (heavy).
+    /// # Arguments
+    /// * `value` - The density in degrees API to convert
+    pub fn to_degrees_baume_heavy(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_degrees_baume_heavy(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts degrees API to degrees Baumé (light).
+    /// # Arguments
+    /// * `value` - The density in degrees API to convert
+    pub fn to_degrees_baume_light(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_degrees_baume_light(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// The degree API as a typed unit of density.
+    pub const UNIT: Unit<Density> = Unit::new(
+        "°API",
+        "degree API",
+        to_kilograms_per_cubic_meter,
+        super::kilograms_per_cubic_meter::to_degrees_api,
+    )
+    .with_reciprocal_scale();
+
+    /// Creates a typed density quantity expressed in degrees API.
+    /// # Arguments
+    /// * `value` - The density in degrees API
+    pub fn new(value: f64) -> Quantity<Density> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed density quantity in degrees API.
+    /// # Arguments
+    /// * `quantity` - The density quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Density>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Baumé scale for liquids heavier than water conversion functions
+///
+/// °Bé = 145 − 145 / SG, so water reads 0 and denser liquids read higher.
+pub mod degrees_baume_heavy {
+    use crate::conversions::quantity::{Density, Quantity, Unit};
+
+    /// Converts degrees Baumé (heavy) to kilograms per cubic meter.
+    /// # Arguments
+    /// * `value` - The density in degrees Baumé (heavy) to convert
+    pub fn to_kilograms_per_cubic_meter(value: f64) -> f64 {
+        super::specific_gravity::to_kilograms_per_cubic_meter(to_specific_gravity(value))
+    }
+
+    /// Converts degrees Baumé (heavy) to grams per cubic centimeter.
+    /// # Arguments
+    /// * `value` - The density in degrees Baumé (heavy) to convert
+    pub fn to_grams_per_cubic_centimeter(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_grams_per_cubic_centimeter(
+            to_kilograms_per_cubic_meter(value),
+        )
+    }
+
+    /// Converts degrees Baumé (heavy) to pounds per cubic foot.
+    /// # Arguments
+    /// * `value` - The density in degrees Baumé (heavy) to convert
+    pub fn to_pounds_per_cubic_foot(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_cubic_foot(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts degrees Baumé (heavy) to pounds per cubic inch.
+    /// # Arguments
+    /// * `value` - The density in degrees Baumé (heavy) to convert
+    pub fn to_pounds_per_cubic_inch(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_cubic_inch(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts degrees Baumé (heavy) to pounds per US gallon.
+    /// # Arguments
+    /// * `value` - The density in degrees Baumé (heavy) to convert
+    pub fn to_pounds_per_gallon_us(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_gallon_us(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts degrees Baumé (heavy) to pounds per imperial gallon.
+    /// # Arguments
+    /// * `value` - The density in degrees Baumé (heavy) to convert
+    pub fn to_pounds_per_gallon_uk(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_gallon_uk(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts degrees Baumé (heavy) to ounces per cubic inch.
+    /// # Arguments
+    /// * `value` - The density in degrees Baumé (heavy) to convert
+    pub fn to_ounces_per_cubic_inch(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_ounces_per_cubic_inch(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts degrees Baumé (heavy) to specific gravity.
+    /// # Arguments
+    /// * `value` - The density in degrees Baumé (heavy) to convert
+    pub fn to_specific_gravity(value: f64) -> f64 {
+        super::BAUME_HEAVY_MODULUS / (super::BAUME_HEAVY_MODULUS - value)
+    }
+
+    /// Converts degrees Baumé (heavy) to degrees API.
+    /// # Arguments
+    /// * `value` - The density in degrees Baumé (heavy) to convert
+    pub fn to_degrees_api(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_degrees_api(to_kilograms_per_cubic_meter(value))
+    }
+
+    /// Converts degrees Baumé (heavy) to degrees Baumé (light).
+    /// # Arguments
+    /// * `value` - The density in degrees Baumé (heavy) to convert
+    pub fn to_degrees_baume_light(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_degrees_baume_light(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// The degree Baumé (heavy) as a typed unit of density.
+    pub const UNIT: Unit<Density> = Unit::new(
+        "°Bé_heavy",
+        "degree Baumé (heavy)",
+        to_kilograms_per_cubic_meter,
+        super::kilograms_per_cubic_meter::to_degrees_baume_heavy,
+    )
+    .with_reciprocal_scale();
+
+    /// Creates a typed density quantity expressed in degrees Baumé (heavy).
+    /// # Arguments
+    /// * `value` - The density in degrees Baumé (heavy)
+    pub fn new(value: f64) -> Quantity<Density> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed density quantity in degrees Baumé (heavy).
+    /// # Arguments
+    /// * `quantity` - The density quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Density>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Baumé scale for liquids lighter than water conversion functions
+///
+/// °Bé = 140 / SG − 130, so water reads 10 and lighter liquids read higher.
+pub mod degrees_baume_light {
+    use crate::conversions::quantity::{Density, Quantity, Unit};
+
+    /// Converts degrees Baumé (light) to kilograms per cubic meter.
+    /// # Arguments
+    /// * `value` - The density in degrees Baumé (light) to convert
+    pub fn to_kilograms_per_cubic_meter(value: f64) -> f64 {
+        super::specific_gravity::to_kilograms_per_cubic_meter(to_specific_gravity(value))
+    }
+
+    /// Converts degrees Baumé (light) to grams per cubic centimeter.
+    /// # Arguments
+    /// * `value` - The density in degrees Baumé (light) to convert
+    pub fn to_grams_per_cubic_centimeter(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_grams_per_cubic_centimeter(
+            to_kilograms_per_cubic_meter(value),
+        )
+    }
+
+    /// Converts degrees Baumé (light) to pounds per cubic foot.
+    /// # Arguments
+    /// * `value` - The density in degrees Baumé (light) to convert
+    pub fn to_pounds_per_cubic_foot(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_cubic_foot(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts degrees Baumé (light) to pounds per cubic inch.
+    /// # Arguments
+    /// * `value` - The density in degrees Baumé (light) to convert
+    pub fn to_pounds_per_cubic_inch(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_cubic_inch(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts degrees Baumé (light) to pounds per US gallon.
+    /// # Arguments
+    /// * `value` - The density in degrees Baumé (light) to convert
+    pub fn to_pounds_per_gallon_us(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_gallon_us(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts degrees Baumé (light) to pounds per imperial gallon.
+    /// # Arguments
+    /// * `value` - The density in degrees Baumé (light) to convert
+    pub fn to_pounds_per_gallon_uk(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_pounds_per_gallon_uk(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts degrees Baumé (light) to ounces per cubic inch.
+    /// # Arguments
+    /// * `value` - The density in degrees Baumé (light) to convert
+    pub fn to_ounces_per_cubic_inch(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_ounces_per_cubic_inch(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// Converts degrees Baumé (light) to specific gravity.
+    /// # Arguments
+    /// * `value` - The density in degrees Baumé (light) to convert
+    pub fn to_specific_gravity(value: f64) -> f64 {
+        super::BAUME_LIGHT_NUMERATOR / (value + super::BAUME_LIGHT_OFFSET)
+    }
+
+    /// Converts degrees Baumé (light) to degrees API.
+    /// # Arguments
+    /// * `value` - The density in degrees Baumé (light) to convert
+    pub fn to_degrees_api(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_degrees_api(to_kilograms_per_cubic_meter(value))
+    }
+
+    /// Converts degrees Baumé (light) to degrees Baumé (heavy).
+    /// # Arguments
+    /// * `value` - The density in degrees Baumé (light) to convert
+    pub fn to_degrees_baume_heavy(value: f64) -> f64 {
+        super::kilograms_per_cubic_meter::to_degrees_baume_heavy(to_kilograms_per_cubic_meter(
+            value,
+        ))
+    }
+
+    /// The degree Baumé (light) as a typed unit of density.
+    pub const UNIT: Unit<Density> = Unit::new(
+        "°Bé_light",
+        "degree Baumé (light)",
+        to_kilograms_per_cubic_meter,
+        super::kilograms_per_cubic_meter::to_degrees_baume_light,
+    )
+    .with_reciprocal_scale();
+
+    /// Creates a typed density quantity expressed in degrees Baumé (light).
+    /// # Arguments
+    /// * `value` - The density in degrees Baumé (light)
+    pub fn new(value: f64) -> Quantity<Density> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed density quantity in degrees Baumé (light).
+    /// # Arguments
+    /// * `quantity` - The density quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Density>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// General density conversion function that accepts string unit names
+///
+/// Converts a density from one unit to another using string identifiers.
+/// Symbols are case-sensitive, names are case-insensitive, and common
+/// abbreviations are supported. `°API`, `°Bé_heavy` and `°Bé_light` readings
+/// must correspond to a positive density, or the conversion fails with
+/// [`ConversionError::NonPositiveDensity`].
+///
+/// # Arguments
+///
+/// * `value` - The numeric value to convert
+/// * `from_unit` - The source unit (e.g., "kg/m³", "g/cm³", "g/mL", "lb/ft³", "lb/in³",
+///   "lb/gal", "lb/gal_uk", "oz/in³", "SG", "°API", "°Bé_heavy", "°Bé_light")
+/// * `to_unit` - The target unit using the same abbreviations
+///
+/// # Returns
+/// * `Ok(f64)` - The converted value
+/// * `Err(ConversionError)` - The reason the conversion is not supported
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::convert_density;
+///
+/// let pounds_per_gallon = convert_density(1.0, "g/mL", "lb/gal").unwrap();
+/// assert!((pounds_per_gallon - 8.345).abs() < 0.001);
+///
+/// // West Texas Intermediate crude is about 39.6 °API
+/// let specific_gravity = convert_density(39.6, "°API", "SG").unwrap();
+/// assert!((specific_gravity - 0.827).abs() < 0.001);
+/// ```
+pub fn convert_density(value: f64, from_unit: &str, to_unit: &str) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from_unit, to_unit, Category::Density)
+}
+
+/// Density units known to the [`UnitRegistry`], with kilograms per cubic meter
+/// as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new(
+            "kg/m³",
+            "kilogram per cubic meter",
+            &[
+                "kg/m3",
+                "kg/m^3",
+                "g/L",
+                "g/l",
+                "kilograms per cubic meter",
+                "kilograms per cubic metre",
+                "kilograms_per_cubic_meter",
+            ],
+            Category::Density,
+            1.0,
+        ),
+        UnitDef::new(
+            "g/cm³",
+            "gram per cubic centimeter",
+            &[
+                "g/cm3",
+                "g/cc",
+                "g/mL",
+                "g/ml",
+                "kg/L",
+                "kg/l",
+                "grams per cubic centimeter",
+                "grams per milliliter",
+                "grams_per_cubic_centimeter",
+            ],
+            Category::Density,
+            grams_per_cubic_centimeter::to_kilograms_per_cubic_meter(1.0),
+        )
+        .with_exact_factor("1000"),
+        UnitDef::new(
+            "lb/ft³",
+            "pound per cubic foot",
+            &[
+                "lb/ft3",
+                "pcf",
+                "pounds per cubic foot",
+                "pounds_per_cubic_foot",
+            ],
+            Category::Density,
+            pounds_per_cubic_foot::to_kilograms_per_cubic_meter(1.0),
+        )
+        .with_exact_factor("0.45359237/0.3048/0.3048/0.3048"),
+        UnitDef::new(
+            "lb/in³",
+            "pound per cubic inch",
+            &["lb/in3", "pounds per cubic inch", "pounds_per_cubic_inch"],
+            Category::Density,
+            pounds_per_cubic_inch::to_kilograms_per_cubic_meter(1.0),
+        )
+        .with_exact_factor("0.45359237/0.0254/0.0254/0.0254"),
+        UnitDef::new(
+            "lb/gal",
+            "pound per US gallon",
+            &[
+                "lb/gal_us",
+                "ppg",
+                "pounds per gallon",
+                "pounds_per_gallon_us",
+            ],
+            Category::Density,
+            pounds_per_gallon_us::to_kilograms_per_cubic_meter(1.0),
+        )
+        .with_exact_factor("0.45359237/0.003785411784"),
+        UnitDef::new(
+            "lb/gal_uk",
+            "pound per imperial gallon",
+            &["pounds per imperial gallon", "pounds_per_gallon_uk"],
+            Category::Density,
+            pounds_per_gallon_uk::to_kilograms_per_cubic_meter(1.0),
+        )
+        .with_exact_factor("0.45359237/0.00454609"),
+        UnitDef::new(
+            "oz/in³",
+            "ounce per cubic inch",
+            &["oz/in3", "ounces per cubic inch", "ounces_per_cubic_inch"],
+            Category::Density,
+            ounces_per_cubic_inch::to_kilograms_per_cubic_meter(1.0),
+        )
+        .with_exact_factor("0.45359237/16/0.0254/0.0254/0.0254"),
+        UnitDef::new(
+            "SG",
+            "specific gravity",
+            &["sg", "relative density", "specific_gravity"],
+            Category::Density,
+            specific_gravity::to_kilograms_per_cubic_meter(1.0),
+        ),
+        UnitDef::new(
+            "°API",
+            "degree API",
+            &["API", "degAPI", "degrees API", "API gravity", "degrees_api"],
+            Category::Density,
+            API_NUMERATOR * WATER_DENSITY_60F,
+        )
+        .with_offset(-API_OFFSET)
+        .with_exact_factor("141.5*999.016")
+        .with_reciprocal_scale(),
+        // "°Bé" alone could be either Baumé scale, so it is ambiguous by design
+        UnitDef::new(
+            "°Bé_heavy",
+            "degree Baumé (heavy)",
+            &[
+                "°Bé",
+                "Bé",
+                "Be_heavy",
+                "degrees Baumé heavy",
+                "degrees_baume_heavy",
+            ],
+            Category::Density,
+            -BAUME_HEAVY_MODULUS * WATER_DENSITY_60F,
+        )
+        .with_offset(BAUME_HEAVY_MODULUS)
+        .with_exact_factor("-145*999.016")
+        .with_reciprocal_scale(),
+        UnitDef::new(
+            "°Bé_light",
+            "degree Baumé (light)",
+            &[
+                "°Bé",
+                "Bé",
+                "Be_light",
+                "degrees Baumé light",
+                "degrees_baume_light",
+            ],
+            Category::Density,
+            BAUME_LIGHT_NUMERATOR * WATER_DENSITY_60F,
+        )
+        .with_offset(-BAUME_LIGHT_OFFSET)
+        .with_exact_factor("140*999.016")
+        .with_reciprocal_scale(),
+    ]
+}
@@ -0,0 +1,2064 @@
+//! Energy conversion functions organized by unit type
+//! All conversions use joules as the base unit for accuracy and consistency
+
+use super::category::Category;
+use super::error::ConversionError;
+use super::length::METERS_PER_FOOT;
+use super::pressure::STANDARD_GRAVITY;
+use super::registry::{UnitDef, UnitRegistry};
+use super::weight::KILOGRAMS_PER_POUND;
+
+/// Joules in one thermochemical calorie (exactly 4.184 J), the calorie used in
+/// chemistry and in most food energy labelling
+pub const JOULES_PER_THERMOCHEMICAL_CALORIE: f64 = 4.184;
+
+/// Joules in one international steam table calorie (exactly 4.1868 J), the
+/// calorie used in engineering and steam tables
+pub const JOULES_PER_INTERNATIONAL_CALORIE: f64 = 4.1868;
+
+/// Joules in one watt-hour (a watt for 3600 seconds)
+pub const JOULES_PER_WATT_HOUR: f64 = 3600.0;
+
+/// Joules in one international table British thermal unit (exactly
+/// 1055.05585262 J)
+pub const JOULES_PER_BTU: f64 = 1_055.055_852_62;
+
+/// Joules in one therm (100 000 international table BTU)
+pub const JOULES_PER_THERM: f64 = 100_000.0 * JOULES_PER_BTU;
+
+/// Joules in one electronvolt (exactly 1.602176634 × 10⁻¹⁹ J since the 2019 SI
+/// redefinition)
+pub const JOULES_PER_ELECTRONVOLT: f64 = 1.602_176_634e-19;
+
+/// Joules in one foot-pound-force
+pub const JOULES_PER_FOOT_POUND: f64 = METERS_PER_FOOT * KILOGRAMS_PER_POUND * STANDARD_GRAVITY;
+
+/// Joule conversion functions
+pub mod joules {
+    use crate::conversions::quantity::{Energy, Quantity, Unit};
+
+    /// Converts joules to kilojoules.
+    /// # Arguments
+    /// * `value` - The energy in joules to convert
+    pub fn to_kilojoules(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// Converts joules to megajoules.
+    /// # Arguments
+    /// * `value` - The energy in joules to convert
+    pub fn to_megajoules(value: f64) -> f64 {
+        value / 1_000_000.0
+    }
+
+    /// Converts joules to thermochemical calories.
+    /// # Arguments
+    /// * `value` - The energy in joules to convert
+    pub fn to_thermochemical_calories(value: f64) -> f64 {
+        value / super::JOULES_PER_THERMOCHEMICAL_CALORIE
+    }
+
+    /// Converts joules to international table calories.
+    /// # Arguments
+    /// * `value` - The energy in joules to convert
+    pub fn to_international_calories(value: f64) -> f64 {
+        value / super::JOULES_PER_INTERNATIONAL_CALORIE
+    }
+
+    /// Converts joules to thermochemical kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in joules to convert
+    pub fn to_thermochemical_kilocalories(value: f64) -> f64 {
+        value / super::JOULES_PER_THERMOCHEMICAL_CALORIE / 1000.0
+    }
+
+    /// Converts joules to international table kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in joules to convert
+    pub fn to_international_kilocalories(value: f64) -> f64 {
+        value / super::JOULES_PER_INTERNATIONAL_CALORIE / 1000.0
+    }
+
+    /// Converts joules to watt-hours.
+    /// # Arguments
+    /// * `value` - The energy in joules to convert
+    pub fn to_watt_hours(value: f64) -> f64 {
+        value / super::JOULES_PER_WATT_HOUR
+    }
+
+    /// Converts joules to kilowatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in joules to convert
+    pub fn to_kilowatt_hours(value: f64) -> f64 {
+        value / super::JOULES_PER_WATT_HOUR / 1000.0
+    }
+
+    /// Converts joules to megawatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in joules to convert
+    pub fn to_megawatt_hours(value: f64) -> f64 {
+        value / super::JOULES_PER_WATT_HOUR / 1_000_000.0
+    }
+
+    /// Converts joules to British thermal units.
+    /// # Arguments
+    /// * `value` - The energy in joules to convert
+    pub fn to_btu(value: f64) -> f64 {
+        value / super::JOULES_PER_BTU
+    }
+
+    /// Converts joules to therms.
+    /// # Arguments
+    /// * `value` - The energy in joules to convert
+    pub fn to_therms(value: f64) -> f64 {
+        value / super::JOULES_PER_THERM
+    }
+
+    /// Converts joules to electronvolts.
+    /// # Arguments
+    /// * `value` - The energy in joules to convert
+    pub fn to_electronvolts(value: f64) -> f64 {
+        value / super::JOULES_PER_ELECTRONVOLT
+    }
+
+    /// Converts joules to ergs.
+    /// # Arguments
+    /// * `value` - The energy in joules to convert
+    pub fn to_ergs(value: f64) -> f64 {
+        value * 1e7
+    }
+
+    /// Converts joules to foot-pounds.
+    /// # Arguments
+    /// * `value` - The energy in joules to convert
+    pub fn to_foot_pounds(value: f64) -> f64 {
+        value / super::JOULES_PER_FOOT_POUND
+    }
+
+    /// The joule as a typed unit of energy.
+    pub const UNIT: Unit<Energy> = Unit::new("J", "joule", |value| value, |value| value);
+
+    /// Creates a typed energy quantity expressed in joules.
+    /// # Arguments
+    /// * `value` - The energy in joules
+    pub fn new(value: f64) -> Quantity<Energy> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed energy quantity in joules.
+    /// # Arguments
+    /// * `quantity` - The energy quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Energy>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Kilojoule conversion functions
+pub mod kilojoules {
+    use crate::conversions::quantity::{Energy, Quantity, Unit};
+
+    /// Converts kilojoules to joules.
+    /// # Arguments
+    /// * `value` - The energy in kilojoules to convert
+    pub fn to_joules(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// Converts kilojoules to megajoules.
+    /// # Arguments
+    /// * `value` - The energy in kilojoules to convert
+    pub fn to_megajoules(value: f64) -> f64 {
+        super::joules::to_megajoules(to_joules(value))
+    }
+
+    /// Converts kilojoules to thermochemical calories.
+    /// # Arguments
+    /// * `value` - The energy in kilojoules to convert
+    pub fn to_thermochemical_calories(value: f64) -> f64 {
+        super::joules::to_thermochemical_calories(to_joules(value))
+    }
+
+    /// Converts kilojoules to international table calories.
+    /// # Arguments
+    /// * `value` - The energy in kilojoules to convert
+    pub fn to_international_calories(value: f64) -> f64 {
+        super::joules::to_international_calories(to_joules(value))
+    }
+
+    /// Converts kilojoules to thermochemical kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in kilojoules to convert
+    pub fn to_thermochemical_kilocalories(value: f64) -> f64 {
+        super::joules::to_thermochemical_kilocalories(to_joules(value))
+    }
+
+    /// Converts kilojoules to international table kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in kilojoules to convert
+    pub fn to_international_kilocalories(value: f64) -> f64 {
+        super::joules::to_international_kilocalories(to_joules(value))
+    }
+
+    /// Converts kilojoules to watt-hours.
+    /// # Arguments
+    /// * `value` - The energy in kilojoules to convert
+    pub fn to_watt_hours(value: f64) -> f64 {
+        super::joules::to_watt_hours(to_joules(value))
+    }
+
+    /// Converts kilojoules to kilowatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in kilojoules to convert
+    pub fn to_kilowatt_hours(value: f64) -> f64 {
+        super::joules::to_kilowatt_hours(to_joules(value))
+    }
+
+    /// Converts kilojoules to megawatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in kilojoules to convert
+    pub fn to_megawatt_hours(value: f64) -> f64 {
+        super::joules::to_megawatt_hours(to_joules(value))
+    }
+
+    /// Converts kilojoules to British thermal units.
+    /// # Arguments
+    /// * `value` - The energy in kilojoules to convert
+    pub fn to_btu(value: f64) -> f64 {
+        super::joules::to_btu(to_joules(value))
+    }
+
+    /// Converts kilojoules to therms.
+    /// # Arguments
+    /// * `value` - The energy in kilojoules to convert
+    pub fn to_therms(value: f64) -> f64 {
+        super::joules::to_therms(to_joules(value))
+    }
+
+    /// Converts kilojoules to electronvolts.
+    /// # Arguments
+    /// * `value` - The energy in kilojoules to convert
+    pub fn to_electronvolts(value: f64) -> f64 {
+        super::joules::to_electronvolts(to_joules(value))
+    }
+
+    /// Converts kilojoules to ergs.
+    /// # Arguments
+    /// * `value` - The energy in kilojoules to convert
+    pub fn to_ergs(value: f64) -> f64 {
+        super::joules::to_ergs(to_joules(value))
+    }
+
+    /// Converts kilojoules to foot-pounds.
+    /// # Arguments
+    /// * `value` - The energy in kilojoules to convert
+    pub fn to_foot_pounds(value: f64) -> f64 {
+        super::joules::to_foot_pounds(to_joules(value))
+    }
+
+    /// The kilojoule as a typed unit of energy.
+    pub const UNIT: Unit<Energy> =
+        Unit::new("kJ", "kilojoule", to_joules, super::joules::to_kilojoules);
+
+    /// Creates a typed energy quantity expressed in kilojoules.
+    /// # Arguments
+    /// * `value` - The energy in kilojoules
+    pub fn new(value: f64) -> Quantity<Energy> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed energy quantity in kilojoules.
+    /// # Arguments
+    /// * `quantity` - The energy quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Energy>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Megajoule conversion functions
+pub mod megajoules {
+    use crate::conversions::quantity::{Energy, Quantity, Unit};
+
+    /// Converts megajoules to joules.
+    /// # Arguments
+    /// * `value` - The energy in megajoules to convert
+    pub fn to_joules(value: f64) -> f64 {
+        value * 1_000_000.0
+    }
+
+    /// Converts megajoules to kilojoules.
+    /// # Arguments
+    /// * `value` - The energy in megajoules to convert
+    pub fn to_kilojoules(value: f64) -> f64 {
+        super::joules::to_kilojoules(to_joules(value))
+    }
+
+    /// Converts megajoules to thermochemical calories.
+    /// # Arguments
+    /// * `value` - The energy in megajoules to convert
+    pub fn to_thermochemical_calories(value: f64) -> f64 {
+        super::joules::to_thermochemical_calories(to_joules(value))
+    }
+
+    /// Converts megajoules to international table calories.
+    /// # Arguments
+    /// * `value` - The energy in megajoules to convert
+    pub fn to_international_calories(value: f64) -> f64 {
+        super::joules::to_international_calories(to_joules(value))
+    }
+
+    /// Converts megajoules to thermochemical kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in megajoules to convert
+    pub fn to_thermochemical_kilocalories(value: f64) -> f64 {
+        super::joules::to_thermochemical_kilocalories(to_joules(value))
+    }
+
+    /// Converts megajoules to international table kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in megajoules to convert
+    pub fn to_international_kilocalories(value: f64) -> f64 {
+        super::joules::to_international_kilocalories(to_joules(value))
+    }
+
+    /// Converts megajoules to watt-hours.
+    /// # Arguments
+    /// * `value` - The energy in megajoules to convert
+    pub fn to_watt_hours(value: f64) -> f64 {
+        super::joules::to_watt_hours(to_joules(value))
+    }
+
+    /// Converts megajoules to kilowatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in megajoules to convert
+    pub fn to_kilowatt_hours(value: f64) -> f64 {
+        super::joules::to_kilowatt_hours(to_joules(value))
+    }
+
+    /// Converts megajoules to megawatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in megajoules to convert
+    pub fn to_megawatt_hours(value: f64) -> f64 {
+        super::joules::to_megawatt_hours(to_joules(value))
+    }
+
+    /// Converts megajoules to British thermal units.
+    /// # Arguments
+    /// * `value` - The energy in megajoules to convert
+    pub fn to_btu(value: f64) -> f64 {
+        super::joules::to_btu(to_joules(value))
+    }
+
+    /// Converts megajoules to therms.
+    /// # Arguments
+    /// * `value` - The energy in megajoules to convert
+    pub fn to_therms(value: f64) -> f64 {
+        super::joules::to_therms(to_joules(value))
+    }
+
+    /// Converts megajoules to electronvolts.
+    /// # Arguments
+    /// * `value` - The energy in megajoules to convert
+    pub fn to_electronvolts(value: f64) -> f64 {
+        super::joules::to_electronvolts(to_joules(value))
+    }
+
+    /// Converts megajoules to ergs.
+    /// # Arguments
+    /// * `value` - The energy in megajoules to convert
+    pub fn to_ergs(value: f64) -> f64 {
+        super::joules::to_ergs(to_joules(value))
+    }
+
+    /// Converts megajoules to foot-pounds.
+    /// # Arguments
+    /// * `value` - The energy in megajoules to convert
+    pub fn to_foot_pounds(value: f64) -> f64 {
+        super::joules::to_foot_pounds(to_joules(value))
+    }
+
+    /// The megajoule as a typed unit of energy.
+    pub const UNIT: Unit<Energy> =
+        Unit::new("MJ", "megajoule", to_joules, super::joules::to_megajoules);
+
+    /// Creates a typed energy quantity expressed in megajoules.
+    /// # Arguments
+    /// * `value` - The energy in megajoules
+    pub fn new(value: f64) -> Quantity<Energy> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed energy quantity in megajoules.
+    /// # Arguments
+    /// * `quantity` - The energy quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Energy>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Thermochemical calorie conversion functions (exactly 4.184 J)
+pub mod thermochemical_calories {
+    use crate::conversions::quantity::{Energy, Quantity, Unit};
+
+    /// Converts thermochemical calories to joules.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical calories to convert
+    pub fn to_joules(value: f64) -> f64 {
+        value * super::JOULES_PER_THERMOCHEMICAL_CALORIE
+    }
+
+    /// Converts thermochemical calories to kilojoules.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical calories to convert
+    pub fn to_kilojoules(value: f64) -> f64 {
+        super::joules::to_kilojoules(to_joules(value))
+    }
+
+    /// Converts thermochemical calories to megajoules.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical calories to convert
+    pub fn to_megajoules(value: f64) -> f64 {
+        super::joules::to_megajoules(to_joules(value))
+    }
+
+    /// Converts thermochemical calories to international table calories.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical calories to convert
+    pub fn to_international_calories(value: f64) -> f64 {
+        super::joules::to_international_calories(to_joules(value))
+    }
+
+    /// Converts thermochemical calories to thermochemical kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical calories to convert
+    pub fn to_thermochemical_kilocalories(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// Converts thermochemical calories to international table kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical calories to convert
+    pub fn to_international_kilocalories(value: f64) -> f64 {
+        super::joules::to_international_kilocalories(to_joules(value))
+    }
+
+    /// Converts thermochemical calories to watt-hours.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical calories to convert
+    pub fn to_watt_hours(value: f64) -> f64 {
+        super::joules::to_watt_hours(to_joules(value))
+    }
+
+    /// Converts thermochemical calories to kilowatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical calories to convert
+    pub fn to_kilowatt_hours(value: f64) -> f64 {
+        super::joules::to_kilowatt_hours(to_joules(value))
+    }
+
+    /// Converts thermochemical calories to megawatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical calories to convert
+    pub fn to_megawatt_hours(value: f64) -> f64 {
+        super::joules::to_megawatt_hours(to_joules(value))
+    }
+
+    /// Converts thermochemical calories to British thermal units.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical calories to convert
+    pub fn to_btu(value: f64) -> f64 {
+        super::joules::to_btu(to_joules(value))
+    }
+
+    /// Converts thermochemical calories to therms.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical calories to convert
+    pub fn to_therms(value: f64) -> f64 {
+        super::joules::to_therms(to_joules(value))
+    }
+
+    /// Converts thermochemical calories to electronvolts.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical calories to convert
+    pub fn to_electronvolts(value: f64) -> f64 {
+        super::joules::to_electronvolts(to_joules(value))
+    }
+
+    /// Converts thermochemical calories to ergs.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical calories to convert
+    pub fn to_ergs(value: f64) -> f64 {
+        super::joules::to_ergs(to_joules(value))
+    }
+
+    /// Converts thermochemical calories to foot-pounds.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical calories to convert
+    pub fn to_foot_pounds(value: f64) -> f64 {
+        super::joules::to_foot_pounds(to_joules(value))
+    }
+
+    /// The thermochemical calorie as a typed unit of energy.
+    pub const UNIT: Unit<Energy> = Unit::new(
+        "cal_th",
+        "thermochemical calorie",
+        to_joules,
+        super::joules::to_thermochemical_calories,
+    );
+
+    /// Creates a typed energy quantity expressed in thermochemical calories.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical calories
+    pub fn new(value: f64) -> Quantity<Energy> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed energy quantity in thermochemical calories.
+    /// # Arguments
+    /// * `quantity` - The energy quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Energy>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// International table calorie conversion functions (exactly 4.1868 J)
+pub mod international_calories {
+    use crate::conversions::quantity::{Energy, Quantity, Unit};
+
+    /// Converts international table calories to joules.
+    /// # Arguments
+    /// * `value` - The energy in international table calories to convert
+    pub fn to_joules(value: f64) -> f64 {
+        value * super::JOULES_PER_INTERNATIONAL_CALORIE
+    }
+
+    /// Converts international table calories to kilojoules.
+    /// # Arguments
+    /// * `value` - The energy in international table calories to convert
+    pub fn to_kilojoules(value: f64) -> f64 {
+        super::joules::to_kilojoules(to_joules(value))
+    }
+
+    /// Converts international table calories to megajoules.
+    /// # Arguments
+    /// * `value` - The energy in international table calories to convert
+    pub fn to_megajoules(value: f64) -> f64 {
+        super::joules::to_megajoules(to_joules(value))
+    }
+
+    /// Converts international table calories to thermochemical calories.
+    /// # Arguments
+    /// * `value` - The energy in international table calories to convert
+    pub fn to_thermochemical_calories(value: f64) -> f64 {
+        super::joules::to_thermochemical_calories(to_joules(value))
+    }
+
+    /// Converts international table calories to thermochemical kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in international table calories to convert
+    pub fn to_thermochemical_kilocalories(value: f64) -> f64 {
+        super::joules::to_thermochemical_kilocalories(to_joules(value))
+    }
+
+    /// Converts international table calories to international table kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in international table calories to convert
+    pub fn to_international_kilocalories(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// Converts international table calories to watt-hours.
+    /// # Arguments
+    /// * `value` - The energy in international table calories to convert
+    pub fn to_watt_hours(value: f64) -> f64 {
+        super::joules::to_watt_hours(to_joules(value))
+    }
+
+    /// Converts international table calories to kilowatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in international table calories to convert
+    pub fn to_kilowatt_hours(value: f64) -> f64 {
+        super::joules::to_kilowatt_hours(to_joules(value))
+    }
+
+    /// Converts international table calories to megawatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in international table calories to convert
+    pub fn to_megawatt_hours(value: f64) -> f64 {
+        super::joules::to_megawatt_hours(to_joules(value))
+    }
+
+    /// Converts international table calories to British thermal units.
+    /// # Arguments
+    /// * `value` - The energy in international table calories to convert
+    pub fn to_btu(value: f64) -> f64 {
+        super::joules::to_btu(to_joules(value))
+    }
+
+    /// Converts international table calories to therms.
+    /// # Arguments
+    /// * `value` - The energy in international table calories to convert
+    pub fn to_therms(value: f64) -> f64 {
+        super::joules::to_therms(to_joules(value))
+    }
+
+    /// Converts international table calories to electronvolts.
+    /// # Arguments
+    /// * `value` - The energy in international table calories to convert
+    pub fn to_electronvolts(value: f64) -> f64 {
+        super::joules::to_electronvolts(to_joules(value))
+    }
+
+    /// Converts international table calories to ergs.
+    /// # Arguments
+    /// * `value` - The energy in international table calories to convert
+    pub fn to_ergs(value: f64) -> f64 {
+        super::joules::to_ergs(to_joules(value))
+    }
+
+    /// Converts international table calories to foot-pounds.
+    /// # Arguments
+    /// * `value` - The energy in international table calories to convert
+    pub fn to_foot_pounds(value: f64) -> f64 {
+        super::joules::to_foot_pounds(to_joules(value))
+    }
+
+    /// The international table calorie as a typed unit of energy.
+    pub const UNIT: Unit<Energy> = Unit::new(
+        "cal_IT",
+        "international table calorie",
+        to_joules,
+        super::joules::to_international_calories,
+    );
+
+    /// Creates a typed energy quantity expressed in international table calories.
+    /// # Arguments
+    /// * `value` - The energy in international table calories
+    pub fn new(value: f64) -> Quantity<Energy> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed energy quantity in international table calories.
+    /// # Arguments
+    /// * `quantity` - The energy quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Energy>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Thermochemical kilocalorie conversion functions, the food "Calorie" in most
+/// nutrition labelling
+pub mod thermochemical_kilocalories {
+    use crate::conversions::quantity::{Energy, Quantity, Unit};
+
+    /// Converts thermochemical kilocalories to joules.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical kilocalories to convert
+    pub fn to_joules(value: f64) -> f64 {
+        value * super::JOULES_PER_THERMOCHEMICAL_CALORIE * 1000.0
+    }
+
+    /// Converts thermochemical kilocalories to kilojoules.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical kilocalories to convert
+    pub fn to_kilojoules(value: f64) -> f64 {
+        super::joules::to_kilojoules(to_joules(value))
+    }
+
+    /// Converts thermochemical kilocalories to megajoules.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical kilocalories to convert
+    pub fn to_megajoules(value: f64) -> f64 {
+        super::joules::to_megajoules(to_joules(value))
+    }
+
+    /// Converts thermochemical kilocalories to thermochemical calories.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical kilocalories to convert
+    pub fn to_thermochemical_calories(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// Converts thermochemical kilocalories to international table calories.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical kilocalories to convert
+    pub fn to_international_calories(value: f64) -> f64 {
+        super::joules::to_international_calories(to_joules(value))
+    }
+
+    /// Converts thermochemical kilocalories to international table kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical kilocalories to convert
+    pub fn to_international_kilocalories(value: f64) -> f64 {
+        super::joules::to_international_kilocalories(to_joules(value))
+    }
+
+    /// Converts thermochemical kilocalories to watt-hours.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical kilocalories to convert
+    pub fn to_watt_hours(value: f64) -> f64 {
+        super::joules::to_watt_hours(to_joules(value))
+    }
+
+    /// Converts thermochemical kilocalories to kilowatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical kilocalories to convert
+    pub fn to_kilowatt_hours(value: f64) -> f64 {
+        super::joules::to_kilowatt_hours(to_joules(value))
+    }
+
+    /// Converts thermochemical kilocalories to megawatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical kilocalories to convert
+    pub fn to_megawatt_hours(value: f64) -> f64 {
+        super::joules::to_megawatt_hours(to_joules(value))
+    }
+
+    /// Converts thermochemical kilocalories to British thermal units.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical kilocalories to convert
+    pub fn to_btu(value: f64) -> f64 {
+        super::joules::to_btu(to_joules(value))
+    }
+
+    /// Converts thermochemical kilocalories to therms.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical kilocalories to convert
+    pub fn to_therms(value: f64) -> f64 {
+        super::joules::to_therms(to_joules(value))
+    }
+
+    /// Converts thermochemical kilocalories to electronvolts.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical kilocalories to convert
+    pub fn to_electronvolts(value: f64) -> f64 {
+        super::joules::to_electronvolts(to_joules(value))
+    }
+
+    /// Converts thermochemical kilocalories to ergs.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical kilocalories to convert
+    pub fn to_ergs(value: f64) -> f64 {
+        super::joules::to_ergs(to_joules(value))
+    }
+
+    /// Converts thermochemical kilocalories to foot-pounds.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical kilocalories to convert
+    pub fn to_foot_pounds(value: f64) -> f64 {
+        super::joules::to_foot_pounds(to_joules(value))
+    }
+
+    /// The thermochemical kilocalorie as a typed unit of energy.
+    pub const UNIT: Unit<Energy> = Unit::new(
+        "kcal_th",
+        "thermochemical kilocalorie",
+        to_joules,
+        super::joules::to_thermochemical_kilocalories,
+    );
+
+    /// Creates a typed energy quantity expressed in thermochemical kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in thermochemical kilocalories
+    pub fn new(value: f64) -> Quantity<Energy> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed energy quantity in thermochemical kilocalories.
+    /// # Arguments
+    /// * `quantity` - The energy quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Energy>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// International table kilocalorie conversion functions
+pub mod international_kilocalories {
+    use crate::conversions::quantity::{Energy, Quantity, Unit};
+
+    /// Converts international table kilocalories to joules.
+    /// # Arguments
+    /// * `value` - The energy in international table kilocalories to convert
+    pub fn to_joules(value: f64) -> f64 {
+        value * super::JOULES_PER_INTERNATIONAL_CALORIE * 1000.0
+    }
+
+    /// Converts international table kilocalories to kilojoules.
+    /// # Arguments
+    /// * `value` - The energy in international table kilocalories to convert
+    pub fn to_kilojoules(value: f64) -> f64 {
+        super::joules::to_kilojoules(to_joules(value))
+    }
+
+    /// Converts international table kilocalories to megajoules.
+    /// # Arguments
+    /// * `value` - The energy in international table kilocalories to convert
+    pub fn to_megajoules(value: f64) -> f64 {
+        super::joules::to_megajoules(to_joules(value))
+    }
+
+    /// Converts international table kilocalories to thermochemical calories.
+    /// # Arguments
+    /// * `value` - The energy in international table kilocalories to convert
+    pub fn to_thermochemical_calories(value: f64) -> f64 {
+        super::joules::to_thermochemical_calories(to_joules(value))
+    }
+
+    /// Converts international table kilocalories to international table calories.
+    /// # Arguments
+    /// * `value` - The energy in international table kilocalories to convert
+    pub fn to_international_calories(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// Converts international table kilocalories to thermochemical kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in international table kilocalories to convert
+    pub fn to_thermochemical_kilocalories(value: f64) -> f64 {
+        super::joules::to_thermochemical_kilocalories(to_joules(value))
+    }
+
+    /// Converts international table kilocalories to watt-hours.
+    /// # Arguments
+    /// * `value` - The energy in international table kilocalories to convert
+    pub fn to_watt_hours(value: f64) -> f64 {
+        super::joules::to_watt_hours(to_joules(value))
+    }
+
+    /// Converts international table kilocalories to kilowatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in international table kilocalories to convert
+    pub fn to_kilowatt_hours(value: f64) -> f64 {
+        super::joules::to_kilowatt_hours(to_joules(value))
+    }
+
+    /// Converts international table kilocalories to megawatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in international table kilocalories to convert
+    pub fn to_megawatt_hours(value: f64) -> f64 {
+        super::joules::to_megawatt_hours(to_joules(value))
+    }
+
+    /// Converts international table kilocalories to British thermal units.
+    /// # Arguments
+    /// * `value` - The energy in international table kilocalories to convert
+    pub fn to_btu(value: f64) -> f64 {
+        super::joules::to_btu(to_joules(value))
+    }
+
+    /// Converts international table kilocalories to therms.
+    /// # Arguments
+    /// * `value` - The energy in international table kilocalories to convert
+    pub fn to_therms(value: f64) -> f64 {
+        super::joules::to_therms(to_joules(value))
+    }
+
+    /// Converts international table kilocalories to electronvolts.
+    /// # Arguments
+    /// * `value` - The energy in international table kilocalories to convert
+    pub fn to_electronvolts(value: f64) -> f64 {
+        super::joules::to_electronvolts(to_joules(value))
+    }
+
+    /// Converts international table kilocalories to ergs.
+    /// # Arguments
+    /// * `value` - The energy in international table kilocalories to convert
+    pub fn to_ergs(value: f64) -> f64 {
+        super::joules::to_ergs(to_joules(value))
+    }
+
+    /// Converts international table kilocalories to foot-pounds.
+    /// # Arguments
+    /// * `value` - The energy in international table kilocalories to convert
+    pub fn to_foot_pounds(value: f64) -> f64 {
+        super::joules::to_foot_pounds(to_joules(value))
+    }
+
+    /// The international table kilocalorie as a typed unit of energy.
+    pub const UNIT: Unit<Energy> = Unit::new(
+        "kcal_IT",
+        "international table kilocalorie",
+        to_joules,
+        super::joules::to_international_kilocalories,
+    );
+
+    /// Creates a typed energy quantity expressed in international table kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in international table kilocalories
+    pub fn new(value: f64) -> Quantity<Energy> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed energy quantity in international table kilocalories.
+    /// # Arguments
+    /// * `quantity` - The energy quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Energy>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Watt-hour conversion functions
+pub mod watt_hours {
+    use crate::conversions::quantity::{Energy, Quantity, Unit};
+
+    /// Converts watt-hours to joules.
+    /// # Arguments
+    /// * `value` - The energy in watt-hours to convert
+    pub fn to_joules(value: f64) -> f64 {
+        value * super::JOULES_PER_WATT_HOUR
+    }
+
+    /// Converts watt-hours to kilojoules.
+    /// # Arguments
+    /// * `value` - The energy in watt-hours to convert
+    pub fn to_kilojoules(value: f64) -> f64 {
+        super::joules::to_kilojoules(to_joules(value))
+    }
+
+    /// Converts watt-hours to megajoules.
+    /// # Arguments
+    /// * `value` - The energy in watt-hours to convert
+    pub fn to_megajoules(value: f64) -> f64 {
+        super::joules::to_megajoules(to_joules(value))
+    }
+
+    /// Converts watt-hours to thermochemical calories.
+    /// # Arguments
+    /// * `value` - The energy in watt-hours to convert
+    pub fn to_thermochemical_calories(value: f64) -> f64 {
+        super::joules::to_thermochemical_calories(to_joules(value))
+    }
+
+    /// Converts watt-hours to international table calories.
+    /// # Arguments
+    /// * `value` - The energy in watt-hours to convert
+    pub fn to_international_calories(value: f64) -> f64 {
+        super::joules::to_international_calories(to_joules(value))
+    }
+
+    /// Converts watt-hours to thermochemical kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in watt-hours to convert
+    pub fn to_thermochemical_kilocalories(value: f64) -> f64 {
+        super::joules::to_thermochemical_kilocalories(to_joules(value))
+    }
+
+    /// Converts watt-hours to international table kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in watt-hours to convert
+    pub fn to_international_kilocalories(value: f64) -> f64 {
+        super::joules::to_international_kilocalories(to_joules(value))
+    }
+
+    /// Converts watt-hours to kilowatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in watt-hours to convert
+    pub fn to_kilowatt_hours(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// Converts watt-hours to megawatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in watt-hours to convert
+    pub fn to_megawatt_hours(value: f64) -> f64 {
+        value / 1_000_000.0
+    }
+
+    /// Converts watt-hours to British thermal units.
+    /// # Arguments
+    /// * `value` - The energy in watt-hours to convert
+    pub fn to_btu(value: f64) -> f64 {
+        super::joules::to_btu(to_joules(value))
+    }
+
+    /// Converts watt-hours to therms.
+    /// # Arguments
+    /// * `value` - The energy in watt-hours to convert
+    pub fn to_therms(value: f64) -> f64 {
+        super::joules::to_therms(to_joules(value))
+    }
+
+    /// Converts watt-hours to electronvolts.
+    /// # Arguments
+    /// * `value` - The energy in watt-hours to convert
+    pub fn to_electronvolts(value: f64) -> f64 {
+        super::joules::to_electronvolts(to_joules(value))
+    }
+
+    /// Converts watt-hours to ergs.
+    /// # Arguments
+    /// * `value` - The energy in watt-hours to convert
+    pub fn to_ergs(value: f64) -> f64 {
+        super::joules::to_ergs(to_joules(value))
+    }
+
+    /// Converts watt-hours to foot-pounds.
+    /// # Arguments
+    /// * `value` - The energy in watt-hours to convert
+    pub fn to_foot_pounds(value: f64) -> f64 {
+        super::joules::to_foot_pounds(to_joules(value))
+    }
+
+    /// The watt-hour as a typed unit of energy.
+    pub const UNIT: Unit<Energy> =
+        Unit::new("Wh", "watt-hour", to_joules, super::joules::to_watt_hours);
+
+    /// Creates a typed energy quantity expressed in watt-hours.
+    /// # Arguments
+    /// * `value` - The energy in watt-hours
+    pub fn new(value: f64) -> Quantity<Energy> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed energy quantity in watt-hours.
+    /// # Arguments
+    /// * `quantity` - The energy quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Energy>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Kilowatt-hour conversion functions
+pub mod kilowatt_hours {
+    use crate::conversions::quantity::{Energy, Quantity, Unit};
+
+    /// Converts kilowatt-hours to joules.
+    /// # Arguments
+    /// * `value` - The energy in kilowatt-hours to convert
+    pub fn to_joules(value: f64) -> f64 {
+        value * super::JOULES_PER_WATT_HOUR * 1000.0
+    }
+
+    /// Converts kilowatt-hours to kilojoules.
+    /// # Arguments
+    /// * `value` - The energy in kilowatt-hours to convert
+    pub fn to_kilojoules(value: f64) -> f64 {
+        super::joules::to_kilojoules(to_joules(value))
+    }
+
+    /// Converts kilowatt-hours to megajoules.
+    /// # Arguments
+    /// * `value` - The energy in kilowatt-hours to convert
+    pub fn to_megajoules(value: f64) -> f64 {
+        super::joules::to_megajoules(to_joules(value))
+    }
+
+    /// Converts kilowatt-hours to thermochemical calories.
+    /// # Arguments
+    /// * `value` - The energy in kilowatt-hours to convert
+    pub fn to_thermochemical_calories(value: f64) -> f64 {
+        super::joules::to_thermochemical_calories(to_joules(value))
+    }
+
+    /// Converts kilowatt-hours to international table calories.
+    /// # Arguments
+    /// * `value` - The energy in kilowatt-hours to convert
+    pub fn to_international_calories(value: f64) -> f64 {
+        super::joules::to_international_calories(to_joules(value))
+    }
+
+    /// Converts kilowatt-hours to thermochemical kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in kilowatt-hours to convert
+    pub fn to_thermochemical_kilocalories(value: f64) -> f64 {
+        super::joules::to_thermochemical_kilocalories(to_joules(value))
+    }
+
+    /// Converts kilowatt-hours to international table kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in kilowatt-hours to convert
+    pub fn to_international_kilocalories(value: f64) -> f64 {
+        super::joules::to_international_kilocalories(to_joules(value))
+    }
+
+    /// Converts kilowatt-hours to watt-hours.
+    /// # Arguments
+    /// * `value` - The energy in kilowatt-hours to convert
+    pub fn to_watt_hours(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// Converts kilowatt-hours to megawatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in kilowatt-hours to convert
+    pub fn to_megawatt_hours(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// Converts kilowatt-hours to British thermal units.
+    /// # Arguments
+    /// * `value` - The energy in kilowatt-hours to convert
+    pub fn to_btu(value: f64) -> f64 {
+        super::joules::to_btu(to_joules(value))
+    }
+
+    /// Converts kilowatt-hours to therms.
+    /// # Arguments
+    /// * `value` - The energy in kilowatt-hours to convert
+    pub fn to_therms(value: f64) -> f64 {
+        super::joules::to_therms(to_joules(value))
+    }
+
+    /// Converts kilowatt-hours to electronvolts.
+    /// # Arguments
+    /// * `value` - The energy in kilowatt-hours to convert
+    pub fn to_electronvolts(value: f64) -> f64 {
+        super::joules::to_electronvolts(to_joules(value))
+    }
+
+    /// Converts kilowatt-hours to ergs.
+    /// # Arguments
+    /// * `value` - The energy in kilowatt-hours to convert
+    pub fn to_ergs(value: f64) -> f64 {
+        super::joules::to_ergs(to_joules(value))
+    }
+
+    /// Converts kilowatt-hours to foot-pounds.
+    /// # Arguments
+    /// * `value` - The energy in kilowatt-hours to convert
+    pub fn to_foot_pounds(value: f64) -> f64 {
+        super::joules::to_foot_pounds(to_joules(value))
+    }
+
+    /// The kilowatt-hour as a typed unit of energy.
+    pub const UNIT: Unit<Energy> = Unit::new(
+        "kWh",
+        "kilowatt-hour",
+        to_joules,
+        super::joules::to_kilowatt_hours,
+    );
+
+    /// Creates a typed energy quantity expressed in kilowatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in kilowatt-hours
+    pub fn new(value: f64) -> Quantity<Energy> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed energy quantity in kilowatt-hours.
+    /// # Arguments
+    /// * `quantity` - The energy quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Energy>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Megawatt-hour conversion functions
+pub mod megawatt_hours {
+    use crate::conversions::quantity::{Energy, Quantity, Unit};
+
+    /// Converts megawatt-hours to joules.
+    /// # Arguments
+    /// * `value` - The energy in megawatt-hours to convert
+    pub fn to_joules(value: f64) -> f64 {
+        value * super::JOULES_PER_WATT_HOUR * 1_000_000.0
+    }
+
+    /// Converts megawatt-hours to kilojoules.
+    /// # Arguments
+    /// * `value` - The energy in megawatt-hours to convert
+    pub fn to_kilojoules(value: f64) -> f64 {
+        super::joules::to_kilojoules(to_joules(value))
+    }
+
+    /// Converts megawatt-hours to megajoules.
+    /// # Arguments
+    /// * `value` - The energy in megawatt-hours to convert
+    pub fn to_megajoules(value: f64) -> f64 {
+        super::joules::to_megajoules(to_joules(value))
+    }
+
+    /// Converts megawatt-hours to thermochemical calories.
+    /// # Arguments
+    /// * `value` - The energy in megawatt-hours to convert
+    pub fn to_thermochemical_calories(value: f64) -> f64 {
+        super::joules::to_thermochemical_calories(to_joules(value))
+    }
+
+    /// Converts megawatt-hours to international table calories.
+    /// # Arguments
+    /// * `value` - The energy in megawatt-hours to convert
+    pub fn to_international_calories(value: f64) -> f64 {
+        super::joules::to_international_calories(to_joules(value))
+    }
+
+    /// Converts megawatt-hours to thermochemical kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in megawatt-hours to convert
+    pub fn to_thermochemical_kilocalories(value: f64) -> f64 {
+        super::joules::to_thermochemical_kilocalories(to_joules(value))
+    }
+
+    /// Converts megawatt-hours to international table kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in megawatt-hours to convert
+    pub fn to_international_kilocalories(value: f64) -> f64 {
+        super::joules::to_international_kilocalories(to_joules(value))
+    }
+
+    /// Converts megawatt-hours to watt-hours.
+    /// # Arguments
+    /// * `value` - The energy in megawatt-hours to convert
+    pub fn to_watt_hours(value: f64) -> f64 {
+        value * 1_000_000.0
+    }
+
+    /// Converts megawatt-hours to kilowatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in megawatt-hours to convert
+    pub fn to_kilowatt_hours(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// Converts megawatt-hours to British thermal units.
+    /// # Arguments
+    /// * `value` - The energy in megawatt-hours to convert
+    pub fn to_btu(value: f64) -> f64 {
+        super::joules::to_btu(to_joules(value))
+    }
+
+    /// Converts megawatt-hours to therms.
+    /// # Arguments
+    /// * `value` - The energy in megawatt-hours to convert
+    pub fn to_therms(value: f64) -> f64 {
+        super::joules::to_therms(to_joules(value))
+    }
+
+    /// Converts megawatt-hours to electronvolts.
+    /// # Arguments
+    /// * `value` - The energy in megawatt-hours to convert
+    pub fn to_electronvolts(value: f64) -> f64 {
+        super::joules::to_electronvolts(to_joules(value))
+    }
+
+    /// Converts megawatt-hours to ergs.
+    /// # Arguments
+    /// * `value` - The energy in megawatt-hours to convert
+    pub fn to_ergs(value: f64) -> f64 {
+        super::joules::to_ergs(to_joules(value))
+    }
+
+    /// Converts megawatt-hours to foot-pounds.
+    /// # Arguments
+    /// * `value` - The energy in megawatt-hours to convert
+    pub fn to_foot_pounds(value: f64) -> f64 {
+        super::joules::to_foot_pounds(to_joules(value))
+    }
+
+    /// The megawatt-hour as a typed unit of energy.
+    pub const UNIT: Unit<Energy> = Unit::new(
+        "MWh",
+        "megawatt-hour",
+        to_joules,
+        super::joules::to_megawatt_hours,
+    );
+
+    /// Creates a typed energy quantity expressed in megawatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in megawatt-hours
+    pub fn new(value: f64) -> Quantity<Energy> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed energy quantity in megawatt-hours.
+    /// # Arguments
+    /// * `quantity` - The energy quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Energy>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// British thermal unit (international table) conversion functions
+pub mod btu {
+    use crate::conversions::quantity::{Energy, Quantity, Unit};
+
+    /// Converts British thermal units to joules.
+    /// # Arguments
+    /// * `value` - The energy in British thermal units to convert
+    pub fn to_joules(value: f64) -> f64 {
+        value * super::JOULES_PER_BTU
+    }
+
+    /// Converts British thermal units to kilojoules.
+    /// # Arguments
+    /// * `value` - The energy in British thermal units to convert
+    pub fn to_kilojoules(value: f64) -> f64 {
+        super::joules::to_kilojoules(to_joules(value))
+    }
+
+    /// Converts British thermal units to megajoules.
+    /// # Arguments
+    /// * `value` - The energy in British thermal units to convert
+    pub fn to_megajoules(value: f64) -> f64 {
+        super::joules::to_megajoules(to_joules(value))
+    }
+
+    /// Converts British thermal units to thermochemical calories.
+    /// # Arguments
+    /// * `value` - The energy in British thermal units to convert
+    pub fn to_thermochemical_calories(value: f64) -> f64 {
+        super::joules::to_thermochemical_calories(to_joules(value))
+    }
+
+    /// Converts British thermal units to international table calories.
+    /// # Arguments
+    /// * `value` - The energy in British thermal units to convert
+    pub fn to_international_calories(value: f64) -> f64 {
+        super::joules::to_international_calories(to_joules(value))
+    }
+
+    /// Converts British thermal units to thermochemical kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in British thermal units to convert
+    pub fn to_thermochemical_kilocalories(value: f64) -> f64 {
+        super::joules::to_thermochemical_kilocalories(to_joules(value))
+    }
+
+    /// Converts British thermal units to international table kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in British thermal units to convert
+    pub fn to_international_kilocalories(value: f64) -> f64 {
+        super::joules::to_international_kilocalories(to_joules(value))
+    }
+
+    /// Converts British thermal units to watt-hours.
+    /// # Arguments
+    /// * `value` - The energy in British thermal units to convert
+    pub fn to_watt_hours(value: f64) -> f64 {
+        super::joules::to_watt_hours(to_joules(value))
+    }
+
+    /// Converts British thermal units to kilowatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in British thermal units to convert
+    pub fn to_kilowatt_hours(value: f64) -> f64 {
+        super::joules::to_kilowatt_hours(to_joules(value))
+    }
+
+    /// Converts British thermal units to megawatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in British thermal units to convert
+    pub fn to_megawatt_hours(value: f64) -> f64 {
+        super::joules::to_megawatt_hours(to_joules(value))
+    }
+
+    /// Converts British thermal units to therms.
+    /// # Arguments
+    /// * `value` - The energy in British thermal units to convert
+    pub fn to_therms(value: f64) -> f64 {
+        value / 100_000.0
+    }
+
+    /// Converts British thermal units to electronvolts.
+    /// # Arguments
+    /// * `value` - The energy in British thermal units to convert
+    pub fn to_electronvolts(value: f64) -> f64 {
+        super::joules::to_electronvolts(to_joules(value))
+    }
+
+    /// Converts British thermal units to ergs.
+    /// # Arguments
+    /// * `value` - The energy in British thermal units to convert
+    pub fn to_ergs(value: f64) -> f64 {
+        super::joules::to_ergs(to_joules(value))
+    }
+
+    /// Converts British thermal units to foot-pounds.
+    /// # Arguments
+    /// * `value` - The energy in British thermal units to convert
+    pub fn to_foot_pounds(value: f64) -> f64 {
+        super::joules::to_foot_pounds(to_joules(value))
+    }
+
+    /// The British thermal unit as a typed unit of energy.
+    pub const UNIT: Unit<Energy> = Unit::new(
+        "BTU",
+        "British thermal unit",
+        to_joules,
+        super::joules::to_btu,
+    );
+
+    /// Creates a typed energy quantity expressed in British thermal units.
+    /// # Arguments
+    /// * `value` - The energy in British thermal units
+    pub fn new(value: f64) -> Quantity<Energy> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed energy quantity in British thermal units.
+    /// # Arguments
+    /// * `quantity` - The energy quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Energy>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Therm conversion functions (100 000 international table BTU)
+pub mod therms {
+    use crate::conversions::quantity::{Energy, Quantity, Unit};
+
+    /// Converts therms to joules.
+    /// # Arguments
+    /// * `value` - The energy in therms to convert
+    pub fn to_joules(value: f64) -> f64 {
+        value * super::JOULES_PER_THERM
+    }
+
+    /// Converts therms to kilojoules.
+    /// # Arguments
+    /// * `value` - The energy in therms to convert
+    pub fn to_kilojoules(value: f64) -> f64 {
+        super::joules::to_kilojoules(to_joules(value))
+    }
+
+    /// Converts therms to megajoules.
+    /// # Arguments
+    /// * `value` - The energy in therms to convert
+    pub fn to_megajoules(value: f64) -> f64 {
+        super::joules::to_megajoules(to_joules(value))
+    }
+
+    /// Converts therms to thermochemical calories.
+    /// # Arguments
+    /// * `value` - The energy in therms to convert
+    pub fn to_thermochemical_calories(value: f64) -> f64 {
+        super::joules::to_thermochemical_calories(to_joules(value))
+    }
+
+    /// Converts therms to international table calories.
+    /// # Arguments
+    /// * `value` - The energy in therms to convert
+    pub fn to_international_calories(value: f64) -> f64 {
+        super::joules::to_international_calories(to_joules(value))
+    }
+
+    /// Converts therms to thermochemical kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in therms to convert
+    pub fn to_thermochemical_kilocalories(value: f64) -> f64 {
+        super::joules::to_thermochemical_kilocalories(to_joules(value))
+    }
+
+    /// Converts therms to international table kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in therms to convert
+    pub fn to_international_kilocalories(value: f64) -> f64 {
+        super::joules::to_international_kilocalories(to_joules(value))
+    }
+
+    /// Converts therms to watt-hours.
+    /// # Arguments
+    /// * `value` - The energy in therms to convert
+    pub fn to_watt_hours(value: f64) -> f64 {
+        super::joules::to_watt_hours(to_joules(value))
+    }
+
+    /// Converts therms to kilowatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in therms to convert
+    pub fn to_kilowatt_hours(value: f64) -> f64 {
+        super::joules::to_kilowatt_hours(to_joules(value))
+    }
+
+    /// Converts therms to megawatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in therms to convert
+    pub fn to_megawatt_hours(value: f64) -> f64 {
+        super::joules::to_megawatt_hours(to_joules(value))
+    }
+
+    /// Converts therms to British thermal units.
+    /// # Arguments
+    /// * `value` - The energy in therms to convert
+    pub fn to_btu(value: f64) -> f64 {
+        value * 100_000.0
+    }
+
+    /// Converts therms to electronvolts.
+    /// # Arguments
+    /// * `value` - The energy in therms to convert
+    pub fn to_electronvolts(value: f64) -> f64 {
+        super::joules::to_electronvolts(to_joules(value))
+    }
+
+    /// Converts therms to ergs.
+    /// # Arguments
+    /// * `value` - The energy in therms to convert
+    pub fn to_ergs(value: f64) -> f64 {
+        super::joules::to_ergs(to_joules(value))
+    }
+
+    /// Converts therms to foot-pounds.
+    /// # Arguments
+    /// * `value` - The energy in therms to convert
+    pub fn to_foot_pounds(value: f64) -> f64 {
+        super::joules::to_foot_pounds(to_joules(value))
+    }
+
+    /// The therm as a typed unit of energy.
+    pub const UNIT: Unit<Energy> = Unit::new("thm", "therm", to_joules, super::joules::to_therms);
+
+    /// Creates a typed energy quantity expressed in therms.
+    /// # Arguments
+    /// * `value` - The energy in therms
+    pub fn new(value: f64) -> Quantity<Energy> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed energy quantity in therms.
+    /// # Arguments
+    /// * `quantity` - The energy quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Energy>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Electronvolt conversion functions
+pub mod electronvolts {
+    use crate::conversions::quantity::{Energy, Quantity, Unit};
+
+    /// Converts electronvolts to joules.
+    /// # Arguments
+    /// * `value` - The energy in electronvolts to convert
+    pub fn to_joules(value: f64) -> f64 {
+        value * super::JOULES_PER_ELECTRONVOLT
+    }
+
+    /// Converts electronvolts to kilojoules.
+    /// # Arguments
+    /// * `value` - The energy in electronvolts to convert
+    pub fn to_kilojoules(value: f64) -> f64 {
+        super::joules::to_kilojoules(to_joules(value))
+    }
+
+    /// Converts electronvolts to megajoules.
+    /// # Arguments
+    /// * `value` - The energy in electronvolts to convert
+    pub fn to_megajoules(value: f64) -> f64 {
+        super::joules::to_megajoules(to_joules(value))
+    }
+
+    /// Converts electronvolts to thermochemical calories.
+    /// # Arguments
+    /// * `value` - The energy in electronvolts to convert
+    pub fn to_thermochemical_calories(value: f64) -> f64 {
+        super::joules::to_thermochemical_calories(to_joules(value))
+    }
+
+    /// Converts electronvolts to international table calories.
+    /// # Arguments
+    /// * `value` - The energy in electronvolts to convert
+    pub fn to_international_calories(value: f64) -> f64 {
+        super::joules::to_international_calories(to_joules(value))
+    }
+
+    /// Converts electronvolts to thermochemical kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in electronvolts to convert
+    pub fn to_thermochemical_kilocalories(value: f64) -> f64 {
+        super::joules::to_thermochemical_kilocalories(to_joules(value))
+    }
+
+    /// Converts electronvolts to international table kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in electronvolts to convert
+    pub fn to_international_kilocalories(value: f64) -> f64 {
+        super::joules::to_international_kilocalories(to_joules(value))
+    }
+
+    /// Converts electronvolts to watt-hours.
+    /// # Arguments
+    /// * `value` - The energy in electronvolts to convert
+    pub fn to_watt_hours(value: f64) -> f64 {
+        super::joules::to_watt_hours(to_joules(value))
+    }
+
+    /// Converts electronvolts to kilowatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in electronvolts to convert
+    pub fn to_kilowatt_hours(value: f64) -> f64 {
+        super::joules::to_kilowatt_hours(to_joules(value))
+    }
+
+    /// Converts electronvolts to megawatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in electronvolts to convert
+    pub fn to_megawatt_hours(value: f64) -> f64 {
+        super::joules::to_megawatt_hours(to_joules(value))
+    }
+
+    /// Converts electronvolts to British thermal units.
+    /// # Arguments
+    /// * `value` - The energy in electronvolts to convert
+    pub fn to_btu(value: f64) -> f64 {
+        super::joules::to_btu(to_joules(value))
+    }
+
+    /// Converts electronvolts to therms.
+    /// # Arguments
+    /// * `value` - The energy in electronvolts to convert
+    pub fn to_therms(value: f64) -> f64 {
+        super::joules::to_therms(to_joules(value))
+    }
+
+    /// Converts electronvolts to ergs.
+    /// # Arguments
+    /// * `value` - The energy in electronvolts to convert
+    pub fn to_ergs(value: f64) -> f64 {
+        super::joules::to_ergs(to_joules(value))
+    }
+
+    /// Converts electronvolts to foot-pounds.
+    /// # Arguments
+    /// * `value` - The energy in electronvolts to convert
+    pub fn to_foot_pounds(value: f64) -> f64 {
+        super::joules::to_foot_pounds(to_joules(value))
+    }
+
+    /// The electronvolt as a typed unit of energy.
+    pub const UNIT: Unit<Energy> = Unit::new(
+        "eV",
+        "electronvolt",
+        to_joules,
+        super::joules::to_electronvolts,
+    );
+
+    /// Creates a typed energy quantity expressed in electronvolts.
+    /// # Arguments
+    /// * `value` - The energy in electronvolts
+    pub fn new(value: f64) -> Quantity<Energy> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed energy quantity in electronvolts.
+    /// # Arguments
+    /// * `quantity` - The energy quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Energy>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Erg conversion functions (CGS unit of energy)
+pub mod ergs {
+    use crate::conversions::quantity::{Energy, Quantity, Unit};
+
+    /// Converts ergs to joules.
+    /// # Arguments
+    /// * `value` - The energy in ergs to convert
+    pub fn to_joules(value: f64) -> f64 {
+        value * 1e-7
+    }
+
+    /// Converts ergs to kilojoules.
+    /// # Arguments
+    /// * `value` - The energy in ergs to convert
+    pub fn to_kilojoules(value: f64) -> f64 {
+        super::joules::to_kilojoules(to_joules(value))
+    }
+
+    /// Converts ergs to megajoules.
+    /// # Arguments
+    /// * `value` - The energy in ergs to convert
+    pub fn to_megajoules(value: f64) -> f64 {
+        super::joules::to_megajoules(to_joules(value))
+    }
+
+    /// Converts ergs to thermochemical calories.
+    /// # Arguments
+    /// * `value` - The energy in ergs to convert
+    pub fn to_thermochemical_calories(value: f64) -> f64 {
+        super::joules::to_thermochemical_calories(to_joules(value))
+    }
+
+    /// Converts ergs to international table calories.
+    /// # Arguments
+    /// * `value` - The energy in ergs to convert
+    pub fn to_international_calories(value: f64) -> f64 {
+        super::joules::to_international_calories(to_joules(value))
+    }
+
+    /// Converts ergs to thermochemical kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in ergs to convert
+    pub fn to_thermochemical_kilocalories(value: f64) -> f64 {
+        super::joules::to_thermochemical_kilocalories(to_joules(value))
+    }
+
+    /// Converts ergs to international table kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in ergs to convert
+    pub fn to_international_kilocalories(value: f64) -> f64 {
+        super::joules::to_international_kilocalories(to_joules(value))
+    }
+
+    /// Converts ergs to watt-hours.
+    /// # Arguments
+    /// * `value` - The energy in ergs to convert
+    pub fn to_watt_hours(value: f64) -> f64 {
+        super::joules::to_watt_hours(to_joules(value))
+    }
+
+    /// Converts ergs to kilowatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in ergs to convert
+    pub fn to_kilowatt_hours(value: f64) -> f64 {
+        super::joules::to_kilowatt_hours(to_joules(value))
+    }
+
+    /// Converts ergs to megawatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in ergs to convert
+    pub fn to_megawatt_hours(value: f64) -> f64 {
+        super::joules::to_megawatt_hours(to_joules(value))
+    }
+
+    /// Converts ergs to British thermal units.
+    /// # Arguments
+    /// * `value` - The energy in ergs to convert
+    pub fn to_btu(value: f64) -> f64 {
+        super::joules::to_btu(to_joules(value))
+    }
+
+    /// Converts ergs to therms.
+    /// # Arguments
+    /// * `value` - The energy in ergs to convert
+    pub fn to_therms(value: f64) -> f64 {
+        super::joules::to_therms(to_joules(value))
+    }
+
+    /// Converts ergs to electronvolts.
+    /// # Arguments
+    /// * `value` - The energy in ergs to convert
+    pub fn to_electronvolts(value: f64) -> f64 {
+        super::joules::to_electronvolts(to_joules(value))
+    }
+
+    /// Converts ergs to foot-pounds.
+    /// # Arguments
+    /// * `value` - The energy in ergs to convert
+    pub fn to_foot_pounds(value: f64) -> f64 {
+        super::joules::to_foot_pounds(to_joules(value))
+    }
+
+    /// The erg as a typed unit of energy.
+    pub const UNIT: Unit<Energy> = Unit::new("erg", "erg", to_joules, super::joules::to_ergs);
+
+    /// Creates a typed energy quantity expressed in ergs.
+    /// # Arguments
+    /// * `value` - The energy in ergs
+    pub fn new(value: f64) -> Quantity<Energy> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed energy quantity in ergs.
+    /// # Arguments
+    /// * `quantity` - The energy quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Energy>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Foot-pound-force conversion functions
+pub mod foot_pounds {
+    use crate::conversions::quantity::{Energy, Quantity, Unit};
+
+    /// Converts foot-pounds to joules.
+    /// # Arguments
+    /// * `value` - The energy in foot-pounds to convert
+    pub fn to_joules(value: f64) -> f64 {
+        value * super::JOULES_PER_FOOT_POUND
+    }
+
+    /// Converts foot-pounds to kilojoules.
+    /// # Arguments
+    /// * `value` - The energy in foot-pounds to convert
+    pub fn to_kilojoules(value: f64) -> f64 {
+        super::joules::to_kilojoules(to_joules(value))
+    }
+
+    /// Converts foot-pounds to megajoules.
+    /// # Arguments
+    /// * `value` - The energy in foot-pounds to convert
+    pub fn to_megajoules(value: f64) -> f64 {
+        super::joules::to_megajoules(to_joules(value))
+    }
+
+    /// Converts foot-pounds to thermochemical calories.
+    /// # Arguments
+    /// * `value` - The energy in foot-pounds to convert
+    pub fn to_thermochemical_calories(value: f64) -> f64 {
+        super::joules::to_thermochemical_calories(to_joules(value))
+    }
+
+    /// Converts foot-pounds to international table calories.
+    /// # Arguments
+    /// * `value` - The energy in foot-pounds to convert
+    pub fn to_international_calories(value: f64) -> f64 {
+        super::joules::to_international_calories(to_joules(value))
+    }
+
+    /// Converts foot-pounds to thermochemical kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in foot-pounds to convert
+    pub fn to_thermochemical_kilocalories(value: f64) -> f64 {
+        super::joules::to_thermochemical_kilocalories(to_joules(value))
+    }
+
+    /// Converts foot-pounds to international table kilocalories.
+    /// # Arguments
+    /// * `value` - The energy in foot-pounds to convert
+    pub fn to_international_kilocalories(value: f64) -> f64 {
+        super::joules::to_international_kilocalories(to_joules(value))
+    }
+
+    /// Converts foot-pounds to watt-hours.
+    /// # Arguments
+    /// * `value` - The energy in foot-pounds to convert
+    pub fn to_watt_hours(value: f64) -> f64 {
+        super::joules::to_watt_hours(to_joules(value))
+    }
+
+    /// Converts foot-pounds to kilowatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in foot-pounds to convert
+    pub fn to_kilowatt_hours(value: f64) -> f64 {
+        super::joules::to_kilowatt_hours(to_joules(value))
+    }
+
+    /// Converts foot-pounds to megawatt-hours.
+    /// # Arguments
+    /// * `value` - The energy in foot-pounds to convert
+    pub fn to_megawatt_hours(value: f64) -> f64 {
+        super::joules::to_megawatt_hours(to_joules(value))
+    }
+
+    /// Converts foot-pounds to British thermal units.
+    /// # Arguments
+    /// * `value` - The energy in foot-pounds to convert
+    pub fn to_btu(value: f64) -> f64 {
+        super::joules::to_btu(to_joules(value))
+    }
+
+    /// Converts foot-pounds to therms.
+    /// # Arguments
+    /// * `value` - The energy in foot-pounds to convert
+    pub fn to_therms(value: f64) -> f64 {
+        super::joules::to_therms(to_joules(value))
+    }
+
+    /// Converts foot-pounds to electronvolts.
+    /// # Arguments
+    /// * `value` - The energy in foot-pounds to convert
+    pub fn to_electronvolts(value: f64) -> f64 {
+        super::joules::to_electronvolts(to_joules(value))
+    }
+
+    /// Converts foot-pounds to ergs.
+    /// # Arguments
+    /// * `value` - The energy in foot-pounds to convert
+    pub fn to_ergs(value: f64) -> f64 {
+        super::joules::to_ergs(to_joules(value))
+    }
+
+    /// The foot-pound as a typed unit of energy.
+    pub const UNIT: Unit<Energy> = Unit::new(
+        "ft·lbf",
+        "foot-pound",
+        to_joules,
+        super::joules::to_foot_pounds,
+    );
+
+    /// Creates a typed energy quantity expressed in foot-pounds.
+    /// # Arguments
+    /// * `value` - The energy in foot-pounds
+    pub fn new(value: f64) -> Quantity<Energy> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed energy quantity in foot-pounds.
+    /// # Arguments
+    /// * `quantity` - The energy quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Energy>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// General energy conversion function that accepts string unit names
+///
+/// Converts an energy from one unit to another using string identifiers.
+/// Symbols are case-sensitive, names are case-insensitive, and common
+/// abbreviations are supported.
+///
+/// The thermochemical (`cal_th`, 4.184 J) and international table (`cal_IT`,
+/// 4.1868 J) calories differ by 0.07 %, so the bare `cal`, `kcal` and `Cal`
+/// are rejected with [`ConversionError::AmbiguousUnit`] rather than silently
+/// resolved to one of them.
+///
+/// # Arguments
+///
+/// * `value` - The numeric value to convert
+/// * `from_unit` - The source unit (e.g., "J", "kJ", "MJ", "cal_th", "cal_IT", "kcal_th",
+///   "kcal_IT", "Wh", "kWh", "MWh", "BTU", "thm", "eV", "erg", "ft·lbf")
+/// * `to_unit` - The target unit using the same abbreviations
+///
+/// # Returns
+/// * `Ok(f64)` - The converted value
+/// * `Err(ConversionError)` - The reason the conversion is not supported
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::{convert_energy, ConversionError};
+///
+/// let kilojoules = convert_energy(1.0, "kWh", "kJ").unwrap();
+/// assert!((kilojoules - 3600.0).abs() < 1e-9);
+///
+/// let joules = convert_energy(250.0, "kcal_th", "kJ").unwrap();
+/// assert!((joules - 1046.0).abs() < 1e-9);
+///
+/// assert!(matches!(
+///     convert_energy(250.0, "kcal", "kJ"),
+///     Err(ConversionError::AmbiguousUnit { .. })
+/// ));
+/// ```
+pub fn convert_energy(value: f64, from_unit: &str, to_unit: &str) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from_unit, to_unit, Category::Energy)
+}
+
+/// Energy units known to the [`UnitRegistry`], with joules as the base unit.
+///
+/// `cal`, `calorie`, `kcal`, `Cal` and `kilocalorie` are deliberately shared by
+/// the thermochemical and international table calories, so they resolve to
+/// neither.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new("J", "joule", &["joules"], Category::Energy, 1.0).with_prefixes(),
+        UnitDef::new(
+            "kJ",
+            "kilojoule",
+            &["kilojoules"],
+            Category::Energy,
+            kilojoules::to_joules(1.0),
+        ),
+        UnitDef::new(
+            "MJ",
+            "megajoule",
+            &["megajoules"],
+            Category::Energy,
+            megajoules::to_joules(1.0),
+        ),
+        UnitDef::new(
+            "cal_th",
+            "thermochemical calorie",
+            &["cal", "calorie", "calories", "thermochemical calories"],
+            Category::Energy,
+            thermochemical_calories::to_joules(1.0),
+        ),
+        UnitDef::new(
+            "cal_IT",
+            "international table calorie",
+            &[
+                "cal",
+                "calorie",
+                "calories",
+                "international table calories",
+                "international calorie",
+            ],
+            Category::Energy,
+            international_calories::to_joules(1.0),
+        ),
+        UnitDef::new(
+            "kcal_th",
+            "thermochemical kilocalorie",
+            &[
+                "kcal",
+                "Cal",
+                "kilocalorie",
+                "kilocalories",
+                "thermochemical kilocalories",
+            ],
+            Category::Energy,
+            thermochemical_kilocalories::to_joules(1.0),
+        ),
+        UnitDef::new(
+            "kcal_IT",
+            "international table kilocalorie",
+            &[
+                "kcal",
+                "Cal",
+                "kilocalorie",
+                "kilocalories",
+                "international table kilocalories",
+            ],
+            Category::Energy,
+            international_kilocalories::to_joules(1.0),
+        ),
+        UnitDef::new(
+            "Wh",
+            "watt-hour",
+            &["watt-hours", "watt hour", "watt hours"],
+            Category::Energy,
+            watt_hours::to_joules(1.0),
+        )
+        .with_prefixes(),
+        UnitDef::new(
+            "kWh",
+            "kilowatt-hour",
+            &["kilowatt-hours", "kilowatt hour", "kilowatt hours"],
+            Category::Energy,
+            kilowatt_hours::to_joules(1.0),
+        ),
+        UnitDef::new(
+            "MWh",
+            "megawatt-hour",
+            &["megawatt-hours", "megawatt hour", "megawatt hours"],
+            Category::Energy,
+            megawatt_hours::to_joules(1.0),
+        ),
+        UnitDef::new(
+            "BTU",
+            "British thermal unit",
+            &["Btu", "BTU_IT", "British thermal units"],
+            Category::Energy,
+            btu::to_joules(1.0),
+        ),
+        UnitDef::new(
+            "thm",
+            "therm",
+            &["therms"],
+            Category::Energy,
+            therms::to_joules(1.0),
+        )
+        .with_exact_factor("105505585.262"),
+        UnitDef::new(
+            "eV",
+            "electronvolt",
+            &["electronvolts", "electron volt", "electron volts"],
+            Category::Energy,
+            electronvolts::to_joules(1.0),
+        )
+        .with_prefixes(),
+        UnitDef::new(
+            "erg",
+            "erg",
+            &["ergs"],
+            Category::Energy,
+            ergs::to_joules(1.0),
+        ),
+        UnitDef::new(
+            "ft·lbf",
+            "foot-pound",
+            &[
+                "ft-lbf",
+                "ft*lbf",
+                "ftlbf",
+                "ft lbf",
+                "foot-pounds",
+                "foot pound",
+                "foot pounds",
+            ],
+            Category::Energy,
+            foot_pounds::to_joules(1.0),
+        )
+        .with_exact_factor("0.3048*0.45359237*9.80665"),
+    ]
+}
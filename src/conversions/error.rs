@@ -0,0 +1,242 @@
+//! Error type shared by all string-based conversion functions
+//! Callers can match on the failure kind instead of parsing error messages
+
+use super::category::Category;
+use std::error::Error;
+use std::fmt;
+
+/// The reason a conversion could not be performed.
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::{convert_length, Category, ConversionError};
+///
+/// match convert_length(100.0, "ft", "smoots") {
+///     Err(ConversionError::UnknownUnit { input, category }) => {
+///         assert_eq!(input, "smoots");
+///         assert_eq!(category, Category::Length);
+///     }
+///     other => panic!("unexpected result: {:?}", other),
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ConversionError {
+    /// The unit is not recognised within the requested category
+    UnknownUnit {
+        /// The unit exactly as it was given
+        input: String,
+        /// The category the unit was looked up in
+        category: Category,
+    },
+    /// The unit is not recognised in any category
+    UnrecognizedUnit {
+        /// The unit exactly as it was given
+        input: String,
+    },
+    /// The two units measure different kinds of quantity (e.g. feet to kilograms)
+    IncompatibleDimensions {
+        /// The source unit
+        from: String,
+        /// The category of the source unit
+        from_category: Category,
+        /// The target unit
+        to: String,
+        /// The category of the target unit
+        to_category: Category,
+    },
+    /// The value to convert is NaN or infinite
+    NonFiniteInput {
+        /// The rejected value
+        value: f64,
+    },
+    /// The value is not a valid number (exact conversions only)
+    InvalidNumber {
+        /// The value exactly as it was given
+        input: String,
+    },
+    /// The temperature lies below absolute zero (0 K)
+    BelowAbsoluteZero {
+        /// The rejected value
+        value: f64,
+        /// The unit the value was given in
+        unit: String,
+        /// Absolute zero expressed in `unit`
+        absolute_zero: f64,
+    },
+    /// The pressure lies below a perfect vacuum, which a gauge reading (psig)
+    /// cannot go under
+    BelowVacuum {
+        /// The rejected value
+        value: f64,
+        /// The unit the value was given in
+        unit: String,
+        /// A perfect vacuum expressed in `unit`
+        vacuum: f64,
+    },
+    /// The unit could refer to more than one unit (e.g. `"ma"` for mA and MA)
+    AmbiguousUnit {
+        /// The unit exactly as it was given
+        input: String,
+        /// The units it could refer to
+        candidates: Vec<String>,
+    },
+    /// The power is zero or negative, so it has no decibel level (dBm, dBW)
+    NonPositivePower {
+        /// The rejected value
+        value: f64,
+        /// The unit the value was given in
+        unit: String,
+    },
+    /// The unit is logarithmic or has an irrational factor, so it cannot be
+    /// converted exactly (exact conversions only)
+    InexactUnit {
+        /// The unit exactly as it was given
+        unit: String,
+    },
+    /// The text is not a degrees-minutes-seconds angle
+    InvalidAngle {
+        /// The angle exactly as it was given
+        input: String,
+    },
+    /// The value has no positive density, as required by the reciprocal
+    /// hydrometer scales (°API, °Baumé)
+    NonPositiveDensity {
+        /// The rejected value
+        value: f64,
+        /// The unit the value was given in
+        unit: String,
+    },
+    /// A value that would have to be divided by but is zero, or zero or
+    /// negative where only a positive value makes sense: a period, a
+    /// frequency, a beam solid angle or a distance to a light source
+    NoReciprocal {
+        /// The rejected value
+        value: f64,
+        /// The unit the value was given in
+        unit: String,
+    },
+    /// The quantity is in a decibel or hydrometer unit (dBm, °API), which has
+    /// no meaningful difference or negation
+    NotSubtractable {
+        /// The unit of the rejected quantity
+        unit: String,
+    },
+}
+
+impl ConversionError {
+    /// Returns a stable, machine-readable identifier for the error kind.
+    ///
+    /// Useful for mapping failures to status codes or localized messages.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ConversionError::UnknownUnit { .. } => "unknown_unit",
+            ConversionError::UnrecognizedUnit { .. } => "unrecognized_unit",
+            ConversionError::IncompatibleDimensions { .. } => "incompatible_dimensions",
+            ConversionError::NonFiniteInput { .. } => "non_finite_input",
+            ConversionError::InvalidNumber { .. } => "invalid_number",
+            ConversionError::BelowAbsoluteZero { .. } => "below_absolute_zero",
+            ConversionError::BelowVacuum { .. } => "below_vacuum",
+            ConversionError::AmbiguousUnit { .. } => "ambiguous_unit",
+            ConversionError::NonPositivePower { .. } => "non_positive_power",
+            ConversionError::InexactUnit { .. } => "inexact_unit",
+            ConversionError::InvalidAngle { .. } => "invalid_angle",
+            ConversionError::NonPositiveDensity { .. } => "non_positive_density",
+            ConversionError::NoReciprocal { .. } => "no_reciprocal",
+            ConversionError::NotSubtractable { .. } => "not_subtractable",
+        }
+    }
+
+    /// Builds an [`ConversionError::UnknownUnit`] error.
+    pub(crate) fn unknown_unit(input: &str, category: Category) -> Self {
+        ConversionError::UnknownUnit {
+            input: input.to_string(),
+            category,
+        }
+    }
+
+    /// Rejects NaN and infinite values before they reach a conversion.
+    pub(crate) fn ensure_finite(value: f64) -> Result<(), Self> {
+        if value.is_finite() {
+            Ok(())
+        } else {
+            Err(ConversionError::NonFiniteInput { value })
+        }
+    }
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConversionError::UnknownUnit { input, category } => {
+                write!(f, "Unknown {} unit: {}", category, input)
+            }
+            ConversionError::UnrecognizedUnit { input } => write!(f, "Unknown unit: {}", input),
+            ConversionError::IncompatibleDimensions {
+                from,
+                from_category,
+                to,
+                to_category,
+            } => write!(
+                f,
+                "Cannot convert {} ({}) to {} ({})",
+                from, from_category, to, to_category
+            ),
+            ConversionError::NonFiniteInput { value } => {
+                write!(f, "Value must be a finite number, got {}", value)
+            }
+            ConversionError::InvalidNumber { input } => {
+                write!(f, "Value must be a number, got {}", input)
+            }
+            ConversionError::BelowAbsoluteZero {
+                value,
+                unit,
+                absolute_zero,
+            } => write!(
+                f,
+                "{} {} is below absolute zero ({} {})",
+                value, unit, absolute_zero, unit
+            ),
+            ConversionError::BelowVacuum {
+                value,
+                unit,
+                vacuum,
+            } => write!(
+                f,
+                "{} {} is below a perfect vacuum ({} {})",
+                value, unit, vacuum, unit
+            ),
+            ConversionError::AmbiguousUnit { input, candidates } => write!(
+                f,
+                "Ambiguous unit: {} could mean {}",
+                input,
+                candidates.join(" or ")
+            ),
+            ConversionError::NonPositivePower { value, unit } => write!(
+                f,
+                "{} {} has no decibel level; the power must be positive",
+                value, unit
+            ),
+            ConversionError::InexactUnit { unit } => {
+                write!(f, "{} cannot be converted exactly", unit)
+            }
+            ConversionError::InvalidAngle { input } => {
+                write!(f, "Not a degrees-minutes-seconds angle: {}", input)
+            }
+            ConversionError::NonPositiveDensity { value, unit } => {
+                write!(f, "{} {} is not a positive density", value, unit)
+            }
+            ConversionError::NoReciprocal { value, unit } => write!(
+                f,
+                "{} {} has no reciprocal; the value must be positive",
+                value, unit
+            ),
+            ConversionError::NotSubtractable { unit } => {
+                write!(f, "Quantities in {} cannot be subtracted or negated", unit)
+            }
+        }
+    }
+}
+
+impl Error for ConversionError {}
@@ -0,0 +1,335 @@
+//! Exact conversions with arbitrary-precision rational arithmetic
+//! Available with the `exact` cargo feature. Values and conversion factors are
+//! kept as exact fractions through the whole `from` → base → `to` chain, so no
+//! rounding happens until the result is formatted.
+//!
+//! ```rust
+//! use conversions_rs::exact::{convert_exact, ExactValue};
+//!
+//! let distance: ExactValue = "0.1".parse().unwrap();
+//! let inches = convert_exact(&distance, "mi", "in").unwrap();
+//! assert_eq!(inches.to_string(), "6336");
+//!
+//! // Non-terminating results can be rounded to any precision
+//! let fahrenheit: ExactValue = "100".parse().unwrap();
+//! let celsius = convert_exact(&fahrenheit, "F", "C").unwrap();
+//! assert_eq!(format!("{:.10}", celsius), "37.7777777778");
+//! ```
+//!
+//! The `f64` functions (`convert`, `convert_length`, ...) remain the fast path.
+
+use super::category::Category;
+use super::error::ConversionError;
+use super::registry::{UnitDef, UnitRegistry};
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+use std::fmt;
+use std::str::FromStr;
+
+/// An exact rational number, as produced by exact conversions.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ExactValue(BigRational);
+
+impl ExactValue {
+    /// Wraps a rational number.
+    /// # Arguments
+    /// * `ratio` - The exact value
+    pub fn new(ratio: BigRational) -> Self {
+        ExactValue(ratio)
+    }
+
+    /// Parses a decimal (`"0.1"`, `"-2.5e-3"`) or a fraction (`"1/3"`) exactly.
+    /// # Arguments
+    /// * `input` - The number to parse
+    ///
+    /// # Returns
+    /// * `Ok(ExactValue)` - The exact value of the input
+    /// * `Err(ConversionError)` - An [`ConversionError::InvalidNumber`] if the
+    ///   input is not a decimal or a fraction, or has an exponent beyond ±4000
+    pub fn parse(input: &str) -> Result<Self, ConversionError> {
+        parse_expression(input)
+            .map(ExactValue)
+            .ok_or_else(|| ConversionError::InvalidNumber {
+                input: input.to_string(),
+            })
+    }
+
+    /// Returns the exact value as a fraction.
+    pub fn ratio(&self) -> &BigRational {
+        &self.0
+    }
+
+    /// Returns the nearest `f64` to the exact value.
+    pub fn to_f64(&self) -> f64 {
+        self.0.to_f64().unwrap_or(f64::NAN)
+    }
+
+    /// Returns the value as a decimal without any rounding, if it has a finite
+    /// decimal expansion (e.g. `"6336"` or `"0.0254"`, but not 1/3).
+    pub fn to_exact_decimal(&self) -> Option<String> {
+        let mut denominator = self.0.denom().clone();
+        let mut twos = 0;
+        let mut fives = 0;
+        while (&denominator % 2u32).is_zero() {
+            denominator /= 2u32;
+            twos += 1;
+        }
+        while (&denominator % 5u32).is_zero() {
+            denominator /= 5u32;
+            fives += 1;
+        }
+
+        if denominator.is_one() {
+            Some(self.to_decimal(twos.max(fives)))
+        } else {
+            None
+        }
+    }
+
+    /// Rounds the value to a number of decimal places, halves away from zero.
+    /// # Arguments
+    /// * `precision` - The number of digits after the decimal point
+    pub fn to_decimal(&self, precision: usize) -> String {
+        let scaled = (&self.0 * BigRational::from_integer(pow10(precision))).round();
+        let digits = scaled.to_integer().abs().to_string();
+        let sign = if scaled.is_negative() { "-" } else { "" };
+
+        if precision == 0 {
+            return format!("{}{}", sign, digits);
+        }
+
+        let digits = format!("{:0>width$}", digits, width = precision + 1);
+        let (whole, fraction) = digits.split_at(digits.len() - precision);
+        format!("{}{}.{}", sign, whole, fraction)
+    }
+}
+
+impl FromStr for ExactValue {
+    type Err = ConversionError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        ExactValue::parse(input)
+    }
+}
+
+impl From<BigRational> for ExactValue {
+    fn from(ratio: BigRational) -> Self {
+        ExactValue(ratio)
+    }
+}
+
+/// Formats the exact decimal when there is one and the fraction otherwise;
+/// a precision (`{:.4}`) rounds to that many decimal places instead.
+impl fmt::Display for ExactValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (f.precision(), self.to_exact_decimal()) {
+            (Some(precision), _) => f.write_str(&self.to_decimal(precision)),
+            (None, Some(decimal)) => f.write_str(&decimal),
+            (None, None) => write!(f, "{}", self.0),
+        }
+    }
+}
+
+/// Converts a value exactly between any two units, inferring the category from
+/// the units.
+///
+/// # Arguments
+/// * `value` - The exact value to convert
+/// * `from` - The source unit
+/// * `to` - The target unit
+///
+/// # Returns
+/// * `Ok(ExactValue)` - The exact converted value
+/// * `Err(ConversionError)` - If a unit is unknown or the units are incompatible
+pub fn convert_exact(
+    value: &ExactValue,
+    from: &str,
+    to: &str,
+) -> Result<ExactValue, ConversionError> {
+    let category = UnitRegistry::global().infer_category(from, to)?;
+    convert_in_exact(value, from, to, category)
+}
+
+/// Converts a value exactly between two units of a known category.
+///
+/// # Arguments
+/// * `value` - The exact value to convert
+/// * `from` - The source unit
+/// * `to` - The target unit
+/// * `category` - The category both units belong to
+///
+/// # Returns
+/// * `Ok(ExactValue)` - The exact converted value
+/// * `Err(ConversionError)` - If a unit is unknown, an
+///   [`ConversionError::InexactUnit`] if a unit is logarithmic (dBm, dBW) or
+///   has an irrational factor (rad/s), or a
+///   [`ConversionError::BelowAbsoluteZero`] if a temperature is colder than 0 K,
+///   or a [`ConversionError::BelowVacuum`] if a gauge pressure is below vacuum
+pub fn convert_in_exact(
+    value: &ExactValue,
+    from: &str,
+    to: &str,
+    category: Category,
+) -> Result<ExactValue, ConversionError> {
+    let registry = UnitRegistry::global();
+    let from_unit = registry.resolve(from, category)?;
+    let to_unit = registry.resolve(to, category)?;
+    for (unit, input) in [(from_unit, from), (to_unit, to)] {
+        if !unit.exact {
+            return Err(ConversionError::InexactUnit {
+                unit: input.to_string(),
+            });
+        }
+    }
+
+    let non_positive_density = || ConversionError::NonPositiveDensity {
+        value: value.to_f64(),
+        unit: from.to_string(),
+    };
+    let difference = &value.0 - exact_offset(from_unit);
+    let base = if from_unit.reciprocal {
+        if difference.is_zero() {
+            return Err(non_positive_density());
+        }
+        exact_factor(from_unit) / difference
+    } else {
+        exact_origin(from_unit) + difference * exact_factor(from_unit)
+    };
+
+    if category == Category::Temperature && base.is_negative() {
+        let in_unit = -exact_origin(from_unit) / exact_factor(from_unit) + exact_offset(from_unit);
+        return Err(ConversionError::BelowAbsoluteZero {
+            value: value.to_f64(),
+            unit: from.to_string(),
+            absolute_zero: ExactValue(in_unit).to_f64(),
+        });
+    }
+
+    let gauge = from_unit.offset != 0.0 || to_unit.offset != 0.0;
+    if category == Category::Pressure && gauge && base.is_negative() {
+        let in_unit = -exact_origin(from_unit) / exact_factor(from_unit) + exact_offset(from_unit);
+        return Err(ConversionError::BelowVacuum {
+            value: value.to_f64(),
+            unit: from.to_string(),
+            vacuum: ExactValue(in_unit).to_f64(),
+        });
+    }
+
+    if to_unit.reciprocal {
+        if !base.is_positive() {
+            return Err(non_positive_density());
+        }
+        Ok(ExactValue(
+            exact_factor(to_unit) / base + exact_offset(to_unit),
+        ))
+    } else if from_unit.reciprocal && !base.is_positive() {
+        Err(non_positive_density())
+    } else {
+        Ok(ExactValue(
+            (base - exact_origin(to_unit)) / exact_factor(to_unit) + exact_offset(to_unit),
+        ))
+    }
+}
+
+/// Returns the exact number of base units in one of `unit`.
+///
+/// Falls back to the decimal `f64` prints for the factor, which the registry
+/// only allows for literal decimals and whole numbers.
+fn exact_factor(unit: &UnitDef) -> BigRational {
+    let definition = match unit.exact_factor {
+        Some(definition) => definition.to_string(),
+        None => unit.factor.to_string(),
+    };
+    parse_expression(&definition).expect("built-in unit factors are valid decimals")
+}
+
+/// Returns the exact offset of `unit` from its category's base unit, falling
+/// back to the printed `f64` offset like [`exact_factor`].
+fn exact_offset(unit: &UnitDef) -> BigRational {
+    let definition = match unit.exact_offset {
+        Some(definition) => definition.to_string(),
+        None => unit.offset.to_string(),
+    };
+    parse_expression(&definition).expect("built-in unit offsets are valid decimals")
+}
+
+/// Returns the exact base value at which `unit` reads its offset; origins are
+/// always literal decimals.
+fn exact_origin(unit: &UnitDef) -> BigRational {
+    parse_decimal(&unit.origin.to_string()).expect("built-in unit origins are valid decimals")
+}
+
+/// Parses decimals joined by `*` and `/`, evaluated left to right.
+fn parse_expression(expression: &str) -> Option<BigRational> {
+    let mut result = BigRational::one();
+    let mut divide = false;
+    let mut rest = expression;
+
+    loop {
+        let end = rest.find(['*', '/']).unwrap_or(rest.len());
+        let term = parse_decimal(&rest[..end])?;
+
+        if divide {
+            if term.is_zero() {
+                return None;
+            }
+            result /= term;
+        } else {
+            result *= term;
+        }
+
+        if end == rest.len() {
+            return Some(result);
+        }
+        divide = rest[end..].starts_with('/');
+        rest = &rest[end + 1..];
+    }
+}
+
+/// The largest decimal exponent accepted when parsing (`1e4000`).
+///
+/// Far beyond any physical quantity, but it keeps a short input such as
+/// `"1e-99999999999"` from building a power of ten that takes minutes to compute.
+const MAX_EXPONENT: i64 = 4000;
+
+/// Parses a decimal number with an optional sign and exponent (e.g. `"-1.5e3"`).
+fn parse_decimal(text: &str) -> Option<BigRational> {
+    let text = text.trim();
+    let (mantissa, exponent) = match text.find(['e', 'E']) {
+        Some(index) => (&text[..index], text[index + 1..].parse::<i64>().ok()?),
+        None => (text, 0),
+    };
+    if exponent.abs() > MAX_EXPONENT {
+        return None;
+    }
+    let (negative, digits) = match mantissa.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, mantissa.strip_prefix('+').unwrap_or(mantissa)),
+    };
+    let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+
+    let all_digits = whole
+        .chars()
+        .chain(fraction.chars())
+        .all(|c| c.is_ascii_digit());
+    if (whole.is_empty() && fraction.is_empty()) || !all_digits {
+        return None;
+    }
+
+    let mantissa: BigInt = format!("{}{}", whole, fraction).parse().ok()?;
+    let scale = exponent.checked_sub(fraction.len() as i64)?;
+    let magnitude = usize::try_from(scale.unsigned_abs()).ok()?;
+    let value = if scale >= 0 {
+        BigRational::from_integer(mantissa * pow10(magnitude))
+    } else {
+        BigRational::new(mantissa, pow10(magnitude))
+    };
+
+    Some(if negative { -value } else { value })
+}
+
+/// Returns 10 raised to `exponent`.
+fn pow10(exponent: usize) -> BigInt {
+    num_traits::pow(BigInt::from(10), exponent)
+}
@@ -0,0 +1,794 @@
+//! Volumetric flow rate conversion functions organized by unit type
+//! All conversions use liters per second as the base unit, composing the
+//! `volume`, `length` and `time` definitions rather than repeating them
+
+use super::category::Category;
+use super::error::ConversionError;
+use super::registry::{UnitDef, UnitRegistry};
+use super::{length, time, volume};
+
+/// US gallons in one oil barrel
+pub const GALLONS_US_PER_BARREL: f64 = 42.0;
+
+/// Liters in one cubic meter
+const LITERS_PER_CUBIC_METER: f64 = 1000.0;
+
+/// Liters per second in one liter per minute
+fn liters_per_minute() -> f64 {
+    1.0 / time::minutes::to_seconds(1.0)
+}
+
+/// Liters per second in one cubic meter per second
+fn cubic_meters_per_second() -> f64 {
+    LITERS_PER_CUBIC_METER
+}
+
+/// Liters per second in one cubic meter per hour
+fn cubic_meters_per_hour() -> f64 {
+    LITERS_PER_CUBIC_METER / time::hours::to_seconds(1.0)
+}
+
+/// Liters per second in one US gallon per minute
+fn gallons_us_per_minute() -> f64 {
+    volume::gallons_us::to_liters(1.0) / time::minutes::to_seconds(1.0)
+}
+
+/// Liters per second in one imperial gallon per minute
+fn gallons_uk_per_minute() -> f64 {
+    volume::gallons_uk::to_liters(1.0) / time::minutes::to_seconds(1.0)
+}
+
+/// Liters per second in one cubic foot per minute
+fn cubic_feet_per_minute() -> f64 {
+    length::feet::to_meters(1.0).powi(3) * LITERS_PER_CUBIC_METER / time::minutes::to_seconds(1.0)
+}
+
+/// Liters per second in one oil barrel per day
+fn barrels_per_day() -> f64 {
+    volume::gallons_us::to_liters(GALLONS_US_PER_BARREL) / time::days::to_seconds(1.0)
+}
+
+/// Liters per second conversion functions
+pub mod liters_per_second {
+    use crate::conversions::quantity::{FlowRate, Quantity, Unit};
+
+    /// Converts liters per second to liters per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in liters per second to convert
+    pub fn to_liters_per_minute(value: f64) -> f64 {
+        value / super::liters_per_minute()
+    }
+
+    /// Converts liters per second to cubic meters per second.
+    /// # Arguments
+    /// * `value` - The flow rate in liters per second to convert
+    pub fn to_cubic_meters_per_second(value: f64) -> f64 {
+        value / super::cubic_meters_per_second()
+    }
+
+    /// Converts liters per second to cubic meters per hour.
+    /// # Arguments
+    /// * `value` - The flow rate in liters per second to convert
+    pub fn to_cubic_meters_per_hour(value: f64) -> f64 {
+        value / super::cubic_meters_per_hour()
+    }
+
+    /// Converts liters per second to US gallons per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in liters per second to convert
+    pub fn to_gallons_us_per_minute(value: f64) -> f64 {
+        value / super::gallons_us_per_minute()
+    }
+
+    /// Converts liters per second to imperial gallons per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in liters per second to convert
+    pub fn to_gallons_uk_per_minute(value: f64) -> f64 {
+        value / super::gallons_uk_per_minute()
+    }
+
+    /// Converts liters per second to cubic feet per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in liters per second to convert
+    pub fn to_cubic_feet_per_minute(value: f64) -> f64 {
+        value / super::cubic_feet_per_minute()
+    }
+
+    /// Converts liters per second to barrels per day.
+    /// # Arguments
+    /// * `value` - The flow rate in liters per second to convert
+    pub fn to_barrels_per_day(value: f64) -> f64 {
+        value / super::barrels_per_day()
+    }
+
+    /// The liter per second as a typed unit of flow rate.
+    pub const UNIT: Unit<FlowRate> =
+        Unit::new("L/s", "liter per second", |value| value, |value| value);
+
+    /// Creates a typed flow rate quantity expressed in liters per second.
+    /// # Arguments
+    /// * `value` - The flow rate in liters per second
+    pub fn new(value: f64) -> Quantity<FlowRate> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed flow rate quantity in liters per second.
+    /// # Arguments
+    /// * `quantity` - The flow rate quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<FlowRate>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Liters per minute conversion functions
+pub mod liters_per_minute {
+    use crate::conversions::quantity::{FlowRate, Quantity, Unit};
+
+    /// Converts liters per minute to liters per second.
+    /// # Arguments
+    /// * `value` - The flow rate in liters per minute to convert
+    pub fn to_liters_per_second(value: f64) -> f64 {
+        value * super::liters_per_minute()
+    }
+
+    /// Converts liters per minute to cubic meters per second.
+    /// # Arguments
+    /// * `value` - The flow rate in liters per minute to convert
+    pub fn to_cubic_meters_per_second(value: f64) -> f64 {
+        super::liters_per_second::to_cubic_meters_per_second(to_liters_per_second(value))
+    }
+
+    /// Converts liters per minute to cubic meters per hour.
+    /// # Arguments
+    /// * `value` - The flow rate in liters per minute to convert
+    pub fn to_cubic_meters_per_hour(value: f64) -> f64 {
+        super::liters_per_second::to_cubic_meters_per_hour(to_liters_per_second(value))
+    }
+
+    /// Converts liters per minute to US gallons per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in liters per minute to convert
+    pub fn to_gallons_us_per_minute(value: f64) -> f64 {
+        super::liters_per_second::to_gallons_us_per_minute(to_liters_per_second(value))
+    }
+
+    /// Converts liters per minute to imperial gallons per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in liters per minute to convert
+    pub fn to_gallons_uk_per_minute(value: f64) -> f64 {
+        super::liters_per_second::to_gallons_uk_per_minute(to_liters_per_second(value))
+    }
+
+    /// Converts liters per minute to cubic feet per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in liters per minute to convert
+    pub fn to_cubic_feet_per_minute(value: f64) -> f64 {
+        super::liters_per_second::to_cubic_feet_per_minute(to_liters_per_second(value))
+    }
+
+    /// Converts liters per minute to barrels per day.
+    /// # Arguments
+    /// * `value` - The flow rate in liters per minute to convert
+    pub fn to_barrels_per_day(value: f64) -> f64 {
+        super::liters_per_second::to_barrels_per_day(to_liters_per_second(value))
+    }
+
+    /// The liter per minute as a typed unit of flow rate.
+    pub const UNIT: Unit<FlowRate> = Unit::new(
+        "L/min",
+        "liter per minute",
+        to_liters_per_second,
+        super::liters_per_second::to_liters_per_minute,
+    );
+
+    /// Creates a typed flow rate quantity expressed in liters per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in liters per minute
+    pub fn new(value: f64) -> Quantity<FlowRate> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed flow rate quantity in liters per minute.
+    /// # Arguments
+    /// * `quantity` - The flow rate quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<FlowRate>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Cubic meters per second conversion functions
+pub mod cubic_meters_per_second {
+    use crate::conversions::quantity::{FlowRate, Quantity, Unit};
+
+    /// Converts cubic meters per second to liters per second.
+    /// # Arguments
+    /// * `value` - The flow rate in cubic meters per second to convert
+    pub fn to_liters_per_second(value: f64) -> f64 {
+        value * super::cubic_meters_per_second()
+    }
+
+    /// Converts cubic meters per second to liters per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in cubic meters per second to convert
+    pub fn to_liters_per_minute(value: f64) -> f64 {
+        super::liters_per_second::to_liters_per_minute(to_liters_per_second(value))
+    }
+
+    /// Converts cubic meters per second to cubic meters per hour.
+    /// # Arguments
+    /// * `value` - The flow rate in cubic meters per second to convert
+    pub fn to_cubic_meters_per_hour(value: f64) -> f64 {
+        super::liters_per_second::to_cubic_meters_per_hour(to_liters_per_second(value))
+    }
+
+    /// Converts cubic meters per second to US gallons per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in cubic meters per second to convert
+    pub fn to_gallons_us_per_minute(value: f64) -> f64 {
+        super::liters_per_second::to_gallons_us_per_minute(to_liters_per_second(value))
+    }
+
+    /// Converts cubic meters per second to imperial gallons per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in cubic meters per second to convert
+    pub fn to_gallons_uk_per_minute(value: f64) -> f64 {
+        super::liters_per_second::to_gallons_uk_per_minute(to_liters_per_second(value))
+    }
+
+    /// Converts cubic meters per second to cubic feet per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in cubic meters per second to convert
+    pub fn to_cubic_feet_per_minute(value: f64) -> f64 {
+        super::liters_per_second::to_cubic_feet_per_minute(to_liters_per_second(value))
+    }
+
+    /// Converts cubic meters per second to barrels per day.
+    /// # Arguments
+    /// * `value` - The flow rate in cubic meters per second to convert
+    pub fn to_barrels_per_day(value: f64) -> f64 {
+        super::liters_per_second::to_barrels_per_day(to_liters_per_second(value))
+    }
+
+    /// The cubic meter per second as a typed unit of flow rate.
+    pub const UNIT: Unit<FlowRate> = Unit::new(
+        "m³/s",
+        "cubic meter per second",
+        to_liters_per_second,
+        super::liters_per_second::to_cubic_meters_per_second,
+    );
+
+    /// Creates a typed flow rate quantity expressed in cubic meters per second.
+    /// # Arguments
+    /// * `value` - The flow rate in cubic meters per second
+    pub fn new(value: f64) -> Quantity<FlowRate> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed flow rate quantity in cubic meters per second.
+    /// # Arguments
+    /// * `quantity` - The flow rate quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<FlowRate>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Cubic meters per hour conversion functions
+pub mod cubic_meters_per_hour {
+    use crate::conversions::quantity::{FlowRate, Quantity, Unit};
+
+    /// Converts cubic meters per hour to liters per second.
+    /// # Arguments
+    /// * `value` - The flow rate in cubic meters per hour to convert
+    pub fn to_liters_per_second(value: f64) -> f64 {
+        value * super::cubic_meters_per_hour()
+    }
+
+    /// Converts cubic meters per hour to liters per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in cubic meters per hour to convert
+    pub fn to_liters_per_minute(value: f64) -> f64 {
+        super::liters_per_second::to_liters_per_minute(to_liters_per_second(value))
+    }
+
+    /// Converts cubic meters per hour to cubic meters per second.
+    /// # Arguments
+    /// * `value` - The flow rate in cubic meters per hour to convert
+    pub fn to_cubic_meters_per_second(value: f64) -> f64 {
+        super::liters_per_second::to_cubic_meters_per_second(to_liters_per_second(value))
+    }
+
+    /// Converts cubic meters per hour to US gallons per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in cubic meters per hour to convert
+    pub fn to_gallons_us_per_minute(value: f64) -> f64 {
+        super::liters_per_second::to_gallons_us_per_minute(to_liters_per_second(value))
+    }
+
+    /// Converts cubic meters per hour to imperial gallons per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in cubic meters per hour to convert
+    pub fn to_gallons_uk_per_minute(value: f64) -> f64 {
+        super::liters_per_second::to_gallons_uk_per_minute(to_liters_per_second(value))
+    }
+
+    /// Converts cubic meters per hour to cubic feet per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in cubic meters per hour to convert
+    pub fn to_cubic_feet_per_minute(value: f64) -> f64 {
+        super::liters_per_second::to_cubic_feet_per_minute(to_liters_per_second(value))
+    }
+
+    /// Converts cubic meters per hour to barrels per day.
+    /// # Arguments
+    /// * `value` - The flow rate in cubic meters per hour to convert
+    pub fn to_barrels_per_day(value: f64) -> f64 {
+        super::liters_per_second::to_barrels_per_day(to_liters_per_second(value))
+    }
+
+    /// The cubic meter per hour as a typed unit of flow rate.
+    pub const UNIT: Unit<FlowRate> = Unit::new(
+        "m³/h",
+        "cubic meter per hour",
+        to_liters_per_second,
+        super::liters_per_second::to_cubic_meters_per_hour,
+    );
+
+    /// Creates a typed flow rate quantity expressed in cubic meters per hour.
+    /// # Arguments
+    /// * `value` - The flow rate in cubic meters per hour
+    pub fn new(value: f64) -> Quantity<FlowRate> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed flow rate quantity in cubic meters per hour.
+    /// # Arguments
+    /// * `quantity` - The flow rate quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<FlowRate>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// US gallons per minute conversion functions
+pub mod gallons_us_per_minute {
+    use crate::conversions::quantity::{FlowRate, Quantity, Unit};
+
+    /// Converts US gallons per minute to liters per second.
+    /// # Arguments
+    /// * `value` - The flow rate in US gallons per minute to convert
+    pub fn to_liters_per_second(value: f64) -> f64 {
+        value * super::gallons_us_per_minute()
+    }
+
+    /// Converts US gallons per minute to liters per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in US gallons per minute to convert
+    pub fn to_liters_per_minute(value: f64) -> f64 {
+        super::liters_per_second::to_liters_per_minute(to_liters_per_second(value))
+    }
+
+    /// Converts US gallons per minute to cubic meters per second.
+    /// # Arguments
+    /// * `value` - The flow rate in US gallons per minute to convert
+    pub fn to_cubic_meters_per_second(value: f64) -> f64 {
+        super::liters_per_second::to_cubic_meters_per_second(to_liters_per_second(value))
+    }
+
+    /// Converts US gallons per minute to cubic meters per hour.
+    /// # Arguments
+    /// * `value` - The flow rate in US gallons per minute to convert
+    pub fn to_cubic_meters_per_hour(value: f64) -> f64 {
+        super::liters_per_second::to_cubic_meters_per_hour(to_liters_per_second(value))
+    }
+
+    /// Converts US gallons per minute to imperial gallons per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in US gallons per minute to convert
+    pub fn to_gallons_uk_per_minute(value: f64) -> f64 {
+        super::liters_per_second::to_gallons_uk_per_minute(to_liters_per_second(value))
+    }
+
+    /// Converts US gallons per minute to cubic feet per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in US gallons per minute to convert
+    pub fn to_cubic_feet_per_minute(value: f64) -> f64 {
+        super::liters_per_second::to_cubic_feet_per_minute(to_liters_per_second(value))
+    }
+
+    /// Converts US gallons per minute to barrels per day.
+    /// # Arguments
+    /// * `value` - The flow rate in US gallons per minute to convert
+    pub fn to_barrels_per_day(value: f64) -> f64 {
+        super::liters_per_second::to_barrels_per_day(to_liters_per_second(value))
+    }
+
+    /// The US gallon per minute as a typed unit of flow rate.
+    pub const UNIT: Unit<FlowRate> = Unit::new(
+        "gpm",
+        "US gallon per minute",
+        to_liters_per_second,
+        super::liters_per_second::to_gallons_us_per_minute,
+    );
+
+    /// Creates a typed flow rate quantity expressed in US gallons per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in US gallons per minute
+    pub fn new(value: f64) -> Quantity<FlowRate> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed flow rate quantity in US gallons per minute.
+    /// # Arguments
+    /// * `quantity` - The flow rate quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<FlowRate>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Imperial gallons per minute conversion functions
+pub mod gallons_uk_per_minute {
+    use crate::conversions::quantity::{FlowRate, Quantity, Unit};
+
+    /// Converts imperial gallons per minute to liters per second.
+    /// # Arguments
+    /// * `value` - The flow rate in imperial gallons per minute to convert
+    pub fn to_liters_per_second(value: f64) -> f64 {
+        value * super::gallons_uk_per_minute()
+    }
+
+    /// Converts imperial gallons per minute to liters per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in imperial gallons per minute to convert
+    pub fn to_liters_per_minute(value: f64) -> f64 {
+        super::liters_per_second::to_liters_per_minute(to_liters_per_second(value))
+    }
+
+    /// Converts imperial gallons per minute to cubic meters per second.
+    /// # Arguments
+    /// * `value` - The flow rate in imperial gallons per minute to convert
+    pub fn to_cubic_meters_per_second(value: f64) -> f64 {
+        super::liters_per_second::to_cubic_meters_per_second(to_liters_per_second(value))
+    }
+
+    /// Converts imperial gallons per minute to cubic meters per hour.
+    /// # Arguments
+    /// * `value` - The flow rate in imperial gallons per minute to convert
+    pub fn to_cubic_meters_per_hour(value: f64) -> f64 {
+        super::liters_per_second::to_cubic_meters_per_hour(to_liters_per_second(value))
+    }
+
+    /// Converts imperial gallons per minute to US gallons per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in imperial gallons per minute to convert
+    pub fn to_gallons_us_per_minute(value: f64) -> f64 {
+        super::liters_per_second::to_gallons_us_per_minute(to_liters_per_second(value))
+    }
+
+    /// Converts imperial gallons per minute to cubic feet per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in imperial gallons per minute to convert
+    pub fn to_cubic_feet_per_minute(value: f64) -> f64 {
+        super::liters_per_second::to_cubic_feet_per_minute(to_liters_per_second(value))
+    }
+
+    /// Converts imperial gallons per minute to barrels per day.
+    /// # Arguments
+    /// * `value` - The flow rate in imperial gallons per minute to convert
+    pub fn to_barrels_per_day(value: f64) -> f64 {
+        super::liters_per_second::to_barrels_per_day(to_liters_per_second(value))
+    }
+
+    /// The imperial gallon per minute as a typed unit of flow rate.
+    pub const UNIT: Unit<FlowRate> = Unit::new(
+        "gpm_uk",
+        "imperial gallon per minute",
+        to_liters_per_second,
+        super::liters_per_second::to_gallons_uk_per_minute,
+    );
+
+    /// Creates a typed flow rate quantity expressed in imperial gallons per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in imperial gallons per minute
+    pub fn new(value: f64) -> Quantity<FlowRate> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed flow rate quantity in imperial gallons per minute.
+    /// # Arguments
+    /// * `quantity` - The flow rate quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<FlowRate>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Cubic feet per minute conversion functions
+pub mod cubic_feet_per_minute {
+    use crate::conversions::quantity::{FlowRate, Quantity, Unit};
+
+    /// Converts cubic feet per minute to liters per second.
+    /// # Arguments
+    /// * `value` - The flow rate in cubic feet per minute to convert
+    pub fn to_liters_per_second(value: f64) -> f64 {
+        value * super::cubic_feet_per_minute()
+    }
+
+    /// Converts cubic feet per minute to liters per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in cubic feet per minute to convert
+    pub fn to_liters_per_minute(value: f64) -> f64 {
+        super::liters_per_second::to_liters_per_minute(to_liters_per_second(value))
+    }
+
+    /// Converts cubic feet per minute to cubic meters per second.
+    /// # Arguments
+    /// * `value` - The flow rate in cubic feet per minute to convert
+    pub fn to_cubic_meters_per_second(value: f64) -> f64 {
+        super::liters_per_second::to_cubic_meters_per_second(to_liters_per_second(value))
+    }
+
+    /// Converts cubic feet per minute to cubic meters per hour.
+    /// # Arguments
+    /// * `value` - The flow rate in cubic feet per minute to convert
+    pub fn to_cubic_meters_per_hour(value: f64) -> f64 {
+        super::liters_per_second::to_cubic_meters_per_hour(to_liters_per_second(value))
+    }
+
+    /// Converts cubic feet per minute to US gallons per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in cubic feet per minute to convert
+    pub fn to_gallons_us_per_minute(value: f64) -> f64 {
+        super::liters_per_second::to_gallons_us_per_minute(to_liters_per_second(value))
+    }
+
+    /// Converts cubic feet per minute to imperial gallons per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in cubic feet per minute to convert
+    pub fn to_gallons_uk_per_minute(value: f64) -> f64 {
+        super::liters_per_second::to_gallons_uk_per_minute(to_liters_per_second(value))
+    }
+
+    /// Converts cubic feet per minute to barrels per day.
+    /// # Arguments
+    /// * `value` - The flow rate in cubic feet per minute to convert
+    pub fn to_barrels_per_day(value: f64) -> f64 {
+        super::liters_per_second::to_barrels_per_day(to_liters_per_second(value))
+    }
+
+    /// The cubic foot per minute as a typed unit of flow rate.
+    pub const UNIT: Unit<FlowRate> = Unit::new(
+        "CFM",
+        "cubic foot per minute",
+        to_liters_per_second,
+        super::liters_per_second::to_cubic_feet_per_minute,
+    );
+
+    /// Creates a typed flow rate quantity expressed in cubic feet per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in cubic feet per minute
+    pub fn new(value: f64) -> Quantity<FlowRate> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed flow rate quantity in cubic feet per minute.
+    /// # Arguments
+    /// * `quantity` - The flow rate quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<FlowRate>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Oil barrels (42 US gallons) per day conversion functions
+pub mod barrels_per_day {
+    use crate::conversions::quantity::{FlowRate, Quantity, Unit};
+
+    /// Converts barrels per day to liters per second.
+    /// # Arguments
+    /// * `value` - The flow rate in barrels per day to convert
+    pub fn to_liters_per_second(value: f64) -> f64 {
+        value * super::barrels_per_day()
+    }
+
+    /// Converts barrels per day to liters per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in barrels per day to convert
+    pub fn to_liters_per_minute(value: f64) -> f64 {
+        super::liters_per_second::to_liters_per_minute(to_liters_per_second(value))
+    }
+
+    /// Converts barrels per day to cubic meters per second.
+    /// # Arguments
+    /// * `value` - The flow rate in barrels per day to convert
+    pub fn to_cubic_meters_per_second(value: f64) -> f64 {
+        super::liters_per_second::to_cubic_meters_per_second(to_liters_per_second(value))
+    }
+
+    /// Converts barrels per day to cubic meters per hour.
+    /// # Arguments
+    /// * `value` - The flow rate in barrels per day to convert
+    pub fn to_cubic_meters_per_hour(value: f64) -> f64 {
+        super::liters_per_second::to_cubic_meters_per_hour(to_liters_per_second(value))
+    }
+
+    /// Converts barrels per day to US gallons per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in barrels per day to convert
+    pub fn to_gallons_us_per_minute(value: f64) -> f64 {
+        super::liters_per_second::to_gallons_us_per_minute(to_liters_per_second(value))
+    }
+
+    /// Converts barrels per day to imperial gallons per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in barrels per day to convert
+    pub fn to_gallons_uk_per_minute(value: f64) -> f64 {
+        super::liters_per_second::to_gallons_uk_per_minute(to_liters_per_second(value))
+    }
+
+    /// Converts barrels per day to cubic feet per minute.
+    /// # Arguments
+    /// * `value` - The flow rate in barrels per day to convert
+    pub fn to_cubic_feet_per_minute(value: f64) -> f64 {
+        super::liters_per_second::to_cubic_feet_per_minute(to_liters_per_second(value))
+    }
+
+    /// The barrel per day as a typed unit of flow rate.
+    pub const UNIT: Unit<FlowRate> = Unit::new(
+        "bbl/d",
+        "barrel per day",
+        to_liters_per_second,
+        super::liters_per_second::to_barrels_per_day,
+    );
+
+    /// Creates a typed flow rate quantity expressed in barrels per day.
+    /// # Arguments
+    /// * `value` - The flow rate in barrels per day
+    pub fn new(value: f64) -> Quantity<FlowRate> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed flow rate quantity in barrels per day.
+    /// # Arguments
+    /// * `quantity` - The flow rate quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<FlowRate>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// General flow rate conversion function that accepts string unit names
+///
+/// Converts a volumetric flow rate from one unit to another using string
+/// identifiers. Symbols are case-sensitive, names are case-insensitive, and
+/// common abbreviations are supported.
+///
+/// # Arguments
+///
+/// * `value` - The numeric value to convert
+/// * `from_unit` - The source unit (e.g., "L/s", "L/min", "m³/s", "m³/h", "gpm", "gpm_uk",
+///   "CFM", "bbl/d")
+/// * `to_unit` - The target unit using the same abbreviations
+///
+/// # Returns
+/// * `Ok(f64)` - The converted value
+/// * `Err(ConversionError)` - The reason the conversion is not supported
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::convert_flow_rate;
+///
+/// let liters_per_minute = convert_flow_rate(1.0, "m³/h", "L/min").unwrap();
+/// assert!((liters_per_minute - 16.667).abs() < 0.001);
+///
+/// let gallons_per_minute = convert_flow_rate(1000.0, "bbl/d", "gpm").unwrap();
+/// assert!((gallons_per_minute - 29.167).abs() < 0.001);
+/// ```
+pub fn convert_flow_rate(
+    value: f64,
+    from_unit: &str,
+    to_unit: &str,
+) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from_unit, to_unit, Category::FlowRate)
+}
+
+/// Flow rate units known to the [`UnitRegistry`], with liters per second as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new(
+            "L/s",
+            "liter per second",
+            &[
+                "l/s",
+                "liters per second",
+                "litres per second",
+                "liters_per_second",
+            ],
+            Category::FlowRate,
+            1.0,
+        ),
+        UnitDef::new(
+            "L/min",
+            "liter per minute",
+            &[
+                "l/min",
+                "lpm",
+                "liters per minute",
+                "litres per minute",
+                "liters_per_minute",
+            ],
+            Category::FlowRate,
+            liters_per_minute::to_liters_per_second(1.0),
+        )
+        .with_exact_factor("1/60"),
+        UnitDef::new(
+            "m³/s",
+            "cubic meter per second",
+            &[
+                "m3/s",
+                "cumec",
+                "cubic meters per second",
+                "cubic_meters_per_second",
+            ],
+            Category::FlowRate,
+            cubic_meters_per_second::to_liters_per_second(1.0),
+        ),
+        UnitDef::new(
+            "m³/h",
+            "cubic meter per hour",
+            &["m3/h", "cubic meters per hour", "cubic_meters_per_hour"],
+            Category::FlowRate,
+            cubic_meters_per_hour::to_liters_per_second(1.0),
+        )
+        .with_exact_factor("1000/3600"),
+        UnitDef::new(
+            "gpm",
+            "US gallon per minute",
+            &[
+                "gal/min",
+                "gpm_us",
+                "gallons per minute",
+                "gallons_us_per_minute",
+            ],
+            Category::FlowRate,
+            gallons_us_per_minute::to_liters_per_second(1.0),
+        )
+        .with_exact_factor("3.785411784/60"),
+        UnitDef::new(
+            "gpm_uk",
+            "imperial gallon per minute",
+            &[
+                "gal_uk/min",
+                "imperial gallons per minute",
+                "gallons_uk_per_minute",
+            ],
+            Category::FlowRate,
+            gallons_uk_per_minute::to_liters_per_second(1.0),
+        )
+        .with_exact_factor("4.54609/60"),
+        UnitDef::new(
+            "CFM",
+            "cubic foot per minute",
+            &[
+                "cfm",
+                "ft³/min",
+                "ft3/min",
+                "cubic feet per minute",
+                "cubic_feet_per_minute",
+            ],
+            Category::FlowRate,
+            cubic_feet_per_minute::to_liters_per_second(1.0),
+        )
+        .with_exact_factor("0.3048*0.3048*0.3048*1000/60"),
+        UnitDef::new(
+            "bbl/d",
+            "barrel per day",
+            &[
+                "bbl/day",
+                "BPD",
+                "bpd",
+                "barrels per day",
+                "barrels_per_day",
+            ],
+            Category::FlowRate,
+            barrels_per_day::to_liters_per_second(1.0),
+        )
+        .with_exact_factor("42*3.785411784/86400"),
+    ]
+}
@@ -0,0 +1,565 @@
+//! Force conversion functions organized by unit type
+//! All conversions use newtons as the base unit for accuracy and consistency
+
+use super::category::Category;
+use super::error::ConversionError;
+use super::length::METERS_PER_FOOT;
+use super::pressure::STANDARD_GRAVITY;
+use super::registry::{UnitDef, UnitRegistry};
+use super::weight::KILOGRAMS_PER_POUND;
+
+/// Newtons in one pound-force (one pound under standard gravity)
+pub const NEWTONS_PER_POUND_FORCE: f64 = KILOGRAMS_PER_POUND * STANDARD_GRAVITY;
+
+/// Newtons in one kilogram-force (one kilogram under standard gravity)
+pub const NEWTONS_PER_KILOGRAM_FORCE: f64 = STANDARD_GRAVITY;
+
+/// Newtons in one poundal (one pound accelerated at one foot per second squared)
+pub const NEWTONS_PER_POUNDAL: f64 = KILOGRAMS_PER_POUND * METERS_PER_FOOT;
+
+/// Newton conversion functions
+pub mod newtons {
+    use crate::conversions::quantity::{Force, Quantity, Unit};
+
+    /// Converts newtons to kilonewtons.
+    /// # Arguments
+    /// * `value` - The force in newtons to convert
+    pub fn to_kilonewtons(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// Converts newtons to dynes.
+    /// # Arguments
+    /// * `value` - The force in newtons to convert
+    pub fn to_dynes(value: f64) -> f64 {
+        value * 1e5
+    }
+
+    /// Converts newtons to pounds-force.
+    /// # Arguments
+    /// * `value` - The force in newtons to convert
+    pub fn to_pounds_force(value: f64) -> f64 {
+        value / super::NEWTONS_PER_POUND_FORCE
+    }
+
+    /// Converts newtons to kilograms-force.
+    /// # Arguments
+    /// * `value` - The force in newtons to convert
+    pub fn to_kilograms_force(value: f64) -> f64 {
+        value / super::NEWTONS_PER_KILOGRAM_FORCE
+    }
+
+    /// Converts newtons to kips.
+    /// # Arguments
+    /// * `value` - The force in newtons to convert
+    pub fn to_kips(value: f64) -> f64 {
+        value / super::NEWTONS_PER_POUND_FORCE / 1000.0
+    }
+
+    /// Converts newtons to poundals.
+    /// # Arguments
+    /// * `value` - The force in newtons to convert
+    pub fn to_poundals(value: f64) -> f64 {
+        value / super::NEWTONS_PER_POUNDAL
+    }
+
+    /// The newton as a typed unit of force.
+    pub const UNIT: Unit<Force> = Unit::new("N", "newton", |value| value, |value| value);
+
+    /// Creates a typed force quantity expressed in newtons.
+    /// # Arguments
+    /// * `value` - The force in newtons
+    pub fn new(value: f64) -> Quantity<Force> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed force quantity in newtons.
+    /// # Arguments
+    /// * `quantity` - The force quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Force>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Kilonewton conversion functions
+pub mod kilonewtons {
+    use crate::conversions::quantity::{Force, Quantity, Unit};
+
+    /// Converts kilonewtons to newtons.
+    /// # Arguments
+    /// * `value` - The force in kilonewtons to convert
+    pub fn to_newtons(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// Converts kilonewtons to dynes.
+    /// # Arguments
+    /// * `value` - The force in kilonewtons to convert
+    pub fn to_dynes(value: f64) -> f64 {
+        super::newtons::to_dynes(to_newtons(value))
+    }
+
+    /// Converts kilonewtons to pounds-force.
+    /// # Arguments
+    /// * `value` - The force in kilonewtons to convert
+    pub fn to_pounds_force(value: f64) -> f64 {
+        super::newtons::to_pounds_force(to_newtons(value))
+    }
+
+    /// Converts kilonewtons to kilograms-force.
+    /// # Arguments
+    /// * `value` - The force in kilonewtons to convert
+    pub fn to_kilograms_force(value: f64) -> f64 {
+        super::newtons::to_kilograms_force(to_newtons(value))
+    }
+
+    /// Converts kilonewtons to kips.
+    /// # Arguments
+    /// * `value` - The force in kilonewtons to convert
+    pub fn to_kips(value: f64) -> f64 {
+        super::newtons::to_kips(to_newtons(value))
+    }
+
+    /// Converts kilonewtons to poundals.
+    /// # Arguments
+    /// * `value` - The force in kilonewtons to convert
+    pub fn to_poundals(value: f64) -> f64 {
+        super::newtons::to_poundals(to_newtons(value))
+    }
+
+    /// The kilonewton as a typed unit of force.
+    pub const UNIT: Unit<Force> = Unit::new(
+        "kN",
+        "kilonewton",
+        to_newtons,
+        super::newtons::to_kilonewtons,
+    );
+
+    /// Creates a typed force quantity expressed in kilonewtons.
+    /// # Arguments
+    /// * `value` - The force in kilonewtons
+    pub fn new(value: f64) -> Quantity<Force> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed force quantity in kilonewtons.
+    /// # Arguments
+    /// * `quantity` - The force quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Force>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Dyne conversion functions (CGS unit of force)
+pub mod dynes {
+    use crate::conversions::quantity::{Force, Quantity, Unit};
+
+    /// Converts dynes to newtons.
+    /// # Arguments
+    /// * `value` - The force in dynes to convert
+    pub fn to_newtons(value: f64) -> f64 {
+        value * 1e-5
+    }
+
+    /// Converts dynes to kilonewtons.
+    /// # Arguments
+    /// * `value` - The force in dynes to convert
+    pub fn to_kilonewtons(value: f64) -> f64 {
+        super::newtons::to_kilonewtons(to_newtons(value))
+    }
+
+    /// Converts dynes to pounds-force.
+    /// # Arguments
+    /// * `value` - The force in dynes to convert
+    pub fn to_pounds_force(value: f64) -> f64 {
+        super::newtons::to_pounds_force(to_newtons(value))
+    }
+
+    /// Converts dynes to kilograms-force.
+    /// # Arguments
+    /// * `value` - The force in dynes to convert
+    pub fn to_kilograms_force(value: f64) -> f64 {
+        super::newtons::to_kilograms_force(to_newtons(value))
+    }
+
+    /// Converts dynes to kips.
+    /// # Arguments
+    /// * `value` - The force in dynes to convert
+    pub fn to_kips(value: f64) -> f64 {
+        super::newtons::to_kips(to_newtons(value))
+    }
+
+    /// Converts dynes to poundals.
+    /// # Arguments
+    /// * `value` - The force in dynes to convert
+    pub fn to_poundals(value: f64) -> f64 {
+        super::newtons::to_poundals(to_newtons(value))
+    }
+
+    /// The dyne as a typed unit of force.
+    pub const UNIT: Unit<Force> = Unit::new("dyn", "dyne", to_newtons, super::newtons::to_dynes);
+
+    /// Creates a typed force quantity expressed in dynes.
+    /// # Arguments
+    /// * `value` - The force in dynes
+    pub fn new(value: f64) -> Quantity<Force> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed force quantity in dynes.
+    /// # Arguments
+    /// * `quantity` - The force quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Force>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Pound-force conversion functions
+pub mod pounds_force {
+    use crate::conversions::quantity::{Force, Quantity, Unit};
+
+    /// Converts pounds-force to newtons.
+    /// # Arguments
+    /// * `value` - The force in pounds-force to convert
+    pub fn to_newtons(value: f64) -> f64 {
+        value * super::NEWTONS_PER_POUND_FORCE
+    }
+
+    /// Converts pounds-force to kilonewtons.
+    /// # Arguments
+    /// * `value` - The force in pounds-force to convert
+    pub fn to_kilonewtons(value: f64) -> f64 {
+        super::newtons::to_kilonewtons(to_newtons(value))
+    }
+
+    /// Converts pounds-force to dynes.
+    /// # Arguments
+    /// * `value` - The force in pounds-force to convert
+    pub fn to_dynes(value: f64) -> f64 {
+        super::newtons::to_dynes(to_newtons(value))
+    }
+
+    /// Converts pounds-force to kilograms-force.
+    /// # Arguments
+    /// * `value` - The force in pounds-force to convert
+    pub fn to_kilograms_force(value: f64) -> f64 {
+        super::newtons::to_kilograms_force(to_newtons(value))
+    }
+
+    /// Converts pounds-force to kips.
+    /// # Arguments
+    /// * `value` - The force in pounds-force to convert
+    pub fn to_kips(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// Converts pounds-force to poundals.
+    /// # Arguments
+    /// * `value` - The force in pounds-force to convert
+    pub fn to_poundals(value: f64) -> f64 {
+        super::newtons::to_poundals(to_newtons(value))
+    }
+
+    /// The pound-force as a typed unit of force.
+    pub const UNIT: Unit<Force> = Unit::new(
+        "lbf",
+        "pound-force",
+        to_newtons,
+        super::newtons::to_pounds_force,
+    );
+
+    /// Creates a typed force quantity expressed in pounds-force.
+    /// # Arguments
+    /// * `value` - The force in pounds-force
+    pub fn new(value: f64) -> Quantity<Force> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed force quantity in pounds-force.
+    /// # Arguments
+    /// * `quantity` - The force quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Force>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Kilogram-force (kilopond) conversion functions
+pub mod kilograms_force {
+    use crate::conversions::quantity::{Force, Quantity, Unit};
+
+    /// Converts kilograms-force to newtons.
+    /// # Arguments
+    /// * `value` - The force in kilograms-force to convert
+    pub fn to_newtons(value: f64) -> f64 {
+        value * super::NEWTONS_PER_KILOGRAM_FORCE
+    }
+
+    /// Converts kilograms-force to kilonewtons.
+    /// # Arguments
+    /// * `value` - The force in kilograms-force to convert
+    pub fn to_kilonewtons(value: f64) -> f64 {
+        super::newtons::to_kilonewtons(to_newtons(value))
+    }
+
+    /// Converts kilograms-force to dynes.
+    /// # Arguments
+    /// * `value` - The force in kilograms-force to convert
+    pub fn to_dynes(value: f64) -> f64 {
+        super::newtons::to_dynes(to_newtons(value))
+    }
+
+    /// Converts kilograms-force to pounds-force.
+    /// # Arguments
+    /// * `value` - The force in kilograms-force to convert
+    pub fn to_pounds_force(value: f64) -> f64 {
+        super::newtons::to_pounds_force(to_newtons(value))
+    }
+
+    /// Converts kilograms-force to kips.
+    /// # Arguments
+    /// * `value` - The force in kilograms-force to convert
+    pub fn to_kips(value: f64) -> f64 {
+        super::newtons::to_kips(to_newtons(value))
+    }
+
+    /// Converts kilograms-force to poundals.
+    /// # Arguments
+    /// * `value` - The force in kilograms-force to convert
+    pub fn to_poundals(value: f64) -> f64 {
+        super::newtons::to_poundals(to_newtons(value))
+    }
+
+    /// The kilogram-force as a typed unit of force.
+    pub const UNIT: Unit<Force> = Unit::new(
+        "kgf",
+        "kilogram-force",
+        to_newtons,
+        super::newtons::to_kilograms_force,
+    );
+
+    /// Creates a typed force quantity expressed in kilograms-force.
+    /// # Arguments
+    /// * `value` - The force in kilograms-force
+    pub fn new(value: f64) -> Quantity<Force> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed force quantity in kilograms-force.
+    /// # Arguments
+    /// * `quantity` - The force quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Force>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Kip conversion functions (1000 pounds-force)
+pub mod kips {
+    use crate::conversions::quantity::{Force, Quantity, Unit};
+
+    /// Converts kips to newtons.
+    /// # Arguments
+    /// * `value` - The force in kips to convert
+    pub fn to_newtons(value: f64) -> f64 {
+        value * super::NEWTONS_PER_POUND_FORCE * 1000.0
+    }
+
+    /// Converts kips to kilonewtons.
+    /// # Arguments
+    /// * `value` - The force in kips to convert
+    pub fn to_kilonewtons(value: f64) -> f64 {
+        super::newtons::to_kilonewtons(to_newtons(value))
+    }
+
+    /// Converts kips to dynes.
+    /// # Arguments
+    /// * `value` - The force in kips to convert
+    pub fn to_dynes(value: f64) -> f64 {
+        super::newtons::to_dynes(to_newtons(value))
+    }
+
+    /// Converts kips to pounds-force.
+    /// # Arguments
+    /// * `value` - The force in kips to convert
+    pub fn to_pounds_force(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// Converts kips to kilograms-force.
+    /// # Arguments
+    /// * `value` - The force in kips to convert
+    pub fn to_kilograms_force(value: f64) -> f64 {
+        super::newtons::to_kilograms_force(to_newtons(value))
+    }
+
+    /// Converts kips to poundals.
+    /// # Arguments
+    /// * `value` - The force in kips to convert
+    pub fn to_poundals(value: f64) -> f64 {
+        super::newtons::to_poundals(to_newtons(value))
+    }
+
+    /// The kip as a typed unit of force.
+    pub const UNIT: Unit<Force> = Unit::new("kip", "kip", to_newtons, super::newtons::to_kips);
+
+    /// Creates a typed force quantity expressed in kips.
+    /// # Arguments
+    /// * `value` - The force in kips
+    pub fn new(value: f64) -> Quantity<Force> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed force quantity in kips.
+    /// # Arguments
+    /// * `quantity` - The force quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Force>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Poundal conversion functions (one pound accelerated at 1 ft/s²)
+pub mod poundals {
+    use crate::conversions::quantity::{Force, Quantity, Unit};
+
+    /// Converts poundals to newtons.
+    /// # Arguments
+    /// * `value` - The force in poundals to convert
+    pub fn to_newtons(value: f64) -> f64 {
+        value * super::NEWTONS_PER_POUNDAL
+    }
+
+    /// Converts poundals to kilonewtons.
+    /// # Arguments
+    /// * `value` - The force in poundals to convert
+    pub fn to_kilonewtons(value: f64) -> f64 {
+        super::newtons::to_kilonewtons(to_newtons(value))
+    }
+
+    /// Converts poundals to dynes.
+    /// # Arguments
+    /// * `value` - The force in poundals to convert
+    pub fn to_dynes(value: f64) -> f64 {
+        super::newtons::to_dynes(to_newtons(value))
+    }
+
+    /// Converts poundals to pounds-force.
+    /// # Arguments
+    /// * `value` - The force in poundals to convert
+    pub fn to_pounds_force(value: f64) -> f64 {
+        super::newtons::to_pounds_force(to_newtons(value))
+    }
+
+    /// Converts poundals to kilograms-force.
+    /// # Arguments
+    /// * `value` - The force in poundals to convert
+    pub fn to_kilograms_force(value: f64) -> f64 {
+        super::newtons::to_kilograms_force(to_newtons(value))
+    }
+
+    /// Converts poundals to kips.
+    /// # Arguments
+    /// * `value` - The force in poundals to convert
+    pub fn to_kips(value: f64) -> f64 {
+        super::newtons::to_kips(to_newtons(value))
+    }
+
+    /// The poundal as a typed unit of force.
+    pub const UNIT: Unit<Force> =
+        Unit::new("pdl", "poundal", to_newtons, super::newtons::to_poundals);
+
+    /// Creates a typed force quantity expressed in poundals.
+    /// # Arguments
+    /// * `value` - The force in poundals
+    pub fn new(value: f64) -> Quantity<Force> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed force quantity in poundals.
+    /// # Arguments
+    /// * `quantity` - The force quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Force>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// General force conversion function that accepts string unit names
+///
+/// Converts a force from one unit to another using string identifiers.
+/// Symbols are case-sensitive, names are case-insensitive, and common
+/// abbreviations are supported.
+///
+/// # Arguments
+///
+/// * `value` - The numeric value to convert
+/// * `from_unit` - The source unit (e.g., "N", "kN", "dyn", "lbf", "kgf", "kip", "pdl")
+/// * `to_unit` - The target unit using the same abbreviations
+///
+/// # Returns
+/// * `Ok(f64)` - The converted value
+/// * `Err(ConversionError)` - The reason the conversion is not supported
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::convert_force;
+///
+/// let newtons = convert_force(1.0, "kgf", "N").unwrap();
+/// assert!((newtons - 9.80665).abs() < 1e-12);
+///
+/// let kilonewtons = convert_force(10.0, "kip", "kN").unwrap();
+/// assert!((kilonewtons - 44.482).abs() < 0.001);
+/// ```
+pub fn convert_force(value: f64, from_unit: &str, to_unit: &str) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from_unit, to_unit, Category::Force)
+}
+
+/// Force units known to the [`UnitRegistry`], with newtons as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new("N", "newton", &["newtons"], Category::Force, 1.0).with_prefixes(),
+        UnitDef::new(
+            "kN",
+            "kilonewton",
+            &["kilonewtons"],
+            Category::Force,
+            kilonewtons::to_newtons(1.0),
+        ),
+        UnitDef::new(
+            "dyn",
+            "dyne",
+            &["dynes"],
+            Category::Force,
+            dynes::to_newtons(1.0),
+        ),
+        UnitDef::new(
+            "lbf",
+            "pound-force",
+            &["pounds-force", "pound force", "pounds force"],
+            Category::Force,
+            pounds_force::to_newtons(1.0),
+        )
+        .with_exact_factor("0.45359237*9.80665"),
+        UnitDef::new(
+            "kgf",
+            "kilogram-force",
+            &["kilograms-force", "kilogram force", "kp", "kilopond"],
+            Category::Force,
+            kilograms_force::to_newtons(1.0),
+        ),
+        UnitDef::new(
+            "kip",
+            "kip",
+            &["kips", "klbf", "kilopound-force"],
+            Category::Force,
+            kips::to_newtons(1.0),
+        )
+        .with_exact_factor("1000*0.45359237*9.80665"),
+        UnitDef::new(
+            "pdl",
+            "poundal",
+            &["poundals"],
+            Category::Force,
+            poundals::to_newtons(1.0),
+        )
+        .with_exact_factor("0.45359237*0.3048"),
+    ]
+}
@@ -0,0 +1,983 @@
+//! Frequency conversion functions organized by unit type
+//! All conversions use hertz as the base unit for accuracy and consistency
+//!
+//! Frequency is the reciprocal of time, so the per-minute and per-hour units
+//! take their lengths from the [`time`] module, and
+//! [`period_to_frequency`] / [`frequency_to_period`] convert between the two.
+
+use super::category::Category;
+use super::error::ConversionError;
+use super::quantity::{Frequency, Quantity, Time};
+use super::registry::{UnitDef, UnitRegistry};
+use super::time::{self, convert_time};
+
+/// Seconds in one minute, as defined by the `time` module
+fn seconds_per_minute() -> f64 {
+    time::minutes::to_seconds(1.0)
+}
+
+/// Seconds in one hour, as defined by the `time` module
+fn seconds_per_hour() -> f64 {
+    time::hours::to_seconds(1.0)
+}
+
+/// Hertz conversion functions
+pub mod hertz {
+    use crate::conversions::quantity::{Frequency, Quantity, Unit};
+
+    /// Converts hertz to kilohertz.
+    /// # Arguments
+    /// * `value` - The frequency in hertz to convert
+    pub fn to_kilohertz(value: f64) -> f64 {
+        value / 1e3
+    }
+
+    /// Converts hertz to megahertz.
+    /// # Arguments
+    /// * `value` - The frequency in hertz to convert
+    pub fn to_megahertz(value: f64) -> f64 {
+        value / 1e6
+    }
+
+    /// Converts hertz to gigahertz.
+    /// # Arguments
+    /// * `value` - The frequency in hertz to convert
+    pub fn to_gigahertz(value: f64) -> f64 {
+        value / 1e9
+    }
+
+    /// Converts hertz to terahertz.
+    /// # Arguments
+    /// * `value` - The frequency in hertz to convert
+    pub fn to_terahertz(value: f64) -> f64 {
+        value / 1e12
+    }
+
+    /// Converts hertz to revolutions per minute.
+    /// # Arguments
+    /// * `value` - The frequency in hertz to convert
+    pub fn to_revolutions_per_minute(value: f64) -> f64 {
+        value * super::seconds_per_minute()
+    }
+
+    /// Converts hertz to radians per second.
+    /// # Arguments
+    /// * `value` - The frequency in hertz to convert
+    pub fn to_radians_per_second(value: f64) -> f64 {
+        value * std::f64::consts::TAU
+    }
+
+    /// Converts hertz to cycles per minute.
+    /// # Arguments
+    /// * `value` - The frequency in hertz to convert
+    pub fn to_cycles_per_minute(value: f64) -> f64 {
+        value * super::seconds_per_minute()
+    }
+
+    /// Converts hertz to cycles per hour.
+    /// # Arguments
+    /// * `value` - The frequency in hertz to convert
+    pub fn to_cycles_per_hour(value: f64) -> f64 {
+        value * super::seconds_per_hour()
+    }
+
+    /// The hertz as a typed unit of frequency.
+    pub const UNIT: Unit<Frequency> = Unit::new("Hz", "hertz", |value| value, |value| value);
+
+    /// Creates a typed frequency quantity expressed in hertz.
+    /// # Arguments
+    /// * `value` - The frequency in hertz
+    pub fn new(value: f64) -> Quantity<Frequency> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed frequency quantity in hertz.
+    /// # Arguments
+    /// * `quantity` - The frequency quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Frequency>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Kilohertz conversion functions
+pub mod kilohertz {
+    use crate::conversions::quantity::{Frequency, Quantity, Unit};
+
+    /// Converts kilohertz to hertz.
+    /// # Arguments
+    /// * `value` - The frequency in kilohertz to convert
+    pub fn to_hertz(value: f64) -> f64 {
+        value * 1e3
+    }
+
+    /// Converts kilohertz to megahertz.
+    /// # Arguments
+    /// * `value` - The frequency in kilohertz to convert
+    pub fn to_megahertz(value: f64) -> f64 {
+        super::hertz::to_megahertz(to_hertz(value))
+    }
+
+    /// Converts kilohertz to gigahertz.
+    /// # Arguments
+    /// * `value` - The frequency in kilohertz to convert
+    pub fn to_gigahertz(value: f64) -> f64 {
+        super::hertz::to_gigahertz(to_hertz(value))
+    }
+
+    /// Converts kilohertz to terahertz.
+    /// # Arguments
+    /// * `value` - The frequency in kilohertz to convert
+    pub fn to_terahertz(value: f64) -> f64 {
+        super::hertz::to_terahertz(to_hertz(value))
+    }
+
+    /// Converts kilohertz to revolutions per minute.
+    /// # Arguments
+    /// * `value` - The frequency in kilohertz to convert
+    pub fn to_revolutions_per_minute(value: f64) -> f64 {
+        super::hertz::to_revolutions_per_minute(to_hertz(value))
+    }
+
+    /// Converts kilohertz to radians per second.
+    /// # Arguments
+    /// * `value` - The frequency in kilohertz to convert
+    pub fn to_radians_per_second(value: f64) -> f64 {
+        super::hertz::to_radians_per_second(to_hertz(value))
+    }
+
+    /// Converts kilohertz to cycles per minute.
+    /// # Arguments
+    /// * `value` - The frequency in kilohertz to convert
+    pub fn to_cycles_per_minute(value: f64) -> f64 {
+        super::hertz::to_cycles_per_minute(to_hertz(value))
+    }
+
+    /// Converts kilohertz to cycles per hour.
+    /// # Arguments
+    /// * `value` - The frequency in kilohertz to convert
+    pub fn to_cycles_per_hour(value: f64) -> f64 {
+        super::hertz::to_cycles_per_hour(to_hertz(value))
+    }
+
+    /// The kilohertz as a typed unit of frequency.
+    pub const UNIT: Unit<Frequency> =
+        Unit::new("kHz", "kilohertz", to_hertz, super::hertz::to_kilohertz);
+
+    /// Creates a typed frequency quantity expressed in kilohertz.
+    /// # Arguments
+    /// * `value` - The frequency in kilohertz
+    pub fn new(value: f64) -> Quantity<Frequency> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed frequency quantity in kilohertz.
+    /// # Arguments
+    /// * `quantity` - The frequency quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Frequency>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Megahertz conversion functions
+pub mod megahertz {
+    use crate::conversions::quantity::{Frequency, Quantity, Unit};
+
+    /// Converts megahertz to hertz.
+    /// # Arguments
+    /// * `value` - The frequency in megahertz to convert
+    pub fn to_hertz(value: f64) -> f64 {
+        value * 1e6
+    }
+
+    /// Converts megahertz to kilohertz.
+    /// # Arguments
+    /// * `value` - The frequency in megahertz to convert
+    pub fn to_kilohertz(value: f64) -> f64 {
+        super::hertz::to_kilohertz(to_hertz(value))
+    }
+
+    /// Converts megahertz to gigahertz.
+    /// # Arguments
+    /// * `value` - The frequency in megahertz to convert
+    pub fn to_gigahertz(value: f64) -> f64 {
+        super::hertz::to_gigahertz(to_hertz(value))
+    }
+
+    /// Converts megahertz to terahertz.
+    /// # Arguments
+    /// * `value` - The frequency in megahertz to convert
+    pub fn to_terahertz(value: f64) -> f64 {
+        super::hertz::to_terahertz(to_hertz(value))
+    }
+
+    /// Converts megahertz to revolutions per minute.
+    /// # Arguments
+    /// * `value` - The frequency in megahertz to convert
+    pub fn to_revolutions_per_minute(value: f64) -> f64 {
+        super::hertz::to_revolutions_per_minute(to_hertz(value))
+    }
+
+    /// Converts megahertz to radians per second.
+    /// # Arguments
+    /// * `value` - The frequency in megahertz to convert
+    pub fn to_radians_per_second(value: f64) -> f64 {
+        super::hertz::to_radians_per_second(to_hertz(value))
+    }
+
+    /// Converts megahertz to cycles per minute.
+    /// # Arguments
+    /// * `value` - The frequency in megahertz to convert
+    pub fn to_cycles_per_minute(value: f64) -> f64 {
+        super::hertz::to_cycles_per_minute(to_hertz(value))
+    }
+
+    /// Converts megahertz to cycles per hour.
+    /// # Arguments
+    /// * `value` - The frequency in megahertz to convert
+    pub fn to_cycles_per_hour(value: f64) -> f64 {
+        super::hertz::to_cycles_per_hour(to_hertz(value))
+    }
+
+    /// The megahertz as a typed unit of frequency.
+    pub const UNIT: Unit<Frequency> =
+        Unit::new("MHz", "megahertz", to_hertz, super::hertz::to_megahertz);
+
+    /// Creates a typed frequency quantity expressed in megahertz.
+    /// # Arguments
+    /// * `value` - The frequency in megahertz
+    pub fn new(value: f64) -> Quantity<Frequency> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed frequency quantity in megahertz.
+    /// # Arguments
+    /// * `quantity` - The frequency quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Frequency>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Gigahertz conversion functions
+pub mod gigahertz {
+    use crate::conversions::quantity::{Frequency, Quantity, Unit};
+
+    /// Converts gigahertz to hertz.
+    /// # Arguments
+    /// * `value` - The frequency in gigahertz to convert
+    pub fn to_hertz(value: f64) -> f64 {
+        value * 1e9
+    }
+
+    /// Converts gigahertz to kilohertz.
+    /// # Arguments
+    /// * `value` - The frequency in gigahertz to convert
+    pub fn to_kilohertz(value: f64) -> f64 {
+        super::hertz::to_kilohertz(to_hertz(value))
+    }
+
+    /// Converts gigahertz to megahertz.
+    /// # Arguments
+    /// * `value` - The frequency in gigahertz to convert
+    pub fn to_megahertz(value: f64) -> f64 {
+        super::hertz::to_megahertz(to_hertz(value))
+    }
+
+    /// Converts gigahertz to terahertz.
+    /// # Arguments
+    /// * `value` - The frequency in gigahertz to convert
+    pub fn to_terahertz(value: f64) -> f64 {
+        super::hertz::to_terahertz(to_hertz(value))
+    }
+
+    /// Converts gigahertz to revolutions per minute.
+    /// # Arguments
+    /// * `value` - The frequency in gigahertz to convert
+    pub fn to_revolutions_per_minute(value: f64) -> f64 {
+        super::hertz::to_revolutions_per_minute(to_hertz(value))
+    }
+
+    /// Converts gigahertz to radians per second.
+    /// # Arguments
+    /// * `value` - The frequency in gigahertz to convert
+    pub fn to_radians_per_second(value: f64) -> f64 {
+        super::hertz::to_radians_per_second(to_hertz(value))
+    }
+
+    /// Converts gigahertz to cycles per minute.
+    /// # Arguments
+    /// * `value` - The frequency in gigahertz to convert
+    pub fn to_cycles_per_minute(value: f64) -> f64 {
+        super::hertz::to_cycles_per_minute(to_hertz(value))
+    }
+
+    /// Converts gigahertz to cycles per hour.
+    /// # Arguments
+    /// * `value` - The frequency in gigahertz to convert
+    pub fn to_cycles_per_hour(value: f64) -> f64 {
+        super::hertz::to_cycles_per_hour(to_hertz(value))
+    }
+
+    /// The gigahertz as a typed unit of frequency.
+    pub const UNIT: Unit<Frequency> =
+        Unit::new("GHz", "gigahertz", to_hertz, super::hertz::to_gigahertz);
+
+    /// Creates a typed frequency quantity expressed in gigahertz.
+    /// # Arguments
+    /// * `value` - The frequency in gigahertz
+    pub fn new(value: f64) -> Quantity<Frequency> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed frequency quantity in gigahertz.
+    /// # Arguments
+    /// * `quantity` - The frequency quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Frequency>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Terahertz conversion functions
+pub mod terahertz {
+    use crate::conversions::quantity::{Frequency, Quantity, Unit};
+
+    /// Converts terahertz to hertz.
+    /// # Arguments
+    /// * `value` - The frequency in terahertz to convert
+    pub fn to_hertz(value: f64) -> f64 {
+        value * 1e12
+    }
+
+    /// Converts terahertz to kilohertz.
+    /// # Arguments
+    /// * `value` - The frequency in terahertz to convert
+    pub fn to_kilohertz(value: f64) -> f64 {
+        super::hertz::to_kilohertz(to_hertz(value))
+    }
+
+    /// Converts terahertz to megahertz.
+    /// # Arguments
+    /// * `value` - The frequency in terahertz to convert
+    pub fn to_megahertz(value: f64) -> f64 {
+        super::hertz::to_megahertz(to_hertz(value))
+    }
+
+    /// Converts terahertz to gigahertz.
+    /// # Arguments
+    /// * `value` - The frequency in terahertz to convert
+    pub fn to_gigahertz(value: f64) -> f64 {
+        super::hertz::to_gigahertz(to_hertz(value))
+    }
+
+    /// Converts terahertz to revolutions per minute.
+    /// # Arguments
+    /// * `value` - The frequency in terahertz to convert
+    pub fn to_revolutions_per_minute(value: f64) -> f64 {
+        super::hertz::to_revolutions_per_minute(to_hertz(value))
+    }
+
+    /// Converts terahertz to radians per second.
+    /// # Arguments
+    /// * `value` - The frequency in terahertz to convert
+    pub fn to_radians_per_second(value: f64) -> f64 {
+        super::hertz::to_radians_per_second(to_hertz(value))
+    }
+
+    /// Converts terahertz to cycles per minute.
+    /// # Arguments
+    /// * `value` - The frequency in terahertz to convert
+    pub fn to_cycles_per_minute(value: f64) -> f64 {
+        super::hertz::to_cycles_per_minute(to_hertz(value))
+    }
+
+    /// Converts terahertz to cycles per hour.
+    /// # Arguments
+    /// * `value` - The frequency in terahertz to convert
+    pub fn to_cycles_per_hour(value: f64) -> f64 {
+        super::hertz::to_cycles_per_hour(to_hertz(value))
+    }
+
+    /// The terahertz as a typed unit of frequency.
+    pub const UNIT: Unit<Frequency> =
+        Unit::new("THz", "terahertz", to_hertz, super::hertz::to_terahertz);
+
+    /// Creates a typed frequency quantity expressed in terahertz.
+    /// # Arguments
+    /// * `value` - The frequency in terahertz
+    pub fn new(value: f64) -> Quantity<Frequency> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed frequency quantity in terahertz.
+    /// # Arguments
+    /// * `quantity` - The frequency quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Frequency>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Revolutions per minute conversion functions
+pub mod revolutions_per_minute {
+    use crate::conversions::quantity::{Frequency, Quantity, Unit};
+
+    /// Converts revolutions per minute to hertz.
+    /// # Arguments
+    /// * `value` - The frequency in revolutions per minute to convert
+    pub fn to_hertz(value: f64) -> f64 {
+        value / super::seconds_per_minute()
+    }
+
+    /// Converts revolutions per minute to kilohertz.
+    /// # Arguments
+    /// * `value` - The frequency in revolutions per minute to convert
+    pub fn to_kilohertz(value: f64) -> f64 {
+        super::hertz::to_kilohertz(to_hertz(value))
+    }
+
+    /// Converts revolutions per minute to megahertz.
+    /// # Arguments
+    /// * `value` - The frequency in revolutions per minute to convert
+    pub fn to_megahertz(value: f64) -> f64 {
+        super::hertz::to_megahertz(to_hertz(value))
+    }
+
+    /// Converts revolutions per minute to gigahertz.
+    /// # Arguments
+    /// * `value` - The frequency in revolutions per minute to convert
+    pub fn to_gigahertz(value: f64) -> f64 {
+        super::hertz::to_gigahertz(to_hertz(value))
+    }
+
+    /// Converts revolutions per minute to terahertz.
+    /// # Arguments
+    /// * `value` - The frequency in revolutions per minute to convert
+    pub fn to_terahertz(value: f64) -> f64 {
+        super::hertz::to_terahertz(to_hertz(value))
+    }
+
+    /// Converts revolutions per minute to radians per second.
+    /// # Arguments
+    /// * `value` - The frequency in revolutions per minute to convert
+    pub fn to_radians_per_second(value: f64) -> f64 {
+        super::hertz::to_radians_per_second(to_hertz(value))
+    }
+
+    /// Converts revolutions per minute to cycles per minute.
+    /// # Arguments
+    /// * `value` - The frequency in revolutions per minute to convert
+    pub fn to_cycles_per_minute(value: f64) -> f64 {
+        value
+    }
+
+    /// Converts revolutions per minute to cycles per hour.
+    /// # Arguments
+    /// * `value` - The frequency in revolutions per minute to convert
+    pub fn to_cycles_per_hour(value: f64) -> f64 {
+        super::hertz::to_cycles_per_hour(to_hertz(value))
+    }
+
+    /// The revolution per minute as a typed unit of frequency.
+    pub const UNIT: Unit<Frequency> = Unit::new(
+        "rpm",
+        "revolution per minute",
+        to_hertz,
+        super::hertz::to_revolutions_per_minute,
+    );
+
+    /// Creates a typed frequency quantity expressed in revolutions per minute.
+    /// # Arguments
+    /// * `value` - The frequency in revolutions per minute
+    pub fn new(value: f64) -> Quantity<Frequency> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed frequency quantity in revolutions per minute.
+    /// # Arguments
+    /// * `quantity` - The frequency quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Frequency>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Radians per second (angular frequency) conversion functions
+///
+/// One revolution is 2π radians, so 1 Hz corresponds to 2π rad/s.
+pub mod radians_per_second {
+    use crate::conversions::quantity::{Frequency, Quantity, Unit};
+
+    /// Converts radians per second to hertz.
+    /// # Arguments
+    /// * `value` - The frequency in radians per second to convert
+    pub fn to_hertz(value: f64) -> f64 {
+        value / std::f64::consts::TAU
+    }
+
+    /// Converts radians per second to kilohertz.
+    /// # Arguments
+    /// * `value` - The frequency in radians per second to convert
+    pub fn to_kilohertz(value: f64) -> f64 {
+        super::hertz::to_kilohertz(to_hertz(value))
+    }
+
+    /// Converts radians per second to megahertz.
+    /// # Arguments
+    /// * `value` - The frequency in radians per second to convert
+    pub fn to_megahertz(value: f64) -> f64 {
+        super::hertz::to_megahertz(to_hertz(value))
+    }
+
+    /// Converts radians per second to gigahertz.
+    /// # Arguments
+    /// * `value` - The frequency in radians per second to convert
+    pub fn to_gigahertz(value: f64) -> f64 {
+        super::hertz::to_gigahertz(to_hertz(value))
+    }
+
+    /// Converts radians per second to terahertz.
+    /// # Arguments
+    /// * `value` - The frequency in radians per second to convert
+    pub fn to_terahertz(value: f64) -> f64 {
+        super::hertz::to_terahertz(to_hertz(value))
+    }
+
+    /// Converts radians per second to revolutions per minute.
+    /// # Arguments
+    /// * `value` - The frequency in radians per second to convert
+    pub fn to_revolutions_per_minute(value: f64) -> f64 {
+        super::hertz::to_revolutions_per_minute(to_hertz(value))
+    }
+
+    /// Converts radians per second to cycles per minute.
+    /// # Arguments
+    /// * `value` - The frequency in radians per second to convert
+    pub fn to_cycles_per_minute(value: f64) -> f64 {
+        super::hertz::to_cycles_per_minute(to_hertz(value))
+    }
+
+    /// Converts radians per second to cycles per hour.
+    /// # Arguments
+    /// * `value` - The frequency in radians per second to convert
+    pub fn to_cycles_per_hour(value: f64) -> f64 {
+        super::hertz::to_cycles_per_hour(to_hertz(value))
+    }
+
+    /// The radian per second as a typed unit of frequency.
+    pub const UNIT: Unit<Frequency> = Unit::new(
+        "rad/s",
+        "radian per second",
+        to_hertz,
+        super::hertz::to_radians_per_second,
+    );
+
+    /// Creates a typed frequency quantity expressed in radians per second.
+    /// # Arguments
+    /// * `value` - The frequency in radians per second
+    pub fn new(value: f64) -> Quantity<Frequency> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed frequency quantity in radians per second.
+    /// # Arguments
+    /// * `quantity` - The frequency quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Frequency>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Cycles per minute conversion functions
+pub mod cycles_per_minute {
+    use crate::conversions::quantity::{Frequency, Quantity, Unit};
+
+    /// Converts cycles per minute to hertz.
+    /// # Arguments
+    /// * `value` - The frequency in cycles per minute to convert
+    pub fn to_hertz(value: f64) -> f64 {
+        value / super::seconds_per_minute()
+    }
+
+    /// Converts cycles per minute to kilohertz.
+    /// # Arguments
+    /// * `value` - The frequency in cycles per minute to convert
+    pub fn to_kilohertz(value: f64) -> f64 {
+        super::hertz::to_kilohertz(to_hertz(value))
+    }
+
+    /// Converts cycles per minute to megahertz.
+    /// # Arguments
+    /// * `value` - The frequency in cycles per minute to convert
+    pub fn to_megahertz(value: f64) -> f64 {
+        super::hertz::to_megahertz(to_hertz(value))
+    }
+
+    /// Converts cycles per minute to gigahertz.
+    /// # Arguments
+    /// * `value` - The frequency in cycles per minute to convert
+    pub fn to_gigahertz(value: f64) -> f64 {
+        super::hertz::to_gigahertz(to_hertz(value))
+    }
+
+    /// Converts cycles per minute to terahertz.
+    /// # Arguments
+    /// * `value` - The frequency in cycles per minute to convert
+    pub fn to_terahertz(value: f64) -> f64 {
+        super::hertz::to_terahertz(to_hertz(value))
+    }
+
+    /// Converts cycles per minute to revolutions per minute.
+    /// # Arguments
+    /// * `value` - The frequency in cycles per minute to convert
+    pub fn to_revolutions_per_minute(value: f64) -> f64 {
+        value
+    }
+
+    /// Converts cycles per minute to radians per second.
+    /// # Arguments
+    /// * `value` - The frequency in cycles per minute to convert
+    pub fn to_radians_per_second(value: f64) -> f64 {
+        super::hertz::to_radians_per_second(to_hertz(value))
+    }
+
+    /// Converts cycles per minute to cycles per hour.
+    /// # Arguments
+    /// * `value` - The frequency in cycles per minute to convert
+    pub fn to_cycles_per_hour(value: f64) -> f64 {
+        value * 60.0
+    }
+
+    /// The cycle per minute as a typed unit of frequency.
+    pub const UNIT: Unit<Frequency> = Unit::new(
+        "cpm",
+        "cycle per minute",
+        to_hertz,
+        super::hertz::to_cycles_per_minute,
+    );
+
+    /// Creates a typed frequency quantity expressed in cycles per minute.
+    /// # Arguments
+    /// * `value` - The frequency in cycles per minute
+    pub fn new(value: f64) -> Quantity<Frequency> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed frequency quantity in cycles per minute.
+    /// # Arguments
+    /// * `quantity` - The frequency quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Frequency>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Cycles per hour conversion functions
+pub mod cycles_per_hour {
+    use crate::conversions::quantity::{Frequency, Quantity, Unit};
+
+    /// Converts cycles per hour to hertz.
+    /// # Arguments
+    /// * `value` - The frequency in cycles per hour to convert
+    pub fn to_hertz(value: f64) -> f64 {
+        value / super::seconds_per_hour()
+    }
+
+    /// Converts cycles per hour to kilohertz.
+    /// # Arguments
+    /// * `value` - The frequency in cycles per hour to convert
+    pub fn to_kilohertz(value: f64) -> f64 {
+        super::hertz::to_kilohertz(to_hertz(value))
+    }
+
+    /// Converts cycles per hour to megahertz.
+    /// # Arguments
+    /// * `value` - The frequency in cycles per hour to convert
+    pub fn to_megahertz(value: f64) -> f64 {
+        super::hertz::to_megahertz(to_hertz(value))
+    }
+
+    /// Converts cycles per hour to gigahertz.
+    /// # Arguments
+    /// * `value` - The frequency in cycles per hour to convert
+    pub fn to_gigahertz(value: f64) -> f64 {
+        super::hertz::to_gigahertz(to_hertz(value))
+    }
+
+    /// Converts cycles per hour to terahertz.
+    /// # Arguments
+    /// * `value` - The frequency in cycles per hour to convert
+    pub fn to_terahertz(value: f64) -> f64 {
+        super::hertz::to_terahertz(to_hertz(value))
+    }
+
+    /// Converts cycles per hour to revolutions per minute.
+    /// # Arguments
+    /// * `value` - The frequency in cycles per hour to convert
+    pub fn to_revolutions_per_minute(value: f64) -> f64 {
+        super::hertz::to_revolutions_per_minute(to_hertz(value))
+    }
+
+    /// Converts cycles per hour to radians per second.
+    /// # Arguments
+    /// * `value` - The frequency in cycles per hour to convert
+    pub fn to_radians_per_second(value: f64) -> f64 {
+        super::hertz::to_radians_per_second(to_hertz(value))
+    }
+
+    /// Converts cycles per hour to cycles per minute.
+    /// # Arguments
+    /// * `value` - The frequency in cycles per hour to convert
+    pub fn to_cycles_per_minute(value: f64) -> f64 {
+        value / 60.0
+    }
+
+    /// The cycle per hour as a typed unit of frequency.
+    pub const UNIT: Unit<Frequency> = Unit::new(
+        "cph",
+        "cycle per hour",
+        to_hertz,
+        super::hertz::to_cycles_per_hour,
+    );
+
+    /// Creates a typed frequency quantity expressed in cycles per hour.
+    /// # Arguments
+    /// * `value` - The frequency in cycles per hour
+    pub fn new(value: f64) -> Quantity<Frequency> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed frequency quantity in cycles per hour.
+    /// # Arguments
+    /// * `quantity` - The frequency quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Frequency>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Converts the period of a repeating signal to its frequency.
+///
+/// # Arguments
+/// * `period` - The typed duration of one cycle
+///
+/// # Returns
+/// * `Ok(Quantity<Frequency>)` - The frequency, in hertz
+/// * `Err(ConversionError)` - A [`ConversionError::NoReciprocal`] if the period
+///   is zero or negative
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::frequency::{from_period, hertz};
+/// use conversions_rs::time::milliseconds;
+///
+/// let frequency = from_period(milliseconds::new(2.5)).unwrap();
+/// assert!((hertz::value_of(frequency) - 400.0).abs() < 1e-9);
+/// ```
+pub fn from_period(period: Quantity<Time>) -> Result<Quantity<Frequency>, ConversionError> {
+    let seconds = time::seconds::value_of(period);
+    ensure_positive(seconds, period.value(), period.unit().symbol())?;
+    Ok(hertz::new(1.0 / seconds))
+}
+
+/// Converts the frequency of a repeating signal to its period.
+///
+/// # Arguments
+/// * `frequency` - The typed frequency
+///
+/// # Returns
+/// * `Ok(Quantity<Time>)` - The period, in seconds
+/// * `Err(ConversionError)` - A [`ConversionError::NoReciprocal`] if the
+///   frequency is zero or negative
+pub fn to_period(frequency: Quantity<Frequency>) -> Result<Quantity<Time>, ConversionError> {
+    let hertz = hertz::value_of(frequency);
+    ensure_positive(hertz, frequency.value(), frequency.unit().symbol())?;
+    Ok(time::seconds::new(1.0 / hertz))
+}
+
+/// Converts a period given with a string time unit to a frequency.
+///
+/// # Arguments
+/// * `period` - The duration of one cycle
+/// * `period_unit` - A time unit (e.g., "s", "ms", "us", "min")
+/// * `frequency_unit` - A frequency unit (e.g., "Hz", "kHz", "rpm", "rad/s")
+///
+/// # Returns
+/// * `Ok(f64)` - The frequency in `frequency_unit`
+/// * `Err(ConversionError)` - The reason the conversion is not supported,
+///   including [`ConversionError::NoReciprocal`] for a zero or negative period
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::period_to_frequency;
+///
+/// let hertz = period_to_frequency(2.5, "ms", "Hz").unwrap();
+/// assert!((hertz - 400.0).abs() < 1e-9);
+/// ```
+pub fn period_to_frequency(
+    period: f64,
+    period_unit: &str,
+    frequency_unit: &str,
+) -> Result<f64, ConversionError> {
+    let seconds = convert_time(period, period_unit, "s")?;
+    ensure_positive(seconds, period, period_unit)?;
+    convert_frequency(1.0 / seconds, "Hz", frequency_unit)
+}
+
+/// Converts a frequency given with a string unit to the period of one cycle.
+///
+/// # Arguments
+/// * `frequency` - The frequency
+/// * `frequency_unit` - A frequency unit (e.g., "Hz", "kHz", "rpm", "rad/s")
+/// * `period_unit` - A time unit (e.g., "s", "ms", "us", "min")
+///
+/// # Returns
+/// * `Ok(f64)` - The period in `period_unit`
+/// * `Err(ConversionError)` - The reason the conversion is not supported,
+///   including [`ConversionError::NoReciprocal`] for a zero or negative frequency
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::frequency_to_period;
+///
+/// let milliseconds = frequency_to_period(3000.0, "rpm", "ms").unwrap();
+/// assert!((milliseconds - 20.0).abs() < 1e-9);
+/// ```
+pub fn frequency_to_period(
+    frequency: f64,
+    frequency_unit: &str,
+    period_unit: &str,
+) -> Result<f64, ConversionError> {
+    let hertz = convert_frequency(frequency, frequency_unit, "Hz")?;
+    ensure_positive(hertz, frequency, frequency_unit)?;
+    convert_time(1.0 / hertz, "s", period_unit)
+}
+
+/// Rejects a period or frequency that is zero or negative, and so has no
+/// meaningful reciprocal.
+/// # Arguments
+/// * `base` - The value in seconds or hertz
+/// * `value` - The value as it was given
+/// * `unit` - The unit the value was given in
+fn ensure_positive(base: f64, value: f64, unit: &str) -> Result<(), ConversionError> {
+    if base > 0.0 {
+        Ok(())
+    } else {
+        Err(ConversionError::NoReciprocal {
+            value,
+            unit: unit.to_string(),
+        })
+    }
+}
+
+/// General frequency conversion function that accepts string unit names
+///
+/// Converts a frequency from one unit to another using string identifiers.
+/// Symbols are case-sensitive, names are case-insensitive, and common
+/// abbreviations are supported. Angular frequency (`rad/s`) is converted with
+/// one cycle equal to 2π radians.
+///
+/// # Arguments
+///
+/// * `value` - The numeric value to convert
+/// * `from_unit` - The source unit (e.g., "Hz", "kHz", "MHz", "GHz", "THz", "rpm",
+///   "rad/s", "cpm", "cph")
+/// * `to_unit` - The target unit using the same abbreviations
+///
+/// # Returns
+/// * `Ok(f64)` - The converted value
+/// * `Err(ConversionError)` - The reason the conversion is not supported
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::convert_frequency;
+///
+/// let hertz = convert_frequency(3000.0, "rpm", "Hz").unwrap();
+/// assert!((hertz - 50.0).abs() < 1e-9);
+///
+/// let radians_per_second = convert_frequency(1.0, "Hz", "rad/s").unwrap();
+/// assert!((radians_per_second - std::f64::consts::TAU).abs() < 1e-12);
+/// ```
+pub fn convert_frequency(
+    value: f64,
+    from_unit: &str,
+    to_unit: &str,
+) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from_unit, to_unit, Category::Frequency)
+}
+
+/// Frequency units known to the [`UnitRegistry`], with hertz as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new(
+            "Hz",
+            "hertz",
+            &["cps", "cycles per second"],
+            Category::Frequency,
+            1.0,
+        )
+        .with_prefixes(),
+        UnitDef::new(
+            "kHz",
+            "kilohertz",
+            &[],
+            Category::Frequency,
+            kilohertz::to_hertz(1.0),
+        ),
+        UnitDef::new(
+            "MHz",
+            "megahertz",
+            &[],
+            Category::Frequency,
+            megahertz::to_hertz(1.0),
+        ),
+        UnitDef::new(
+            "GHz",
+            "gigahertz",
+            &[],
+            Category::Frequency,
+            gigahertz::to_hertz(1.0),
+        ),
+        UnitDef::new(
+            "THz",
+            "terahertz",
+            &[],
+            Category::Frequency,
+            terahertz::to_hertz(1.0),
+        ),
+        UnitDef::new(
+            "rpm",
+            "revolution per minute",
+            &[
+                "r/min",
+                "rev/min",
+                "revolutions per minute",
+                "revolutions_per_minute",
+            ],
+            Category::Frequency,
+            revolutions_per_minute::to_hertz(1.0),
+        )
+        .with_exact_factor("1/60"),
+        UnitDef::new(
+            "rad/s",
+            "radian per second",
+            &["radians per second", "radians_per_second"],
+            Category::Frequency,
+            radians_per_second::to_hertz(1.0),
+        )
+        .with_irrational_factor(),
+        UnitDef::new(
+            "cpm",
+            "cycle per minute",
+            &["cycles per minute", "cycles_per_minute", "per minute"],
+            Category::Frequency,
+            cycles_per_minute::to_hertz(1.0),
+        )
+        .with_exact_factor("1/60"),
+        UnitDef::new(
+            "cph",
+            "cycle per hour",
+            &["cycles per hour", "cycles_per_hour", "per hour"],
+            Category::Frequency,
+            cycles_per_hour::to_hertz(1.0),
+        )
+        .with_exact_factor("1/3600"),
+    ]
+}
@@ -0,0 +1,122 @@
+//! Category-agnostic conversions
+//! Infers the kind of quantity from the units themselves, so callers do not
+//! have to pick between `convert_length`, `convert_current`, etc. up front.
+
+use super::angle::convert_angle;
+use super::area::convert_area;
+use super::capacitance::convert_capacitance;
+use super::category::Category;
+use super::charge::convert_charge;
+use super::conductance::convert_conductance;
+use super::current::convert_current;
+use super::data::convert_data;
+use super::data_rate::convert_data_rate;
+use super::density::convert_density;
+use super::energy::convert_energy;
+use super::error::ConversionError;
+use super::flow_rate::convert_flow_rate;
+use super::force::convert_force;
+use super::frequency::convert_frequency;
+use super::illuminance::convert_illuminance;
+use super::inductance::convert_inductance;
+use super::length::convert_length;
+use super::luminance::convert_luminance;
+use super::luminous_flux::convert_luminous_flux;
+use super::luminous_intensity::convert_luminous_intensity;
+use super::mass_flow_rate::convert_mass_flow_rate;
+use super::power::convert_power;
+use super::pressure::convert_pressure;
+use super::registry::UnitRegistry;
+use super::resistance::convert_resistance;
+use super::solid_angle::convert_solid_angle;
+use super::speed::convert_speed;
+use super::substance::convert_amount;
+use super::temperature::{convert_temperature, convert_temperature_interval};
+use super::time::convert_time;
+use super::torque::convert_torque;
+use super::voltage::convert_voltage;
+use super::volume::convert_volume;
+use super::weight::convert_weight;
+
+/// Converts a value between any two units, inferring the category from the units.
+///
+/// # Arguments
+/// * `value` - The numeric value to convert
+/// * `from` - The source unit (any symbol, name or alias)
+/// * `to` - The target unit (any symbol, name or alias)
+///
+/// # Returns
+/// * `Ok(f64)` - The converted value
+/// * `Err(ConversionError)` - If a unit is unknown, or the units measure
+///   different quantities (e.g. feet to kilograms)
+///
+/// # Examples
+/// ```rust
+/// use conversions_rs::{convert, ConversionError};
+///
+/// let meters = convert(3.0, "ft", "m").unwrap();
+/// assert!((meters - 0.9144).abs() < 0.0001);
+///
+/// let amperes = convert(20.0, "mA", "A").unwrap();
+/// assert!((amperes - 0.02).abs() < 1e-12);
+///
+/// assert!(matches!(
+///     convert(3.0, "ft", "kg"),
+///     Err(ConversionError::IncompatibleDimensions { .. })
+/// ));
+/// ```
+pub fn convert(value: f64, from: &str, to: &str) -> Result<f64, ConversionError> {
+    let category = UnitRegistry::global().infer_category(from, to)?;
+    convert_in(value, from, to, category)
+}
+
+/// Converts a value between two units of a known category.
+///
+/// Dispatches to the category's `convert_*` function.
+/// # Arguments
+/// * `value` - The numeric value to convert
+/// * `from` - The source unit
+/// * `to` - The target unit
+/// * `category` - The category both units belong to
+pub fn convert_in(
+    value: f64,
+    from: &str,
+    to: &str,
+    category: Category,
+) -> Result<f64, ConversionError> {
+    match category {
+        Category::Length => convert_length(value, from, to),
+        Category::Mass => convert_weight(value, from, to),
+        Category::Temperature => convert_temperature(value, from, to),
+        Category::Volume => convert_volume(value, from, to),
+        Category::Time => convert_time(value, from, to),
+        Category::Current => convert_current(value, from, to),
+        Category::Amount => convert_amount(value, from, to),
+        Category::LuminousIntensity => convert_luminous_intensity(value, from, to),
+        Category::Area => convert_area(value, from, to),
+        Category::Speed => convert_speed(value, from, to),
+        Category::Pressure => convert_pressure(value, from, to),
+        Category::Energy => convert_energy(value, from, to),
+        Category::Power => convert_power(value, from, to),
+        Category::Force => convert_force(value, from, to),
+        Category::Torque => convert_torque(value, from, to),
+        Category::Frequency => convert_frequency(value, from, to),
+        Category::Angle => convert_angle(value, from, to),
+        Category::SolidAngle => convert_solid_angle(value, from, to),
+        Category::Data => convert_data(value, from, to),
+        Category::DataRate => convert_data_rate(value, from, to),
+        Category::Density => convert_density(value, from, to),
+        Category::FlowRate => convert_flow_rate(value, from, to),
+        Category::MassFlowRate => convert_mass_flow_rate(value, from, to),
+        Category::Voltage => convert_voltage(value, from, to),
+        Category::Resistance => convert_resistance(value, from, to),
+        Category::Conductance => convert_conductance(value, from, to),
+        Category::Charge => convert_charge(value, from, to),
+        Category::Capacitance => convert_capacitance(value, from, to),
+        Category::Inductance => convert_inductance(value, from, to),
+        Category::LuminousFlux => convert_luminous_flux(value, from, to),
+        Category::Illuminance => convert_illuminance(value, from, to),
+        Category::Luminance => convert_luminance(value, from, to),
+        Category::TemperatureInterval => convert_temperature_interval(value, from, to),
+    }
+}
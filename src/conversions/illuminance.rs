@@ -0,0 +1,347 @@
+//! Illuminance conversion functions organized by unit type
+//! All conversions use lux (lumens per square meter) as the base unit,
+//! composing the `area` definitions for the foot-candle and phot. A point
+//! source's intensity and distance give the illuminance it casts by the
+//! inverse-square law
+
+use super::area;
+use super::category::Category;
+use super::error::ConversionError;
+use super::length::{self, convert_length};
+use super::luminous_intensity::{self, convert_luminous_intensity};
+use super::quantity::{Illuminance, Length, LuminousIntensity, Quantity};
+use super::registry::{UnitDef, UnitRegistry};
+
+/// Lux in one foot-candle (one lumen per square foot)
+fn foot_candles() -> f64 {
+    1.0 / area::square_feet::to_square_meters(1.0)
+}
+
+/// Lux in one phot (one lumen per square centimeter)
+fn phots() -> f64 {
+    1.0 / area::square_centimeters::to_square_meters(1.0)
+}
+
+/// Lux conversion functions
+pub mod lux {
+    use crate::conversions::quantity::{Illuminance, Quantity, Unit};
+
+    /// Converts lux to foot-candles.
+    /// # Arguments
+    /// * `value` - The illuminance in lux to convert
+    pub fn to_foot_candles(value: f64) -> f64 {
+        value / super::foot_candles()
+    }
+
+    /// Converts lux to phots.
+    /// # Arguments
+    /// * `value` - The illuminance in lux to convert
+    pub fn to_phots(value: f64) -> f64 {
+        value / super::phots()
+    }
+
+    /// The lux as a typed unit of illuminance.
+    pub const UNIT: Unit<Illuminance> = Unit::new("lx", "lux", |value| value, |value| value);
+
+    /// Creates a typed illuminance quantity expressed in lux.
+    /// # Arguments
+    /// * `value` - The illuminance in lux
+    pub fn new(value: f64) -> Quantity<Illuminance> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed illuminance quantity in lux.
+    /// # Arguments
+    /// * `quantity` - The illuminance quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Illuminance>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Foot-candles conversion functions
+pub mod foot_candles {
+    use crate::conversions::quantity::{Illuminance, Quantity, Unit};
+
+    /// Converts foot-candles to lux.
+    /// # Arguments
+    /// * `value` - The illuminance in foot-candles to convert
+    pub fn to_lux(value: f64) -> f64 {
+        value * super::foot_candles()
+    }
+
+    /// Converts foot-candles to phots.
+    /// # Arguments
+    /// * `value` - The illuminance in foot-candles to convert
+    pub fn to_phots(value: f64) -> f64 {
+        super::lux::to_phots(to_lux(value))
+    }
+
+    /// The foot-candle as a typed unit of illuminance.
+    pub const UNIT: Unit<Illuminance> =
+        Unit::new("fc", "foot-candle", to_lux, super::lux::to_foot_candles);
+
+    /// Creates a typed illuminance quantity expressed in foot-candles.
+    /// # Arguments
+    /// * `value` - The illuminance in foot-candles
+    pub fn new(value: f64) -> Quantity<Illuminance> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed illuminance quantity in foot-candles.
+    /// # Arguments
+    /// * `quantity` - The illuminance quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Illuminance>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Phots conversion functions
+pub mod phots {
+    use crate::conversions::quantity::{Illuminance, Quantity, Unit};
+
+    /// Converts phots to lux.
+    /// # Arguments
+    /// * `value` - The illuminance in phots to convert
+    pub fn to_lux(value: f64) -> f64 {
+        value * super::phots()
+    }
+
+    /// Converts phots to foot-candles.
+    /// # Arguments
+    /// * `value` - The illuminance in phots to convert
+    pub fn to_foot_candles(value: f64) -> f64 {
+        super::lux::to_foot_candles(to_lux(value))
+    }
+
+    /// The phot as a typed unit of illuminance.
+    pub const UNIT: Unit<Illuminance> = Unit::new("ph", "phot", to_lux, super::lux::to_phots);
+
+    /// Creates a typed illuminance quantity expressed in phots.
+    /// # Arguments
+    /// * `value` - The illuminance in phots
+    pub fn new(value: f64) -> Quantity<Illuminance> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed illuminance quantity in phots.
+    /// # Arguments
+    /// * `quantity` - The illuminance quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Illuminance>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Converts the luminous intensity of a point source to the illuminance it
+/// casts on a surface facing it at a given distance (E = I / d²).
+///
+/// # Arguments
+/// * `intensity` - The typed luminous intensity of the source
+/// * `distance` - The typed distance from the source to the surface
+///
+/// # Returns
+/// * `Ok(Quantity<Illuminance>)` - The illuminance, in lux
+/// * `Err(ConversionError)` - A [`ConversionError::NoReciprocal`] if the
+///   distance is zero or negative
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::illuminance::{from_intensity_at, lux};
+/// use conversions_rs::length::meters;
+/// use conversions_rs::luminous_intensity::candela;
+///
+/// let illuminance = from_intensity_at(candela::new(800.0), meters::new(2.0)).unwrap();
+/// assert!((lux::value_of(illuminance) - 200.0).abs() < 1e-9);
+/// ```
+pub fn from_intensity_at(
+    intensity: Quantity<LuminousIntensity>,
+    distance: Quantity<Length>,
+) -> Result<Quantity<Illuminance>, ConversionError> {
+    let meters = length::meters::value_of(distance);
+    ensure_positive_distance(meters, distance.value(), distance.unit().symbol())?;
+    Ok(lux::new(
+        luminous_intensity::candela::value_of(intensity) / (meters * meters),
+    ))
+}
+
+/// Converts the illuminance on a surface to the luminous intensity of a
+/// point source facing it at a given distance (I = E · d²).
+///
+/// # Arguments
+/// * `illuminance` - The typed illuminance on the surface
+/// * `distance` - The typed distance from the source to the surface
+///
+/// # Returns
+/// * `Ok(Quantity<LuminousIntensity>)` - The luminous intensity, in candela
+/// * `Err(ConversionError)` - A [`ConversionError::NoReciprocal`] if the
+///   distance is zero or negative
+pub fn to_intensity_at(
+    illuminance: Quantity<Illuminance>,
+    distance: Quantity<Length>,
+) -> Result<Quantity<LuminousIntensity>, ConversionError> {
+    let meters = length::meters::value_of(distance);
+    ensure_positive_distance(meters, distance.value(), distance.unit().symbol())?;
+    Ok(luminous_intensity::candela::new(
+        lux::value_of(illuminance) * meters * meters,
+    ))
+}
+
+/// Converts a luminous intensity given with a string unit to the illuminance
+/// it casts at a given distance, by the inverse-square law.
+///
+/// # Arguments
+/// * `intensity` - The luminous intensity of the point source
+/// * `intensity_unit` - A luminous intensity unit (e.g., "cd", "mcd")
+/// * `distance` - The distance from the source to the surface
+/// * `distance_unit` - A length unit (e.g., "m", "ft")
+/// * `illuminance_unit` - An illuminance unit (e.g., "lx", "fc")
+///
+/// # Returns
+/// * `Ok(f64)` - The illuminance in `illuminance_unit`
+/// * `Err(ConversionError)` - The reason the conversion is not supported,
+///   including [`ConversionError::NoReciprocal`] for a zero or negative distance
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::intensity_to_illuminance;
+///
+/// let foot_candles = intensity_to_illuminance(2500.0, "cd", 10.0, "ft", "fc").unwrap();
+/// assert!((foot_candles - 25.0).abs() < 1e-9);
+/// ```
+pub fn intensity_to_illuminance(
+    intensity: f64,
+    intensity_unit: &str,
+    distance: f64,
+    distance_unit: &str,
+    illuminance_unit: &str,
+) -> Result<f64, ConversionError> {
+    let candela = convert_luminous_intensity(intensity, intensity_unit, "cd")?;
+    let meters = convert_length(distance, distance_unit, "m")?;
+    ensure_positive_distance(meters, distance, distance_unit)?;
+    convert_illuminance(candela / (meters * meters), "lx", illuminance_unit)
+}
+
+/// Converts an illuminance given with a string unit to the luminous intensity
+/// of a point source at a given distance, by the inverse-square law.
+///
+/// # Arguments
+/// * `illuminance` - The illuminance on the surface
+/// * `illuminance_unit` - An illuminance unit (e.g., "lx", "fc")
+/// * `distance` - The distance from the source to the surface
+/// * `distance_unit` - A length unit (e.g., "m", "ft")
+/// * `intensity_unit` - A luminous intensity unit (e.g., "cd", "mcd")
+///
+/// # Returns
+/// * `Ok(f64)` - The luminous intensity in `intensity_unit`
+/// * `Err(ConversionError)` - The reason the conversion is not supported,
+///   including [`ConversionError::NoReciprocal`] for a zero or negative distance
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::illuminance_to_intensity;
+///
+/// // 500 lx measured 3 m below a downlight
+/// let candela = illuminance_to_intensity(500.0, "lx", 3.0, "m", "cd").unwrap();
+/// assert!((candela - 4500.0).abs() < 1e-9);
+/// ```
+pub fn illuminance_to_intensity(
+    illuminance: f64,
+    illuminance_unit: &str,
+    distance: f64,
+    distance_unit: &str,
+    intensity_unit: &str,
+) -> Result<f64, ConversionError> {
+    let lux = convert_illuminance(illuminance, illuminance_unit, "lx")?;
+    let meters = convert_length(distance, distance_unit, "m")?;
+    ensure_positive_distance(meters, distance, distance_unit)?;
+    convert_luminous_intensity(lux * meters * meters, "cd", intensity_unit)
+}
+
+/// Rejects a distance to a point source that is zero, where the
+/// inverse-square law has no value, or negative.
+/// # Arguments
+/// * `meters` - The distance in meters
+/// * `value` - The distance as it was given
+/// * `unit` - The unit the distance was given in
+fn ensure_positive_distance(meters: f64, value: f64, unit: &str) -> Result<(), ConversionError> {
+    if meters > 0.0 {
+        Ok(())
+    } else {
+        Err(ConversionError::NoReciprocal {
+            value,
+            unit: unit.to_string(),
+        })
+    }
+}
+
+/// General illuminance conversion function that accepts string unit names
+///
+/// Converts an illuminance from one unit to another using string
+/// identifiers. Symbols are case-sensitive, names are case-insensitive, and
+/// SI prefixes are accepted on the lux.
+///
+/// # Arguments
+///
+/// * `value` - The numeric value to convert
+/// * `from_unit` - The source unit (e.g., "lx", "klx", "fc", "ph")
+/// * `to_unit` - The target unit using the same abbreviations
+///
+/// # Returns
+/// * `Ok(f64)` - The converted value
+/// * `Err(ConversionError)` - The reason the conversion is not supported
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::convert_illuminance;
+///
+/// let lux = convert_illuminance(50.0, "fc", "lx").unwrap();
+/// assert!((lux - 538.196).abs() < 0.001);
+/// ```
+pub fn convert_illuminance(
+    value: f64,
+    from_unit: &str,
+    to_unit: &str,
+) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from_unit, to_unit, Category::Illuminance)
+}
+
+/// Illuminance units known to the [`UnitRegistry`], with lux as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new(
+            "lx",
+            "lux",
+            &["lm/m²", "lm/m2", "lumens per square meter"],
+            Category::Illuminance,
+            1.0,
+        )
+        .with_prefixes(),
+        UnitDef::new(
+            "fc",
+            "foot-candle",
+            &[
+                "ft·c",
+                "lm/ft²",
+                "lm/ft2",
+                "footcandle",
+                "footcandles",
+                "foot-candles",
+                "foot_candles",
+            ],
+            Category::Illuminance,
+            foot_candles::to_lux(1.0),
+        )
+        .with_exact_factor("1/0.09290304"),
+        UnitDef::new(
+            "ph",
+            "phot",
+            &["phots", "lm/cm²", "lm/cm2"],
+            Category::Illuminance,
+            phots::to_lux(1.0),
+        )
+        .with_exact_factor("10000"),
+    ]
+}
@@ -0,0 +1,184 @@
+//! Inductance conversion functions organized by unit type
+//! All conversions use henries as the base unit for accuracy and consistency
+
+use super::category::Category;
+use super::error::ConversionError;
+use super::registry::{UnitDef, UnitRegistry};
+
+/// Henries conversion functions
+pub mod henries {
+    use crate::conversions::quantity::{Inductance, Quantity, Unit};
+
+    /// Converts henries to millihenries.
+    /// # Arguments
+    /// * `value` - The inductance in henries to convert
+    pub fn to_millihenries(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// Converts henries to microhenries.
+    /// # Arguments
+    /// * `value` - The inductance in henries to convert
+    pub fn to_microhenries(value: f64) -> f64 {
+        value * 1_000_000.0
+    }
+
+    /// The henry as a typed unit of inductance.
+    pub const UNIT: Unit<Inductance> = Unit::new("H", "henry", |value| value, |value| value);
+
+    /// Creates a typed inductance quantity expressed in henries.
+    /// # Arguments
+    /// * `value` - The inductance in henries
+    pub fn new(value: f64) -> Quantity<Inductance> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed inductance quantity in henries.
+    /// # Arguments
+    /// * `quantity` - The inductance quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Inductance>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Millihenries conversion functions
+pub mod millihenries {
+    use crate::conversions::quantity::{Inductance, Quantity, Unit};
+
+    /// Converts millihenries to henries.
+    /// # Arguments
+    /// * `value` - The inductance in millihenries to convert
+    pub fn to_henries(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// Converts millihenries to microhenries.
+    /// # Arguments
+    /// * `value` - The inductance in millihenries to convert
+    pub fn to_microhenries(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// The millihenry as a typed unit of inductance.
+    pub const UNIT: Unit<Inductance> = Unit::new(
+        "mH",
+        "millihenry",
+        to_henries,
+        super::henries::to_millihenries,
+    );
+
+    /// Creates a typed inductance quantity expressed in millihenries.
+    /// # Arguments
+    /// * `value` - The inductance in millihenries
+    pub fn new(value: f64) -> Quantity<Inductance> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed inductance quantity in millihenries.
+    /// # Arguments
+    /// * `quantity` - The inductance quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Inductance>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Microhenries conversion functions
+pub mod microhenries {
+    use crate::conversions::quantity::{Inductance, Quantity, Unit};
+
+    /// Converts microhenries to henries.
+    /// # Arguments
+    /// * `value` - The inductance in microhenries to convert
+    pub fn to_henries(value: f64) -> f64 {
+        value / 1_000_000.0
+    }
+
+    /// Converts microhenries to millihenries.
+    /// # Arguments
+    /// * `value` - The inductance in microhenries to convert
+    pub fn to_millihenries(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// The microhenry as a typed unit of inductance.
+    pub const UNIT: Unit<Inductance> = Unit::new(
+        "μH",
+        "microhenry",
+        to_henries,
+        super::henries::to_microhenries,
+    );
+
+    /// Creates a typed inductance quantity expressed in microhenries.
+    /// # Arguments
+    /// * `value` - The inductance in microhenries
+    pub fn new(value: f64) -> Quantity<Inductance> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed inductance quantity in microhenries.
+    /// # Arguments
+    /// * `quantity` - The inductance quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Inductance>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// General inductance conversion function that accepts string unit names
+///
+/// Converts an inductance from one unit to another using string identifiers.
+/// Symbols are case-sensitive, so `mH` (millihenry) and `MH` (megahenry) are
+/// different units; names are case-insensitive.
+///
+/// # Arguments
+///
+/// * `value` - The numeric value to convert
+/// * `from_unit` - The source unit (e.g., "H", "mH", "μH", "uH")
+/// * `to_unit` - The target unit using the same abbreviations
+///
+/// # Returns
+/// * `Ok(f64)` - The converted value
+/// * `Err(ConversionError)` - The reason the conversion is not supported
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::convert_inductance;
+///
+/// let microhenries = convert_inductance(2.2, "mH", "μH").unwrap();
+/// assert!((microhenries - 2200.0).abs() < 1e-9);
+/// ```
+pub fn convert_inductance(
+    value: f64,
+    from_unit: &str,
+    to_unit: &str,
+) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from_unit, to_unit, Category::Inductance)
+}
+
+/// Inductance units known to the [`UnitRegistry`], with henries as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new(
+            "H",
+            "henry",
+            &["henries", "henrys"],
+            Category::Inductance,
+            1.0,
+        )
+        .with_prefixes(),
+        UnitDef::new(
+            "mH",
+            "millihenry",
+            &["millihenries", "millihenrys"],
+            Category::Inductance,
+            millihenries::to_henries(1.0),
+        ),
+        UnitDef::new(
+            "μH",
+            "microhenry",
+            &["uH", "microhenries", "microhenrys"],
+            Category::Inductance,
+            microhenries::to_henries(1.0),
+        ),
+    ]
+}
@@ -1,419 +1,8226 @@
 //! Length conversion functions organized by unit type
 //! All conversions use meters as the base unit for accuracy and consistency
 
+use super::category::Category;
+use super::error::ConversionError;
+use super::registry::{UnitDef, UnitRegistry};
+
+/// Meters in one international inch (exactly 2.54 cm)
+pub const METERS_PER_INCH: f64 = 0.0254;
+
+/// Meters in one international foot (12 inches)
+pub const METERS_PER_FOOT: f64 = 0.3048;
+
+/// Meters in one international yard, exact by the 1959 international yard and
+/// pound agreement
+pub const METERS_PER_YARD: f64 = 0.9144;
+
+/// Meters in one international mile (1760 yards)
+pub const METERS_PER_MILE: f64 = 1609.344;
+
+/// Meters in one international nautical mile (exactly 1852 m)
+pub const METERS_PER_NAUTICAL_MILE: f64 = 1852.0;
+
+/// Meters in one US survey foot (exactly 1200/3937 m), the pre-1959 foot kept
+/// for US land surveys; it is two parts per million longer than [`METERS_PER_FOOT`]
+pub const METERS_PER_US_SURVEY_FOOT: f64 = 1200.0 / 3937.0;
+
+/// Meters in one US survey mile (5280 US survey feet, exactly 6336000/3937 m)
+pub const METERS_PER_US_SURVEY_MILE: f64 = 6_336_000.0 / 3937.0;
+
+/// Meters in one astronomical unit, exact by IAU 2012 Resolution B2
+pub const METERS_PER_ASTRONOMICAL_UNIT: f64 = 149_597_870_700.0;
+
+/// Meters light travels in one second, exact by the SI definition of the meter
+pub const METERS_PER_LIGHT_SECOND: f64 = 299_792_458.0;
+
+/// Meters light travels in one Julian year (365.25 days, the year of
+/// `time::years`), exactly 9 460 730 472 580 800 m
+pub const METERS_PER_LIGHT_YEAR: f64 = METERS_PER_LIGHT_SECOND * 31_557_600.0;
+
+/// Meters in one parsec, defined by IAU 2015 Resolution B2 as 648 000/π
+/// astronomical units
+pub const METERS_PER_PARSEC: f64 = METERS_PER_ASTRONOMICAL_UNIT * 648_000.0 / std::f64::consts::PI;
+
+/// Meters in the nominal equatorial Earth radius (IAU 2015 Resolution B3)
+pub const METERS_PER_EARTH_RADIUS: f64 = 6_378_100.0;
+
+/// Meters in the nominal solar radius (IAU 2015 Resolution B3)
+pub const METERS_PER_SOLAR_RADIUS: f64 = 695_700_000.0;
+
 /// Meter conversion functions
 pub mod meters {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
     /// Converts meters to feet.
     /// # Arguments
-    /// * `value` - The length in meters to convert
-    pub fn to_feet(value: f64) -> f64 {
-        value * 3.28084
+    /// * `value` - The length in meters to convert
+    pub fn to_feet(value: f64) -> f64 {
+        value / super::METERS_PER_FOOT
+    }
+
+    /// Converts meters to inches.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_inches(value: f64) -> f64 {
+        value / super::METERS_PER_INCH
+    }
+
+    /// Converts meters to kilometers.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// Converts meters to centimeters.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        value * 100.0
+    }
+
+    /// Converts meters to millimeters.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// Converts meters to yards.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_yards(value: f64) -> f64 {
+        value / super::METERS_PER_YARD
+    }
+
+    /// Converts meters to miles.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_miles(value: f64) -> f64 {
+        value / super::METERS_PER_MILE
+    }
+
+    /// Converts meters to micrometers.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        value * 1e6
+    }
+
+    /// Converts meters to nanometers.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        value * 1e9
+    }
+
+    /// Converts meters to picometers.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        value * 1e12
+    }
+
+    /// Converts meters to ångströms.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        value * 1e10
+    }
+
+    /// Converts meters to femtometers.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        value * 1e15
+    }
+
+    /// Converts meters to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        value / super::METERS_PER_ASTRONOMICAL_UNIT
+    }
+
+    /// Converts meters to light-years.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        value / super::METERS_PER_LIGHT_YEAR
+    }
+
+    /// Converts meters to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        value / super::METERS_PER_LIGHT_SECOND
+    }
+
+    /// Converts meters to parsecs.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        value / super::METERS_PER_PARSEC
+    }
+
+    /// Converts meters to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        value / (1000.0 * super::METERS_PER_PARSEC)
+    }
+
+    /// Converts meters to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        value / (1e6 * super::METERS_PER_PARSEC)
+    }
+
+    /// Converts meters to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        value / super::METERS_PER_EARTH_RADIUS
+    }
+
+    /// Converts meters to solar radii.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        value / super::METERS_PER_SOLAR_RADIUS
+    }
+
+    /// Converts meters to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        value / super::METERS_PER_NAUTICAL_MILE
+    }
+
+    /// Converts meters to cables.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_cables(value: f64) -> f64 {
+        value * 10.0 / super::METERS_PER_NAUTICAL_MILE
+    }
+
+    /// Converts meters to fathoms.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_feet(value) / 6.0
+    }
+
+    /// Converts meters to chains.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_chains(value: f64) -> f64 {
+        super::meters::to_feet(value) / 66.0
+    }
+
+    /// Converts meters to links.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_chains(value) * 100.0
+    }
+
+    /// Converts meters to rods.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_rods(value: f64) -> f64 {
+        super::meters::to_feet(value) / 16.5
+    }
+
+    /// Converts meters to furlongs.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        super::meters::to_feet(value) / 660.0
+    }
+
+    /// Converts meters to leagues.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_miles(value) / 3.0
+    }
+
+    /// Converts meters to hands.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_inches(value) / 4.0
+    }
+
+    /// Converts meters to mils.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_inches(value) * 1000.0
+    }
+
+    /// Converts meters to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        value / super::METERS_PER_US_SURVEY_FOOT
+    }
+
+    /// Converts meters to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in meters to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        value / super::METERS_PER_US_SURVEY_MILE
+    }
+
+    /// The meter as a typed unit of length.
+    pub const UNIT: Unit<Length> = Unit::new("m", "meter", |value| value, |value| value);
+
+    /// Creates a typed length quantity expressed in meters.
+    /// # Arguments
+    /// * `value` - The length in meters
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in meters.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// International foot conversion functions (see [`us_survey_feet`] for the US
+/// survey foot)
+pub mod feet {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts feet to meters.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_meters(value: f64) -> f64 {
+        value * super::METERS_PER_FOOT
+    }
+
+    /// Converts feet to inches.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_inches(value: f64) -> f64 {
+        value * 12.0
+    }
+
+    /// Converts feet to yards.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_yards(value: f64) -> f64 {
+        value / 3.0
+    }
+
+    /// Converts feet to miles.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_miles(value: f64) -> f64 {
+        value / 5280.0
+    }
+
+    /// Converts feet to centimeters.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        super::meters::to_centimeters(to_meters(value))
+    }
+
+    /// Converts feet to millimeters.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        super::meters::to_millimeters(to_meters(value))
+    }
+
+    /// Converts feet to kilometers.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
+    }
+
+    /// Converts feet to micrometers.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts feet to nanometers.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts feet to picometers.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts feet to ångströms.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
+    }
+
+    /// Converts feet to femtometers.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts feet to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts feet to light-years.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
+    }
+
+    /// Converts feet to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
+    }
+
+    /// Converts feet to parsecs.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
+    }
+
+    /// Converts feet to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
+    }
+
+    /// Converts feet to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
+    }
+
+    /// Converts feet to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts feet to solar radii.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts feet to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
+    }
+
+    /// Converts feet to cables.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
+    }
+
+    /// Converts feet to fathoms.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        value / 6.0
+    }
+
+    /// Converts feet to chains.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_chains(value: f64) -> f64 {
+        value / 66.0
+    }
+
+    /// Converts feet to links.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_links(to_meters(value))
+    }
+
+    /// Converts feet to rods.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_rods(value: f64) -> f64 {
+        value / 16.5
+    }
+
+    /// Converts feet to furlongs.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        value / 660.0
+    }
+
+    /// Converts feet to leagues.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
+    }
+
+    /// Converts feet to hands.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_hands(value: f64) -> f64 {
+        value * 3.0
+    }
+
+    /// Converts feet to mils.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
+    }
+
+    /// Converts feet to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts feet to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in feet to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The foot as a typed unit of length.
+    pub const UNIT: Unit<Length> = Unit::new("ft", "foot", to_meters, super::meters::to_feet);
+
+    /// Creates a typed length quantity expressed in feet.
+    /// # Arguments
+    /// * `value` - The length in feet
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in feet.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Inches conversion functions
+pub mod inches {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts inches to meters.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_meters(value: f64) -> f64 {
+        value * super::METERS_PER_INCH
+    }
+
+    /// Converts inches to feet.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_feet(value: f64) -> f64 {
+        value / 12.0
+    }
+
+    /// Converts inches to yards.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_yards(value: f64) -> f64 {
+        value / 36.0
+    }
+
+    /// Converts inches to centimeters.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        value * 2.54
+    }
+
+    /// Converts inches to millimeters.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        value * 25.4
+    }
+
+    /// Converts inches to kilometers.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
+    }
+
+    /// Converts inches to miles.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_miles(value: f64) -> f64 {
+        super::meters::to_miles(to_meters(value))
+    }
+
+    /// Converts inches to micrometers.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts inches to nanometers.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts inches to picometers.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts inches to ångströms.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
+    }
+
+    /// Converts inches to femtometers.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts inches to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts inches to light-years.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
+    }
+
+    /// Converts inches to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
+    }
+
+    /// Converts inches to parsecs.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
+    }
+
+    /// Converts inches to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
+    }
+
+    /// Converts inches to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
+    }
+
+    /// Converts inches to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts inches to solar radii.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts inches to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
+    }
+
+    /// Converts inches to cables.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
+    }
+
+    /// Converts inches to fathoms.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
+    }
+
+    /// Converts inches to chains.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_chains(value: f64) -> f64 {
+        super::meters::to_chains(to_meters(value))
+    }
+
+    /// Converts inches to links.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_links(to_meters(value))
+    }
+
+    /// Converts inches to rods.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_rods(value: f64) -> f64 {
+        super::meters::to_rods(to_meters(value))
+    }
+
+    /// Converts inches to furlongs.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        super::meters::to_furlongs(to_meters(value))
+    }
+
+    /// Converts inches to leagues.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
+    }
+
+    /// Converts inches to hands.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_hands(value: f64) -> f64 {
+        value / 4.0
+    }
+
+    /// Converts inches to mils.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_mils(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// Converts inches to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts inches to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in inches to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The inch as a typed unit of length.
+    pub const UNIT: Unit<Length> = Unit::new("in", "inch", to_meters, super::meters::to_inches);
+
+    /// Creates a typed length quantity expressed in inches.
+    /// # Arguments
+    /// * `value` - The length in inches
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in inches.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Kilometers conversion functions
+pub mod kilometers {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts kilometers to meters.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_meters(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// Converts kilometers to feet.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_feet(value: f64) -> f64 {
+        super::meters::to_feet(to_meters(value))
+    }
+
+    /// Converts kilometers to inches.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_inches(value: f64) -> f64 {
+        super::meters::to_inches(to_meters(value))
+    }
+
+    /// Converts kilometers to yards.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_yards(value: f64) -> f64 {
+        super::meters::to_yards(to_meters(value))
+    }
+
+    /// Converts kilometers to miles.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_miles(value: f64) -> f64 {
+        super::meters::to_miles(to_meters(value))
+    }
+
+    /// Converts kilometers to centimeters.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        value * 100000.0
+    }
+
+    /// Converts kilometers to millimeters.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        value * 1000000.0
+    }
+
+    /// Converts kilometers to micrometers.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts kilometers to nanometers.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts kilometers to picometers.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts kilometers to ångströms.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
+    }
+
+    /// Converts kilometers to femtometers.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts kilometers to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts kilometers to light-years.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
+    }
+
+    /// Converts kilometers to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
+    }
+
+    /// Converts kilometers to parsecs.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
+    }
+
+    /// Converts kilometers to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
+    }
+
+    /// Converts kilometers to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
+    }
+
+    /// Converts kilometers to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts kilometers to solar radii.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts kilometers to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
+    }
+
+    /// Converts kilometers to cables.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
+    }
+
+    /// Converts kilometers to fathoms.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
+    }
+
+    /// Converts kilometers to chains.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_chains(value: f64) -> f64 {
+        super::meters::to_chains(to_meters(value))
+    }
+
+    /// Converts kilometers to links.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_links(to_meters(value))
+    }
+
+    /// Converts kilometers to rods.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_rods(value: f64) -> f64 {
+        super::meters::to_rods(to_meters(value))
+    }
+
+    /// Converts kilometers to furlongs.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        super::meters::to_furlongs(to_meters(value))
+    }
+
+    /// Converts kilometers to leagues.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
+    }
+
+    /// Converts kilometers to hands.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
+    }
+
+    /// Converts kilometers to mils.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
+    }
+
+    /// Converts kilometers to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts kilometers to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in kilometers to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The kilometer as a typed unit of length.
+    pub const UNIT: Unit<Length> =
+        Unit::new("km", "kilometer", to_meters, super::meters::to_kilometers);
+
+    /// Creates a typed length quantity expressed in kilometers.
+    /// # Arguments
+    /// * `value` - The length in kilometers
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in kilometers.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Centimeters conversion functions
+pub mod centimeters {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts centimeters to meters.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_meters(value: f64) -> f64 {
+        value / 100.0
+    }
+
+    /// Converts centimeters to feet.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_feet(value: f64) -> f64 {
+        super::meters::to_feet(to_meters(value))
+    }
+
+    /// Converts centimeters to inches.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_inches(value: f64) -> f64 {
+        value / 2.54
+    }
+
+    /// Converts centimeters to yards.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_yards(value: f64) -> f64 {
+        super::meters::to_yards(to_meters(value))
+    }
+
+    /// Converts centimeters to miles.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_miles(value: f64) -> f64 {
+        super::meters::to_miles(to_meters(value))
+    }
+
+    /// Converts centimeters to kilometers.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        value / 100000.0
+    }
+
+    /// Converts centimeters to millimeters.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        value * 10.0
+    }
+
+    /// Converts centimeters to micrometers.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts centimeters to nanometers.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts centimeters to picometers.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts centimeters to ångströms.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
+    }
+
+    /// Converts centimeters to femtometers.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts centimeters to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts centimeters to light-years.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
+    }
+
+    /// Converts centimeters to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
+    }
+
+    /// Converts centimeters to parsecs.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
+    }
+
+    /// Converts centimeters to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
+    }
+
+    /// Converts centimeters to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
+    }
+
+    /// Converts centimeters to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts centimeters to solar radii.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts centimeters to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
+    }
+
+    /// Converts centimeters to cables.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
+    }
+
+    /// Converts centimeters to fathoms.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
+    }
+
+    /// Converts centimeters to chains.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_chains(value: f64) -> f64 {
+        super::meters::to_chains(to_meters(value))
+    }
+
+    /// Converts centimeters to links.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_links(to_meters(value))
+    }
+
+    /// Converts centimeters to rods.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_rods(value: f64) -> f64 {
+        super::meters::to_rods(to_meters(value))
+    }
+
+    /// Converts centimeters to furlongs.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        super::meters::to_furlongs(to_meters(value))
+    }
+
+    /// Converts centimeters to leagues.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
+    }
+
+    /// Converts centimeters to hands.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
+    }
+
+    /// Converts centimeters to mils.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
+    }
+
+    /// Converts centimeters to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts centimeters to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in centimeters to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The centimeter as a typed unit of length.
+    pub const UNIT: Unit<Length> =
+        Unit::new("cm", "centimeter", to_meters, super::meters::to_centimeters);
+
+    /// Creates a typed length quantity expressed in centimeters.
+    /// # Arguments
+    /// * `value` - The length in centimeters
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in centimeters.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Millimeters conversion functions
+pub mod millimeters {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts millimeters to meters.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_meters(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// Converts millimeters to feet.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_feet(value: f64) -> f64 {
+        super::meters::to_feet(to_meters(value))
+    }
+
+    /// Converts millimeters to inches.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_inches(value: f64) -> f64 {
+        value / 25.4
+    }
+
+    /// Converts millimeters to yards.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_yards(value: f64) -> f64 {
+        super::meters::to_yards(to_meters(value))
+    }
+
+    /// Converts millimeters to miles.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_miles(value: f64) -> f64 {
+        super::meters::to_miles(to_meters(value))
+    }
+
+    /// Converts millimeters to kilometers.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        value / 1000000.0
+    }
+
+    /// Converts millimeters to centimeters.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        value / 10.0
+    }
+
+    /// Converts millimeters to micrometers.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts millimeters to nanometers.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts millimeters to picometers.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts millimeters to ångströms.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
+    }
+
+    /// Converts millimeters to femtometers.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts millimeters to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts millimeters to light-years.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
+    }
+
+    /// Converts millimeters to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
+    }
+
+    /// Converts millimeters to parsecs.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
+    }
+
+    /// Converts millimeters to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
+    }
+
+    /// Converts millimeters to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
+    }
+
+    /// Converts millimeters to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts millimeters to solar radii.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts millimeters to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
+    }
+
+    /// Converts millimeters to cables.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
+    }
+
+    /// Converts millimeters to fathoms.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
+    }
+
+    /// Converts millimeters to chains.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_chains(value: f64) -> f64 {
+        super::meters::to_chains(to_meters(value))
+    }
+
+    /// Converts millimeters to links.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_links(to_meters(value))
+    }
+
+    /// Converts millimeters to rods.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_rods(value: f64) -> f64 {
+        super::meters::to_rods(to_meters(value))
+    }
+
+    /// Converts millimeters to furlongs.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        super::meters::to_furlongs(to_meters(value))
+    }
+
+    /// Converts millimeters to leagues.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
+    }
+
+    /// Converts millimeters to hands.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
+    }
+
+    /// Converts millimeters to mils.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
+    }
+
+    /// Converts millimeters to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts millimeters to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in millimeters to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The millimeter as a typed unit of length.
+    pub const UNIT: Unit<Length> =
+        Unit::new("mm", "millimeter", to_meters, super::meters::to_millimeters);
+
+    /// Creates a typed length quantity expressed in millimeters.
+    /// # Arguments
+    /// * `value` - The length in millimeters
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in millimeters.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Yards conversion functions
+pub mod yards {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts yards to meters.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_meters(value: f64) -> f64 {
+        value * super::METERS_PER_YARD
+    }
+
+    /// Converts yards to feet.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_feet(value: f64) -> f64 {
+        value * 3.0
+    }
+
+    /// Converts yards to inches.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_inches(value: f64) -> f64 {
+        value * 36.0
+    }
+
+    /// Converts yards to miles.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_miles(value: f64) -> f64 {
+        value / 1760.0
+    }
+
+    /// Converts yards to kilometers.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
+    }
+
+    /// Converts yards to centimeters.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        super::meters::to_centimeters(to_meters(value))
+    }
+
+    /// Converts yards to millimeters.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        super::meters::to_millimeters(to_meters(value))
+    }
+
+    /// Converts yards to micrometers.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts yards to nanometers.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts yards to picometers.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts yards to ångströms.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
+    }
+
+    /// Converts yards to femtometers.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts yards to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts yards to light-years.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
+    }
+
+    /// Converts yards to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
+    }
+
+    /// Converts yards to parsecs.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
+    }
+
+    /// Converts yards to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
+    }
+
+    /// Converts yards to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
+    }
+
+    /// Converts yards to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts yards to solar radii.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts yards to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
+    }
+
+    /// Converts yards to cables.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
+    }
+
+    /// Converts yards to fathoms.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        value / 2.0
+    }
+
+    /// Converts yards to chains.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_chains(value: f64) -> f64 {
+        value / 22.0
+    }
+
+    /// Converts yards to links.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_links(to_meters(value))
+    }
+
+    /// Converts yards to rods.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_rods(value: f64) -> f64 {
+        value / 5.5
+    }
+
+    /// Converts yards to furlongs.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        value / 220.0
+    }
+
+    /// Converts yards to leagues.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
+    }
+
+    /// Converts yards to hands.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
+    }
+
+    /// Converts yards to mils.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
+    }
+
+    /// Converts yards to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts yards to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in yards to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The yard as a typed unit of length.
+    pub const UNIT: Unit<Length> = Unit::new("yd", "yard", to_meters, super::meters::to_yards);
+
+    /// Creates a typed length quantity expressed in yards.
+    /// # Arguments
+    /// * `value` - The length in yards
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in yards.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Miles conversion functions
+pub mod miles {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts miles to meters.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_meters(value: f64) -> f64 {
+        value * super::METERS_PER_MILE
+    }
+
+    /// Converts miles to feet.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_feet(value: f64) -> f64 {
+        value * 5280.0
+    }
+
+    /// Converts miles to inches.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_inches(value: f64) -> f64 {
+        value * 63360.0
+    }
+
+    /// Converts miles to yards.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_yards(value: f64) -> f64 {
+        value * 1760.0
+    }
+
+    /// Converts miles to kilometers.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
+    }
+
+    /// Converts miles to centimeters.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        super::meters::to_centimeters(to_meters(value))
+    }
+
+    /// Converts miles to millimeters.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        super::meters::to_millimeters(to_meters(value))
+    }
+
+    /// Converts miles to micrometers.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts miles to nanometers.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts miles to picometers.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts miles to ångströms.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
+    }
+
+    /// Converts miles to femtometers.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts miles to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts miles to light-years.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
+    }
+
+    /// Converts miles to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
+    }
+
+    /// Converts miles to parsecs.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
+    }
+
+    /// Converts miles to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
+    }
+
+    /// Converts miles to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
+    }
+
+    /// Converts miles to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts miles to solar radii.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts miles to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
+    }
+
+    /// Converts miles to cables.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
+    }
+
+    /// Converts miles to fathoms.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
+    }
+
+    /// Converts miles to chains.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_chains(value: f64) -> f64 {
+        value * 80.0
+    }
+
+    /// Converts miles to links.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_links(to_meters(value))
+    }
+
+    /// Converts miles to rods.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_rods(value: f64) -> f64 {
+        super::meters::to_rods(to_meters(value))
+    }
+
+    /// Converts miles to furlongs.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        value * 8.0
+    }
+
+    /// Converts miles to leagues.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        value / 3.0
+    }
+
+    /// Converts miles to hands.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
+    }
+
+    /// Converts miles to mils.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
+    }
+
+    /// Converts miles to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts miles to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in miles to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The mile as a typed unit of length.
+    pub const UNIT: Unit<Length> = Unit::new("mi", "mile", to_meters, super::meters::to_miles);
+
+    /// Creates a typed length quantity expressed in miles.
+    /// # Arguments
+    /// * `value` - The length in miles
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in miles.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Micrometer conversion functions
+pub mod micrometers {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts micrometers to meters.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_meters(value: f64) -> f64 {
+        value / 1e6
+    }
+
+    /// Converts micrometers to feet.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_feet(value: f64) -> f64 {
+        super::meters::to_feet(to_meters(value))
+    }
+
+    /// Converts micrometers to inches.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_inches(value: f64) -> f64 {
+        super::meters::to_inches(to_meters(value))
+    }
+
+    /// Converts micrometers to kilometers.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
+    }
+
+    /// Converts micrometers to centimeters.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        super::meters::to_centimeters(to_meters(value))
+    }
+
+    /// Converts micrometers to millimeters.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        super::meters::to_millimeters(to_meters(value))
+    }
+
+    /// Converts micrometers to yards.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_yards(value: f64) -> f64 {
+        super::meters::to_yards(to_meters(value))
+    }
+
+    /// Converts micrometers to miles.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_miles(value: f64) -> f64 {
+        super::meters::to_miles(to_meters(value))
+    }
+
+    /// Converts micrometers to nanometers.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts micrometers to picometers.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts micrometers to ångströms.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
+    }
+
+    /// Converts micrometers to femtometers.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts micrometers to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts micrometers to light-years.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
+    }
+
+    /// Converts micrometers to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
+    }
+
+    /// Converts micrometers to parsecs.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
+    }
+
+    /// Converts micrometers to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
+    }
+
+    /// Converts micrometers to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
+    }
+
+    /// Converts micrometers to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts micrometers to solar radii.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts micrometers to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
+    }
+
+    /// Converts micrometers to cables.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
+    }
+
+    /// Converts micrometers to fathoms.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
+    }
+
+    /// Converts micrometers to chains.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_chains(value: f64) -> f64 {
+        super::meters::to_chains(to_meters(value))
+    }
+
+    /// Converts micrometers to links.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_links(to_meters(value))
+    }
+
+    /// Converts micrometers to rods.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_rods(value: f64) -> f64 {
+        super::meters::to_rods(to_meters(value))
+    }
+
+    /// Converts micrometers to furlongs.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        super::meters::to_furlongs(to_meters(value))
+    }
+
+    /// Converts micrometers to leagues.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
+    }
+
+    /// Converts micrometers to hands.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
+    }
+
+    /// Converts micrometers to mils.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
+    }
+
+    /// Converts micrometers to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts micrometers to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in micrometers to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The micrometer as a typed unit of length.
+    pub const UNIT: Unit<Length> =
+        Unit::new("μm", "micrometer", to_meters, super::meters::to_micrometers);
+
+    /// Creates a typed length quantity expressed in micrometers.
+    /// # Arguments
+    /// * `value` - The length in micrometers
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in micrometers.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Nanometer conversion functions
+pub mod nanometers {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts nanometers to meters.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_meters(value: f64) -> f64 {
+        value / 1e9
+    }
+
+    /// Converts nanometers to feet.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_feet(value: f64) -> f64 {
+        super::meters::to_feet(to_meters(value))
+    }
+
+    /// Converts nanometers to inches.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_inches(value: f64) -> f64 {
+        super::meters::to_inches(to_meters(value))
+    }
+
+    /// Converts nanometers to kilometers.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
+    }
+
+    /// Converts nanometers to centimeters.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        super::meters::to_centimeters(to_meters(value))
+    }
+
+    /// Converts nanometers to millimeters.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        super::meters::to_millimeters(to_meters(value))
+    }
+
+    /// Converts nanometers to yards.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_yards(value: f64) -> f64 {
+        super::meters::to_yards(to_meters(value))
+    }
+
+    /// Converts nanometers to miles.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_miles(value: f64) -> f64 {
+        super::meters::to_miles(to_meters(value))
+    }
+
+    /// Converts nanometers to micrometers.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts nanometers to picometers.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts nanometers to ångströms.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        value * 10.0
+    }
+
+    /// Converts nanometers to femtometers.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts nanometers to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts nanometers to light-years.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
+    }
+
+    /// Converts nanometers to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
+    }
+
+    /// Converts nanometers to parsecs.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
+    }
+
+    /// Converts nanometers to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
+    }
+
+    /// Converts nanometers to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
+    }
+
+    /// Converts nanometers to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts nanometers to solar radii.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts nanometers to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
+    }
+
+    /// Converts nanometers to cables.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
+    }
+
+    /// Converts nanometers to fathoms.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
+    }
+
+    /// Converts nanometers to chains.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_chains(value: f64) -> f64 {
+        super::meters::to_chains(to_meters(value))
+    }
+
+    /// Converts nanometers to links.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_links(to_meters(value))
+    }
+
+    /// Converts nanometers to rods.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_rods(value: f64) -> f64 {
+        super::meters::to_rods(to_meters(value))
+    }
+
+    /// Converts nanometers to furlongs.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        super::meters::to_furlongs(to_meters(value))
+    }
+
+    /// Converts nanometers to leagues.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
+    }
+
+    /// Converts nanometers to hands.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
+    }
+
+    /// Converts nanometers to mils.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
+    }
+
+    /// Converts nanometers to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts nanometers to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in nanometers to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The nanometer as a typed unit of length.
+    pub const UNIT: Unit<Length> =
+        Unit::new("nm", "nanometer", to_meters, super::meters::to_nanometers);
+
+    /// Creates a typed length quantity expressed in nanometers.
+    /// # Arguments
+    /// * `value` - The length in nanometers
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in nanometers.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Picometer conversion functions
+pub mod picometers {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts picometers to meters.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_meters(value: f64) -> f64 {
+        value / 1e12
+    }
+
+    /// Converts picometers to feet.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_feet(value: f64) -> f64 {
+        super::meters::to_feet(to_meters(value))
+    }
+
+    /// Converts picometers to inches.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_inches(value: f64) -> f64 {
+        super::meters::to_inches(to_meters(value))
+    }
+
+    /// Converts picometers to kilometers.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
+    }
+
+    /// Converts picometers to centimeters.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        super::meters::to_centimeters(to_meters(value))
+    }
+
+    /// Converts picometers to millimeters.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        super::meters::to_millimeters(to_meters(value))
+    }
+
+    /// Converts picometers to yards.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_yards(value: f64) -> f64 {
+        super::meters::to_yards(to_meters(value))
+    }
+
+    /// Converts picometers to miles.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_miles(value: f64) -> f64 {
+        super::meters::to_miles(to_meters(value))
+    }
+
+    /// Converts picometers to micrometers.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts picometers to nanometers.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts picometers to ångströms.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        value / 100.0
+    }
+
+    /// Converts picometers to femtometers.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts picometers to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts picometers to light-years.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
+    }
+
+    /// Converts picometers to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
+    }
+
+    /// Converts picometers to parsecs.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
+    }
+
+    /// Converts picometers to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
+    }
+
+    /// Converts picometers to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
+    }
+
+    /// Converts picometers to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts picometers to solar radii.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts picometers to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
+    }
+
+    /// Converts picometers to cables.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
+    }
+
+    /// Converts picometers to fathoms.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
+    }
+
+    /// Converts picometers to chains.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_chains(value: f64) -> f64 {
+        super::meters::to_chains(to_meters(value))
+    }
+
+    /// Converts picometers to links.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_links(to_meters(value))
+    }
+
+    /// Converts picometers to rods.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_rods(value: f64) -> f64 {
+        super::meters::to_rods(to_meters(value))
+    }
+
+    /// Converts picometers to furlongs.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        super::meters::to_furlongs(to_meters(value))
+    }
+
+    /// Converts picometers to leagues.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
+    }
+
+    /// Converts picometers to hands.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
+    }
+
+    /// Converts picometers to mils.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
+    }
+
+    /// Converts picometers to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts picometers to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in picometers to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The picometer as a typed unit of length.
+    pub const UNIT: Unit<Length> =
+        Unit::new("pm", "picometer", to_meters, super::meters::to_picometers);
+
+    /// Creates a typed length quantity expressed in picometers.
+    /// # Arguments
+    /// * `value` - The length in picometers
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in picometers.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Ångström conversion functions
+pub mod angstroms {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts ångströms to meters.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_meters(value: f64) -> f64 {
+        value / 1e10
+    }
+
+    /// Converts ångströms to feet.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_feet(value: f64) -> f64 {
+        super::meters::to_feet(to_meters(value))
+    }
+
+    /// Converts ångströms to inches.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_inches(value: f64) -> f64 {
+        super::meters::to_inches(to_meters(value))
+    }
+
+    /// Converts ångströms to kilometers.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
+    }
+
+    /// Converts ångströms to centimeters.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        super::meters::to_centimeters(to_meters(value))
+    }
+
+    /// Converts ångströms to millimeters.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        super::meters::to_millimeters(to_meters(value))
+    }
+
+    /// Converts ångströms to yards.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_yards(value: f64) -> f64 {
+        super::meters::to_yards(to_meters(value))
+    }
+
+    /// Converts ångströms to miles.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_miles(value: f64) -> f64 {
+        super::meters::to_miles(to_meters(value))
+    }
+
+    /// Converts ångströms to micrometers.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts ångströms to nanometers.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        value / 10.0
+    }
+
+    /// Converts ångströms to picometers.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        value * 100.0
+    }
+
+    /// Converts ångströms to femtometers.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts ångströms to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts ångströms to light-years.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
+    }
+
+    /// Converts ångströms to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
+    }
+
+    /// Converts ångströms to parsecs.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
+    }
+
+    /// Converts ångströms to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
+    }
+
+    /// Converts ångströms to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
+    }
+
+    /// Converts ångströms to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts ångströms to solar radii.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts ångströms to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
+    }
+
+    /// Converts ångströms to cables.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
+    }
+
+    /// Converts ångströms to fathoms.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
+    }
+
+    /// Converts ångströms to chains.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_chains(value: f64) -> f64 {
+        super::meters::to_chains(to_meters(value))
+    }
+
+    /// Converts ångströms to links.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_links(to_meters(value))
+    }
+
+    /// Converts ångströms to rods.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_rods(value: f64) -> f64 {
+        super::meters::to_rods(to_meters(value))
+    }
+
+    /// Converts ångströms to furlongs.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        super::meters::to_furlongs(to_meters(value))
+    }
+
+    /// Converts ångströms to leagues.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
+    }
+
+    /// Converts ångströms to hands.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
+    }
+
+    /// Converts ångströms to mils.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
+    }
+
+    /// Converts ångströms to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts ångströms to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in ångströms to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The ångström as a typed unit of length.
+    pub const UNIT: Unit<Length> =
+        Unit::new("Å", "ångström", to_meters, super::meters::to_angstroms);
+
+    /// Creates a typed length quantity expressed in ångströms.
+    /// # Arguments
+    /// * `value` - The length in ångströms
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in ångströms.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Femtometer conversion functions
+pub mod femtometers {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts femtometers to meters.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_meters(value: f64) -> f64 {
+        value / 1e15
+    }
+
+    /// Converts femtometers to feet.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_feet(value: f64) -> f64 {
+        super::meters::to_feet(to_meters(value))
+    }
+
+    /// Converts femtometers to inches.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_inches(value: f64) -> f64 {
+        super::meters::to_inches(to_meters(value))
+    }
+
+    /// Converts femtometers to kilometers.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
+    }
+
+    /// Converts femtometers to centimeters.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        super::meters::to_centimeters(to_meters(value))
+    }
+
+    /// Converts femtometers to millimeters.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        super::meters::to_millimeters(to_meters(value))
+    }
+
+    /// Converts femtometers to yards.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_yards(value: f64) -> f64 {
+        super::meters::to_yards(to_meters(value))
+    }
+
+    /// Converts femtometers to miles.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_miles(value: f64) -> f64 {
+        super::meters::to_miles(to_meters(value))
+    }
+
+    /// Converts femtometers to micrometers.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts femtometers to nanometers.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts femtometers to picometers.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts femtometers to ångströms.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
+    }
+
+    /// Converts femtometers to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts femtometers to light-years.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
+    }
+
+    /// Converts femtometers to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
+    }
+
+    /// Converts femtometers to parsecs.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
+    }
+
+    /// Converts femtometers to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
+    }
+
+    /// Converts femtometers to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
+    }
+
+    /// Converts femtometers to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts femtometers to solar radii.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts femtometers to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
+    }
+
+    /// Converts femtometers to cables.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
+    }
+
+    /// Converts femtometers to fathoms.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
+    }
+
+    /// Converts femtometers to chains.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_chains(value: f64) -> f64 {
+        super::meters::to_chains(to_meters(value))
+    }
+
+    /// Converts femtometers to links.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_links(to_meters(value))
+    }
+
+    /// Converts femtometers to rods.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_rods(value: f64) -> f64 {
+        super::meters::to_rods(to_meters(value))
+    }
+
+    /// Converts femtometers to furlongs.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        super::meters::to_furlongs(to_meters(value))
+    }
+
+    /// Converts femtometers to leagues.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
+    }
+
+    /// Converts femtometers to hands.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
+    }
+
+    /// Converts femtometers to mils.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
+    }
+
+    /// Converts femtometers to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts femtometers to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in femtometers to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The femtometer as a typed unit of length.
+    pub const UNIT: Unit<Length> =
+        Unit::new("fm", "femtometer", to_meters, super::meters::to_femtometers);
+
+    /// Creates a typed length quantity expressed in femtometers.
+    /// # Arguments
+    /// * `value` - The length in femtometers
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in femtometers.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Astronomical unit conversion functions
+pub mod astronomical_units {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts astronomical units to meters.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_meters(value: f64) -> f64 {
+        value * super::METERS_PER_ASTRONOMICAL_UNIT
+    }
+
+    /// Converts astronomical units to feet.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_feet(value: f64) -> f64 {
+        super::meters::to_feet(to_meters(value))
+    }
+
+    /// Converts astronomical units to inches.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_inches(value: f64) -> f64 {
+        super::meters::to_inches(to_meters(value))
+    }
+
+    /// Converts astronomical units to kilometers.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
+    }
+
+    /// Converts astronomical units to centimeters.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        super::meters::to_centimeters(to_meters(value))
+    }
+
+    /// Converts astronomical units to millimeters.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        super::meters::to_millimeters(to_meters(value))
+    }
+
+    /// Converts astronomical units to yards.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_yards(value: f64) -> f64 {
+        super::meters::to_yards(to_meters(value))
+    }
+
+    /// Converts astronomical units to miles.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_miles(value: f64) -> f64 {
+        super::meters::to_miles(to_meters(value))
+    }
+
+    /// Converts astronomical units to micrometers.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts astronomical units to nanometers.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts astronomical units to picometers.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts astronomical units to ångströms.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
+    }
+
+    /// Converts astronomical units to femtometers.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts astronomical units to light-years.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
+    }
+
+    /// Converts astronomical units to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
+    }
+
+    /// Converts astronomical units to parsecs.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
+    }
+
+    /// Converts astronomical units to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
+    }
+
+    /// Converts astronomical units to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
+    }
+
+    /// Converts astronomical units to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts astronomical units to solar radii.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts astronomical units to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
+    }
+
+    /// Converts astronomical units to cables.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
+    }
+
+    /// Converts astronomical units to fathoms.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
+    }
+
+    /// Converts astronomical units to chains.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_chains(value: f64) -> f64 {
+        super::meters::to_chains(to_meters(value))
+    }
+
+    /// Converts astronomical units to links.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_links(to_meters(value))
+    }
+
+    /// Converts astronomical units to rods.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_rods(value: f64) -> f64 {
+        super::meters::to_rods(to_meters(value))
+    }
+
+    /// Converts astronomical units to furlongs.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        super::meters::to_furlongs(to_meters(value))
+    }
+
+    /// Converts astronomical units to leagues.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
+    }
+
+    /// Converts astronomical units to hands.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
+    }
+
+    /// Converts astronomical units to mils.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
+    }
+
+    /// Converts astronomical units to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts astronomical units to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in astronomical units to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The astronomical unit as a typed unit of length.
+    pub const UNIT: Unit<Length> = Unit::new(
+        "au",
+        "astronomical unit",
+        to_meters,
+        super::meters::to_astronomical_units,
+    );
+
+    /// Creates a typed length quantity expressed in astronomical units.
+    /// # Arguments
+    /// * `value` - The length in astronomical units
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in astronomical units.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Light-year conversion functions
+pub mod light_years {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+    use crate::conversions::time;
+
+    /// Converts light-years to meters.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_meters(value: f64) -> f64 {
+        value * super::METERS_PER_LIGHT_YEAR
+    }
+
+    /// Converts light-years to feet.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_feet(value: f64) -> f64 {
+        super::meters::to_feet(to_meters(value))
+    }
+
+    /// Converts light-years to inches.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_inches(value: f64) -> f64 {
+        super::meters::to_inches(to_meters(value))
+    }
+
+    /// Converts light-years to kilometers.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
+    }
+
+    /// Converts light-years to centimeters.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        super::meters::to_centimeters(to_meters(value))
+    }
+
+    /// Converts light-years to millimeters.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        super::meters::to_millimeters(to_meters(value))
+    }
+
+    /// Converts light-years to yards.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_yards(value: f64) -> f64 {
+        super::meters::to_yards(to_meters(value))
+    }
+
+    /// Converts light-years to miles.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_miles(value: f64) -> f64 {
+        super::meters::to_miles(to_meters(value))
+    }
+
+    /// Converts light-years to micrometers.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts light-years to nanometers.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts light-years to picometers.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts light-years to ångströms.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
+    }
+
+    /// Converts light-years to femtometers.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts light-years to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts light-years to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        time::years::to_seconds(value)
+    }
+
+    /// Converts light-years to parsecs.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
+    }
+
+    /// Converts light-years to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
+    }
+
+    /// Converts light-years to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
+    }
+
+    /// Converts light-years to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts light-years to solar radii.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts light-years to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
+    }
+
+    /// Converts light-years to cables.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
+    }
+
+    /// Converts light-years to fathoms.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
+    }
+
+    /// Converts light-years to chains.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_chains(value: f64) -> f64 {
+        super::meters::to_chains(to_meters(value))
+    }
+
+    /// Converts light-years to links.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_links(to_meters(value))
+    }
+
+    /// Converts light-years to rods.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_rods(value: f64) -> f64 {
+        super::meters::to_rods(to_meters(value))
+    }
+
+    /// Converts light-years to furlongs.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        super::meters::to_furlongs(to_meters(value))
+    }
+
+    /// Converts light-years to leagues.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
+    }
+
+    /// Converts light-years to hands.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
+    }
+
+    /// Converts light-years to mils.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
+    }
+
+    /// Converts light-years to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts light-years to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in light-years to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The light-year as a typed unit of length.
+    pub const UNIT: Unit<Length> =
+        Unit::new("ly", "light-year", to_meters, super::meters::to_light_years);
+
+    /// Creates a typed length quantity expressed in light-years.
+    /// # Arguments
+    /// * `value` - The length in light-years
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in light-years.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Light-second conversion functions
+pub mod light_seconds {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+    use crate::conversions::time;
+
+    /// Converts light-seconds to meters.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_meters(value: f64) -> f64 {
+        value * super::METERS_PER_LIGHT_SECOND
+    }
+
+    /// Converts light-seconds to feet.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_feet(value: f64) -> f64 {
+        super::meters::to_feet(to_meters(value))
+    }
+
+    /// Converts light-seconds to inches.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_inches(value: f64) -> f64 {
+        super::meters::to_inches(to_meters(value))
+    }
+
+    /// Converts light-seconds to kilometers.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
+    }
+
+    /// Converts light-seconds to centimeters.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        super::meters::to_centimeters(to_meters(value))
+    }
+
+    /// Converts light-seconds to millimeters.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        super::meters::to_millimeters(to_meters(value))
+    }
+
+    /// Converts light-seconds to yards.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_yards(value: f64) -> f64 {
+        super::meters::to_yards(to_meters(value))
+    }
+
+    /// Converts light-seconds to miles.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_miles(value: f64) -> f64 {
+        super::meters::to_miles(to_meters(value))
+    }
+
+    /// Converts light-seconds to micrometers.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts light-seconds to nanometers.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts light-seconds to picometers.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts light-seconds to ångströms.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
+    }
+
+    /// Converts light-seconds to femtometers.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts light-seconds to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts light-seconds to light-years.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        time::seconds::to_years(value)
+    }
+
+    /// Converts light-seconds to parsecs.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
+    }
+
+    /// Converts light-seconds to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
+    }
+
+    /// Converts light-seconds to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
+    }
+
+    /// Converts light-seconds to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts light-seconds to solar radii.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts light-seconds to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
+    }
+
+    /// Converts light-seconds to cables.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
+    }
+
+    /// Converts light-seconds to fathoms.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
+    }
+
+    /// Converts light-seconds to chains.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_chains(value: f64) -> f64 {
+        super::meters::to_chains(to_meters(value))
+    }
+
+    /// Converts light-seconds to links.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_links(to_meters(value))
+    }
+
+    /// Converts light-seconds to rods.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_rods(value: f64) -> f64 {
+        super::meters::to_rods(to_meters(value))
+    }
+
+    /// Converts light-seconds to furlongs.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        super::meters::to_furlongs(to_meters(value))
+    }
+
+    /// Converts light-seconds to leagues.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
+    }
+
+    /// Converts light-seconds to hands.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
+    }
+
+    /// Converts light-seconds to mils.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
+    }
+
+    /// Converts light-seconds to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts light-seconds to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in light-seconds to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The light-second as a typed unit of length.
+    pub const UNIT: Unit<Length> = Unit::new(
+        "ls",
+        "light-second",
+        to_meters,
+        super::meters::to_light_seconds,
+    );
+
+    /// Creates a typed length quantity expressed in light-seconds.
+    /// # Arguments
+    /// * `value` - The length in light-seconds
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in light-seconds.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Parsec conversion functions
+pub mod parsecs {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts parsecs to meters.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_meters(value: f64) -> f64 {
+        value * super::METERS_PER_PARSEC
+    }
+
+    /// Converts parsecs to feet.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_feet(value: f64) -> f64 {
+        super::meters::to_feet(to_meters(value))
+    }
+
+    /// Converts parsecs to inches.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_inches(value: f64) -> f64 {
+        super::meters::to_inches(to_meters(value))
+    }
+
+    /// Converts parsecs to kilometers.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
+    }
+
+    /// Converts parsecs to centimeters.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        super::meters::to_centimeters(to_meters(value))
+    }
+
+    /// Converts parsecs to millimeters.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        super::meters::to_millimeters(to_meters(value))
+    }
+
+    /// Converts parsecs to yards.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_yards(value: f64) -> f64 {
+        super::meters::to_yards(to_meters(value))
+    }
+
+    /// Converts parsecs to miles.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_miles(value: f64) -> f64 {
+        super::meters::to_miles(to_meters(value))
+    }
+
+    /// Converts parsecs to micrometers.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts parsecs to nanometers.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts parsecs to picometers.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts parsecs to ångströms.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
+    }
+
+    /// Converts parsecs to femtometers.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts parsecs to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts parsecs to light-years.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
+    }
+
+    /// Converts parsecs to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
+    }
+
+    /// Converts parsecs to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// Converts parsecs to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        value / 1e6
+    }
+
+    /// Converts parsecs to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts parsecs to solar radii.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts parsecs to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
+    }
+
+    /// Converts parsecs to cables.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
+    }
+
+    /// Converts parsecs to fathoms.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
+    }
+
+    /// Converts parsecs to chains.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_chains(value: f64) -> f64 {
+        super::meters::to_chains(to_meters(value))
+    }
+
+    /// Converts parsecs to links.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_links(to_meters(value))
+    }
+
+    /// Converts parsecs to rods.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_rods(value: f64) -> f64 {
+        super::meters::to_rods(to_meters(value))
+    }
+
+    /// Converts parsecs to furlongs.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        super::meters::to_furlongs(to_meters(value))
+    }
+
+    /// Converts parsecs to leagues.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
+    }
+
+    /// Converts parsecs to hands.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
+    }
+
+    /// Converts parsecs to mils.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
+    }
+
+    /// Converts parsecs to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts parsecs to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in parsecs to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The parsec as a typed unit of length.
+    pub const UNIT: Unit<Length> = Unit::new("pc", "parsec", to_meters, super::meters::to_parsecs);
+
+    /// Creates a typed length quantity expressed in parsecs.
+    /// # Arguments
+    /// * `value` - The length in parsecs
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in parsecs.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Kiloparsec conversion functions
+pub mod kiloparsecs {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts kiloparsecs to meters.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_meters(value: f64) -> f64 {
+        value * 1000.0 * super::METERS_PER_PARSEC
+    }
+
+    /// Converts kiloparsecs to feet.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_feet(value: f64) -> f64 {
+        super::meters::to_feet(to_meters(value))
+    }
+
+    /// Converts kiloparsecs to inches.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_inches(value: f64) -> f64 {
+        super::meters::to_inches(to_meters(value))
+    }
+
+    /// Converts kiloparsecs to kilometers.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
+    }
+
+    /// Converts kiloparsecs to centimeters.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        super::meters::to_centimeters(to_meters(value))
+    }
+
+    /// Converts kiloparsecs to millimeters.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        super::meters::to_millimeters(to_meters(value))
+    }
+
+    /// Converts kiloparsecs to yards.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_yards(value: f64) -> f64 {
+        super::meters::to_yards(to_meters(value))
+    }
+
+    /// Converts kiloparsecs to miles.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_miles(value: f64) -> f64 {
+        super::meters::to_miles(to_meters(value))
+    }
+
+    /// Converts kiloparsecs to micrometers.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts kiloparsecs to nanometers.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts kiloparsecs to picometers.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts kiloparsecs to ångströms.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
+    }
+
+    /// Converts kiloparsecs to femtometers.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts kiloparsecs to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts kiloparsecs to light-years.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
+    }
+
+    /// Converts kiloparsecs to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
+    }
+
+    /// Converts kiloparsecs to parsecs.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// Converts kiloparsecs to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// Converts kiloparsecs to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts kiloparsecs to solar radii.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts kiloparsecs to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
+    }
+
+    /// Converts kiloparsecs to cables.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
+    }
+
+    /// Converts kiloparsecs to fathoms.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
+    }
+
+    /// Converts kiloparsecs to chains.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_chains(value: f64) -> f64 {
+        super::meters::to_chains(to_meters(value))
+    }
+
+    /// Converts kiloparsecs to links.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_links(to_meters(value))
+    }
+
+    /// Converts kiloparsecs to rods.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_rods(value: f64) -> f64 {
+        super::meters::to_rods(to_meters(value))
+    }
+
+    /// Converts kiloparsecs to furlongs.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        super::meters::to_furlongs(to_meters(value))
+    }
+
+    /// Converts kiloparsecs to leagues.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
+    }
+
+    /// Converts kiloparsecs to hands.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
+    }
+
+    /// Converts kiloparsecs to mils.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
+    }
+
+    /// Converts kiloparsecs to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts kiloparsecs to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The kiloparsec as a typed unit of length.
+    pub const UNIT: Unit<Length> = Unit::new(
+        "kpc",
+        "kiloparsec",
+        to_meters,
+        super::meters::to_kiloparsecs,
+    );
+
+    /// Creates a typed length quantity expressed in kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in kiloparsecs
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in kiloparsecs.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Megaparsec conversion functions
+pub mod megaparsecs {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts megaparsecs to meters.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_meters(value: f64) -> f64 {
+        value * 1e6 * super::METERS_PER_PARSEC
+    }
+
+    /// Converts megaparsecs to feet.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_feet(value: f64) -> f64 {
+        super::meters::to_feet(to_meters(value))
+    }
+
+    /// Converts megaparsecs to inches.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_inches(value: f64) -> f64 {
+        super::meters::to_inches(to_meters(value))
+    }
+
+    /// Converts megaparsecs to kilometers.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
+    }
+
+    /// Converts megaparsecs to centimeters.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        super::meters::to_centimeters(to_meters(value))
+    }
+
+    /// Converts megaparsecs to millimeters.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        super::meters::to_millimeters(to_meters(value))
+    }
+
+    /// Converts megaparsecs to yards.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_yards(value: f64) -> f64 {
+        super::meters::to_yards(to_meters(value))
+    }
+
+    /// Converts megaparsecs to miles.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_miles(value: f64) -> f64 {
+        super::meters::to_miles(to_meters(value))
+    }
+
+    /// Converts megaparsecs to micrometers.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts megaparsecs to nanometers.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts megaparsecs to picometers.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts megaparsecs to ångströms.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
+    }
+
+    /// Converts megaparsecs to femtometers.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts megaparsecs to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts megaparsecs to light-years.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
+    }
+
+    /// Converts megaparsecs to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
+    }
+
+    /// Converts megaparsecs to parsecs.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        value * 1e6
+    }
+
+    /// Converts megaparsecs to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// Converts megaparsecs to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts megaparsecs to solar radii.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts megaparsecs to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
+    }
+
+    /// Converts megaparsecs to cables.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
+    }
+
+    /// Converts megaparsecs to fathoms.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
+    }
+
+    /// Converts megaparsecs to chains.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_chains(value: f64) -> f64 {
+        super::meters::to_chains(to_meters(value))
+    }
+
+    /// Converts megaparsecs to links.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_links(to_meters(value))
+    }
+
+    /// Converts megaparsecs to rods.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_rods(value: f64) -> f64 {
+        super::meters::to_rods(to_meters(value))
+    }
+
+    /// Converts megaparsecs to furlongs.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        super::meters::to_furlongs(to_meters(value))
+    }
+
+    /// Converts megaparsecs to leagues.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
+    }
+
+    /// Converts megaparsecs to hands.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
+    }
+
+    /// Converts megaparsecs to mils.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
+    }
+
+    /// Converts megaparsecs to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts megaparsecs to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The megaparsec as a typed unit of length.
+    pub const UNIT: Unit<Length> = Unit::new(
+        "Mpc",
+        "megaparsec",
+        to_meters,
+        super::meters::to_megaparsecs,
+    );
+
+    /// Creates a typed length quantity expressed in megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in megaparsecs
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in megaparsecs.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Earth radius conversion functions
+pub mod earth_radii {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts Earth radii to meters.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_meters(value: f64) -> f64 {
+        value * super::METERS_PER_EARTH_RADIUS
+    }
+
+    /// Converts Earth radii to feet.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_feet(value: f64) -> f64 {
+        super::meters::to_feet(to_meters(value))
+    }
+
+    /// Converts Earth radii to inches.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_inches(value: f64) -> f64 {
+        super::meters::to_inches(to_meters(value))
+    }
+
+    /// Converts Earth radii to kilometers.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
+    }
+
+    /// Converts Earth radii to centimeters.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        super::meters::to_centimeters(to_meters(value))
+    }
+
+    /// Converts Earth radii to millimeters.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        super::meters::to_millimeters(to_meters(value))
+    }
+
+    /// Converts Earth radii to yards.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_yards(value: f64) -> f64 {
+        super::meters::to_yards(to_meters(value))
+    }
+
+    /// Converts Earth radii to miles.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_miles(value: f64) -> f64 {
+        super::meters::to_miles(to_meters(value))
+    }
+
+    /// Converts Earth radii to micrometers.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts Earth radii to nanometers.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts Earth radii to picometers.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts Earth radii to ångströms.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
+    }
+
+    /// Converts Earth radii to femtometers.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts Earth radii to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts Earth radii to light-years.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
+    }
+
+    /// Converts Earth radii to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
+    }
+
+    /// Converts Earth radii to parsecs.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
+    }
+
+    /// Converts Earth radii to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
+    }
+
+    /// Converts Earth radii to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
+    }
+
+    /// Converts Earth radii to solar radii.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts Earth radii to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
+    }
+
+    /// Converts Earth radii to cables.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
+    }
+
+    /// Converts Earth radii to fathoms.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
+    }
+
+    /// Converts Earth radii to chains.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_chains(value: f64) -> f64 {
+        super::meters::to_chains(to_meters(value))
+    }
+
+    /// Converts Earth radii to links.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_links(to_meters(value))
+    }
+
+    /// Converts Earth radii to rods.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_rods(value: f64) -> f64 {
+        super::meters::to_rods(to_meters(value))
+    }
+
+    /// Converts Earth radii to furlongs.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        super::meters::to_furlongs(to_meters(value))
+    }
+
+    /// Converts Earth radii to leagues.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
+    }
+
+    /// Converts Earth radii to hands.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
+    }
+
+    /// Converts Earth radii to mils.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
+    }
+
+    /// Converts Earth radii to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts Earth radii to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in Earth radii to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The Earth radius as a typed unit of length.
+    pub const UNIT: Unit<Length> = Unit::new(
+        "R⊕",
+        "Earth radius",
+        to_meters,
+        super::meters::to_earth_radii,
+    );
+
+    /// Creates a typed length quantity expressed in Earth radii.
+    /// # Arguments
+    /// * `value` - The length in Earth radii
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in Earth radii.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Solar radius conversion functions
+pub mod solar_radii {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts solar radii to meters.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_meters(value: f64) -> f64 {
+        value * super::METERS_PER_SOLAR_RADIUS
+    }
+
+    /// Converts solar radii to feet.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_feet(value: f64) -> f64 {
+        super::meters::to_feet(to_meters(value))
+    }
+
+    /// Converts solar radii to inches.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_inches(value: f64) -> f64 {
+        super::meters::to_inches(to_meters(value))
+    }
+
+    /// Converts solar radii to kilometers.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
+    }
+
+    /// Converts solar radii to centimeters.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        super::meters::to_centimeters(to_meters(value))
+    }
+
+    /// Converts solar radii to millimeters.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        super::meters::to_millimeters(to_meters(value))
+    }
+
+    /// Converts solar radii to yards.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_yards(value: f64) -> f64 {
+        super::meters::to_yards(to_meters(value))
+    }
+
+    /// Converts solar radii to miles.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_miles(value: f64) -> f64 {
+        super::meters::to_miles(to_meters(value))
+    }
+
+    /// Converts solar radii to micrometers.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts solar radii to nanometers.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts solar radii to picometers.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts solar radii to ångströms.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
+    }
+
+    /// Converts solar radii to femtometers.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts solar radii to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts solar radii to light-years.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
+    }
+
+    /// Converts solar radii to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
+    }
+
+    /// Converts solar radii to parsecs.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
+    }
+
+    /// Converts solar radii to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
+    }
+
+    /// Converts solar radii to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
+    }
+
+    /// Converts solar radii to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts solar radii to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
+    }
+
+    /// Converts solar radii to cables.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
+    }
+
+    /// Converts solar radii to fathoms.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
+    }
+
+    /// Converts solar radii to chains.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_chains(value: f64) -> f64 {
+        super::meters::to_chains(to_meters(value))
+    }
+
+    /// Converts solar radii to links.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_links(to_meters(value))
+    }
+
+    /// Converts solar radii to rods.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_rods(value: f64) -> f64 {
+        super::meters::to_rods(to_meters(value))
+    }
+
+    /// Converts solar radii to furlongs.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        super::meters::to_furlongs(to_meters(value))
+    }
+
+    /// Converts solar radii to leagues.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
+    }
+
+    /// Converts solar radii to hands.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
+    }
+
+    /// Converts solar radii to mils.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
+    }
+
+    /// Converts solar radii to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts solar radii to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in solar radii to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The solar radius as a typed unit of length.
+    pub const UNIT: Unit<Length> = Unit::new(
+        "R☉",
+        "solar radius",
+        to_meters,
+        super::meters::to_solar_radii,
+    );
+
+    /// Creates a typed length quantity expressed in solar radii.
+    /// # Arguments
+    /// * `value` - The length in solar radii
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in solar radii.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Nautical mile conversion functions
+pub mod nautical_miles {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts nautical miles to meters.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_meters(value: f64) -> f64 {
+        value * super::METERS_PER_NAUTICAL_MILE
+    }
+
+    /// Converts nautical miles to feet.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_feet(value: f64) -> f64 {
+        super::meters::to_feet(to_meters(value))
+    }
+
+    /// Converts nautical miles to inches.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_inches(value: f64) -> f64 {
+        super::meters::to_inches(to_meters(value))
+    }
+
+    /// Converts nautical miles to kilometers.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
+    }
+
+    /// Converts nautical miles to centimeters.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        super::meters::to_centimeters(to_meters(value))
+    }
+
+    /// Converts nautical miles to millimeters.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        super::meters::to_millimeters(to_meters(value))
+    }
+
+    /// Converts nautical miles to yards.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_yards(value: f64) -> f64 {
+        super::meters::to_yards(to_meters(value))
+    }
+
+    /// Converts nautical miles to miles.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_miles(value: f64) -> f64 {
+        super::meters::to_miles(to_meters(value))
+    }
+
+    /// Converts nautical miles to micrometers.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts nautical miles to nanometers.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts nautical miles to picometers.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts nautical miles to ångströms.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
+    }
+
+    /// Converts nautical miles to femtometers.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts nautical miles to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts nautical miles to light-years.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
+    }
+
+    /// Converts nautical miles to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
+    }
+
+    /// Converts nautical miles to parsecs.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
+    }
+
+    /// Converts nautical miles to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
+    }
+
+    /// Converts nautical miles to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
+    }
+
+    /// Converts nautical miles to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts nautical miles to solar radii.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts nautical miles to cables.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_cables(value: f64) -> f64 {
+        value * 10.0
+    }
+
+    /// Converts nautical miles to fathoms.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
+    }
+
+    /// Converts nautical miles to chains.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_chains(value: f64) -> f64 {
+        super::meters::to_chains(to_meters(value))
+    }
+
+    /// Converts nautical miles to links.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_links(to_meters(value))
+    }
+
+    /// Converts nautical miles to rods.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_rods(value: f64) -> f64 {
+        super::meters::to_rods(to_meters(value))
+    }
+
+    /// Converts nautical miles to furlongs.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        super::meters::to_furlongs(to_meters(value))
+    }
+
+    /// Converts nautical miles to leagues.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
+    }
+
+    /// Converts nautical miles to hands.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
+    }
+
+    /// Converts nautical miles to mils.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
+    }
+
+    /// Converts nautical miles to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts nautical miles to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in nautical miles to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The nautical mile as a typed unit of length.
+    pub const UNIT: Unit<Length> = Unit::new(
+        "nmi",
+        "nautical mile",
+        to_meters,
+        super::meters::to_nautical_miles,
+    );
+
+    /// Creates a typed length quantity expressed in nautical miles.
+    /// # Arguments
+    /// * `value` - The length in nautical miles
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in nautical miles.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Cable conversion functions
+pub mod cables {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts cables to meters.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_meters(value: f64) -> f64 {
+        value * super::METERS_PER_NAUTICAL_MILE / 10.0
+    }
+
+    /// Converts cables to feet.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_feet(value: f64) -> f64 {
+        super::meters::to_feet(to_meters(value))
+    }
+
+    /// Converts cables to inches.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_inches(value: f64) -> f64 {
+        super::meters::to_inches(to_meters(value))
+    }
+
+    /// Converts cables to kilometers.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
+    }
+
+    /// Converts cables to centimeters.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        super::meters::to_centimeters(to_meters(value))
+    }
+
+    /// Converts cables to millimeters.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        super::meters::to_millimeters(to_meters(value))
+    }
+
+    /// Converts cables to yards.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_yards(value: f64) -> f64 {
+        super::meters::to_yards(to_meters(value))
+    }
+
+    /// Converts cables to miles.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_miles(value: f64) -> f64 {
+        super::meters::to_miles(to_meters(value))
+    }
+
+    /// Converts cables to micrometers.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts cables to nanometers.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts cables to picometers.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts cables to ångströms.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
+    }
+
+    /// Converts cables to femtometers.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts cables to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts cables to light-years.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
+    }
+
+    /// Converts cables to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
+    }
+
+    /// Converts cables to parsecs.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
+    }
+
+    /// Converts cables to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
+    }
+
+    /// Converts cables to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
+    }
+
+    /// Converts cables to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts cables to solar radii.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts cables to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        value / 10.0
+    }
+
+    /// Converts cables to fathoms.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
+    }
+
+    /// Converts cables to chains.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_chains(value: f64) -> f64 {
+        super::meters::to_chains(to_meters(value))
+    }
+
+    /// Converts cables to links.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_links(to_meters(value))
+    }
+
+    /// Converts cables to rods.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_rods(value: f64) -> f64 {
+        super::meters::to_rods(to_meters(value))
+    }
+
+    /// Converts cables to furlongs.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        super::meters::to_furlongs(to_meters(value))
+    }
+
+    /// Converts cables to leagues.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
+    }
+
+    /// Converts cables to hands.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
+    }
+
+    /// Converts cables to mils.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
+    }
+
+    /// Converts cables to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts cables to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in cables to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The cable as a typed unit of length.
+    pub const UNIT: Unit<Length> = Unit::new("cbl", "cable", to_meters, super::meters::to_cables);
+
+    /// Creates a typed length quantity expressed in cables.
+    /// # Arguments
+    /// * `value` - The length in cables
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in cables.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Fathom conversion functions
+pub mod fathoms {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts fathoms to meters.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_meters(value: f64) -> f64 {
+        super::feet::to_meters(value * 6.0)
+    }
+
+    /// Converts fathoms to feet.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_feet(value: f64) -> f64 {
+        value * 6.0
+    }
+
+    /// Converts fathoms to inches.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_inches(value: f64) -> f64 {
+        super::meters::to_inches(to_meters(value))
+    }
+
+    /// Converts fathoms to kilometers.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
+    }
+
+    /// Converts fathoms to centimeters.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        super::meters::to_centimeters(to_meters(value))
+    }
+
+    /// Converts fathoms to millimeters.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        super::meters::to_millimeters(to_meters(value))
+    }
+
+    /// Converts fathoms to yards.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_yards(value: f64) -> f64 {
+        value * 2.0
+    }
+
+    /// Converts fathoms to miles.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_miles(value: f64) -> f64 {
+        super::meters::to_miles(to_meters(value))
+    }
+
+    /// Converts fathoms to micrometers.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts fathoms to nanometers.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts fathoms to picometers.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts fathoms to ångströms.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
+    }
+
+    /// Converts fathoms to femtometers.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts fathoms to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts fathoms to light-years.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
+    }
+
+    /// Converts fathoms to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
+    }
+
+    /// Converts fathoms to parsecs.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
+    }
+
+    /// Converts fathoms to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
+    }
+
+    /// Converts fathoms to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
+    }
+
+    /// Converts fathoms to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts fathoms to solar radii.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts fathoms to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
+    }
+
+    /// Converts fathoms to cables.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
+    }
+
+    /// Converts fathoms to chains.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_chains(value: f64) -> f64 {
+        super::meters::to_chains(to_meters(value))
+    }
+
+    /// Converts fathoms to links.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_links(to_meters(value))
+    }
+
+    /// Converts fathoms to rods.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_rods(value: f64) -> f64 {
+        super::meters::to_rods(to_meters(value))
+    }
+
+    /// Converts fathoms to furlongs.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        super::meters::to_furlongs(to_meters(value))
+    }
+
+    /// Converts fathoms to leagues.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
+    }
+
+    /// Converts fathoms to hands.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
+    }
+
+    /// Converts fathoms to mils.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
+    }
+
+    /// Converts fathoms to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts fathoms to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in fathoms to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The fathom as a typed unit of length.
+    pub const UNIT: Unit<Length> = Unit::new("ftm", "fathom", to_meters, super::meters::to_fathoms);
+
+    /// Creates a typed length quantity expressed in fathoms.
+    /// # Arguments
+    /// * `value` - The length in fathoms
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in fathoms.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Chain conversion functions
+pub mod chains {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts chains to meters.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_meters(value: f64) -> f64 {
+        super::feet::to_meters(value * 66.0)
+    }
+
+    /// Converts chains to feet.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_feet(value: f64) -> f64 {
+        value * 66.0
+    }
+
+    /// Converts chains to inches.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_inches(value: f64) -> f64 {
+        super::meters::to_inches(to_meters(value))
+    }
+
+    /// Converts chains to kilometers.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
+    }
+
+    /// Converts chains to centimeters.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        super::meters::to_centimeters(to_meters(value))
+    }
+
+    /// Converts chains to millimeters.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        super::meters::to_millimeters(to_meters(value))
+    }
+
+    /// Converts chains to yards.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_yards(value: f64) -> f64 {
+        value * 22.0
+    }
+
+    /// Converts chains to miles.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_miles(value: f64) -> f64 {
+        value / 80.0
+    }
+
+    /// Converts chains to micrometers.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts chains to nanometers.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts chains to picometers.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts chains to ångströms.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
+    }
+
+    /// Converts chains to femtometers.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts chains to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts chains to light-years.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
+    }
+
+    /// Converts chains to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
+    }
+
+    /// Converts chains to parsecs.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
+    }
+
+    /// Converts chains to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
+    }
+
+    /// Converts chains to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
+    }
+
+    /// Converts chains to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts chains to solar radii.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts chains to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
+    }
+
+    /// Converts chains to cables.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
+    }
+
+    /// Converts chains to fathoms.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
+    }
+
+    /// Converts chains to links.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_links(value: f64) -> f64 {
+        value * 100.0
+    }
+
+    /// Converts chains to rods.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_rods(value: f64) -> f64 {
+        value * 4.0
+    }
+
+    /// Converts chains to furlongs.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        value / 10.0
+    }
+
+    /// Converts chains to leagues.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
+    }
+
+    /// Converts chains to hands.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
+    }
+
+    /// Converts chains to mils.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
+    }
+
+    /// Converts chains to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts chains to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in chains to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The chain as a typed unit of length.
+    pub const UNIT: Unit<Length> = Unit::new("ch", "chain", to_meters, super::meters::to_chains);
+
+    /// Creates a typed length quantity expressed in chains.
+    /// # Arguments
+    /// * `value` - The length in chains
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in chains.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Link conversion functions
+pub mod links {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts links to meters.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_meters(value: f64) -> f64 {
+        super::chains::to_meters(value / 100.0)
+    }
+
+    /// Converts links to feet.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_feet(value: f64) -> f64 {
+        super::meters::to_feet(to_meters(value))
+    }
+
+    /// Converts links to inches.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_inches(value: f64) -> f64 {
+        super::meters::to_inches(to_meters(value))
+    }
+
+    /// Converts links to kilometers.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
+    }
+
+    /// Converts links to centimeters.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        super::meters::to_centimeters(to_meters(value))
+    }
+
+    /// Converts links to millimeters.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        super::meters::to_millimeters(to_meters(value))
+    }
+
+    /// Converts links to yards.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_yards(value: f64) -> f64 {
+        super::meters::to_yards(to_meters(value))
+    }
+
+    /// Converts links to miles.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_miles(value: f64) -> f64 {
+        super::meters::to_miles(to_meters(value))
+    }
+
+    /// Converts links to micrometers.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts links to nanometers.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts links to picometers.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts links to ångströms.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
+    }
+
+    /// Converts links to femtometers.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts links to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts links to light-years.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
+    }
+
+    /// Converts links to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
+    }
+
+    /// Converts links to parsecs.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
+    }
+
+    /// Converts links to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
+    }
+
+    /// Converts links to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
+    }
+
+    /// Converts links to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts links to solar radii.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts links to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
+    }
+
+    /// Converts links to cables.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
+    }
+
+    /// Converts links to fathoms.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
+    }
+
+    /// Converts links to chains.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_chains(value: f64) -> f64 {
+        value / 100.0
+    }
+
+    /// Converts links to rods.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_rods(value: f64) -> f64 {
+        value / 25.0
+    }
+
+    /// Converts links to furlongs.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// Converts links to leagues.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
+    }
+
+    /// Converts links to hands.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
+    }
+
+    /// Converts links to mils.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
+    }
+
+    /// Converts links to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts links to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in links to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The link as a typed unit of length.
+    pub const UNIT: Unit<Length> = Unit::new("li", "link", to_meters, super::meters::to_links);
+
+    /// Creates a typed length quantity expressed in links.
+    /// # Arguments
+    /// * `value` - The length in links
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in links.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Rod conversion functions
+pub mod rods {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts rods to meters.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_meters(value: f64) -> f64 {
+        super::feet::to_meters(value * 16.5)
+    }
+
+    /// Converts rods to feet.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_feet(value: f64) -> f64 {
+        value * 16.5
+    }
+
+    /// Converts rods to inches.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_inches(value: f64) -> f64 {
+        super::meters::to_inches(to_meters(value))
+    }
+
+    /// Converts rods to kilometers.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
+    }
+
+    /// Converts rods to centimeters.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        super::meters::to_centimeters(to_meters(value))
+    }
+
+    /// Converts rods to millimeters.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        super::meters::to_millimeters(to_meters(value))
+    }
+
+    /// Converts rods to yards.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_yards(value: f64) -> f64 {
+        value * 5.5
+    }
+
+    /// Converts rods to miles.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_miles(value: f64) -> f64 {
+        super::meters::to_miles(to_meters(value))
+    }
+
+    /// Converts rods to micrometers.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts rods to nanometers.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts rods to picometers.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts rods to ångströms.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
+    }
+
+    /// Converts rods to femtometers.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts rods to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts rods to light-years.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
+    }
+
+    /// Converts rods to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
+    }
+
+    /// Converts rods to parsecs.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
+    }
+
+    /// Converts rods to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
+    }
+
+    /// Converts rods to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
+    }
+
+    /// Converts rods to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts rods to solar radii.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts rods to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
+    }
+
+    /// Converts rods to cables.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
+    }
+
+    /// Converts rods to fathoms.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
+    }
+
+    /// Converts rods to chains.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_chains(value: f64) -> f64 {
+        value / 4.0
+    }
+
+    /// Converts rods to links.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_links(value: f64) -> f64 {
+        value * 25.0
+    }
+
+    /// Converts rods to furlongs.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        value / 40.0
+    }
+
+    /// Converts rods to leagues.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
+    }
+
+    /// Converts rods to hands.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
+    }
+
+    /// Converts rods to mils.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
+    }
+
+    /// Converts rods to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts rods to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in rods to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The rod as a typed unit of length.
+    pub const UNIT: Unit<Length> = Unit::new("rd", "rod", to_meters, super::meters::to_rods);
+
+    /// Creates a typed length quantity expressed in rods.
+    /// # Arguments
+    /// * `value` - The length in rods
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in rods.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Furlong conversion functions
+pub mod furlongs {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts furlongs to meters.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_meters(value: f64) -> f64 {
+        super::feet::to_meters(value * 660.0)
+    }
+
+    /// Converts furlongs to feet.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_feet(value: f64) -> f64 {
+        value * 660.0
+    }
+
+    /// Converts furlongs to inches.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_inches(value: f64) -> f64 {
+        super::meters::to_inches(to_meters(value))
+    }
+
+    /// Converts furlongs to kilometers.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
+    }
+
+    /// Converts furlongs to centimeters.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        super::meters::to_centimeters(to_meters(value))
+    }
+
+    /// Converts furlongs to millimeters.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        super::meters::to_millimeters(to_meters(value))
+    }
+
+    /// Converts furlongs to yards.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_yards(value: f64) -> f64 {
+        value * 220.0
+    }
+
+    /// Converts furlongs to miles.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_miles(value: f64) -> f64 {
+        value / 8.0
+    }
+
+    /// Converts furlongs to micrometers.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts furlongs to nanometers.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts furlongs to picometers.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts furlongs to ångströms.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
+    }
+
+    /// Converts furlongs to femtometers.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts furlongs to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts furlongs to light-years.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
+    }
+
+    /// Converts furlongs to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
+    }
+
+    /// Converts furlongs to parsecs.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
+    }
+
+    /// Converts furlongs to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
+    }
+
+    /// Converts furlongs to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
+    }
+
+    /// Converts furlongs to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts furlongs to solar radii.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts furlongs to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
+    }
+
+    /// Converts furlongs to cables.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
+    }
+
+    /// Converts furlongs to fathoms.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
+    }
+
+    /// Converts furlongs to chains.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_chains(value: f64) -> f64 {
+        value * 10.0
+    }
+
+    /// Converts furlongs to links.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_links(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// Converts furlongs to rods.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_rods(value: f64) -> f64 {
+        value * 40.0
+    }
+
+    /// Converts furlongs to leagues.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        value / 24.0
+    }
+
+    /// Converts furlongs to hands.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
+    }
+
+    /// Converts furlongs to mils.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
+    }
+
+    /// Converts furlongs to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts furlongs to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in furlongs to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The furlong as a typed unit of length.
+    pub const UNIT: Unit<Length> =
+        Unit::new("fur", "furlong", to_meters, super::meters::to_furlongs);
+
+    /// Creates a typed length quantity expressed in furlongs.
+    /// # Arguments
+    /// * `value` - The length in furlongs
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in furlongs.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// League conversion functions
+pub mod leagues {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts leagues to meters.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_meters(value: f64) -> f64 {
+        super::miles::to_meters(value * 3.0)
+    }
+
+    /// Converts leagues to feet.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_feet(value: f64) -> f64 {
+        super::meters::to_feet(to_meters(value))
+    }
+
+    /// Converts leagues to inches.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_inches(value: f64) -> f64 {
+        super::meters::to_inches(to_meters(value))
+    }
+
+    /// Converts leagues to kilometers.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
+    }
+
+    /// Converts leagues to centimeters.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        super::meters::to_centimeters(to_meters(value))
+    }
+
+    /// Converts leagues to millimeters.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        super::meters::to_millimeters(to_meters(value))
+    }
+
+    /// Converts leagues to yards.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_yards(value: f64) -> f64 {
+        super::meters::to_yards(to_meters(value))
+    }
+
+    /// Converts leagues to miles.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_miles(value: f64) -> f64 {
+        value * 3.0
+    }
+
+    /// Converts leagues to micrometers.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts leagues to nanometers.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts leagues to picometers.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts leagues to ångströms.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
+    }
+
+    /// Converts leagues to femtometers.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts leagues to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts leagues to light-years.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
+    }
+
+    /// Converts leagues to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
+    }
+
+    /// Converts leagues to parsecs.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
+    }
+
+    /// Converts leagues to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
+    }
+
+    /// Converts leagues to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
+    }
+
+    /// Converts leagues to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts leagues to solar radii.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts leagues to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
+    }
+
+    /// Converts leagues to cables.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
+    }
+
+    /// Converts leagues to fathoms.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
+    }
+
+    /// Converts leagues to chains.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_chains(value: f64) -> f64 {
+        super::meters::to_chains(to_meters(value))
+    }
+
+    /// Converts leagues to links.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_links(to_meters(value))
+    }
+
+    /// Converts leagues to rods.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_rods(value: f64) -> f64 {
+        super::meters::to_rods(to_meters(value))
+    }
+
+    /// Converts leagues to furlongs.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        value * 24.0
+    }
+
+    /// Converts leagues to hands.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
+    }
+
+    /// Converts leagues to mils.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
+    }
+
+    /// Converts leagues to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts leagues to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in leagues to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The league as a typed unit of length.
+    pub const UNIT: Unit<Length> = Unit::new("lea", "league", to_meters, super::meters::to_leagues);
+
+    /// Creates a typed length quantity expressed in leagues.
+    /// # Arguments
+    /// * `value` - The length in leagues
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in leagues.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Hand conversion functions
+pub mod hands {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts hands to meters.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_meters(value: f64) -> f64 {
+        super::inches::to_meters(value * 4.0)
+    }
+
+    /// Converts hands to feet.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_feet(value: f64) -> f64 {
+        value / 3.0
+    }
+
+    /// Converts hands to inches.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_inches(value: f64) -> f64 {
+        value * 4.0
+    }
+
+    /// Converts hands to kilometers.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
+    }
+
+    /// Converts hands to centimeters.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        super::meters::to_centimeters(to_meters(value))
+    }
+
+    /// Converts hands to millimeters.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        super::meters::to_millimeters(to_meters(value))
+    }
+
+    /// Converts hands to yards.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_yards(value: f64) -> f64 {
+        super::meters::to_yards(to_meters(value))
+    }
+
+    /// Converts hands to miles.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_miles(value: f64) -> f64 {
+        super::meters::to_miles(to_meters(value))
+    }
+
+    /// Converts hands to micrometers.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts hands to nanometers.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts hands to picometers.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts hands to ångströms.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
+    }
+
+    /// Converts hands to femtometers.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts hands to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts hands to light-years.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
+    }
+
+    /// Converts hands to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
+    }
+
+    /// Converts hands to parsecs.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
+    }
+
+    /// Converts hands to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
+    }
+
+    /// Converts hands to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
+    }
+
+    /// Converts hands to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts hands to solar radii.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts hands to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
+    }
+
+    /// Converts hands to cables.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
+    }
+
+    /// Converts hands to fathoms.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
+    }
+
+    /// Converts hands to chains.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_chains(value: f64) -> f64 {
+        super::meters::to_chains(to_meters(value))
+    }
+
+    /// Converts hands to links.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_links(to_meters(value))
+    }
+
+    /// Converts hands to rods.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_rods(value: f64) -> f64 {
+        super::meters::to_rods(to_meters(value))
+    }
+
+    /// Converts hands to furlongs.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        super::meters::to_furlongs(to_meters(value))
+    }
+
+    /// Converts hands to leagues.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
+    }
+
+    /// Converts hands to mils.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
+    }
+
+    /// Converts hands to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts hands to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in hands to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The hand as a typed unit of length.
+    pub const UNIT: Unit<Length> = Unit::new("hh", "hand", to_meters, super::meters::to_hands);
+
+    /// Creates a typed length quantity expressed in hands.
+    /// # Arguments
+    /// * `value` - The length in hands
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in hands.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Mil conversion functions
+pub mod mils {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts mils to meters.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_meters(value: f64) -> f64 {
+        super::inches::to_meters(value / 1000.0)
+    }
+
+    /// Converts mils to feet.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_feet(value: f64) -> f64 {
+        super::meters::to_feet(to_meters(value))
+    }
+
+    /// Converts mils to inches.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_inches(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// Converts mils to kilometers.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
+    }
+
+    /// Converts mils to centimeters.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        super::meters::to_centimeters(to_meters(value))
+    }
+
+    /// Converts mils to millimeters.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        super::meters::to_millimeters(to_meters(value))
+    }
+
+    /// Converts mils to yards.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_yards(value: f64) -> f64 {
+        super::meters::to_yards(to_meters(value))
+    }
+
+    /// Converts mils to miles.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_miles(value: f64) -> f64 {
+        super::meters::to_miles(to_meters(value))
+    }
+
+    /// Converts mils to micrometers.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts mils to nanometers.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts mils to picometers.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts mils to ångströms.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
+    }
+
+    /// Converts mils to femtometers.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
+    }
+
+    /// Converts mils to astronomical units.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
+    }
+
+    /// Converts mils to light-years.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
+    }
+
+    /// Converts mils to light-seconds.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
+    }
+
+    /// Converts mils to parsecs.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
+    }
+
+    /// Converts mils to kiloparsecs.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
+    }
+
+    /// Converts mils to megaparsecs.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
+    }
+
+    /// Converts mils to Earth radii.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
+    }
+
+    /// Converts mils to solar radii.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
+    }
+
+    /// Converts mils to nautical miles.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
+    }
+
+    /// Converts mils to cables.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
+    }
+
+    /// Converts mils to fathoms.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
+    }
+
+    /// Converts mils to chains.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_chains(value: f64) -> f64 {
+        super::meters::to_chains(to_meters(value))
+    }
+
+    /// Converts mils to links.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_links(to_meters(value))
+    }
+
+    /// Converts mils to rods.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_rods(value: f64) -> f64 {
+        super::meters::to_rods(to_meters(value))
+    }
+
+    /// Converts mils to furlongs.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        super::meters::to_furlongs(to_meters(value))
+    }
+
+    /// Converts mils to leagues.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
+    }
+
+    /// Converts mils to hands.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
+    }
+
+    /// Converts mils to US survey feet.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        super::meters::to_us_survey_feet(to_meters(value))
+    }
+
+    /// Converts mils to US survey miles.
+    /// # Arguments
+    /// * `value` - The length in mils to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        super::meters::to_us_survey_miles(to_meters(value))
+    }
+
+    /// The mil as a typed unit of length.
+    pub const UNIT: Unit<Length> = Unit::new("mil", "mil", to_meters, super::meters::to_mils);
+
+    /// Creates a typed length quantity expressed in mils.
+    /// # Arguments
+    /// * `value` - The length in mils
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in mils.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// US survey foot conversion functions
+pub mod us_survey_feet {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts US survey feet to meters.
+    /// # Arguments
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_meters(value: f64) -> f64 {
+        value * super::METERS_PER_US_SURVEY_FOOT
+    }
+
+    /// Converts US survey feet to feet.
+    /// # Arguments
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_feet(value: f64) -> f64 {
+        super::meters::to_feet(to_meters(value))
+    }
+
+    /// Converts US survey feet to inches.
+    /// # Arguments
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_inches(value: f64) -> f64 {
+        super::meters::to_inches(to_meters(value))
+    }
+
+    /// Converts US survey feet to kilometers.
+    /// # Arguments
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
+    }
+
+    /// Converts US survey feet to centimeters.
+    /// # Arguments
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_centimeters(value: f64) -> f64 {
+        super::meters::to_centimeters(to_meters(value))
+    }
+
+    /// Converts US survey feet to millimeters.
+    /// # Arguments
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_millimeters(value: f64) -> f64 {
+        super::meters::to_millimeters(to_meters(value))
+    }
+
+    /// Converts US survey feet to yards.
+    /// # Arguments
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_yards(value: f64) -> f64 {
+        super::meters::to_yards(to_meters(value))
+    }
+
+    /// Converts US survey feet to miles.
+    /// # Arguments
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_miles(value: f64) -> f64 {
+        super::meters::to_miles(to_meters(value))
+    }
+
+    /// Converts US survey feet to micrometers.
+    /// # Arguments
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
+    }
+
+    /// Converts US survey feet to nanometers.
+    /// # Arguments
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
+    }
+
+    /// Converts US survey feet to picometers.
+    /// # Arguments
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
+    }
+
+    /// Converts US survey feet to ångströms.
+    /// # Arguments
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
     }
 
-    /// Converts meters to inches.
+    /// Converts US survey feet to femtometers.
     /// # Arguments
-    /// * `value` - The length in meters to convert
-    pub fn to_inches(value: f64) -> f64 {
-        value * 39.3701
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
     }
 
-    /// Converts meters to kilometers.
+    /// Converts US survey feet to astronomical units.
     /// # Arguments
-    /// * `value` - The length in meters to convert
-    pub fn to_kilometers(value: f64) -> f64 {
-        value / 1000.0
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
     }
 
-    /// Converts meters to centimeters.
+    /// Converts US survey feet to light-years.
     /// # Arguments
-    /// * `value` - The length in meters to convert
-    pub fn to_centimeters(value: f64) -> f64 {
-        value * 100.0
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
     }
 
-    /// Converts meters to millimeters.
+    /// Converts US survey feet to light-seconds.
     /// # Arguments
-    /// * `value` - The length in meters to convert
-    pub fn to_millimeters(value: f64) -> f64 {
-        value * 1000.0
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
     }
 
-    /// Converts meters to yards.
+    /// Converts US survey feet to parsecs.
     /// # Arguments
-    /// * `value` - The length in meters to convert
-    pub fn to_yards(value: f64) -> f64 {
-        value * 1.09361
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
     }
 
-    /// Converts meters to miles.
+    /// Converts US survey feet to kiloparsecs.
     /// # Arguments
-    /// * `value` - The length in meters to convert
-    pub fn to_miles(value: f64) -> f64 {
-        value / 1609.34
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
     }
-}
 
-/// Feet conversion functions
-pub mod feet {
-    /// Converts feet to meters.
+    /// Converts US survey feet to megaparsecs.
     /// # Arguments
-    /// * `value` - The length in feet to convert
-    pub fn to_meters(value: f64) -> f64 {
-        value / 3.28084
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
     }
 
-    /// Converts feet to inches.
+    /// Converts US survey feet to Earth radii.
     /// # Arguments
-    /// * `value` - The length in feet to convert
-    pub fn to_inches(value: f64) -> f64 {
-        value * 12.0
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
     }
 
-    /// Converts feet to yards.
+    /// Converts US survey feet to solar radii.
     /// # Arguments
-    /// * `value` - The length in feet to convert
-    pub fn to_yards(value: f64) -> f64 {
-        value / 3.0
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
     }
 
-    /// Converts feet to miles.
+    /// Converts US survey feet to nautical miles.
     /// # Arguments
-    /// * `value` - The length in feet to convert
-    pub fn to_miles(value: f64) -> f64 {
-        value / 5280.0
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
     }
 
-    /// Converts feet to centimeters.
+    /// Converts US survey feet to cables.
     /// # Arguments
-    /// * `value` - The length in feet to convert
-    pub fn to_centimeters(value: f64) -> f64 {
-        super::meters::to_centimeters(to_meters(value))
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
     }
 
-    /// Converts feet to millimeters.
+    /// Converts US survey feet to fathoms.
     /// # Arguments
-    /// * `value` - The length in feet to convert
-    pub fn to_millimeters(value: f64) -> f64 {
-        super::meters::to_millimeters(to_meters(value))
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
     }
 
-    /// Converts feet to kilometers.
+    /// Converts US survey feet to chains.
     /// # Arguments
-    /// * `value` - The length in feet to convert
-    pub fn to_kilometers(value: f64) -> f64 {
-        super::meters::to_kilometers(to_meters(value))
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_chains(value: f64) -> f64 {
+        super::meters::to_chains(to_meters(value))
     }
-}
 
-/// Inches conversion functions
-pub mod inches {
-    /// Converts inches to meters.
+    /// Converts US survey feet to links.
     /// # Arguments
-    /// * `value` - The length in inches to convert
-    pub fn to_meters(value: f64) -> f64 {
-        value / 39.3701
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_links(to_meters(value))
     }
 
-    /// Converts inches to feet.
+    /// Converts US survey feet to rods.
     /// # Arguments
-    /// * `value` - The length in inches to convert
-    pub fn to_feet(value: f64) -> f64 {
-        value / 12.0
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_rods(value: f64) -> f64 {
+        super::meters::to_rods(to_meters(value))
     }
 
-    /// Converts inches to yards.
+    /// Converts US survey feet to furlongs.
     /// # Arguments
-    /// * `value` - The length in inches to convert
-    pub fn to_yards(value: f64) -> f64 {
-        value / 36.0
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        super::meters::to_furlongs(to_meters(value))
     }
 
-    /// Converts inches to centimeters.
+    /// Converts US survey feet to leagues.
     /// # Arguments
-    /// * `value` - The length in inches to convert
-    pub fn to_centimeters(value: f64) -> f64 {
-        value * 2.54
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
     }
 
-    /// Converts inches to millimeters.
+    /// Converts US survey feet to hands.
     /// # Arguments
-    /// * `value` - The length in inches to convert
-    pub fn to_millimeters(value: f64) -> f64 {
-        value * 25.4
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
     }
 
-    /// Converts inches to kilometers.
+    /// Converts US survey feet to mils.
     /// # Arguments
-    /// * `value` - The length in inches to convert
-    pub fn to_kilometers(value: f64) -> f64 {
-        super::meters::to_kilometers(to_meters(value))
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
     }
 
-    /// Converts inches to miles.
+    /// Converts US survey feet to US survey miles.
     /// # Arguments
-    /// * `value` - The length in inches to convert
-    pub fn to_miles(value: f64) -> f64 {
-        super::meters::to_miles(to_meters(value))
+    /// * `value` - The length in US survey feet to convert
+    pub fn to_us_survey_miles(value: f64) -> f64 {
+        value / 5280.0
+    }
+
+    /// The US survey foot as a typed unit of length.
+    pub const UNIT: Unit<Length> = Unit::new(
+        "ft_us",
+        "US survey foot",
+        to_meters,
+        super::meters::to_us_survey_feet,
+    );
+
+    /// Creates a typed length quantity expressed in US survey feet.
+    /// # Arguments
+    /// * `value` - The length in US survey feet
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed length quantity in US survey feet.
+    /// # Arguments
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
     }
 }
 
-/// Kilometers conversion functions
-pub mod kilometers {
-    /// Converts kilometers to meters.
+/// US survey mile conversion functions
+pub mod us_survey_miles {
+    use crate::conversions::quantity::{Length, Quantity, Unit};
+
+    /// Converts US survey miles to meters.
     /// # Arguments
-    /// * `value` - The length in kilometers to convert
+    /// * `value` - The length in US survey miles to convert
     pub fn to_meters(value: f64) -> f64 {
-        value * 1000.0
+        value * super::METERS_PER_US_SURVEY_MILE
     }
 
-    /// Converts kilometers to feet.
+    /// Converts US survey miles to feet.
     /// # Arguments
-    /// * `value` - The length in kilometers to convert
+    /// * `value` - The length in US survey miles to convert
     pub fn to_feet(value: f64) -> f64 {
         super::meters::to_feet(to_meters(value))
     }
 
-    /// Converts kilometers to inches.
+    /// Converts US survey miles to inches.
     /// # Arguments
-    /// * `value` - The length in kilometers to convert
+    /// * `value` - The length in US survey miles to convert
     pub fn to_inches(value: f64) -> f64 {
         super::meters::to_inches(to_meters(value))
     }
 
-    /// Converts kilometers to yards.
-    /// # Arguments
-    /// * `value` - The length in kilometers to convert
-    pub fn to_yards(value: f64) -> f64 {
-        super::meters::to_yards(to_meters(value))
-    }
-
-    /// Converts kilometers to miles.
+    /// Converts US survey miles to kilometers.
     /// # Arguments
-    /// * `value` - The length in kilometers to convert
-    pub fn to_miles(value: f64) -> f64 {
-        value / 1.60934
+    /// * `value` - The length in US survey miles to convert
+    pub fn to_kilometers(value: f64) -> f64 {
+        super::meters::to_kilometers(to_meters(value))
     }
 
-    /// Converts kilometers to centimeters.
+    /// Converts US survey miles to centimeters.
     /// # Arguments
-    /// * `value` - The length in kilometers to convert
+    /// * `value` - The length in US survey miles to convert
     pub fn to_centimeters(value: f64) -> f64 {
-        value * 100000.0
+        super::meters::to_centimeters(to_meters(value))
     }
 
-    /// Converts kilometers to millimeters.
+    /// Converts US survey miles to millimeters.
     /// # Arguments
-    /// * `value` - The length in kilometers to convert
+    /// * `value` - The length in US survey miles to convert
     pub fn to_millimeters(value: f64) -> f64 {
-        value * 1000000.0
+        super::meters::to_millimeters(to_meters(value))
     }
-}
 
-/// Centimeters conversion functions
-pub mod centimeters {
-    /// Converts centimeters to meters.
+    /// Converts US survey miles to yards.
     /// # Arguments
-    /// * `value` - The length in centimeters to convert
-    pub fn to_meters(value: f64) -> f64 {
-        value / 100.0
+    /// * `value` - The length in US survey miles to convert
+    pub fn to_yards(value: f64) -> f64 {
+        super::meters::to_yards(to_meters(value))
     }
 
-    /// Converts centimeters to feet.
+    /// Converts US survey miles to miles.
     /// # Arguments
-    /// * `value` - The length in centimeters to convert
-    pub fn to_feet(value: f64) -> f64 {
-        super::meters::to_feet(to_meters(value))
+    /// * `value` - The length in US survey miles to convert
+    pub fn to_miles(value: f64) -> f64 {
+        super::meters::to_miles(to_meters(value))
     }
 
-    /// Converts centimeters to inches.
+    /// Converts US survey miles to micrometers.
     /// # Arguments
-    /// * `value` - The length in centimeters to convert
-    pub fn to_inches(value: f64) -> f64 {
-        value / 2.54
+    /// * `value` - The length in US survey miles to convert
+    pub fn to_micrometers(value: f64) -> f64 {
+        super::meters::to_micrometers(to_meters(value))
     }
 
-    /// Converts centimeters to yards.
+    /// Converts US survey miles to nanometers.
     /// # Arguments
-    /// * `value` - The length in centimeters to convert
-    pub fn to_yards(value: f64) -> f64 {
-        super::meters::to_yards(to_meters(value))
+    /// * `value` - The length in US survey miles to convert
+    pub fn to_nanometers(value: f64) -> f64 {
+        super::meters::to_nanometers(to_meters(value))
     }
 
-    /// Converts centimeters to miles.
+    /// Converts US survey miles to picometers.
     /// # Arguments
-    /// * `value` - The length in centimeters to convert
-    pub fn to_miles(value: f64) -> f64 {
-        super::meters::to_miles(to_meters(value))
+    /// * `value` - The length in US survey miles to convert
+    pub fn to_picometers(value: f64) -> f64 {
+        super::meters::to_picometers(to_meters(value))
     }
 
-    /// Converts centimeters to kilometers.
+    /// Converts US survey miles to ångströms.
     /// # Arguments
-    /// * `value` - The length in centimeters to convert
-    pub fn to_kilometers(value: f64) -> f64 {
-        value / 100000.0
+    /// * `value` - The length in US survey miles to convert
+    pub fn to_angstroms(value: f64) -> f64 {
+        super::meters::to_angstroms(to_meters(value))
     }
 
-    /// Converts centimeters to millimeters.
+    /// Converts US survey miles to femtometers.
     /// # Arguments
-    /// * `value` - The length in centimeters to convert
-    pub fn to_millimeters(value: f64) -> f64 {
-        value * 10.0
+    /// * `value` - The length in US survey miles to convert
+    pub fn to_femtometers(value: f64) -> f64 {
+        super::meters::to_femtometers(to_meters(value))
     }
-}
 
-/// Millimeters conversion functions
-pub mod millimeters {
-    /// Converts millimeters to meters.
+    /// Converts US survey miles to astronomical units.
     /// # Arguments
-    /// * `value` - The length in millimeters to convert
-    pub fn to_meters(value: f64) -> f64 {
-        value / 1000.0
+    /// * `value` - The length in US survey miles to convert
+    pub fn to_astronomical_units(value: f64) -> f64 {
+        super::meters::to_astronomical_units(to_meters(value))
     }
 
-    /// Converts millimeters to feet.
+    /// Converts US survey miles to light-years.
     /// # Arguments
-    /// * `value` - The length in millimeters to convert
-    pub fn to_feet(value: f64) -> f64 {
-        super::meters::to_feet(to_meters(value))
+    /// * `value` - The length in US survey miles to convert
+    pub fn to_light_years(value: f64) -> f64 {
+        super::meters::to_light_years(to_meters(value))
     }
 
-    /// Converts millimeters to inches.
+    /// Converts US survey miles to light-seconds.
     /// # Arguments
-    /// * `value` - The length in millimeters to convert
-    pub fn to_inches(value: f64) -> f64 {
-        value / 25.4
+    /// * `value` - The length in US survey miles to convert
+    pub fn to_light_seconds(value: f64) -> f64 {
+        super::meters::to_light_seconds(to_meters(value))
     }
 
-    /// Converts millimeters to yards.
+    /// Converts US survey miles to parsecs.
     /// # Arguments
-    /// * `value` - The length in millimeters to convert
-    pub fn to_yards(value: f64) -> f64 {
-        super::meters::to_yards(to_meters(value))
+    /// * `value` - The length in US survey miles to convert
+    pub fn to_parsecs(value: f64) -> f64 {
+        super::meters::to_parsecs(to_meters(value))
     }
 
-    /// Converts millimeters to miles.
+    /// Converts US survey miles to kiloparsecs.
     /// # Arguments
-    /// * `value` - The length in millimeters to convert
-    pub fn to_miles(value: f64) -> f64 {
-        super::meters::to_miles(to_meters(value))
+    /// * `value` - The length in US survey miles to convert
+    pub fn to_kiloparsecs(value: f64) -> f64 {
+        super::meters::to_kiloparsecs(to_meters(value))
     }
 
-    /// Converts millimeters to kilometers.
+    /// Converts US survey miles to megaparsecs.
     /// # Arguments
-    /// * `value` - The length in millimeters to convert
-    pub fn to_kilometers(value: f64) -> f64 {
-        value / 1000000.0
+    /// * `value` - The length in US survey miles to convert
+    pub fn to_megaparsecs(value: f64) -> f64 {
+        super::meters::to_megaparsecs(to_meters(value))
     }
 
-    /// Converts millimeters to centimeters.
+    /// Converts US survey miles to Earth radii.
     /// # Arguments
-    /// * `value` - The length in millimeters to convert
-    pub fn to_centimeters(value: f64) -> f64 {
-        value / 10.0
+    /// * `value` - The length in US survey miles to convert
+    pub fn to_earth_radii(value: f64) -> f64 {
+        super::meters::to_earth_radii(to_meters(value))
     }
-}
 
-/// Yards conversion functions
-pub mod yards {
-    /// Converts yards to meters.
+    /// Converts US survey miles to solar radii.
     /// # Arguments
-    /// * `value` - The length in yards to convert
-    pub fn to_meters(value: f64) -> f64 {
-        value / 1.09361
+    /// * `value` - The length in US survey miles to convert
+    pub fn to_solar_radii(value: f64) -> f64 {
+        super::meters::to_solar_radii(to_meters(value))
     }
 
-    /// Converts yards to feet.
+    /// Converts US survey miles to nautical miles.
     /// # Arguments
-    /// * `value` - The length in yards to convert
-    pub fn to_feet(value: f64) -> f64 {
-        value * 3.0
+    /// * `value` - The length in US survey miles to convert
+    pub fn to_nautical_miles(value: f64) -> f64 {
+        super::meters::to_nautical_miles(to_meters(value))
     }
 
-    /// Converts yards to inches.
+    /// Converts US survey miles to cables.
     /// # Arguments
-    /// * `value` - The length in yards to convert
-    pub fn to_inches(value: f64) -> f64 {
-        value * 36.0
+    /// * `value` - The length in US survey miles to convert
+    pub fn to_cables(value: f64) -> f64 {
+        super::meters::to_cables(to_meters(value))
     }
 
-    /// Converts yards to miles.
+    /// Converts US survey miles to fathoms.
     /// # Arguments
-    /// * `value` - The length in yards to convert
-    pub fn to_miles(value: f64) -> f64 {
-        value / 1760.0
+    /// * `value` - The length in US survey miles to convert
+    pub fn to_fathoms(value: f64) -> f64 {
+        super::meters::to_fathoms(to_meters(value))
     }
 
-    /// Converts yards to kilometers.
+    /// Converts US survey miles to chains.
     /// # Arguments
-    /// * `value` - The length in yards to convert
-    pub fn to_kilometers(value: f64) -> f64 {
-        super::meters::to_kilometers(to_meters(value))
+    /// * `value` - The length in US survey miles to convert
+    pub fn to_chains(value: f64) -> f64 {
+        super::meters::to_chains(to_meters(value))
     }
 
-    /// Converts yards to centimeters.
+    /// Converts US survey miles to links.
     /// # Arguments
-    /// * `value` - The length in yards to convert
-    pub fn to_centimeters(value: f64) -> f64 {
-        super::meters::to_centimeters(to_meters(value))
+    /// * `value` - The length in US survey miles to convert
+    pub fn to_links(value: f64) -> f64 {
+        super::meters::to_links(to_meters(value))
     }
 
-    /// Converts yards to millimeters.
+    /// Converts US survey miles to rods.
     /// # Arguments
-    /// * `value` - The length in yards to convert
-    pub fn to_millimeters(value: f64) -> f64 {
-        super::meters::to_millimeters(to_meters(value))
+    /// * `value` - The length in US survey miles to convert
+    pub fn to_rods(value: f64) -> f64 {
+        super::meters::to_rods(to_meters(value))
     }
-}
 
-/// Miles conversion functions
-pub mod miles {
-    /// Converts miles to meters.
+    /// Converts US survey miles to furlongs.
     /// # Arguments
-    /// * `value` - The length in miles to convert
-    pub fn to_meters(value: f64) -> f64 {
-        value * 1609.34
+    /// * `value` - The length in US survey miles to convert
+    pub fn to_furlongs(value: f64) -> f64 {
+        super::meters::to_furlongs(to_meters(value))
     }
 
-    /// Converts miles to feet.
+    /// Converts US survey miles to leagues.
     /// # Arguments
-    /// * `value` - The length in miles to convert
-    pub fn to_feet(value: f64) -> f64 {
-        value * 5280.0
+    /// * `value` - The length in US survey miles to convert
+    pub fn to_leagues(value: f64) -> f64 {
+        super::meters::to_leagues(to_meters(value))
     }
 
-    /// Converts miles to inches.
+    /// Converts US survey miles to hands.
     /// # Arguments
-    /// * `value` - The length in miles to convert
-    pub fn to_inches(value: f64) -> f64 {
-        value * 63360.0
+    /// * `value` - The length in US survey miles to convert
+    pub fn to_hands(value: f64) -> f64 {
+        super::meters::to_hands(to_meters(value))
     }
 
-    /// Converts miles to yards.
+    /// Converts US survey miles to mils.
     /// # Arguments
-    /// * `value` - The length in miles to convert
-    pub fn to_yards(value: f64) -> f64 {
-        value * 1760.0
+    /// * `value` - The length in US survey miles to convert
+    pub fn to_mils(value: f64) -> f64 {
+        super::meters::to_mils(to_meters(value))
     }
 
-    /// Converts miles to kilometers.
+    /// Converts US survey miles to US survey feet.
     /// # Arguments
-    /// * `value` - The length in miles to convert
-    pub fn to_kilometers(value: f64) -> f64 {
-        value * 1.60934
+    /// * `value` - The length in US survey miles to convert
+    pub fn to_us_survey_feet(value: f64) -> f64 {
+        value * 5280.0
     }
 
-    /// Converts miles to centimeters.
+    /// The US survey mile as a typed unit of length.
+    pub const UNIT: Unit<Length> = Unit::new(
+        "mi_us",
+        "US survey mile",
+        to_meters,
+        super::meters::to_us_survey_miles,
+    );
+
+    /// Creates a typed length quantity expressed in US survey miles.
     /// # Arguments
-    /// * `value` - The length in miles to convert
-    pub fn to_centimeters(value: f64) -> f64 {
-        super::meters::to_centimeters(to_meters(value))
+    /// * `value` - The length in US survey miles
+    pub fn new(value: f64) -> Quantity<Length> {
+        Quantity::new(value, UNIT)
     }
 
-    /// Converts miles to millimeters.
+    /// Reads a typed length quantity in US survey miles.
     /// # Arguments
-    /// * `value` - The length in miles to convert
-    pub fn to_millimeters(value: f64) -> f64 {
-        super::meters::to_millimeters(to_meters(value))
+    /// * `quantity` - The length quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Length>) -> f64 {
+        quantity.value_in(UNIT)
     }
 }
 
@@ -570,6 +8377,29 @@ pub fn miles_to_meters(miles: f64) -> f64 {
 ///   `cm`, `centimeter`, `centimeters`, `mm`, `millimeter`, `millimeters`
 /// * **Imperial:** `ft`, `foot`, `feet`, `in`, `inch`, `inches`,
 ///   `yd`, `yard`, `yards`, `mi`, `mile`, `miles`
+/// * **Microscopic:** `μm` (`um`, `micron`), `nm`, `pm`, `Å` (`angstrom`),
+///   `fm` (`fermi`), plus any SI-prefixed meter
+/// * **Astronomical:** `au`, `ly` (`light_year`), `ls` (`light_second`), `pc`
+///   (`parsec`), `kpc`, `Mpc`, `R⊕` (`R_E`, `earth_radius`) and `R☉` (`R_sun`,
+///   `solar_radius`)
+/// * **Nautical:** `nmi` (`NM`, `nautical_mile`), `cbl` (`cable`), `ftm`
+///   (`fathom`)
+/// * **Surveying:** `ch` (`chain`), `li` (`link`), `rd` (`rod`, `pole`,
+///   `perch`), `fur` (`furlong`), `ft_us` (`survey_foot`) and `mi_us`
+///   (`survey_mile`)
+/// * **Historical and trade:** `lea` (`league`), `hh` (`hand`), `mil` (`thou`)
+///
+/// # Nautical Miles and Nanometers
+///
+/// `nm` is always the nanometer. A nautical mile is written `nmi` or `NM`, and
+/// because symbols are case-sensitive, spellings that only match one of them
+/// ignoring case (`Nm`, `nM`) are rejected as ambiguous rather than guessed.
+///
+/// # Survey and International Feet
+///
+/// `ft` and `mi` are the international foot and mile (0.3048 m). The US survey
+/// foot (`ft_us`, 1200/3937 m) is two parts per million longer; it is only
+/// used when asked for by name.
 ///
 /// Unit names are case-insensitive and support both singular and plural forms.
 ///
@@ -581,7 +8411,8 @@ pub fn miles_to_meters(miles: f64) -> f64 {
 ///
 /// # Returns
 /// * `Ok(f64)` - The converted value if both units are recognized
-/// * `Err(String)` - An error message if either unit is not supported
+/// * `Err(ConversionError)` - An [`ConversionError::UnknownUnit`] if either unit is not supported,
+///   or [`ConversionError::NonFiniteInput`] if `value` is NaN or infinite
 ///
 /// # Examples
 ///
@@ -604,45 +8435,285 @@ pub fn miles_to_meters(miles: f64) -> f64 {
 /// let result = convert_length(1.0, "METER", "feet").unwrap();
 /// assert!((result - 3.28084).abs() < 0.0001);
 ///
+/// // Nautical and surveying units
+/// let result = convert_length(1.0, "nmi", "cbl").unwrap();
+/// assert!((result - 10.0).abs() < 1e-9);
+/// let result = convert_length(1.0, "fur", "ch").unwrap();
+/// assert!((result - 10.0).abs() < 1e-9);
+/// assert!(convert_length(1.0, "Nm", "m").is_err());
+///
+/// // Optics and astronomy
+/// let result = convert_length(550.0, "nm", "Å").unwrap();
+/// assert!((result - 5500.0).abs() < 1e-9);
+/// let result = convert_length(1.0, "pc", "ly").unwrap();
+/// assert!((result - 3.26156).abs() < 1e-5);
+///
 /// // Error handling for unknown units
 /// assert!(convert_length(1.0, "invalid", "m").is_err());
 /// ```
 ///
 /// # Conversion Accuracy
 ///
-/// All conversions use standard international conversion factors:
-/// - 1 meter = 3.28084 feet
-/// - 1 meter = 39.3701 inches  
-/// - 1 mile = 1609.34 meters
-/// - etc.
+/// All conversions derive from the exact international definitions:
+/// - 1 yard = 0.9144 meters
+/// - 1 foot = 0.3048 meters
+/// - 1 inch = 2.54 centimeters
+/// - 1 mile = 1609.344 meters
+/// - 1 astronomical unit = 149 597 870 700 meters
+/// - 1 light-year = 299 792 458 m/s × 365.25 days
+/// - 1 parsec = 648 000/π astronomical units
+/// - nominal Earth and solar radii = 6 378 100 m and 695 700 000 m (IAU 2015)
+/// - 1 nautical mile = 1852 meters, and 1 cable = 1/10 nautical mile
+/// - 1 fathom = 6 feet, 1 rod = 16.5 feet, 1 chain = 66 feet = 100 links,
+///   1 furlong = 660 feet, 1 league = 3 miles, 1 hand = 4 inches, 1 mil = 1/1000 inch
+/// - 1 US survey foot = 1200/3937 meters, and 1 US survey mile = 5280 US survey feet
 ///
 /// Results maintain high precision suitable for most applications.
-pub fn convert_length(value: f64, from: &str, to: &str) -> Result<f64, String> {
-    // First convert to meters (base unit)
-    let meters = match from.to_lowercase().as_str() {
-        "m" | "meter" | "meters" => value,
-        "km" | "kilometer" | "kilometers" => kilometers_to_meters(value),
-        "cm" | "centimeter" | "centimeters" => centimeters_to_meters(value),
-        "mm" | "millimeter" | "millimeters" => millimeters_to_meters(value),
-        "ft" | "foot" | "feet" => feet_to_meters(value),
-        "in" | "inch" | "inches" => inches_to_meters(value),
-        "yd" | "yard" | "yards" => yards_to_meters(value),
-        "mi" | "mile" | "miles" => miles_to_meters(value),
-        _ => return Err(format!("Unknown length unit: {}", from)),
-    };
-
-    // Then convert from meters to target unit
-    let result = match to.to_lowercase().as_str() {
-        "m" | "meter" | "meters" => meters,
-        "km" | "kilometer" | "kilometers" => meters_to_kilometers(meters),
-        "cm" | "centimeter" | "centimeters" => meters_to_centimeters(meters),
-        "mm" | "millimeter" | "millimeters" => meters_to_millimeters(meters),
-        "ft" | "foot" | "feet" => meters_to_feet(meters),
-        "in" | "inch" | "inches" => meters_to_inches(meters),
-        "yd" | "yard" | "yards" => meters_to_yards(meters),
-        "mi" | "mile" | "miles" => meters_to_miles(meters),
-        _ => return Err(format!("Unknown length unit: {}", to)),
-    };
-
-    Ok(result)
+pub fn convert_length(value: f64, from: &str, to: &str) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from, to, Category::Length)
+}
+
+/// Length units known to the [`UnitRegistry`], with meters as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new("m", "meter", &["meters"], Category::Length, 1.0).with_prefixes(),
+        UnitDef::new(
+            "km",
+            "kilometer",
+            &["kilometers"],
+            Category::Length,
+            kilometers::to_meters(1.0),
+        ),
+        UnitDef::new(
+            "cm",
+            "centimeter",
+            &["centimeters"],
+            Category::Length,
+            centimeters::to_meters(1.0),
+        ),
+        UnitDef::new(
+            "mm",
+            "millimeter",
+            &["millimeters"],
+            Category::Length,
+            millimeters::to_meters(1.0),
+        ),
+        UnitDef::new(
+            "ft",
+            "foot",
+            &["feet"],
+            Category::Length,
+            feet::to_meters(1.0),
+        ),
+        UnitDef::new(
+            "in",
+            "inch",
+            &["inches"],
+            Category::Length,
+            inches::to_meters(1.0),
+        ),
+        UnitDef::new(
+            "yd",
+            "yard",
+            &["yards"],
+            Category::Length,
+            yards::to_meters(1.0),
+        ),
+        UnitDef::new(
+            "mi",
+            "mile",
+            &["miles"],
+            Category::Length,
+            miles::to_meters(1.0),
+        ),
+        UnitDef::new(
+            "μm",
+            "micrometer",
+            &["um", "micrometers", "micron", "microns"],
+            Category::Length,
+            micrometers::to_meters(1.0),
+        ),
+        UnitDef::new(
+            "nm",
+            "nanometer",
+            &["nanometers"],
+            Category::Length,
+            nanometers::to_meters(1.0),
+        ),
+        UnitDef::new(
+            "pm",
+            "picometer",
+            &["picometers"],
+            Category::Length,
+            picometers::to_meters(1.0),
+        ),
+        UnitDef::new(
+            "Å",
+            "ångström",
+            &["\u{212b}", "ångströms", "angstrom", "angstroms"],
+            Category::Length,
+            angstroms::to_meters(1.0),
+        ),
+        UnitDef::new(
+            "fm",
+            "femtometer",
+            &["femtometers", "fermi", "fermis"],
+            Category::Length,
+            femtometers::to_meters(1.0),
+        ),
+        UnitDef::new(
+            "au",
+            "astronomical_unit",
+            &["AU", "ua", "astronomical_units"],
+            Category::Length,
+            METERS_PER_ASTRONOMICAL_UNIT,
+        ),
+        UnitDef::new(
+            "ly",
+            "light_year",
+            &["light_years", "light-year", "light-years"],
+            Category::Length,
+            METERS_PER_LIGHT_YEAR,
+        ),
+        UnitDef::new(
+            "ls",
+            "light_second",
+            &["light_seconds", "light-second", "light-seconds"],
+            Category::Length,
+            METERS_PER_LIGHT_SECOND,
+        ),
+        UnitDef::new(
+            "pc",
+            "parsec",
+            &["parsecs"],
+            Category::Length,
+            METERS_PER_PARSEC,
+        )
+        .with_irrational_factor(),
+        UnitDef::new(
+            "kpc",
+            "kiloparsec",
+            &["kiloparsecs"],
+            Category::Length,
+            kiloparsecs::to_meters(1.0),
+        )
+        .with_irrational_factor(),
+        UnitDef::new(
+            "Mpc",
+            "megaparsec",
+            &["megaparsecs"],
+            Category::Length,
+            megaparsecs::to_meters(1.0),
+        )
+        .with_irrational_factor(),
+        UnitDef::new(
+            "R⊕",
+            "earth_radius",
+            &["R_E", "R_earth", "earth_radii"],
+            Category::Length,
+            METERS_PER_EARTH_RADIUS,
+        ),
+        UnitDef::new(
+            "R☉",
+            "solar_radius",
+            &["R_sun", "R_S", "solar_radii"],
+            Category::Length,
+            METERS_PER_SOLAR_RADIUS,
+        ),
+        UnitDef::new(
+            "nmi",
+            "nautical_mile",
+            &["NM", "nautical_miles"],
+            Category::Length,
+            nautical_miles::to_meters(1.0),
+        ),
+        UnitDef::new(
+            "cbl",
+            "cable",
+            &["cables"],
+            Category::Length,
+            cables::to_meters(1.0),
+        )
+        .with_exact_factor("185.2"),
+        UnitDef::new(
+            "ftm",
+            "fathom",
+            &["fathoms"],
+            Category::Length,
+            fathoms::to_meters(1.0),
+        )
+        .with_exact_factor("1.8288"),
+        UnitDef::new(
+            "ch",
+            "chain",
+            &["chains", "gunter_chain"],
+            Category::Length,
+            chains::to_meters(1.0),
+        )
+        .with_exact_factor("20.1168"),
+        UnitDef::new(
+            "li",
+            "link",
+            &["links"],
+            Category::Length,
+            links::to_meters(1.0),
+        )
+        .with_exact_factor("0.201168"),
+        UnitDef::new(
+            "rd",
+            "rod",
+            &["rods", "pole", "poles", "perch", "perches"],
+            Category::Length,
+            rods::to_meters(1.0),
+        )
+        .with_exact_factor("5.0292"),
+        UnitDef::new(
+            "fur",
+            "furlong",
+            &["furlongs"],
+            Category::Length,
+            furlongs::to_meters(1.0),
+        )
+        .with_exact_factor("201.168"),
+        UnitDef::new(
+            "lea",
+            "league",
+            &["leagues"],
+            Category::Length,
+            leagues::to_meters(1.0),
+        )
+        .with_exact_factor("4828.032"),
+        UnitDef::new(
+            "hh",
+            "hand",
+            &["hands"],
+            Category::Length,
+            hands::to_meters(1.0),
+        )
+        .with_exact_factor("0.1016"),
+        UnitDef::new(
+            "mil",
+            "mil",
+            &["mils", "thou"],
+            Category::Length,
+            mils::to_meters(1.0),
+        )
+        .with_exact_factor("0.0000254"),
+        UnitDef::new(
+            "ft_us",
+            "us_survey_foot",
+            &["us_survey_feet", "survey_foot", "survey_feet"],
+            Category::Length,
+            us_survey_feet::to_meters(1.0),
+        )
+        .with_exact_factor("1200/3937"),
+        UnitDef::new(
+            "mi_us",
+            "us_survey_mile",
+            &["us_survey_miles", "survey_mile", "survey_miles"],
+            Category::Length,
+            us_survey_miles::to_meters(1.0),
+        )
+        .with_exact_factor("6336000/3937"),
+    ]
 }
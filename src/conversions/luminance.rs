@@ -0,0 +1,279 @@
+//! Luminance conversion functions organized by unit type
+//! All conversions use the nit (candela per square meter) as the base unit,
+//! composing the `area` definitions. The foot-lambert and lambert carry a
+//! factor of 1/π, so they cannot be converted exactly
+
+use super::area;
+use super::category::Category;
+use super::error::ConversionError;
+use super::registry::{UnitDef, UnitRegistry};
+use std::f64::consts::PI;
+
+/// Nits in one foot-lambert (1/π candela per square foot)
+fn foot_lamberts() -> f64 {
+    1.0 / (PI * area::square_feet::to_square_meters(1.0))
+}
+
+/// Nits in one lambert (1/π candela per square centimeter)
+fn lamberts() -> f64 {
+    1.0 / (PI * area::square_centimeters::to_square_meters(1.0))
+}
+
+/// Nits in one stilb (one candela per square centimeter)
+fn stilbs() -> f64 {
+    1.0 / area::square_centimeters::to_square_meters(1.0)
+}
+
+/// Nits (candelas per square meter) conversion functions
+pub mod nits {
+    use crate::conversions::quantity::{Luminance, Quantity, Unit};
+
+    /// Converts nits to foot-lamberts.
+    /// # Arguments
+    /// * `value` - The luminance in nits to convert
+    pub fn to_foot_lamberts(value: f64) -> f64 {
+        value / super::foot_lamberts()
+    }
+
+    /// Converts nits to lamberts.
+    /// # Arguments
+    /// * `value` - The luminance in nits to convert
+    pub fn to_lamberts(value: f64) -> f64 {
+        value / super::lamberts()
+    }
+
+    /// Converts nits to stilbs.
+    /// # Arguments
+    /// * `value` - The luminance in nits to convert
+    pub fn to_stilbs(value: f64) -> f64 {
+        value / super::stilbs()
+    }
+
+    /// The candela per square meter as a typed unit of luminance.
+    pub const UNIT: Unit<Luminance> = Unit::new(
+        "cd/m²",
+        "candela per square meter",
+        |value| value,
+        |value| value,
+    );
+
+    /// Creates a typed luminance quantity expressed in nits.
+    /// # Arguments
+    /// * `value` - The luminance in nits
+    pub fn new(value: f64) -> Quantity<Luminance> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed luminance quantity in nits.
+    /// # Arguments
+    /// * `quantity` - The luminance quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Luminance>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Foot-lamberts conversion functions
+pub mod foot_lamberts {
+    use crate::conversions::quantity::{Luminance, Quantity, Unit};
+
+    /// Converts foot-lamberts to nits.
+    /// # Arguments
+    /// * `value` - The luminance in foot-lamberts to convert
+    pub fn to_nits(value: f64) -> f64 {
+        value * super::foot_lamberts()
+    }
+
+    /// Converts foot-lamberts to lamberts.
+    /// # Arguments
+    /// * `value` - The luminance in foot-lamberts to convert
+    pub fn to_lamberts(value: f64) -> f64 {
+        super::nits::to_lamberts(to_nits(value))
+    }
+
+    /// Converts foot-lamberts to stilbs.
+    /// # Arguments
+    /// * `value` - The luminance in foot-lamberts to convert
+    pub fn to_stilbs(value: f64) -> f64 {
+        super::nits::to_stilbs(to_nits(value))
+    }
+
+    /// The foot-lambert as a typed unit of luminance.
+    pub const UNIT: Unit<Luminance> =
+        Unit::new("fL", "foot-lambert", to_nits, super::nits::to_foot_lamberts);
+
+    /// Creates a typed luminance quantity expressed in foot-lamberts.
+    /// # Arguments
+    /// * `value` - The luminance in foot-lamberts
+    pub fn new(value: f64) -> Quantity<Luminance> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed luminance quantity in foot-lamberts.
+    /// # Arguments
+    /// * `quantity` - The luminance quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Luminance>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Lamberts conversion functions
+pub mod lamberts {
+    use crate::conversions::quantity::{Luminance, Quantity, Unit};
+
+    /// Converts lamberts to nits.
+    /// # Arguments
+    /// * `value` - The luminance in lamberts to convert
+    pub fn to_nits(value: f64) -> f64 {
+        value * super::lamberts()
+    }
+
+    /// Converts lamberts to foot-lamberts.
+    /// # Arguments
+    /// * `value` - The luminance in lamberts to convert
+    pub fn to_foot_lamberts(value: f64) -> f64 {
+        super::nits::to_foot_lamberts(to_nits(value))
+    }
+
+    /// Converts lamberts to stilbs.
+    /// # Arguments
+    /// * `value` - The luminance in lamberts to convert
+    pub fn to_stilbs(value: f64) -> f64 {
+        super::nits::to_stilbs(to_nits(value))
+    }
+
+    /// The lambert as a typed unit of luminance.
+    pub const UNIT: Unit<Luminance> = Unit::new("La", "lambert", to_nits, super::nits::to_lamberts);
+
+    /// Creates a typed luminance quantity expressed in lamberts.
+    /// # Arguments
+    /// * `value` - The luminance in lamberts
+    pub fn new(value: f64) -> Quantity<Luminance> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed luminance quantity in lamberts.
+    /// # Arguments
+    /// * `quantity` - The luminance quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Luminance>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Stilbs conversion functions
+pub mod stilbs {
+    use crate::conversions::quantity::{Luminance, Quantity, Unit};
+
+    /// Converts stilbs to nits.
+    /// # Arguments
+    /// * `value` - The luminance in stilbs to convert
+    pub fn to_nits(value: f64) -> f64 {
+        value * super::stilbs()
+    }
+
+    /// Converts stilbs to foot-lamberts.
+    /// # Arguments
+    /// * `value` - The luminance in stilbs to convert
+    pub fn to_foot_lamberts(value: f64) -> f64 {
+        super::nits::to_foot_lamberts(to_nits(value))
+    }
+
+    /// Converts stilbs to lamberts.
+    /// # Arguments
+    /// * `value` - The luminance in stilbs to convert
+    pub fn to_lamberts(value: f64) -> f64 {
+        super::nits::to_lamberts(to_nits(value))
+    }
+
+    /// The stilb as a typed unit of luminance.
+    pub const UNIT: Unit<Luminance> = Unit::new("sb", "stilb", to_nits, super::nits::to_stilbs);
+
+    /// Creates a typed luminance quantity expressed in stilbs.
+    /// # Arguments
+    /// * `value` - The luminance in stilbs
+    pub fn new(value: f64) -> Quantity<Luminance> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed luminance quantity in stilbs.
+    /// # Arguments
+    /// * `quantity` - The luminance quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Luminance>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// General luminance conversion function that accepts string unit names
+///
+/// Converts a luminance from one unit to another using string identifiers.
+/// Symbols are case-sensitive, names are case-insensitive, and `nit` is
+/// accepted for the candela per square meter.
+///
+/// # Arguments
+///
+/// * `value` - The numeric value to convert
+/// * `from_unit` - The source unit (e.g., "cd/m²", "nit", "fL", "La", "sb")
+/// * `to_unit` - The target unit using the same abbreviations
+///
+/// # Returns
+/// * `Ok(f64)` - The converted value
+/// * `Err(ConversionError)` - The reason the conversion is not supported
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::convert_luminance;
+///
+/// // A cinema screen at 14 fL
+/// let nits = convert_luminance(14.0, "fL", "nit").unwrap();
+/// assert!((nits - 47.967).abs() < 0.001);
+/// ```
+pub fn convert_luminance(
+    value: f64,
+    from_unit: &str,
+    to_unit: &str,
+) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from_unit, to_unit, Category::Luminance)
+}
+
+/// Luminance units known to the [`UnitRegistry`], with nits as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new(
+            "cd/m²",
+            "candela per square meter",
+            &["cd/m2", "nit", "nits", "nt", "candelas per square meter"],
+            Category::Luminance,
+            1.0,
+        ),
+        UnitDef::new(
+            "fL",
+            "foot-lambert",
+            &[
+                "ft·L",
+                "footlambert",
+                "footlamberts",
+                "foot-lamberts",
+                "foot_lamberts",
+            ],
+            Category::Luminance,
+            foot_lamberts::to_nits(1.0),
+        )
+        .with_irrational_factor(),
+        UnitDef::new(
+            "La",
+            "lambert",
+            &["lamberts"],
+            Category::Luminance,
+            lamberts::to_nits(1.0),
+        )
+        .with_irrational_factor(),
+        UnitDef::new(
+            "sb",
+            "stilb",
+            &["stilbs", "cd/cm²", "cd/cm2"],
+            Category::Luminance,
+            stilbs::to_nits(1.0),
+        )
+        .with_exact_factor("10000"),
+    ]
+}
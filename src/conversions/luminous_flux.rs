@@ -0,0 +1,241 @@
+//! Luminous flux conversion functions organized by unit type
+//! All conversions use lumens as the base unit. A lumen is one candela
+//! radiating into one steradian, which links flux to `luminous_intensity`
+//! through the beam's `solid_angle`
+
+use super::category::Category;
+use super::error::ConversionError;
+use super::luminous_intensity::{self, convert_luminous_intensity};
+use super::quantity::{LuminousFlux, LuminousIntensity, Quantity, SolidAngle};
+use super::registry::{UnitDef, UnitRegistry};
+use super::solid_angle::{self, convert_solid_angle};
+
+/// Lumens conversion functions
+pub mod lumens {
+    use crate::conversions::quantity::{LuminousFlux, Quantity, Unit};
+
+    /// Converts lumens to kilolumens.
+    /// # Arguments
+    /// * `value` - The luminous flux in lumens to convert
+    pub fn to_kilolumens(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// The lumen as a typed unit of luminous flux.
+    pub const UNIT: Unit<LuminousFlux> = Unit::new("lm", "lumen", |value| value, |value| value);
+
+    /// Creates a typed luminous flux quantity expressed in lumens.
+    /// # Arguments
+    /// * `value` - The luminous flux in lumens
+    pub fn new(value: f64) -> Quantity<LuminousFlux> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed luminous flux quantity in lumens.
+    /// # Arguments
+    /// * `quantity` - The luminous flux quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<LuminousFlux>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Kilolumens conversion functions
+pub mod kilolumens {
+    use crate::conversions::quantity::{LuminousFlux, Quantity, Unit};
+
+    /// Converts kilolumens to lumens.
+    /// # Arguments
+    /// * `value` - The luminous flux in kilolumens to convert
+    pub fn to_lumens(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// The kilolumen as a typed unit of luminous flux.
+    pub const UNIT: Unit<LuminousFlux> =
+        Unit::new("klm", "kilolumen", to_lumens, super::lumens::to_kilolumens);
+
+    /// Creates a typed luminous flux quantity expressed in kilolumens.
+    /// # Arguments
+    /// * `value` - The luminous flux in kilolumens
+    pub fn new(value: f64) -> Quantity<LuminousFlux> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed luminous flux quantity in kilolumens.
+    /// # Arguments
+    /// * `quantity` - The luminous flux quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<LuminousFlux>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Converts the luminous intensity of a source to the flux it sends into a
+/// beam, assuming the intensity is uniform across the beam.
+///
+/// # Arguments
+/// * `intensity` - The typed luminous intensity
+/// * `beam` - The typed solid angle of the beam
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::luminous_flux::{from_intensity, lumens};
+/// use conversions_rs::luminous_intensity::candela;
+/// use conversions_rs::solid_angle::steradians;
+///
+/// let flux = from_intensity(candela::new(100.0), steradians::new(0.5));
+/// assert!((lumens::value_of(flux) - 50.0).abs() < 1e-9);
+/// ```
+pub fn from_intensity(
+    intensity: Quantity<LuminousIntensity>,
+    beam: Quantity<SolidAngle>,
+) -> Quantity<LuminousFlux> {
+    lumens::new(
+        luminous_intensity::candela::value_of(intensity) * solid_angle::steradians::value_of(beam),
+    )
+}
+
+/// Converts the flux sent into a beam to the source's luminous intensity,
+/// assuming the intensity is uniform across the beam.
+///
+/// # Arguments
+/// * `flux` - The typed luminous flux
+/// * `beam` - The typed solid angle of the beam
+pub fn to_intensity(
+    flux: Quantity<LuminousFlux>,
+    beam: Quantity<SolidAngle>,
+) -> Quantity<LuminousIntensity> {
+    luminous_intensity::candela::new(
+        lumens::value_of(flux) / solid_angle::steradians::value_of(beam),
+    )
+}
+
+/// Converts a luminous intensity given with a string unit to the flux sent
+/// into a beam of the given solid angle.
+///
+/// # Arguments
+/// * `intensity` - The luminous intensity of the source
+/// * `intensity_unit` - A luminous intensity unit (e.g., "cd", "mcd")
+/// * `beam` - The solid angle of the beam
+/// * `beam_unit` - A solid angle unit (e.g., "sr", "deg²")
+/// * `flux_unit` - A luminous flux unit (e.g., "lm", "klm")
+///
+/// # Returns
+/// * `Ok(f64)` - The luminous flux in `flux_unit`
+/// * `Err(ConversionError)` - The reason the conversion is not supported
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::intensity_to_flux;
+///
+/// // A 1000 cd spotlight with a 0.2 sr beam
+/// let lumens = intensity_to_flux(1000.0, "cd", 0.2, "sr", "lm").unwrap();
+/// assert!((lumens - 200.0).abs() < 1e-9);
+/// ```
+pub fn intensity_to_flux(
+    intensity: f64,
+    intensity_unit: &str,
+    beam: f64,
+    beam_unit: &str,
+    flux_unit: &str,
+) -> Result<f64, ConversionError> {
+    let candela = convert_luminous_intensity(intensity, intensity_unit, "cd")?;
+    let steradians = convert_solid_angle(beam, beam_unit, "sr")?;
+    convert_luminous_flux(candela * steradians, "lm", flux_unit)
+}
+
+/// Converts the flux sent into a beam, given with a string unit, to the
+/// source's luminous intensity.
+///
+/// # Arguments
+/// * `flux` - The luminous flux in the beam
+/// * `flux_unit` - A luminous flux unit (e.g., "lm", "klm")
+/// * `beam` - The solid angle of the beam
+/// * `beam_unit` - A solid angle unit (e.g., "sr", "deg²")
+/// * `intensity_unit` - A luminous intensity unit (e.g., "cd", "mcd")
+///
+/// # Returns
+/// * `Ok(f64)` - The luminous intensity in `intensity_unit`
+/// * `Err(ConversionError)` - The reason the conversion is not supported,
+///   including [`ConversionError::NoReciprocal`] for a beam of zero solid angle
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::flux_to_intensity;
+///
+/// // An 800 lm lamp radiating evenly in every direction (4π sr)
+/// let candela = flux_to_intensity(800.0, "lm", 1.0, "sp", "cd").unwrap();
+/// assert!((candela - 63.662).abs() < 0.001);
+/// ```
+pub fn flux_to_intensity(
+    flux: f64,
+    flux_unit: &str,
+    beam: f64,
+    beam_unit: &str,
+    intensity_unit: &str,
+) -> Result<f64, ConversionError> {
+    let lumens = convert_luminous_flux(flux, flux_unit, "lm")?;
+    let steradians = convert_solid_angle(beam, beam_unit, "sr")?;
+    if steradians == 0.0 {
+        return Err(ConversionError::NoReciprocal {
+            value: beam,
+            unit: beam_unit.to_string(),
+        });
+    }
+    convert_luminous_intensity(lumens / steradians, "cd", intensity_unit)
+}
+
+/// General luminous flux conversion function that accepts string unit names
+///
+/// Converts a luminous flux from one unit to another using string
+/// identifiers. Symbols are case-sensitive, names are case-insensitive, and
+/// SI prefixes are accepted on the lumen.
+///
+/// # Arguments
+///
+/// * `value` - The numeric value to convert
+/// * `from_unit` - The source unit (e.g., "lm", "klm", "mlm")
+/// * `to_unit` - The target unit using the same abbreviations
+///
+/// # Returns
+/// * `Ok(f64)` - The converted value
+/// * `Err(ConversionError)` - The reason the conversion is not supported
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::convert_luminous_flux;
+///
+/// let lumens = convert_luminous_flux(2.5, "klm", "lm").unwrap();
+/// assert_eq!(lumens, 2500.0);
+/// ```
+pub fn convert_luminous_flux(
+    value: f64,
+    from_unit: &str,
+    to_unit: &str,
+) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from_unit, to_unit, Category::LuminousFlux)
+}
+
+/// Luminous flux units known to the [`UnitRegistry`], with lumens as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new(
+            "lm",
+            "lumen",
+            &["lumens", "cd·sr"],
+            Category::LuminousFlux,
+            1.0,
+        )
+        .with_prefixes(),
+        UnitDef::new(
+            "klm",
+            "kilolumen",
+            &["kilolumens"],
+            Category::LuminousFlux,
+            kilolumens::to_lumens(1.0),
+        ),
+    ]
+}
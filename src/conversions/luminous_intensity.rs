@@ -1,8 +1,14 @@
 //! Luminous intensity conversion functions organized by unit type
 //! All conversions use candela as the base unit for accuracy and consistency
 
+use super::category::Category;
+use super::error::ConversionError;
+use super::registry::{UnitDef, UnitRegistry};
+
 /// Candela conversion functions
 pub mod candela {
+    use crate::conversions::quantity::{LuminousIntensity, Quantity, Unit};
+
     /// Converts candela to millicandela.
     /// # Arguments
     /// * `value` - The luminous intensity in candela to convert
@@ -43,10 +49,30 @@ pub mod candela {
     pub fn to_decimal_candle(value: f64) -> f64 {
         value
     }
+
+    /// The candela as a typed unit of luminous intensity.
+    pub const UNIT: Unit<LuminousIntensity> =
+        Unit::new("cd", "candela", |value| value, |value| value);
+
+    /// Creates a typed luminous intensity quantity expressed in candela.
+    /// # Arguments
+    /// * `value` - The luminous intensity in candela
+    pub fn new(value: f64) -> Quantity<LuminousIntensity> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed luminous intensity quantity in candela.
+    /// # Arguments
+    /// * `quantity` - The luminous intensity quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<LuminousIntensity>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Millicandela conversion functions
 pub mod millicandela {
+    use crate::conversions::quantity::{LuminousIntensity, Quantity, Unit};
+
     /// Converts millicandela to candela.
     /// # Arguments
     /// * `value` - The luminous intensity in millicandela to convert
@@ -81,10 +107,34 @@ pub mod millicandela {
     pub fn to_decimal_candle(value: f64) -> f64 {
         super::candela::to_decimal_candle(to_candela(value))
     }
+
+    /// The millicandela as a typed unit of luminous intensity.
+    pub const UNIT: Unit<LuminousIntensity> = Unit::new(
+        "mcd",
+        "millicandela",
+        to_candela,
+        super::candela::to_millicandela,
+    );
+
+    /// Creates a typed luminous intensity quantity expressed in millicandela.
+    /// # Arguments
+    /// * `value` - The luminous intensity in millicandela
+    pub fn new(value: f64) -> Quantity<LuminousIntensity> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed luminous intensity quantity in millicandela.
+    /// # Arguments
+    /// * `quantity` - The luminous intensity quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<LuminousIntensity>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Kilocandela conversion functions
 pub mod kilocandela {
+    use crate::conversions::quantity::{LuminousIntensity, Quantity, Unit};
+
     /// Converts kilocandela to candela.
     /// # Arguments
     /// * `value` - The luminous intensity in kilocandela to convert
@@ -119,10 +169,34 @@ pub mod kilocandela {
     pub fn to_decimal_candle(value: f64) -> f64 {
         super::candela::to_decimal_candle(to_candela(value))
     }
+
+    /// The kilocandela as a typed unit of luminous intensity.
+    pub const UNIT: Unit<LuminousIntensity> = Unit::new(
+        "kcd",
+        "kilocandela",
+        to_candela,
+        super::candela::to_kilocandela,
+    );
+
+    /// Creates a typed luminous intensity quantity expressed in kilocandela.
+    /// # Arguments
+    /// * `value` - The luminous intensity in kilocandela
+    pub fn new(value: f64) -> Quantity<LuminousIntensity> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed luminous intensity quantity in kilocandela.
+    /// # Arguments
+    /// * `quantity` - The luminous intensity quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<LuminousIntensity>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Hefnerkerze conversion functions
 pub mod hefnerkerze {
+    use crate::conversions::quantity::{LuminousIntensity, Quantity, Unit};
+
     /// Converts hefnerkerze to candela.
     /// # Arguments
     /// * `value` - The luminous intensity in hefnerkerze to convert
@@ -159,10 +233,34 @@ pub mod hefnerkerze {
     pub fn to_decimal_candle(value: f64) -> f64 {
         super::candela::to_decimal_candle(to_candela(value))
     }
+
+    /// The hefnerkerze as a typed unit of luminous intensity.
+    pub const UNIT: Unit<LuminousIntensity> = Unit::new(
+        "HK",
+        "hefnerkerze",
+        to_candela,
+        super::candela::to_hefnerkerze,
+    );
+
+    /// Creates a typed luminous intensity quantity expressed in hefnerkerze.
+    /// # Arguments
+    /// * `value` - The luminous intensity in hefnerkerze
+    pub fn new(value: f64) -> Quantity<LuminousIntensity> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed luminous intensity quantity in hefnerkerze.
+    /// # Arguments
+    /// * `quantity` - The luminous intensity quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<LuminousIntensity>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// International candle conversion functions
 pub mod international_candle {
+    use crate::conversions::quantity::{LuminousIntensity, Quantity, Unit};
+
     /// Converts international candle to candela.
     /// # Arguments
     /// * `value` - The luminous intensity in international candle to convert
@@ -199,10 +297,34 @@ pub mod international_candle {
     pub fn to_decimal_candle(value: f64) -> f64 {
         super::candela::to_decimal_candle(to_candela(value))
     }
+
+    /// The international candle as a typed unit of luminous intensity.
+    pub const UNIT: Unit<LuminousIntensity> = Unit::new(
+        "ic",
+        "international candle",
+        to_candela,
+        super::candela::to_international_candle,
+    );
+
+    /// Creates a typed luminous intensity quantity expressed in international candle.
+    /// # Arguments
+    /// * `value` - The luminous intensity in international candle
+    pub fn new(value: f64) -> Quantity<LuminousIntensity> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed luminous intensity quantity in international candle.
+    /// # Arguments
+    /// * `quantity` - The luminous intensity quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<LuminousIntensity>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Decimal candle conversion functions
 pub mod decimal_candle {
+    use crate::conversions::quantity::{LuminousIntensity, Quantity, Unit};
+
     /// Converts decimal candle to candela.
     /// # Arguments
     /// * `value` - The luminous intensity in decimal candle to convert
@@ -239,6 +361,28 @@ pub mod decimal_candle {
     pub fn to_international_candle(value: f64) -> f64 {
         super::candela::to_international_candle(to_candela(value))
     }
+
+    /// The decimal candle as a typed unit of luminous intensity.
+    pub const UNIT: Unit<LuminousIntensity> = Unit::new(
+        "dc",
+        "decimal candle",
+        to_candela,
+        super::candela::to_decimal_candle,
+    );
+
+    /// Creates a typed luminous intensity quantity expressed in decimal candle.
+    /// # Arguments
+    /// * `value` - The luminous intensity in decimal candle
+    pub fn new(value: f64) -> Quantity<LuminousIntensity> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed luminous intensity quantity in decimal candle.
+    /// # Arguments
+    /// * `quantity` - The luminous intensity quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<LuminousIntensity>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 // Legacy function wrappers for backward compatibility
@@ -269,7 +413,8 @@ pub fn hefnerkerze_to_candela(hefnerkerze: f64) -> f64 {
 /// General luminous intensity conversion function that accepts string unit names
 ///
 /// Converts a luminous intensity value from one unit to another using string identifiers.
-/// This function is case-insensitive and supports common abbreviations.
+/// Symbols are case-sensitive, names are case-insensitive, and common
+/// abbreviations are supported.
 ///
 /// # Arguments
 ///
@@ -279,7 +424,7 @@ pub fn hefnerkerze_to_candela(hefnerkerze: f64) -> f64 {
 ///
 /// # Returns
 /// * `Ok(f64)` - The converted value
-/// * `Err(String)` - Error message if the conversion is not supported
+/// * `Err(ConversionError)` - The reason the conversion is not supported
 ///
 /// # Examples
 ///
@@ -296,36 +441,50 @@ pub fn convert_luminous_intensity(
     value: f64,
     from_unit: &str,
     to_unit: &str,
-) -> Result<f64, String> {
-    let from_unit = from_unit.to_lowercase();
-    let to_unit = to_unit.to_lowercase();
-
-    // Convert input to candela first
-    let candela = match from_unit.as_str() {
-        "cd" | "candela" => value,
-        "mcd" | "millicandela" => millicandela::to_candela(value),
-        "kcd" | "kilocandela" => kilocandela::to_candela(value),
-        "hk" | "hefnerkerze" => hefnerkerze::to_candela(value),
-        "ic" | "international_candle" | "intl_candle" => international_candle::to_candela(value),
-        "dc" | "decimal_candle" => decimal_candle::to_candela(value),
-        _ => {
-            return Err(format!(
-                "Unsupported luminous intensity unit: {}",
-                from_unit
-            ))
-        }
-    };
-
-    // Convert candela to target unit
-    let result = match to_unit.as_str() {
-        "cd" | "candela" => candela,
-        "mcd" | "millicandela" => candela::to_millicandela(candela),
-        "kcd" | "kilocandela" => candela::to_kilocandela(candela),
-        "hk" | "hefnerkerze" => candela::to_hefnerkerze(candela),
-        "ic" | "international_candle" | "intl_candle" => candela::to_international_candle(candela),
-        "dc" | "decimal_candle" => candela::to_decimal_candle(candela),
-        _ => return Err(format!("Unsupported luminous intensity unit: {}", to_unit)),
-    };
-
-    Ok(result)
+) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from_unit, to_unit, Category::LuminousIntensity)
+}
+
+/// Luminous intensity units known to the [`UnitRegistry`], with candela as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new("cd", "candela", &[], Category::LuminousIntensity, 1.0).with_prefixes(),
+        UnitDef::new(
+            "mcd",
+            "millicandela",
+            &[],
+            Category::LuminousIntensity,
+            millicandela::to_candela(1.0),
+        ),
+        UnitDef::new(
+            "kcd",
+            "kilocandela",
+            &[],
+            Category::LuminousIntensity,
+            kilocandela::to_candela(1.0),
+        ),
+        UnitDef::new(
+            "hk",
+            "hefnerkerze",
+            &[],
+            Category::LuminousIntensity,
+            hefnerkerze::to_candela(1.0),
+        )
+        .with_exact_factor("1/1.11"),
+        UnitDef::new(
+            "ic",
+            "international candle",
+            &["international_candle", "intl_candle"],
+            Category::LuminousIntensity,
+            international_candle::to_candela(1.0),
+        )
+        .with_exact_factor("1/0.98"),
+        UnitDef::new(
+            "dc",
+            "decimal candle",
+            &["decimal_candle"],
+            Category::LuminousIntensity,
+            decimal_candle::to_candela(1.0),
+        ),
+    ]
 }
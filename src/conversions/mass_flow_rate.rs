@@ -0,0 +1,490 @@
+//! Mass flow rate conversion functions organized by unit type
+//! All conversions use kilograms per second as the base unit, composing the
+//! `weight` and `time` definitions rather than repeating them
+
+use super::category::Category;
+use super::error::ConversionError;
+use super::registry::{UnitDef, UnitRegistry};
+use super::{time, weight};
+
+/// Kilograms per second in one kilogram per hour
+fn kilograms_per_hour() -> f64 {
+    1.0 / time::hours::to_seconds(1.0)
+}
+
+/// Kilograms per second in one gram per second
+fn grams_per_second() -> f64 {
+    weight::grams::to_kilograms(1.0)
+}
+
+/// Kilograms per second in one metric ton per hour
+fn tonnes_per_hour() -> f64 {
+    weight::tons::to_kilograms(1.0) / time::hours::to_seconds(1.0)
+}
+
+/// Kilograms per second in one pound per second
+fn pounds_per_second() -> f64 {
+    weight::pounds::to_kilograms(1.0)
+}
+
+/// Kilograms per second in one pound per hour
+fn pounds_per_hour() -> f64 {
+    weight::pounds::to_kilograms(1.0) / time::hours::to_seconds(1.0)
+}
+
+/// Kilograms per second conversion functions
+pub mod kilograms_per_second {
+    use crate::conversions::quantity::{MassFlowRate, Quantity, Unit};
+
+    /// Converts kilograms per second to kilograms per hour.
+    /// # Arguments
+    /// * `value` - The mass flow rate in kilograms per second to convert
+    pub fn to_kilograms_per_hour(value: f64) -> f64 {
+        value / super::kilograms_per_hour()
+    }
+
+    /// Converts kilograms per second to grams per second.
+    /// # Arguments
+    /// * `value` - The mass flow rate in kilograms per second to convert
+    pub fn to_grams_per_second(value: f64) -> f64 {
+        value / super::grams_per_second()
+    }
+
+    /// Converts kilograms per second to metric tons per hour.
+    /// # Arguments
+    /// * `value` - The mass flow rate in kilograms per second to convert
+    pub fn to_tonnes_per_hour(value: f64) -> f64 {
+        value / super::tonnes_per_hour()
+    }
+
+    /// Converts kilograms per second to pounds per second.
+    /// # Arguments
+    /// * `value` - The mass flow rate in kilograms per second to convert
+    pub fn to_pounds_per_second(value: f64) -> f64 {
+        value / super::pounds_per_second()
+    }
+
+    /// Converts kilograms per second to pounds per hour.
+    /// # Arguments
+    /// * `value` - The mass flow rate in kilograms per second to convert
+    pub fn to_pounds_per_hour(value: f64) -> f64 {
+        value / super::pounds_per_hour()
+    }
+
+    /// The kilogram per second as a typed unit of mass flow rate.
+    pub const UNIT: Unit<MassFlowRate> =
+        Unit::new("kg/s", "kilogram per second", |value| value, |value| value);
+
+    /// Creates a typed mass flow rate quantity expressed in kilograms per second.
+    /// # Arguments
+    /// * `value` - The mass flow rate in kilograms per second
+    pub fn new(value: f64) -> Quantity<MassFlowRate> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed mass flow rate quantity in kilograms per second.
+    /// # Arguments
+    /// * `quantity` - The mass flow rate quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<MassFlowRate>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Kilograms per hour conversion functions
+pub mod kilograms_per_hour {
+    use crate::conversions::quantity::{MassFlowRate, Quantity, Unit};
+
+    /// Converts kilograms per hour to kilograms per second.
+    /// # Arguments
+    /// * `value` - The mass flow rate in kilograms per hour to convert
+    pub fn to_kilograms_per_second(value: f64) -> f64 {
+        value * super::kilograms_per_hour()
+    }
+
+    /// Converts kilograms per hour to grams per second.
+    /// # Arguments
+    /// * `value` - The mass flow rate in kilograms per hour to convert
+    pub fn to_grams_per_second(value: f64) -> f64 {
+        super::kilograms_per_second::to_grams_per_second(to_kilograms_per_second(value))
+    }
+
+    /// Converts kilograms per hour to metric tons per hour.
+    /// # Arguments
+    /// * `value` - The mass flow rate in kilograms per hour to convert
+    pub fn to_tonnes_per_hour(value: f64) -> f64 {
+        super::kilograms_per_second::to_tonnes_per_hour(to_kilograms_per_second(value))
+    }
+
+    /// Converts kilograms per hour to pounds per second.
+    /// # Arguments
+    /// * `value` - The mass flow rate in kilograms per hour to convert
+    pub fn to_pounds_per_second(value: f64) -> f64 {
+        super::kilograms_per_second::to_pounds_per_second(to_kilograms_per_second(value))
+    }
+
+    /// Converts kilograms per hour to pounds per hour.
+    /// # Arguments
+    /// * `value` - The mass flow rate in kilograms per hour to convert
+    pub fn to_pounds_per_hour(value: f64) -> f64 {
+        super::kilograms_per_second::to_pounds_per_hour(to_kilograms_per_second(value))
+    }
+
+    /// The kilogram per hour as a typed unit of mass flow rate.
+    pub const UNIT: Unit<MassFlowRate> = Unit::new(
+        "kg/h",
+        "kilogram per hour",
+        to_kilograms_per_second,
+        super::kilograms_per_second::to_kilograms_per_hour,
+    );
+
+    /// Creates a typed mass flow rate quantity expressed in kilograms per hour.
+    /// # Arguments
+    /// * `value` - The mass flow rate in kilograms per hour
+    pub fn new(value: f64) -> Quantity<MassFlowRate> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed mass flow rate quantity in kilograms per hour.
+    /// # Arguments
+    /// * `quantity` - The mass flow rate quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<MassFlowRate>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Grams per second conversion functions
+pub mod grams_per_second {
+    use crate::conversions::quantity::{MassFlowRate, Quantity, Unit};
+
+    /// Converts grams per second to kilograms per second.
+    /// # Arguments
+    /// * `value` - The mass flow rate in grams per second to convert
+    pub fn to_kilograms_per_second(value: f64) -> f64 {
+        value * super::grams_per_second()
+    }
+
+    /// Converts grams per second to kilograms per hour.
+    /// # Arguments
+    /// * `value` - The mass flow rate in grams per second to convert
+    pub fn to_kilograms_per_hour(value: f64) -> f64 {
+        super::kilograms_per_second::to_kilograms_per_hour(to_kilograms_per_second(value))
+    }
+
+    /// Converts grams per second to metric tons per hour.
+    /// # Arguments
+    /// * `value` - The mass flow rate in grams per second to convert
+    pub fn to_tonnes_per_hour(value: f64) -> f64 {
+        super::kilograms_per_second::to_tonnes_per_hour(to_kilograms_per_second(value))
+    }
+
+    /// Converts grams per second to pounds per second.
+    /// # Arguments
+    /// * `value` - The mass flow rate in grams per second to convert
+    pub fn to_pounds_per_second(value: f64) -> f64 {
+        super::kilograms_per_second::to_pounds_per_second(to_kilograms_per_second(value))
+    }
+
+    /// Converts grams per second to pounds per hour.
+    /// # Arguments
+    /// * `value` - The mass flow rate in grams per second to convert
+    pub fn to_pounds_per_hour(value: f64) -> f64 {
+        super::kilograms_per_second::to_pounds_per_hour(to_kilograms_per_second(value))
+    }
+
+    /// The gram per second as a typed unit of mass flow rate.
+    pub const UNIT: Unit<MassFlowRate> = Unit::new(
+        "g/s",
+        "gram per second",
+        to_kilograms_per_second,
+        super::kilograms_per_second::to_grams_per_second,
+    );
+
+    /// Creates a typed mass flow rate quantity expressed in grams per second.
+    /// # Arguments
+    /// * `value` - The mass flow rate in grams per second
+    pub fn new(value: f64) -> Quantity<MassFlowRate> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed mass flow rate quantity in grams per second.
+    /// # Arguments
+    /// * `quantity` - The mass flow rate quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<MassFlowRate>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Metric tons per hour conversion functions
+pub mod tonnes_per_hour {
+    use crate::conversions::quantity::{MassFlowRate, Quantity, Unit};
+
+    /// Converts metric tons per hour to kilograms per second.
+    /// # Arguments
+    /// * `value` - The mass flow rate in metric tons per hour to convert
+    pub fn to_kilograms_per_second(value: f64) -> f64 {
+        value * super::tonnes_per_hour()
+    }
+
+    /// Converts metric tons per hour to kilograms per hour.
+    /// # Arguments
+    /// * `value` - The mass flow rate in metric tons per hour to convert
+    pub fn to_kilograms_per_hour(value: f64) -> f64 {
+        super::kilograms_per_second::to_kilograms_per_hour(to_kilograms_per_second(value))
+    }
+
+    /// Converts metric tons per hour to grams per second.
+    /// # Arguments
+    /// * `value` - The mass flow rate in metric tons per hour to convert
+    pub fn to_grams_per_second(value: f64) -> f64 {
+        super::kilograms_per_second::to_grams_per_second(to_kilograms_per_second(value))
+    }
+
+    /// Converts metric tons per hour to pounds per second.
+    /// # Arguments
+    /// * `value` - The mass flow rate in metric tons per hour to convert
+    pub fn to_pounds_per_second(value: f64) -> f64 {
+        super::kilograms_per_second::to_pounds_per_second(to_kilograms_per_second(value))
+    }
+
+    /// Converts metric tons per hour to pounds per hour.
+    /// # Arguments
+    /// * `value` - The mass flow rate in metric tons per hour to convert
+    pub fn to_pounds_per_hour(value: f64) -> f64 {
+        super::kilograms_per_second::to_pounds_per_hour(to_kilograms_per_second(value))
+    }
+
+    /// The metric ton per hour as a typed unit of mass flow rate.
+    pub const UNIT: Unit<MassFlowRate> = Unit::new(
+        "t/h",
+        "metric ton per hour",
+        to_kilograms_per_second,
+        super::kilograms_per_second::to_tonnes_per_hour,
+    );
+
+    /// Creates a typed mass flow rate quantity expressed in metric tons per hour.
+    /// # Arguments
+    /// * `value` - The mass flow rate in metric tons per hour
+    pub fn new(value: f64) -> Quantity<MassFlowRate> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed mass flow rate quantity in metric tons per hour.
+    /// # Arguments
+    /// * `quantity` - The mass flow rate quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<MassFlowRate>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Pounds per second conversion functions
+pub mod pounds_per_second {
+    use crate::conversions::quantity::{MassFlowRate, Quantity, Unit};
+
+    /// Converts pounds per second to kilograms per second.
+    /// # Arguments
+    /// * `value` - The mass flow rate in pounds per second to convert
+    pub fn to_kilograms_per_second(value: f64) -> f64 {
+        value * super::pounds_per_second()
+    }
+
+    /// Converts pounds per second to kilograms per hour.
+    /// # Arguments
+    /// * `value` - The mass flow rate in pounds per second to convert
+    pub fn to_kilograms_per_hour(value: f64) -> f64 {
+        super::kilograms_per_second::to_kilograms_per_hour(to_kilograms_per_second(value))
+    }
+
+    /// Converts pounds per second to grams per second.
+    /// # Arguments
+    /// * `value` - The mass flow rate in pounds per second to convert
+    pub fn to_grams_per_second(value: f64) -> f64 {
+        super::kilograms_per_second::to_grams_per_second(to_kilograms_per_second(value))
+    }
+
+    /// Converts pounds per second to metric tons per hour.
+    /// # Arguments
+    /// * `value` - The mass flow rate in pounds per second to convert
+    pub fn to_tonnes_per_hour(value: f64) -> f64 {
+        super::kilograms_per_second::to_tonnes_per_hour(to_kilograms_per_second(value))
+    }
+
+    /// Converts pounds per second to pounds per hour.
+    /// # Arguments
+    /// * `value` - The mass flow rate in pounds per second to convert
+    pub fn to_pounds_per_hour(value: f64) -> f64 {
+        super::kilograms_per_second::to_pounds_per_hour(to_kilograms_per_second(value))
+    }
+
+    /// The pound per second as a typed unit of mass flow rate.
+    pub const UNIT: Unit<MassFlowRate> = Unit::new(
+        "lb/s",
+        "pound per second",
+        to_kilograms_per_second,
+        super::kilograms_per_second::to_pounds_per_second,
+    );
+
+    /// Creates a typed mass flow rate quantity expressed in pounds per second.
+    /// # Arguments
+    /// * `value` - The mass flow rate in pounds per second
+    pub fn new(value: f64) -> Quantity<MassFlowRate> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed mass flow rate quantity in pounds per second.
+    /// # Arguments
+    /// * `quantity` - The mass flow rate quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<MassFlowRate>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Pounds per hour conversion functions
+pub mod pounds_per_hour {
+    use crate::conversions::quantity::{MassFlowRate, Quantity, Unit};
+
+    /// Converts pounds per hour to kilograms per second.
+    /// # Arguments
+    /// * `value` - The mass flow rate in pounds per hour to convert
+    pub fn to_kilograms_per_second(value: f64) -> f64 {
+        value * super::pounds_per_hour()
+    }
+
+    /// Converts pounds per hour to kilograms per hour.
+    /// # Arguments
+    /// * `value` - The mass flow rate in pounds per hour to convert
+    pub fn to_kilograms_per_hour(value: f64) -> f64 {
+        super::kilograms_per_second::to_kilograms_per_hour(to_kilograms_per_second(value))
+    }
+
+    /// Converts pounds per hour to grams per second.
+    /// # Arguments
+    /// * `value` - The mass flow rate in pounds per hour to convert
+    pub fn to_grams_per_second(value: f64) -> f64 {
+        super::kilograms_per_second::to_grams_per_second(to_kilograms_per_second(value))
+    }
+
+    /// Converts pounds per hour to metric tons per hour.
+    /// # Arguments
+    /// * `value` - The mass flow rate in pounds per hour to convert
+    pub fn to_tonnes_per_hour(value: f64) -> f64 {
+        super::kilograms_per_second::to_tonnes_per_hour(to_kilograms_per_second(value))
+    }
+
+    /// Converts pounds per hour to pounds per second.
+    /// # Arguments
+    /// * `value` - The mass flow rate in pounds per hour to convert
+    pub fn to_pounds_per_second(value: f64) -> f64 {
+        super::kilograms_per_second::to_pounds_per_second(to_kilograms_per_second(value))
+    }
+
+    /// The pound per hour as a typed unit of mass flow rate.
+    pub const UNIT: Unit<MassFlowRate> = Unit::new(
+        "lb/h",
+        "pound per hour",
+        to_kilograms_per_second,
+        super::kilograms_per_second::to_pounds_per_hour,
+    );
+
+    /// Creates a typed mass flow rate quantity expressed in pounds per hour.
+    /// # Arguments
+    /// * `value` - The mass flow rate in pounds per hour
+    pub fn new(value: f64) -> Quantity<MassFlowRate> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed mass flow rate quantity in pounds per hour.
+    /// # Arguments
+    /// * `quantity` - The mass flow rate quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<MassFlowRate>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// General mass flow rate conversion function that accepts string unit names
+///
+/// Converts a mass flow rate from one unit to another using string
+/// identifiers. Symbols are case-sensitive, names are case-insensitive, and
+/// common abbreviations are supported.
+///
+/// # Arguments
+///
+/// * `value` - The numeric value to convert
+/// * `from_unit` - The source unit (e.g., "kg/s", "kg/h", "g/s", "t/h", "lb/s", "lb/h")
+/// * `to_unit` - The target unit using the same abbreviations
+///
+/// # Returns
+/// * `Ok(f64)` - The converted value
+/// * `Err(ConversionError)` - The reason the conversion is not supported
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::convert_mass_flow_rate;
+///
+/// let pounds_per_hour = convert_mass_flow_rate(1.0, "kg/s", "lb/h").unwrap();
+/// assert!((pounds_per_hour - 7936.641).abs() < 0.001);
+/// ```
+pub fn convert_mass_flow_rate(
+    value: f64,
+    from_unit: &str,
+    to_unit: &str,
+) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from_unit, to_unit, Category::MassFlowRate)
+}
+
+/// Mass flow rate units known to the [`UnitRegistry`], with kilograms per second
+/// as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new(
+            "kg/s",
+            "kilogram per second",
+            &["kilograms per second", "kilograms_per_second"],
+            Category::MassFlowRate,
+            1.0,
+        ),
+        UnitDef::new(
+            "kg/h",
+            "kilogram per hour",
+            &["kg/hr", "kilograms per hour", "kilograms_per_hour"],
+            Category::MassFlowRate,
+            kilograms_per_hour::to_kilograms_per_second(1.0),
+        )
+        .with_exact_factor("1/3600"),
+        UnitDef::new(
+            "g/s",
+            "gram per second",
+            &["grams per second", "grams_per_second"],
+            Category::MassFlowRate,
+            grams_per_second::to_kilograms_per_second(1.0),
+        ),
+        UnitDef::new(
+            "t/h",
+            "metric ton per hour",
+            &[
+                "tph",
+                "tonnes per hour",
+                "metric tons per hour",
+                "tonnes_per_hour",
+            ],
+            Category::MassFlowRate,
+            tonnes_per_hour::to_kilograms_per_second(1.0),
+        )
+        .with_exact_factor("1000/3600"),
+        UnitDef::new(
+            "lb/s",
+            "pound per second",
+            &["pounds per second", "pounds_per_second"],
+            Category::MassFlowRate,
+            pounds_per_second::to_kilograms_per_second(1.0),
+        ),
+        UnitDef::new(
+            "lb/h",
+            "pound per hour",
+            &["lb/hr", "pph", "pounds per hour", "pounds_per_hour"],
+            Category::MassFlowRate,
+            pounds_per_hour::to_kilograms_per_second(1.0),
+        )
+        .with_exact_factor("0.45359237/3600"),
+    ]
+}
@@ -2,7 +2,11 @@
 //!
 //! This module provides comprehensive unit conversion functionality across
 //! SI base units and derived units including: length, weight/mass, temperature,
-//! volume, time, electric current, amount of substance, luminous intensity, and area.
+//! volume, time, electric current, amount of substance, luminous intensity, area,
+//! speed, pressure, energy, power, force, torque, frequency, plane and solid
+//! angle, data, data rate, density, volumetric and mass flow rate, voltage,
+//! resistance, conductance, charge, capacitance, inductance, luminous flux,
+//! illuminance, luminance and temperature intervals.
 //!
 //! # Usage
 //!
@@ -16,6 +20,10 @@
 //! let feet = meters_to_feet(10.0);
 //! let pounds = kilograms_to_pounds(5.0);
 //!
+//! // Letting the library infer the category from the units
+//! let result = convert(3.0, "ft", "m").unwrap();
+//! let result = convert(20.0, "mA", "A").unwrap();
+//!
 //! // Using general conversion functions
 //! let result = convert_length(100.0, "ft", "m").unwrap();
 //! let result = convert_weight(10.0, "kg", "lb").unwrap();
@@ -26,14 +34,30 @@
 //! let result = convert_amount(0.5, "mol", "mmol").unwrap();
 //! let result = convert_luminous_intensity(2.5, "cd", "mcd").unwrap();
 //! let result = convert_area(10000.0, "m²", "ha").unwrap();
+//! let result = convert_speed(100.0, "km/h", "mph").unwrap();
+//! let result = convert_pressure(32.0, "psig", "kPa").unwrap();
+//! let result = convert_energy(1.0, "kWh", "MJ").unwrap();
+//! let result = convert_power(20.0, "dBm", "W").unwrap();
+//! let result = convert_force(10.0, "lbf", "N").unwrap();
+//! let result = convert_torque(100.0, "lbf·ft", "N·m").unwrap();
+//! let result = convert_frequency(3000.0, "rpm", "Hz").unwrap();
+//! let result = convert_angle(180.0, "deg", "rad").unwrap();
+//! let result = convert_data(1.0, "GiB", "MB").unwrap();
+//! let result = convert_density(39.6, "°API", "SG").unwrap();
+//! let result = convert_flow_rate(100.0, "gpm", "L/s").unwrap();
+//! let result = convert_charge(3000.0, "mAh", "C").unwrap();
+//! let result = convert_illuminance(50.0, "fc", "lx").unwrap();
+//! let result = convert_temperature_interval(10.0, "ΔC", "ΔF").unwrap();
 //! ```
 //!
 //! # Modules
 //!
 //! ## SI Base Units
-//! * [`length`] - Length and distance conversions (meters, feet, inches, etc.)
+//! * [`length`] - Length and distance conversions (meters, feet, inches, nm, Å, au, ly, pc, nmi,
+//!   chains, US survey feet, etc.)
 //! * [`weight`] - Weight and mass conversions (kilograms, pounds, ounces, etc.)
-//! * [`temperature`] - Temperature conversions (Celsius, Fahrenheit, Kelvin)
+//! * [`temperature`] - Temperature conversions (Celsius, Fahrenheit, Kelvin, Rankine,
+//!   Réaumur, Delisle, Newton, Rømer) and temperature differences (ΔC, ΔF, ΔK, ΔR)
 //! * [`time`] - Time conversions (seconds, minutes, hours, days, etc.)
 //! * [`current`] - Electric current conversions (amperes and SI multiples)
 //! * [`substance`] - Amount of substance conversions (moles and SI multiples)
@@ -42,23 +66,129 @@
 //! ## SI Derived Units
 //! * [`volume`] - Volume conversions (liters, gallons, cups, etc.)
 //! * [`area`] - Area conversions (square meters, acres, hectares, etc.)
+//! * [`speed`] - Speed conversions (m/s, km/h, mph, knots, ft/s, Mach)
+//! * [`pressure`] - Pressure conversions (Pa, bar, atm, psi/psig, torr, mmHg, etc.)
+//! * [`energy`] - Energy conversions (J, calories, Wh, kWh, BTU, eV, etc.)
+//! * [`power`] - Power conversions (W, horsepower, BTU/h, tons of refrigeration, dBm, etc.)
+//! * [`force`] - Force conversions (N, kN, dyn, lbf, kgf, kip, poundal)
+//! * [`torque`] - Torque conversions (N·m, lbf·ft, lbf·in, kgf·m, dyn·cm)
+//! * [`frequency`] - Frequency conversions (Hz, kHz, MHz, GHz, THz, rpm, rad/s, cpm, cph)
+//! * [`angle`] - Plane angle conversions (°, rad, gon, tr, arcmin, arcsec, mrad, mil) and DMS notation
+//! * [`solid_angle`] - Solid angle conversions (sr, deg², spat)
+//! * [`data`] - Digital storage conversions (bit, B, kB, MB, GB, TB, KiB, MiB, GiB, TiB) and best-unit formatting
+//! * [`data_rate`] - Data rate conversions (bit/s, kbit/s, Mbit/s, Gbit/s, B/s, MB/s, MiB/s, GiB/s)
+//! * [`density`] - Density conversions (kg/m³, g/cm³, lb/ft³, lb/in³, lb/gal, oz/in³, SG, °API, °Bé)
+//! * [`flow_rate`] - Volumetric flow rate conversions (L/s, L/min, m³/s, m³/h, gpm, CFM, bbl/d)
+//! * [`mass_flow_rate`] - Mass flow rate conversions (kg/s, kg/h, g/s, t/h, lb/s, lb/h)
+//! * [`voltage`] - Voltage conversions (V, mV, kV, abV, statV)
+//! * [`resistance`] - Electrical resistance conversions (Ω, mΩ, kΩ, MΩ)
+//! * [`conductance`] - Electrical conductance conversions (S, mS, μS, mho)
+//! * [`charge`] - Electric charge conversions (C, mAh, Ah, e)
+//! * [`capacitance`] - Capacitance conversions (F, μF, nF, pF)
+//! * [`inductance`] - Inductance conversions (H, mH, μH)
+//! * [`luminous_flux`] - Luminous flux conversions (lm, klm) and candela ↔ lumen over a beam solid angle
+//! * [`illuminance`] - Illuminance conversions (lx, fc, ph) and lux ↔ candela by the inverse-square law
+//! * [`luminance`] - Luminance conversions (cd/m², fL, La, sb)
+//!
+//! ## General Conversions
+//! * [`general`] - [`convert`], which infers the category from the units, and
+//!   [`convert_in`], which converts within a known category
+//!
+//! ## Errors
+//! * [`error`] - [`ConversionError`], the structured error returned by every
+//!   `convert_*` function
+//! * [`category`] - [`Category`], the kind of quantity a unit measures
+//!
+//! ## Unit Registry
+//! * [`registry`] - [`UnitRegistry`], the single source of truth for unit
+//!   symbols, aliases and conversion factors
+//! * [`prefix`] - [`SI_PREFIXES`], the SI prefixes the registry applies to
+//!   coherent units
+//!
+//! ## Exact Conversions
+//! * `exact` - Arbitrary-precision rational conversions (requires the `exact`
+//!   cargo feature)
+//!
+//! ## Typed Quantities
+//! * [`quantity`] - `Quantity<D>` values that carry their unit and can only be
+//!   converted within their own dimension
 
+pub mod angle;
 pub mod area;
+pub mod capacitance;
+pub mod category;
+pub mod charge;
+pub mod conductance;
 pub mod current;
+pub mod data;
+pub mod data_rate;
+pub mod density;
+pub mod energy;
+pub mod error;
+#[cfg(feature = "exact")]
+pub mod exact;
+pub mod flow_rate;
+pub mod force;
+pub mod frequency;
+pub mod general;
+pub mod illuminance;
+pub mod inductance;
 pub mod length;
+pub mod luminance;
+pub mod luminous_flux;
 pub mod luminous_intensity;
+pub mod mass_flow_rate;
+pub mod power;
+pub mod prefix;
+pub mod pressure;
+pub mod quantity;
+pub mod registry;
+pub mod resistance;
+pub mod solid_angle;
+pub mod speed;
 pub mod substance;
 pub mod temperature;
 pub mod time;
+pub mod torque;
+pub mod voltage;
 pub mod volume;
 pub mod weight;
 
+pub use angle::*;
 pub use area::*;
+pub use capacitance::*;
+pub use category::*;
+pub use charge::*;
+pub use conductance::*;
 pub use current::*;
+pub use data::*;
+pub use data_rate::*;
+pub use density::*;
+pub use energy::*;
+pub use error::*;
+pub use flow_rate::*;
+pub use force::*;
+pub use frequency::*;
+pub use general::*;
+pub use illuminance::*;
+pub use inductance::*;
 pub use length::*;
+pub use luminance::*;
+pub use luminous_flux::*;
 pub use luminous_intensity::*;
+pub use mass_flow_rate::*;
+pub use power::*;
+pub use prefix::*;
+pub use pressure::*;
+pub use quantity::*;
+pub use registry::*;
+pub use resistance::*;
+pub use solid_angle::*;
+pub use speed::*;
 pub use substance::*;
 pub use temperature::*;
 pub use time::*;
+pub use torque::*;
+pub use voltage::*;
 pub use volume::*;
 pub use weight::*;
@@ -0,0 +1,1204 @@
+//! Power conversion functions organized by unit type
+//! All conversions use watts as the base unit for accuracy and consistency
+
+use super::category::Category;
+use super::energy::{JOULES_PER_BTU, JOULES_PER_FOOT_POUND};
+use super::error::ConversionError;
+use super::pressure::STANDARD_GRAVITY;
+use super::registry::{UnitDef, UnitRegistry};
+
+/// Watts in one mechanical (imperial) horsepower, 550 foot-pounds-force per
+/// second
+pub const WATTS_PER_MECHANICAL_HORSEPOWER: f64 = 550.0 * JOULES_PER_FOOT_POUND;
+
+/// Watts in one metric horsepower (PS, CV, pk), 75 kilogram-force meters per
+/// second
+pub const WATTS_PER_METRIC_HORSEPOWER: f64 = 75.0 * STANDARD_GRAVITY;
+
+/// Watts in one electrical horsepower (exactly 746 W)
+pub const WATTS_PER_ELECTRICAL_HORSEPOWER: f64 = 746.0;
+
+/// Watts in one international table BTU per hour
+pub const WATTS_PER_BTU_PER_HOUR: f64 = JOULES_PER_BTU / 3600.0;
+
+/// Reference power of the dBm scale in watts (one milliwatt)
+pub const DBM_REFERENCE: f64 = 0.001;
+
+/// Watt conversion functions
+pub mod watts {
+    use crate::conversions::quantity::{Power, Quantity, Unit};
+
+    /// Converts watts to kilowatts.
+    /// # Arguments
+    /// * `value` - The power in watts to convert
+    pub fn to_kilowatts(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// Converts watts to megawatts.
+    /// # Arguments
+    /// * `value` - The power in watts to convert
+    pub fn to_megawatts(value: f64) -> f64 {
+        value / 1_000_000.0
+    }
+
+    /// Converts watts to mechanical horsepower.
+    /// # Arguments
+    /// * `value` - The power in watts to convert
+    pub fn to_horsepower(value: f64) -> f64 {
+        value / super::WATTS_PER_MECHANICAL_HORSEPOWER
+    }
+
+    /// Converts watts to metric horsepower.
+    /// # Arguments
+    /// * `value` - The power in watts to convert
+    pub fn to_metric_horsepower(value: f64) -> f64 {
+        value / super::WATTS_PER_METRIC_HORSEPOWER
+    }
+
+    /// Converts watts to electrical horsepower.
+    /// # Arguments
+    /// * `value` - The power in watts to convert
+    pub fn to_electrical_horsepower(value: f64) -> f64 {
+        value / super::WATTS_PER_ELECTRICAL_HORSEPOWER
+    }
+
+    /// Converts watts to BTU per hour.
+    /// # Arguments
+    /// * `value` - The power in watts to convert
+    pub fn to_btu_per_hour(value: f64) -> f64 {
+        value / super::WATTS_PER_BTU_PER_HOUR
+    }
+
+    /// Converts watts to tons of refrigeration.
+    /// # Arguments
+    /// * `value` - The power in watts to convert
+    pub fn to_tons_of_refrigeration(value: f64) -> f64 {
+        value / super::WATTS_PER_BTU_PER_HOUR / 12_000.0
+    }
+
+    /// Converts watts to ergs per second.
+    /// # Arguments
+    /// * `value` - The power in watts to convert
+    pub fn to_ergs_per_second(value: f64) -> f64 {
+        value * 1e7
+    }
+
+    /// Converts watts to dBm.
+    /// # Arguments
+    /// * `value` - The power in watts to convert
+    pub fn to_dbm(value: f64) -> f64 {
+        10.0 * (value / super::DBM_REFERENCE).log10()
+    }
+
+    /// Converts watts to dBW.
+    /// # Arguments
+    /// * `value` - The power in watts to convert
+    pub fn to_dbw(value: f64) -> f64 {
+        10.0 * value.log10()
+    }
+
+    /// The watt as a typed unit of power.
+    pub const UNIT: Unit<Power> = Unit::new("W", "watt", |value| value, |value| value);
+
+    /// Creates a typed power quantity expressed in watts.
+    /// # Arguments
+    /// * `value` - The power in watts
+    pub fn new(value: f64) -> Quantity<Power> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed power quantity in watts.
+    /// # Arguments
+    /// * `quantity` - The power quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Power>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Kilowatt conversion functions
+pub mod kilowatts {
+    use crate::conversions::quantity::{Power, Quantity, Unit};
+
+    /// Converts kilowatts to watts.
+    /// # Arguments
+    /// * `value` - The power in kilowatts to convert
+    pub fn to_watts(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// Converts kilowatts to megawatts.
+    /// # Arguments
+    /// * `value` - The power in kilowatts to convert
+    pub fn to_megawatts(value: f64) -> f64 {
+        super::watts::to_megawatts(to_watts(value))
+    }
+
+    /// Converts kilowatts to mechanical horsepower.
+    /// # Arguments
+    /// * `value` - The power in kilowatts to convert
+    pub fn to_horsepower(value: f64) -> f64 {
+        super::watts::to_horsepower(to_watts(value))
+    }
+
+    /// Converts kilowatts to metric horsepower.
+    /// # Arguments
+    /// * `value` - The power in kilowatts to convert
+    pub fn to_metric_horsepower(value: f64) -> f64 {
+        super::watts::to_metric_horsepower(to_watts(value))
+    }
+
+    /// Converts kilowatts to electrical horsepower.
+    /// # Arguments
+    /// * `value` - The power in kilowatts to convert
+    pub fn to_electrical_horsepower(value: f64) -> f64 {
+        super::watts::to_electrical_horsepower(to_watts(value))
+    }
+
+    /// Converts kilowatts to BTU per hour.
+    /// # Arguments
+    /// * `value` - The power in kilowatts to convert
+    pub fn to_btu_per_hour(value: f64) -> f64 {
+        super::watts::to_btu_per_hour(to_watts(value))
+    }
+
+    /// Converts kilowatts to tons of refrigeration.
+    /// # Arguments
+    /// * `value` - The power in kilowatts to convert
+    pub fn to_tons_of_refrigeration(value: f64) -> f64 {
+        super::watts::to_tons_of_refrigeration(to_watts(value))
+    }
+
+    /// Converts kilowatts to ergs per second.
+    /// # Arguments
+    /// * `value` - The power in kilowatts to convert
+    pub fn to_ergs_per_second(value: f64) -> f64 {
+        super::watts::to_ergs_per_second(to_watts(value))
+    }
+
+    /// Converts kilowatts to dBm.
+    /// # Arguments
+    /// * `value` - The power in kilowatts to convert
+    pub fn to_dbm(value: f64) -> f64 {
+        super::watts::to_dbm(to_watts(value))
+    }
+
+    /// Converts kilowatts to dBW.
+    /// # Arguments
+    /// * `value` - The power in kilowatts to convert
+    pub fn to_dbw(value: f64) -> f64 {
+        super::watts::to_dbw(to_watts(value))
+    }
+
+    /// The kilowatt as a typed unit of power.
+    pub const UNIT: Unit<Power> = Unit::new("kW", "kilowatt", to_watts, super::watts::to_kilowatts);
+
+    /// Creates a typed power quantity expressed in kilowatts.
+    /// # Arguments
+    /// * `value` - The power in kilowatts
+    pub fn new(value: f64) -> Quantity<Power> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed power quantity in kilowatts.
+    /// # Arguments
+    /// * `quantity` - The power quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Power>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Megawatt conversion functions
+pub mod megawatts {
+    use crate::conversions::quantity::{Power, Quantity, Unit};
+
+    /// Converts megawatts to watts.
+    /// # Arguments
+    /// * `value` - The power in megawatts to convert
+    pub fn to_watts(value: f64) -> f64 {
+        value * 1_000_000.0
+    }
+
+    /// Converts megawatts to kilowatts.
+    /// # Arguments
+    /// * `value` - The power in megawatts to convert
+    pub fn to_kilowatts(value: f64) -> f64 {
+        super::watts::to_kilowatts(to_watts(value))
+    }
+
+    /// Converts megawatts to mechanical horsepower.
+    /// # Arguments
+    /// * `value` - The power in megawatts to convert
+    pub fn to_horsepower(value: f64) -> f64 {
+        super::watts::to_horsepower(to_watts(value))
+    }
+
+    /// Converts megawatts to metric horsepower.
+    /// # Arguments
+    /// * `value` - The power in megawatts to convert
+    pub fn to_metric_horsepower(value: f64) -> f64 {
+        super::watts::to_metric_horsepower(to_watts(value))
+    }
+
+    /// Converts megawatts to electrical horsepower.
+    /// # Arguments
+    /// * `value` - The power in megawatts to convert
+    pub fn to_electrical_horsepower(value: f64) -> f64 {
+        super::watts::to_electrical_horsepower(to_watts(value))
+    }
+
+    /// Converts megawatts to BTU per hour.
+    /// # Arguments
+    /// * `value` - The power in megawatts to convert
+    pub fn to_btu_per_hour(value: f64) -> f64 {
+        super::watts::to_btu_per_hour(to_watts(value))
+    }
+
+    /// Converts megawatts to tons of refrigeration.
+    /// # Arguments
+    /// * `value` - The power in megawatts to convert
+    pub fn to_tons_of_refrigeration(value: f64) -> f64 {
+        super::watts::to_tons_of_refrigeration(to_watts(value))
+    }
+
+    /// Converts megawatts to ergs per second.
+    /// # Arguments
+    /// * `value` - The power in megawatts to convert
+    pub fn to_ergs_per_second(value: f64) -> f64 {
+        super::watts::to_ergs_per_second(to_watts(value))
+    }
+
+    /// Converts megawatts to dBm.
+    /// # Arguments
+    /// * `value` - The power in megawatts to convert
+    pub fn to_dbm(value: f64) -> f64 {
+        super::watts::to_dbm(to_watts(value))
+    }
+
+    /// Converts megawatts to dBW.
+    /// # Arguments
+    /// * `value` - The power in megawatts to convert
+    pub fn to_dbw(value: f64) -> f64 {
+        super::watts::to_dbw(to_watts(value))
+    }
+
+    /// The megawatt as a typed unit of power.
+    pub const UNIT: Unit<Power> = Unit::new("MW", "megawatt", to_watts, super::watts::to_megawatts);
+
+    /// Creates a typed power quantity expressed in megawatts.
+    /// # Arguments
+    /// * `value` - The power in megawatts
+    pub fn new(value: f64) -> Quantity<Power> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed power quantity in megawatts.
+    /// # Arguments
+    /// * `quantity` - The power quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Power>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Mechanical (imperial) horsepower conversion functions (550 ft·lbf/s)
+pub mod horsepower {
+    use crate::conversions::quantity::{Power, Quantity, Unit};
+
+    /// Converts mechanical horsepower to watts.
+    /// # Arguments
+    /// * `value` - The power in mechanical horsepower to convert
+    pub fn to_watts(value: f64) -> f64 {
+        value * super::WATTS_PER_MECHANICAL_HORSEPOWER
+    }
+
+    /// Converts mechanical horsepower to kilowatts.
+    /// # Arguments
+    /// * `value` - The power in mechanical horsepower to convert
+    pub fn to_kilowatts(value: f64) -> f64 {
+        super::watts::to_kilowatts(to_watts(value))
+    }
+
+    /// Converts mechanical horsepower to megawatts.
+    /// # Arguments
+    /// * `value` - The power in mechanical horsepower to convert
+    pub fn to_megawatts(value: f64) -> f64 {
+        super::watts::to_megawatts(to_watts(value))
+    }
+
+    /// Converts mechanical horsepower to metric horsepower.
+    /// # Arguments
+    /// * `value` - The power in mechanical horsepower to convert
+    pub fn to_metric_horsepower(value: f64) -> f64 {
+        super::watts::to_metric_horsepower(to_watts(value))
+    }
+
+    /// Converts mechanical horsepower to electrical horsepower.
+    /// # Arguments
+    /// * `value` - The power in mechanical horsepower to convert
+    pub fn to_electrical_horsepower(value: f64) -> f64 {
+        super::watts::to_electrical_horsepower(to_watts(value))
+    }
+
+    /// Converts mechanical horsepower to BTU per hour.
+    /// # Arguments
+    /// * `value` - The power in mechanical horsepower to convert
+    pub fn to_btu_per_hour(value: f64) -> f64 {
+        super::watts::to_btu_per_hour(to_watts(value))
+    }
+
+    /// Converts mechanical horsepower to tons of refrigeration.
+    /// # Arguments
+    /// * `value` - The power in mechanical horsepower to convert
+    pub fn to_tons_of_refrigeration(value: f64) -> f64 {
+        super::watts::to_tons_of_refrigeration(to_watts(value))
+    }
+
+    /// Converts mechanical horsepower to ergs per second.
+    /// # Arguments
+    /// * `value` - The power in mechanical horsepower to convert
+    pub fn to_ergs_per_second(value: f64) -> f64 {
+        super::watts::to_ergs_per_second(to_watts(value))
+    }
+
+    /// Converts mechanical horsepower to dBm.
+    /// # Arguments
+    /// * `value` - The power in mechanical horsepower to convert
+    pub fn to_dbm(value: f64) -> f64 {
+        super::watts::to_dbm(to_watts(value))
+    }
+
+    /// Converts mechanical horsepower to dBW.
+    /// # Arguments
+    /// * `value` - The power in mechanical horsepower to convert
+    pub fn to_dbw(value: f64) -> f64 {
+        super::watts::to_dbw(to_watts(value))
+    }
+
+    /// The mechanical horsepower as a typed unit of power.
+    pub const UNIT: Unit<Power> = Unit::new(
+        "hp",
+        "mechanical horsepower",
+        to_watts,
+        super::watts::to_horsepower,
+    );
+
+    /// Creates a typed power quantity expressed in mechanical horsepower.
+    /// # Arguments
+    /// * `value` - The power in mechanical horsepower
+    pub fn new(value: f64) -> Quantity<Power> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed power quantity in mechanical horsepower.
+    /// # Arguments
+    /// * `quantity` - The power quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Power>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Metric horsepower conversion functions (75 kgf·m/s)
+pub mod metric_horsepower {
+    use crate::conversions::quantity::{Power, Quantity, Unit};
+
+    /// Converts metric horsepower to watts.
+    /// # Arguments
+    /// * `value` - The power in metric horsepower to convert
+    pub fn to_watts(value: f64) -> f64 {
+        value * super::WATTS_PER_METRIC_HORSEPOWER
+    }
+
+    /// Converts metric horsepower to kilowatts.
+    /// # Arguments
+    /// * `value` - The power in metric horsepower to convert
+    pub fn to_kilowatts(value: f64) -> f64 {
+        super::watts::to_kilowatts(to_watts(value))
+    }
+
+    /// Converts metric horsepower to megawatts.
+    /// # Arguments
+    /// * `value` - The power in metric horsepower to convert
+    pub fn to_megawatts(value: f64) -> f64 {
+        super::watts::to_megawatts(to_watts(value))
+    }
+
+    /// Converts metric horsepower to mechanical horsepower.
+    /// # Arguments
+    /// * `value` - The power in metric horsepower to convert
+    pub fn to_horsepower(value: f64) -> f64 {
+        super::watts::to_horsepower(to_watts(value))
+    }
+
+    /// Converts metric horsepower to electrical horsepower.
+    /// # Arguments
+    /// * `value` - The power in metric horsepower to convert
+    pub fn to_electrical_horsepower(value: f64) -> f64 {
+        super::watts::to_electrical_horsepower(to_watts(value))
+    }
+
+    /// Converts metric horsepower to BTU per hour.
+    /// # Arguments
+    /// * `value` - The power in metric horsepower to convert
+    pub fn to_btu_per_hour(value: f64) -> f64 {
+        super::watts::to_btu_per_hour(to_watts(value))
+    }
+
+    /// Converts metric horsepower to tons of refrigeration.
+    /// # Arguments
+    /// * `value` - The power in metric horsepower to convert
+    pub fn to_tons_of_refrigeration(value: f64) -> f64 {
+        super::watts::to_tons_of_refrigeration(to_watts(value))
+    }
+
+    /// Converts metric horsepower to ergs per second.
+    /// # Arguments
+    /// * `value` - The power in metric horsepower to convert
+    pub fn to_ergs_per_second(value: f64) -> f64 {
+        super::watts::to_ergs_per_second(to_watts(value))
+    }
+
+    /// Converts metric horsepower to dBm.
+    /// # Arguments
+    /// * `value` - The power in metric horsepower to convert
+    pub fn to_dbm(value: f64) -> f64 {
+        super::watts::to_dbm(to_watts(value))
+    }
+
+    /// Converts metric horsepower to dBW.
+    /// # Arguments
+    /// * `value` - The power in metric horsepower to convert
+    pub fn to_dbw(value: f64) -> f64 {
+        super::watts::to_dbw(to_watts(value))
+    }
+
+    /// The metric horsepower as a typed unit of power.
+    pub const UNIT: Unit<Power> = Unit::new(
+        "PS",
+        "metric horsepower",
+        to_watts,
+        super::watts::to_metric_horsepower,
+    );
+
+    /// Creates a typed power quantity expressed in metric horsepower.
+    /// # Arguments
+    /// * `value` - The power in metric horsepower
+    pub fn new(value: f64) -> Quantity<Power> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed power quantity in metric horsepower.
+    /// # Arguments
+    /// * `quantity` - The power quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Power>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Electrical horsepower conversion functions (exactly 746 W)
+pub mod electrical_horsepower {
+    use crate::conversions::quantity::{Power, Quantity, Unit};
+
+    /// Converts electrical horsepower to watts.
+    /// # Arguments
+    /// * `value` - The power in electrical horsepower to convert
+    pub fn to_watts(value: f64) -> f64 {
+        value * super::WATTS_PER_ELECTRICAL_HORSEPOWER
+    }
+
+    /// Converts electrical horsepower to kilowatts.
+    /// # Arguments
+    /// * `value` - The power in electrical horsepower to convert
+    pub fn to_kilowatts(value: f64) -> f64 {
+        super::watts::to_kilowatts(to_watts(value))
+    }
+
+    /// Converts electrical horsepower to megawatts.
+    /// # Arguments
+    /// * `value` - The power in electrical horsepower to convert
+    pub fn to_megawatts(value: f64) -> f64 {
+        super::watts::to_megawatts(to_watts(value))
+    }
+
+    /// Converts electrical horsepower to mechanical horsepower.
+    /// # Arguments
+    /// * `value` - The power in electrical horsepower to convert
+    pub fn to_horsepower(value: f64) -> f64 {
+        super::watts::to_horsepower(to_watts(value))
+    }
+
+    /// Converts electrical horsepower to metric horsepower.
+    /// # Arguments
+    /// * `value` - The power in electrical horsepower to convert
+    pub fn to_metric_horsepower(value: f64) -> f64 {
+        super::watts::to_metric_horsepower(to_watts(value))
+    }
+
+    /// Converts electrical horsepower to BTU per hour.
+    /// # Arguments
+    /// * `value` - The power in electrical horsepower to convert
+    pub fn to_btu_per_hour(value: f64) -> f64 {
+        super::watts::to_btu_per_hour(to_watts(value))
+    }
+
+    /// Converts electrical horsepower to tons of refrigeration.
+    /// # Arguments
+    /// * `value` - The power in electrical horsepower to convert
+    pub fn to_tons_of_refrigeration(value: f64) -> f64 {
+        super::watts::to_tons_of_refrigeration(to_watts(value))
+    }
+
+    /// Converts electrical horsepower to ergs per second.
+    /// # Arguments
+    /// * `value` - The power in electrical horsepower to convert
+    pub fn to_ergs_per_second(value: f64) -> f64 {
+        super::watts::to_ergs_per_second(to_watts(value))
+    }
+
+    /// Converts electrical horsepower to dBm.
+    /// # Arguments
+    /// * `value` - The power in electrical horsepower to convert
+    pub fn to_dbm(value: f64) -> f64 {
+        super::watts::to_dbm(to_watts(value))
+    }
+
+    /// Converts electrical horsepower to dBW.
+    /// # Arguments
+    /// * `value` - The power in electrical horsepower to convert
+    pub fn to_dbw(value: f64) -> f64 {
+        super::watts::to_dbw(to_watts(value))
+    }
+
+    /// The electrical horsepower as a typed unit of power.
+    pub const UNIT: Unit<Power> = Unit::new(
+        "hp_E",
+        "electrical horsepower",
+        to_watts,
+        super::watts::to_electrical_horsepower,
+    );
+
+    /// Creates a typed power quantity expressed in electrical horsepower.
+    /// # Arguments
+    /// * `value` - The power in electrical horsepower
+    pub fn new(value: f64) -> Quantity<Power> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed power quantity in electrical horsepower.
+    /// # Arguments
+    /// * `quantity` - The power quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Power>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// BTU per hour conversion functions
+pub mod btu_per_hour {
+    use crate::conversions::quantity::{Power, Quantity, Unit};
+
+    /// Converts BTU per hour to watts.
+    /// # Arguments
+    /// * `value` - The power in BTU per hour to convert
+    pub fn to_watts(value: f64) -> f64 {
+        value * super::WATTS_PER_BTU_PER_HOUR
+    }
+
+    /// Converts BTU per hour to kilowatts.
+    /// # Arguments
+    /// * `value` - The power in BTU per hour to convert
+    pub fn to_kilowatts(value: f64) -> f64 {
+        super::watts::to_kilowatts(to_watts(value))
+    }
+
+    /// Converts BTU per hour to megawatts.
+    /// # Arguments
+    /// * `value` - The power in BTU per hour to convert
+    pub fn to_megawatts(value: f64) -> f64 {
+        super::watts::to_megawatts(to_watts(value))
+    }
+
+    /// Converts BTU per hour to mechanical horsepower.
+    /// # Arguments
+    /// * `value` - The power in BTU per hour to convert
+    pub fn to_horsepower(value: f64) -> f64 {
+        super::watts::to_horsepower(to_watts(value))
+    }
+
+    /// Converts BTU per hour to metric horsepower.
+    /// # Arguments
+    /// * `value` - The power in BTU per hour to convert
+    pub fn to_metric_horsepower(value: f64) -> f64 {
+        super::watts::to_metric_horsepower(to_watts(value))
+    }
+
+    /// Converts BTU per hour to electrical horsepower.
+    /// # Arguments
+    /// * `value` - The power in BTU per hour to convert
+    pub fn to_electrical_horsepower(value: f64) -> f64 {
+        super::watts::to_electrical_horsepower(to_watts(value))
+    }
+
+    /// Converts BTU per hour to tons of refrigeration.
+    /// # Arguments
+    /// * `value` - The power in BTU per hour to convert
+    pub fn to_tons_of_refrigeration(value: f64) -> f64 {
+        value / 12_000.0
+    }
+
+    /// Converts BTU per hour to ergs per second.
+    /// # Arguments
+    /// * `value` - The power in BTU per hour to convert
+    pub fn to_ergs_per_second(value: f64) -> f64 {
+        super::watts::to_ergs_per_second(to_watts(value))
+    }
+
+    /// Converts BTU per hour to dBm.
+    /// # Arguments
+    /// * `value` - The power in BTU per hour to convert
+    pub fn to_dbm(value: f64) -> f64 {
+        super::watts::to_dbm(to_watts(value))
+    }
+
+    /// Converts BTU per hour to dBW.
+    /// # Arguments
+    /// * `value` - The power in BTU per hour to convert
+    pub fn to_dbw(value: f64) -> f64 {
+        super::watts::to_dbw(to_watts(value))
+    }
+
+    /// The BTU per hour as a typed unit of power.
+    pub const UNIT: Unit<Power> = Unit::new(
+        "BTU/h",
+        "BTU per hour",
+        to_watts,
+        super::watts::to_btu_per_hour,
+    );
+
+    /// Creates a typed power quantity expressed in BTU per hour.
+    /// # Arguments
+    /// * `value` - The power in BTU per hour
+    pub fn new(value: f64) -> Quantity<Power> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed power quantity in BTU per hour.
+    /// # Arguments
+    /// * `quantity` - The power quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Power>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Ton of refrigeration conversion functions (12 000 BTU/h)
+pub mod tons_of_refrigeration {
+    use crate::conversions::quantity::{Power, Quantity, Unit};
+
+    /// Converts tons of refrigeration to watts.
+    /// # Arguments
+    /// * `value` - The power in tons of refrigeration to convert
+    pub fn to_watts(value: f64) -> f64 {
+        value * super::WATTS_PER_BTU_PER_HOUR * 12_000.0
+    }
+
+    /// Converts tons of refrigeration to kilowatts.
+    /// # Arguments
+    /// * `value` - The power in tons of refrigeration to convert
+    pub fn to_kilowatts(value: f64) -> f64 {
+        super::watts::to_kilowatts(to_watts(value))
+    }
+
+    /// Converts tons of refrigeration to megawatts.
+    /// # Arguments
+    /// * `value` - The power in tons of refrigeration to convert
+    pub fn to_megawatts(value: f64) -> f64 {
+        super::watts::to_megawatts(to_watts(value))
+    }
+
+    /// Converts tons of refrigeration to mechanical horsepower.
+    /// # Arguments
+    /// * `value` - The power in tons of refrigeration to convert
+    pub fn to_horsepower(value: f64) -> f64 {
+        super::watts::to_horsepower(to_watts(value))
+    }
+
+    /// Converts tons of refrigeration to metric horsepower.
+    /// # Arguments
+    /// * `value` - The power in tons of refrigeration to convert
+    pub fn to_metric_horsepower(value: f64) -> f64 {
+        super::watts::to_metric_horsepower(to_watts(value))
+    }
+
+    /// Converts tons of refrigeration to electrical horsepower.
+    /// # Arguments
+    /// * `value` - The power in tons of refrigeration to convert
+    pub fn to_electrical_horsepower(value: f64) -> f64 {
+        super::watts::to_electrical_horsepower(to_watts(value))
+    }
+
+    /// Converts tons of refrigeration to BTU per hour.
+    /// # Arguments
+    /// * `value` - The power in tons of refrigeration to convert
+    pub fn to_btu_per_hour(value: f64) -> f64 {
+        value * 12_000.0
+    }
+
+    /// Converts tons of refrigeration to ergs per second.
+    /// # Arguments
+    /// * `value` - The power in tons of refrigeration to convert
+    pub fn to_ergs_per_second(value: f64) -> f64 {
+        super::watts::to_ergs_per_second(to_watts(value))
+    }
+
+    /// Converts tons of refrigeration to dBm.
+    /// # Arguments
+    /// * `value` - The power in tons of refrigeration to convert
+    pub fn to_dbm(value: f64) -> f64 {
+        super::watts::to_dbm(to_watts(value))
+    }
+
+    /// Converts tons of refrigeration to dBW.
+    /// # Arguments
+    /// * `value` - The power in tons of refrigeration to convert
+    pub fn to_dbw(value: f64) -> f64 {
+        super::watts::to_dbw(to_watts(value))
+    }
+
+    /// The ton of refrigeration as a typed unit of power.
+    pub const UNIT: Unit<Power> = Unit::new(
+        "TR",
+        "ton of refrigeration",
+        to_watts,
+        super::watts::to_tons_of_refrigeration,
+    );
+
+    /// Creates a typed power quantity expressed in tons of refrigeration.
+    /// # Arguments
+    /// * `value` - The power in tons of refrigeration
+    pub fn new(value: f64) -> Quantity<Power> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed power quantity in tons of refrigeration.
+    /// # Arguments
+    /// * `quantity` - The power quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Power>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Erg per second conversion functions
+pub mod ergs_per_second {
+    use crate::conversions::quantity::{Power, Quantity, Unit};
+
+    /// Converts ergs per second to watts.
+    /// # Arguments
+    /// * `value` - The power in ergs per second to convert
+    pub fn to_watts(value: f64) -> f64 {
+        value * 1e-7
+    }
+
+    /// Converts ergs per second to kilowatts.
+    /// # Arguments
+    /// * `value` - The power in ergs per second to convert
+    pub fn to_kilowatts(value: f64) -> f64 {
+        super::watts::to_kilowatts(to_watts(value))
+    }
+
+    /// Converts ergs per second to megawatts.
+    /// # Arguments
+    /// * `value` - The power in ergs per second to convert
+    pub fn to_megawatts(value: f64) -> f64 {
+        super::watts::to_megawatts(to_watts(value))
+    }
+
+    /// Converts ergs per second to mechanical horsepower.
+    /// # Arguments
+    /// * `value` - The power in ergs per second to convert
+    pub fn to_horsepower(value: f64) -> f64 {
+        super::watts::to_horsepower(to_watts(value))
+    }
+
+    /// Converts ergs per second to metric horsepower.
+    /// # Arguments
+    /// * `value` - The power in ergs per second to convert
+    pub fn to_metric_horsepower(value: f64) -> f64 {
+        super::watts::to_metric_horsepower(to_watts(value))
+    }
+
+    /// Converts ergs per second to electrical horsepower.
+    /// # Arguments
+    /// * `value` - The power in ergs per second to convert
+    pub fn to_electrical_horsepower(value: f64) -> f64 {
+        super::watts::to_electrical_horsepower(to_watts(value))
+    }
+
+    /// Converts ergs per second to BTU per hour.
+    /// # Arguments
+    /// * `value` - The power in ergs per second to convert
+    pub fn to_btu_per_hour(value: f64) -> f64 {
+        super::watts::to_btu_per_hour(to_watts(value))
+    }
+
+    /// Converts ergs per second to tons of refrigeration.
+    /// # Arguments
+    /// * `value` - The power in ergs per second to convert
+    pub fn to_tons_of_refrigeration(value: f64) -> f64 {
+        super::watts::to_tons_of_refrigeration(to_watts(value))
+    }
+
+    /// Converts ergs per second to dBm.
+    /// # Arguments
+    /// * `value` - The power in ergs per second to convert
+    pub fn to_dbm(value: f64) -> f64 {
+        super::watts::to_dbm(to_watts(value))
+    }
+
+    /// Converts ergs per second to dBW.
+    /// # Arguments
+    /// * `value` - The power in ergs per second to convert
+    pub fn to_dbw(value: f64) -> f64 {
+        super::watts::to_dbw(to_watts(value))
+    }
+
+    /// The erg per second as a typed unit of power.
+    pub const UNIT: Unit<Power> = Unit::new(
+        "erg/s",
+        "erg per second",
+        to_watts,
+        super::watts::to_ergs_per_second,
+    );
+
+    /// Creates a typed power quantity expressed in ergs per second.
+    /// # Arguments
+    /// * `value` - The power in ergs per second
+    pub fn new(value: f64) -> Quantity<Power> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed power quantity in ergs per second.
+    /// # Arguments
+    /// * `quantity` - The power quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Power>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Decibel-milliwatt conversion functions
+///
+/// dBm is a logarithmic power level relative to one milliwatt. Only positive
+/// powers have a level: converting zero to dBm gives negative infinity and a
+/// negative power NaN, which [`convert_power`] reports as an error instead.
+///
+/// Typed quantities add and scale their numeric value, so convert dBm levels
+/// to watts before summing powers.
+pub mod dbm {
+    use crate::conversions::quantity::{Power, Quantity, Unit};
+
+    /// Converts dBm to watts.
+    /// # Arguments
+    /// * `value` - The power in dBm to convert
+    pub fn to_watts(value: f64) -> f64 {
+        super::DBM_REFERENCE * 10f64.powf(value / 10.0)
+    }
+
+    /// Converts dBm to kilowatts.
+    /// # Arguments
+    /// * `value` - The power in dBm to convert
+    pub fn to_kilowatts(value: f64) -> f64 {
+        super::watts::to_kilowatts(to_watts(value))
+    }
+
+    /// Converts dBm to megawatts.
+    /// # Arguments
+    /// * `value` - The power in dBm to convert
+    pub fn to_megawatts(value: f64) -> f64 {
+        super::watts::to_megawatts(to_watts(value))
+    }
+
+    /// Converts dBm to mechanical horsepower.
+    /// # Arguments
+    /// * `value` - The power in dBm to convert
+    pub fn to_horsepower(value: f64) -> f64 {
+        super::watts::to_horsepower(to_watts(value))
+    }
+
+    /// Converts dBm to metric horsepower.
+    /// # Arguments
+    /// * `value` - The power in dBm to convert
+    pub fn to_metric_horsepower(value: f64) -> f64 {
+        super::watts::to_metric_horsepower(to_watts(value))
+    }
+
+    /// Converts dBm to electrical horsepower.
+    /// # Arguments
+    /// * `value` - The power in dBm to convert
+    pub fn to_electrical_horsepower(value: f64) -> f64 {
+        super::watts::to_electrical_horsepower(to_watts(value))
+    }
+
+    /// Converts dBm to BTU per hour.
+    /// # Arguments
+    /// * `value` - The power in dBm to convert
+    pub fn to_btu_per_hour(value: f64) -> f64 {
+        super::watts::to_btu_per_hour(to_watts(value))
+    }
+
+    /// Converts dBm to tons of refrigeration.
+    /// # Arguments
+    /// * `value` - The power in dBm to convert
+    pub fn to_tons_of_refrigeration(value: f64) -> f64 {
+        super::watts::to_tons_of_refrigeration(to_watts(value))
+    }
+
+    /// Converts dBm to ergs per second.
+    /// # Arguments
+    /// * `value` - The power in dBm to convert
+    pub fn to_ergs_per_second(value: f64) -> f64 {
+        super::watts::to_ergs_per_second(to_watts(value))
+    }
+
+    /// Converts dBm to dBW.
+    /// # Arguments
+    /// * `value` - The power in dBm to convert
+    pub fn to_dbw(value: f64) -> f64 {
+        value - 30.0
+    }
+
+    /// The decibel-milliwatt as a typed unit of power.
+    pub const UNIT: Unit<Power> =
+        Unit::new("dBm", "decibel-milliwatt", to_watts, super::watts::to_dbm).with_decibel_scale();
+
+    /// Creates a typed power quantity expressed in dBm.
+    /// # Arguments
+    /// * `value` - The power in dBm
+    pub fn new(value: f64) -> Quantity<Power> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed power quantity in dBm.
+    /// # Arguments
+    /// * `quantity` - The power quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Power>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Decibel-watt conversion functions
+///
+/// dBW is a logarithmic power level relative to one watt. Only positive powers
+/// have a level: converting zero to dBW gives negative infinity and a negative
+/// power NaN, which [`convert_power`] reports as an error instead.
+pub mod dbw {
+    use crate::conversions::quantity::{Power, Quantity, Unit};
+
+    /// Converts dBW to watts.
+    /// # Arguments
+    /// * `value` - The power in dBW to convert
+    pub fn to_watts(value: f64) -> f64 {
+        10f64.powf(value / 10.0)
+    }
+
+    /// Converts dBW to kilowatts.
+    /// # Arguments
+    /// * `value` - The power in dBW to convert
+    pub fn to_kilowatts(value: f64) -> f64 {
+        super::watts::to_kilowatts(to_watts(value))
+    }
+
+    /// Converts dBW to megawatts.
+    /// # Arguments
+    /// * `value` - The power in dBW to convert
+    pub fn to_megawatts(value: f64) -> f64 {
+        super::watts::to_megawatts(to_watts(value))
+    }
+
+    /// Converts dBW to mechanical horsepower.
+    /// # Arguments
+    /// * `value` - The power in dBW to convert
+    pub fn to_horsepower(value: f64) -> f64 {
+        super::watts::to_horsepower(to_watts(value))
+    }
+
+    /// Converts dBW to metric horsepower.
+    /// # Arguments
+    /// * `value` - The power in dBW to convert
+    pub fn to_metric_horsepower(value: f64) -> f64 {
+        super::watts::to_metric_horsepower(to_watts(value))
+    }
+
+    /// Converts dBW to electrical horsepower.
+    /// # Arguments
+    /// * `value` - The power in dBW to convert
+    pub fn to_electrical_horsepower(value: f64) -> f64 {
+        super::watts::to_electrical_horsepower(to_watts(value))
+    }
+
+    /// Converts dBW to BTU per hour.
+    /// # Arguments
+    /// * `value` - The power in dBW to convert
+    pub fn to_btu_per_hour(value: f64) -> f64 {
+        super::watts::to_btu_per_hour(to_watts(value))
+    }
+
+    /// Converts dBW to tons of refrigeration.
+    /// # Arguments
+    /// * `value` - The power in dBW to convert
+    pub fn to_tons_of_refrigeration(value: f64) -> f64 {
+        super::watts::to_tons_of_refrigeration(to_watts(value))
+    }
+
+    /// Converts dBW to ergs per second.
+    /// # Arguments
+    /// * `value` - The power in dBW to convert
+    pub fn to_ergs_per_second(value: f64) -> f64 {
+        super::watts::to_ergs_per_second(to_watts(value))
+    }
+
+    /// Converts dBW to dBm.
+    /// # Arguments
+    /// * `value` - The power in dBW to convert
+    pub fn to_dbm(value: f64) -> f64 {
+        value + 30.0
+    }
+
+    /// The decibel-watt as a typed unit of power.
+    pub const UNIT: Unit<Power> =
+        Unit::new("dBW", "decibel-watt", to_watts, super::watts::to_dbw).with_decibel_scale();
+
+    /// Creates a typed power quantity expressed in dBW.
+    /// # Arguments
+    /// * `value` - The power in dBW
+    pub fn new(value: f64) -> Quantity<Power> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed power quantity in dBW.
+    /// # Arguments
+    /// * `quantity` - The power quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Power>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// General power conversion function that accepts string unit names
+///
+/// Converts a power from one unit to another using string identifiers.
+/// Symbols are case-sensitive, names are case-insensitive, and common
+/// abbreviations are supported.
+///
+/// dBm and dBW are logarithmic, so converting to them is not a simple
+/// multiplication; a zero or negative power has no decibel level and is
+/// rejected with [`ConversionError::NonPositivePower`].
+///
+/// # Arguments
+///
+/// * `value` - The numeric value to convert
+/// * `from_unit` - The source unit (e.g., "W", "kW", "MW", "hp", "PS", "hp_E", "BTU/h",
+///   "TR", "erg/s", "dBm", "dBW")
+/// * `to_unit` - The target unit using the same abbreviations
+///
+/// # Returns
+/// * `Ok(f64)` - The converted value
+/// * `Err(ConversionError)` - The reason the conversion is not supported
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::{convert_power, ConversionError};
+///
+/// let dbm = convert_power(1.0, "W", "dBm").unwrap();
+/// assert!((dbm - 30.0).abs() < 1e-12);
+///
+/// let milliwatts = convert_power(-10.0, "dBm", "mW").unwrap();
+/// assert!((milliwatts - 0.1).abs() < 1e-12);
+///
+/// assert!(matches!(
+///     convert_power(0.0, "W", "dBm"),
+///     Err(ConversionError::NonPositivePower { .. })
+/// ));
+/// ```
+pub fn convert_power(value: f64, from_unit: &str, to_unit: &str) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from_unit, to_unit, Category::Power)
+}
+
+/// Power units known to the [`UnitRegistry`], with watts as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new("W", "watt", &["watts"], Category::Power, 1.0).with_prefixes(),
+        UnitDef::new(
+            "kW",
+            "kilowatt",
+            &["kilowatts"],
+            Category::Power,
+            kilowatts::to_watts(1.0),
+        ),
+        UnitDef::new(
+            "MW",
+            "megawatt",
+            &["megawatts"],
+            Category::Power,
+            megawatts::to_watts(1.0),
+        ),
+        UnitDef::new(
+            "hp",
+            "mechanical horsepower",
+            &["horsepower", "hp_I", "imperial horsepower"],
+            Category::Power,
+            horsepower::to_watts(1.0),
+        )
+        .with_exact_factor("550*0.3048*0.45359237*9.80665"),
+        UnitDef::new(
+            "PS",
+            "metric horsepower",
+            &["hp_M", "CV", "pk"],
+            Category::Power,
+            metric_horsepower::to_watts(1.0),
+        )
+        .with_exact_factor("735.49875"),
+        UnitDef::new(
+            "hp_E",
+            "electrical horsepower",
+            &["electric horsepower"],
+            Category::Power,
+            electrical_horsepower::to_watts(1.0),
+        ),
+        UnitDef::new(
+            "BTU/h",
+            "BTU per hour",
+            &["Btu/h", "BTU/hr", "BTUH", "British thermal units per hour"],
+            Category::Power,
+            btu_per_hour::to_watts(1.0),
+        )
+        .with_exact_factor("1055.05585262/3600"),
+        UnitDef::new(
+            "TR",
+            "ton of refrigeration",
+            &["tons of refrigeration", "RT"],
+            Category::Power,
+            tons_of_refrigeration::to_watts(1.0),
+        )
+        .with_exact_factor("1055.05585262*12000/3600"),
+        UnitDef::new(
+            "erg/s",
+            "erg per second",
+            &["ergs per second"],
+            Category::Power,
+            ergs_per_second::to_watts(1.0),
+        ),
+        UnitDef::new(
+            "dBm",
+            "decibel-milliwatt",
+            &["dBmW", "decibel-milliwatts"],
+            Category::Power,
+            DBM_REFERENCE,
+        )
+        .with_decibel_scale(),
+        UnitDef::new(
+            "dBW",
+            "decibel-watt",
+            &["decibel-watts"],
+            Category::Power,
+            1.0,
+        )
+        .with_decibel_scale(),
+    ]
+}
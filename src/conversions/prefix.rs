@@ -0,0 +1,64 @@
+//! SI prefixes
+//! The 24 decimal prefixes from quecto (10⁻³⁰) to quetta (10³⁰), including the
+//! ronna, quetta, ronto and quecto prefixes adopted in 2022
+
+/// A decimal SI prefix such as kilo (`k`) or micro (`μ`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SiPrefix {
+    /// Prefix symbol (e.g. `"k"`); micro uses the Greek letter mu (`"μ"`)
+    pub symbol: &'static str,
+    /// Prefix name (e.g. `"kilo"`)
+    pub name: &'static str,
+    /// The power of ten the prefix stands for (e.g. `1e3`)
+    pub factor: f64,
+}
+
+impl SiPrefix {
+    const fn new(symbol: &'static str, name: &'static str, factor: f64) -> Self {
+        SiPrefix {
+            symbol,
+            name,
+            factor,
+        }
+    }
+
+    /// Other accepted spellings of the prefix symbol.
+    ///
+    /// Micro may be written with a plain `u` when neither the Greek mu nor
+    /// the micro sign (`µ`, which unit lookup treats as mu) is at hand.
+    pub fn alternative_symbols(&self) -> &'static [&'static str] {
+        if self.symbol == "μ" {
+            &["u"]
+        } else {
+            &[]
+        }
+    }
+}
+
+/// Every SI prefix, from largest to smallest.
+pub const SI_PREFIXES: [SiPrefix; 24] = [
+    SiPrefix::new("Q", "quetta", 1e30),
+    SiPrefix::new("R", "ronna", 1e27),
+    SiPrefix::new("Y", "yotta", 1e24),
+    SiPrefix::new("Z", "zetta", 1e21),
+    SiPrefix::new("E", "exa", 1e18),
+    SiPrefix::new("P", "peta", 1e15),
+    SiPrefix::new("T", "tera", 1e12),
+    SiPrefix::new("G", "giga", 1e9),
+    SiPrefix::new("M", "mega", 1e6),
+    SiPrefix::new("k", "kilo", 1e3),
+    SiPrefix::new("h", "hecto", 1e2),
+    SiPrefix::new("da", "deca", 1e1),
+    SiPrefix::new("d", "deci", 1e-1),
+    SiPrefix::new("c", "centi", 1e-2),
+    SiPrefix::new("m", "milli", 1e-3),
+    SiPrefix::new("μ", "micro", 1e-6),
+    SiPrefix::new("n", "nano", 1e-9),
+    SiPrefix::new("p", "pico", 1e-12),
+    SiPrefix::new("f", "femto", 1e-15),
+    SiPrefix::new("a", "atto", 1e-18),
+    SiPrefix::new("z", "zepto", 1e-21),
+    SiPrefix::new("y", "yocto", 1e-24),
+    SiPrefix::new("r", "ronto", 1e-27),
+    SiPrefix::new("q", "quecto", 1e-30),
+];
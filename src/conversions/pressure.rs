@@ -0,0 +1,1456 @@
+//! Pressure conversion functions organized by unit type
+//! All conversions use pascals as the base unit for accuracy and consistency
+
+use super::category::Category;
+use super::error::ConversionError;
+use super::length::METERS_PER_INCH;
+use super::registry::{UnitDef, UnitRegistry};
+use super::weight::KILOGRAMS_PER_POUND;
+
+/// Standard acceleration of gravity in meters per second squared (exact by
+/// definition), which turns a pound of mass into a pound-force
+pub const STANDARD_GRAVITY: f64 = 9.806_65;
+
+/// Pascals in one standard atmosphere (exactly 101 325 Pa), the reference
+/// for gauge pressures
+pub const STANDARD_ATMOSPHERE: f64 = 101_325.0;
+
+/// Pascals in one bar (exactly 100 kPa)
+pub const PASCALS_PER_BAR: f64 = 100_000.0;
+
+/// Pascals in one pound-force per square inch
+pub const PASCALS_PER_PSI: f64 =
+    KILOGRAMS_PER_POUND * STANDARD_GRAVITY / (METERS_PER_INCH * METERS_PER_INCH);
+
+/// Pascals in one torr (exactly 1/760 of a standard atmosphere)
+pub const PASCALS_PER_TORR: f64 = STANDARD_ATMOSPHERE / 760.0;
+
+/// Pascals in one conventional millimeter of mercury (13.5951 g/cm³ mercury
+/// under standard gravity)
+pub const PASCALS_PER_MILLIMETER_OF_MERCURY: f64 = 133.322_387_415;
+
+/// Pascals in one conventional inch of mercury (25.4 mm of mercury)
+pub const PASCALS_PER_INCH_OF_MERCURY: f64 = 3_386.388_640_341;
+
+/// Pascals in one conventional inch of water column (1 g/cm³ water under
+/// standard gravity)
+pub const PASCALS_PER_INCH_OF_WATER: f64 = 249.088_91;
+
+/// Pascal conversion functions
+pub mod pascals {
+    use crate::conversions::quantity::{Pressure, Quantity, Unit};
+
+    /// Converts pascals to kilopascals.
+    /// # Arguments
+    /// * `value` - The pressure in pascals to convert
+    pub fn to_kilopascals(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// Converts pascals to megapascals.
+    /// # Arguments
+    /// * `value` - The pressure in pascals to convert
+    pub fn to_megapascals(value: f64) -> f64 {
+        value / 1_000_000.0
+    }
+
+    /// Converts pascals to bars.
+    /// # Arguments
+    /// * `value` - The pressure in pascals to convert
+    pub fn to_bars(value: f64) -> f64 {
+        value / super::PASCALS_PER_BAR
+    }
+
+    /// Converts pascals to millibars.
+    /// # Arguments
+    /// * `value` - The pressure in pascals to convert
+    pub fn to_millibars(value: f64) -> f64 {
+        value / 100.0
+    }
+
+    /// Converts pascals to atmospheres.
+    /// # Arguments
+    /// * `value` - The pressure in pascals to convert
+    pub fn to_atmospheres(value: f64) -> f64 {
+        value / super::STANDARD_ATMOSPHERE
+    }
+
+    /// Converts pascals to pounds per square inch.
+    /// # Arguments
+    /// * `value` - The pressure in pascals to convert
+    pub fn to_psi(value: f64) -> f64 {
+        value / super::PASCALS_PER_PSI
+    }
+
+    /// Converts pascals to pounds per square inch gauge.
+    /// # Arguments
+    /// * `value` - The pressure in pascals to convert
+    pub fn to_psig(value: f64) -> f64 {
+        (value - super::STANDARD_ATMOSPHERE) / super::PASCALS_PER_PSI
+    }
+
+    /// Converts pascals to torr.
+    /// # Arguments
+    /// * `value` - The pressure in pascals to convert
+    pub fn to_torr(value: f64) -> f64 {
+        value / super::PASCALS_PER_TORR
+    }
+
+    /// Converts pascals to millimeters of mercury.
+    /// # Arguments
+    /// * `value` - The pressure in pascals to convert
+    pub fn to_millimeters_of_mercury(value: f64) -> f64 {
+        value / super::PASCALS_PER_MILLIMETER_OF_MERCURY
+    }
+
+    /// Converts pascals to inches of mercury.
+    /// # Arguments
+    /// * `value` - The pressure in pascals to convert
+    pub fn to_inches_of_mercury(value: f64) -> f64 {
+        value / super::PASCALS_PER_INCH_OF_MERCURY
+    }
+
+    /// Converts pascals to inches of water.
+    /// # Arguments
+    /// * `value` - The pressure in pascals to convert
+    pub fn to_inches_of_water(value: f64) -> f64 {
+        value / super::PASCALS_PER_INCH_OF_WATER
+    }
+
+    /// The pascal as a typed unit of pressure.
+    pub const UNIT: Unit<Pressure> = Unit::new("Pa", "pascal", |value| value, |value| value);
+
+    /// Creates a typed pressure quantity expressed in pascals.
+    /// # Arguments
+    /// * `value` - The pressure in pascals
+    pub fn new(value: f64) -> Quantity<Pressure> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed pressure quantity in pascals.
+    /// # Arguments
+    /// * `quantity` - The pressure quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Pressure>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Kilopascal conversion functions
+pub mod kilopascals {
+    use crate::conversions::quantity::{Pressure, Quantity, Unit};
+
+    /// Converts kilopascals to pascals.
+    /// # Arguments
+    /// * `value` - The pressure in kilopascals to convert
+    pub fn to_pascals(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// Converts kilopascals to megapascals.
+    /// # Arguments
+    /// * `value` - The pressure in kilopascals to convert
+    pub fn to_megapascals(value: f64) -> f64 {
+        super::pascals::to_megapascals(to_pascals(value))
+    }
+
+    /// Converts kilopascals to bars.
+    /// # Arguments
+    /// * `value` - The pressure in kilopascals to convert
+    pub fn to_bars(value: f64) -> f64 {
+        super::pascals::to_bars(to_pascals(value))
+    }
+
+    /// Converts kilopascals to millibars.
+    /// # Arguments
+    /// * `value` - The pressure in kilopascals to convert
+    pub fn to_millibars(value: f64) -> f64 {
+        super::pascals::to_millibars(to_pascals(value))
+    }
+
+    /// Converts kilopascals to atmospheres.
+    /// # Arguments
+    /// * `value` - The pressure in kilopascals to convert
+    pub fn to_atmospheres(value: f64) -> f64 {
+        super::pascals::to_atmospheres(to_pascals(value))
+    }
+
+    /// Converts kilopascals to pounds per square inch.
+    /// # Arguments
+    /// * `value` - The pressure in kilopascals to convert
+    pub fn to_psi(value: f64) -> f64 {
+        super::pascals::to_psi(to_pascals(value))
+    }
+
+    /// Converts kilopascals to pounds per square inch gauge.
+    /// # Arguments
+    /// * `value` - The pressure in kilopascals to convert
+    pub fn to_psig(value: f64) -> f64 {
+        super::pascals::to_psig(to_pascals(value))
+    }
+
+    /// Converts kilopascals to torr.
+    /// # Arguments
+    /// * `value` - The pressure in kilopascals to convert
+    pub fn to_torr(value: f64) -> f64 {
+        super::pascals::to_torr(to_pascals(value))
+    }
+
+    /// Converts kilopascals to millimeters of mercury.
+    /// # Arguments
+    /// * `value` - The pressure in kilopascals to convert
+    pub fn to_millimeters_of_mercury(value: f64) -> f64 {
+        super::pascals::to_millimeters_of_mercury(to_pascals(value))
+    }
+
+    /// Converts kilopascals to inches of mercury.
+    /// # Arguments
+    /// * `value` - The pressure in kilopascals to convert
+    pub fn to_inches_of_mercury(value: f64) -> f64 {
+        super::pascals::to_inches_of_mercury(to_pascals(value))
+    }
+
+    /// Converts kilopascals to inches of water.
+    /// # Arguments
+    /// * `value` - The pressure in kilopascals to convert
+    pub fn to_inches_of_water(value: f64) -> f64 {
+        super::pascals::to_inches_of_water(to_pascals(value))
+    }
+
+    /// The kilopascal as a typed unit of pressure.
+    pub const UNIT: Unit<Pressure> = Unit::new(
+        "kPa",
+        "kilopascal",
+        to_pascals,
+        super::pascals::to_kilopascals,
+    );
+
+    /// Creates a typed pressure quantity expressed in kilopascals.
+    /// # Arguments
+    /// * `value` - The pressure in kilopascals
+    pub fn new(value: f64) -> Quantity<Pressure> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed pressure quantity in kilopascals.
+    /// # Arguments
+    /// * `quantity` - The pressure quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Pressure>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Megapascal conversion functions
+pub mod megapascals {
+    use crate::conversions::quantity::{Pressure, Quantity, Unit};
+
+    /// Converts megapascals to pascals.
+    /// # Arguments
+    /// * `value` - The pressure in megapascals to convert
+    pub fn to_pascals(value: f64) -> f64 {
+        value * 1_000_000.0
+    }
+
+    /// Converts megapascals to kilopascals.
+    /// # Arguments
+    /// * `value` - The pressure in megapascals to convert
+    pub fn to_kilopascals(value: f64) -> f64 {
+        super::pascals::to_kilopascals(to_pascals(value))
+    }
+
+    /// Converts megapascals to bars.
+    /// # Arguments
+    /// * `value` - The pressure in megapascals to convert
+    pub fn to_bars(value: f64) -> f64 {
+        super::pascals::to_bars(to_pascals(value))
+    }
+
+    /// Converts megapascals to millibars.
+    /// # Arguments
+    /// * `value` - The pressure in megapascals to convert
+    pub fn to_millibars(value: f64) -> f64 {
+        super::pascals::to_millibars(to_pascals(value))
+    }
+
+    /// Converts megapascals to atmospheres.
+    /// # Arguments
+    /// * `value` - The pressure in megapascals to convert
+    pub fn to_atmospheres(value: f64) -> f64 {
+        super::pascals::to_atmospheres(to_pascals(value))
+    }
+
+    /// Converts megapascals to pounds per square inch.
+    /// # Arguments
+    /// * `value` - The pressure in megapascals to convert
+    pub fn to_psi(value: f64) -> f64 {
+        super::pascals::to_psi(to_pascals(value))
+    }
+
+    /// Converts megapascals to pounds per square inch gauge.
+    /// # Arguments
+    /// * `value` - The pressure in megapascals to convert
+    pub fn to_psig(value: f64) -> f64 {
+        super::pascals::to_psig(to_pascals(value))
+    }
+
+    /// Converts megapascals to torr.
+    /// # Arguments
+    /// * `value` - The pressure in megapascals to convert
+    pub fn to_torr(value: f64) -> f64 {
+        super::pascals::to_torr(to_pascals(value))
+    }
+
+    /// Converts megapascals to millimeters of mercury.
+    /// # Arguments
+    /// * `value` - The pressure in megapascals to convert
+    pub fn to_millimeters_of_mercury(value: f64) -> f64 {
+        super::pascals::to_millimeters_of_mercury(to_pascals(value))
+    }
+
+    /// Converts megapascals to inches of mercury.
+    /// # Arguments
+    /// * `value` - The pressure in megapascals to convert
+    pub fn to_inches_of_mercury(value: f64) -> f64 {
+        super::pascals::to_inches_of_mercury(to_pascals(value))
+    }
+
+    /// Converts megapascals to inches of water.
+    /// # Arguments
+    /// * `value` - The pressure in megapascals to convert
+    pub fn to_inches_of_water(value: f64) -> f64 {
+        super::pascals::to_inches_of_water(to_pascals(value))
+    }
+
+    /// The megapascal as a typed unit of pressure.
+    pub const UNIT: Unit<Pressure> = Unit::new(
+        "MPa",
+        "megapascal",
+        to_pascals,
+        super::pascals::to_megapascals,
+    );
+
+    /// Creates a typed pressure quantity expressed in megapascals.
+    /// # Arguments
+    /// * `value` - The pressure in megapascals
+    pub fn new(value: f64) -> Quantity<Pressure> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed pressure quantity in megapascals.
+    /// # Arguments
+    /// * `quantity` - The pressure quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Pressure>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Bar conversion functions
+pub mod bars {
+    use crate::conversions::quantity::{Pressure, Quantity, Unit};
+
+    /// Converts bars to pascals.
+    /// # Arguments
+    /// * `value` - The pressure in bars to convert
+    pub fn to_pascals(value: f64) -> f64 {
+        value * super::PASCALS_PER_BAR
+    }
+
+    /// Converts bars to kilopascals.
+    /// # Arguments
+    /// * `value` - The pressure in bars to convert
+    pub fn to_kilopascals(value: f64) -> f64 {
+        super::pascals::to_kilopascals(to_pascals(value))
+    }
+
+    /// Converts bars to megapascals.
+    /// # Arguments
+    /// * `value` - The pressure in bars to convert
+    pub fn to_megapascals(value: f64) -> f64 {
+        super::pascals::to_megapascals(to_pascals(value))
+    }
+
+    /// Converts bars to millibars.
+    /// # Arguments
+    /// * `value` - The pressure in bars to convert
+    pub fn to_millibars(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// Converts bars to atmospheres.
+    /// # Arguments
+    /// * `value` - The pressure in bars to convert
+    pub fn to_atmospheres(value: f64) -> f64 {
+        super::pascals::to_atmospheres(to_pascals(value))
+    }
+
+    /// Converts bars to pounds per square inch.
+    /// # Arguments
+    /// * `value` - The pressure in bars to convert
+    pub fn to_psi(value: f64) -> f64 {
+        super::pascals::to_psi(to_pascals(value))
+    }
+
+    /// Converts bars to pounds per square inch gauge.
+    /// # Arguments
+    /// * `value` - The pressure in bars to convert
+    pub fn to_psig(value: f64) -> f64 {
+        super::pascals::to_psig(to_pascals(value))
+    }
+
+    /// Converts bars to torr.
+    /// # Arguments
+    /// * `value` - The pressure in bars to convert
+    pub fn to_torr(value: f64) -> f64 {
+        super::pascals::to_torr(to_pascals(value))
+    }
+
+    /// Converts bars to millimeters of mercury.
+    /// # Arguments
+    /// * `value` - The pressure in bars to convert
+    pub fn to_millimeters_of_mercury(value: f64) -> f64 {
+        super::pascals::to_millimeters_of_mercury(to_pascals(value))
+    }
+
+    /// Converts bars to inches of mercury.
+    /// # Arguments
+    /// * `value` - The pressure in bars to convert
+    pub fn to_inches_of_mercury(value: f64) -> f64 {
+        super::pascals::to_inches_of_mercury(to_pascals(value))
+    }
+
+    /// Converts bars to inches of water.
+    /// # Arguments
+    /// * `value` - The pressure in bars to convert
+    pub fn to_inches_of_water(value: f64) -> f64 {
+        super::pascals::to_inches_of_water(to_pascals(value))
+    }
+
+    /// The bar as a typed unit of pressure.
+    pub const UNIT: Unit<Pressure> = Unit::new("bar", "bar", to_pascals, super::pascals::to_bars);
+
+    /// Creates a typed pressure quantity expressed in bars.
+    /// # Arguments
+    /// * `value` - The pressure in bars
+    pub fn new(value: f64) -> Quantity<Pressure> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed pressure quantity in bars.
+    /// # Arguments
+    /// * `quantity` - The pressure quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Pressure>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Millibar conversion functions
+pub mod millibars {
+    use crate::conversions::quantity::{Pressure, Quantity, Unit};
+
+    /// Converts millibars to pascals.
+    /// # Arguments
+    /// * `value` - The pressure in millibars to convert
+    pub fn to_pascals(value: f64) -> f64 {
+        value * 100.0
+    }
+
+    /// Converts millibars to kilopascals.
+    /// # Arguments
+    /// * `value` - The pressure in millibars to convert
+    pub fn to_kilopascals(value: f64) -> f64 {
+        super::pascals::to_kilopascals(to_pascals(value))
+    }
+
+    /// Converts millibars to megapascals.
+    /// # Arguments
+    /// * `value` - The pressure in millibars to convert
+    pub fn to_megapascals(value: f64) -> f64 {
+        super::pascals::to_megapascals(to_pascals(value))
+    }
+
+    /// Converts millibars to bars.
+    /// # Arguments
+    /// * `value` - The pressure in millibars to convert
+    pub fn to_bars(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// Converts millibars to atmospheres.
+    /// # Arguments
+    /// * `value` - The pressure in millibars to convert
+    pub fn to_atmospheres(value: f64) -> f64 {
+        super::pascals::to_atmospheres(to_pascals(value))
+    }
+
+    /// Converts millibars to pounds per square inch.
+    /// # Arguments
+    /// * `value` - The pressure in millibars to convert
+    pub fn to_psi(value: f64) -> f64 {
+        super::pascals::to_psi(to_pascals(value))
+    }
+
+    /// Converts millibars to pounds per square inch gauge.
+    /// # Arguments
+    /// * `value` - The pressure in millibars to convert
+    pub fn to_psig(value: f64) -> f64 {
+        super::pascals::to_psig(to_pascals(value))
+    }
+
+    /// Converts millibars to torr.
+    /// # Arguments
+    /// * `value` - The pressure in millibars to convert
+    pub fn to_torr(value: f64) -> f64 {
+        super::pascals::to_torr(to_pascals(value))
+    }
+
+    /// Converts millibars to millimeters of mercury.
+    /// # Arguments
+    /// * `value` - The pressure in millibars to convert
+    pub fn to_millimeters_of_mercury(value: f64) -> f64 {
+        super::pascals::to_millimeters_of_mercury(to_pascals(value))
+    }
+
+    /// Converts millibars to inches of mercury.
+    /// # Arguments
+    /// * `value` - The pressure in millibars to convert
+    pub fn to_inches_of_mercury(value: f64) -> f64 {
+        super::pascals::to_inches_of_mercury(to_pascals(value))
+    }
+
+    /// Converts millibars to inches of water.
+    /// # Arguments
+    /// * `value` - The pressure in millibars to convert
+    pub fn to_inches_of_water(value: f64) -> f64 {
+        super::pascals::to_inches_of_water(to_pascals(value))
+    }
+
+    /// The millibar as a typed unit of pressure.
+    pub const UNIT: Unit<Pressure> =
+        Unit::new("mbar", "millibar", to_pascals, super::pascals::to_millibars);
+
+    /// Creates a typed pressure quantity expressed in millibars.
+    /// # Arguments
+    /// * `value` - The pressure in millibars
+    pub fn new(value: f64) -> Quantity<Pressure> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed pressure quantity in millibars.
+    /// # Arguments
+    /// * `quantity` - The pressure quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Pressure>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Standard atmosphere conversion functions
+pub mod atmospheres {
+    use crate::conversions::quantity::{Pressure, Quantity, Unit};
+
+    /// Converts atmospheres to pascals.
+    /// # Arguments
+    /// * `value` - The pressure in atmospheres to convert
+    pub fn to_pascals(value: f64) -> f64 {
+        value * super::STANDARD_ATMOSPHERE
+    }
+
+    /// Converts atmospheres to kilopascals.
+    /// # Arguments
+    /// * `value` - The pressure in atmospheres to convert
+    pub fn to_kilopascals(value: f64) -> f64 {
+        super::pascals::to_kilopascals(to_pascals(value))
+    }
+
+    /// Converts atmospheres to megapascals.
+    /// # Arguments
+    /// * `value` - The pressure in atmospheres to convert
+    pub fn to_megapascals(value: f64) -> f64 {
+        super::pascals::to_megapascals(to_pascals(value))
+    }
+
+    /// Converts atmospheres to bars.
+    /// # Arguments
+    /// * `value` - The pressure in atmospheres to convert
+    pub fn to_bars(value: f64) -> f64 {
+        super::pascals::to_bars(to_pascals(value))
+    }
+
+    /// Converts atmospheres to millibars.
+    /// # Arguments
+    /// * `value` - The pressure in atmospheres to convert
+    pub fn to_millibars(value: f64) -> f64 {
+        super::pascals::to_millibars(to_pascals(value))
+    }
+
+    /// Converts atmospheres to pounds per square inch.
+    /// # Arguments
+    /// * `value` - The pressure in atmospheres to convert
+    pub fn to_psi(value: f64) -> f64 {
+        super::pascals::to_psi(to_pascals(value))
+    }
+
+    /// Converts atmospheres to pounds per square inch gauge.
+    /// # Arguments
+    /// * `value` - The pressure in atmospheres to convert
+    pub fn to_psig(value: f64) -> f64 {
+        super::pascals::to_psig(to_pascals(value))
+    }
+
+    /// Converts atmospheres to torr.
+    /// # Arguments
+    /// * `value` - The pressure in atmospheres to convert
+    pub fn to_torr(value: f64) -> f64 {
+        value * 760.0
+    }
+
+    /// Converts atmospheres to millimeters of mercury.
+    /// # Arguments
+    /// * `value` - The pressure in atmospheres to convert
+    pub fn to_millimeters_of_mercury(value: f64) -> f64 {
+        super::pascals::to_millimeters_of_mercury(to_pascals(value))
+    }
+
+    /// Converts atmospheres to inches of mercury.
+    /// # Arguments
+    /// * `value` - The pressure in atmospheres to convert
+    pub fn to_inches_of_mercury(value: f64) -> f64 {
+        super::pascals::to_inches_of_mercury(to_pascals(value))
+    }
+
+    /// Converts atmospheres to inches of water.
+    /// # Arguments
+    /// * `value` - The pressure in atmospheres to convert
+    pub fn to_inches_of_water(value: f64) -> f64 {
+        super::pascals::to_inches_of_water(to_pascals(value))
+    }
+
+    /// The standard atmosphere as a typed unit of pressure.
+    pub const UNIT: Unit<Pressure> = Unit::new(
+        "atm",
+        "standard atmosphere",
+        to_pascals,
+        super::pascals::to_atmospheres,
+    );
+
+    /// Creates a typed pressure quantity expressed in atmospheres.
+    /// # Arguments
+    /// * `value` - The pressure in atmospheres
+    pub fn new(value: f64) -> Quantity<Pressure> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed pressure quantity in atmospheres.
+    /// # Arguments
+    /// * `quantity` - The pressure quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Pressure>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Pounds per square inch (absolute) conversion functions
+pub mod psi {
+    use crate::conversions::quantity::{Pressure, Quantity, Unit};
+
+    /// Converts pounds per square inch to pascals.
+    /// # Arguments
+    /// * `value` - The pressure in pounds per square inch to convert
+    pub fn to_pascals(value: f64) -> f64 {
+        value * super::PASCALS_PER_PSI
+    }
+
+    /// Converts pounds per square inch to kilopascals.
+    /// # Arguments
+    /// * `value` - The pressure in pounds per square inch to convert
+    pub fn to_kilopascals(value: f64) -> f64 {
+        super::pascals::to_kilopascals(to_pascals(value))
+    }
+
+    /// Converts pounds per square inch to megapascals.
+    /// # Arguments
+    /// * `value` - The pressure in pounds per square inch to convert
+    pub fn to_megapascals(value: f64) -> f64 {
+        super::pascals::to_megapascals(to_pascals(value))
+    }
+
+    /// Converts pounds per square inch to bars.
+    /// # Arguments
+    /// * `value` - The pressure in pounds per square inch to convert
+    pub fn to_bars(value: f64) -> f64 {
+        super::pascals::to_bars(to_pascals(value))
+    }
+
+    /// Converts pounds per square inch to millibars.
+    /// # Arguments
+    /// * `value` - The pressure in pounds per square inch to convert
+    pub fn to_millibars(value: f64) -> f64 {
+        super::pascals::to_millibars(to_pascals(value))
+    }
+
+    /// Converts pounds per square inch to atmospheres.
+    /// # Arguments
+    /// * `value` - The pressure in pounds per square inch to convert
+    pub fn to_atmospheres(value: f64) -> f64 {
+        super::pascals::to_atmospheres(to_pascals(value))
+    }
+
+    /// Converts pounds per square inch to pounds per square inch gauge.
+    /// # Arguments
+    /// * `value` - The pressure in pounds per square inch to convert
+    pub fn to_psig(value: f64) -> f64 {
+        value - super::STANDARD_ATMOSPHERE / super::PASCALS_PER_PSI
+    }
+
+    /// Converts pounds per square inch to torr.
+    /// # Arguments
+    /// * `value` - The pressure in pounds per square inch to convert
+    pub fn to_torr(value: f64) -> f64 {
+        super::pascals::to_torr(to_pascals(value))
+    }
+
+    /// Converts pounds per square inch to millimeters of mercury.
+    /// # Arguments
+    /// * `value` - The pressure in pounds per square inch to convert
+    pub fn to_millimeters_of_mercury(value: f64) -> f64 {
+        super::pascals::to_millimeters_of_mercury(to_pascals(value))
+    }
+
+    /// Converts pounds per square inch to inches of mercury.
+    /// # Arguments
+    /// * `value` - The pressure in pounds per square inch to convert
+    pub fn to_inches_of_mercury(value: f64) -> f64 {
+        super::pascals::to_inches_of_mercury(to_pascals(value))
+    }
+
+    /// Converts pounds per square inch to inches of water.
+    /// # Arguments
+    /// * `value` - The pressure in pounds per square inch to convert
+    pub fn to_inches_of_water(value: f64) -> f64 {
+        super::pascals::to_inches_of_water(to_pascals(value))
+    }
+
+    /// The pound per square inch as a typed unit of pressure.
+    pub const UNIT: Unit<Pressure> = Unit::new(
+        "psi",
+        "pound per square inch",
+        to_pascals,
+        super::pascals::to_psi,
+    );
+
+    /// Creates a typed pressure quantity expressed in pounds per square inch.
+    /// # Arguments
+    /// * `value` - The pressure in pounds per square inch
+    pub fn new(value: f64) -> Quantity<Pressure> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed pressure quantity in pounds per square inch.
+    /// # Arguments
+    /// * `quantity` - The pressure quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Pressure>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Pounds per square inch gauge conversion functions
+///
+/// Gauge readings are relative to the [`STANDARD_ATMOSPHERE`]; use
+/// [`gauge_to_absolute`] and [`absolute_to_gauge`] for any other reference.
+pub mod psig {
+    use crate::conversions::quantity::{Pressure, Quantity, Unit};
+
+    /// Converts pounds per square inch gauge to pascals.
+    /// # Arguments
+    /// * `value` - The pressure in pounds per square inch gauge to convert
+    pub fn to_pascals(value: f64) -> f64 {
+        value * super::PASCALS_PER_PSI + super::STANDARD_ATMOSPHERE
+    }
+
+    /// Converts pounds per square inch gauge to kilopascals.
+    /// # Arguments
+    /// * `value` - The pressure in pounds per square inch gauge to convert
+    pub fn to_kilopascals(value: f64) -> f64 {
+        super::pascals::to_kilopascals(to_pascals(value))
+    }
+
+    /// Converts pounds per square inch gauge to megapascals.
+    /// # Arguments
+    /// * `value` - The pressure in pounds per square inch gauge to convert
+    pub fn to_megapascals(value: f64) -> f64 {
+        super::pascals::to_megapascals(to_pascals(value))
+    }
+
+    /// Converts pounds per square inch gauge to bars.
+    /// # Arguments
+    /// * `value` - The pressure in pounds per square inch gauge to convert
+    pub fn to_bars(value: f64) -> f64 {
+        super::pascals::to_bars(to_pascals(value))
+    }
+
+    /// Converts pounds per square inch gauge to millibars.
+    /// # Arguments
+    /// * `value` - The pressure in pounds per square inch gauge to convert
+    pub fn to_millibars(value: f64) -> f64 {
+        super::pascals::to_millibars(to_pascals(value))
+    }
+
+    /// Converts pounds per square inch gauge to atmospheres.
+    /// # Arguments
+    /// * `value` - The pressure in pounds per square inch gauge to convert
+    pub fn to_atmospheres(value: f64) -> f64 {
+        super::pascals::to_atmospheres(to_pascals(value))
+    }
+
+    /// Converts pounds per square inch gauge to pounds per square inch.
+    /// # Arguments
+    /// * `value` - The pressure in pounds per square inch gauge to convert
+    pub fn to_psi(value: f64) -> f64 {
+        value + super::STANDARD_ATMOSPHERE / super::PASCALS_PER_PSI
+    }
+
+    /// Converts pounds per square inch gauge to torr.
+    /// # Arguments
+    /// * `value` - The pressure in pounds per square inch gauge to convert
+    pub fn to_torr(value: f64) -> f64 {
+        super::pascals::to_torr(to_pascals(value))
+    }
+
+    /// Converts pounds per square inch gauge to millimeters of mercury.
+    /// # Arguments
+    /// * `value` - The pressure in pounds per square inch gauge to convert
+    pub fn to_millimeters_of_mercury(value: f64) -> f64 {
+        super::pascals::to_millimeters_of_mercury(to_pascals(value))
+    }
+
+    /// Converts pounds per square inch gauge to inches of mercury.
+    /// # Arguments
+    /// * `value` - The pressure in pounds per square inch gauge to convert
+    pub fn to_inches_of_mercury(value: f64) -> f64 {
+        super::pascals::to_inches_of_mercury(to_pascals(value))
+    }
+
+    /// Converts pounds per square inch gauge to inches of water.
+    /// # Arguments
+    /// * `value` - The pressure in pounds per square inch gauge to convert
+    pub fn to_inches_of_water(value: f64) -> f64 {
+        super::pascals::to_inches_of_water(to_pascals(value))
+    }
+
+    /// The pound per square inch gauge as a typed unit of pressure.
+    pub const UNIT: Unit<Pressure> = Unit::new(
+        "psig",
+        "pound per square inch gauge",
+        to_pascals,
+        super::pascals::to_psig,
+    );
+
+    /// Creates a typed pressure quantity expressed in pounds per square inch gauge.
+    /// # Arguments
+    /// * `value` - The pressure in pounds per square inch gauge
+    pub fn new(value: f64) -> Quantity<Pressure> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed pressure quantity in pounds per square inch gauge.
+    /// # Arguments
+    /// * `quantity` - The pressure quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Pressure>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Torr conversion functions
+pub mod torr {
+    use crate::conversions::quantity::{Pressure, Quantity, Unit};
+
+    /// Converts torr to pascals.
+    /// # Arguments
+    /// * `value` - The pressure in torr to convert
+    pub fn to_pascals(value: f64) -> f64 {
+        value * super::PASCALS_PER_TORR
+    }
+
+    /// Converts torr to kilopascals.
+    /// # Arguments
+    /// * `value` - The pressure in torr to convert
+    pub fn to_kilopascals(value: f64) -> f64 {
+        super::pascals::to_kilopascals(to_pascals(value))
+    }
+
+    /// Converts torr to megapascals.
+    /// # Arguments
+    /// * `value` - The pressure in torr to convert
+    pub fn to_megapascals(value: f64) -> f64 {
+        super::pascals::to_megapascals(to_pascals(value))
+    }
+
+    /// Converts torr to bars.
+    /// # Arguments
+    /// * `value` - The pressure in torr to convert
+    pub fn to_bars(value: f64) -> f64 {
+        super::pascals::to_bars(to_pascals(value))
+    }
+
+    /// Converts torr to millibars.
+    /// # Arguments
+    /// * `value` - The pressure in torr to convert
+    pub fn to_millibars(value: f64) -> f64 {
+        super::pascals::to_millibars(to_pascals(value))
+    }
+
+    /// Converts torr to atmospheres.
+    /// # Arguments
+    /// * `value` - The pressure in torr to convert
+    pub fn to_atmospheres(value: f64) -> f64 {
+        value / 760.0
+    }
+
+    /// Converts torr to pounds per square inch.
+    /// # Arguments
+    /// * `value` - The pressure in torr to convert
+    pub fn to_psi(value: f64) -> f64 {
+        super::pascals::to_psi(to_pascals(value))
+    }
+
+    /// Converts torr to pounds per square inch gauge.
+    /// # Arguments
+    /// * `value` - The pressure in torr to convert
+    pub fn to_psig(value: f64) -> f64 {
+        super::pascals::to_psig(to_pascals(value))
+    }
+
+    /// Converts torr to millimeters of mercury.
+    /// # Arguments
+    /// * `value` - The pressure in torr to convert
+    pub fn to_millimeters_of_mercury(value: f64) -> f64 {
+        super::pascals::to_millimeters_of_mercury(to_pascals(value))
+    }
+
+    /// Converts torr to inches of mercury.
+    /// # Arguments
+    /// * `value` - The pressure in torr to convert
+    pub fn to_inches_of_mercury(value: f64) -> f64 {
+        super::pascals::to_inches_of_mercury(to_pascals(value))
+    }
+
+    /// Converts torr to inches of water.
+    /// # Arguments
+    /// * `value` - The pressure in torr to convert
+    pub fn to_inches_of_water(value: f64) -> f64 {
+        super::pascals::to_inches_of_water(to_pascals(value))
+    }
+
+    /// The torr as a typed unit of pressure.
+    pub const UNIT: Unit<Pressure> = Unit::new("Torr", "torr", to_pascals, super::pascals::to_torr);
+
+    /// Creates a typed pressure quantity expressed in torr.
+    /// # Arguments
+    /// * `value` - The pressure in torr
+    pub fn new(value: f64) -> Quantity<Pressure> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed pressure quantity in torr.
+    /// # Arguments
+    /// * `quantity` - The pressure quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Pressure>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Millimeters of mercury conversion functions
+pub mod millimeters_of_mercury {
+    use crate::conversions::quantity::{Pressure, Quantity, Unit};
+
+    /// Converts millimeters of mercury to pascals.
+    /// # Arguments
+    /// * `value` - The pressure in millimeters of mercury to convert
+    pub fn to_pascals(value: f64) -> f64 {
+        value * super::PASCALS_PER_MILLIMETER_OF_MERCURY
+    }
+
+    /// Converts millimeters of mercury to kilopascals.
+    /// # Arguments
+    /// * `value` - The pressure in millimeters of mercury to convert
+    pub fn to_kilopascals(value: f64) -> f64 {
+        super::pascals::to_kilopascals(to_pascals(value))
+    }
+
+    /// Converts millimeters of mercury to megapascals.
+    /// # Arguments
+    /// * `value` - The pressure in millimeters of mercury to convert
+    pub fn to_megapascals(value: f64) -> f64 {
+        super::pascals::to_megapascals(to_pascals(value))
+    }
+
+    /// Converts millimeters of mercury to bars.
+    /// # Arguments
+    /// * `value` - The pressure in millimeters of mercury to convert
+    pub fn to_bars(value: f64) -> f64 {
+        super::pascals::to_bars(to_pascals(value))
+    }
+
+    /// Converts millimeters of mercury to millibars.
+    /// # Arguments
+    /// * `value` - The pressure in millimeters of mercury to convert
+    pub fn to_millibars(value: f64) -> f64 {
+        super::pascals::to_millibars(to_pascals(value))
+    }
+
+    /// Converts millimeters of mercury to atmospheres.
+    /// # Arguments
+    /// * `value` - The pressure in millimeters of mercury to convert
+    pub fn to_atmospheres(value: f64) -> f64 {
+        super::pascals::to_atmospheres(to_pascals(value))
+    }
+
+    /// Converts millimeters of mercury to pounds per square inch.
+    /// # Arguments
+    /// * `value` - The pressure in millimeters of mercury to convert
+    pub fn to_psi(value: f64) -> f64 {
+        super::pascals::to_psi(to_pascals(value))
+    }
+
+    /// Converts millimeters of mercury to pounds per square inch gauge.
+    /// # Arguments
+    /// * `value` - The pressure in millimeters of mercury to convert
+    pub fn to_psig(value: f64) -> f64 {
+        super::pascals::to_psig(to_pascals(value))
+    }
+
+    /// Converts millimeters of mercury to torr.
+    /// # Arguments
+    /// * `value` - The pressure in millimeters of mercury to convert
+    pub fn to_torr(value: f64) -> f64 {
+        super::pascals::to_torr(to_pascals(value))
+    }
+
+    /// Converts millimeters of mercury to inches of mercury.
+    /// # Arguments
+    /// * `value` - The pressure in millimeters of mercury to convert
+    pub fn to_inches_of_mercury(value: f64) -> f64 {
+        value / 25.4
+    }
+
+    /// Converts millimeters of mercury to inches of water.
+    /// # Arguments
+    /// * `value` - The pressure in millimeters of mercury to convert
+    pub fn to_inches_of_water(value: f64) -> f64 {
+        super::pascals::to_inches_of_water(to_pascals(value))
+    }
+
+    /// The millimeter of mercury as a typed unit of pressure.
+    pub const UNIT: Unit<Pressure> = Unit::new(
+        "mmHg",
+        "millimeter of mercury",
+        to_pascals,
+        super::pascals::to_millimeters_of_mercury,
+    );
+
+    /// Creates a typed pressure quantity expressed in millimeters of mercury.
+    /// # Arguments
+    /// * `value` - The pressure in millimeters of mercury
+    pub fn new(value: f64) -> Quantity<Pressure> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed pressure quantity in millimeters of mercury.
+    /// # Arguments
+    /// * `quantity` - The pressure quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Pressure>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Inches of mercury conversion functions
+pub mod inches_of_mercury {
+    use crate::conversions::quantity::{Pressure, Quantity, Unit};
+
+    /// Converts inches of mercury to pascals.
+    /// # Arguments
+    /// * `value` - The pressure in inches of mercury to convert
+    pub fn to_pascals(value: f64) -> f64 {
+        value * super::PASCALS_PER_INCH_OF_MERCURY
+    }
+
+    /// Converts inches of mercury to kilopascals.
+    /// # Arguments
+    /// * `value` - The pressure in inches of mercury to convert
+    pub fn to_kilopascals(value: f64) -> f64 {
+        super::pascals::to_kilopascals(to_pascals(value))
+    }
+
+    /// Converts inches of mercury to megapascals.
+    /// # Arguments
+    /// * `value` - The pressure in inches of mercury to convert
+    pub fn to_megapascals(value: f64) -> f64 {
+        super::pascals::to_megapascals(to_pascals(value))
+    }
+
+    /// Converts inches of mercury to bars.
+    /// # Arguments
+    /// * `value` - The pressure in inches of mercury to convert
+    pub fn to_bars(value: f64) -> f64 {
+        super::pascals::to_bars(to_pascals(value))
+    }
+
+    /// Converts inches of mercury to millibars.
+    /// # Arguments
+    /// * `value` - The pressure in inches of mercury to convert
+    pub fn to_millibars(value: f64) -> f64 {
+        super::pascals::to_millibars(to_pascals(value))
+    }
+
+    /// Converts inches of mercury to atmospheres.
+    /// # Arguments
+    /// * `value` - The pressure in inches of mercury to convert
+    pub fn to_atmospheres(value: f64) -> f64 {
+        super::pascals::to_atmospheres(to_pascals(value))
+    }
+
+    /// Converts inches of mercury to pounds per square inch.
+    /// # Arguments
+    /// * `value` - The pressure in inches of mercury to convert
+    pub fn to_psi(value: f64) -> f64 {
+        super::pascals::to_psi(to_pascals(value))
+    }
+
+    /// Converts inches of mercury to pounds per square inch gauge.
+    /// # Arguments
+    /// * `value` - The pressure in inches of mercury to convert
+    pub fn to_psig(value: f64) -> f64 {
+        super::pascals::to_psig(to_pascals(value))
+    }
+
+    /// Converts inches of mercury to torr.
+    /// # Arguments
+    /// * `value` - The pressure in inches of mercury to convert
+    pub fn to_torr(value: f64) -> f64 {
+        super::pascals::to_torr(to_pascals(value))
+    }
+
+    /// Converts inches of mercury to millimeters of mercury.
+    /// # Arguments
+    /// * `value` - The pressure in inches of mercury to convert
+    pub fn to_millimeters_of_mercury(value: f64) -> f64 {
+        value * 25.4
+    }
+
+    /// Converts inches of mercury to inches of water.
+    /// # Arguments
+    /// * `value` - The pressure in inches of mercury to convert
+    pub fn to_inches_of_water(value: f64) -> f64 {
+        super::pascals::to_inches_of_water(to_pascals(value))
+    }
+
+    /// The inch of mercury as a typed unit of pressure.
+    pub const UNIT: Unit<Pressure> = Unit::new(
+        "inHg",
+        "inch of mercury",
+        to_pascals,
+        super::pascals::to_inches_of_mercury,
+    );
+
+    /// Creates a typed pressure quantity expressed in inches of mercury.
+    /// # Arguments
+    /// * `value` - The pressure in inches of mercury
+    pub fn new(value: f64) -> Quantity<Pressure> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed pressure quantity in inches of mercury.
+    /// # Arguments
+    /// * `quantity` - The pressure quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Pressure>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Inches of water column conversion functions
+pub mod inches_of_water {
+    use crate::conversions::quantity::{Pressure, Quantity, Unit};
+
+    /// Converts inches of water to pascals.
+    /// # Arguments
+    /// * `value` - The pressure in inches of water to convert
+    pub fn to_pascals(value: f64) -> f64 {
+        value * super::PASCALS_PER_INCH_OF_WATER
+    }
+
+    /// Converts inches of water to kilopascals.
+    /// # Arguments
+    /// * `value` - The pressure in inches of water to convert
+    pub fn to_kilopascals(value: f64) -> f64 {
+        super::pascals::to_kilopascals(to_pascals(value))
+    }
+
+    /// Converts inches of water to megapascals.
+    /// # Arguments
+    /// * `value` - The pressure in inches of water to convert
+    pub fn to_megapascals(value: f64) -> f64 {
+        super::pascals::to_megapascals(to_pascals(value))
+    }
+
+    /// Converts inches of water to bars.
+    /// # Arguments
+    /// * `value` - The pressure in inches of water to convert
+    pub fn to_bars(value: f64) -> f64 {
+        super::pascals::to_bars(to_pascals(value))
+    }
+
+    /// Converts inches of water to millibars.
+    /// # Arguments
+    /// * `value` - The pressure in inches of water to convert
+    pub fn to_millibars(value: f64) -> f64 {
+        super::pascals::to_millibars(to_pascals(value))
+    }
+
+    /// Converts inches of water to atmospheres.
+    /// # Arguments
+    /// * `value` - The pressure in inches of water to convert
+    pub fn to_atmospheres(value: f64) -> f64 {
+        super::pascals::to_atmospheres(to_pascals(value))
+    }
+
+    /// Converts inches of water to pounds per square inch.
+    /// # Arguments
+    /// * `value` - The pressure in inches of water to convert
+    pub fn to_psi(value: f64) -> f64 {
+        super::pascals::to_psi(to_pascals(value))
+    }
+
+    /// Converts inches of water to pounds per square inch gauge.
+    /// # Arguments
+    /// * `value` - The pressure in inches of water to convert
+    pub fn to_psig(value: f64) -> f64 {
+        super::pascals::to_psig(to_pascals(value))
+    }
+
+    /// Converts inches of water to torr.
+    /// # Arguments
+    /// * `value` - The pressure in inches of water to convert
+    pub fn to_torr(value: f64) -> f64 {
+        super::pascals::to_torr(to_pascals(value))
+    }
+
+    /// Converts inches of water to millimeters of mercury.
+    /// # Arguments
+    /// * `value` - The pressure in inches of water to convert
+    pub fn to_millimeters_of_mercury(value: f64) -> f64 {
+        super::pascals::to_millimeters_of_mercury(to_pascals(value))
+    }
+
+    /// Converts inches of water to inches of mercury.
+    /// # Arguments
+    /// * `value` - The pressure in inches of water to convert
+    pub fn to_inches_of_mercury(value: f64) -> f64 {
+        super::pascals::to_inches_of_mercury(to_pascals(value))
+    }
+
+    /// The inch of water as a typed unit of pressure.
+    pub const UNIT: Unit<Pressure> = Unit::new(
+        "inH₂O",
+        "inch of water",
+        to_pascals,
+        super::pascals::to_inches_of_water,
+    );
+
+    /// Creates a typed pressure quantity expressed in inches of water.
+    /// # Arguments
+    /// * `value` - The pressure in inches of water
+    pub fn new(value: f64) -> Quantity<Pressure> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed pressure quantity in inches of water.
+    /// # Arguments
+    /// * `quantity` - The pressure quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Pressure>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Converts a gauge pressure to an absolute pressure.
+///
+/// Both pressures and the reference are in the same unit, so any local
+/// atmosphere can be used (e.g. the barometric reading at altitude).
+/// # Arguments
+/// * `gauge` - The pressure relative to the reference atmosphere
+/// * `atmosphere` - The absolute pressure of the reference atmosphere
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::pressure;
+///
+/// // 30 psig at a site where the barometer reads 12.2 psia
+/// assert!((pressure::gauge_to_absolute(30.0, 12.2) - 42.2).abs() < 1e-12);
+/// ```
+pub fn gauge_to_absolute(gauge: f64, atmosphere: f64) -> f64 {
+    gauge + atmosphere
+}
+
+/// Converts an absolute pressure to a gauge pressure.
+///
+/// Both pressures and the reference are in the same unit.
+/// # Arguments
+/// * `absolute` - The absolute pressure
+/// * `atmosphere` - The absolute pressure of the reference atmosphere
+pub fn absolute_to_gauge(absolute: f64, atmosphere: f64) -> f64 {
+    absolute - atmosphere
+}
+
+/// General pressure conversion function that accepts string unit names
+///
+/// Converts a pressure from one unit to another using string identifiers.
+/// Symbols are case-sensitive, names are case-insensitive, and common
+/// abbreviations are supported. Every unit is absolute except `psig`, which
+/// is relative to the [`STANDARD_ATMOSPHERE`].
+///
+/// # Arguments
+///
+/// * `value` - The numeric value to convert
+/// * `from_unit` - The source unit (e.g., "Pa", "kPa", "MPa", "bar", "mbar", "atm",
+///   "psi", "psia", "psig", "Torr", "mmHg", "inHg", "inH₂O")
+/// * `to_unit` - The target unit using the same abbreviations
+///
+/// # Returns
+/// * `Ok(f64)` - The converted value
+/// * `Err(ConversionError)` - The reason the conversion is not supported, or a
+///   [`ConversionError::BelowVacuum`] if a conversion to or from `psig` would
+///   need a negative absolute pressure
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::convert_pressure;
+///
+/// let kilopascals = convert_pressure(1.0, "atm", "kPa").unwrap();
+/// assert!((kilopascals - 101.325).abs() < 1e-9);
+///
+/// // A tyre at 32 psig holds about 46.7 psi absolute
+/// let absolute = convert_pressure(32.0, "psig", "psia").unwrap();
+/// assert!((absolute - 46.696).abs() < 0.001);
+///
+/// // No gauge reads more than one atmosphere below the atmosphere
+/// assert!(convert_pressure(-20.0, "psig", "psia").is_err());
+/// ```
+pub fn convert_pressure(
+    value: f64,
+    from_unit: &str,
+    to_unit: &str,
+) -> Result<f64, ConversionError> {
+    ConversionError::ensure_finite(value)?;
+    let registry = UnitRegistry::global();
+    let from = registry.resolve(from_unit, Category::Pressure)?;
+    let to = registry.resolve(to_unit, Category::Pressure)?;
+    if from.offset != 0.0 || to.offset != 0.0 {
+        ensure_above_vacuum(value, from_unit, from)?;
+    }
+    registry.convert(value, from_unit, to_unit, Category::Pressure)
+}
+
+/// Rejects a pressure below a perfect vacuum.
+///
+/// Only applied when a gauge unit is involved, since absolute units are also
+/// used for pressure differences, which may be negative. A micropascal of
+/// slack lets the vacuum reading itself (about -14.696 psig) through.
+fn ensure_above_vacuum(value: f64, input: &str, unit: &UnitDef) -> Result<(), ConversionError> {
+    if unit.convert_to_base(value) >= -1e-6 {
+        return Ok(());
+    }
+
+    let vacuum = unit.convert_from_base(0.0);
+    Err(ConversionError::BelowVacuum {
+        value,
+        unit: input.to_string(),
+        vacuum: (vacuum * 1e9).round() / 1e9,
+    })
+}
+
+/// Pressure units known to the [`UnitRegistry`], with pascals as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new("Pa", "pascal", &["pascals"], Category::Pressure, 1.0).with_prefixes(),
+        UnitDef::new(
+            "kPa",
+            "kilopascal",
+            &["kilopascals"],
+            Category::Pressure,
+            kilopascals::to_pascals(1.0),
+        ),
+        UnitDef::new(
+            "MPa",
+            "megapascal",
+            &["megapascals"],
+            Category::Pressure,
+            megapascals::to_pascals(1.0),
+        ),
+        UnitDef::new(
+            "bar",
+            "bar",
+            &["bars"],
+            Category::Pressure,
+            bars::to_pascals(1.0),
+        ),
+        UnitDef::new(
+            "mbar",
+            "millibar",
+            &["millibars"],
+            Category::Pressure,
+            millibars::to_pascals(1.0),
+        ),
+        UnitDef::new(
+            "atm",
+            "standard atmosphere",
+            &["atmosphere", "atmospheres"],
+            Category::Pressure,
+            atmospheres::to_pascals(1.0),
+        ),
+        UnitDef::new(
+            "psi",
+            "pound per square inch",
+            &["psia", "pounds per square inch", "lbf/in²", "lbf/in2"],
+            Category::Pressure,
+            psi::to_pascals(1.0),
+        )
+        .with_exact_factor("0.45359237*9.80665/0.00064516"),
+        UnitDef::new(
+            "psig",
+            "pound per square inch gauge",
+            &["pounds per square inch gauge"],
+            Category::Pressure,
+            psi::to_pascals(1.0),
+        )
+        .with_offset(psi::to_psig(0.0))
+        .with_exact_factor("0.45359237*9.80665/0.00064516")
+        .with_exact_offset("-101325/0.45359237/9.80665*0.00064516"),
+        UnitDef::new(
+            "Torr",
+            "torr",
+            &["torrs"],
+            Category::Pressure,
+            torr::to_pascals(1.0),
+        )
+        .with_exact_factor("101325/760"),
+        UnitDef::new(
+            "mmHg",
+            "millimeter of mercury",
+            &["millimeters of mercury", "millimetres of mercury"],
+            Category::Pressure,
+            millimeters_of_mercury::to_pascals(1.0),
+        ),
+        UnitDef::new(
+            "inHg",
+            "inch of mercury",
+            &["inches of mercury"],
+            Category::Pressure,
+            inches_of_mercury::to_pascals(1.0),
+        ),
+        UnitDef::new(
+            "inH₂O",
+            "inch of water",
+            &["inH2O", "inches of water", "inwc"],
+            Category::Pressure,
+            inches_of_water::to_pascals(1.0),
+        ),
+    ]
+}
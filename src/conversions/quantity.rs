@@ -0,0 +1,775 @@
+//! Typed quantities with compile-time dimension checking
+//! A `Quantity<D>` carries both its value and its unit, and can only be
+//! converted to (or combined with) quantities of the same dimension `D`.
+//!
+//! ```rust
+//! use conversions_rs::conversions::*;
+//!
+//! let run = length::kilometers::new(5.0);
+//! let feet = run.to(length::feet::UNIT);
+//! assert!((feet.value() - 16404.2).abs() < 0.1);
+//!
+//! // Quantities of the same dimension can be added, whatever their units
+//! let total = run + length::miles::new(1.0);
+//! assert!((length::meters::value_of(total) - 6609.34).abs() < 0.01);
+//! ```
+//!
+//! Mixing dimensions is rejected by the compiler:
+//!
+//! ```compile_fail
+//! use conversions_rs::conversions::*;
+//!
+//! let distance = length::meters::new(10.0);
+//! let mass = weight::pounds::value_of(distance);
+//! ```
+//!
+//! Absolute temperatures cannot be added; their difference is a
+//! [`TemperatureInterval`], which can be added to a temperature:
+//!
+//! ```rust
+//! use conversions_rs::conversions::*;
+//!
+//! let rise = temperature::celsius::new(30.0) - temperature::celsius::new(20.0);
+//! assert!((temperature::delta_fahrenheit::value_of(rise) - 18.0).abs() < 1e-9);
+//!
+//! let supply = temperature::fahrenheit::new(55.0) + rise;
+//! assert!((supply.value() - 73.0).abs() < 1e-9);
+//! ```
+//!
+//! ```compile_fail
+//! use conversions_rs::conversions::*;
+//!
+//! let total = temperature::celsius::new(20.0) + temperature::celsius::new(10.0);
+//! ```
+
+use super::error::ConversionError;
+use std::cmp::Ordering;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A physical dimension that quantities can be measured in.
+///
+/// Each dimension names the base unit that every conversion goes through.
+pub trait Dimension: Copy + fmt::Debug {
+    /// Human-readable name of the dimension (e.g. `"length"`)
+    const NAME: &'static str;
+
+    /// The base unit of the dimension (e.g. meters for length)
+    const BASE: Unit<Self>;
+}
+
+/// A dimension whose quantities can be added and scaled.
+///
+/// Every dimension is additive except absolute [`Temperature`]: 20°C plus
+/// 10°C is not 30°C in any meaningful sense. Temperatures are instead
+/// subtracted into a [`TemperatureInterval`], which can be added back.
+///
+/// The arithmetic is done in the left-hand unit, so gauge pressures add as
+/// gauge readings: 10 psig plus 10 psig is 20 psig. Decibel and hydrometer
+/// units are not linear, so they are combined through the base unit instead:
+/// 10 dBm plus 10 dBm is 20 mW, about 13.01 dBm, and two °API readings add up
+/// as the densities they stand for.
+pub trait Additive: Dimension {}
+
+/// An additive dimension whose quantities can also be subtracted and negated.
+///
+/// [`Power`] and [`Density`] are left out because their decibel and
+/// hydrometer units have no meaningful difference or negation: subtracting
+/// quantities of those dimensions returns a `Result` instead, which is a
+/// [`ConversionError::NotSubtractable`] when the left-hand unit is one of them.
+pub trait Subtractive: Additive {}
+
+/// Length dimension (base unit: meter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Length;
+
+/// Mass dimension (base unit: kilogram)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mass;
+
+/// Thermodynamic temperature dimension (base unit: kelvin)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Temperature;
+
+/// Time dimension (base unit: second)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Time;
+
+/// Electric current dimension (base unit: ampere)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Current;
+
+/// Amount of substance dimension (base unit: mole)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Amount;
+
+/// Luminous intensity dimension (base unit: candela)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LuminousIntensity;
+
+/// Area dimension (base unit: square meter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Area;
+
+/// Volume dimension (base unit: liter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Volume;
+
+/// Speed dimension (base unit: meter per second)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Speed;
+
+/// Pressure dimension (base unit: pascal)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pressure;
+
+/// Energy dimension (base unit: joule)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Energy;
+
+/// Power dimension (base unit: watt)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Power;
+
+/// Force dimension (base unit: newton)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Force;
+
+/// Torque dimension (base unit: newton-meter)
+///
+/// Distinct from [`Energy`] although both are N·m in SI base units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Torque;
+
+/// Frequency dimension (base unit: hertz)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Frequency;
+
+/// Plane angle dimension (base unit: degree)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Angle;
+
+/// Solid angle dimension (base unit: steradian)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SolidAngle;
+
+/// Digital storage dimension (base unit: bit)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Data;
+
+/// Data rate dimension (base unit: bit per second)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DataRate;
+
+/// Density dimension (base unit: kilogram per cubic meter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Density;
+
+/// Volumetric flow rate dimension (base unit: liter per second)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FlowRate;
+
+/// Mass flow rate dimension (base unit: kilogram per second)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MassFlowRate;
+
+/// Voltage dimension (base unit: volt)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Voltage;
+
+/// Electrical resistance dimension (base unit: ohm)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Resistance;
+
+/// Electrical conductance dimension (base unit: siemens)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Conductance;
+
+/// Electric charge dimension (base unit: coulomb)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Charge;
+
+/// Capacitance dimension (base unit: farad)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capacitance;
+
+/// Inductance dimension (base unit: henry)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Inductance;
+
+/// Luminous flux dimension (base unit: lumen)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LuminousFlux;
+
+/// Illuminance dimension (base unit: lux)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Illuminance;
+
+/// Luminance dimension (base unit: candela per square meter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Luminance;
+
+/// Temperature difference dimension (base unit: kelvin)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TemperatureInterval;
+
+impl Dimension for Length {
+    const NAME: &'static str = "length";
+    const BASE: Unit<Self> = super::length::meters::UNIT;
+}
+
+impl Dimension for Mass {
+    const NAME: &'static str = "mass";
+    const BASE: Unit<Self> = super::weight::kilograms::UNIT;
+}
+
+impl Dimension for Temperature {
+    const NAME: &'static str = "temperature";
+    const BASE: Unit<Self> = super::temperature::kelvin::UNIT;
+}
+
+impl Dimension for Time {
+    const NAME: &'static str = "time";
+    const BASE: Unit<Self> = super::time::seconds::UNIT;
+}
+
+impl Dimension for Current {
+    const NAME: &'static str = "electric current";
+    const BASE: Unit<Self> = super::current::amperes::UNIT;
+}
+
+impl Dimension for Amount {
+    const NAME: &'static str = "amount of substance";
+    const BASE: Unit<Self> = super::substance::moles::UNIT;
+}
+
+impl Dimension for LuminousIntensity {
+    const NAME: &'static str = "luminous intensity";
+    const BASE: Unit<Self> = super::luminous_intensity::candela::UNIT;
+}
+
+impl Dimension for Area {
+    const NAME: &'static str = "area";
+    const BASE: Unit<Self> = super::area::square_meters::UNIT;
+}
+
+impl Dimension for Volume {
+    const NAME: &'static str = "volume";
+    const BASE: Unit<Self> = super::volume::liters::UNIT;
+}
+
+impl Dimension for Speed {
+    const NAME: &'static str = "speed";
+    const BASE: Unit<Self> = super::speed::meters_per_second::UNIT;
+}
+
+impl Dimension for Pressure {
+    const NAME: &'static str = "pressure";
+    const BASE: Unit<Self> = super::pressure::pascals::UNIT;
+}
+
+impl Dimension for Energy {
+    const NAME: &'static str = "energy";
+    const BASE: Unit<Self> = super::energy::joules::UNIT;
+}
+
+impl Dimension for Power {
+    const NAME: &'static str = "power";
+    const BASE: Unit<Self> = super::power::watts::UNIT;
+}
+
+impl Dimension for Force {
+    const NAME: &'static str = "force";
+    const BASE: Unit<Self> = super::force::newtons::UNIT;
+}
+
+impl Dimension for Torque {
+    const NAME: &'static str = "torque";
+    const BASE: Unit<Self> = super::torque::newton_meters::UNIT;
+}
+
+impl Dimension for Frequency {
+    const NAME: &'static str = "frequency";
+    const BASE: Unit<Self> = super::frequency::hertz::UNIT;
+}
+
+impl Dimension for Angle {
+    const NAME: &'static str = "angle";
+    const BASE: Unit<Self> = super::angle::degrees::UNIT;
+}
+
+impl Dimension for SolidAngle {
+    const NAME: &'static str = "solid angle";
+    const BASE: Unit<Self> = super::solid_angle::steradians::UNIT;
+}
+
+impl Dimension for Data {
+    const NAME: &'static str = "amount of data";
+    const BASE: Unit<Self> = super::data::bits::UNIT;
+}
+
+impl Dimension for DataRate {
+    const NAME: &'static str = "data rate";
+    const BASE: Unit<Self> = super::data_rate::bits_per_second::UNIT;
+}
+
+impl Dimension for Density {
+    const NAME: &'static str = "density";
+    const BASE: Unit<Self> = super::density::kilograms_per_cubic_meter::UNIT;
+}
+
+impl Dimension for FlowRate {
+    const NAME: &'static str = "flow rate";
+    const BASE: Unit<Self> = super::flow_rate::liters_per_second::UNIT;
+}
+
+impl Dimension for MassFlowRate {
+    const NAME: &'static str = "mass flow rate";
+    const BASE: Unit<Self> = super::mass_flow_rate::kilograms_per_second::UNIT;
+}
+
+impl Dimension for Voltage {
+    const NAME: &'static str = "voltage";
+    const BASE: Unit<Self> = super::voltage::volts::UNIT;
+}
+
+impl Dimension for Resistance {
+    const NAME: &'static str = "resistance";
+    const BASE: Unit<Self> = super::resistance::ohms::UNIT;
+}
+
+impl Dimension for Conductance {
+    const NAME: &'static str = "conductance";
+    const BASE: Unit<Self> = super::conductance::siemens::UNIT;
+}
+
+impl Dimension for Charge {
+    const NAME: &'static str = "charge";
+    const BASE: Unit<Self> = super::charge::coulombs::UNIT;
+}
+
+impl Dimension for Capacitance {
+    const NAME: &'static str = "capacitance";
+    const BASE: Unit<Self> = super::capacitance::farads::UNIT;
+}
+
+impl Dimension for Inductance {
+    const NAME: &'static str = "inductance";
+    const BASE: Unit<Self> = super::inductance::henries::UNIT;
+}
+
+impl Dimension for LuminousFlux {
+    const NAME: &'static str = "luminous flux";
+    const BASE: Unit<Self> = super::luminous_flux::lumens::UNIT;
+}
+
+impl Dimension for Illuminance {
+    const NAME: &'static str = "illuminance";
+    const BASE: Unit<Self> = super::illuminance::lux::UNIT;
+}
+
+impl Dimension for Luminance {
+    const NAME: &'static str = "luminance";
+    const BASE: Unit<Self> = super::luminance::nits::UNIT;
+}
+
+impl Dimension for TemperatureInterval {
+    const NAME: &'static str = "temperature interval";
+    const BASE: Unit<Self> = super::temperature::delta_kelvin::UNIT;
+}
+
+macro_rules! additive {
+    ($($dimension:ty),* $(,)?) => {
+        $(
+            impl Additive for $dimension {}
+            impl Subtractive for $dimension {}
+        )*
+    };
+}
+
+additive!(
+    Length,
+    Mass,
+    Time,
+    Current,
+    Amount,
+    LuminousIntensity,
+    Area,
+    Volume,
+    Speed,
+    Pressure,
+    Energy,
+    Force,
+    Torque,
+    Frequency,
+    Angle,
+    SolidAngle,
+    Data,
+    DataRate,
+    FlowRate,
+    MassFlowRate,
+    Voltage,
+    Resistance,
+    Conductance,
+    Charge,
+    Capacitance,
+    Inductance,
+    LuminousFlux,
+    Illuminance,
+    Luminance,
+    TemperatureInterval,
+);
+
+impl Additive for Power {}
+impl Additive for Density {}
+
+/// How a unit's values relate to the base unit of its dimension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Scale {
+    /// Proportional to the base unit, possibly with an offset (e.g. psig)
+    Linear,
+    /// Logarithmic in the base unit (e.g. dBm)
+    Decibel,
+    /// Inversely related to the base unit (e.g. °API)
+    Reciprocal,
+}
+
+/// A unit of measurement belonging to dimension `D`.
+///
+/// Units are defined by the per-unit modules (e.g. `length::feet::UNIT`) and
+/// convert to and from the dimension's base unit using the same functions as
+/// the modular API, so both APIs always agree.
+pub struct Unit<D> {
+    symbol: &'static str,
+    name: &'static str,
+    to_base: fn(f64) -> f64,
+    from_base: fn(f64) -> f64,
+    scale: Scale,
+    dimension: PhantomData<D>,
+}
+
+impl<D> Unit<D> {
+    /// Defines a unit from its conversions to and from the base unit.
+    /// # Arguments
+    /// * `symbol` - The unit symbol (e.g. `"ft"`)
+    /// * `name` - The unit name (e.g. `"foot"`)
+    /// * `to_base` - Converts a value in this unit to the base unit
+    /// * `from_base` - Converts a value in the base unit to this unit
+    pub const fn new(
+        symbol: &'static str,
+        name: &'static str,
+        to_base: fn(f64) -> f64,
+        from_base: fn(f64) -> f64,
+    ) -> Self {
+        Unit {
+            symbol,
+            name,
+            to_base,
+            from_base,
+            scale: Scale::Linear,
+            dimension: PhantomData,
+        }
+    }
+
+    /// Marks the unit as logarithmic in the base unit, like dBm, so that its
+    /// quantities are added through the base unit.
+    pub const fn with_decibel_scale(mut self) -> Self {
+        self.scale = Scale::Decibel;
+        self
+    }
+
+    /// Marks the unit as inversely related to the base unit, like °API, so
+    /// that its quantities are added through the base unit.
+    pub const fn with_reciprocal_scale(mut self) -> Self {
+        self.scale = Scale::Reciprocal;
+        self
+    }
+
+    /// Returns the unit symbol (e.g. `"ft"`).
+    pub fn symbol(&self) -> &'static str {
+        self.symbol
+    }
+
+    /// Returns the unit name (e.g. `"foot"`).
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Converts a value in this unit to the base unit of the dimension.
+    pub fn convert_to_base(&self, value: f64) -> f64 {
+        (self.to_base)(value)
+    }
+
+    /// Converts a value in the base unit of the dimension to this unit.
+    pub fn convert_from_base(&self, value: f64) -> f64 {
+        (self.from_base)(value)
+    }
+}
+
+impl<D> Clone for Unit<D> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<D> Copy for Unit<D> {}
+
+impl<D> PartialEq for Unit<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.symbol == other.symbol
+    }
+}
+
+impl<D> fmt::Debug for Unit<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Unit")
+            .field("symbol", &self.symbol)
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+impl<D> fmt::Display for Unit<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.symbol)
+    }
+}
+
+/// A value tagged with its unit and dimension.
+///
+/// Conversions are only possible between units of the same dimension, so a
+/// length can never be passed where a mass is expected.
+#[derive(Debug, Clone, Copy)]
+pub struct Quantity<D> {
+    value: f64,
+    unit: Unit<D>,
+}
+
+impl<D: Dimension> Quantity<D> {
+    /// Creates a new quantity.
+    /// # Arguments
+    /// * `value` - The numeric value
+    /// * `unit` - The unit the value is expressed in
+    pub fn new(value: f64, unit: Unit<D>) -> Self {
+        Quantity { value, unit }
+    }
+
+    /// Returns the numeric value in the quantity's own unit.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the unit the quantity is expressed in.
+    pub fn unit(&self) -> Unit<D> {
+        self.unit
+    }
+
+    /// Returns the numeric value expressed in the dimension's base unit.
+    pub fn base_value(&self) -> f64 {
+        self.unit.convert_to_base(self.value)
+    }
+
+    /// Returns the numeric value expressed in another unit of the same dimension.
+    /// # Arguments
+    /// * `unit` - The unit to express the value in
+    pub fn value_in(&self, unit: Unit<D>) -> f64 {
+        if unit == self.unit {
+            self.value
+        } else {
+            unit.convert_from_base(self.base_value())
+        }
+    }
+
+    /// Converts the quantity to another unit of the same dimension.
+    /// # Arguments
+    /// * `unit` - The target unit
+    pub fn to(self, unit: Unit<D>) -> Self {
+        Quantity::new(self.value_in(unit), unit)
+    }
+
+    /// Converts the quantity to the dimension's base unit.
+    pub fn to_base(self) -> Self {
+        self.to(D::BASE)
+    }
+
+    /// Returns a quantity in the same unit as `self` from a value in the base unit.
+    fn with_base_value(self, base: f64) -> Self {
+        Quantity::new(self.unit.convert_from_base(base), self.unit)
+    }
+
+    /// Whether the quantity's unit is linear in the base unit, so that
+    /// arithmetic can be done on its own values.
+    fn is_linear(&self) -> bool {
+        self.unit.scale == Scale::Linear
+    }
+
+    /// Subtracts `other`, rejecting decibel and reciprocal left-hand units.
+    fn checked_difference(self, other: Self) -> Result<Self, ConversionError> {
+        self.ensure_linear()?;
+        Ok(Quantity::new(
+            self.value - other.value_in(self.unit),
+            self.unit,
+        ))
+    }
+
+    /// Rejects units whose quantities cannot be subtracted or negated.
+    fn ensure_linear(&self) -> Result<(), ConversionError> {
+        if self.is_linear() {
+            Ok(())
+        } else {
+            Err(ConversionError::NotSubtractable {
+                unit: self.unit.symbol.to_string(),
+            })
+        }
+    }
+}
+
+impl<D: Dimension> fmt::Display for Quantity<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(precision) = f.precision() {
+            write!(f, "{:.*} {}", precision, self.value, self.unit)
+        } else {
+            write!(f, "{} {}", self.value, self.unit)
+        }
+    }
+}
+
+/// Quantities are compared in the base unit, so scales that run backwards
+/// (°De, °API) still order by the physical quantity.
+impl<D: Dimension> PartialEq for Quantity<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.base_value() == other.base_value()
+    }
+}
+
+impl<D: Dimension> PartialOrd for Quantity<D> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.base_value().partial_cmp(&other.base_value())
+    }
+}
+
+/// Adds two quantities of the same dimension; the result keeps the left-hand unit.
+impl<D: Additive> Add for Quantity<D> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        if self.is_linear() {
+            Quantity::new(self.value + other.value_in(self.unit), self.unit)
+        } else {
+            self.with_base_value(self.base_value() + other.base_value())
+        }
+    }
+}
+
+/// Subtracts two quantities of the same dimension; the result keeps the left-hand unit.
+impl<D: Subtractive> Sub for Quantity<D> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Quantity::new(self.value - other.value_in(self.unit), self.unit)
+    }
+}
+
+impl<D: Additive> Mul<f64> for Quantity<D> {
+    type Output = Self;
+
+    fn mul(self, factor: f64) -> Self {
+        if self.is_linear() {
+            Quantity::new(self.value * factor, self.unit)
+        } else {
+            self.with_base_value(self.base_value() * factor)
+        }
+    }
+}
+
+impl<D: Additive> Div<f64> for Quantity<D> {
+    type Output = Self;
+
+    fn div(self, divisor: f64) -> Self {
+        if self.is_linear() {
+            Quantity::new(self.value / divisor, self.unit)
+        } else {
+            self.with_base_value(self.base_value() / divisor)
+        }
+    }
+}
+
+impl<D: Subtractive> Neg for Quantity<D> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Quantity::new(-self.value, self.unit)
+    }
+}
+
+macro_rules! fallible_difference {
+    ($($dimension:ty),* $(,)?) => {
+        $(
+            /// Subtracts two quantities of the same dimension; the result keeps
+            /// the left-hand unit, which must not be a decibel or hydrometer unit.
+            impl Sub for Quantity<$dimension> {
+                type Output = Result<Self, ConversionError>;
+
+                fn sub(self, other: Self) -> Result<Self, ConversionError> {
+                    self.checked_difference(other)
+                }
+            }
+
+            /// Negates a quantity, which must not be in a decibel or hydrometer unit.
+            impl Neg for Quantity<$dimension> {
+                type Output = Result<Self, ConversionError>;
+
+                fn neg(self) -> Result<Self, ConversionError> {
+                    self.ensure_linear()?;
+                    Ok(Quantity::new(-self.value, self.unit))
+                }
+            }
+        )*
+    };
+}
+
+fallible_difference!(Power, Density);
+
+/// The interval unit matching a temperature scale's degree, e.g. `ΔF` for
+/// `°F`; scales without one use kelvins.
+fn interval_unit(scale: Unit<Temperature>) -> Unit<TemperatureInterval> {
+    match scale.symbol() {
+        "°C" => super::temperature::delta_celsius::UNIT,
+        "°F" => super::temperature::delta_fahrenheit::UNIT,
+        "°R" => super::temperature::delta_rankine::UNIT,
+        _ => super::temperature::delta_kelvin::UNIT,
+    }
+}
+
+/// Subtracts two temperatures; the difference is an interval in the left-hand
+/// scale's degrees.
+impl Sub for Quantity<Temperature> {
+    type Output = Quantity<TemperatureInterval>;
+
+    fn sub(self, other: Self) -> Quantity<TemperatureInterval> {
+        let kelvins = self.base_value() - other.base_value();
+        let unit = interval_unit(self.unit);
+        Quantity::new(unit.convert_from_base(kelvins), unit)
+    }
+}
+
+/// Raises a temperature by an interval; the result keeps the temperature's unit.
+impl Add<Quantity<TemperatureInterval>> for Quantity<Temperature> {
+    type Output = Self;
+
+    fn add(self, interval: Quantity<TemperatureInterval>) -> Self {
+        let kelvins = self.base_value() + interval.base_value();
+        Quantity::new(self.unit.convert_from_base(kelvins), self.unit)
+    }
+}
+
+/// Lowers a temperature by an interval; the result keeps the temperature's unit.
+impl Sub<Quantity<TemperatureInterval>> for Quantity<Temperature> {
+    type Output = Self;
+
+    fn sub(self, interval: Quantity<TemperatureInterval>) -> Self {
+        let kelvins = self.base_value() - interval.base_value();
+        Quantity::new(self.unit.convert_from_base(kelvins), self.unit)
+    }
+}
@@ -0,0 +1,561 @@
+//! Central registry of every supported unit
+//! The string-based converters, the CLI help text, the interactive menu and
+//! the WASM unit lists are all derived from this registry, so they always agree
+//! on which units exist and what they are called.
+//!
+//! Symbols are case-sensitive, as in SI (`mA` is a milliampere, `MA` a
+//! megaampere), while names and other spellings are also accepted in any case.
+//! An input that matches no spelling exactly and whose case-folded form fits
+//! more than one unit is rejected as ambiguous instead of guessed at, and so is
+//! a spelling deliberately shared by several units (such as `"cal"`, which
+//! could be a thermochemical or an international table calorie).
+//!
+//! Coherent SI units such as the meter, gram, second or ampere also accept
+//! every SI prefix from quecto to quetta (`"nm"`, `"Mg"`, `"ks"`, `"GA"`,
+//! `"fmol"`, `"µcd"`), without a hand-written entry for each prefixed form.
+//! Micro can be written with the Greek mu (`μ`), the micro sign (`µ`) or `u`.
+//!
+//! Each category module contributes its units through a `units()` function;
+//! conversion factors are taken from the per-unit modules themselves, so the
+//! string API and the modular API can never drift apart.
+//!
+//! ```rust
+//! use conversions_rs::{Category, UnitRegistry};
+//!
+//! let registry = UnitRegistry::global();
+//! let foot = registry.resolve("feet", Category::Length).unwrap();
+//! assert_eq!(foot.symbol, "ft");
+//!
+//! let meters = registry.convert(100.0, "ft", "m", Category::Length).unwrap();
+//! assert!((meters - 30.48).abs() < 0.01);
+//! ```
+
+use super::category::Category;
+use super::error::ConversionError;
+use super::prefix::SI_PREFIXES;
+use std::sync::OnceLock;
+
+/// A unit known to the registry.
+///
+/// A value `v` in this unit corresponds to `origin + (v - offset) * factor` in
+/// the base unit of its category. The offset is zero for every unit except
+/// temperature scales and gauge pressures, where it is the unit's reading at
+/// `origin` base units. The origin is zero except for the temperature scales
+/// that are not absolute, whose offset is their reading at the freezing point
+/// of water (273.15 K), so that everyday conversions between them stay exact
+/// in `f64` while their base values are still kelvins.
+///
+/// Decibel units (dBm, dBW) are logarithmic instead: a level `v` corresponds to
+/// `factor * 10^(v / 10)` base units, `factor` being the reference power.
+/// Reciprocal scales (°API, °Baumé) are hyperbolic: a reading `v` corresponds to
+/// `factor / (v - offset)` base units.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct UnitDef {
+    /// Canonical symbol, as shown in help text and unit lists (e.g. `"ft"`)
+    pub symbol: &'static str,
+    /// Singular name (e.g. `"foot"`)
+    pub name: &'static str,
+    /// Other accepted spellings (plurals, ASCII forms, abbreviations)
+    pub aliases: &'static [&'static str],
+    /// The category the unit belongs to
+    pub category: Category,
+    /// Number of base units in one of this unit
+    pub factor: f64,
+    /// Reading of this unit at `origin` (temperatures and gauge pressures), or
+    /// the offset of a reciprocal scale
+    pub offset: f64,
+    /// Base value at which the unit reads `offset`; zero except for relative
+    /// temperature scales, which are anchored at the ice point
+    pub origin: f64,
+    /// Whether SI prefixes can be applied to the unit (e.g. `k` + `m` = `km`)
+    pub prefixable: bool,
+    /// Exact definition of `factor` when it is not a plain decimal, written as
+    /// decimals joined by `*` and `/` (e.g. `"4.54609/160"`); used by exact
+    /// conversions, and required whenever `factor` is computed in floating point
+    pub exact_factor: Option<&'static str>,
+    /// Exact definition of `offset` when it is not a plain decimal, in the same
+    /// notation as `exact_factor`
+    pub exact_offset: Option<&'static str>,
+    /// Whether the unit is a decibel level relative to `factor` base units
+    pub decibel: bool,
+    /// Whether the unit is a reciprocal scale, reading `factor / (v - offset)`
+    /// base units
+    pub reciprocal: bool,
+    /// Whether exact conversions support the unit; false for decibel units
+    /// and for irrational factors (e.g. rad/s, which involves π)
+    pub exact: bool,
+}
+
+impl UnitDef {
+    /// Defines a unit that is a fixed multiple of its category's base unit.
+    /// # Arguments
+    /// * `symbol` - The canonical symbol
+    /// * `name` - The singular name
+    /// * `aliases` - Other accepted spellings
+    /// * `category` - The category the unit belongs to
+    /// * `factor` - Number of base units in one of this unit
+    pub(crate) fn new(
+        symbol: &'static str,
+        name: &'static str,
+        aliases: &'static [&'static str],
+        category: Category,
+        factor: f64,
+    ) -> Self {
+        UnitDef {
+            symbol,
+            name,
+            aliases,
+            category,
+            factor,
+            offset: 0.0,
+            origin: 0.0,
+            prefixable: false,
+            exact_factor: None,
+            exact_offset: None,
+            decibel: false,
+            reciprocal: false,
+            exact: true,
+        }
+    }
+
+    /// Records the exact definition of a factor that `f64` can only approximate.
+    /// # Arguments
+    /// * `definition` - Decimals joined by `*` and `/` (e.g. `"5/9"`)
+    pub(crate) fn with_exact_factor(mut self, definition: &'static str) -> Self {
+        self.exact_factor = Some(definition);
+        self
+    }
+
+    /// Sets the offset of a unit whose zero differs from the base unit's.
+    pub(crate) fn with_offset(mut self, offset: f64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Anchors the unit's `offset` reading at `origin` base units instead of at
+    /// the base unit's zero.
+    pub(crate) fn with_origin(mut self, origin: f64) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// Records the exact definition of an offset that `f64` can only approximate.
+    /// # Arguments
+    /// * `definition` - Decimals joined by `*` and `/` (e.g. `"-101325/6894.757"`)
+    pub(crate) fn with_exact_offset(mut self, definition: &'static str) -> Self {
+        self.exact_offset = Some(definition);
+        self
+    }
+
+    /// Makes the unit a decibel level relative to `factor` base units.
+    pub(crate) fn with_decibel_scale(mut self) -> Self {
+        self.decibel = true;
+        self.exact = false;
+        self
+    }
+
+    /// Makes the unit a reciprocal scale, whose reading `v` corresponds to
+    /// `factor / (v - offset)` base units.
+    pub(crate) fn with_reciprocal_scale(mut self) -> Self {
+        self.reciprocal = true;
+        self
+    }
+
+    /// Marks a factor as irrational, so exact conversions reject the unit.
+    pub(crate) fn with_irrational_factor(mut self) -> Self {
+        self.exact = false;
+        self
+    }
+
+    /// Allows every SI prefix to be applied to a coherent unit.
+    ///
+    /// Only meaningful for linear units without an offset.
+    pub(crate) fn with_prefixes(mut self) -> Self {
+        self.prefixable = true;
+        self
+    }
+
+    /// Returns the symbol, the name and every alias of the unit.
+    pub fn spellings(&self) -> impl Iterator<Item = &'static str> + '_ {
+        [self.symbol, self.name]
+            .into_iter()
+            .chain(self.aliases.iter().copied())
+    }
+
+    /// Converts a value in this unit to the base unit of its category.
+    pub fn convert_to_base(&self, value: f64) -> f64 {
+        if self.decibel {
+            self.factor * 10f64.powf(value / 10.0)
+        } else if self.reciprocal {
+            self.factor / (value - self.offset)
+        } else {
+            self.origin + (value - self.offset) * self.factor
+        }
+    }
+
+    /// Converts a value in the base unit of its category to this unit.
+    ///
+    /// For decibel units the value must be positive; zero gives negative
+    /// infinity and negative values NaN. For reciprocal scales zero gives
+    /// infinity.
+    pub fn convert_from_base(&self, value: f64) -> f64 {
+        if self.decibel {
+            10.0 * (value / self.factor).log10()
+        } else if self.reciprocal {
+            self.factor / value + self.offset
+        } else {
+            (value - self.origin) / self.factor + self.offset
+        }
+    }
+
+    /// Checks whether `input` is exactly one of the unit's spellings.
+    fn matches_exactly(&self, input: &str) -> bool {
+        self.spellings().any(|spelling| spelling == input)
+    }
+
+    /// Checks whether `input` is one of the unit's spellings, ignoring case.
+    fn matches_ignoring_case(&self, input: &str) -> bool {
+        let input = input.to_lowercase();
+        self.spellings()
+            .any(|spelling| spelling.to_lowercase() == input)
+    }
+}
+
+/// The set of all units the library can convert between.
+#[derive(Debug, Clone)]
+pub struct UnitRegistry {
+    units: Vec<UnitDef>,
+    prefixed: Vec<UnitDef>,
+}
+
+impl UnitRegistry {
+    /// Returns the registry of all built-in units.
+    pub fn global() -> &'static UnitRegistry {
+        static REGISTRY: OnceLock<UnitRegistry> = OnceLock::new();
+        REGISTRY.get_or_init(UnitRegistry::builtin)
+    }
+
+    /// Builds the registry from the units contributed by each category module.
+    fn builtin() -> Self {
+        let units = [
+            super::length::units(),
+            super::weight::units(),
+            super::temperature::units(),
+            super::volume::units(),
+            super::time::units(),
+            super::current::units(),
+            super::substance::units(),
+            super::luminous_intensity::units(),
+            super::area::units(),
+            super::speed::units(),
+            super::pressure::units(),
+            super::energy::units(),
+            super::power::units(),
+            super::force::units(),
+            super::torque::units(),
+            super::frequency::units(),
+            super::angle::units(),
+            super::solid_angle::units(),
+            super::data::units(),
+            super::data_rate::units(),
+            super::density::units(),
+            super::flow_rate::units(),
+            super::mass_flow_rate::units(),
+            super::voltage::units(),
+            super::resistance::units(),
+            super::conductance::units(),
+            super::charge::units(),
+            super::capacitance::units(),
+            super::inductance::units(),
+            super::luminous_flux::units(),
+            super::illuminance::units(),
+            super::luminance::units(),
+            super::temperature::interval_units(),
+        ]
+        .concat();
+        let prefixed = prefixed_units(&units);
+
+        UnitRegistry { units, prefixed }
+    }
+
+    /// Returns every registered unit.
+    ///
+    /// Prefixed forms generated from prefixable units (e.g. `"GA"`) are not
+    /// listed, but [`UnitRegistry::resolve`] accepts them.
+    pub fn units(&self) -> &[UnitDef] {
+        &self.units
+    }
+
+    /// Returns the units of one category, in registration order.
+    /// # Arguments
+    /// * `category` - The category to list
+    pub fn units_in(&self, category: Category) -> impl Iterator<Item = &UnitDef> + '_ {
+        self.units
+            .iter()
+            .filter(move |unit| unit.category == category)
+    }
+
+    /// Returns the canonical symbols of one category (e.g. for help text).
+    /// # Arguments
+    /// * `category` - The category to list
+    pub fn symbols(&self, category: Category) -> Vec<&'static str> {
+        self.units_in(category).map(|unit| unit.symbol).collect()
+    }
+
+    /// Looks up a unit by symbol, name or alias within a category.
+    ///
+    /// An exact match always wins, so `"MA"` and `"mA"` resolve to different
+    /// units. Otherwise the input is compared ignoring case, which accepts
+    /// spellings like `"Milliampere"` or `"KILOMETERS"` as long as only one
+    /// unit fits.
+    /// # Arguments
+    /// * `input` - The unit as typed by the user
+    /// * `category` - The category to look in
+    ///
+    /// # Returns
+    /// * `Ok(&UnitDef)` - The matching unit
+    /// * `Err(ConversionError)` - An [`ConversionError::UnknownUnit`] if nothing matches,
+    ///   or an [`ConversionError::AmbiguousUnit`] if the input is shared by
+    ///   several units (e.g. `"cal"`) or only matches several units ignoring
+    ///   case (e.g. `"ma"`)
+    pub fn resolve(&self, input: &str, category: Category) -> Result<&UnitDef, ConversionError> {
+        let candidates = self.candidates_in(input, category);
+
+        match candidates.as_slice() {
+            [unit] => Ok(unit),
+            [] => Err(ConversionError::unknown_unit(input, category)),
+            _ => Err(ConversionError::AmbiguousUnit {
+                input: input.to_string(),
+                candidates: candidates
+                    .iter()
+                    .map(|unit| format!("{} ({})", unit.symbol, unit.name))
+                    .collect(),
+            }),
+        }
+    }
+
+    /// Returns the units of one category that `input` could name: those spelled
+    /// exactly like it, or failing that those spelled like it ignoring case.
+    fn candidates_in(&self, input: &str, category: Category) -> Vec<&UnitDef> {
+        let normalized = normalize_symbols(input);
+
+        let candidates: Vec<&UnitDef> = self
+            .known_in(category)
+            .filter(|unit| unit.matches_exactly(&normalized))
+            .collect();
+        if !candidates.is_empty() {
+            return candidates;
+        }
+        self.known_in(category)
+            .filter(|unit| unit.matches_ignoring_case(&normalized))
+            .collect()
+    }
+
+    /// Returns the registered and generated prefixed units of one category.
+    fn known_in(&self, category: Category) -> impl Iterator<Item = &UnitDef> + '_ {
+        self.units_in(category).chain(
+            self.prefixed
+                .iter()
+                .filter(move |unit| unit.category == category),
+        )
+    }
+
+    /// Checks whether `input` is spelled exactly (including case) like a unit
+    /// of `category`.
+    fn spelled_exactly_in(&self, input: &str, category: Category) -> bool {
+        let normalized = normalize_symbols(input);
+        self.known_in(category)
+            .any(|unit| unit.matches_exactly(&normalized))
+    }
+
+    /// Returns every category in which `input` names a unit, including
+    /// categories where it is ambiguous between several units.
+    /// # Arguments
+    /// * `input` - The unit as typed by the user
+    pub fn categories_of(&self, input: &str) -> Vec<Category> {
+        Category::ALL
+            .into_iter()
+            .filter(|&category| {
+                !matches!(
+                    self.resolve(input, category),
+                    Err(ConversionError::UnknownUnit { .. })
+                )
+            })
+            .collect()
+    }
+
+    /// Infers the category to convert in from the two units alone.
+    /// # Arguments
+    /// * `from` - The source unit
+    /// * `to` - The target unit
+    ///
+    /// # Returns
+    /// * `Ok(Category)` - The only category both units belong to, or the only one
+    ///   in which both are spelled exactly (`"S"` is siemens, `"s"` is seconds)
+    /// * `Err(ConversionError)` - An [`ConversionError::UnrecognizedUnit`] if a unit
+    ///   is not known at all, an [`ConversionError::IncompatibleDimensions`] if the
+    ///   units measure different quantities, or an [`ConversionError::AmbiguousUnit`]
+    ///   if they share more than one category
+    pub fn infer_category(&self, from: &str, to: &str) -> Result<Category, ConversionError> {
+        let from_categories = self.categories_of(from);
+        let to_categories = self.categories_of(to);
+
+        let (Some(&from_category), Some(&to_category)) =
+            (from_categories.first(), to_categories.first())
+        else {
+            let input = if from_categories.is_empty() { from } else { to };
+            return Err(ConversionError::UnrecognizedUnit {
+                input: input.to_string(),
+            });
+        };
+
+        let mut shared: Vec<Category> = from_categories
+            .into_iter()
+            .filter(|category| to_categories.contains(category))
+            .collect();
+        // Prefer the one category where both units are spelled exactly, so
+        // "mS" to "S" is conductance rather than milliseconds to seconds
+        let exact: Vec<Category> = shared
+            .iter()
+            .copied()
+            .filter(|&category| {
+                self.spelled_exactly_in(from, category) && self.spelled_exactly_in(to, category)
+            })
+            .collect();
+        if exact.len() == 1 {
+            shared = exact;
+        }
+
+        match shared.as_slice() {
+            [category] => Ok(*category),
+            [] => Err(ConversionError::IncompatibleDimensions {
+                from: from.to_string(),
+                from_category,
+                to: to.to_string(),
+                to_category,
+            }),
+            _ => Err(ConversionError::AmbiguousUnit {
+                input: from.to_string(),
+                candidates: shared
+                    .iter()
+                    .flat_map(|&category| {
+                        self.candidates_in(from, category)
+                            .into_iter()
+                            .map(move |unit| {
+                                format!("{} ({}, {})", unit.symbol, unit.name, category)
+                            })
+                    })
+                    .collect(),
+            }),
+        }
+    }
+
+    /// Converts a value between two units of the same category.
+    /// # Arguments
+    /// * `value` - The numeric value to convert
+    /// * `from` - The source unit
+    /// * `to` - The target unit
+    /// * `category` - The category both units belong to
+    pub fn convert(
+        &self,
+        value: f64,
+        from: &str,
+        to: &str,
+        category: Category,
+    ) -> Result<f64, ConversionError> {
+        ConversionError::ensure_finite(value)?;
+
+        let from_unit = self.resolve(from, category)?;
+        let to_unit = self.resolve(to, category)?;
+
+        if from_unit == to_unit {
+            return Ok(value);
+        }
+
+        let base = from_unit.convert_to_base(value);
+        if to_unit.decibel && base <= 0.0 {
+            return Err(ConversionError::NonPositivePower {
+                value,
+                unit: from.to_string(),
+            });
+        }
+        let reciprocal = from_unit.reciprocal || to_unit.reciprocal;
+        if reciprocal && !(base > 0.0 && base.is_finite()) {
+            return Err(ConversionError::NonPositiveDensity {
+                value,
+                unit: from.to_string(),
+            });
+        }
+
+        Ok(to_unit.convert_from_base(base))
+    }
+}
+
+/// Generates the prefixed forms of every prefixable unit, skipping those that
+/// are already registered explicitly (e.g. `km`, `mA`).
+fn prefixed_units(units: &[UnitDef]) -> Vec<UnitDef> {
+    let mut prefixed = Vec::new();
+
+    for unit in units.iter().filter(|unit| unit.prefixable) {
+        for prefix in SI_PREFIXES {
+            let symbol = format!("{}{}", prefix.symbol, unit.symbol);
+            let registered = units
+                .iter()
+                .any(|other| other.category == unit.category && other.matches_exactly(&symbol));
+            if registered {
+                continue;
+            }
+
+            let alternative_symbols = prefix
+                .alternative_symbols()
+                .iter()
+                .map(|alternative| format!("{}{}", alternative, unit.symbol));
+            let prefixed_names = unit
+                .aliases
+                .iter()
+                .filter(|alias| alias.chars().all(|c| c.is_ascii_lowercase()))
+                .map(|alias| format!("{}{}", prefix.name, alias));
+            let aliases: Vec<&'static str> = alternative_symbols
+                .chain(prefixed_names)
+                .map(leak)
+                .collect();
+
+            let exact_factor = match unit.exact_factor {
+                Some(definition) => format!("{}*{}", prefix.factor, definition),
+                None => format!("{}*{}", prefix.factor, unit.factor),
+            };
+
+            let mut prefixed_unit = UnitDef::new(
+                leak(symbol),
+                leak(format!("{}{}", prefix.name, unit.name)),
+                Box::leak(aliases.into_boxed_slice()),
+                unit.category,
+                prefix.factor * unit.factor,
+            )
+            .with_exact_factor(leak(exact_factor));
+            prefixed_unit.exact = unit.exact;
+            prefixed.push(prefixed_unit);
+        }
+    }
+
+    prefixed
+}
+
+/// Replaces look-alike characters with the ones unit symbols are written in:
+/// the micro sign (`µ`) with Greek mu (`μ`) and the ohm sign (`Ω`) with Greek
+/// omega (`Ω`) and the increment sign (`∆`) with Greek delta (`Δ`).
+fn normalize_symbols(input: &str) -> String {
+    input
+        .replace('\u{b5}', "\u{3bc}")
+        .replace('\u{2126}', "\u{3a9}")
+        .replace('\u{2206}', "\u{394}")
+}
+
+/// Gives a generated spelling the same `'static` lifetime as the built-in ones.
+///
+/// Only used while building the global registry, which lives for the rest of
+/// the program, so each spelling is allocated exactly once.
+fn leak(spelling: String) -> &'static str {
+    Box::leak(spelling.into_boxed_str())
+}
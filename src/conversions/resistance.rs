@@ -0,0 +1,247 @@
+//! Electrical resistance conversion functions organized by unit type
+//! All conversions use ohms as the base unit. Symbols are written with the
+//! Greek capital omega (`Ω`); the ohm sign (`Ω`) and the ASCII spellings
+//! `ohm`, `kohm` and `Mohm` are accepted as well
+
+use super::category::Category;
+use super::error::ConversionError;
+use super::registry::{UnitDef, UnitRegistry};
+
+/// Ohms conversion functions
+pub mod ohms {
+    use crate::conversions::quantity::{Quantity, Resistance, Unit};
+
+    /// Converts ohms to milliohms.
+    /// # Arguments
+    /// * `value` - The resistance in ohms to convert
+    pub fn to_milliohms(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// Converts ohms to kiloohms.
+    /// # Arguments
+    /// * `value` - The resistance in ohms to convert
+    pub fn to_kiloohms(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// Converts ohms to megaohms.
+    /// # Arguments
+    /// * `value` - The resistance in ohms to convert
+    pub fn to_megaohms(value: f64) -> f64 {
+        value / 1_000_000.0
+    }
+
+    /// The ohm as a typed unit of resistance.
+    pub const UNIT: Unit<Resistance> = Unit::new("Ω", "ohm", |value| value, |value| value);
+
+    /// Creates a typed resistance quantity expressed in ohms.
+    /// # Arguments
+    /// * `value` - The resistance in ohms
+    pub fn new(value: f64) -> Quantity<Resistance> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed resistance quantity in ohms.
+    /// # Arguments
+    /// * `quantity` - The resistance quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Resistance>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Milliohms conversion functions
+pub mod milliohms {
+    use crate::conversions::quantity::{Quantity, Resistance, Unit};
+
+    /// Converts milliohms to ohms.
+    /// # Arguments
+    /// * `value` - The resistance in milliohms to convert
+    pub fn to_ohms(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// Converts milliohms to kiloohms.
+    /// # Arguments
+    /// * `value` - The resistance in milliohms to convert
+    pub fn to_kiloohms(value: f64) -> f64 {
+        value / 1_000_000.0
+    }
+
+    /// Converts milliohms to megaohms.
+    /// # Arguments
+    /// * `value` - The resistance in milliohms to convert
+    pub fn to_megaohms(value: f64) -> f64 {
+        value / 1_000_000_000.0
+    }
+
+    /// The milliohm as a typed unit of resistance.
+    pub const UNIT: Unit<Resistance> =
+        Unit::new("mΩ", "milliohm", to_ohms, super::ohms::to_milliohms);
+
+    /// Creates a typed resistance quantity expressed in milliohms.
+    /// # Arguments
+    /// * `value` - The resistance in milliohms
+    pub fn new(value: f64) -> Quantity<Resistance> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed resistance quantity in milliohms.
+    /// # Arguments
+    /// * `quantity` - The resistance quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Resistance>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Kiloohms conversion functions
+pub mod kiloohms {
+    use crate::conversions::quantity::{Quantity, Resistance, Unit};
+
+    /// Converts kiloohms to ohms.
+    /// # Arguments
+    /// * `value` - The resistance in kiloohms to convert
+    pub fn to_ohms(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// Converts kiloohms to milliohms.
+    /// # Arguments
+    /// * `value` - The resistance in kiloohms to convert
+    pub fn to_milliohms(value: f64) -> f64 {
+        value * 1_000_000.0
+    }
+
+    /// Converts kiloohms to megaohms.
+    /// # Arguments
+    /// * `value` - The resistance in kiloohms to convert
+    pub fn to_megaohms(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// The kiloohm as a typed unit of resistance.
+    pub const UNIT: Unit<Resistance> =
+        Unit::new("kΩ", "kiloohm", to_ohms, super::ohms::to_kiloohms);
+
+    /// Creates a typed resistance quantity expressed in kiloohms.
+    /// # Arguments
+    /// * `value` - The resistance in kiloohms
+    pub fn new(value: f64) -> Quantity<Resistance> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed resistance quantity in kiloohms.
+    /// # Arguments
+    /// * `quantity` - The resistance quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Resistance>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Megaohms conversion functions
+pub mod megaohms {
+    use crate::conversions::quantity::{Quantity, Resistance, Unit};
+
+    /// Converts megaohms to ohms.
+    /// # Arguments
+    /// * `value` - The resistance in megaohms to convert
+    pub fn to_ohms(value: f64) -> f64 {
+        value * 1_000_000.0
+    }
+
+    /// Converts megaohms to milliohms.
+    /// # Arguments
+    /// * `value` - The resistance in megaohms to convert
+    pub fn to_milliohms(value: f64) -> f64 {
+        value * 1_000_000_000.0
+    }
+
+    /// Converts megaohms to kiloohms.
+    /// # Arguments
+    /// * `value` - The resistance in megaohms to convert
+    pub fn to_kiloohms(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// The megaohm as a typed unit of resistance.
+    pub const UNIT: Unit<Resistance> =
+        Unit::new("MΩ", "megaohm", to_ohms, super::ohms::to_megaohms);
+
+    /// Creates a typed resistance quantity expressed in megaohms.
+    /// # Arguments
+    /// * `value` - The resistance in megaohms
+    pub fn new(value: f64) -> Quantity<Resistance> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed resistance quantity in megaohms.
+    /// # Arguments
+    /// * `quantity` - The resistance quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Resistance>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// General resistance conversion function that accepts string unit names
+///
+/// Converts an electrical resistance from one unit to another using string
+/// identifiers. Symbols are case-sensitive, so `mΩ` (milliohm) and `MΩ`
+/// (megaohm) are different units, as are the ASCII `mohm` and `Mohm`; names
+/// are case-insensitive.
+///
+/// # Arguments
+///
+/// * `value` - The numeric value to convert
+/// * `from_unit` - The source unit (e.g., "Ω", "ohm", "mΩ", "kΩ", "kohm", "MΩ", "Mohm")
+/// * `to_unit` - The target unit using the same abbreviations
+///
+/// # Returns
+/// * `Ok(f64)` - The converted value
+/// * `Err(ConversionError)` - The reason the conversion is not supported
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::convert_resistance;
+///
+/// let ohms = convert_resistance(4.7, "kΩ", "Ω").unwrap();
+/// assert!((ohms - 4700.0).abs() < 1e-9);
+///
+/// let megaohms = convert_resistance(2_200_000.0, "ohms", "Mohm").unwrap();
+/// assert!((megaohms - 2.2).abs() < 1e-12);
+/// ```
+pub fn convert_resistance(
+    value: f64,
+    from_unit: &str,
+    to_unit: &str,
+) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from_unit, to_unit, Category::Resistance)
+}
+
+/// Resistance units known to the [`UnitRegistry`], with ohms as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new("Ω", "ohm", &["ohms"], Category::Resistance, 1.0).with_prefixes(),
+        UnitDef::new(
+            "mΩ",
+            "milliohm",
+            &["mohm", "milliohms"],
+            Category::Resistance,
+            milliohms::to_ohms(1.0),
+        ),
+        UnitDef::new(
+            "kΩ",
+            "kiloohm",
+            &["kohm", "kohms", "kilohm", "kilohms", "kiloohms"],
+            Category::Resistance,
+            kiloohms::to_ohms(1.0),
+        ),
+        UnitDef::new(
+            "MΩ",
+            "megaohm",
+            &["Mohm", "Mohms", "megohm", "megohms", "megaohms"],
+            Category::Resistance,
+            megaohms::to_ohms(1.0),
+        ),
+    ]
+}
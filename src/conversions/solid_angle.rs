@@ -0,0 +1,196 @@
+//! Solid angle conversion functions organized by unit type
+//! All conversions use steradians as the base unit for accuracy and consistency
+
+use super::category::Category;
+use super::error::ConversionError;
+use super::registry::{UnitDef, UnitRegistry};
+use std::f64::consts::PI;
+
+/// Steradians in one square degree, (π/180)²
+pub const STERADIANS_PER_SQUARE_DEGREE: f64 = (PI / 180.0) * (PI / 180.0);
+
+/// Steradians in one spat, the solid angle of a whole sphere
+pub const STERADIANS_PER_SPAT: f64 = 4.0 * PI;
+
+/// Steradians conversion functions
+pub mod steradians {
+    use crate::conversions::quantity::{Quantity, SolidAngle, Unit};
+
+    /// Converts steradians to square degrees.
+    /// # Arguments
+    /// * `value` - The solid angle in steradians to convert
+    pub fn to_square_degrees(value: f64) -> f64 {
+        value / super::STERADIANS_PER_SQUARE_DEGREE
+    }
+
+    /// Converts steradians to spats.
+    /// # Arguments
+    /// * `value` - The solid angle in steradians to convert
+    pub fn to_spats(value: f64) -> f64 {
+        value / super::STERADIANS_PER_SPAT
+    }
+
+    /// The steradian as a typed unit of solid angle.
+    pub const UNIT: Unit<SolidAngle> = Unit::new("sr", "steradian", |value| value, |value| value);
+
+    /// Creates a typed solid angle quantity expressed in steradians.
+    /// # Arguments
+    /// * `value` - The solid angle in steradians
+    pub fn new(value: f64) -> Quantity<SolidAngle> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed solid angle quantity in steradians.
+    /// # Arguments
+    /// * `quantity` - The solid angle quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<SolidAngle>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Square degrees conversion functions
+pub mod square_degrees {
+    use crate::conversions::quantity::{Quantity, SolidAngle, Unit};
+
+    /// Converts square degrees to steradians.
+    /// # Arguments
+    /// * `value` - The solid angle in square degrees to convert
+    pub fn to_steradians(value: f64) -> f64 {
+        value * super::STERADIANS_PER_SQUARE_DEGREE
+    }
+
+    /// Converts square degrees to spats.
+    /// # Arguments
+    /// * `value` - The solid angle in square degrees to convert
+    pub fn to_spats(value: f64) -> f64 {
+        super::steradians::to_spats(to_steradians(value))
+    }
+
+    /// The square degree as a typed unit of solid angle.
+    pub const UNIT: Unit<SolidAngle> = Unit::new(
+        "deg²",
+        "square degree",
+        to_steradians,
+        super::steradians::to_square_degrees,
+    );
+
+    /// Creates a typed solid angle quantity expressed in square degrees.
+    /// # Arguments
+    /// * `value` - The solid angle in square degrees
+    pub fn new(value: f64) -> Quantity<SolidAngle> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed solid angle quantity in square degrees.
+    /// # Arguments
+    /// * `quantity` - The solid angle quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<SolidAngle>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Spats (the whole sphere, 4π sr) conversion functions
+pub mod spats {
+    use crate::conversions::quantity::{Quantity, SolidAngle, Unit};
+
+    /// Converts spats to steradians.
+    /// # Arguments
+    /// * `value` - The solid angle in spats to convert
+    pub fn to_steradians(value: f64) -> f64 {
+        value * super::STERADIANS_PER_SPAT
+    }
+
+    /// Converts spats to square degrees.
+    /// # Arguments
+    /// * `value` - The solid angle in spats to convert
+    pub fn to_square_degrees(value: f64) -> f64 {
+        super::steradians::to_square_degrees(to_steradians(value))
+    }
+
+    /// The spat as a typed unit of solid angle.
+    pub const UNIT: Unit<SolidAngle> =
+        Unit::new("sp", "spat", to_steradians, super::steradians::to_spats);
+
+    /// Creates a typed solid angle quantity expressed in spats.
+    /// # Arguments
+    /// * `value` - The solid angle in spats
+    pub fn new(value: f64) -> Quantity<SolidAngle> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed solid angle quantity in spats.
+    /// # Arguments
+    /// * `quantity` - The solid angle quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<SolidAngle>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// General solid angle conversion function that accepts string unit names
+///
+/// Converts a solid angle from one unit to another using string identifiers.
+/// Symbols are case-sensitive, names are case-insensitive, and common
+/// abbreviations are supported.
+///
+/// # Arguments
+///
+/// * `value` - The numeric value to convert
+/// * `from_unit` - The source unit (e.g., "sr", "msr", "deg²", "sq deg", "sp")
+/// * `to_unit` - The target unit using the same abbreviations
+///
+/// # Returns
+/// * `Ok(f64)` - The converted value
+/// * `Err(ConversionError)` - The reason the conversion is not supported
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::convert_solid_angle;
+///
+/// // The whole sky is about 41 253 square degrees
+/// let square_degrees = convert_solid_angle(1.0, "sp", "deg²").unwrap();
+/// assert!((square_degrees - 41_252.96).abs() < 0.01);
+/// ```
+pub fn convert_solid_angle(
+    value: f64,
+    from_unit: &str,
+    to_unit: &str,
+) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from_unit, to_unit, Category::SolidAngle)
+}
+
+/// Solid angle units known to the [`UnitRegistry`], with steradians as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new(
+            "sr",
+            "steradian",
+            &["steradians"],
+            Category::SolidAngle,
+            1.0,
+        )
+        .with_prefixes(),
+        UnitDef::new(
+            "deg²",
+            "square degree",
+            &[
+                "deg2",
+                "deg^2",
+                "sq deg",
+                "square degrees",
+                "square_degrees",
+            ],
+            Category::SolidAngle,
+            square_degrees::to_steradians(1.0),
+        )
+        .with_irrational_factor(),
+        UnitDef::new(
+            "sp",
+            "spat",
+            &["spats"],
+            Category::SolidAngle,
+            spats::to_steradians(1.0),
+        )
+        .with_irrational_factor(),
+    ]
+}
@@ -0,0 +1,479 @@
+//! Speed conversion functions organized by unit type
+//! All conversions use meters per second as the base unit for accuracy and consistency
+
+use super::category::Category;
+use super::error::ConversionError;
+use super::length::{METERS_PER_FOOT, METERS_PER_MILE, METERS_PER_NAUTICAL_MILE};
+use super::registry::{UnitDef, UnitRegistry};
+
+/// Seconds in one hour
+const SECONDS_PER_HOUR: f64 = 3600.0;
+
+/// Speed of sound in meters per second at sea level in the ICAO standard
+/// atmosphere (15 °C), used as Mach 1
+pub const SPEED_OF_SOUND_SEA_LEVEL: f64 = 340.294;
+
+/// Meters per second conversion functions
+pub mod meters_per_second {
+    use crate::conversions::quantity::{Quantity, Speed, Unit};
+
+    /// Converts meters per second to kilometers per hour.
+    /// # Arguments
+    /// * `value` - The speed in meters per second to convert
+    pub fn to_kilometers_per_hour(value: f64) -> f64 {
+        value * 3.6
+    }
+
+    /// Converts meters per second to miles per hour.
+    /// # Arguments
+    /// * `value` - The speed in meters per second to convert
+    pub fn to_miles_per_hour(value: f64) -> f64 {
+        value * super::SECONDS_PER_HOUR / super::METERS_PER_MILE
+    }
+
+    /// Converts meters per second to knots.
+    /// # Arguments
+    /// * `value` - The speed in meters per second to convert
+    pub fn to_knots(value: f64) -> f64 {
+        value * super::SECONDS_PER_HOUR / super::METERS_PER_NAUTICAL_MILE
+    }
+
+    /// Converts meters per second to feet per second.
+    /// # Arguments
+    /// * `value` - The speed in meters per second to convert
+    pub fn to_feet_per_second(value: f64) -> f64 {
+        value / super::METERS_PER_FOOT
+    }
+
+    /// Converts meters per second to Mach.
+    /// # Arguments
+    /// * `value` - The speed in meters per second to convert
+    pub fn to_mach(value: f64) -> f64 {
+        value / super::SPEED_OF_SOUND_SEA_LEVEL
+    }
+
+    /// The meter per second as a typed unit of speed.
+    pub const UNIT: Unit<Speed> =
+        Unit::new("m/s", "meter per second", |value| value, |value| value);
+
+    /// Creates a typed speed quantity expressed in meters per second.
+    /// # Arguments
+    /// * `value` - The speed in meters per second
+    pub fn new(value: f64) -> Quantity<Speed> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed speed quantity in meters per second.
+    /// # Arguments
+    /// * `quantity` - The speed quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Speed>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Kilometers per hour conversion functions
+pub mod kilometers_per_hour {
+    use crate::conversions::quantity::{Quantity, Speed, Unit};
+
+    /// Converts kilometers per hour to meters per second.
+    /// # Arguments
+    /// * `value` - The speed in kilometers per hour to convert
+    pub fn to_meters_per_second(value: f64) -> f64 {
+        value / 3.6
+    }
+
+    /// Converts kilometers per hour to miles per hour.
+    /// # Arguments
+    /// * `value` - The speed in kilometers per hour to convert
+    pub fn to_miles_per_hour(value: f64) -> f64 {
+        super::meters_per_second::to_miles_per_hour(to_meters_per_second(value))
+    }
+
+    /// Converts kilometers per hour to knots.
+    /// # Arguments
+    /// * `value` - The speed in kilometers per hour to convert
+    pub fn to_knots(value: f64) -> f64 {
+        super::meters_per_second::to_knots(to_meters_per_second(value))
+    }
+
+    /// Converts kilometers per hour to feet per second.
+    /// # Arguments
+    /// * `value` - The speed in kilometers per hour to convert
+    pub fn to_feet_per_second(value: f64) -> f64 {
+        super::meters_per_second::to_feet_per_second(to_meters_per_second(value))
+    }
+
+    /// Converts kilometers per hour to Mach.
+    /// # Arguments
+    /// * `value` - The speed in kilometers per hour to convert
+    pub fn to_mach(value: f64) -> f64 {
+        super::meters_per_second::to_mach(to_meters_per_second(value))
+    }
+
+    /// The kilometer per hour as a typed unit of speed.
+    pub const UNIT: Unit<Speed> = Unit::new(
+        "km/h",
+        "kilometer per hour",
+        to_meters_per_second,
+        super::meters_per_second::to_kilometers_per_hour,
+    );
+
+    /// Creates a typed speed quantity expressed in kilometers per hour.
+    /// # Arguments
+    /// * `value` - The speed in kilometers per hour
+    pub fn new(value: f64) -> Quantity<Speed> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed speed quantity in kilometers per hour.
+    /// # Arguments
+    /// * `quantity` - The speed quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Speed>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Miles per hour conversion functions
+pub mod miles_per_hour {
+    use crate::conversions::quantity::{Quantity, Speed, Unit};
+
+    /// Converts miles per hour to meters per second.
+    /// # Arguments
+    /// * `value` - The speed in miles per hour to convert
+    pub fn to_meters_per_second(value: f64) -> f64 {
+        value * super::METERS_PER_MILE / super::SECONDS_PER_HOUR
+    }
+
+    /// Converts miles per hour to kilometers per hour.
+    /// # Arguments
+    /// * `value` - The speed in miles per hour to convert
+    pub fn to_kilometers_per_hour(value: f64) -> f64 {
+        super::meters_per_second::to_kilometers_per_hour(to_meters_per_second(value))
+    }
+
+    /// Converts miles per hour to knots.
+    /// # Arguments
+    /// * `value` - The speed in miles per hour to convert
+    pub fn to_knots(value: f64) -> f64 {
+        super::meters_per_second::to_knots(to_meters_per_second(value))
+    }
+
+    /// Converts miles per hour to feet per second.
+    /// # Arguments
+    /// * `value` - The speed in miles per hour to convert
+    pub fn to_feet_per_second(value: f64) -> f64 {
+        value * 5280.0 / super::SECONDS_PER_HOUR
+    }
+
+    /// Converts miles per hour to Mach.
+    /// # Arguments
+    /// * `value` - The speed in miles per hour to convert
+    pub fn to_mach(value: f64) -> f64 {
+        super::meters_per_second::to_mach(to_meters_per_second(value))
+    }
+
+    /// The mile per hour as a typed unit of speed.
+    pub const UNIT: Unit<Speed> = Unit::new(
+        "mph",
+        "mile per hour",
+        to_meters_per_second,
+        super::meters_per_second::to_miles_per_hour,
+    );
+
+    /// Creates a typed speed quantity expressed in miles per hour.
+    /// # Arguments
+    /// * `value` - The speed in miles per hour
+    pub fn new(value: f64) -> Quantity<Speed> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed speed quantity in miles per hour.
+    /// # Arguments
+    /// * `quantity` - The speed quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Speed>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Knots conversion functions
+pub mod knots {
+    use crate::conversions::quantity::{Quantity, Speed, Unit};
+
+    /// Converts knots to meters per second.
+    /// # Arguments
+    /// * `value` - The speed in knots to convert
+    pub fn to_meters_per_second(value: f64) -> f64 {
+        value * super::METERS_PER_NAUTICAL_MILE / super::SECONDS_PER_HOUR
+    }
+
+    /// Converts knots to kilometers per hour.
+    /// # Arguments
+    /// * `value` - The speed in knots to convert
+    pub fn to_kilometers_per_hour(value: f64) -> f64 {
+        super::meters_per_second::to_kilometers_per_hour(to_meters_per_second(value))
+    }
+
+    /// Converts knots to miles per hour.
+    /// # Arguments
+    /// * `value` - The speed in knots to convert
+    pub fn to_miles_per_hour(value: f64) -> f64 {
+        super::meters_per_second::to_miles_per_hour(to_meters_per_second(value))
+    }
+
+    /// Converts knots to feet per second.
+    /// # Arguments
+    /// * `value` - The speed in knots to convert
+    pub fn to_feet_per_second(value: f64) -> f64 {
+        super::meters_per_second::to_feet_per_second(to_meters_per_second(value))
+    }
+
+    /// Converts knots to Mach.
+    /// # Arguments
+    /// * `value` - The speed in knots to convert
+    pub fn to_mach(value: f64) -> f64 {
+        super::meters_per_second::to_mach(to_meters_per_second(value))
+    }
+
+    /// The knot as a typed unit of speed.
+    pub const UNIT: Unit<Speed> = Unit::new(
+        "kn",
+        "knot",
+        to_meters_per_second,
+        super::meters_per_second::to_knots,
+    );
+
+    /// Creates a typed speed quantity expressed in knots.
+    /// # Arguments
+    /// * `value` - The speed in knots
+    pub fn new(value: f64) -> Quantity<Speed> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed speed quantity in knots.
+    /// # Arguments
+    /// * `quantity` - The speed quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Speed>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Feet per second conversion functions
+pub mod feet_per_second {
+    use crate::conversions::quantity::{Quantity, Speed, Unit};
+
+    /// Converts feet per second to meters per second.
+    /// # Arguments
+    /// * `value` - The speed in feet per second to convert
+    pub fn to_meters_per_second(value: f64) -> f64 {
+        value * super::METERS_PER_FOOT
+    }
+
+    /// Converts feet per second to kilometers per hour.
+    /// # Arguments
+    /// * `value` - The speed in feet per second to convert
+    pub fn to_kilometers_per_hour(value: f64) -> f64 {
+        super::meters_per_second::to_kilometers_per_hour(to_meters_per_second(value))
+    }
+
+    /// Converts feet per second to miles per hour.
+    /// # Arguments
+    /// * `value` - The speed in feet per second to convert
+    pub fn to_miles_per_hour(value: f64) -> f64 {
+        value * super::SECONDS_PER_HOUR / 5280.0
+    }
+
+    /// Converts feet per second to knots.
+    /// # Arguments
+    /// * `value` - The speed in feet per second to convert
+    pub fn to_knots(value: f64) -> f64 {
+        super::meters_per_second::to_knots(to_meters_per_second(value))
+    }
+
+    /// Converts feet per second to Mach.
+    /// # Arguments
+    /// * `value` - The speed in feet per second to convert
+    pub fn to_mach(value: f64) -> f64 {
+        super::meters_per_second::to_mach(to_meters_per_second(value))
+    }
+
+    /// The foot per second as a typed unit of speed.
+    pub const UNIT: Unit<Speed> = Unit::new(
+        "ft/s",
+        "foot per second",
+        to_meters_per_second,
+        super::meters_per_second::to_feet_per_second,
+    );
+
+    /// Creates a typed speed quantity expressed in feet per second.
+    /// # Arguments
+    /// * `value` - The speed in feet per second
+    pub fn new(value: f64) -> Quantity<Speed> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed speed quantity in feet per second.
+    /// # Arguments
+    /// * `quantity` - The speed quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Speed>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Mach number conversion functions
+///
+/// Mach 1 is taken as the speed of sound at sea level in the ICAO standard
+/// atmosphere ([`SPEED_OF_SOUND_SEA_LEVEL`]).
+pub mod mach {
+    use crate::conversions::quantity::{Quantity, Speed, Unit};
+
+    /// Converts Mach to meters per second.
+    /// # Arguments
+    /// * `value` - The speed in Mach to convert
+    pub fn to_meters_per_second(value: f64) -> f64 {
+        value * super::SPEED_OF_SOUND_SEA_LEVEL
+    }
+
+    /// Converts Mach to kilometers per hour.
+    /// # Arguments
+    /// * `value` - The speed in Mach to convert
+    pub fn to_kilometers_per_hour(value: f64) -> f64 {
+        super::meters_per_second::to_kilometers_per_hour(to_meters_per_second(value))
+    }
+
+    /// Converts Mach to miles per hour.
+    /// # Arguments
+    /// * `value` - The speed in Mach to convert
+    pub fn to_miles_per_hour(value: f64) -> f64 {
+        super::meters_per_second::to_miles_per_hour(to_meters_per_second(value))
+    }
+
+    /// Converts Mach to knots.
+    /// # Arguments
+    /// * `value` - The speed in Mach to convert
+    pub fn to_knots(value: f64) -> f64 {
+        super::meters_per_second::to_knots(to_meters_per_second(value))
+    }
+
+    /// Converts Mach to feet per second.
+    /// # Arguments
+    /// * `value` - The speed in Mach to convert
+    pub fn to_feet_per_second(value: f64) -> f64 {
+        super::meters_per_second::to_feet_per_second(to_meters_per_second(value))
+    }
+
+    /// The Mach number as a typed unit of speed.
+    pub const UNIT: Unit<Speed> = Unit::new(
+        "Mach",
+        "Mach number",
+        to_meters_per_second,
+        super::meters_per_second::to_mach,
+    );
+
+    /// Creates a typed speed quantity expressed in Mach.
+    /// # Arguments
+    /// * `value` - The speed in Mach
+    pub fn new(value: f64) -> Quantity<Speed> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed speed quantity in Mach.
+    /// # Arguments
+    /// * `quantity` - The speed quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Speed>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// General speed conversion function that accepts string unit names
+///
+/// Converts a speed from one unit to another using string identifiers.
+/// Symbols are case-sensitive, names are case-insensitive, and common
+/// abbreviations are supported.
+///
+/// # Arguments
+///
+/// * `value` - The numeric value to convert
+/// * `from_unit` - The source unit (e.g., "m/s", "km/h", "mph", "kn", "ft/s", "Mach")
+/// * `to_unit` - The target unit using the same abbreviations
+///
+/// # Returns
+/// * `Ok(f64)` - The converted value
+/// * `Err(ConversionError)` - The reason the conversion is not supported
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::convert_speed;
+///
+/// let kilometers_per_hour = convert_speed(10.0, "m/s", "km/h").unwrap();
+/// assert!((kilometers_per_hour - 36.0).abs() < 1e-9);
+///
+/// let miles_per_hour = convert_speed(100.0, "kn", "mph").unwrap();
+/// assert!((miles_per_hour - 115.078).abs() < 0.001);
+/// ```
+pub fn convert_speed(value: f64, from_unit: &str, to_unit: &str) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from_unit, to_unit, Category::Speed)
+}
+
+/// Speed units known to the [`UnitRegistry`], with meters per second as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new(
+            "m/s",
+            "meter per second",
+            &[
+                "meters per second",
+                "metres per second",
+                "meters_per_second",
+                "mps",
+            ],
+            Category::Speed,
+            1.0,
+        ),
+        UnitDef::new(
+            "km/h",
+            "kilometer per hour",
+            &[
+                "kilometers per hour",
+                "kilometres per hour",
+                "kilometers_per_hour",
+                "kph",
+                "kmh",
+                "km/hr",
+            ],
+            Category::Speed,
+            kilometers_per_hour::to_meters_per_second(1.0),
+        )
+        .with_exact_factor("1/3.6"),
+        UnitDef::new(
+            "mph",
+            "mile per hour",
+            &["miles per hour", "miles_per_hour", "mi/h"],
+            Category::Speed,
+            miles_per_hour::to_meters_per_second(1.0),
+        )
+        .with_exact_factor("1609.344/3600"),
+        UnitDef::new(
+            "kn",
+            "knot",
+            &["knots", "kt", "kts"],
+            Category::Speed,
+            knots::to_meters_per_second(1.0),
+        )
+        .with_exact_factor("1852/3600"),
+        UnitDef::new(
+            "ft/s",
+            "foot per second",
+            &["feet per second", "feet_per_second", "fps", "ft/sec"],
+            Category::Speed,
+            feet_per_second::to_meters_per_second(1.0),
+        ),
+        UnitDef::new(
+            "Mach",
+            "Mach number",
+            &["mach"],
+            Category::Speed,
+            mach::to_meters_per_second(1.0),
+        ),
+    ]
+}
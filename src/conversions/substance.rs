@@ -1,8 +1,14 @@
 //! Amount of substance conversion functions organized by unit type
 //! All conversions use moles as the base unit for accuracy and consistency
 
+use super::category::Category;
+use super::error::ConversionError;
+use super::registry::{UnitDef, UnitRegistry};
+
 /// Moles conversion functions
 pub mod moles {
+    use crate::conversions::quantity::{Amount, Quantity, Unit};
+
     /// Converts moles to millimoles.
     /// # Arguments
     /// * `value` - The amount in moles to convert
@@ -37,10 +43,29 @@ pub mod moles {
     pub fn to_kilomoles(value: f64) -> f64 {
         value / 1000.0
     }
+
+    /// The mole as a typed unit of amount.
+    pub const UNIT: Unit<Amount> = Unit::new("mol", "mole", |value| value, |value| value);
+
+    /// Creates a typed amount quantity expressed in moles.
+    /// # Arguments
+    /// * `value` - The amount in moles
+    pub fn new(value: f64) -> Quantity<Amount> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed amount quantity in moles.
+    /// # Arguments
+    /// * `quantity` - The amount quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Amount>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Millimoles conversion functions
 pub mod millimoles {
+    use crate::conversions::quantity::{Amount, Quantity, Unit};
+
     /// Converts millimoles to moles.
     /// # Arguments
     /// * `value` - The amount in millimoles to convert
@@ -75,10 +100,30 @@ pub mod millimoles {
     pub fn to_kilomoles(value: f64) -> f64 {
         super::moles::to_kilomoles(to_moles(value))
     }
+
+    /// The millimole as a typed unit of amount.
+    pub const UNIT: Unit<Amount> =
+        Unit::new("mmol", "millimole", to_moles, super::moles::to_millimoles);
+
+    /// Creates a typed amount quantity expressed in millimoles.
+    /// # Arguments
+    /// * `value` - The amount in millimoles
+    pub fn new(value: f64) -> Quantity<Amount> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed amount quantity in millimoles.
+    /// # Arguments
+    /// * `quantity` - The amount quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Amount>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Micromoles conversion functions
 pub mod micromoles {
+    use crate::conversions::quantity::{Amount, Quantity, Unit};
+
     /// Converts micromoles to moles.
     /// # Arguments
     /// * `value` - The amount in micromoles to convert
@@ -113,10 +158,30 @@ pub mod micromoles {
     pub fn to_kilomoles(value: f64) -> f64 {
         super::moles::to_kilomoles(to_moles(value))
     }
+
+    /// The micromole as a typed unit of amount.
+    pub const UNIT: Unit<Amount> =
+        Unit::new("μmol", "micromole", to_moles, super::moles::to_micromoles);
+
+    /// Creates a typed amount quantity expressed in micromoles.
+    /// # Arguments
+    /// * `value` - The amount in micromoles
+    pub fn new(value: f64) -> Quantity<Amount> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed amount quantity in micromoles.
+    /// # Arguments
+    /// * `quantity` - The amount quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Amount>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Nanomoles conversion functions
 pub mod nanomoles {
+    use crate::conversions::quantity::{Amount, Quantity, Unit};
+
     /// Converts nanomoles to moles.
     /// # Arguments
     /// * `value` - The amount in nanomoles to convert
@@ -151,10 +216,30 @@ pub mod nanomoles {
     pub fn to_kilomoles(value: f64) -> f64 {
         super::moles::to_kilomoles(to_moles(value))
     }
+
+    /// The nanomole as a typed unit of amount.
+    pub const UNIT: Unit<Amount> =
+        Unit::new("nmol", "nanomole", to_moles, super::moles::to_nanomoles);
+
+    /// Creates a typed amount quantity expressed in nanomoles.
+    /// # Arguments
+    /// * `value` - The amount in nanomoles
+    pub fn new(value: f64) -> Quantity<Amount> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed amount quantity in nanomoles.
+    /// # Arguments
+    /// * `quantity` - The amount quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Amount>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Picomoles conversion functions
 pub mod picomoles {
+    use crate::conversions::quantity::{Amount, Quantity, Unit};
+
     /// Converts picomoles to moles.
     /// # Arguments
     /// * `value` - The amount in picomoles to convert
@@ -189,10 +274,30 @@ pub mod picomoles {
     pub fn to_kilomoles(value: f64) -> f64 {
         super::moles::to_kilomoles(to_moles(value))
     }
+
+    /// The picomole as a typed unit of amount.
+    pub const UNIT: Unit<Amount> =
+        Unit::new("pmol", "picomole", to_moles, super::moles::to_picomoles);
+
+    /// Creates a typed amount quantity expressed in picomoles.
+    /// # Arguments
+    /// * `value` - The amount in picomoles
+    pub fn new(value: f64) -> Quantity<Amount> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed amount quantity in picomoles.
+    /// # Arguments
+    /// * `quantity` - The amount quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Amount>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Kilomoles conversion functions
 pub mod kilomoles {
+    use crate::conversions::quantity::{Amount, Quantity, Unit};
+
     /// Converts kilomoles to moles.
     /// # Arguments
     /// * `value` - The amount in kilomoles to convert
@@ -227,6 +332,24 @@ pub mod kilomoles {
     pub fn to_picomoles(value: f64) -> f64 {
         super::moles::to_picomoles(to_moles(value))
     }
+
+    /// The kilomole as a typed unit of amount.
+    pub const UNIT: Unit<Amount> =
+        Unit::new("kmol", "kilomole", to_moles, super::moles::to_kilomoles);
+
+    /// Creates a typed amount quantity expressed in kilomoles.
+    /// # Arguments
+    /// * `value` - The amount in kilomoles
+    pub fn new(value: f64) -> Quantity<Amount> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed amount quantity in kilomoles.
+    /// # Arguments
+    /// * `quantity` - The amount quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Amount>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 // Legacy function wrappers for backward compatibility
@@ -265,7 +388,8 @@ pub fn kilomoles_to_moles(kilomoles: f64) -> f64 {
 /// General amount of substance conversion function that accepts string unit names
 ///
 /// Converts an amount of substance value from one unit to another using string identifiers.
-/// This function is case-insensitive and supports common abbreviations.
+/// Symbols are case-sensitive, names are case-insensitive, and common
+/// abbreviations are supported.
 ///
 /// # Arguments
 ///
@@ -275,7 +399,7 @@ pub fn kilomoles_to_moles(kilomoles: f64) -> f64 {
 ///
 /// # Returns
 /// * `Ok(f64)` - The converted value
-/// * `Err(String)` - Error message if the conversion is not supported
+/// * `Err(ConversionError)` - The reason the conversion is not supported
 ///
 /// # Examples
 ///
@@ -288,31 +412,48 @@ pub fn kilomoles_to_moles(kilomoles: f64) -> f64 {
 /// let moles = convert_amount(500.0, "mmol", "mol").unwrap();
 /// assert_eq!(moles, 0.5);
 /// ```
-pub fn convert_amount(value: f64, from_unit: &str, to_unit: &str) -> Result<f64, String> {
-    let from_unit = from_unit.to_lowercase();
-    let to_unit = to_unit.to_lowercase();
-
-    // Convert input to moles first
-    let moles = match from_unit.as_str() {
-        "mol" | "mole" | "moles" => value,
-        "mmol" | "millimol" | "millimole" | "millimoles" => millimoles::to_moles(value),
-        "μmol" | "umol" | "micromol" | "micromole" | "micromoles" => micromoles::to_moles(value),
-        "nmol" | "nanomol" | "nanomole" | "nanomoles" => nanomoles::to_moles(value),
-        "pmol" | "picomol" | "picomole" | "picomoles" => picomoles::to_moles(value),
-        "kmol" | "kilomol" | "kilomole" | "kilomoles" => kilomoles::to_moles(value),
-        _ => return Err(format!("Unsupported amount unit: {}", from_unit)),
-    };
-
-    // Convert moles to target unit
-    let result = match to_unit.as_str() {
-        "mol" | "mole" | "moles" => moles,
-        "mmol" | "millimol" | "millimole" | "millimoles" => moles::to_millimoles(moles),
-        "μmol" | "umol" | "micromol" | "micromole" | "micromoles" => moles::to_micromoles(moles),
-        "nmol" | "nanomol" | "nanomole" | "nanomoles" => moles::to_nanomoles(moles),
-        "pmol" | "picomol" | "picomole" | "picomoles" => moles::to_picomoles(moles),
-        "kmol" | "kilomol" | "kilomole" | "kilomoles" => moles::to_kilomoles(moles),
-        _ => return Err(format!("Unsupported amount unit: {}", to_unit)),
-    };
-
-    Ok(result)
+pub fn convert_amount(value: f64, from_unit: &str, to_unit: &str) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from_unit, to_unit, Category::Amount)
+}
+
+/// Amount of substance units known to the [`UnitRegistry`], with moles as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new("mol", "mole", &["moles"], Category::Amount, 1.0).with_prefixes(),
+        UnitDef::new(
+            "mmol",
+            "millimole",
+            &["millimol", "millimoles"],
+            Category::Amount,
+            millimoles::to_moles(1.0),
+        ),
+        UnitDef::new(
+            "μmol",
+            "micromole",
+            &["umol", "micromol", "micromoles"],
+            Category::Amount,
+            micromoles::to_moles(1.0),
+        ),
+        UnitDef::new(
+            "nmol",
+            "nanomole",
+            &["nanomol", "nanomoles"],
+            Category::Amount,
+            nanomoles::to_moles(1.0),
+        ),
+        UnitDef::new(
+            "pmol",
+            "picomole",
+            &["picomol", "picomoles"],
+            Category::Amount,
+            picomoles::to_moles(1.0),
+        ),
+        UnitDef::new(
+            "kmol",
+            "kilomole",
+            &["kilomol", "kilomoles"],
+            Category::Amount,
+            kilomoles::to_moles(1.0),
+        ),
+    ]
 }
@@ -1,7 +1,24 @@
 //! Temperature conversion functions organized by unit type
+//! Every scale is an affine transform of the kelvin: each module converts to
+//! and from kelvins, and any other pair goes through that Kelvin hub, except
+//! where a scale is defined directly on another (Réaumur, Delisle, Newton and
+//! Rømer on Celsius, Rankine on Fahrenheit)
+//!
+//! Temperature differences are a separate dimension: a rise of 10 °C is a rise
+//! of 18 °F, not 50 °F. The `delta_*` modules and
+//! [`convert_temperature_interval`] convert intervals by the size of a degree
+//! alone, ignoring each scale's zero point
+
+use std::borrow::Cow;
+
+use super::category::Category;
+use super::error::ConversionError;
+use super::registry::{UnitDef, UnitRegistry};
 
 /// Celsius conversion functions
 pub mod celsius {
+    use crate::conversions::quantity::{Quantity, Temperature, Unit};
+
     /// Converts Celsius to Fahrenheit.
     /// # Arguments
     /// * `value` - The temperature in Celsius to convert
@@ -15,10 +32,65 @@ pub mod celsius {
     pub fn to_kelvin(value: f64) -> f64 {
         value + 273.15
     }
+
+    /// Converts Celsius to Rankine.
+    /// # Arguments
+    /// * `value` - The temperature in Celsius to convert
+    pub fn to_rankine(value: f64) -> f64 {
+        super::kelvin::to_rankine(to_kelvin(value))
+    }
+
+    /// Converts Celsius to Réaumur.
+    /// # Arguments
+    /// * `value` - The temperature in Celsius to convert
+    pub fn to_reaumur(value: f64) -> f64 {
+        value * 0.8
+    }
+
+    /// Converts Celsius to Delisle.
+    /// # Arguments
+    /// * `value` - The temperature in Celsius to convert
+    pub fn to_delisle(value: f64) -> f64 {
+        (100.0 - value) * 1.5
+    }
+
+    /// Converts Celsius to Newton.
+    /// # Arguments
+    /// * `value` - The temperature in Celsius to convert
+    pub fn to_newton(value: f64) -> f64 {
+        value * 0.33
+    }
+
+    /// Converts Celsius to Rømer.
+    /// # Arguments
+    /// * `value` - The temperature in Celsius to convert
+    pub fn to_romer(value: f64) -> f64 {
+        value * 21.0 / 40.0 + 7.5
+    }
+
+    /// The degree Celsius as a typed unit of temperature.
+    pub const UNIT: Unit<Temperature> =
+        Unit::new("°C", "degree Celsius", to_kelvin, super::kelvin::to_celsius);
+
+    /// Creates a typed temperature quantity expressed in celsius.
+    /// # Arguments
+    /// * `value` - The temperature in Celsius
+    pub fn new(value: f64) -> Quantity<Temperature> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed temperature quantity in celsius.
+    /// # Arguments
+    /// * `quantity` - The temperature quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Temperature>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Fahrenheit conversion functions
 pub mod fahrenheit {
+    use crate::conversions::quantity::{Quantity, Temperature, Unit};
+
     /// Converts Fahrenheit to Celsius.
     /// # Arguments
     /// * `value` - The temperature in Fahrenheit to convert
@@ -32,10 +104,69 @@ pub mod fahrenheit {
     pub fn to_kelvin(value: f64) -> f64 {
         super::celsius::to_kelvin(to_celsius(value))
     }
+
+    /// Converts Fahrenheit to Rankine.
+    /// # Arguments
+    /// * `value` - The temperature in Fahrenheit to convert
+    pub fn to_rankine(value: f64) -> f64 {
+        value + 459.67
+    }
+
+    /// Converts Fahrenheit to Réaumur.
+    /// # Arguments
+    /// * `value` - The temperature in Fahrenheit to convert
+    pub fn to_reaumur(value: f64) -> f64 {
+        super::kelvin::to_reaumur(to_kelvin(value))
+    }
+
+    /// Converts Fahrenheit to Delisle.
+    /// # Arguments
+    /// * `value` - The temperature in Fahrenheit to convert
+    pub fn to_delisle(value: f64) -> f64 {
+        super::kelvin::to_delisle(to_kelvin(value))
+    }
+
+    /// Converts Fahrenheit to Newton.
+    /// # Arguments
+    /// * `value` - The temperature in Fahrenheit to convert
+    pub fn to_newton(value: f64) -> f64 {
+        super::kelvin::to_newton(to_kelvin(value))
+    }
+
+    /// Converts Fahrenheit to Rømer.
+    /// # Arguments
+    /// * `value` - The temperature in Fahrenheit to convert
+    pub fn to_romer(value: f64) -> f64 {
+        super::kelvin::to_romer(to_kelvin(value))
+    }
+
+    /// The degree Fahrenheit as a typed unit of temperature.
+    pub const UNIT: Unit<Temperature> = Unit::new(
+        "°F",
+        "degree Fahrenheit",
+        to_kelvin,
+        super::kelvin::to_fahrenheit,
+    );
+
+    /// Creates a typed temperature quantity expressed in fahrenheit.
+    /// # Arguments
+    /// * `value` - The temperature in Fahrenheit
+    pub fn new(value: f64) -> Quantity<Temperature> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed temperature quantity in fahrenheit.
+    /// # Arguments
+    /// * `quantity` - The temperature quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Temperature>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Kelvin conversion functions
 pub mod kelvin {
+    use crate::conversions::quantity::{Quantity, Temperature, Unit};
+
     /// Converts Kelvin to Celsius.
     /// # Arguments
     /// * `value` - The temperature in Kelvin to convert
@@ -49,6 +180,614 @@ pub mod kelvin {
     pub fn to_fahrenheit(value: f64) -> f64 {
         super::celsius::to_fahrenheit(to_celsius(value))
     }
+
+    /// Converts Kelvin to Rankine.
+    /// # Arguments
+    /// * `value` - The temperature in Kelvin to convert
+    pub fn to_rankine(value: f64) -> f64 {
+        value * 9.0 / 5.0
+    }
+
+    /// Converts Kelvin to Réaumur.
+    /// # Arguments
+    /// * `value` - The temperature in Kelvin to convert
+    pub fn to_reaumur(value: f64) -> f64 {
+        (value - 273.15) * 0.8
+    }
+
+    /// Converts Kelvin to Delisle.
+    /// # Arguments
+    /// * `value` - The temperature in Kelvin to convert
+    pub fn to_delisle(value: f64) -> f64 {
+        (373.15 - value) * 1.5
+    }
+
+    /// Converts Kelvin to Newton.
+    /// # Arguments
+    /// * `value` - The temperature in Kelvin to convert
+    pub fn to_newton(value: f64) -> f64 {
+        (value - 273.15) * 0.33
+    }
+
+    /// Converts Kelvin to Rømer.
+    /// # Arguments
+    /// * `value` - The temperature in Kelvin to convert
+    pub fn to_romer(value: f64) -> f64 {
+        (value - 273.15) * 21.0 / 40.0 + 7.5
+    }
+
+    /// The kelvin as a typed unit of temperature.
+    pub const UNIT: Unit<Temperature> = Unit::new("K", "kelvin", |value| value, |value| value);
+
+    /// Creates a typed temperature quantity expressed in kelvin.
+    /// # Arguments
+    /// * `value` - The temperature in Kelvin
+    pub fn new(value: f64) -> Quantity<Temperature> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed temperature quantity in kelvin.
+    /// # Arguments
+    /// * `quantity` - The temperature quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Temperature>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Rankine conversion functions
+pub mod rankine {
+    use crate::conversions::quantity::{Quantity, Temperature, Unit};
+
+    /// Converts Rankine to Celsius.
+    /// # Arguments
+    /// * `value` - The temperature in Rankine to convert
+    pub fn to_celsius(value: f64) -> f64 {
+        super::kelvin::to_celsius(to_kelvin(value))
+    }
+
+    /// Converts Rankine to Fahrenheit.
+    /// # Arguments
+    /// * `value` - The temperature in Rankine to convert
+    pub fn to_fahrenheit(value: f64) -> f64 {
+        value - 459.67
+    }
+
+    /// Converts Rankine to Kelvin.
+    /// # Arguments
+    /// * `value` - The temperature in Rankine to convert
+    pub fn to_kelvin(value: f64) -> f64 {
+        value * 5.0 / 9.0
+    }
+
+    /// Converts Rankine to Réaumur.
+    /// # Arguments
+    /// * `value` - The temperature in Rankine to convert
+    pub fn to_reaumur(value: f64) -> f64 {
+        super::kelvin::to_reaumur(to_kelvin(value))
+    }
+
+    /// Converts Rankine to Delisle.
+    /// # Arguments
+    /// * `value` - The temperature in Rankine to convert
+    pub fn to_delisle(value: f64) -> f64 {
+        super::kelvin::to_delisle(to_kelvin(value))
+    }
+
+    /// Converts Rankine to Newton.
+    /// # Arguments
+    /// * `value` - The temperature in Rankine to convert
+    pub fn to_newton(value: f64) -> f64 {
+        super::kelvin::to_newton(to_kelvin(value))
+    }
+
+    /// Converts Rankine to Rømer.
+    /// # Arguments
+    /// * `value` - The temperature in Rankine to convert
+    pub fn to_romer(value: f64) -> f64 {
+        super::kelvin::to_romer(to_kelvin(value))
+    }
+
+    /// The degree Rankine as a typed unit of temperature.
+    pub const UNIT: Unit<Temperature> =
+        Unit::new("°R", "degree Rankine", to_kelvin, super::kelvin::to_rankine);
+
+    /// Creates a typed temperature quantity expressed in Rankine.
+    /// # Arguments
+    /// * `value` - The temperature in Rankine
+    pub fn new(value: f64) -> Quantity<Temperature> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed temperature quantity in Rankine.
+    /// # Arguments
+    /// * `quantity` - The temperature quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Temperature>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Réaumur conversion functions
+pub mod reaumur {
+    use crate::conversions::quantity::{Quantity, Temperature, Unit};
+
+    /// Converts Réaumur to Celsius.
+    /// # Arguments
+    /// * `value` - The temperature in Réaumur to convert
+    pub fn to_celsius(value: f64) -> f64 {
+        value * 1.25
+    }
+
+    /// Converts Réaumur to Fahrenheit.
+    /// # Arguments
+    /// * `value` - The temperature in Réaumur to convert
+    pub fn to_fahrenheit(value: f64) -> f64 {
+        super::kelvin::to_fahrenheit(to_kelvin(value))
+    }
+
+    /// Converts Réaumur to Kelvin.
+    /// # Arguments
+    /// * `value` - The temperature in Réaumur to convert
+    pub fn to_kelvin(value: f64) -> f64 {
+        value * 1.25 + 273.15
+    }
+
+    /// Converts Réaumur to Rankine.
+    /// # Arguments
+    /// * `value` - The temperature in Réaumur to convert
+    pub fn to_rankine(value: f64) -> f64 {
+        super::kelvin::to_rankine(to_kelvin(value))
+    }
+
+    /// Converts Réaumur to Delisle.
+    /// # Arguments
+    /// * `value` - The temperature in Réaumur to convert
+    pub fn to_delisle(value: f64) -> f64 {
+        super::kelvin::to_delisle(to_kelvin(value))
+    }
+
+    /// Converts Réaumur to Newton.
+    /// # Arguments
+    /// * `value` - The temperature in Réaumur to convert
+    pub fn to_newton(value: f64) -> f64 {
+        super::kelvin::to_newton(to_kelvin(value))
+    }
+
+    /// Converts Réaumur to Rømer.
+    /// # Arguments
+    /// * `value` - The temperature in Réaumur to convert
+    pub fn to_romer(value: f64) -> f64 {
+        super::kelvin::to_romer(to_kelvin(value))
+    }
+
+    /// The degree Réaumur as a typed unit of temperature.
+    pub const UNIT: Unit<Temperature> = Unit::new(
+        "°Ré",
+        "degree Réaumur",
+        to_kelvin,
+        super::kelvin::to_reaumur,
+    );
+
+    /// Creates a typed temperature quantity expressed in Réaumur.
+    /// # Arguments
+    /// * `value` - The temperature in Réaumur
+    pub fn new(value: f64) -> Quantity<Temperature> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed temperature quantity in Réaumur.
+    /// # Arguments
+    /// * `quantity` - The temperature quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Temperature>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Delisle conversion functions
+pub mod delisle {
+    use crate::conversions::quantity::{Quantity, Temperature, Unit};
+
+    /// Converts Delisle to Celsius.
+    /// # Arguments
+    /// * `value` - The temperature in Delisle to convert
+    pub fn to_celsius(value: f64) -> f64 {
+        100.0 - value * 2.0 / 3.0
+    }
+
+    /// Converts Delisle to Fahrenheit.
+    /// # Arguments
+    /// * `value` - The temperature in Delisle to convert
+    pub fn to_fahrenheit(value: f64) -> f64 {
+        super::kelvin::to_fahrenheit(to_kelvin(value))
+    }
+
+    /// Converts Delisle to Kelvin.
+    /// # Arguments
+    /// * `value` - The temperature in Delisle to convert
+    pub fn to_kelvin(value: f64) -> f64 {
+        373.15 - value * 2.0 / 3.0
+    }
+
+    /// Converts Delisle to Rankine.
+    /// # Arguments
+    /// * `value` - The temperature in Delisle to convert
+    pub fn to_rankine(value: f64) -> f64 {
+        super::kelvin::to_rankine(to_kelvin(value))
+    }
+
+    /// Converts Delisle to Réaumur.
+    /// # Arguments
+    /// * `value` - The temperature in Delisle to convert
+    pub fn to_reaumur(value: f64) -> f64 {
+        super::kelvin::to_reaumur(to_kelvin(value))
+    }
+
+    /// Converts Delisle to Newton.
+    /// # Arguments
+    /// * `value` - The temperature in Delisle to convert
+    pub fn to_newton(value: f64) -> f64 {
+        super::kelvin::to_newton(to_kelvin(value))
+    }
+
+    /// Converts Delisle to Rømer.
+    /// # Arguments
+    /// * `value` - The temperature in Delisle to convert
+    pub fn to_romer(value: f64) -> f64 {
+        super::kelvin::to_romer(to_kelvin(value))
+    }
+
+    /// The degree Delisle as a typed unit of temperature.
+    pub const UNIT: Unit<Temperature> = Unit::new(
+        "°De",
+        "degree Delisle",
+        to_kelvin,
+        super::kelvin::to_delisle,
+    );
+
+    /// Creates a typed temperature quantity expressed in Delisle.
+    /// # Arguments
+    /// * `value` - The temperature in Delisle
+    pub fn new(value: f64) -> Quantity<Temperature> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed temperature quantity in Delisle.
+    /// # Arguments
+    /// * `quantity` - The temperature quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Temperature>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Newton conversion functions
+pub mod newton {
+    use crate::conversions::quantity::{Quantity, Temperature, Unit};
+
+    /// Converts Newton to Celsius.
+    /// # Arguments
+    /// * `value` - The temperature in Newton to convert
+    pub fn to_celsius(value: f64) -> f64 {
+        value * 100.0 / 33.0
+    }
+
+    /// Converts Newton to Fahrenheit.
+    /// # Arguments
+    /// * `value` - The temperature in Newton to convert
+    pub fn to_fahrenheit(value: f64) -> f64 {
+        super::kelvin::to_fahrenheit(to_kelvin(value))
+    }
+
+    /// Converts Newton to Kelvin.
+    /// # Arguments
+    /// * `value` - The temperature in Newton to convert
+    pub fn to_kelvin(value: f64) -> f64 {
+        value * 100.0 / 33.0 + 273.15
+    }
+
+    /// Converts Newton to Rankine.
+    /// # Arguments
+    /// * `value` - The temperature in Newton to convert
+    pub fn to_rankine(value: f64) -> f64 {
+        super::kelvin::to_rankine(to_kelvin(value))
+    }
+
+    /// Converts Newton to Réaumur.
+    /// # Arguments
+    /// * `value` - The temperature in Newton to convert
+    pub fn to_reaumur(value: f64) -> f64 {
+        super::kelvin::to_reaumur(to_kelvin(value))
+    }
+
+    /// Converts Newton to Delisle.
+    /// # Arguments
+    /// * `value` - The temperature in Newton to convert
+    pub fn to_delisle(value: f64) -> f64 {
+        super::kelvin::to_delisle(to_kelvin(value))
+    }
+
+    /// Converts Newton to Rømer.
+    /// # Arguments
+    /// * `value` - The temperature in Newton to convert
+    pub fn to_romer(value: f64) -> f64 {
+        super::kelvin::to_romer(to_kelvin(value))
+    }
+
+    /// The degree Newton as a typed unit of temperature.
+    pub const UNIT: Unit<Temperature> =
+        Unit::new("°N", "degree Newton", to_kelvin, super::kelvin::to_newton);
+
+    /// Creates a typed temperature quantity expressed in Newton.
+    /// # Arguments
+    /// * `value` - The temperature in Newton
+    pub fn new(value: f64) -> Quantity<Temperature> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed temperature quantity in Newton.
+    /// # Arguments
+    /// * `quantity` - The temperature quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Temperature>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Rømer conversion functions
+pub mod romer {
+    use crate::conversions::quantity::{Quantity, Temperature, Unit};
+
+    /// Converts Rømer to Celsius.
+    /// # Arguments
+    /// * `value` - The temperature in Rømer to convert
+    pub fn to_celsius(value: f64) -> f64 {
+        (value - 7.5) * 40.0 / 21.0
+    }
+
+    /// Converts Rømer to Fahrenheit.
+    /// # Arguments
+    /// * `value` - The temperature in Rømer to convert
+    pub fn to_fahrenheit(value: f64) -> f64 {
+        super::kelvin::to_fahrenheit(to_kelvin(value))
+    }
+
+    /// Converts Rømer to Kelvin.
+    /// # Arguments
+    /// * `value` - The temperature in Rømer to convert
+    pub fn to_kelvin(value: f64) -> f64 {
+        (value - 7.5) * 40.0 / 21.0 + 273.15
+    }
+
+    /// Converts Rømer to Rankine.
+    /// # Arguments
+    /// * `value` - The temperature in Rømer to convert
+    pub fn to_rankine(value: f64) -> f64 {
+        super::kelvin::to_rankine(to_kelvin(value))
+    }
+
+    /// Converts Rømer to Réaumur.
+    /// # Arguments
+    /// * `value` - The temperature in Rømer to convert
+    pub fn to_reaumur(value: f64) -> f64 {
+        super::kelvin::to_reaumur(to_kelvin(value))
+    }
+
+    /// Converts Rømer to Delisle.
+    /// # Arguments
+    /// * `value` - The temperature in Rømer to convert
+    pub fn to_delisle(value: f64) -> f64 {
+        super::kelvin::to_delisle(to_kelvin(value))
+    }
+
+    /// Converts Rømer to Newton.
+    /// # Arguments
+    /// * `value` - The temperature in Rømer to convert
+    pub fn to_newton(value: f64) -> f64 {
+        super::kelvin::to_newton(to_kelvin(value))
+    }
+
+    /// The degree Rømer as a typed unit of temperature.
+    pub const UNIT: Unit<Temperature> =
+        Unit::new("°Rø", "degree Rømer", to_kelvin, super::kelvin::to_romer);
+
+    /// Creates a typed temperature quantity expressed in Rømer.
+    /// # Arguments
+    /// * `value` - The temperature in Rømer
+    pub fn new(value: f64) -> Quantity<Temperature> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed temperature quantity in Rømer.
+    /// # Arguments
+    /// * `quantity` - The temperature quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Temperature>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Celsius temperature difference conversion functions
+pub mod delta_celsius {
+    use crate::conversions::quantity::{Quantity, TemperatureInterval, Unit};
+
+    /// Converts a Celsius temperature difference to Fahrenheit.
+    /// # Arguments
+    /// * `value` - The temperature difference in Celsius degrees to convert
+    pub fn to_delta_fahrenheit(value: f64) -> f64 {
+        value * 9.0 / 5.0
+    }
+
+    /// Converts a Celsius temperature difference to Kelvin.
+    /// # Arguments
+    /// * `value` - The temperature difference in Celsius degrees to convert
+    pub fn to_delta_kelvin(value: f64) -> f64 {
+        value
+    }
+
+    /// Converts a Celsius temperature difference to Rankine.
+    /// # Arguments
+    /// * `value` - The temperature difference in Celsius degrees to convert
+    pub fn to_delta_rankine(value: f64) -> f64 {
+        value * 9.0 / 5.0
+    }
+
+    /// The Celsius degree as a typed unit of temperature difference.
+    pub const UNIT: Unit<TemperatureInterval> = Unit::new(
+        "ΔC",
+        "Celsius degree",
+        to_delta_kelvin,
+        super::delta_kelvin::to_delta_celsius,
+    );
+
+    /// Creates a typed temperature difference quantity expressed in Celsius degrees.
+    /// # Arguments
+    /// * `value` - The temperature difference in Celsius degrees
+    pub fn new(value: f64) -> Quantity<TemperatureInterval> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed temperature difference quantity in Celsius degrees.
+    /// # Arguments
+    /// * `quantity` - The temperature difference quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<TemperatureInterval>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Fahrenheit temperature difference conversion functions
+pub mod delta_fahrenheit {
+    use crate::conversions::quantity::{Quantity, TemperatureInterval, Unit};
+
+    /// Converts a Fahrenheit temperature difference to Celsius.
+    /// # Arguments
+    /// * `value` - The temperature difference in Fahrenheit degrees to convert
+    pub fn to_delta_celsius(value: f64) -> f64 {
+        value * 5.0 / 9.0
+    }
+
+    /// Converts a Fahrenheit temperature difference to Kelvin.
+    /// # Arguments
+    /// * `value` - The temperature difference in Fahrenheit degrees to convert
+    pub fn to_delta_kelvin(value: f64) -> f64 {
+        value * 5.0 / 9.0
+    }
+
+    /// Converts a Fahrenheit temperature difference to Rankine.
+    /// # Arguments
+    /// * `value` - The temperature difference in Fahrenheit degrees to convert
+    pub fn to_delta_rankine(value: f64) -> f64 {
+        value
+    }
+
+    /// The Fahrenheit degree as a typed unit of temperature difference.
+    pub const UNIT: Unit<TemperatureInterval> = Unit::new(
+        "ΔF",
+        "Fahrenheit degree",
+        to_delta_kelvin,
+        super::delta_kelvin::to_delta_fahrenheit,
+    );
+
+    /// Creates a typed temperature difference quantity expressed in Fahrenheit degrees.
+    /// # Arguments
+    /// * `value` - The temperature difference in Fahrenheit degrees
+    pub fn new(value: f64) -> Quantity<TemperatureInterval> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed temperature difference quantity in Fahrenheit degrees.
+    /// # Arguments
+    /// * `quantity` - The temperature difference quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<TemperatureInterval>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Kelvin temperature difference conversion functions
+pub mod delta_kelvin {
+    use crate::conversions::quantity::{Quantity, TemperatureInterval, Unit};
+
+    /// Converts a Kelvin temperature difference to Celsius.
+    /// # Arguments
+    /// * `value` - The temperature difference in kelvins to convert
+    pub fn to_delta_celsius(value: f64) -> f64 {
+        value
+    }
+
+    /// Converts a Kelvin temperature difference to Fahrenheit.
+    /// # Arguments
+    /// * `value` - The temperature difference in kelvins to convert
+    pub fn to_delta_fahrenheit(value: f64) -> f64 {
+        value * 9.0 / 5.0
+    }
+
+    /// Converts a Kelvin temperature difference to Rankine.
+    /// # Arguments
+    /// * `value` - The temperature difference in kelvins to convert
+    pub fn to_delta_rankine(value: f64) -> f64 {
+        value * 9.0 / 5.0
+    }
+
+    /// The kelvin as a typed unit of temperature difference.
+    pub const UNIT: Unit<TemperatureInterval> =
+        Unit::new("ΔK", "kelvin", |value| value, |value| value);
+
+    /// Creates a typed temperature difference quantity expressed in kelvins.
+    /// # Arguments
+    /// * `value` - The temperature difference in kelvins
+    pub fn new(value: f64) -> Quantity<TemperatureInterval> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed temperature difference quantity in kelvins.
+    /// # Arguments
+    /// * `quantity` - The temperature difference quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<TemperatureInterval>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Rankine temperature difference conversion functions
+pub mod delta_rankine {
+    use crate::conversions::quantity::{Quantity, TemperatureInterval, Unit};
+
+    /// Converts a Rankine temperature difference to Celsius.
+    /// # Arguments
+    /// * `value` - The temperature difference in Rankine degrees to convert
+    pub fn to_delta_celsius(value: f64) -> f64 {
+        super::delta_kelvin::to_delta_celsius(to_delta_kelvin(value))
+    }
+
+    /// Converts a Rankine temperature difference to Fahrenheit.
+    /// # Arguments
+    /// * `value` - The temperature difference in Rankine degrees to convert
+    pub fn to_delta_fahrenheit(value: f64) -> f64 {
+        value
+    }
+
+    /// Converts a Rankine temperature difference to Kelvin.
+    /// # Arguments
+    /// * `value` - The temperature difference in Rankine degrees to convert
+    pub fn to_delta_kelvin(value: f64) -> f64 {
+        value * 5.0 / 9.0
+    }
+
+    /// The Rankine degree as a typed unit of temperature difference.
+    pub const UNIT: Unit<TemperatureInterval> = Unit::new(
+        "ΔR",
+        "Rankine degree",
+        to_delta_kelvin,
+        super::delta_kelvin::to_delta_rankine,
+    );
+
+    /// Creates a typed temperature difference quantity expressed in Rankine degrees.
+    /// # Arguments
+    /// * `value` - The temperature difference in Rankine degrees
+    pub fn new(value: f64) -> Quantity<TemperatureInterval> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed temperature difference quantity in Rankine degrees.
+    /// # Arguments
+    /// * `quantity` - The temperature difference quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<TemperatureInterval>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 // Legacy function wrappers for backward compatibility
@@ -222,14 +961,20 @@ pub fn kelvin_to_fahrenheit(kelvin: f64) -> f64 {
 
 /// Converts between any two supported temperature units.
 ///
-/// This function handles conversions between Celsius, Fahrenheit, and Kelvin.
-/// All conversions use the standard formulas and maintain high precision.
+/// This function handles conversions between the Celsius, Fahrenheit and
+/// Kelvin scales and the Rankine, Réaumur, Delisle, Newton and Rømer scales,
+/// each described by its size of degree and its zero point.
 ///
 /// # Supported Units
 ///
-/// * **Celsius:** `c`, `celsius`
-/// * **Fahrenheit:** `f`, `fahrenheit`  
-/// * **Kelvin:** `k`, `kelvin`
+/// * **Celsius:** `C`, `°C`, `celsius`
+/// * **Fahrenheit:** `F`, `°F`, `fahrenheit`
+/// * **Kelvin:** `K`, `kelvin`
+/// * **Rankine:** `Ra`, `°R`, `°Ra`, `rankine`
+/// * **Réaumur:** `Re`, `°Ré`, `réaumur`, `reaumur`
+/// * **Delisle:** `De`, `°De`, `delisle`
+/// * **Newton:** `N`, `°N`, `newton`
+/// * **Rømer:** `Ro`, `°Rø`, `rømer`, `romer`
 ///
 /// Unit names are case-insensitive.
 ///
@@ -241,7 +986,12 @@ pub fn kelvin_to_fahrenheit(kelvin: f64) -> f64 {
 ///
 /// # Returns
 /// * `Ok(f64)` - The converted temperature if both units are recognized
-/// * `Err(String)` - An error message if either unit is not supported
+/// * `Err(ConversionError)` - An [`ConversionError::UnknownUnit`] if either unit is not supported,
+///   [`ConversionError::BelowAbsoluteZero`] if `value` is colder than 0 K, or
+///   [`ConversionError::NonFiniteInput`] if `value` is NaN or infinite
+///
+/// Use [`convert_temperature_lenient`] to convert values below absolute zero
+/// anyway.
 ///
 /// # Examples
 ///
@@ -264,8 +1014,15 @@ pub fn kelvin_to_fahrenheit(kelvin: f64) -> f64 {
 /// let result = convert_temperature(25.0, "CELSIUS", "fahrenheit").unwrap();
 /// assert_eq!(result, 77.0);
 ///
-/// // Error handling for unknown units
+/// // Historical scales
+/// let result = convert_temperature(100.0, "C", "De").unwrap();
+/// assert_eq!(result, 0.0);
+/// let result = convert_temperature(0.0, "F", "°R").unwrap();
+/// assert!((result - 459.67).abs() < 1e-9);
+///
+/// // Error handling for unknown units and impossible temperatures
 /// assert!(convert_temperature(100.0, "invalid", "C").is_err());
+/// assert!(convert_temperature(-500.0, "C", "K").is_err());
 /// ```
 ///
 /// # Temperature Scale Information
@@ -273,30 +1030,263 @@ pub fn kelvin_to_fahrenheit(kelvin: f64) -> f64 {
 /// * **Celsius (°C):** Water freezes at 0°C, boils at 100°C at standard pressure
 /// * **Fahrenheit (°F):** Water freezes at 32°F, boils at 212°F at standard pressure
 /// * **Kelvin (K):** Absolute temperature scale, 0K = absolute zero (-273.15°C)
-pub fn convert_temperature(value: f64, from: &str, to: &str) -> Result<f64, String> {
-    let result = match (from.to_lowercase().as_str(), to.to_lowercase().as_str()) {
-        // Celsius conversions
-        ("c" | "celsius", "f" | "fahrenheit") => celsius_to_fahrenheit(value),
-        ("c" | "celsius", "k" | "kelvin") => celsius_to_kelvin(value),
-        ("c" | "celsius", "c" | "celsius") => value,
-
-        // Fahrenheit conversions
-        ("f" | "fahrenheit", "c" | "celsius") => fahrenheit_to_celsius(value),
-        ("f" | "fahrenheit", "k" | "kelvin") => fahrenheit_to_kelvin(value),
-        ("f" | "fahrenheit", "f" | "fahrenheit") => value,
-
-        // Kelvin conversions
-        ("k" | "kelvin", "c" | "celsius") => kelvin_to_celsius(value),
-        ("k" | "kelvin", "f" | "fahrenheit") => kelvin_to_fahrenheit(value),
-        ("k" | "kelvin", "k" | "kelvin") => value,
-
-        _ => {
-            return Err(format!(
-                "Unknown temperature conversion: {} to {}",
-                from, to
-            ))
-        }
-    };
-
-    Ok(result)
+/// * **Rankine (°R):** Absolute scale with Fahrenheit-sized degrees, 0°R = absolute zero
+/// * **Réaumur (°Ré):** Water freezes at 0°Ré, boils at 80°Ré
+/// * **Delisle (°De):** Runs backwards: water boils at 0°De, freezes at 150°De
+/// * **Newton (°N):** Water freezes at 0°N, boils at 33°N
+/// * **Rømer (°Rø):** Water freezes at 7.5°Rø, boils at 60°Rø
+pub fn convert_temperature(value: f64, from: &str, to: &str) -> Result<f64, ConversionError> {
+    ConversionError::ensure_finite(value)?;
+    let registry = UnitRegistry::global();
+    let scale = registry.resolve(from, Category::Temperature)?;
+    ensure_above_absolute_zero(value, from, scale)?;
+
+    // A reading a rounding error below 0 K (e.g. -459.67°F) is absolute zero
+    if scale.convert_to_base(value) < 0.0 {
+        let target = registry.resolve(to, Category::Temperature)?;
+        return Ok(target.convert_from_base(0.0));
+    }
+    convert_temperature_lenient(value, from, to)
+}
+
+/// Converts between any two supported temperature units without checking for
+/// absolute zero.
+///
+/// Behaves like [`convert_temperature`] but lets nonphysical values through,
+/// for users who deliberately work with them (e.g. extrapolated fits).
+///
+/// # Arguments
+///
+/// * `value` - The temperature value to convert, which may be below 0 K
+/// * `from` - The source temperature unit (case-insensitive)
+/// * `to` - The target temperature unit (case-insensitive)
+///
+/// # Returns
+/// * `Ok(f64)` - The converted temperature if both units are recognized
+/// * `Err(ConversionError)` - An [`ConversionError::UnknownUnit`] if either unit is not supported,
+///   or [`ConversionError::NonFiniteInput`] if `value` is NaN or infinite
+///
+/// # Examples
+///
+/// ```
+/// use conversions_rs::convert_temperature_lenient;
+///
+/// let kelvin = convert_temperature_lenient(-500.0, "C", "K").unwrap();
+/// assert!((kelvin + 226.85).abs() < 1e-9);
+/// ```
+pub fn convert_temperature_lenient(
+    value: f64,
+    from: &str,
+    to: &str,
+) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from, to, Category::Temperature)
+}
+
+/// Rejects a reading colder than absolute zero on its scale.
+///
+/// The registry works in kelvins, so the check is made there, with a
+/// nanokelvin of slack for readings such as -459.67°F that land a rounding
+/// error below 0 K. Delisle runs backwards, so its impossible readings are the
+/// large ones.
+fn ensure_above_absolute_zero(
+    value: f64,
+    input: &str,
+    scale: &UnitDef,
+) -> Result<(), ConversionError> {
+    if scale.convert_to_base(value) >= -1e-9 {
+        return Ok(());
+    }
+
+    let absolute_zero = scale.convert_from_base(0.0);
+    Err(ConversionError::BelowAbsoluteZero {
+        value,
+        unit: input.to_string(),
+        absolute_zero: (absolute_zero * 1e9).round() / 1e9,
+    })
+}
+
+/// Temperature scales known to the [`UnitRegistry`], with the kelvin as the
+/// base unit.
+///
+/// Each scale is an affine transform of the kelvin: `factor` is the size of one
+/// degree in kelvins. The absolute scales (Kelvin, Rankine) read zero at 0 K,
+/// so absolute zero converts exactly between them. The other scales give their
+/// reading at the ice point (273.15 K) as `offset` instead, which keeps
+/// everyday conversions such as Celsius ↔ Fahrenheit exact in `f64`. Delisle
+/// runs backwards, so its factor is negative.
+pub(crate) fn units() -> Vec<UnitDef> {
+    let ice_point = celsius::to_kelvin(0.0);
+    vec![
+        UnitDef::new(
+            "C",
+            "celsius",
+            &["°C", "degree_celsius"],
+            Category::Temperature,
+            1.0,
+        )
+        .with_origin(ice_point),
+        UnitDef::new(
+            "F",
+            "fahrenheit",
+            &["°F", "degree_fahrenheit"],
+            Category::Temperature,
+            5.0 / 9.0,
+        )
+        .with_offset(32.0)
+        .with_origin(ice_point)
+        .with_exact_factor("5/9"),
+        UnitDef::new("K", "kelvin", &["kelvins"], Category::Temperature, 1.0).with_prefixes(),
+        UnitDef::new(
+            "Ra",
+            "rankine",
+            &["°R", "°Ra", "degree_rankine"],
+            Category::Temperature,
+            5.0 / 9.0,
+        )
+        .with_exact_factor("5/9"),
+        UnitDef::new(
+            "Re",
+            "réaumur",
+            &["°Ré", "°Re", "Ré", "reaumur", "degree_reaumur"],
+            Category::Temperature,
+            1.25,
+        )
+        .with_origin(ice_point),
+        UnitDef::new(
+            "De",
+            "delisle",
+            &["°De", "degree_delisle"],
+            Category::Temperature,
+            -2.0 / 3.0,
+        )
+        .with_offset(celsius::to_delisle(0.0))
+        .with_origin(ice_point)
+        .with_exact_factor("-2/3"),
+        UnitDef::new(
+            "N",
+            "newton",
+            &["°N", "degree_newton"],
+            Category::Temperature,
+            100.0 / 33.0,
+        )
+        .with_origin(ice_point)
+        .with_exact_factor("100/33"),
+        UnitDef::new(
+            "Ro",
+            "rømer",
+            &["°Rø", "°Ro", "Rø", "romer", "degree_romer"],
+            Category::Temperature,
+            40.0 / 21.0,
+        )
+        .with_offset(celsius::to_romer(0.0))
+        .with_origin(ice_point)
+        .with_exact_factor("40/21"),
+    ]
+}
+
+/// Converts a temperature difference between any two supported interval units.
+///
+/// Unlike [`convert_temperature`], only the size of a degree matters, so a
+/// rise of 10°C is a rise of 18°F. Units may be written with or without the
+/// `Δ`: a bare scale such as `C`, `°F` or `kelvin` is read as a difference on
+/// that scale.
+///
+/// # Supported Units
+///
+/// * **Celsius degrees:** `ΔC`, `Δ°C`, `delta_C`, `C`
+/// * **Fahrenheit degrees:** `ΔF`, `Δ°F`, `delta_F`, `F`
+/// * **Kelvins:** `ΔK`, `delta_K`, `K`
+/// * **Rankine degrees:** `ΔR`, `Δ°R`, `delta_R`, `R`, `Ra`
+///
+/// # Arguments
+///
+/// * `value` - The temperature difference to convert
+/// * `from` - The source interval unit (case-insensitive)
+/// * `to` - The target interval unit (case-insensitive)
+///
+/// # Returns
+/// * `Ok(f64)` - The converted temperature difference if both units are recognized
+/// * `Err(ConversionError)` - An [`ConversionError::UnknownUnit`] if either unit is not supported,
+///   or [`ConversionError::NonFiniteInput`] if `value` is NaN or infinite
+///
+/// # Examples
+///
+/// ```
+/// use conversions_rs::convert_temperature_interval;
+///
+/// // A 10°C rise is an 18°F rise
+/// let result = convert_temperature_interval(10.0, "ΔC", "ΔF").unwrap();
+/// assert!((result - 18.0).abs() < 1e-9);
+///
+/// // The Δ is optional
+/// let result = convert_temperature_interval(9.0, "F", "K").unwrap();
+/// assert!((result - 5.0).abs() < 1e-9);
+///
+/// assert!(convert_temperature_interval(1.0, "ΔC", "ΔDe").is_err());
+/// ```
+pub fn convert_temperature_interval(
+    value: f64,
+    from: &str,
+    to: &str,
+) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(
+        value,
+        &interval_spelling(from),
+        &interval_spelling(to),
+        Category::TemperatureInterval,
+    )
+}
+
+/// Marks a temperature scale as a difference (`C` → `ΔC`), leaving interval
+/// units that are already marked untouched.
+pub fn interval_spelling(unit: &str) -> Cow<'_, str> {
+    let unit = unit.trim();
+    let marked = unit.starts_with(['Δ', '∆'])
+        || unit
+            .get(..6)
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case("delta_"));
+    if marked {
+        Cow::Borrowed(unit)
+    } else {
+        Cow::Owned(format!("Δ{}", unit))
+    }
+}
+
+/// Temperature interval units known to the [`UnitRegistry`].
+///
+/// An interval is converted by the size of a degree alone, so unlike
+/// [`units`] none of these has an offset.
+pub(crate) fn interval_units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new(
+            "ΔC",
+            "celsius_degree",
+            &["Δ°C", "Δcelsius", "delta_C", "delta_celsius"],
+            Category::TemperatureInterval,
+            1.0,
+        ),
+        UnitDef::new(
+            "ΔF",
+            "fahrenheit_degree",
+            &["Δ°F", "Δfahrenheit", "delta_F", "delta_fahrenheit"],
+            Category::TemperatureInterval,
+            5.0 / 9.0,
+        )
+        .with_exact_factor("5/9"),
+        UnitDef::new(
+            "ΔK",
+            "kelvin_interval",
+            &["Δkelvin", "Δkelvins", "delta_K", "delta_kelvin"],
+            Category::TemperatureInterval,
+            1.0,
+        ),
+        UnitDef::new(
+            "ΔR",
+            "rankine_degree",
+            &["Δ°R", "ΔRa", "Δ°Ra", "Δrankine", "delta_R", "delta_rankine"],
+            Category::TemperatureInterval,
+            5.0 / 9.0,
+        )
+        .with_exact_factor("5/9"),
+    ]
 }
@@ -1,8 +1,14 @@
 //! Time conversion functions organized by unit type
 //! All conversions use seconds as the base unit for accuracy and consistency
 
+use super::category::Category;
+use super::error::ConversionError;
+use super::registry::{UnitDef, UnitRegistry};
+
 /// Seconds conversion functions
 pub mod seconds {
+    use crate::conversions::quantity::{Quantity, Time, Unit};
+
     /// Converts seconds to minutes.
     /// # Arguments
     /// * `value` - The time in seconds to convert
@@ -58,10 +64,29 @@ pub mod seconds {
     pub fn to_nanoseconds(value: f64) -> f64 {
         value * 1_000_000_000.0
     }
+
+    /// The second as a typed unit of time.
+    pub const UNIT: Unit<Time> = Unit::new("s", "second", |value| value, |value| value);
+
+    /// Creates a typed time quantity expressed in seconds.
+    /// # Arguments
+    /// * `value` - The time in seconds
+    pub fn new(value: f64) -> Quantity<Time> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed time quantity in seconds.
+    /// # Arguments
+    /// * `quantity` - The time quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Time>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Minutes conversion functions
 pub mod minutes {
+    use crate::conversions::quantity::{Quantity, Time, Unit};
+
     /// Converts minutes to seconds.
     /// # Arguments
     /// * `value` - The time in minutes to convert
@@ -117,10 +142,29 @@ pub mod minutes {
     pub fn to_nanoseconds(value: f64) -> f64 {
         super::seconds::to_nanoseconds(to_seconds(value))
     }
+
+    /// The minute as a typed unit of time.
+    pub const UNIT: Unit<Time> = Unit::new("min", "minute", to_seconds, super::seconds::to_minutes);
+
+    /// Creates a typed time quantity expressed in minutes.
+    /// # Arguments
+    /// * `value` - The time in minutes
+    pub fn new(value: f64) -> Quantity<Time> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed time quantity in minutes.
+    /// # Arguments
+    /// * `quantity` - The time quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Time>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Hours conversion functions
 pub mod hours {
+    use crate::conversions::quantity::{Quantity, Time, Unit};
+
     /// Converts hours to seconds.
     /// # Arguments
     /// * `value` - The time in hours to convert
@@ -176,10 +220,29 @@ pub mod hours {
     pub fn to_nanoseconds(value: f64) -> f64 {
         super::seconds::to_nanoseconds(to_seconds(value))
     }
+
+    /// The hour as a typed unit of time.
+    pub const UNIT: Unit<Time> = Unit::new("h", "hour", to_seconds, super::seconds::to_hours);
+
+    /// Creates a typed time quantity expressed in hours.
+    /// # Arguments
+    /// * `value` - The time in hours
+    pub fn new(value: f64) -> Quantity<Time> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed time quantity in hours.
+    /// # Arguments
+    /// * `quantity` - The time quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Time>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Days conversion functions
 pub mod days {
+    use crate::conversions::quantity::{Quantity, Time, Unit};
+
     /// Converts days to seconds.
     /// # Arguments
     /// * `value` - The time in days to convert
@@ -235,10 +298,29 @@ pub mod days {
     pub fn to_nanoseconds(value: f64) -> f64 {
         super::seconds::to_nanoseconds(to_seconds(value))
     }
+
+    /// The day as a typed unit of time.
+    pub const UNIT: Unit<Time> = Unit::new("d", "day", to_seconds, super::seconds::to_days);
+
+    /// Creates a typed time quantity expressed in days.
+    /// # Arguments
+    /// * `value` - The time in days
+    pub fn new(value: f64) -> Quantity<Time> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed time quantity in days.
+    /// # Arguments
+    /// * `quantity` - The time quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Time>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Weeks conversion functions
 pub mod weeks {
+    use crate::conversions::quantity::{Quantity, Time, Unit};
+
     /// Converts weeks to seconds.
     /// # Arguments
     /// * `value` - The time in weeks to convert
@@ -294,10 +376,29 @@ pub mod weeks {
     pub fn to_nanoseconds(value: f64) -> f64 {
         super::seconds::to_nanoseconds(to_seconds(value))
     }
+
+    /// The week as a typed unit of time.
+    pub const UNIT: Unit<Time> = Unit::new("wk", "week", to_seconds, super::seconds::to_weeks);
+
+    /// Creates a typed time quantity expressed in weeks.
+    /// # Arguments
+    /// * `value` - The time in weeks
+    pub fn new(value: f64) -> Quantity<Time> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed time quantity in weeks.
+    /// # Arguments
+    /// * `quantity` - The time quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Time>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Years conversion functions
 pub mod years {
+    use crate::conversions::quantity::{Quantity, Time, Unit};
+
     /// Converts years to seconds.
     /// # Arguments
     /// * `value` - The time in years to convert
@@ -353,10 +454,30 @@ pub mod years {
     pub fn to_nanoseconds(value: f64) -> f64 {
         super::seconds::to_nanoseconds(to_seconds(value))
     }
+
+    /// The Julian year as a typed unit of time.
+    pub const UNIT: Unit<Time> =
+        Unit::new("yr", "Julian year", to_seconds, super::seconds::to_years);
+
+    /// Creates a typed time quantity expressed in years.
+    /// # Arguments
+    /// * `value` - The time in years
+    pub fn new(value: f64) -> Quantity<Time> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed time quantity in years.
+    /// # Arguments
+    /// * `quantity` - The time quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Time>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Milliseconds conversion functions
 pub mod milliseconds {
+    use crate::conversions::quantity::{Quantity, Time, Unit};
+
     /// Converts milliseconds to seconds.
     /// # Arguments
     /// * `value` - The time in milliseconds to convert
@@ -412,10 +533,34 @@ pub mod milliseconds {
     pub fn to_nanoseconds(value: f64) -> f64 {
         value * 1_000_000.0
     }
+
+    /// The millisecond as a typed unit of time.
+    pub const UNIT: Unit<Time> = Unit::new(
+        "ms",
+        "millisecond",
+        to_seconds,
+        super::seconds::to_milliseconds,
+    );
+
+    /// Creates a typed time quantity expressed in milliseconds.
+    /// # Arguments
+    /// * `value` - The time in milliseconds
+    pub fn new(value: f64) -> Quantity<Time> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed time quantity in milliseconds.
+    /// # Arguments
+    /// * `quantity` - The time quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Time>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Microseconds conversion functions
 pub mod microseconds {
+    use crate::conversions::quantity::{Quantity, Time, Unit};
+
     /// Converts microseconds to seconds.
     /// # Arguments
     /// * `value` - The time in microseconds to convert
@@ -471,10 +616,34 @@ pub mod microseconds {
     pub fn to_nanoseconds(value: f64) -> f64 {
         value * 1000.0
     }
+
+    /// The microsecond as a typed unit of time.
+    pub const UNIT: Unit<Time> = Unit::new(
+        "μs",
+        "microsecond",
+        to_seconds,
+        super::seconds::to_microseconds,
+    );
+
+    /// Creates a typed time quantity expressed in microseconds.
+    /// # Arguments
+    /// * `value` - The time in microseconds
+    pub fn new(value: f64) -> Quantity<Time> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed time quantity in microseconds.
+    /// # Arguments
+    /// * `quantity` - The time quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Time>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Nanoseconds conversion functions
 pub mod nanoseconds {
+    use crate::conversions::quantity::{Quantity, Time, Unit};
+
     /// Converts nanoseconds to seconds.
     /// # Arguments
     /// * `value` - The time in nanoseconds to convert
@@ -530,6 +699,28 @@ pub mod nanoseconds {
     pub fn to_microseconds(value: f64) -> f64 {
         value / 1000.0
     }
+
+    /// The nanosecond as a typed unit of time.
+    pub const UNIT: Unit<Time> = Unit::new(
+        "ns",
+        "nanosecond",
+        to_seconds,
+        super::seconds::to_nanoseconds,
+    );
+
+    /// Creates a typed time quantity expressed in nanoseconds.
+    /// # Arguments
+    /// * `value` - The time in nanoseconds
+    pub fn new(value: f64) -> Quantity<Time> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed time quantity in nanoseconds.
+    /// # Arguments
+    /// * `quantity` - The time quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Time>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 // Legacy function wrappers for backward compatibility
@@ -600,7 +791,8 @@ pub fn seconds_to_nanoseconds(seconds: f64) -> f64 {
 /// General time conversion function that accepts string unit names
 ///
 /// Converts a time value from one unit to another using string identifiers.
-/// This function is case-insensitive and supports common abbreviations.
+/// Symbols are case-sensitive, names are case-insensitive, and common
+/// abbreviations are supported.
 ///
 /// # Arguments
 ///
@@ -610,7 +802,7 @@ pub fn seconds_to_nanoseconds(seconds: f64) -> f64 {
 ///
 /// # Returns
 /// * `Ok(f64)` - The converted value
-/// * `Err(String)` - Error message if the conversion is not supported
+/// * `Err(ConversionError)` - The reason the conversion is not supported
 ///
 /// # Examples
 ///
@@ -623,37 +815,69 @@ pub fn seconds_to_nanoseconds(seconds: f64) -> f64 {
 /// let milliseconds = convert_time(2.5, "s", "ms").unwrap();
 /// assert_eq!(milliseconds, 2500.0);
 /// ```
-pub fn convert_time(value: f64, from_unit: &str, to_unit: &str) -> Result<f64, String> {
-    let from_unit = from_unit.to_lowercase();
-    let to_unit = to_unit.to_lowercase();
-
-    // Convert input to seconds first
-    let seconds = match from_unit.as_str() {
-        "s" | "sec" | "second" | "seconds" => value,
-        "min" | "minute" | "minutes" => minutes::to_seconds(value),
-        "h" | "hr" | "hour" | "hours" => hours::to_seconds(value),
-        "d" | "day" | "days" => days::to_seconds(value),
-        "w" | "week" | "weeks" => weeks::to_seconds(value),
-        "y" | "year" | "years" => years::to_seconds(value),
-        "ms" | "millisecond" | "milliseconds" => milliseconds::to_seconds(value),
-        "us" | "μs" | "microsecond" | "microseconds" => microseconds::to_seconds(value),
-        "ns" | "nanosecond" | "nanoseconds" => nanoseconds::to_seconds(value),
-        _ => return Err(format!("Unsupported time unit: {}", from_unit)),
-    };
-
-    // Convert seconds to target unit
-    let result = match to_unit.as_str() {
-        "s" | "sec" | "second" | "seconds" => seconds,
-        "min" | "minute" | "minutes" => seconds::to_minutes(seconds),
-        "h" | "hr" | "hour" | "hours" => seconds::to_hours(seconds),
-        "d" | "day" | "days" => seconds::to_days(seconds),
-        "w" | "week" | "weeks" => seconds::to_weeks(seconds),
-        "y" | "year" | "years" => seconds::to_years(seconds),
-        "ms" | "millisecond" | "milliseconds" => seconds::to_milliseconds(seconds),
-        "us" | "μs" | "microsecond" | "microseconds" => seconds::to_microseconds(seconds),
-        "ns" | "nanosecond" | "nanoseconds" => seconds::to_nanoseconds(seconds),
-        _ => return Err(format!("Unsupported time unit: {}", to_unit)),
-    };
-
-    Ok(result)
+pub fn convert_time(value: f64, from_unit: &str, to_unit: &str) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from_unit, to_unit, Category::Time)
+}
+
+/// Time units known to the [`UnitRegistry`], with seconds as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new("s", "second", &["sec", "seconds"], Category::Time, 1.0).with_prefixes(),
+        UnitDef::new(
+            "min",
+            "minute",
+            &["minutes"],
+            Category::Time,
+            minutes::to_seconds(1.0),
+        ),
+        UnitDef::new(
+            "h",
+            "hour",
+            &["hr", "hours"],
+            Category::Time,
+            hours::to_seconds(1.0),
+        ),
+        UnitDef::new(
+            "day",
+            "day",
+            &["d", "days"],
+            Category::Time,
+            days::to_seconds(1.0),
+        ),
+        UnitDef::new(
+            "week",
+            "week",
+            &["w", "weeks"],
+            Category::Time,
+            weeks::to_seconds(1.0),
+        ),
+        UnitDef::new(
+            "year",
+            "year",
+            &["y", "years"],
+            Category::Time,
+            years::to_seconds(1.0),
+        ),
+        UnitDef::new(
+            "ms",
+            "millisecond",
+            &["milliseconds"],
+            Category::Time,
+            milliseconds::to_seconds(1.0),
+        ),
+        UnitDef::new(
+            "μs",
+            "microsecond",
+            &["us", "microseconds"],
+            Category::Time,
+            microseconds::to_seconds(1.0),
+        ),
+        UnitDef::new(
+            "ns",
+            "nanosecond",
+            &["nanoseconds"],
+            Category::Time,
+            nanoseconds::to_seconds(1.0),
+        ),
+    ]
 }
@@ -0,0 +1,399 @@
+//! Torque conversion functions organized by unit type
+//! All conversions use newton-meters as the base unit for accuracy and consistency
+//!
+//! Torque has the same SI dimensions as energy (N·m = J), but the two are
+//! different quantities: torque units are a separate category and dimension,
+//! so they never convert to or from joules.
+//!
+//! ```compile_fail
+//! use conversions_rs::conversions::*;
+//!
+//! let torque = torque::newton_meters::new(10.0);
+//! let energy = energy::joules::value_of(torque);
+//! ```
+
+use super::category::Category;
+use super::error::ConversionError;
+use super::force::{NEWTONS_PER_KILOGRAM_FORCE, NEWTONS_PER_POUND_FORCE};
+use super::length::{METERS_PER_FOOT, METERS_PER_INCH};
+use super::registry::{UnitDef, UnitRegistry};
+
+/// Newton-meters in one pound-force foot
+pub const NEWTON_METERS_PER_POUND_FORCE_FOOT: f64 = NEWTONS_PER_POUND_FORCE * METERS_PER_FOOT;
+
+/// Newton-meters in one pound-force inch
+pub const NEWTON_METERS_PER_POUND_FORCE_INCH: f64 = NEWTONS_PER_POUND_FORCE * METERS_PER_INCH;
+
+/// Newton-meter conversion functions
+pub mod newton_meters {
+    use crate::conversions::quantity::{Quantity, Torque, Unit};
+
+    /// Converts newton-meters to pound-force feet.
+    /// # Arguments
+    /// * `value` - The torque in newton-meters to convert
+    pub fn to_pound_force_feet(value: f64) -> f64 {
+        value / super::NEWTON_METERS_PER_POUND_FORCE_FOOT
+    }
+
+    /// Converts newton-meters to pound-force inches.
+    /// # Arguments
+    /// * `value` - The torque in newton-meters to convert
+    pub fn to_pound_force_inches(value: f64) -> f64 {
+        value / super::NEWTON_METERS_PER_POUND_FORCE_INCH
+    }
+
+    /// Converts newton-meters to kilogram-force meters.
+    /// # Arguments
+    /// * `value` - The torque in newton-meters to convert
+    pub fn to_kilogram_force_meters(value: f64) -> f64 {
+        value / super::NEWTONS_PER_KILOGRAM_FORCE
+    }
+
+    /// Converts newton-meters to dyne-centimeters.
+    /// # Arguments
+    /// * `value` - The torque in newton-meters to convert
+    pub fn to_dyne_centimeters(value: f64) -> f64 {
+        value * 1e7
+    }
+
+    /// The newton-meter as a typed unit of torque.
+    pub const UNIT: Unit<Torque> = Unit::new("N·m", "newton-meter", |value| value, |value| value);
+
+    /// Creates a typed torque quantity expressed in newton-meters.
+    /// # Arguments
+    /// * `value` - The torque in newton-meters
+    pub fn new(value: f64) -> Quantity<Torque> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed torque quantity in newton-meters.
+    /// # Arguments
+    /// * `quantity` - The torque quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Torque>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Pound-force foot conversion functions
+pub mod pound_force_feet {
+    use crate::conversions::quantity::{Quantity, Torque, Unit};
+
+    /// Converts pound-force feet to newton-meters.
+    /// # Arguments
+    /// * `value` - The torque in pound-force feet to convert
+    pub fn to_newton_meters(value: f64) -> f64 {
+        value * super::NEWTON_METERS_PER_POUND_FORCE_FOOT
+    }
+
+    /// Converts pound-force feet to pound-force inches.
+    /// # Arguments
+    /// * `value` - The torque in pound-force feet to convert
+    pub fn to_pound_force_inches(value: f64) -> f64 {
+        value * 12.0
+    }
+
+    /// Converts pound-force feet to kilogram-force meters.
+    /// # Arguments
+    /// * `value` - The torque in pound-force feet to convert
+    pub fn to_kilogram_force_meters(value: f64) -> f64 {
+        super::newton_meters::to_kilogram_force_meters(to_newton_meters(value))
+    }
+
+    /// Converts pound-force feet to dyne-centimeters.
+    /// # Arguments
+    /// * `value` - The torque in pound-force feet to convert
+    pub fn to_dyne_centimeters(value: f64) -> f64 {
+        super::newton_meters::to_dyne_centimeters(to_newton_meters(value))
+    }
+
+    /// The pound-force foot as a typed unit of torque.
+    pub const UNIT: Unit<Torque> = Unit::new(
+        "lbf·ft",
+        "pound-force foot",
+        to_newton_meters,
+        super::newton_meters::to_pound_force_feet,
+    );
+
+    /// Creates a typed torque quantity expressed in pound-force feet.
+    /// # Arguments
+    /// * `value` - The torque in pound-force feet
+    pub fn new(value: f64) -> Quantity<Torque> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed torque quantity in pound-force feet.
+    /// # Arguments
+    /// * `quantity` - The torque quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Torque>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Pound-force inch conversion functions
+pub mod pound_force_inches {
+    use crate::conversions::quantity::{Quantity, Torque, Unit};
+
+    /// Converts pound-force inches to newton-meters.
+    /// # Arguments
+    /// * `value` - The torque in pound-force inches to convert
+    pub fn to_newton_meters(value: f64) -> f64 {
+        value * super::NEWTON_METERS_PER_POUND_FORCE_INCH
+    }
+
+    /// Converts pound-force inches to pound-force feet.
+    /// # Arguments
+    /// * `value` - The torque in pound-force inches to convert
+    pub fn to_pound_force_feet(value: f64) -> f64 {
+        value / 12.0
+    }
+
+    /// Converts pound-force inches to kilogram-force meters.
+    /// # Arguments
+    /// * `value` - The torque in pound-force inches to convert
+    pub fn to_kilogram_force_meters(value: f64) -> f64 {
+        super::newton_meters::to_kilogram_force_meters(to_newton_meters(value))
+    }
+
+    /// Converts pound-force inches to dyne-centimeters.
+    /// # Arguments
+    /// * `value` - The torque in pound-force inches to convert
+    pub fn to_dyne_centimeters(value: f64) -> f64 {
+        super::newton_meters::to_dyne_centimeters(to_newton_meters(value))
+    }
+
+    /// The pound-force inch as a typed unit of torque.
+    pub const UNIT: Unit<Torque> = Unit::new(
+        "lbf·in",
+        "pound-force inch",
+        to_newton_meters,
+        super::newton_meters::to_pound_force_inches,
+    );
+
+    /// Creates a typed torque quantity expressed in pound-force inches.
+    /// # Arguments
+    /// * `value` - The torque in pound-force inches
+    pub fn new(value: f64) -> Quantity<Torque> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed torque quantity in pound-force inches.
+    /// # Arguments
+    /// * `quantity` - The torque quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Torque>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Kilogram-force meter conversion functions
+pub mod kilogram_force_meters {
+    use crate::conversions::quantity::{Quantity, Torque, Unit};
+
+    /// Converts kilogram-force meters to newton-meters.
+    /// # Arguments
+    /// * `value` - The torque in kilogram-force meters to convert
+    pub fn to_newton_meters(value: f64) -> f64 {
+        value * super::NEWTONS_PER_KILOGRAM_FORCE
+    }
+
+    /// Converts kilogram-force meters to pound-force feet.
+    /// # Arguments
+    /// * `value` - The torque in kilogram-force meters to convert
+    pub fn to_pound_force_feet(value: f64) -> f64 {
+        super::newton_meters::to_pound_force_feet(to_newton_meters(value))
+    }
+
+    /// Converts kilogram-force meters to pound-force inches.
+    /// # Arguments
+    /// * `value` - The torque in kilogram-force meters to convert
+    pub fn to_pound_force_inches(value: f64) -> f64 {
+        super::newton_meters::to_pound_force_inches(to_newton_meters(value))
+    }
+
+    /// Converts kilogram-force meters to dyne-centimeters.
+    /// # Arguments
+    /// * `value` - The torque in kilogram-force meters to convert
+    pub fn to_dyne_centimeters(value: f64) -> f64 {
+        super::newton_meters::to_dyne_centimeters(to_newton_meters(value))
+    }
+
+    /// The kilogram-force meter as a typed unit of torque.
+    pub const UNIT: Unit<Torque> = Unit::new(
+        "kgf·m",
+        "kilogram-force meter",
+        to_newton_meters,
+        super::newton_meters::to_kilogram_force_meters,
+    );
+
+    /// Creates a typed torque quantity expressed in kilogram-force meters.
+    /// # Arguments
+    /// * `value` - The torque in kilogram-force meters
+    pub fn new(value: f64) -> Quantity<Torque> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed torque quantity in kilogram-force meters.
+    /// # Arguments
+    /// * `quantity` - The torque quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Torque>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Dyne-centimeter conversion functions (CGS unit of torque)
+pub mod dyne_centimeters {
+    use crate::conversions::quantity::{Quantity, Torque, Unit};
+
+    /// Converts dyne-centimeters to newton-meters.
+    /// # Arguments
+    /// * `value` - The torque in dyne-centimeters to convert
+    pub fn to_newton_meters(value: f64) -> f64 {
+        value * 1e-7
+    }
+
+    /// Converts dyne-centimeters to pound-force feet.
+    /// # Arguments
+    /// * `value` - The torque in dyne-centimeters to convert
+    pub fn to_pound_force_feet(value: f64) -> f64 {
+        super::newton_meters::to_pound_force_feet(to_newton_meters(value))
+    }
+
+    /// Converts dyne-centimeters to pound-force inches.
+    /// # Arguments
+    /// * `value` - The torque in dyne-centimeters to convert
+    pub fn to_pound_force_inches(value: f64) -> f64 {
+        super::newton_meters::to_pound_force_inches(to_newton_meters(value))
+    }
+
+    /// Converts dyne-centimeters to kilogram-force meters.
+    /// # Arguments
+    /// * `value` - The torque in dyne-centimeters to convert
+    pub fn to_kilogram_force_meters(value: f64) -> f64 {
+        super::newton_meters::to_kilogram_force_meters(to_newton_meters(value))
+    }
+
+    /// The dyne-centimeter as a typed unit of torque.
+    pub const UNIT: Unit<Torque> = Unit::new(
+        "dyn·cm",
+        "dyne-centimeter",
+        to_newton_meters,
+        super::newton_meters::to_dyne_centimeters,
+    );
+
+    /// Creates a typed torque quantity expressed in dyne-centimeters.
+    /// # Arguments
+    /// * `value` - The torque in dyne-centimeters
+    pub fn new(value: f64) -> Quantity<Torque> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed torque quantity in dyne-centimeters.
+    /// # Arguments
+    /// * `quantity` - The torque quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Torque>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// General torque conversion function that accepts string unit names
+///
+/// Converts a torque from one unit to another using string identifiers.
+/// Symbols are case-sensitive, names are case-insensitive, and common
+/// abbreviations are supported. Energy units such as `J` or `ft·lbf` are not
+/// accepted, even though they share the dimensions of torque.
+///
+/// # Arguments
+///
+/// * `value` - The numeric value to convert
+/// * `from_unit` - The source unit (e.g., "N·m", "lbf·ft", "lbf·in", "kgf·m", "dyn·cm")
+/// * `to_unit` - The target unit using the same abbreviations
+///
+/// # Returns
+/// * `Ok(f64)` - The converted value
+/// * `Err(ConversionError)` - The reason the conversion is not supported
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::{convert_torque, ConversionError};
+///
+/// let newton_meters = convert_torque(100.0, "lbf·ft", "N·m").unwrap();
+/// assert!((newton_meters - 135.582).abs() < 0.001);
+///
+/// assert!(matches!(
+///     convert_torque(1.0, "N·m", "J"),
+///     Err(ConversionError::UnknownUnit { .. })
+/// ));
+/// ```
+pub fn convert_torque(value: f64, from_unit: &str, to_unit: &str) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from_unit, to_unit, Category::Torque)
+}
+
+/// Torque units known to the [`UnitRegistry`], with newton-meters as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new(
+            "N·m",
+            "newton-meter",
+            &[
+                "N*m",
+                "N-m",
+                "Nm",
+                "newton-meters",
+                "newton meter",
+                "newton-metre",
+            ],
+            Category::Torque,
+            1.0,
+        ),
+        UnitDef::new(
+            "lbf·ft",
+            "pound-force foot",
+            &[
+                "lbf*ft",
+                "lbf-ft",
+                "lbft",
+                "pound-feet",
+                "pound-foot",
+                "pound-force feet",
+            ],
+            Category::Torque,
+            pound_force_feet::to_newton_meters(1.0),
+        )
+        .with_exact_factor("0.45359237*9.80665*0.3048"),
+        UnitDef::new(
+            "lbf·in",
+            "pound-force inch",
+            &[
+                "lbf*in",
+                "lbf-in",
+                "lbin",
+                "pound-inches",
+                "pound-inch",
+                "pound-force inches",
+            ],
+            Category::Torque,
+            pound_force_inches::to_newton_meters(1.0),
+        )
+        .with_exact_factor("0.45359237*9.80665*0.0254"),
+        UnitDef::new(
+            "kgf·m",
+            "kilogram-force meter",
+            &[
+                "kgf*m",
+                "kgf-m",
+                "kgm",
+                "kilogram-force meters",
+                "kilopond meter",
+            ],
+            Category::Torque,
+            kilogram_force_meters::to_newton_meters(1.0),
+        ),
+        UnitDef::new(
+            "dyn·cm",
+            "dyne-centimeter",
+            &["dyn*cm", "dyn-cm", "dyne-centimeters"],
+            Category::Torque,
+            dyne_centimeters::to_newton_meters(1.0),
+        ),
+    ]
+}
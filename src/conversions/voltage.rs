@@ -0,0 +1,332 @@
+//! Voltage (electric potential) conversion functions organized by unit type
+//! All conversions use volts as the base unit; the CGS abvolt and statvolt are
+//! defined by their exact relation to the volt
+
+use super::category::Category;
+use super::error::ConversionError;
+use super::registry::{UnitDef, UnitRegistry};
+
+/// Volts in one abvolt, the electromagnetic CGS unit
+pub const VOLTS_PER_ABVOLT: f64 = 1e-8;
+
+/// Volts in one statvolt, the electrostatic CGS unit (c / 10⁶ with c in m/s)
+pub const VOLTS_PER_STATVOLT: f64 = 299.792_458;
+
+/// Volts conversion functions
+pub mod volts {
+    use crate::conversions::quantity::{Quantity, Unit, Voltage};
+
+    /// Converts volts to millivolts.
+    /// # Arguments
+    /// * `value` - The voltage in volts to convert
+    pub fn to_millivolts(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// Converts volts to kilovolts.
+    /// # Arguments
+    /// * `value` - The voltage in volts to convert
+    pub fn to_kilovolts(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// Converts volts to abvolts.
+    /// # Arguments
+    /// * `value` - The voltage in volts to convert
+    pub fn to_abvolts(value: f64) -> f64 {
+        value / super::VOLTS_PER_ABVOLT
+    }
+
+    /// Converts volts to statvolts.
+    /// # Arguments
+    /// * `value` - The voltage in volts to convert
+    pub fn to_statvolts(value: f64) -> f64 {
+        value / super::VOLTS_PER_STATVOLT
+    }
+
+    /// The volt as a typed unit of voltage.
+    pub const UNIT: Unit<Voltage> = Unit::new("V", "volt", |value| value, |value| value);
+
+    /// Creates a typed voltage quantity expressed in volts.
+    /// # Arguments
+    /// * `value` - The voltage in volts
+    pub fn new(value: f64) -> Quantity<Voltage> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed voltage quantity in volts.
+    /// # Arguments
+    /// * `quantity` - The voltage quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Voltage>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Millivolts conversion functions
+pub mod millivolts {
+    use crate::conversions::quantity::{Quantity, Unit, Voltage};
+
+    /// Converts millivolts to volts.
+    /// # Arguments
+    /// * `value` - The voltage in millivolts to convert
+    pub fn to_volts(value: f64) -> f64 {
+        value / 1000.0
+    }
+
+    /// Converts millivolts to kilovolts.
+    /// # Arguments
+    /// * `value` - The voltage in millivolts to convert
+    pub fn to_kilovolts(value: f64) -> f64 {
+        value / 1_000_000.0
+    }
+
+    /// Converts millivolts to abvolts.
+    /// # Arguments
+    /// * `value` - The voltage in millivolts to convert
+    pub fn to_abvolts(value: f64) -> f64 {
+        super::volts::to_abvolts(to_volts(value))
+    }
+
+    /// Converts millivolts to statvolts.
+    /// # Arguments
+    /// * `value` - The voltage in millivolts to convert
+    pub fn to_statvolts(value: f64) -> f64 {
+        super::volts::to_statvolts(to_volts(value))
+    }
+
+    /// The millivolt as a typed unit of voltage.
+    pub const UNIT: Unit<Voltage> =
+        Unit::new("mV", "millivolt", to_volts, super::volts::to_millivolts);
+
+    /// Creates a typed voltage quantity expressed in millivolts.
+    /// # Arguments
+    /// * `value` - The voltage in millivolts
+    pub fn new(value: f64) -> Quantity<Voltage> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed voltage quantity in millivolts.
+    /// # Arguments
+    /// * `quantity` - The voltage quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Voltage>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Kilovolts conversion functions
+pub mod kilovolts {
+    use crate::conversions::quantity::{Quantity, Unit, Voltage};
+
+    /// Converts kilovolts to volts.
+    /// # Arguments
+    /// * `value` - The voltage in kilovolts to convert
+    pub fn to_volts(value: f64) -> f64 {
+        value * 1000.0
+    }
+
+    /// Converts kilovolts to millivolts.
+    /// # Arguments
+    /// * `value` - The voltage in kilovolts to convert
+    pub fn to_millivolts(value: f64) -> f64 {
+        value * 1_000_000.0
+    }
+
+    /// Converts kilovolts to abvolts.
+    /// # Arguments
+    /// * `value` - The voltage in kilovolts to convert
+    pub fn to_abvolts(value: f64) -> f64 {
+        super::volts::to_abvolts(to_volts(value))
+    }
+
+    /// Converts kilovolts to statvolts.
+    /// # Arguments
+    /// * `value` - The voltage in kilovolts to convert
+    pub fn to_statvolts(value: f64) -> f64 {
+        super::volts::to_statvolts(to_volts(value))
+    }
+
+    /// The kilovolt as a typed unit of voltage.
+    pub const UNIT: Unit<Voltage> =
+        Unit::new("kV", "kilovolt", to_volts, super::volts::to_kilovolts);
+
+    /// Creates a typed voltage quantity expressed in kilovolts.
+    /// # Arguments
+    /// * `value` - The voltage in kilovolts
+    pub fn new(value: f64) -> Quantity<Voltage> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed voltage quantity in kilovolts.
+    /// # Arguments
+    /// * `quantity` - The voltage quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Voltage>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Abvolts (electromagnetic CGS unit) conversion functions
+pub mod abvolts {
+    use crate::conversions::quantity::{Quantity, Unit, Voltage};
+
+    /// Converts abvolts to volts.
+    /// # Arguments
+    /// * `value` - The voltage in abvolts to convert
+    pub fn to_volts(value: f64) -> f64 {
+        value * super::VOLTS_PER_ABVOLT
+    }
+
+    /// Converts abvolts to millivolts.
+    /// # Arguments
+    /// * `value` - The voltage in abvolts to convert
+    pub fn to_millivolts(value: f64) -> f64 {
+        super::volts::to_millivolts(to_volts(value))
+    }
+
+    /// Converts abvolts to kilovolts.
+    /// # Arguments
+    /// * `value` - The voltage in abvolts to convert
+    pub fn to_kilovolts(value: f64) -> f64 {
+        super::volts::to_kilovolts(to_volts(value))
+    }
+
+    /// Converts abvolts to statvolts.
+    /// # Arguments
+    /// * `value` - The voltage in abvolts to convert
+    pub fn to_statvolts(value: f64) -> f64 {
+        super::volts::to_statvolts(to_volts(value))
+    }
+
+    /// The abvolt as a typed unit of voltage.
+    pub const UNIT: Unit<Voltage> = Unit::new("abV", "abvolt", to_volts, super::volts::to_abvolts);
+
+    /// Creates a typed voltage quantity expressed in abvolts.
+    /// # Arguments
+    /// * `value` - The voltage in abvolts
+    pub fn new(value: f64) -> Quantity<Voltage> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed voltage quantity in abvolts.
+    /// # Arguments
+    /// * `quantity` - The voltage quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Voltage>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// Statvolts (electrostatic CGS unit) conversion functions
+pub mod statvolts {
+    use crate::conversions::quantity::{Quantity, Unit, Voltage};
+
+    /// Converts statvolts to volts.
+    /// # Arguments
+    /// * `value` - The voltage in statvolts to convert
+    pub fn to_volts(value: f64) -> f64 {
+        value * super::VOLTS_PER_STATVOLT
+    }
+
+    /// Converts statvolts to millivolts.
+    /// # Arguments
+    /// * `value` - The voltage in statvolts to convert
+    pub fn to_millivolts(value: f64) -> f64 {
+        super::volts::to_millivolts(to_volts(value))
+    }
+
+    /// Converts statvolts to kilovolts.
+    /// # Arguments
+    /// * `value` - The voltage in statvolts to convert
+    pub fn to_kilovolts(value: f64) -> f64 {
+        super::volts::to_kilovolts(to_volts(value))
+    }
+
+    /// Converts statvolts to abvolts.
+    /// # Arguments
+    /// * `value` - The voltage in statvolts to convert
+    pub fn to_abvolts(value: f64) -> f64 {
+        super::volts::to_abvolts(to_volts(value))
+    }
+
+    /// The statvolt as a typed unit of voltage.
+    pub const UNIT: Unit<Voltage> =
+        Unit::new("statV", "statvolt", to_volts, super::volts::to_statvolts);
+
+    /// Creates a typed voltage quantity expressed in statvolts.
+    /// # Arguments
+    /// * `value` - The voltage in statvolts
+    pub fn new(value: f64) -> Quantity<Voltage> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed voltage quantity in statvolts.
+    /// # Arguments
+    /// * `quantity` - The voltage quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Voltage>) -> f64 {
+        quantity.value_in(UNIT)
+    }
+}
+
+/// General voltage conversion function that accepts string unit names
+///
+/// Converts an electric potential from one unit to another using string
+/// identifiers. Symbols are case-sensitive, so `mV` (millivolt) and `MV`
+/// (megavolt) are different units; names are case-insensitive.
+///
+/// # Arguments
+///
+/// * `value` - The numeric value to convert
+/// * `from_unit` - The source unit (e.g., "V", "mV", "kV", "abV", "statV")
+/// * `to_unit` - The target unit using the same abbreviations
+///
+/// # Returns
+/// * `Ok(f64)` - The converted value
+/// * `Err(ConversionError)` - The reason the conversion is not supported
+///
+/// # Examples
+///
+/// ```rust
+/// use conversions_rs::convert_voltage;
+///
+/// let millivolts = convert_voltage(3.3, "V", "mV").unwrap();
+/// assert!((millivolts - 3300.0).abs() < 1e-9);
+///
+/// let volts = convert_voltage(1.0, "statV", "V").unwrap();
+/// assert!((volts - 299.792_458).abs() < 1e-9);
+/// ```
+pub fn convert_voltage(value: f64, from_unit: &str, to_unit: &str) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from_unit, to_unit, Category::Voltage)
+}
+
+/// Voltage units known to the [`UnitRegistry`], with volts as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new("V", "volt", &["volts"], Category::Voltage, 1.0).with_prefixes(),
+        UnitDef::new(
+            "mV",
+            "millivolt",
+            &["millivolts"],
+            Category::Voltage,
+            millivolts::to_volts(1.0),
+        ),
+        UnitDef::new(
+            "kV",
+            "kilovolt",
+            &["kilovolts"],
+            Category::Voltage,
+            kilovolts::to_volts(1.0),
+        ),
+        UnitDef::new(
+            "abV",
+            "abvolt",
+            &["abvolts"],
+            Category::Voltage,
+            abvolts::to_volts(1.0),
+        ),
+        UnitDef::new(
+            "statV",
+            "statvolt",
+            &["statvolts"],
+            Category::Voltage,
+            statvolts::to_volts(1.0),
+        ),
+    ]
+}
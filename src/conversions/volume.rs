@@ -1,20 +1,32 @@
 //! Volume conversion functions organized by unit type
 //! All conversions use liters as the base unit for accuracy and consistency
 
+use super::category::Category;
+use super::error::ConversionError;
+use super::registry::{UnitDef, UnitRegistry};
+
+/// Liters in one US liquid gallon, defined as exactly 231 cubic inches
+pub const LITERS_PER_GALLON_US: f64 = 3.785_411_784;
+
+/// Liters in one imperial (UK) gallon, defined as exactly 4.54609 liters
+pub const LITERS_PER_GALLON_UK: f64 = 4.546_09;
+
 /// Liters conversion functions
 pub mod liters {
+    use crate::conversions::quantity::{Quantity, Unit, Volume};
+
     /// Converts liters to US gallons.
     /// # Arguments
     /// * `value` - The volume in liters to convert
     pub fn to_gallons_us(value: f64) -> f64 {
-        value / 3.78541
+        value / super::LITERS_PER_GALLON_US
     }
 
     /// Converts liters to UK gallons.
     /// # Arguments
     /// * `value` - The volume in liters to convert
     pub fn to_gallons_uk(value: f64) -> f64 {
-        value / 4.54609
+        value / super::LITERS_PER_GALLON_UK
     }
 
     /// Converts liters to milliliters.
@@ -28,45 +40,64 @@ pub mod liters {
     /// # Arguments
     /// * `value` - The volume in liters to convert
     pub fn to_fluid_ounces_us(value: f64) -> f64 {
-        value * 33.814
+        super::gallons_us::to_fluid_ounces_us(to_gallons_us(value))
     }
 
     /// Converts liters to UK fluid ounces.
     /// # Arguments
     /// * `value` - The volume in liters to convert
     pub fn to_fluid_ounces_uk(value: f64) -> f64 {
-        value * 35.1951
+        super::gallons_uk::to_fluid_ounces_uk(to_gallons_uk(value))
     }
 
     /// Converts liters to US cups.
     /// # Arguments
     /// * `value` - The volume in liters to convert
     pub fn to_cups_us(value: f64) -> f64 {
-        value * 4.22675
+        super::gallons_us::to_cups_us(to_gallons_us(value))
     }
 
     /// Converts liters to US pints.
     /// # Arguments
     /// * `value` - The volume in liters to convert
     pub fn to_pints_us(value: f64) -> f64 {
-        value * 2.11338
+        super::gallons_us::to_pints_us(to_gallons_us(value))
     }
 
     /// Converts liters to US quarts.
     /// # Arguments
     /// * `value` - The volume in liters to convert
     pub fn to_quarts_us(value: f64) -> f64 {
-        value * 1.05669
+        super::gallons_us::to_quarts_us(to_gallons_us(value))
+    }
+
+    /// The liter as a typed unit of volume.
+    pub const UNIT: Unit<Volume> = Unit::new("L", "liter", |value| value, |value| value);
+
+    /// Creates a typed volume quantity expressed in liters.
+    /// # Arguments
+    /// * `value` - The volume in liters
+    pub fn new(value: f64) -> Quantity<Volume> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed volume quantity in liters.
+    /// # Arguments
+    /// * `quantity` - The volume quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Volume>) -> f64 {
+        quantity.value_in(UNIT)
     }
 }
 
 /// US gallons conversion functions
 pub mod gallons_us {
+    use crate::conversions::quantity::{Quantity, Unit, Volume};
+
     /// Converts US gallons to liters.
     /// # Arguments
     /// * `value` - The volume in US gallons to convert
     pub fn to_liters(value: f64) -> f64 {
-        value * 3.78541
+        value * super::LITERS_PER_GALLON_US
     }
 
     /// Converts US gallons to UK gallons.
@@ -117,15 +148,35 @@ pub mod gallons_us {
     pub fn to_quarts_us(value: f64) -> f64 {
         value * 4.0
     }
+
+    /// The US gallon as a typed unit of volume.
+    pub const UNIT: Unit<Volume> =
+        Unit::new("gal", "US gallon", to_liters, super::liters::to_gallons_us);
+
+    /// Creates a typed volume quantity expressed in gallons us.
+    /// # Arguments
+    /// * `value` - The volume in US gallons
+    pub fn new(value: f64) -> Quantity<Volume> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed volume quantity in gallons us.
+    /// # Arguments
+    /// * `quantity` - The volume quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Volume>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// UK gallons conversion functions
 pub mod gallons_uk {
+    use crate::conversions::quantity::{Quantity, Unit, Volume};
+
     /// Converts UK gallons to liters.
     /// # Arguments
     /// * `value` - The volume in UK gallons to convert
     pub fn to_liters(value: f64) -> f64 {
-        value * 4.54609
+        value * super::LITERS_PER_GALLON_UK
     }
 
     /// Converts UK gallons to US gallons.
@@ -176,10 +227,34 @@ pub mod gallons_uk {
     pub fn to_quarts_us(value: f64) -> f64 {
         super::liters::to_quarts_us(to_liters(value))
     }
+
+    /// The UK gallon as a typed unit of volume.
+    pub const UNIT: Unit<Volume> = Unit::new(
+        "gal_uk",
+        "UK gallon",
+        to_liters,
+        super::liters::to_gallons_uk,
+    );
+
+    /// Creates a typed volume quantity expressed in gallons uk.
+    /// # Arguments
+    /// * `value` - The volume in UK gallons
+    pub fn new(value: f64) -> Quantity<Volume> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed volume quantity in gallons uk.
+    /// # Arguments
+    /// * `quantity` - The volume quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Volume>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Milliliters conversion functions
 pub mod milliliters {
+    use crate::conversions::quantity::{Quantity, Unit, Volume};
+
     /// Converts milliliters to liters.
     /// # Arguments
     /// * `value` - The volume in milliliters to convert
@@ -205,45 +280,65 @@ pub mod milliliters {
     /// # Arguments
     /// * `value` - The volume in milliliters to convert
     pub fn to_fluid_ounces_us(value: f64) -> f64 {
-        value / 29.5735
+        super::liters::to_fluid_ounces_us(to_liters(value))
     }
 
     /// Converts milliliters to UK fluid ounces.
     /// # Arguments
     /// * `value` - The volume in milliliters to convert
     pub fn to_fluid_ounces_uk(value: f64) -> f64 {
-        value / 28.4131
+        super::liters::to_fluid_ounces_uk(to_liters(value))
     }
 
     /// Converts milliliters to US cups.
     /// # Arguments
     /// * `value` - The volume in milliliters to convert
     pub fn to_cups_us(value: f64) -> f64 {
-        value / 236.588
+        super::liters::to_cups_us(to_liters(value))
     }
 
     /// Converts milliliters to US pints.
     /// # Arguments
     /// * `value` - The volume in milliliters to convert
     pub fn to_pints_us(value: f64) -> f64 {
-        value / 473.176
+        super::liters::to_pints_us(to_liters(value))
     }
 
     /// Converts milliliters to US quarts.
     /// # Arguments
     /// * `value` - The volume in milliliters to convert
     pub fn to_quarts_us(value: f64) -> f64 {
-        value / 946.353
+        super::liters::to_quarts_us(to_liters(value))
+    }
+
+    /// The milliliter as a typed unit of volume.
+    pub const UNIT: Unit<Volume> =
+        Unit::new("mL", "milliliter", to_liters, super::liters::to_milliliters);
+
+    /// Creates a typed volume quantity expressed in milliliters.
+    /// # Arguments
+    /// * `value` - The volume in milliliters
+    pub fn new(value: f64) -> Quantity<Volume> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed volume quantity in milliliters.
+    /// # Arguments
+    /// * `quantity` - The volume quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Volume>) -> f64 {
+        quantity.value_in(UNIT)
     }
 }
 
 /// US fluid ounces conversion functions
 pub mod fluid_ounces_us {
+    use crate::conversions::quantity::{Quantity, Unit, Volume};
+
     /// Converts US fluid ounces to liters.
     /// # Arguments
     /// * `value` - The volume in US fluid ounces to convert
     pub fn to_liters(value: f64) -> f64 {
-        value / 33.814
+        super::gallons_us::to_liters(to_gallons_us(value))
     }
 
     /// Converts US fluid ounces to US gallons.
@@ -264,7 +359,7 @@ pub mod fluid_ounces_us {
     /// # Arguments
     /// * `value` - The volume in US fluid ounces to convert
     pub fn to_milliliters(value: f64) -> f64 {
-        value * 29.5735
+        super::liters::to_milliliters(to_liters(value))
     }
 
     /// Converts US fluid ounces to UK fluid ounces.
@@ -294,15 +389,39 @@ pub mod fluid_ounces_us {
     pub fn to_quarts_us(value: f64) -> f64 {
         value / 32.0
     }
+
+    /// The US fluid ounce as a typed unit of volume.
+    pub const UNIT: Unit<Volume> = Unit::new(
+        "fl_oz",
+        "US fluid ounce",
+        to_liters,
+        super::liters::to_fluid_ounces_us,
+    );
+
+    /// Creates a typed volume quantity expressed in fluid ounces us.
+    /// # Arguments
+    /// * `value` - The volume in US fluid ounces
+    pub fn new(value: f64) -> Quantity<Volume> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed volume quantity in fluid ounces us.
+    /// # Arguments
+    /// * `quantity` - The volume quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Volume>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// UK fluid ounces conversion functions
 pub mod fluid_ounces_uk {
+    use crate::conversions::quantity::{Quantity, Unit, Volume};
+
     /// Converts UK fluid ounces to liters.
     /// # Arguments
     /// * `value` - The volume in UK fluid ounces to convert
     pub fn to_liters(value: f64) -> f64 {
-        value / 35.1951
+        super::gallons_uk::to_liters(to_gallons_uk(value))
     }
 
     /// Converts UK fluid ounces to US gallons.
@@ -323,7 +442,7 @@ pub mod fluid_ounces_uk {
     /// # Arguments
     /// * `value` - The volume in UK fluid ounces to convert
     pub fn to_milliliters(value: f64) -> f64 {
-        value * 28.4131
+        super::liters::to_milliliters(to_liters(value))
     }
 
     /// Converts UK fluid ounces to US fluid ounces.
@@ -353,15 +472,39 @@ pub mod fluid_ounces_uk {
     pub fn to_quarts_us(value: f64) -> f64 {
         super::liters::to_quarts_us(to_liters(value))
     }
+
+    /// The UK fluid ounce as a typed unit of volume.
+    pub const UNIT: Unit<Volume> = Unit::new(
+        "fl_oz_uk",
+        "UK fluid ounce",
+        to_liters,
+        super::liters::to_fluid_ounces_uk,
+    );
+
+    /// Creates a typed volume quantity expressed in fluid ounces uk.
+    /// # Arguments
+    /// * `value` - The volume in UK fluid ounces
+    pub fn new(value: f64) -> Quantity<Volume> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed volume quantity in fluid ounces uk.
+    /// # Arguments
+    /// * `quantity` - The volume quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Volume>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// US cups conversion functions
 pub mod cups_us {
+    use crate::conversions::quantity::{Quantity, Unit, Volume};
+
     /// Converts US cups to liters.
     /// # Arguments
     /// * `value` - The volume in US cups to convert
     pub fn to_liters(value: f64) -> f64 {
-        value / 4.22675
+        super::gallons_us::to_liters(to_gallons_us(value))
     }
 
     /// Converts US cups to US gallons.
@@ -382,7 +525,7 @@ pub mod cups_us {
     /// # Arguments
     /// * `value` - The volume in US cups to convert
     pub fn to_milliliters(value: f64) -> f64 {
-        value * 236.588
+        super::liters::to_milliliters(to_liters(value))
     }
 
     /// Converts US cups to US fluid ounces.
@@ -412,15 +555,34 @@ pub mod cups_us {
     pub fn to_quarts_us(value: f64) -> f64 {
         value / 4.0
     }
+
+    /// The US cup as a typed unit of volume.
+    pub const UNIT: Unit<Volume> = Unit::new("cup", "US cup", to_liters, super::liters::to_cups_us);
+
+    /// Creates a typed volume quantity expressed in cups us.
+    /// # Arguments
+    /// * `value` - The volume in US cups
+    pub fn new(value: f64) -> Quantity<Volume> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed volume quantity in cups us.
+    /// # Arguments
+    /// * `quantity` - The volume quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Volume>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// US pints conversion functions
 pub mod pints_us {
+    use crate::conversions::quantity::{Quantity, Unit, Volume};
+
     /// Converts US pints to liters.
     /// # Arguments
     /// * `value` - The volume in US pints to convert
     pub fn to_liters(value: f64) -> f64 {
-        value / 2.11338
+        super::gallons_us::to_liters(to_gallons_us(value))
     }
 
     /// Converts US pints to US gallons.
@@ -441,7 +603,7 @@ pub mod pints_us {
     /// # Arguments
     /// * `value` - The volume in US pints to convert
     pub fn to_milliliters(value: f64) -> f64 {
-        value * 473.176
+        super::liters::to_milliliters(to_liters(value))
     }
 
     /// Converts US pints to US fluid ounces.
@@ -471,15 +633,35 @@ pub mod pints_us {
     pub fn to_quarts_us(value: f64) -> f64 {
         value / 2.0
     }
+
+    /// The US pint as a typed unit of volume.
+    pub const UNIT: Unit<Volume> =
+        Unit::new("pt", "US pint", to_liters, super::liters::to_pints_us);
+
+    /// Creates a typed volume quantity expressed in pints us.
+    /// # Arguments
+    /// * `value` - The volume in US pints
+    pub fn new(value: f64) -> Quantity<Volume> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed volume quantity in pints us.
+    /// # Arguments
+    /// * `quantity` - The volume quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Volume>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// US quarts conversion functions
 pub mod quarts_us {
+    use crate::conversions::quantity::{Quantity, Unit, Volume};
+
     /// Converts US quarts to liters.
     /// # Arguments
     /// * `value` - The volume in US quarts to convert
     pub fn to_liters(value: f64) -> f64 {
-        value / 1.05669
+        super::gallons_us::to_liters(to_gallons_us(value))
     }
 
     /// Converts US quarts to US gallons.
@@ -500,7 +682,7 @@ pub mod quarts_us {
     /// # Arguments
     /// * `value` - The volume in US quarts to convert
     pub fn to_milliliters(value: f64) -> f64 {
-        value * 946.353
+        super::liters::to_milliliters(to_liters(value))
     }
 
     /// Converts US quarts to US fluid ounces.
@@ -530,6 +712,24 @@ pub mod quarts_us {
     pub fn to_pints_us(value: f64) -> f64 {
         value * 2.0
     }
+
+    /// The US quart as a typed unit of volume.
+    pub const UNIT: Unit<Volume> =
+        Unit::new("qt", "US quart", to_liters, super::liters::to_quarts_us);
+
+    /// Creates a typed volume quantity expressed in quarts us.
+    /// # Arguments
+    /// * `value` - The volume in US quarts
+    pub fn new(value: f64) -> Quantity<Volume> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed volume quantity in quarts us.
+    /// # Arguments
+    /// * `quantity` - The volume quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Volume>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 // Legacy function wrappers for backward compatibility
@@ -598,38 +798,76 @@ pub fn quarts_us_to_liters(quarts: f64) -> f64 {
 }
 
 /// Convert between any two volume units
-pub fn convert_volume(value: f64, from: &str, to: &str) -> Result<f64, String> {
-    // First convert to liters (base unit)
-    let liters = match from.to_lowercase().as_str() {
-        "l" | "liter" | "liters" | "litre" | "litres" => value,
-        "ml" | "milliliter" | "milliliters" | "millilitre" | "millilitres" => {
-            milliliters_to_liters(value)
-        }
-        "gal" | "gallon" | "gallons" | "gal_us" => gallons_us_to_liters(value),
-        "gal_uk" | "gallon_uk" | "gallons_uk" => gallons_uk_to_liters(value),
-        "fl_oz" | "fl_oz_us" | "fluid_ounce" | "fluid_ounces" => fluid_ounces_us_to_liters(value),
-        "fl_oz_uk" | "fluid_ounce_uk" | "fluid_ounces_uk" => fluid_ounces_uk_to_liters(value),
-        "cup" | "cups" | "cup_us" => cups_us_to_liters(value),
-        "pt" | "pint" | "pints" | "pt_us" => pints_us_to_liters(value),
-        "qt" | "quart" | "quarts" | "qt_us" => quarts_us_to_liters(value),
-        _ => return Err(format!("Unknown volume unit: {}", from)),
-    };
-
-    // Then convert from liters to target unit
-    let result = match to.to_lowercase().as_str() {
-        "l" | "liter" | "liters" | "litre" | "litres" => liters,
-        "ml" | "milliliter" | "milliliters" | "millilitre" | "millilitres" => {
-            liters_to_milliliters(liters)
-        }
-        "gal" | "gallon" | "gallons" | "gal_us" => liters_to_gallons_us(liters),
-        "gal_uk" | "gallon_uk" | "gallons_uk" => liters_to_gallons_uk(liters),
-        "fl_oz" | "fl_oz_us" | "fluid_ounce" | "fluid_ounces" => liters_to_fluid_ounces_us(liters),
-        "fl_oz_uk" | "fluid_ounce_uk" | "fluid_ounces_uk" => liters_to_fluid_ounces_uk(liters),
-        "cup" | "cups" | "cup_us" => liters_to_cups_us(liters),
-        "pt" | "pint" | "pints" | "pt_us" => liters_to_pints_us(liters),
-        "qt" | "quart" | "quarts" | "qt_us" => liters_to_quarts_us(liters),
-        _ => return Err(format!("Unknown volume unit: {}", to)),
-    };
-
-    Ok(result)
+pub fn convert_volume(value: f64, from: &str, to: &str) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from, to, Category::Volume)
+}
+
+/// Volume units known to the [`UnitRegistry`], with liters as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new(
+            "l",
+            "liter",
+            &["liters", "litre", "litres"],
+            Category::Volume,
+            1.0,
+        ),
+        UnitDef::new(
+            "ml",
+            "milliliter",
+            &["milliliters", "millilitre", "millilitres"],
+            Category::Volume,
+            milliliters::to_liters(1.0),
+        ),
+        UnitDef::new(
+            "gal",
+            "US gallon",
+            &["gallon", "gallons", "gal_us"],
+            Category::Volume,
+            gallons_us::to_liters(1.0),
+        ),
+        UnitDef::new(
+            "gal_uk",
+            "imperial gallon",
+            &["gallon_uk", "gallons_uk"],
+            Category::Volume,
+            gallons_uk::to_liters(1.0),
+        ),
+        UnitDef::new(
+            "fl_oz",
+            "US fluid ounce",
+            &["fl_oz_us", "fluid_ounce", "fluid_ounces"],
+            Category::Volume,
+            fluid_ounces_us::to_liters(1.0),
+        ),
+        UnitDef::new(
+            "fl_oz_uk",
+            "imperial fluid ounce",
+            &["fluid_ounce_uk", "fluid_ounces_uk"],
+            Category::Volume,
+            fluid_ounces_uk::to_liters(1.0),
+        )
+        .with_exact_factor("4.54609/160"),
+        UnitDef::new(
+            "cup",
+            "US cup",
+            &["cups", "cup_us"],
+            Category::Volume,
+            cups_us::to_liters(1.0),
+        ),
+        UnitDef::new(
+            "pt",
+            "US pint",
+            &["pint", "pints", "pt_us"],
+            Category::Volume,
+            pints_us::to_liters(1.0),
+        ),
+        UnitDef::new(
+            "qt",
+            "US quart",
+            &["quart", "quarts", "qt_us"],
+            Category::Volume,
+            quarts_us::to_liters(1.0),
+        ),
+    ]
 }
@@ -1,13 +1,29 @@
 //! Weight/Mass conversion functions organized by unit type
 //! All conversions use kilograms as the base unit for accuracy and consistency
 
+use super::category::Category;
+use super::error::ConversionError;
+use super::registry::{UnitDef, UnitRegistry};
+
+/// Kilograms in one avoirdupois pound, exact by the 1959 international yard and
+/// pound agreement
+pub const KILOGRAMS_PER_POUND: f64 = 0.453_592_37;
+
+/// Avoirdupois ounces in one pound
+pub const OUNCES_PER_POUND: f64 = 16.0;
+
+/// Pounds in one stone
+pub const POUNDS_PER_STONE: f64 = 14.0;
+
 /// Kilograms conversion functions
 pub mod kilograms {
+    use crate::conversions::quantity::{Mass, Quantity, Unit};
+
     /// Converts kilograms to pounds.
     /// # Arguments
     /// * `value` - The weight in kilograms to convert
     pub fn to_pounds(value: f64) -> f64 {
-        value * 2.20462
+        value / super::KILOGRAMS_PER_POUND
     }
 
     /// Converts kilograms to grams.
@@ -21,7 +37,7 @@ pub mod kilograms {
     /// # Arguments
     /// * `value` - The weight in kilograms to convert
     pub fn to_ounces(value: f64) -> f64 {
-        value * 35.274
+        to_pounds(value) * super::OUNCES_PER_POUND
     }
 
     /// Converts kilograms to tons (metric).
@@ -35,17 +51,36 @@ pub mod kilograms {
     /// # Arguments
     /// * `value` - The weight in kilograms to convert
     pub fn to_stones(value: f64) -> f64 {
-        value / 6.35029
+        to_pounds(value) / super::POUNDS_PER_STONE
+    }
+
+    /// The kilogram as a typed unit of weight.
+    pub const UNIT: Unit<Mass> = Unit::new("kg", "kilogram", |value| value, |value| value);
+
+    /// Creates a typed weight quantity expressed in kilograms.
+    /// # Arguments
+    /// * `value` - The weight in kilograms
+    pub fn new(value: f64) -> Quantity<Mass> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed weight quantity in kilograms.
+    /// # Arguments
+    /// * `quantity` - The weight quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Mass>) -> f64 {
+        quantity.value_in(UNIT)
     }
 }
 
 /// Pounds conversion functions
 pub mod pounds {
+    use crate::conversions::quantity::{Mass, Quantity, Unit};
+
     /// Converts pounds to kilograms.
     /// # Arguments
     /// * `value` - The weight in pounds to convert
     pub fn to_kilograms(value: f64) -> f64 {
-        value / 2.20462
+        value * super::KILOGRAMS_PER_POUND
     }
 
     /// Converts pounds to grams.
@@ -59,7 +94,7 @@ pub mod pounds {
     /// # Arguments
     /// * `value` - The weight in pounds to convert
     pub fn to_ounces(value: f64) -> f64 {
-        value * 16.0
+        value * super::OUNCES_PER_POUND
     }
 
     /// Converts pounds to tons (metric).
@@ -73,12 +108,32 @@ pub mod pounds {
     /// # Arguments
     /// * `value` - The weight in pounds to convert
     pub fn to_stones(value: f64) -> f64 {
-        value / 14.0
+        value / super::POUNDS_PER_STONE
+    }
+
+    /// The pound as a typed unit of weight.
+    pub const UNIT: Unit<Mass> =
+        Unit::new("lb", "pound", to_kilograms, super::kilograms::to_pounds);
+
+    /// Creates a typed weight quantity expressed in pounds.
+    /// # Arguments
+    /// * `value` - The weight in pounds
+    pub fn new(value: f64) -> Quantity<Mass> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed weight quantity in pounds.
+    /// # Arguments
+    /// * `quantity` - The weight quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Mass>) -> f64 {
+        quantity.value_in(UNIT)
     }
 }
 
 /// Grams conversion functions
 pub mod grams {
+    use crate::conversions::quantity::{Mass, Quantity, Unit};
+
     /// Converts grams to kilograms.
     /// # Arguments
     /// * `value` - The weight in grams to convert
@@ -97,7 +152,7 @@ pub mod grams {
     /// # Arguments
     /// * `value` - The weight in grams to convert
     pub fn to_ounces(value: f64) -> f64 {
-        value / 28.3495
+        super::kilograms::to_ounces(to_kilograms(value))
     }
 
     /// Converts grams to tons (metric).
@@ -113,29 +168,48 @@ pub mod grams {
     pub fn to_stones(value: f64) -> f64 {
         super::kilograms::to_stones(to_kilograms(value))
     }
+
+    /// The gram as a typed unit of weight.
+    pub const UNIT: Unit<Mass> = Unit::new("g", "gram", to_kilograms, super::kilograms::to_grams);
+
+    /// Creates a typed weight quantity expressed in grams.
+    /// # Arguments
+    /// * `value` - The weight in grams
+    pub fn new(value: f64) -> Quantity<Mass> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed weight quantity in grams.
+    /// # Arguments
+    /// * `quantity` - The weight quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Mass>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Ounces conversion functions
 pub mod ounces {
+    use crate::conversions::quantity::{Mass, Quantity, Unit};
+
     /// Converts ounces to kilograms.
     /// # Arguments
     /// * `value` - The weight in ounces to convert
     pub fn to_kilograms(value: f64) -> f64 {
-        value / 35.274
+        super::pounds::to_kilograms(to_pounds(value))
     }
 
     /// Converts ounces to pounds.
     /// # Arguments
     /// * `value` - The weight in ounces to convert
     pub fn to_pounds(value: f64) -> f64 {
-        value / 16.0
+        value / super::OUNCES_PER_POUND
     }
 
     /// Converts ounces to grams.
     /// # Arguments
     /// * `value` - The weight in ounces to convert
     pub fn to_grams(value: f64) -> f64 {
-        value * 28.3495
+        super::kilograms::to_grams(to_kilograms(value))
     }
 
     /// Converts ounces to tons (metric).
@@ -151,10 +225,30 @@ pub mod ounces {
     pub fn to_stones(value: f64) -> f64 {
         super::kilograms::to_stones(to_kilograms(value))
     }
+
+    /// The ounce as a typed unit of weight.
+    pub const UNIT: Unit<Mass> =
+        Unit::new("oz", "ounce", to_kilograms, super::kilograms::to_ounces);
+
+    /// Creates a typed weight quantity expressed in ounces.
+    /// # Arguments
+    /// * `value` - The weight in ounces
+    pub fn new(value: f64) -> Quantity<Mass> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed weight quantity in ounces.
+    /// # Arguments
+    /// * `quantity` - The weight quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Mass>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Tons (metric) conversion functions
 pub mod tons {
+    use crate::conversions::quantity::{Mass, Quantity, Unit};
+
     /// Converts tons to kilograms.
     /// # Arguments
     /// * `value` - The weight in tons to convert
@@ -189,22 +283,42 @@ pub mod tons {
     pub fn to_stones(value: f64) -> f64 {
         super::kilograms::to_stones(to_kilograms(value))
     }
+
+    /// The metric ton as a typed unit of weight.
+    pub const UNIT: Unit<Mass> =
+        Unit::new("t", "metric ton", to_kilograms, super::kilograms::to_tons);
+
+    /// Creates a typed weight quantity expressed in tons.
+    /// # Arguments
+    /// * `value` - The weight in tons
+    pub fn new(value: f64) -> Quantity<Mass> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed weight quantity in tons.
+    /// # Arguments
+    /// * `quantity` - The weight quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Mass>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 /// Stones conversion functions
 pub mod stones {
+    use crate::conversions::quantity::{Mass, Quantity, Unit};
+
     /// Converts stones to kilograms.
     /// # Arguments
     /// * `value` - The weight in stones to convert
     pub fn to_kilograms(value: f64) -> f64 {
-        value * 6.35029
+        super::pounds::to_kilograms(to_pounds(value))
     }
 
     /// Converts stones to pounds.
     /// # Arguments
     /// * `value` - The weight in stones to convert
     pub fn to_pounds(value: f64) -> f64 {
-        value * 14.0
+        value * super::POUNDS_PER_STONE
     }
 
     /// Converts stones to grams.
@@ -227,6 +341,24 @@ pub mod stones {
     pub fn to_tons(value: f64) -> f64 {
         super::kilograms::to_tons(to_kilograms(value))
     }
+
+    /// The stone as a typed unit of weight.
+    pub const UNIT: Unit<Mass> =
+        Unit::new("st", "stone", to_kilograms, super::kilograms::to_stones);
+
+    /// Creates a typed weight quantity expressed in stones.
+    /// # Arguments
+    /// * `value` - The weight in stones
+    pub fn new(value: f64) -> Quantity<Mass> {
+        Quantity::new(value, UNIT)
+    }
+
+    /// Reads a typed weight quantity in stones.
+    /// # Arguments
+    /// * `quantity` - The weight quantity to read, in any unit
+    pub fn value_of(quantity: Quantity<Mass>) -> f64 {
+        quantity.value_in(UNIT)
+    }
 }
 
 // Legacy function wrappers for backward compatibility
@@ -234,7 +366,7 @@ pub mod stones {
 ///
 /// **Note:** Consider using `kilograms::to_pounds()` for better organization.
 ///
-/// Uses the conversion factor: 1 kilogram = 2.20462 pounds
+/// Uses the exact definition: 1 pound = 0.45359237 kilograms
 ///
 /// # Arguments
 ///
@@ -260,7 +392,7 @@ pub fn kilograms_to_pounds(kg: f64) -> f64 {
 ///
 /// **Note:** Consider using `pounds::to_kilograms()` for better organization.
 ///
-/// Uses the conversion factor: 1 pound = 0.453592 kilograms
+/// Uses the exact definition: 1 pound = 0.45359237 kilograms
 ///
 /// # Arguments
 ///
@@ -337,7 +469,8 @@ pub fn stones_to_kilograms(stones: f64) -> f64 {
 ///
 /// # Returns
 /// * `Ok(f64)` - The converted value if both units are recognized
-/// * `Err(String)` - An error message if either unit is not supported
+/// * `Err(ConversionError)` - An [`ConversionError::UnknownUnit`] if either unit is not supported,
+///   or [`ConversionError::NonFiniteInput`] if `value` is NaN or infinite
 ///
 /// # Examples
 ///
@@ -366,33 +499,55 @@ pub fn stones_to_kilograms(stones: f64) -> f64 {
 ///
 /// # Conversion Accuracy
 ///
-/// All conversions use standard international conversion factors:
-/// - 1 kilogram = 2.20462 pounds
+/// All conversions derive from the exact international definitions:
+/// - 1 pound = 0.45359237 kilograms
+/// - 1 pound = 16 ounces
+/// - 1 stone = 14 pounds
 /// - 1 kilogram = 1000 grams
-/// - 1 stone = 6.35029 kilograms
-/// - etc.
-pub fn convert_weight(value: f64, from: &str, to: &str) -> Result<f64, String> {
-    // First convert to kilograms (base unit)
-    let kilograms = match from.to_lowercase().as_str() {
-        "kg" | "kilogram" | "kilograms" => value,
-        "g" | "gram" | "grams" => grams_to_kilograms(value),
-        "lb" | "lbs" | "pound" | "pounds" => pounds_to_kilograms(value),
-        "oz" | "ounce" | "ounces" => ounces_to_kilograms(value),
-        "t" | "ton" | "tons" => tons_to_kilograms(value),
-        "st" | "stone" | "stones" => stones_to_kilograms(value),
-        _ => return Err(format!("Unknown weight unit: {}", from)),
-    };
-
-    // Then convert from kilograms to target unit
-    let result = match to.to_lowercase().as_str() {
-        "kg" | "kilogram" | "kilograms" => kilograms,
-        "g" | "gram" | "grams" => kilograms_to_grams(kilograms),
-        "lb" | "lbs" | "pound" | "pounds" => kilograms_to_pounds(kilograms),
-        "oz" | "ounce" | "ounces" => kilograms_to_ounces(kilograms),
-        "t" | "ton" | "tons" => kilograms_to_tons(kilograms),
-        "st" | "stone" | "stones" => kilograms_to_stones(kilograms),
-        _ => return Err(format!("Unknown weight unit: {}", to)),
-    };
-
-    Ok(result)
+pub fn convert_weight(value: f64, from: &str, to: &str) -> Result<f64, ConversionError> {
+    UnitRegistry::global().convert(value, from, to, Category::Mass)
+}
+
+/// Weight/mass units known to the [`UnitRegistry`], with kilograms as the base unit.
+pub(crate) fn units() -> Vec<UnitDef> {
+    vec![
+        UnitDef::new("kg", "kilogram", &["kilograms"], Category::Mass, 1.0),
+        UnitDef::new(
+            "g",
+            "gram",
+            &["grams"],
+            Category::Mass,
+            grams::to_kilograms(1.0),
+        )
+        .with_prefixes(),
+        UnitDef::new(
+            "lb",
+            "pound",
+            &["lbs", "pounds"],
+            Category::Mass,
+            pounds::to_kilograms(1.0),
+        ),
+        UnitDef::new(
+            "oz",
+            "ounce",
+            &["ounces"],
+            Category::Mass,
+            ounces::to_kilograms(1.0),
+        ),
+        UnitDef::new(
+            "t",
+            "metric ton",
+            &["ton", "tons"],
+            Category::Mass,
+            tons::to_kilograms(1.0),
+        ),
+        UnitDef::new(
+            "st",
+            "stone",
+            &["stones"],
+            Category::Mass,
+            stones::to_kilograms(1.0),
+        )
+        .with_exact_factor("14*0.45359237"),
+    ]
 }
@@ -5,14 +5,22 @@
 //! different units of measurement. It supports comprehensive SI (International
 //! System of Units) base and derived units including: length, weight/mass,
 //! temperature, volume, time, electric current, amount of substance, luminous
-//! intensity, and area with high precision and extensive unit support.
+//! intensity, area, speed, pressure, energy, power, force, torque, frequency,
+//! plane and solid angle, data, data rate, density, volumetric and mass flow rate,
+//! voltage, resistance, conductance, charge, capacitance, inductance, luminous
+//! flux, illuminance, luminance and temperature intervals with high precision
+//! and extensive unit support.
 //!
 //! ## Features
 //!
 //! ### SI Base Units
-//! - **Length conversions**: meters, kilometers, feet, inches, yards, miles, etc.
+//! - **Length conversions**: meters, kilometers, feet, inches, yards, miles,
+//!   micrometers, nanometers, ångströms, astronomical units, light-years,
+//!   parsecs, Earth and solar radii, nautical miles, fathoms, chains, rods,
+//!   furlongs, the US survey foot and mile, etc.
 //! - **Mass conversions**: kilograms, grams, pounds, ounces, tons, stones
-//! - **Temperature conversions**: Celsius, Fahrenheit, Kelvin
+//! - **Temperature conversions**: Celsius, Fahrenheit, Kelvin, Rankine, Réaumur,
+//!   Delisle, Newton, Rømer, plus temperature differences (ΔC, ΔF, ΔK, ΔR)
 //! - **Time conversions**: seconds, minutes, hours, days, weeks, years, etc.
 //! - **Electric current conversions**: amperes and SI prefixes (mA, µA, kA, etc.)
 //! - **Amount of substance conversions**: moles and SI prefixes (mmol, µmol, etc.)
@@ -21,12 +29,45 @@
 //! ### SI Derived Units
 //! - **Volume conversions**: liters, gallons (US/UK), cups, pints, quarts, etc.
 //! - **Area conversions**: square meters, acres, hectares, square feet, etc.
+//! - **Speed conversions**: m/s, km/h, mph, knots, ft/s and Mach
+//! - **Pressure conversions**: Pa, kPa, MPa, bar, mbar, atm, psi (absolute and
+//!   gauge), torr, mmHg, inHg, inH₂O
+//! - **Energy conversions**: J, kJ, MJ, thermochemical and international table
+//!   calories, Wh, kWh, MWh, BTU, therms, eV, erg, ft·lbf
+//! - **Power conversions**: W, kW, MW, mechanical/metric/electrical horsepower,
+//!   BTU/h, tons of refrigeration, erg/s and the logarithmic dBm/dBW
+//! - **Force conversions**: N, kN, dyn, lbf, kgf, kip, poundal
+//! - **Torque conversions**: N·m, lbf·ft, lbf·in, kgf·m, dyn·cm (kept apart from
+//!   energy, although both are N·m in SI base units)
+//! - **Frequency conversions**: Hz through THz, rpm, rad/s, cycles per minute and
+//!   per hour, plus period ↔ frequency helpers
+//! - **Angle conversions**: degrees, radians, gradians, turns, arcminutes,
+//!   arcseconds, milliradians and NATO mils, with DMS parsing/formatting and
+//!   normalisation; steradians, square degrees and spats for solid angles
+//! - **Data conversions**: bits and bytes with decimal (kB through YB) and
+//!   binary (KiB through YiB) prefixes, data rates (bit/s, Mbit/s, MB/s,
+//!   GiB/s) and a best-unit formatter
+//! - **Density conversions**: kg/m³, g/cm³, lb/ft³, lb/in³, lb/gal (US/UK),
+//!   oz/in³, specific gravity and the reciprocal °API and °Baumé scales
+//! - **Flow rate conversions**: L/s, L/min, m³/s, m³/h, gal/min (US/UK), CFM and
+//!   bbl/day, plus kg/s, kg/h, g/s, t/h, lb/s and lb/h mass flow rates
+//! - **Electrical conversions**: voltage (V, mV, kV, abV, statV), resistance (Ω,
+//!   kΩ, MΩ), conductance (S, mho), charge (C, mAh, Ah, e), capacitance (F, μF,
+//!   nF, pF) and inductance (H, mH, μH)
+//! - **Photometry conversions**: luminous flux (lm), illuminance (lx, fc, ph) and
+//!   luminance (cd/m², fL, La, sb), plus candela ↔ lumen over a beam solid angle
+//!   and lux ↔ candela at a distance
 //!
 //! ### Additional Features
-//! - **Case-insensitive unit names** with support for singular/plural forms
+//! - **Case-sensitive SI symbols** (`mA` vs `MA`) with case-insensitive unit
+//!   names and support for singular/plural forms
+//! - **Every SI prefix** from quecto to quetta on coherent units (`nm`, `Mg`, `GA`, ...)
+//! - **Exact mode** (`exact` feature) with arbitrary-precision rational arithmetic
 //! - **High precision** calculations using standard conversion factors
 //! - **Comprehensive error handling** for invalid units
 //! - **Modular API** organized by unit type for better discoverability
+//! - **Typed quantities** (`Quantity<Length>`, `Quantity<Mass>`, ...) that cannot
+//!   be mixed across dimensions, and absolute temperatures that cannot be added
 //!
 //! ## Usage Patterns
 //!
@@ -43,6 +84,7 @@
 //! let inches = length::feet::to_inches(5.0);          // 60.0 inches  
 //! let cm = length::inches::to_centimeters(12.0);      // 30.48 cm
 //! let km = length::miles::to_kilometers(5.0);         // 8.0467 km
+//! let ly = length::parsecs::to_light_years(1.0);      // 3.2616 ly
 //!
 //! // Weight conversions
 //! let pounds = weight::kilograms::to_pounds(5.0);     // 11.0231 pounds
@@ -51,6 +93,7 @@
 //! // Temperature conversions
 //! let fahrenheit = temperature::celsius::to_fahrenheit(25.0);  // 77.0°F
 //! let kelvin = temperature::celsius::to_kelvin(0.0);           // 273.15K
+//! let rankine = temperature::kelvin::to_rankine(300.0);        // 540°R
 //!
 //! // Volume conversions
 //! let gallons = volume::liters::to_gallons_us(10.0);          // 2.64 gallons
@@ -81,17 +124,94 @@
 //! let acres = area::hectares::to_acres(1.0);                  // 2.471 acres
 //! let sq_feet = area::square_meters::to_square_feet(1.0);     // 10.764 ft²
 //!
+//! // Speed conversions
+//! let kmh = speed::meters_per_second::to_kilometers_per_hour(10.0); // 36.0 km/h
+//! let mph = speed::knots::to_miles_per_hour(100.0);           // 115.08 mph
+//!
+//! // Pressure conversions
+//! let kpa = pressure::psi::to_kilopascals(32.0);             // 220.63 kPa
+//! let psia = pressure::psig::to_psi(32.0);                    // 46.70 psi absolute
+//!
+//! // Energy conversions (the two calories are kept apart)
+//! let kj = energy::thermochemical_kilocalories::to_kilojoules(2000.0); // 8368 kJ
+//! let kwh = energy::therms::to_kilowatt_hours(1.0);          // 29.31 kWh
+//!
+//! // Power conversions (dBm and dBW are logarithmic)
+//! let dbm = power::watts::to_dbm(2.0);                        // 33.01 dBm
+//! let kw = power::tons_of_refrigeration::to_kilowatts(5.0);   // 17.58 kW
+//!
+//! // Force conversions
+//! let newtons = force::pounds_force::to_newtons(10.0);        // 44.48 N
+//! let kgf = force::kilonewtons::to_kilograms_force(1.0);      // 101.97 kgf
+//!
+//! // Torque conversions
+//! let nm = torque::pound_force_feet::to_newton_meters(100.0); // 135.58 N·m
+//!
+//! // Frequency conversions (one revolution is 2π radians)
+//! let hz = frequency::revolutions_per_minute::to_hertz(3000.0);   // 50 Hz
+//! let rad_s = frequency::hertz::to_radians_per_second(1.0);       // 6.2832 rad/s
+//!
+//! // Angle conversions
+//! let rad = angle::degrees::to_radians(180.0);                   // 3.1416 rad
+//! let mils = angle::degrees::to_nato_mils(90.0);                 // 1600 mil
+//!
+//! // Data conversions (decimal and binary prefixes are kept apart)
+//! let mib = data::gibibytes::to_mebibytes(1.0);                  // 1024 MiB
+//! let mb_s = data_rate::megabits_per_second::to_megabytes_per_second(100.0); // 12.5 MB/s
+//!
+//! // Density conversions (lb/gal uses the volume module's US gallon)
+//! let ppg = density::grams_per_cubic_centimeter::to_pounds_per_gallon_us(1.0); // 8.3454 lb/gal
+//! let sg = density::degrees_api::to_specific_gravity(39.6);      // 0.8270 SG
+//!
+//! // Flow rate conversions (composed from the volume and time modules)
+//! let lpm = flow_rate::gallons_us_per_minute::to_liters_per_minute(10.0); // 37.85 L/min
+//! let kg_s = mass_flow_rate::pounds_per_hour::to_kilograms_per_second(1000.0); // 0.126 kg/s
+//!
+//! // Electrical conversions (battery capacities compose current and time)
+//! let coulombs = charge::milliampere_hours::to_coulombs(3000.0); // 10800 C
+//! let nanofarads = capacitance::microfarads::to_nanofarads(0.1); // 100 nF
+//!
+//! // Photometry conversions (composed from the area modules)
+//! let lux = illuminance::foot_candles::to_lux(50.0); // 538.2 lx
+//! let nits = luminance::foot_lamberts::to_nits(14.0); // 47.97 cd/m²
+//!
 //! // Easy to chain conversions
 //! let result = length::meters::to_feet(length::kilometers::to_meters(1.0)); // 1 km to feet
 //! ```
 //!
-//! ### 2. General Conversion Functions (String-based)
+//! ### 2. Typed Quantities (Compile-time dimension checking)
+//!
+//! Each per-unit module also provides a `new` constructor, a `value_of` accessor
+//! and a `UNIT` constant built on [`Quantity`]. A quantity carries its unit and
+//! can only be converted within its own dimension:
+//!
+//! ```rust
+//! use conversions_rs::conversions::*;
+//!
+//! let height = length::feet::new(6.0);
+//! let meters = length::meters::value_of(height);              // 1.8288 m
+//! let inches = height.to(length::inches::UNIT);               // 72 in
+//! let total = height + length::centimeters::new(10.0);        // 6.33 ft
+//!
+//! // Two temperatures subtract into an interval, which shifts a temperature
+//! let rise = temperature::celsius::new(25.0) - temperature::celsius::new(15.0); // 10 ΔC
+//! let warmed = temperature::fahrenheit::new(68.0) + rise;                       // 86 °F
+//!
+//! // weight::pounds::value_of(height) would not compile, and neither would
+//! // adding two temperatures
+//! ```
+//!
+//! ### 3. General Conversion Functions (String-based)
 //!
 //! Best for user input and flexible conversions:
 //!
 //! ```rust
 //! use conversions_rs::*;
 //!
+//! // Any two units - the category is inferred from the units
+//! let meters = convert(3.0, "ft", "m").unwrap();
+//! println!("3 feet = {:.4} meters", meters);
+//!
 //! // Length conversions
 //! let meters = convert_length(100.0, "ft", "m").unwrap();
 //! println!("100 feet = {:.2} meters", meters);
@@ -104,6 +224,10 @@
 //! let celsius = convert_temperature(32.0, "F", "C").unwrap();
 //! println!("32°F = {:.1}°C", celsius);
 //!
+//! // Temperature differences ignore the zero point: a 10°C rise is an 18°F rise
+//! let rise = convert_temperature_interval(10.0, "ΔC", "ΔF").unwrap();
+//! println!("10 ΔC = {:.1} ΔF", rise);
+//!
 //! // Volume conversions
 //! let liters = convert_volume(1.0, "gal", "l").unwrap();
 //! println!("1 gallon = {:.2} liters", liters);
@@ -127,9 +251,68 @@
 //! // Area conversions
 //! let hectares = convert_area(10000.0, "m²", "ha").unwrap();
 //! println!("10000 m² = {:.1} ha", hectares);
+//!
+//! // Speed conversions
+//! let mph = convert_speed(100.0, "km/h", "mph").unwrap();
+//! println!("100 km/h = {:.2} mph", mph);
+//!
+//! // Pressure conversions (psig is relative to one standard atmosphere)
+//! let psia = convert_pressure(32.0, "psig", "psia").unwrap();
+//! println!("32 psig = {:.2} psia", psia);
+//!
+//! // Energy conversions
+//! let megajoules = convert_energy(1.0, "kWh", "MJ").unwrap();
+//! println!("1 kWh = {:.1} MJ", megajoules);
+//!
+//! // Power conversions
+//! let watts = convert_power(20.0, "dBm", "W").unwrap();
+//! println!("20 dBm = {:.1} W", watts);
+//!
+//! // Force conversions
+//! let newtons = convert_force(10.0, "lbf", "N").unwrap();
+//! println!("10 lbf = {:.2} N", newtons);
+//!
+//! // Torque conversions (never interchangeable with energy)
+//! let newton_meters = convert_torque(100.0, "lbf·ft", "N·m").unwrap();
+//! println!("100 lbf·ft = {:.2} N·m", newton_meters);
+//!
+//! // Frequency conversions, and a 2.5 ms period as a frequency
+//! let hertz = convert_frequency(3000.0, "rpm", "Hz").unwrap();
+//! let tone = period_to_frequency(2.5, "ms", "Hz").unwrap();
+//! println!("3000 rpm = {:.1} Hz, 2.5 ms period = {:.0} Hz", hertz, tone);
+//!
+//! // Angle conversions, DMS notation and normalisation
+//! let radians = convert_angle(90.0, "deg", "rad").unwrap();
+//! let degrees = parse_dms("12°34'56\"").unwrap();
+//! let heading = normalize_angle(-90.0, "deg", AngleRange::Positive).unwrap();
+//! println!("{:.4} rad, {} = {:.4}°, heading {}°", radians, format_dms(degrees, 0), degrees, heading);
+//!
+//! // Data conversions and best-unit formatting
+//! let megabytes = convert_data(1.0, "GiB", "MB").unwrap();
+//! let size = format_data(1_536_000.0, "B", PrefixConvention::Binary, 2).unwrap();
+//! println!("1 GiB = {:.2} MB, 1536000 B = {}", megabytes, size);
+//!
+//! // Density conversions, including the hydrometer scales
+//! let specific_gravity = convert_density(39.6, "°API", "SG").unwrap();
+//! println!("39.6 °API = {:.4} SG", specific_gravity);
+//!
+//! // Flow rate conversions
+//! let liters_per_second = convert_flow_rate(100.0, "gpm", "L/s").unwrap();
+//! let kilograms_per_second = convert_mass_flow_rate(1000.0, "lb/h", "kg/s").unwrap();
+//! println!("100 gpm = {:.3} L/s, 1000 lb/h = {:.4} kg/s", liters_per_second, kilograms_per_second);
+//!
+//! // Electrical conversions
+//! let ohms = convert_resistance(4.7, "kΩ", "ohm").unwrap();
+//! let coulombs = convert_charge(3000.0, "mAh", "C").unwrap();
+//! println!("4.7 kΩ = {} Ω, 3000 mAh = {} C", ohms, coulombs);
+//!
+//! // Photometry conversions, and the illuminance 2 m below an 800 cd source
+//! let lux = convert_illuminance(50.0, "fc", "lx").unwrap();
+//! let below = intensity_to_illuminance(800.0, "cd", 2.0, "m", "lx").unwrap();
+//! println!("50 fc = {:.1} lx, 800 cd at 2 m = {:.0} lx", lux, below);
 //! ```
 //!
-//! ### 3. Legacy Functions (Backward compatible)
+//! ### 4. Legacy Functions (Backward compatible)
 //!
 //! ```rust
 //! use conversions_rs::*;
@@ -141,15 +324,19 @@
 //!
 //! ## Error Handling
 //!
-//! The general conversion functions return `Result<f64, String>` for proper
-//! error handling:
+//! The general conversion functions return `Result<f64, ConversionError>`.
+//! [`ConversionError`] implements `std::error::Error`, and its variants let you
+//! react to the kind of failure without inspecting the message:
 //!
 //! ```rust
-//! use conversions_rs::convert_length;
+//! use conversions_rs::{convert_length, ConversionError};
 //!
 //! match convert_length(100.0, "ft", "invalid_unit") {
 //!     Ok(result) => println!("Converted: {}", result),
-//!     Err(error) => eprintln!("Conversion error: {}", error),
+//!     Err(ConversionError::UnknownUnit { input, category }) => {
+//!         eprintln!("'{}' is not a {} unit", input, category)
+//!     }
+//!     Err(error) => eprintln!("Conversion error [{}]: {}", error.kind(), error),
 //! }
 //! ```
 //!
@@ -158,10 +345,15 @@
 //! This library can also be used as a command-line tool with support for all SI units:
 //!
 //! ```bash
+//! # Any two units, with the category inferred from the units
+//! conversions_rs convert 3 ft m               # Category-agnostic conversions
+//!
 //! # SI Base Units
 //! conversions_rs length 100 ft m              # Length conversions
 //! conversions_rs weight 5 kg lb               # Mass conversions  
 //! conversions_rs temperature 32 F C           # Temperature conversions
+//! conversions_rs temperature --delta 10 C F   # Temperature differences
+//! conversions_rs temperature --lenient -500 C K # Allow readings below 0 K
 //! conversions_rs time 3600 s min              # Time conversions
 //! conversions_rs current 1500 mA A            # Electric current conversions
 //! conversions_rs amount 0.5 mol mmol          # Amount of substance conversions
@@ -170,6 +362,19 @@
 //! # SI Derived Units
 //! conversions_rs volume 5 gal l               # Volume conversions
 //! conversions_rs area 10000 "m²" ha           # Area conversions
+//! conversions_rs speed 100 km/h mph           # Speed conversions
+//! conversions_rs pressure 32 psig kPa        # Pressure conversions
+//! conversions_rs energy 2000 kcal_th kJ       # Energy conversions
+//! conversions_rs power 20 dBm W               # Power conversions
+//! conversions_rs force 10 lbf N               # Force conversions
+//! conversions_rs torque 100 "lbf·ft" "N·m"    # Torque conversions
+//! conversions_rs frequency 3000 rpm Hz        # Frequency conversions
+//! conversions_rs angle 1 rad deg --dms         # Angle conversions
+//! conversions_rs data 1536000 B --best binary # Data conversions
+//! conversions_rs density 39.6 °API SG          # Density conversions
+//! conversions_rs flow-rate 100 gpm L/s         # Flow rate conversions
+//! conversions_rs charge 3000 mAh C             # Electric charge conversions
+//! conversions_rs illuminance 50 fc lx          # Illuminance conversions
 //!
 //! # Interactive mode with full menu
 //! conversions_rs
@@ -371,6 +576,1318 @@ mod tests {
         assert_eq!(convert_area(100.0, "m²", "m²").unwrap(), 100.0);
     }
 
+    #[test]
+    fn test_typed_quantities() {
+        // Converting a quantity matches the modular API
+        let distance = length::kilometers::new(1.0);
+        assert_eq!(
+            distance.to(length::feet::UNIT).value(),
+            length::kilometers::to_feet(1.0)
+        );
+        assert_eq!(length::meters::value_of(distance), 1000.0);
+
+        // Quantities carry their unit
+        let mass = weight::pounds::new(2.0);
+        assert_eq!(mass.unit(), weight::pounds::UNIT);
+        assert_eq!(mass.value(), 2.0);
+        assert_eq!(mass.to_base().unit(), Mass::BASE);
+
+        // Arithmetic across units of the same dimension keeps the left-hand unit
+        let total = length::meters::new(1.0) + length::centimeters::new(50.0);
+        assert_eq!(total.unit(), length::meters::UNIT);
+        assert_eq!(total.value(), 1.5);
+        assert!(length::feet::new(3.0) < length::meters::new(1.0));
+
+        // Gauge pressures add and scale as gauge readings
+        assert_eq!(
+            pressure::psig::new(10.0) + pressure::psig::new(10.0),
+            pressure::psig::new(20.0)
+        );
+        assert_eq!((pressure::psig::new(10.0) * 2.0).value(), 20.0);
+        assert_eq!((pressure::psig::new(0.0) * 0.0).value(), 0.0);
+        assert_eq!(
+            (pressure::psig::new(30.0) - pressure::psig::new(10.0)).value(),
+            20.0
+        );
+        assert_eq!((-pressure::psig::new(10.0)).value(), -10.0);
+
+        // Logarithmic units add and scale the powers they stand for
+        let doubled = power::dbm::new(10.0) + power::dbm::new(10.0);
+        assert_eq!(doubled.unit(), power::dbm::UNIT);
+        assert!((doubled.value() - 13.0103).abs() < 1e-4);
+        assert!((power::watts::value_of(doubled) - 0.02).abs() < 1e-12);
+        assert!(((power::dbm::new(10.0) * 2.0).value() - 13.0103).abs() < 1e-4);
+        assert!(((power::dbm::new(10.0) / 10.0).value() - 0.0).abs() < 1e-12);
+
+        // ...but have no difference or negation
+        let difference = power::dbm::new(10.0) - power::dbm::new(10.0);
+        assert_eq!(difference.unwrap_err().kind(), "not_subtractable");
+        assert_eq!(
+            (-power::dbm::new(10.0)).unwrap_err().to_string(),
+            "Quantities in dBm cannot be subtracted or negated"
+        );
+        let remaining = (power::watts::new(0.02) - power::dbm::new(10.0)).unwrap();
+        assert!((remaining.value() - 0.01).abs() < 1e-12);
+        assert_eq!((-power::watts::new(1.0)).unwrap().value(), -1.0);
+
+        // So do reciprocal hydrometer scales, through the densities they stand for
+        let api = density::degrees_api::new(10.0) + density::degrees_api::new(10.0);
+        assert_eq!(api.unit(), density::degrees_api::UNIT);
+        assert!((density::specific_gravity::value_of(api) - 2.0).abs() < 1e-12);
+        assert!((api.value() - -60.75).abs() < 1e-9);
+        assert_eq!(
+            (api - density::degrees_api::new(10.0)).unwrap_err(),
+            ConversionError::NotSubtractable {
+                unit: "°API".to_string()
+            }
+        );
+
+        // Temperatures go through kelvin
+        let boiling = temperature::celsius::new(100.0);
+        assert_eq!(temperature::fahrenheit::value_of(boiling), 212.0);
+        assert_eq!(format!("{:.2}", boiling), "100.00 °C");
+    }
+
+    #[test]
+    fn test_conversion_errors() {
+        // Unknown units report the offending input and the category
+        assert_eq!(
+            convert_area(1.0, "m²", "Furlongs²"),
+            Err(ConversionError::UnknownUnit {
+                input: "Furlongs²".to_string(),
+                category: Category::Area,
+            })
+        );
+        let error = convert_temperature(1.0, "C", "X").unwrap_err();
+        assert_eq!(error.kind(), "unknown_unit");
+        assert_eq!(error.to_string(), "Unknown temperature unit: X");
+
+        // Non-finite inputs are rejected before conversion
+        assert_eq!(
+            convert_length(f64::NAN, "m", "ft").unwrap_err().kind(),
+            "non_finite_input"
+        );
+        assert!(matches!(
+            convert_weight(f64::INFINITY, "kg", "lb"),
+            Err(ConversionError::NonFiniteInput { .. })
+        ));
+
+        // The error type works with `?` and `Box<dyn Error>`
+        let boxed: Box<dyn std::error::Error> = Box::new(error);
+        assert!(boxed.to_string().contains("temperature"));
+    }
+
+    #[test]
+    fn test_unit_registry() {
+        let registry = UnitRegistry::global();
+
+        // Every category has units, and every spelling of every unit resolves
+        // to that unit (or, if several units share it, is reported as
+        // ambiguous between them) and converts to itself unchanged
+        for category in Category::ALL {
+            assert!(!registry.symbols(category).is_empty());
+            for unit in registry.units_in(category) {
+                for spelling in unit.spellings() {
+                    match registry.resolve(spelling, category) {
+                        Err(ConversionError::AmbiguousUnit { candidates, .. }) => {
+                            let own = format!("{} ({})", unit.symbol, unit.name);
+                            assert!(candidates.contains(&own), "{}", spelling);
+                        }
+                        resolved => assert_eq!(resolved, Ok(unit)),
+                    }
+                }
+                assert_eq!(
+                    registry.convert(42.0, unit.symbol, unit.symbol, category),
+                    Ok(42.0)
+                );
+            }
+        }
+
+        // Names and aliases also work case-insensitively
+        assert_eq!(
+            registry
+                .resolve("Square_Feet", Category::Area)
+                .unwrap()
+                .symbol,
+            "ft²"
+        );
+        assert_eq!(
+            registry
+                .resolve("metric ton", Category::Mass)
+                .unwrap()
+                .symbol,
+            "t"
+        );
+
+        // Units are only found in their own category
+        assert!(matches!(
+            registry.resolve("ft", Category::Mass),
+            Err(ConversionError::UnknownUnit { .. })
+        ));
+
+        // Category names round-trip
+        for category in Category::ALL {
+            assert_eq!(Category::from_name(category.name()), Some(category));
+        }
+        assert_eq!(Category::from_name("substance"), Some(Category::Amount));
+        assert_eq!(Category::from_name("velocity"), Some(Category::Speed));
+        assert_eq!(Category::from_name("flavour"), None);
+    }
+
+    #[test]
+    fn test_category_inference() {
+        // The category is inferred from the units
+        assert!((convert(3.0, "ft", "m").unwrap() - 0.9144).abs() < 1e-6);
+        assert!((convert(20.0, "mA", "A").unwrap() - 0.02).abs() < 1e-12);
+        assert_eq!(
+            convert(100.0, "C", "F").unwrap(),
+            convert_temperature(100.0, "C", "F").unwrap()
+        );
+        assert_eq!(
+            UnitRegistry::global().infer_category("acres", "ha"),
+            Ok(Category::Area)
+        );
+
+        // Units of different categories cannot be converted
+        assert_eq!(
+            convert(3.0, "ft", "kg"),
+            Err(ConversionError::IncompatibleDimensions {
+                from: "ft".to_string(),
+                from_category: Category::Length,
+                to: "kg".to_string(),
+                to_category: Category::Mass,
+            })
+        );
+
+        // Units unknown in every category are reported as such
+        let error = convert(3.0, "smoot", "m").unwrap_err();
+        assert_eq!(error.kind(), "unrecognized_unit");
+        assert_eq!(error.to_string(), "Unknown unit: smoot");
+
+        // Units shared by several categories list what they could resolve to
+        let error = convert(1.0, "pa", "Pa").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Ambiguous unit: pa could mean PA (petaampere, current) or \
+             pA (picoampere, current) or Pa (pascal, pressure)"
+        );
+
+        // Category-specific validation still applies
+        assert!(matches!(
+            convert(f64::NAN, "ft", "m"),
+            Err(ConversionError::NonFiniteInput { .. })
+        ));
+    }
+
+    #[test]
+    fn test_case_sensitive_symbols() {
+        // Symbols that differ only in case are different units
+        assert_eq!(convert_current(1.0, "mA", "A").unwrap(), 0.001);
+        assert_eq!(convert_current(1.0, "MA", "A").unwrap(), 1_000_000.0);
+
+        // Names are still case-insensitive
+        assert_eq!(convert_current(1.0, "Milliampere", "A").unwrap(), 0.001);
+        assert_eq!(
+            convert_current(1.0, "MEGAAMPERES", "A").unwrap(),
+            1_000_000.0
+        );
+        assert_eq!(convert_length(1.0, "KILOMETERS", "m").unwrap(), 1000.0);
+
+        // Case-folding is only a fallback, and only when it is unambiguous
+        assert_eq!(convert_length(1.0, "KM", "m").unwrap(), 1000.0);
+        assert_eq!(
+            convert_current(1.0, "ma", "A"),
+            Err(ConversionError::AmbiguousUnit {
+                input: "ma".to_string(),
+                candidates: vec![
+                    "mA (milliampere)".to_string(),
+                    "MA (megaampere)".to_string()
+                ],
+            })
+        );
+        assert_eq!(
+            convert(1.0, "ma", "A").unwrap_err().kind(),
+            "ambiguous_unit"
+        );
+    }
+
+    #[test]
+    fn test_si_prefixes() {
+        fn close(a: f64, b: f64) -> bool {
+            ((a - b) / b).abs() < 1e-12
+        }
+
+        // Any SI prefix applies to coherent units without a dedicated module
+        assert!(close(convert(1.0, "GA", "A").unwrap(), 1e9));
+        assert!(close(convert(1.0, "fmol", "mol").unwrap(), 1e-15));
+        assert!(close(convert(1.0, "nm", "m").unwrap(), 1e-9));
+        assert!(close(convert(1.0, "Mg", "kg").unwrap(), 1000.0));
+        assert!(close(convert(1.0, "ks", "min").unwrap(), 1000.0 / 60.0));
+        assert!(close(convert_temperature(1500.0, "mK", "K").unwrap(), 1.5));
+        assert!(close(convert(2.0, "kK", "K").unwrap(), 2000.0));
+        assert!(close(convert(1.0, "millikelvins", "K").unwrap(), 1e-3));
+
+        // Including the 2022 prefixes, by symbol or by name
+        assert!(close(convert(1.0, "Qm", "km").unwrap(), 1e27));
+        assert!(close(convert(1.0, "Rg", "kg").unwrap(), 1e24));
+        assert!(close(convert(1.0, "rontoseconds", "s").unwrap(), 1e-27));
+        assert!(close(convert(1.0, "quectomole", "mol").unwrap(), 1e-30));
+
+        // Micro sign, Greek mu and plain u are interchangeable
+        for micro in ["µcd", "μcd", "ucd"] {
+            assert!(close(convert(1.0, micro, "cd").unwrap(), 1e-6));
+        }
+        assert_eq!(convert(5.0, "µA", "μA").unwrap(), 5.0);
+
+        // Explicitly registered units keep priority, and case still matters
+        let registry = UnitRegistry::global();
+        assert_eq!(
+            registry
+                .resolve("mcd", Category::LuminousIntensity)
+                .unwrap()
+                .name,
+            "millicandela"
+        );
+        assert_eq!(
+            registry.resolve("min", Category::Time).unwrap().name,
+            "minute"
+        );
+        assert!(matches!(
+            convert(1.0, "MG", "kg"),
+            Err(ConversionError::AmbiguousUnit { .. })
+        ));
+
+        // Generated forms are accepted but not advertised
+        assert!(!registry.symbols(Category::Length).contains(&"dm"));
+    }
+
+    #[test]
+    fn test_exact_definitions() {
+        // Exact conversions take a factor, offset or origin without an exact
+        // definition at face value, as `f64` prints it. That is only right for
+        // a literal decimal or a whole number up to 2⁵³: a value needing more
+        // significant digits than `f64` holds was computed in floating point
+        // (1000.0000000000001), or prints rounded (2⁸³), so it must come with
+        // its exact definition
+        let significant_digits = |value: f64| {
+            let scientific = format!("{:e}", value.abs());
+            let mantissa = scientific.split('e').next().unwrap_or_default();
+            mantissa.chars().filter(|c| c.is_ascii_digit()).count()
+        };
+        let max_exact_integer = 2f64.powi(53);
+
+        let registry = UnitRegistry::global();
+        for unit in registry.units().iter().filter(|unit| unit.exact) {
+            for (value, definition, what) in [
+                (unit.factor, unit.exact_factor, "factor"),
+                (unit.offset, unit.exact_offset, "offset"),
+                (unit.origin, None, "origin"),
+            ] {
+                assert!(
+                    definition.is_some()
+                        || (value.fract() == 0.0 && value.abs() <= max_exact_integer)
+                        || significant_digits(value) <= f64::DIGITS as usize,
+                    "{} {} of {} is computed, not a literal; give it an exact definition",
+                    unit.symbol,
+                    what,
+                    value,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_exact_imperial_definitions() {
+        // Factors are the exact legal definitions, not rounded approximations
+        assert_eq!(convert_weight(1.0, "lb", "kg").unwrap(), 0.45359237);
+        assert_eq!(convert_length(1.0, "ft", "m").unwrap(), 0.3048);
+        assert_eq!(convert_length(1.0, "mi", "m").unwrap(), 1609.344);
+        assert_eq!(convert_volume(1.0, "gal", "l").unwrap(), 3.785411784);
+        assert_eq!(convert_volume(1.0, "gal_uk", "l").unwrap(), 4.54609);
+        assert_eq!(convert_area(1.0, "ac", "m²").unwrap(), 4046.8564224);
+
+        // The US gallon is 231 cubic inches
+        let cubic_inch_in_liters = length::METERS_PER_INCH.powi(3) * 1000.0;
+        assert!((volume::LITERS_PER_GALLON_US / cubic_inch_in_liters - 231.0).abs() < 1e-12);
+
+        // Round trips through the base unit are stable to within a few ULPs
+        let within_ulps = |a: f64, b: f64| (a - b).abs() <= 4.0 * f64::EPSILON * b.abs();
+        let round_trips = [
+            ("lb", "kg", Category::Mass),
+            ("oz", "g", Category::Mass),
+            ("st", "kg", Category::Mass),
+            ("ft", "m", Category::Length),
+            ("mi", "km", Category::Length),
+            ("in", "mm", Category::Length),
+            ("gal", "l", Category::Volume),
+            ("gal_uk", "ml", Category::Volume),
+            ("cup", "l", Category::Volume),
+            ("fl_oz_uk", "fl_oz", Category::Volume),
+            ("ac", "ha", Category::Area),
+            ("mi²", "km²", Category::Area),
+            ("ft²", "m²", Category::Area),
+        ];
+        for (from, to, category) in round_trips {
+            for value in [1.0, 1000.0, 123_456.789] {
+                let there = convert_in(value, from, to, category).unwrap();
+                let back = convert_in(there, to, from, category).unwrap();
+                assert!(
+                    within_ulps(back, value),
+                    "{} {} -> {} -> {}",
+                    value,
+                    from,
+                    to,
+                    back
+                );
+            }
+        }
+
+        // Batches no longer drift: 1000 lb -> kg -> lb gives 1000 lb
+        let kilograms = pounds_to_kilograms(1000.0);
+        assert_eq!(kilograms, 453.59237);
+        assert!(within_ulps(kilograms_to_pounds(kilograms), 1000.0));
+    }
+
+    #[cfg(feature = "exact")]
+    #[test]
+    fn test_exact_conversions() {
+        use crate::conversions::exact::{convert_exact, convert_in_exact, ExactValue};
+
+        let exact = |text: &str| text.parse::<ExactValue>().unwrap();
+
+        // Decimal inputs stay exact through the whole conversion chain
+        let inches = convert_exact(&exact("0.1"), "mi", "in").unwrap();
+        assert_eq!(inches, exact("6336"));
+        assert_eq!(inches.to_string(), "6336");
+        let kilograms = convert_exact(&exact("1000"), "lb", "kg").unwrap();
+        assert_eq!(kilograms.to_string(), "453.59237");
+        let liters = convert_exact(&exact("1"), "fl_oz_uk", "ml").unwrap();
+        assert_eq!(liters.to_string(), "28.4130625");
+        let grams = convert_exact(&exact("2.5"), "pg", "Mg").unwrap();
+        assert_eq!(grams.to_string(), "0.0000000000000000025");
+
+        // Round trips are exact, including through non-decimal factors
+        let value = exact("98.6");
+        let celsius = convert_in_exact(&value, "F", "C", Category::Temperature).unwrap();
+        assert_eq!(celsius, exact("37"));
+        let back = convert_in_exact(&celsius, "C", "F", Category::Temperature).unwrap();
+        assert_eq!(back, value);
+        let absolute = convert_exact(&exact("0"), "psig", "Pa").unwrap();
+        assert_eq!(absolute.to_string(), "101325");
+        assert_eq!(
+            convert_exact(&exact("-20"), "psig", "Pa")
+                .unwrap_err()
+                .kind(),
+            "below_vacuum"
+        );
+
+        // Output precision is chosen by the caller
+        let third = convert_exact(&exact("20"), "min", "h").unwrap();
+        assert_eq!(third.to_exact_decimal(), None);
+        assert_eq!(third.to_string(), "1/3");
+        assert_eq!(format!("{:.4}", third), "0.3333");
+        assert_eq!(exact("-2.5").to_decimal(0), "-3");
+        assert_eq!(exact("1/8").to_decimal(2), "0.13");
+
+        // Exact factors agree with the f64 ones, to the last bit where the f64
+        // factor is a literal
+        let registry = UnitRegistry::global();
+        for category in Category::ALL {
+            let base = registry.symbols(category)[0];
+            for unit in registry.units_in(category).filter(|unit| unit.exact) {
+                let exact_result = convert_in_exact(&exact("1"), unit.symbol, base, category);
+                let exact_result = exact_result.unwrap().to_f64();
+                let approximate = registry.convert(1.0, unit.symbol, base, category).unwrap();
+                let literal = unit.exact_factor.is_none() && unit.exact_offset.is_none();
+                if literal && !unit.reciprocal {
+                    assert_eq!(exact_result, approximate, "{}", unit.symbol);
+                } else {
+                    let difference = (exact_result - approximate).abs();
+                    assert!(
+                        difference <= 1e-12 * approximate.abs().max(1.0),
+                        "{}",
+                        unit.symbol
+                    );
+                }
+            }
+        }
+
+        // Logarithmic units and radian-based angles cannot be converted exactly
+        assert_eq!(
+            convert_exact(&exact("1"), "W", "dBm").unwrap_err().kind(),
+            "inexact_unit"
+        );
+        assert_eq!(
+            convert_exact(&exact("1"), "µrad", "°").unwrap_err().kind(),
+            "inexact_unit"
+        );
+        let mils = convert_exact(&exact("1"), "mil", "arcmin").unwrap();
+        assert_eq!(mils.to_string(), "3.375");
+
+        // Reciprocal hydrometer scales stay exact
+        let water = convert_exact(&exact("10"), "°API", "SG").unwrap();
+        assert_eq!(water.to_string(), "1");
+        let heavy = convert_exact(&exact("1.45"), "SG", "°Bé_heavy").unwrap();
+        assert_eq!(heavy.to_string(), "45");
+        assert_eq!(
+            convert_exact(&exact("-131.5"), "°API", "SG")
+                .unwrap_err()
+                .kind(),
+            "non_positive_density"
+        );
+
+        // Battery capacities are whole coulombs
+        let battery = convert_exact(&exact("3000"), "mAh", "C").unwrap();
+        assert_eq!(battery.to_string(), "10800");
+        let newton = convert_exact(&exact("33"), "°N", "°Rø").unwrap();
+        assert_eq!(newton.to_string(), "60");
+        let delisle = convert_exact(&exact("-40"), "F", "De").unwrap();
+        assert_eq!(delisle.to_string(), "210");
+        let rise = convert_exact(&exact("10"), "ΔC", "ΔF").unwrap();
+        assert_eq!(rise.to_string(), "18");
+        assert!(matches!(
+            convert_exact(&exact("-273.16"), "C", "F"),
+            Err(ConversionError::BelowAbsoluteZero { .. })
+        ));
+        let zero = convert_exact(&exact("-459.67"), "F", "K").unwrap();
+        assert_eq!(zero.to_string(), "0");
+        let light_year = convert_exact(&exact("1"), "ly", "km").unwrap();
+        assert_eq!(light_year.to_string(), "9460730472580.8");
+        assert!(matches!(
+            convert_exact(&exact("1"), "pc", "m"),
+            Err(ConversionError::InexactUnit { .. })
+        ));
+        let survey_foot = convert_exact(&exact("3937"), "ft_us", "m").unwrap();
+        assert_eq!(survey_foot.to_string(), "1200");
+        let chain = convert_exact(&exact("1"), "ch", "li").unwrap();
+        assert_eq!(chain.to_string(), "100");
+        let foot_candles = convert_exact(&exact("1"), "ph", "fc").unwrap();
+        assert_eq!(foot_candles.to_string(), "929.0304");
+        assert_eq!(
+            convert_exact(&exact("1"), "fL", "nit").unwrap_err().kind(),
+            "inexact_unit"
+        );
+
+        // Invalid numbers are reported as such
+        assert_eq!(
+            "1.2.3".parse::<ExactValue>().unwrap_err().kind(),
+            "invalid_number"
+        );
+        assert!("abc".parse::<ExactValue>().is_err());
+        assert!("1/0".parse::<ExactValue>().is_err());
+
+        // Exponents are bounded, so a short input cannot build a huge power of ten
+        assert_eq!(
+            "1e-99999999999".parse::<ExactValue>(),
+            Err(ConversionError::InvalidNumber {
+                input: "1e-99999999999".to_string()
+            })
+        );
+        assert_eq!(
+            "1e-1000000".parse::<ExactValue>().unwrap_err().kind(),
+            "invalid_number"
+        );
+        assert!("1e4001".parse::<ExactValue>().is_err());
+        assert!("1e4000".parse::<ExactValue>().is_ok());
+        assert!("2.5e-4000".parse::<ExactValue>().is_ok());
+    }
+
+    #[test]
+    fn test_speed_conversions() {
+        // Test kilometers per hour to meters per second
+        assert!((convert_speed(36.0, "km/h", "m/s").unwrap() - 10.0).abs() < 1e-12);
+
+        // Test miles per hour to feet per second (exactly 22/15)
+        assert!((convert_speed(15.0, "mph", "ft/s").unwrap() - 22.0).abs() < 1e-12);
+
+        // Test knots to kilometers per hour (1 kn = 1.852 km/h)
+        assert!((convert_speed(1.0, "kn", "km/h").unwrap() - 1.852).abs() < 1e-12);
+
+        // Test Mach 1 at sea level
+        assert!((convert_speed(1.0, "Mach", "m/s").unwrap() - 340.294).abs() < 1e-9);
+
+        // Test the modular API and typed quantities
+        assert!((speed::miles_per_hour::to_kilometers_per_hour(60.0) - 96.56064).abs() < 1e-9);
+        let cruise = speed::knots::new(450.0);
+        assert!((cruise.to(speed::mach::UNIT).value() - 0.6803).abs() < 0.0001);
+
+        // Speed units are inferred by the general convert function
+        assert_eq!(
+            UnitRegistry::global()
+                .infer_category("mph", "km/h")
+                .unwrap(),
+            Category::Speed
+        );
+    }
+
+    #[test]
+    fn test_pressure_conversions() {
+        // Test standard atmosphere to kilopascals, bar and torr
+        assert!((convert_pressure(1.0, "atm", "kPa").unwrap() - 101.325).abs() < 1e-12);
+        assert!((convert_pressure(1.0, "atm", "bar").unwrap() - 1.01325).abs() < 1e-12);
+        assert!((convert_pressure(1.0, "atm", "Torr").unwrap() - 760.0).abs() < 1e-9);
+
+        // Test psi to kilopascals (1 psi = 6.894757 kPa)
+        assert!((pressure::psi::to_kilopascals(1.0) - 6.894_757).abs() < 1e-6);
+
+        // Test inches of mercury to millimeters of mercury
+        assert!((convert_pressure(1.0, "inHg", "mmHg").unwrap() - 25.4).abs() < 1e-9);
+        assert!((convert_pressure(1.0, "inH2O", "Pa").unwrap() - 249.088_91).abs() < 1e-9);
+
+        // Gauge pressures are relative to one standard atmosphere
+        assert!((convert_pressure(0.0, "psig", "atm").unwrap() - 1.0).abs() < 1e-12);
+        assert!((convert_pressure(14.7, "psia", "psig").unwrap() - 0.0041).abs() < 1e-4);
+        assert!((convert_pressure(1.0, "bar", "psig").unwrap() + 0.1922).abs() < 1e-4);
+
+        // But never below a perfect vacuum
+        let vacuum = convert_pressure(0.0, "Pa", "psig").unwrap();
+        assert!((convert_pressure(vacuum, "psig", "psia").unwrap()).abs() < 1e-12);
+        let error = convert_pressure(-20.0, "psig", "psia").unwrap_err();
+        assert_eq!(error.kind(), "below_vacuum");
+        assert_eq!(
+            error.to_string(),
+            "-20 psig is below a perfect vacuum (-14.695948776 psig)"
+        );
+        assert!(matches!(
+            convert_pressure(-1.0, "kPa", "psig"),
+            Err(ConversionError::BelowVacuum { .. })
+        ));
+        assert!((convert_pressure(-1.0, "kPa", "Pa").unwrap() + 1000.0).abs() < 1e-12);
+
+        // Or to an explicit atmosphere
+        assert_eq!(pressure::gauge_to_absolute(30.0, 12.5), 42.5);
+        assert_eq!(pressure::absolute_to_gauge(42.5, 12.5), 30.0);
+
+        // SI prefixes apply to the pascal
+        assert!((convert_pressure(1013.25, "hPa", "atm").unwrap() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_energy_conversions() {
+        // Test kilowatt-hours to megajoules
+        assert!((convert_energy(1.0, "kWh", "MJ").unwrap() - 3.6).abs() < 1e-12);
+
+        // Test the two calories
+        assert_eq!(convert_energy(1.0, "cal_th", "J").unwrap(), 4.184);
+        assert_eq!(convert_energy(1.0, "cal_IT", "J").unwrap(), 4.1868);
+        assert!((energy::thermochemical_kilocalories::to_kilojoules(2000.0) - 8368.0).abs() < 1e-9);
+
+        // The bare calorie spellings do not silently pick a definition
+        for spelling in ["cal", "calorie", "kcal", "Cal", "kilocalories"] {
+            assert!(matches!(
+                convert_energy(1.0, spelling, "J"),
+                Err(ConversionError::AmbiguousUnit { .. })
+            ));
+        }
+
+        // Test BTU, therms, electronvolts, ergs and foot-pounds
+        assert!((convert_energy(1.0, "BTU", "J").unwrap() - 1055.05585262).abs() < 1e-9);
+        assert!((convert_energy(1.0, "thm", "kWh").unwrap() - 29.3071).abs() < 1e-4);
+        assert!((convert_energy(1.0, "MeV", "J").unwrap() - 1.602176634e-13).abs() < 1e-25);
+        assert!((convert_energy(1.0, "J", "erg").unwrap() - 1e7).abs() < 1e-6);
+        assert!((convert_energy(1.0, "ft·lbf", "J").unwrap() - 1.355_818).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_power_conversions() {
+        // Test the three horsepowers
+        assert!((convert_power(1.0, "hp", "W").unwrap() - 745.699_872).abs() < 1e-6);
+        assert!((convert_power(1.0, "PS", "W").unwrap() - 735.498_75).abs() < 1e-9);
+        assert_eq!(convert_power(1.0, "hp_E", "W").unwrap(), 746.0);
+
+        // Test tons of refrigeration to BTU per hour and kilowatts
+        assert!((convert_power(1.0, "TR", "BTU/h").unwrap() - 12_000.0).abs() < 1e-9);
+        assert!((power::tons_of_refrigeration::to_kilowatts(1.0) - 3.516_853).abs() < 1e-6);
+
+        // Test the logarithmic decibel units
+        assert!((convert_power(1.0, "mW", "dBm").unwrap()).abs() < 1e-12);
+        assert!((convert_power(100.0, "W", "dBm").unwrap() - 50.0).abs() < 1e-12);
+        assert!((convert_power(20.0, "dBm", "W").unwrap() - 0.1).abs() < 1e-12);
+        assert!((convert_power(-30.0, "dBW", "dBm").unwrap()).abs() < 1e-12);
+        assert!((power::dbw::to_kilowatts(30.0) - 1.0).abs() < 1e-12);
+
+        // Zero and negative powers have no decibel level
+        for watts in [0.0, -5.0] {
+            assert_eq!(
+                convert_power(watts, "W", "dBm").unwrap_err().kind(),
+                "non_positive_power"
+            );
+        }
+        assert!(convert_power(-5.0, "dBW", "W").is_ok());
+    }
+
+    #[test]
+    fn test_force_and_torque_conversions() {
+        // Test the gravitational force units
+        assert!((convert_force(1.0, "lbf", "N").unwrap() - 4.448_221_615_260_5).abs() < 1e-12);
+        assert_eq!(convert_force(1.0, "kgf", "N").unwrap(), 9.80665);
+        assert!((convert_force(1.0, "kip", "lbf").unwrap() - 1000.0).abs() < 1e-9);
+
+        // Test the CGS and absolute English units
+        assert!((convert_force(1.0, "N", "dyn").unwrap() - 100_000.0).abs() < 1e-9);
+        assert!((force::poundals::to_newtons(1.0) - 0.138_254_954_376).abs() < 1e-12);
+
+        // Test torque units
+        assert!((convert_torque(1.0, "lbf·ft", "N·m").unwrap() - 1.355_818).abs() < 1e-6);
+        assert!((convert_torque(1.0, "lbf-ft", "lbf-in").unwrap() - 12.0).abs() < 1e-12);
+        assert!((torque::kilogram_force_meters::to_newton_meters(1.0) - 9.80665).abs() < 1e-12);
+
+        // Torque is never interchangeable with energy
+        assert!(matches!(
+            convert(1.0, "N·m", "J"),
+            Err(ConversionError::IncompatibleDimensions { .. })
+        ));
+        assert!(matches!(
+            convert(1.0, "lbf·ft", "ft·lbf"),
+            Err(ConversionError::IncompatibleDimensions { .. })
+        ));
+        assert!(convert_energy(1.0, "N·m", "J").is_err());
+    }
+
+    #[test]
+    fn test_frequency_conversions() {
+        // Test rotational and cyclic rates
+        assert!((convert_frequency(60.0, "rpm", "Hz").unwrap() - 1.0).abs() < 1e-12);
+        assert!(
+            (convert_frequency(1.0, "Hz", "rad/s").unwrap() - std::f64::consts::TAU).abs() < 1e-12
+        );
+        assert!((convert_frequency(1.0, "cph", "cpm").unwrap() - 1.0 / 60.0).abs() < 1e-12);
+        assert!((convert_frequency(2.4, "GHz", "MHz").unwrap() - 2400.0).abs() < 1e-9);
+        assert!(
+            (frequency::radians_per_second::to_revolutions_per_minute(std::f64::consts::PI) - 30.0)
+                .abs()
+                < 1e-9
+        );
+
+        // Test period ↔ frequency
+        assert!((period_to_frequency(2.5, "ms", "Hz").unwrap() - 400.0).abs() < 1e-9);
+        assert!((frequency_to_period(50.0, "Hz", "ms").unwrap() - 20.0).abs() < 1e-9);
+        let period = frequency::to_period(frequency::kilohertz::new(1.0)).unwrap();
+        assert!((time::microseconds::value_of(period) - 1000.0).abs() < 1e-9);
+        let tone = frequency::from_period(time::milliseconds::new(2.5)).unwrap();
+        assert!((frequency::hertz::value_of(tone) - 400.0).abs() < 1e-9);
+
+        // Zero and negative periods or frequencies have no reciprocal
+        assert_eq!(
+            period_to_frequency(0.0, "s", "Hz").unwrap_err().kind(),
+            "no_reciprocal"
+        );
+        assert_eq!(
+            period_to_frequency(-1.0, "ms", "Hz"),
+            Err(ConversionError::NoReciprocal {
+                value: -1.0,
+                unit: "ms".to_string()
+            })
+        );
+        assert_eq!(
+            frequency_to_period(-50.0, "Hz", "ms")
+                .unwrap_err()
+                .to_string(),
+            "-50 Hz has no reciprocal; the value must be positive"
+        );
+        assert!(frequency_to_period(0.0, "rpm", "s").is_err());
+        assert_eq!(
+            frequency::from_period(time::seconds::new(0.0))
+                .unwrap_err()
+                .kind(),
+            "no_reciprocal"
+        );
+        assert!(frequency::to_period(frequency::hertz::new(-1.0)).is_err());
+        assert!(period_to_frequency(1.0, "Hz", "Hz").is_err());
+    }
+
+    #[test]
+    fn test_angle_conversions() {
+        // Test plane angle units
+        assert!((convert_angle(180.0, "deg", "rad").unwrap() - std::f64::consts::PI).abs() < 1e-12);
+        assert!((convert_angle(1.0, "tr", "gon").unwrap() - 400.0).abs() < 1e-9);
+        assert!((convert_angle(1.0, "°", "arcsec").unwrap() - 3600.0).abs() < 1e-9);
+        assert!((convert_angle(1.0, "tr", "mil").unwrap() - 6400.0).abs() < 1e-9);
+        assert!((convert_angle(1.0, "tr", "mrad").unwrap() - 6_283.185_307).abs() < 1e-6);
+        assert!((angle::arcminutes::to_arcseconds(1.5) - 90.0).abs() < 1e-12);
+
+        // Test solid angle units
+        assert!(
+            (convert_solid_angle(1.0, "sp", "sr").unwrap() - 4.0 * std::f64::consts::PI).abs()
+                < 1e-12
+        );
+        assert!((convert_solid_angle(1.0, "sr", "deg²").unwrap() - 3_282.806_35).abs() < 1e-5);
+        assert!(convert(1.0, "sr", "rad").is_err());
+
+        // Test DMS parsing and formatting
+        for input in [
+            "12°34'56\"",
+            "12° 34′ 56″",
+            "12d34m56s",
+            "12 34 56",
+            "12:34:56N",
+        ] {
+            assert!(
+                (parse_dms(input).unwrap() - 12.582_222_222).abs() < 1e-9,
+                "{}",
+                input
+            );
+        }
+        assert_eq!(parse_dms("-33°52'").unwrap(), parse_dms("33°52'S").unwrap());
+        assert_eq!(parse_dms("45.5°").unwrap(), 45.5);
+        for input in ["", "12°60'", "12.5°30'", "12'34°", "1 2 3 4", "abc"] {
+            assert_eq!(
+                parse_dms(input).unwrap_err().kind(),
+                "invalid_angle",
+                "{}",
+                input
+            );
+        }
+        assert_eq!(format_dms(12.582_222_222, 0), "12°34'56\"");
+        assert_eq!(format_dms(-33.866_666_667, 0), "-33°52'00\"");
+        assert_eq!(
+            format_dms(parse_dms("1°02'03.25\"").unwrap(), 2),
+            "1°02'03.25\""
+        );
+        assert_eq!(format_dms(0.5, usize::MAX), format_dms(0.5, 17));
+        assert_eq!(format_dms(0.5, 100), format!("0°30'{:020.17}\"", 0.0));
+
+        // Test normalisation in any unit
+        assert_eq!(
+            normalize_angle(-90.0, "°", AngleRange::Positive).unwrap(),
+            270.0
+        );
+        assert_eq!(
+            normalize_angle(180.0, "°", AngleRange::Signed).unwrap(),
+            180.0
+        );
+        assert_eq!(
+            normalize_angle(540.0, "°", AngleRange::Signed).unwrap(),
+            180.0
+        );
+        assert_eq!(
+            normalize_angle(7000.0, "mil", AngleRange::Positive).unwrap(),
+            600.0
+        );
+        let wrapped =
+            normalize_angle(-std::f64::consts::FRAC_PI_2, "rad", AngleRange::Positive).unwrap();
+        assert!((wrapped - 3.0 * std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+        assert_eq!(
+            normalize_angle(-1e-20, "°", AngleRange::Positive).unwrap(),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_data_conversions() {
+        // Test decimal and binary prefixes
+        assert_eq!(convert_data(1.0, "GiB", "MiB").unwrap(), 1024.0);
+        assert_eq!(convert_data(1.0, "GB", "MB").unwrap(), 1000.0);
+        assert_eq!(convert_data(1.0, "MiB", "B").unwrap(), 1_048_576.0);
+        assert_eq!(convert_data(1.0, "B", "bit").unwrap(), 8.0);
+        assert!((convert_data(1.0, "TiB", "TB").unwrap() - 1.099_511_627_776).abs() < 1e-12);
+        assert_eq!(data::kibibytes::to_bytes(2.0), 2048.0);
+
+        // MB and MiB, and bits and bytes, are never conflated
+        for input in ["KB", "mb", "gb"] {
+            assert_eq!(
+                convert_data(1.0, input, "B").unwrap_err().kind(),
+                "ambiguous_unit",
+                "{}",
+                input
+            );
+        }
+        assert_eq!(
+            convert_data(1.0, "megabytes", "mebibytes").unwrap(),
+            0.953_674_316_406_25
+        );
+
+        // Test data rates
+        assert_eq!(convert_data_rate(100.0, "Mbit/s", "MB/s").unwrap(), 12.5);
+        assert_eq!(convert_data_rate(1.0, "MiB/s", "KiB/s").unwrap(), 1024.0);
+        assert_eq!(convert_data_rate(1.0, "Gbps", "Mbps").unwrap(), 1000.0);
+        assert!(convert_data_rate(1.0, "KB/s", "B/s").is_err());
+        assert!(convert(1.0, "MB", "MB/s").is_err());
+
+        // Test the best-unit formatter
+        let binary = PrefixConvention::Binary;
+        let decimal = PrefixConvention::Decimal;
+        assert_eq!(
+            format_data(1_536_000.0, "B", binary, 2).unwrap(),
+            "1.46 MiB"
+        );
+        assert_eq!(
+            format_data(1_536_000.0, "B", decimal, 2).unwrap(),
+            "1.54 MB"
+        );
+        assert_eq!(format_data(1023.999, "KiB", binary, 2).unwrap(), "1.00 MiB");
+        assert_eq!(format_data(512.0, "B", decimal, 2).unwrap(), "512 B");
+        assert_eq!(format_data(999.0, "B", decimal, 1).unwrap(), "1.0 kB");
+        assert_eq!(format_data(4.0, "bit", decimal, 2).unwrap(), "0.50 B");
+        assert_eq!(format_data(-2048.0, "B", binary, 0).unwrap(), "-2 KiB");
+        assert_eq!(format_data(3.0, "PiB", binary, 2).unwrap(), "3.00 PiB");
+        assert_eq!(format_data(0.0, "B", decimal, 2).unwrap(), "0.00 B");
+        assert_eq!(format_data(1e30, "B", binary, 2).unwrap(), "827180.61 YiB");
+        assert_eq!(format_data(1e30, "B", decimal, 2).unwrap(), "1000000.00 YB");
+        assert_eq!(format_data(2048.0, "PiB", binary, 1).unwrap(), "2.0 EiB");
+        assert_eq!(convert_data(1.0, "ZB", "EB").unwrap(), 1000.0);
+        assert_eq!(convert_data(1.0, "YiB", "ZiB").unwrap(), 1024.0);
+        assert!(format_data(1.0, "KB", binary, 2).is_err());
+
+        // Precision is clamped, so any usize gives a sensible result
+        assert_eq!(
+            format_data(1536.0, "B", binary, usize::MAX).unwrap(),
+            format!("{:.17} KiB", 1.5)
+        );
+        assert_eq!(
+            format_data(1536.0, "B", binary, MAX_FORMAT_PRECISION + 1).unwrap(),
+            format_data(1536.0, "B", binary, MAX_FORMAT_PRECISION).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_density_conversions() {
+        // Test linear density units
+        assert_eq!(convert_density(1.0, "g/cm³", "kg/m³").unwrap(), 1000.0);
+        assert_eq!(convert_density(1000.0, "kg/m³", "g/cm³").unwrap(), 1.0);
+        assert!((convert_density(1.0, "lb/ft³", "kg/m³").unwrap() - 16.018_463).abs() < 1e-6);
+        assert!((convert_density(1.0, "lb/in³", "lb/ft³").unwrap() - 1728.0).abs() < 1e-9);
+        assert!((convert_density(1.0, "oz/in³", "lb/in³").unwrap() - 0.0625).abs() < 1e-12);
+
+        // Pounds per gallon use the volume module's gallons
+        let per_us_gallon = convert_density(1.0, "lb/gal", "kg/m³").unwrap();
+        let us_gallon = volume::gallons_us::to_liters(1.0) / 1000.0;
+        assert!((per_us_gallon - weight::pounds::to_kilograms(1.0) / us_gallon).abs() < 1e-9);
+        let ratio = convert_density(1.0, "lb/gal_uk", "lb/gal").unwrap();
+        assert!((ratio - volume::gallons_us::to_gallons_uk(1.0)).abs() < 1e-12);
+
+        // Test specific gravity and the reciprocal hydrometer scales
+        assert!((convert_density(1.0, "SG", "kg/m³").unwrap() - 999.016).abs() < 1e-9);
+        assert!((convert_density(10.0, "°API", "SG").unwrap() - 1.0).abs() < 1e-12);
+        assert!((convert_density(0.85, "SG", "°API").unwrap() - 34.97).abs() < 0.01);
+        assert!((convert_density(0.0, "°Bé_heavy", "SG").unwrap() - 1.0).abs() < 1e-12);
+        assert!((convert_density(1.2, "SG", "°Bé_heavy").unwrap() - 24.1667).abs() < 1e-4);
+        assert!((convert_density(10.0, "°Bé_light", "SG").unwrap() - 1.0).abs() < 1e-12);
+        assert!((density::degrees_api::to_specific_gravity(45.0) - 0.8017).abs() < 1e-4);
+        let api = density::kilograms_per_cubic_meter::to_degrees_api(850.0);
+        assert!((density::degrees_api::to_kilograms_per_cubic_meter(api) - 850.0).abs() < 1e-9);
+
+        // Hydrometer quantities order by density, so a higher °API is lighter
+        assert!(density::degrees_api::new(10.0) > density::specific_gravity::new(0.5));
+        assert!(density::degrees_api::new(45.0) < density::degrees_api::new(10.0));
+        assert!(density::degrees_api::new(10.0) == density::specific_gravity::new(1.0));
+
+        // Readings with no positive density are rejected
+        for (value, unit) in [(-131.5, "°API"), (-200.0, "°API"), (145.0, "°Bé_heavy")] {
+            assert_eq!(
+                convert_density(value, unit, "SG").unwrap_err().kind(),
+                "non_positive_density"
+            );
+        }
+        assert_eq!(
+            convert_density(0.0, "kg/m³", "°API").unwrap_err().kind(),
+            "non_positive_density"
+        );
+        assert!(matches!(
+            convert_density(1.0, "°Bé", "SG"),
+            Err(ConversionError::AmbiguousUnit { .. })
+        ));
+    }
+
+    #[test]
+    fn test_flow_rate_conversions() {
+        // Test volumetric flow rates
+        assert!((convert_flow_rate(1.0, "L/s", "L/min").unwrap() - 60.0).abs() < 1e-9);
+        assert!((convert_flow_rate(1.0, "m³/s", "m³/h").unwrap() - 3600.0).abs() < 1e-9);
+        assert!((convert_flow_rate(1.0, "gpm", "L/min").unwrap() - 3.785_411_784).abs() < 1e-9);
+        assert!((convert_flow_rate(1.0, "gpm_uk", "L/min").unwrap() - 4.546_09).abs() < 1e-9);
+        assert!((convert_flow_rate(1.0, "CFM", "L/min").unwrap() - 28.316_846_592).abs() < 1e-9);
+        assert!((convert_flow_rate(1.0, "bbl/d", "gpm").unwrap() - 42.0 / 1440.0).abs() < 1e-12);
+
+        // Flow rates compose the volume and time definitions
+        let gallons_per_minute = flow_rate::gallons_us_per_minute::to_liters_per_second(1.0);
+        let expected = volume::gallons_us::to_liters(1.0) / time::minutes::to_seconds(1.0);
+        assert_eq!(gallons_per_minute, expected);
+
+        // Test mass flow rates
+        assert!((convert_mass_flow_rate(1.0, "kg/s", "kg/h").unwrap() - 3600.0).abs() < 1e-9);
+        assert!((convert_mass_flow_rate(1.0, "t/h", "kg/h").unwrap() - 1000.0).abs() < 1e-9);
+        assert!((convert_mass_flow_rate(3600.0, "lb/h", "lb/s").unwrap() - 1.0).abs() < 1e-12);
+        assert!((mass_flow_rate::grams_per_second::to_kilograms_per_hour(1.0) - 3.6).abs() < 1e-12);
+        assert!(convert(1.0, "kg/s", "L/s").is_err());
+    }
+
+    #[test]
+    fn test_electrical_conversions() {
+        // Test voltage, including the CGS units
+        assert!((convert_voltage(1.5, "V", "mV").unwrap() - 1500.0).abs() < 1e-9);
+        assert!((convert_voltage(11.0, "kV", "V").unwrap() - 11_000.0).abs() < 1e-9);
+        assert!((convert_voltage(1e8, "abV", "V").unwrap() - 1.0).abs() < 1e-12);
+        assert!((convert_voltage(1.0, "statV", "V").unwrap() - 299.792_458).abs() < 1e-9);
+        assert!((convert_voltage(5.0, "μV", "mV").unwrap() - 0.005).abs() < 1e-15);
+
+        // Test resistance, with the omega letter, the ohm sign and ASCII spellings
+        assert!((convert_resistance(4.7, "kΩ", "Ω").unwrap() - 4700.0).abs() < 1e-9);
+        assert!((convert_resistance(4.7, "k\u{2126}", "ohms").unwrap() - 4700.0).abs() < 1e-9);
+        assert!((convert_resistance(2.2, "Mohm", "kohm").unwrap() - 2200.0).abs() < 1e-9);
+        assert!((convert_resistance(250.0, "mohm", "Ohm").unwrap() - 0.25).abs() < 1e-12);
+        assert!(matches!(
+            convert_resistance(1.0, "MOHM", "Ω"),
+            Err(ConversionError::AmbiguousUnit { .. })
+        ));
+
+        // Test conductance; a mho is a siemens
+        assert_eq!(convert_conductance(3.0, "mho", "S").unwrap(), 3.0);
+        assert!((convert_conductance(1.5, "mS", "μS").unwrap() - 1500.0).abs() < 1e-9);
+        assert_eq!(convert(2.0, "mS", "S").unwrap(), 0.002);
+
+        // Test charge, with battery capacities composed from current and time
+        assert!((convert_charge(1000.0, "mAh", "C").unwrap() - 3600.0).abs() < 1e-9);
+        assert!((convert_charge(7200.0, "C", "Ah").unwrap() - 2.0).abs() < 1e-12);
+        assert!((convert_charge(2.5, "Ah", "mAh").unwrap() - 2500.0).abs() < 1e-9);
+        assert!((convert_charge(1.0, "e", "C").unwrap() - 1.602_176_634e-19).abs() < 1e-30);
+        let milliampere_hour =
+            current::milliamperes::to_amperes(1.0) * time::hours::to_seconds(1.0);
+        assert_eq!(
+            charge::milliampere_hours::to_coulombs(1.0),
+            milliampere_hour
+        );
+
+        // Test capacitance and inductance
+        assert!((convert_capacitance(0.1, "uF", "nF").unwrap() - 100.0).abs() < 1e-9);
+        assert!((convert_capacitance(4.7, "nF", "pF").unwrap() - 4700.0).abs() < 1e-9);
+        assert!((convert_capacitance(1.0, "F", "mF").unwrap() - 1000.0).abs() < 1e-9);
+        assert!((convert_inductance(2.2, "mH", "μH").unwrap() - 2200.0).abs() < 1e-9);
+        assert!((convert_inductance(1.0, "uH", "H").unwrap() - 1e-6).abs() < 1e-18);
+
+        // Symbols shared with other categories follow the other unit
+        assert!((convert(100.0, "C", "F").unwrap() - 212.0).abs() < 1e-9);
+        assert!((convert(1.0, "F", "μF").unwrap() - 1e6).abs() < 1e-6);
+        assert!(convert(1.0, "V", "A").is_err());
+    }
+
+    #[test]
+    fn test_photometry_conversions() {
+        // Test luminous flux, illuminance and luminance
+        assert_eq!(convert_luminous_flux(2.5, "klm", "lm").unwrap(), 2500.0);
+        assert!((convert_illuminance(1.0, "fc", "lx").unwrap() - 10.763_910_417).abs() < 1e-9);
+        assert!((convert_illuminance(1.0, "ph", "klx").unwrap() - 10.0).abs() < 1e-12);
+        assert!((convert_luminance(1.0, "sb", "nit").unwrap() - 10_000.0).abs() < 1e-9);
+        assert!((convert_luminance(1.0, "fL", "cd/m²").unwrap() - 3.426_259_1).abs() < 1e-7);
+        assert!((convert_luminance(std::f64::consts::PI, "La", "sb").unwrap() - 1.0).abs() < 1e-12);
+
+        // Illuminance and luminance compose the area definitions
+        let foot_candle = 1.0 / area::square_feet::to_square_meters(1.0);
+        assert_eq!(illuminance::foot_candles::to_lux(1.0), foot_candle);
+
+        // Candela ↔ lumen over a beam solid angle
+        assert!((intensity_to_flux(1000.0, "cd", 0.2, "sr", "lm").unwrap() - 200.0).abs() < 1e-9);
+        let isotropic =
+            flux_to_intensity(4.0 * std::f64::consts::PI, "lm", 1.0, "sp", "cd").unwrap();
+        assert!((isotropic - 1.0).abs() < 1e-12);
+        let flux = luminous_flux::from_intensity(
+            luminous_intensity::candela::new(100.0),
+            solid_angle::steradians::new(0.5),
+        );
+        assert!((luminous_flux::lumens::value_of(flux) - 50.0).abs() < 1e-12);
+        let source = luminous_flux::to_intensity(flux, solid_angle::steradians::new(0.5));
+        assert!((luminous_intensity::candela::value_of(source) - 100.0).abs() < 1e-12);
+
+        // Lux ↔ candela by the inverse-square law
+        assert!(
+            (intensity_to_illuminance(800.0, "cd", 2.0, "m", "lx").unwrap() - 200.0).abs() < 1e-9
+        );
+        assert!(
+            (illuminance_to_intensity(25.0, "fc", 10.0, "ft", "cd").unwrap() - 2500.0).abs() < 1e-9
+        );
+        let lit = illuminance::from_intensity_at(
+            luminous_intensity::candela::new(2500.0),
+            length::feet::new(10.0),
+        )
+        .unwrap();
+        assert!((illuminance::foot_candles::value_of(lit) - 25.0).abs() < 1e-9);
+        let back = illuminance::to_intensity_at(lit, length::feet::new(10.0)).unwrap();
+        assert!((luminous_intensity::candela::value_of(back) - 2500.0).abs() < 1e-9);
+
+        // A zero beam or a zero or negative distance has no reciprocal
+        assert_eq!(
+            flux_to_intensity(100.0, "lm", 0.0, "sr", "cd")
+                .unwrap_err()
+                .kind(),
+            "no_reciprocal"
+        );
+        assert_eq!(
+            intensity_to_illuminance(100.0, "cd", 0.0, "m", "lx")
+                .unwrap_err()
+                .kind(),
+            "no_reciprocal"
+        );
+        assert_eq!(
+            intensity_to_illuminance(2500.0, "cd", -10.0, "ft", "fc"),
+            Err(ConversionError::NoReciprocal {
+                value: -10.0,
+                unit: "ft".to_string()
+            })
+        );
+        assert!(illuminance_to_intensity(25.0, "fc", -10.0, "ft", "cd").is_err());
+        assert!(illuminance::from_intensity_at(
+            luminous_intensity::candela::new(1.0),
+            length::meters::new(0.0)
+        )
+        .is_err());
+        assert!(illuminance::to_intensity_at(lit, length::meters::new(-1.0)).is_err());
+        assert!(intensity_to_flux(1.0, "lm", 1.0, "sr", "lm").is_err());
+        assert!(convert(1.0, "lx", "cd/m²").is_err());
+    }
+
+    #[test]
+    fn test_extended_temperature_scales() {
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+
+        // The boiling point of water on every scale
+        let boiling = [
+            ("C", 100.0),
+            ("F", 212.0),
+            ("K", 373.15),
+            ("°R", 671.67),
+            ("°Ré", 80.0),
+            ("°De", 0.0),
+            ("°N", 33.0),
+            ("°Rø", 60.0),
+        ];
+        for (from, expected_from) in boiling {
+            for (to, expected_to) in boiling {
+                let result = convert_temperature(expected_from, from, to).unwrap();
+                assert!(close(result, expected_to), "{} -> {}: {}", from, to, result);
+            }
+        }
+
+        // Delisle runs backwards and Rankine starts at absolute zero
+        assert!(close(convert_temperature(0.0, "C", "De").unwrap(), 150.0));
+        assert_eq!(convert_temperature(0.0, "Ra", "K").unwrap(), 0.0);
+        assert_eq!(convert_temperature(0.0, "K", "Ra").unwrap(), 0.0);
+        assert_eq!(convert_temperature(-459.67, "F", "Ra").unwrap(), 0.0);
+        assert!(close(
+            convert_temperature(0.0, "romer", "C").unwrap(),
+            -100.0 / 7.0
+        ));
+
+        // Every scale module goes through the Kelvin hub
+        assert!(close(temperature::rankine::to_kelvin(540.0), 300.0));
+        assert!(close(temperature::kelvin::to_reaumur(373.15), 80.0));
+        assert!(close(temperature::delisle::to_fahrenheit(150.0), 32.0));
+        assert!(close(temperature::newton::to_romer(33.0), 60.0));
+        assert!(close(temperature::fahrenheit::to_rankine(32.0), 491.67));
+        let body = temperature::romer::new(27.0);
+        assert!((temperature::celsius::value_of(body) - 37.142_857).abs() < 1e-6);
+
+        // Quantities order by temperature, not by the number on a reversed scale
+        assert!(temperature::delisle::new(0.0) > temperature::celsius::new(0.0));
+        assert!(temperature::delisle::new(10.0) < temperature::delisle::new(0.0));
+        assert!(temperature::delisle::new(0.0) == temperature::celsius::new(100.0));
+
+        // Newton the scale and newton the force are told apart by the other unit
+        assert!(close(convert(33.0, "N", "C").unwrap(), 100.0));
+        assert!(close(convert(1000.0, "N", "kN").unwrap(), 1.0));
+    }
+
+    #[test]
+    fn test_temperature_intervals() {
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+
+        // A rise is converted by the size of a degree, not through the zero point
+        assert!(close(
+            convert_temperature_interval(10.0, "ΔC", "ΔF").unwrap(),
+            18.0
+        ));
+        assert!(close(
+            convert_temperature_interval(18.0, "ΔF", "ΔK").unwrap(),
+            10.0
+        ));
+        assert!(close(
+            convert_temperature_interval(5.0, "ΔR", "ΔF").unwrap(),
+            5.0
+        ));
+        assert!(close(
+            convert_temperature_interval(1.0, "delta_K", "Δ°C").unwrap(),
+            1.0
+        ));
+
+        // Bare scales and the increment sign are read as intervals
+        assert!(close(
+            convert_temperature_interval(10.0, "C", "F").unwrap(),
+            18.0
+        ));
+        assert!(close(
+            convert_temperature_interval(9.0, "∆F", "Ra").unwrap(),
+            9.0
+        ));
+        assert!(close(convert(10.0, "ΔC", "ΔF").unwrap(), 18.0));
+        assert!(close(convert(10.0, "C", "F").unwrap(), 50.0));
+
+        // The delta modules
+        assert!(close(
+            temperature::delta_celsius::to_delta_fahrenheit(10.0),
+            18.0
+        ));
+        assert!(close(temperature::delta_rankine::to_delta_kelvin(9.0), 5.0));
+
+        // Temperatures subtract into intervals, and intervals shift temperatures
+        let rise = temperature::celsius::new(25.0) - temperature::celsius::new(15.0);
+        assert_eq!(rise.unit(), temperature::delta_celsius::UNIT);
+        assert!(close(temperature::delta_fahrenheit::value_of(rise), 18.0));
+        let warmed = temperature::fahrenheit::new(68.0) + rise;
+        assert!(close(warmed.value(), 86.0));
+        let cooled = temperature::kelvin::new(300.0) - temperature::delta_fahrenheit::new(9.0);
+        assert!(close(cooled.value(), 295.0));
+        let gap = temperature::newton::new(33.0) - temperature::celsius::new(0.0);
+        assert_eq!(gap.unit(), temperature::delta_kelvin::UNIT);
+        assert!(close(gap.value(), 100.0));
+        let total = temperature::delta_kelvin::new(1.0) + temperature::delta_fahrenheit::new(9.0);
+        assert!(close(total.value(), 6.0));
+    }
+
+    #[test]
+    fn test_absolute_zero() {
+        // Absolute zero itself converts on every absolute and relative scale
+        assert_eq!(convert_temperature(-273.15, "C", "K").unwrap(), 0.0);
+        assert!(convert_temperature(-459.67, "F", "K").unwrap().abs() < 1e-9);
+        assert!(convert_temperature(0.0, "Ra", "C").is_ok());
+        assert!(convert_temperature(559.725, "De", "K").unwrap().abs() < 1e-9);
+
+        // Anything colder is rejected, whichever direction the scale runs
+        let error = convert_temperature(-500.0, "C", "K").unwrap_err();
+        assert_eq!(error.kind(), "below_absolute_zero");
+        assert_eq!(
+            error.to_string(),
+            "-500 C is below absolute zero (-273.15 C)"
+        );
+        assert!(matches!(
+            convert_temperature(-1.0, "K", "C"),
+            Err(ConversionError::BelowAbsoluteZero { .. })
+        ));
+        assert!(matches!(
+            convert_temperature(-460.0, "°F", "C"),
+            Err(ConversionError::BelowAbsoluteZero { absolute_zero, .. })
+                if absolute_zero == -459.67
+        ));
+        assert!(convert_temperature(600.0, "De", "C").is_err());
+        assert!(convert(-300.0, "C", "F").is_err());
+
+        // Lenient mode lets nonphysical values through
+        let kelvin = convert_temperature_lenient(-500.0, "C", "K").unwrap();
+        assert!((kelvin + 226.85).abs() < 1e-9);
+
+        // Intervals have no zero point to fall below
+        assert!(convert_temperature_interval(-500.0, "ΔC", "ΔK").is_ok());
+    }
+
+    #[test]
+    fn test_astronomical_and_microscopic_lengths() {
+        let close = |a: f64, b: f64| (a - b).abs() <= 1e-12 * b.abs();
+
+        // Microscopic lengths
+        assert!(close(convert_length(1.0, "μm", "nm").unwrap(), 1000.0));
+        assert!(close(convert_length(1.0, "micron", "m").unwrap(), 1e-6));
+        assert!(close(convert_length(1.0, "nm", "Å").unwrap(), 10.0));
+        assert!(close(convert_length(1.0, "Å", "pm").unwrap(), 100.0));
+        assert!(close(convert_length(1.0, "pm", "fm").unwrap(), 1000.0));
+        assert!(close(convert_length(2.0, "angstroms", "nm").unwrap(), 0.2));
+
+        // Astronomical lengths use the exact IAU and SI definitions
+        assert_eq!(convert_length(1.0, "au", "m").unwrap(), 149_597_870_700.0);
+        assert_eq!(
+            convert_length(1.0, "ly", "m").unwrap(),
+            9_460_730_472_580_800.0
+        );
+        assert_eq!(convert_length(1.0, "ls", "m").unwrap(), 299_792_458.0);
+        assert!(close(
+            convert_length(1.0, "pc", "au").unwrap(),
+            648_000.0 / std::f64::consts::PI
+        ));
+        assert!(close(convert_length(1.0, "Mpc", "kpc").unwrap(), 1000.0));
+        assert!((convert_length(1.0, "pc", "ly").unwrap() - 3.261_564).abs() < 1e-6);
+        assert!((convert_length(1.0, "R☉", "R⊕").unwrap() - 109.076).abs() < 1e-3);
+        assert!((convert_length(1.0, "au", "ls").unwrap() - 499.004_784).abs() < 1e-6);
+
+        // The per-unit modules agree
+        assert!(close(
+            length::light_years::to_light_seconds(1.0),
+            31_557_600.0
+        ));
+        assert!(close(length::parsecs::to_kiloparsecs(1000.0), 1.0));
+        assert!(close(length::nanometers::to_angstroms(550.0), 5500.0));
+        let orbit = length::astronomical_units::new(1.0);
+        assert!(close(length::kilometers::value_of(orbit), 149_597_870.7));
+
+        // The CLI help and WASM unit lists advertise the new units
+        let symbols = UnitRegistry::global().symbols(Category::Length);
+        for symbol in ["μm", "nm", "Å", "au", "ly", "pc", "Mpc", "R⊕"] {
+            assert!(symbols.contains(&symbol), "{} is not listed", symbol);
+        }
+    }
+
+    #[test]
+    fn test_nautical_surveying_and_historical_lengths() {
+        let close = |a: f64, b: f64| (a - b).abs() <= 1e-12 * b.abs();
+
+        // Each unit is pinned to its legal definition in meters
+        let definitions = [
+            ("nmi", 1852.0),
+            ("cbl", 185.2),
+            ("ftm", 1.8288),
+            ("ch", 20.1168),
+            ("li", 0.201168),
+            ("rd", 5.0292),
+            ("fur", 201.168),
+            ("lea", 4828.032),
+            ("hh", 0.1016),
+            ("mil", 0.0000254),
+            ("ft_us", 1200.0 / 3937.0),
+            ("mi_us", 6_336_000.0 / 3937.0),
+        ];
+        for (unit, meters) in definitions {
+            let result = convert_length(1.0, unit, "m").unwrap();
+            assert!(close(result, meters), "{}: {}", unit, result);
+        }
+
+        // ... and to the units it is traditionally counted in
+        assert!(close(convert_length(1.0, "nmi", "cable").unwrap(), 10.0));
+        assert!(close(convert_length(1.0, "fathom", "ft").unwrap(), 6.0));
+        assert!(close(convert_length(1.0, "chain", "links").unwrap(), 100.0));
+        assert!(close(convert_length(1.0, "chain", "perches").unwrap(), 4.0));
+        assert!(close(
+            convert_length(1.0, "furlong", "chains").unwrap(),
+            10.0
+        ));
+        assert!(close(convert_length(1.0, "mi", "furlongs").unwrap(), 8.0));
+        assert!(close(convert_length(1.0, "league", "mi").unwrap(), 3.0));
+        assert!(close(convert_length(1.0, "hand", "in").unwrap(), 4.0));
+        assert!(close(convert_length(1.0, "in", "thou").unwrap(), 1000.0));
+        assert!(close(
+            convert_length(1.0, "mi_us", "ft_us").unwrap(),
+            5280.0
+        ));
+
+        // `nm` is the nanometer; `nmi` and `NM` the nautical mile
+        assert!(close(convert_length(1.0, "nm", "m").unwrap(), 1e-9));
+        assert!(close(convert_length(1.0, "NM", "m").unwrap(), 1852.0));
+        assert!(matches!(
+            convert_length(1.0, "Nm", "m"),
+            Err(ConversionError::AmbiguousUnit { .. })
+        ));
+
+        // The survey foot is two parts per million longer than the international foot
+        let survey = convert_length(1.0, "ft_us", "ft").unwrap();
+        assert!(close(survey, 1.000_002_000_004));
+        assert_eq!(length::feet::to_meters(1.0), 0.3048);
+
+        // The per-unit modules agree
+        assert!(close(length::furlongs::to_rods(1.0), 40.0));
+        assert!(close(length::nautical_miles::to_kilometers(1.0), 1.852));
+        assert!(close(
+            length::us_survey_miles::to_miles(1.0),
+            1.000_002_000_004
+        ));
+        let depth = length::fathoms::new(10.0);
+        assert!(close(length::meters::value_of(depth), 18.288));
+    }
+
     #[test]
     fn test_invalid_units() {
         // Test error handling for invalid units
@@ -383,5 +1900,31 @@ mod tests {
         assert!(convert_amount(0.5, "mol", "invalid").is_err());
         assert!(convert_luminous_intensity(1.0, "cd", "invalid").is_err());
         assert!(convert_area(100.0, "m²", "invalid").is_err());
+        assert!(convert_speed(100.0, "km/h", "invalid").is_err());
+        assert!(convert_pressure(1.0, "atm", "invalid").is_err());
+        assert!(convert_energy(1.0, "J", "invalid").is_err());
+        assert!(convert_power(1.0, "W", "invalid").is_err());
+        assert!(convert_force(1.0, "N", "invalid").is_err());
+        assert!(convert_torque(1.0, "N·m", "invalid").is_err());
+        assert!(convert_frequency(1.0, "Hz", "invalid").is_err());
+        assert!(convert_angle(1.0, "°", "invalid").is_err());
+        assert!(convert_solid_angle(1.0, "sr", "invalid").is_err());
+        assert!(convert_data(1.0, "B", "invalid").is_err());
+        assert!(convert_data_rate(1.0, "bit/s", "invalid").is_err());
+        assert!(convert_density(1.0, "kg/m³", "invalid").is_err());
+        assert!(convert_flow_rate(1.0, "L/s", "invalid").is_err());
+        assert!(convert_mass_flow_rate(1.0, "kg/s", "invalid").is_err());
+        assert!(convert_voltage(1.0, "V", "invalid").is_err());
+        assert!(convert_resistance(1.0, "Ω", "invalid").is_err());
+        assert!(convert_conductance(1.0, "S", "invalid").is_err());
+        assert!(convert_charge(1.0, "C", "invalid").is_err());
+        assert!(convert_capacitance(1.0, "F", "invalid").is_err());
+        assert!(convert_inductance(1.0, "H", "invalid").is_err());
+        assert!(convert_luminous_flux(1.0, "lm", "invalid").is_err());
+        assert!(convert_illuminance(1.0, "lx", "invalid").is_err());
+        assert!(convert_luminance(1.0, "cd/m²", "invalid").is_err());
+        assert!(convert_temperature_interval(1.0, "ΔC", "invalid").is_err());
+        assert!(convert_length(1.0, "ly", "invalid").is_err());
+        assert!(convert_length(1.0, "nmi", "invalid").is_err());
     }
 }
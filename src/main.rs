@@ -1,8 +1,7 @@
 #![allow(dead_code)]
-mod conversions;
 
-use clap::{Parser, Subcommand};
-use conversions::*;
+use clap::{Parser, Subcommand, ValueEnum};
+use conversions_rs::conversions::*;
 use std::io::{self, Write};
 
 #[derive(Parser)]
@@ -16,89 +15,393 @@ struct Args {
 
 #[derive(Subcommand)]
 enum Commands {
+    /// Convert between any two units, inferring the category from the units
+    Convert {
+        /// Value to convert
+        #[arg(allow_negative_numbers = true)]
+        value: f64,
+        /// Source unit (any supported unit, e.g. ft, mA, °C)
+        from: String,
+        /// Target unit (must measure the same kind of quantity as the source)
+        to: String,
+    },
     /// Convert length units
     Length {
         /// Value to convert
+        #[arg(allow_negative_numbers = true)]
         value: f64,
-        /// Source unit (m, km, cm, mm, ft, in, yd, mi)
+        #[arg(help = unit_help("Source", Category::Length))]
         from: String,
-        /// Target unit (m, km, cm, mm, ft, in, yd, mi)
+        #[arg(help = unit_help("Target", Category::Length))]
         to: String,
     },
     /// Convert weight/mass units
     Weight {
         /// Value to convert
+        #[arg(allow_negative_numbers = true)]
         value: f64,
-        /// Source unit (kg, g, lb, oz, t, st)
+        #[arg(help = unit_help("Source", Category::Mass))]
         from: String,
-        /// Target unit (kg, g, lb, oz, t, st)
+        #[arg(help = unit_help("Target", Category::Mass))]
         to: String,
     },
     /// Convert temperature units
     Temperature {
         /// Value to convert
+        #[arg(allow_negative_numbers = true)]
         value: f64,
-        /// Source unit (C, F, K)
+        #[arg(help = unit_help("Source", Category::Temperature))]
         from: String,
-        /// Target unit (C, F, K)
+        #[arg(help = unit_help("Target", Category::Temperature))]
         to: String,
+        /// Treat the value as a temperature difference, e.g. a 10 °C rise
+        #[arg(long)]
+        delta: bool,
+        /// Allow values below absolute zero instead of rejecting them
+        #[arg(long, conflicts_with = "delta")]
+        lenient: bool,
     },
     /// Convert volume units
     Volume {
         /// Value to convert
+        #[arg(allow_negative_numbers = true)]
         value: f64,
-        /// Source unit (l, ml, gal, gal_uk, fl_oz, fl_oz_uk, cup, pt, qt)
+        #[arg(help = unit_help("Source", Category::Volume))]
         from: String,
-        /// Target unit (l, ml, gal, gal_uk, fl_oz, fl_oz_uk, cup, pt, qt)
+        #[arg(help = unit_help("Target", Category::Volume))]
         to: String,
     },
     /// Convert time units
     Time {
         /// Value to convert
+        #[arg(allow_negative_numbers = true)]
         value: f64,
-        /// Source unit (s, min, h, day, week, year, ms, μs, ns)
+        #[arg(help = unit_help("Source", Category::Time))]
         from: String,
-        /// Target unit (s, min, h, day, week, year, ms, μs, ns)
+        #[arg(help = unit_help("Target", Category::Time))]
         to: String,
     },
     /// Convert electric current units
     Current {
         /// Value to convert
+        #[arg(allow_negative_numbers = true)]
         value: f64,
-        /// Source unit (A, mA, μA, nA, kA)
+        #[arg(help = unit_help("Source", Category::Current))]
         from: String,
-        /// Target unit (A, mA, μA, nA, kA)
+        #[arg(help = unit_help("Target", Category::Current))]
         to: String,
     },
     /// Convert amount of substance units
     Amount {
         /// Value to convert
+        #[arg(allow_negative_numbers = true)]
         value: f64,
-        /// Source unit (mol, mmol, μmol, nmol, pmol, kmol)
+        #[arg(help = unit_help("Source", Category::Amount))]
         from: String,
-        /// Target unit (mol, mmol, μmol, nmol, pmol, kmol)
+        #[arg(help = unit_help("Target", Category::Amount))]
         to: String,
     },
     /// Convert luminous intensity units
     Luminosity {
         /// Value to convert
+        #[arg(allow_negative_numbers = true)]
         value: f64,
-        /// Source unit (cd, mcd, kcd, hk, ic, dc)
+        #[arg(help = unit_help("Source", Category::LuminousIntensity))]
         from: String,
-        /// Target unit (cd, mcd, kcd, hk, ic, dc)
+        #[arg(help = unit_help("Target", Category::LuminousIntensity))]
         to: String,
     },
     /// Convert area units
     Area {
         /// Value to convert
+        #[arg(allow_negative_numbers = true)]
+        value: f64,
+        #[arg(help = unit_help("Source", Category::Area))]
+        from: String,
+        #[arg(help = unit_help("Target", Category::Area))]
+        to: String,
+    },
+    /// Convert speed units
+    Speed {
+        /// Value to convert
+        #[arg(allow_negative_numbers = true)]
+        value: f64,
+        #[arg(help = unit_help("Source", Category::Speed))]
+        from: String,
+        #[arg(help = unit_help("Target", Category::Speed))]
+        to: String,
+    },
+    /// Convert pressure units
+    Pressure {
+        /// Value to convert
+        #[arg(allow_negative_numbers = true)]
+        value: f64,
+        #[arg(help = unit_help("Source", Category::Pressure))]
+        from: String,
+        #[arg(help = unit_help("Target", Category::Pressure))]
+        to: String,
+    },
+    /// Convert energy units
+    Energy {
+        /// Value to convert
+        #[arg(allow_negative_numbers = true)]
+        value: f64,
+        #[arg(help = unit_help("Source", Category::Energy))]
+        from: String,
+        #[arg(help = unit_help("Target", Category::Energy))]
+        to: String,
+    },
+    /// Convert power units
+    Power {
+        /// Value to convert
+        #[arg(allow_negative_numbers = true)]
+        value: f64,
+        #[arg(help = unit_help("Source", Category::Power))]
+        from: String,
+        #[arg(help = unit_help("Target", Category::Power))]
+        to: String,
+    },
+    /// Convert force units
+    Force {
+        /// Value to convert
+        #[arg(allow_negative_numbers = true)]
+        value: f64,
+        #[arg(help = unit_help("Source", Category::Force))]
+        from: String,
+        #[arg(help = unit_help("Target", Category::Force))]
+        to: String,
+    },
+    /// Convert torque units
+    Torque {
+        /// Value to convert
+        #[arg(allow_negative_numbers = true)]
+        value: f64,
+        #[arg(help = unit_help("Source", Category::Torque))]
+        from: String,
+        #[arg(help = unit_help("Target", Category::Torque))]
+        to: String,
+    },
+    /// Convert frequency units (Hz, kHz, MHz, GHz, THz, rpm, rad/s, cpm, cph)
+    Frequency {
+        /// Value to convert
+        #[arg(allow_negative_numbers = true)]
+        value: f64,
+        #[arg(help = unit_help("Source", Category::Frequency))]
+        from: String,
+        #[arg(help = unit_help("Target", Category::Frequency))]
+        to: String,
+    },
+    /// Convert plane angle units (°, rad, gon, tr, arcmin, arcsec, mrad, mil)
+    Angle {
+        /// Value to convert
+        #[arg(allow_negative_numbers = true)]
+        value: f64,
+        #[arg(help = unit_help("Source", Category::Angle))]
+        from: String,
+        #[arg(help = unit_help("Target", Category::Angle))]
+        to: String,
+        /// Wrap the result into a single turn
+        #[arg(long, value_enum)]
+        normalize: Option<Normalization>,
+        /// Print the result as degrees, minutes and seconds
+        #[arg(long)]
+        dms: bool,
+    },
+    /// Convert solid angle units (sr, deg², sp)
+    SolidAngle {
+        /// Value to convert
+        #[arg(allow_negative_numbers = true)]
+        value: f64,
+        #[arg(help = unit_help("Source", Category::SolidAngle))]
+        from: String,
+        #[arg(help = unit_help("Target", Category::SolidAngle))]
+        to: String,
+    },
+    /// Convert digital storage units (bit, B, kB, MB, GB, KiB, MiB, GiB, ...)
+    Data {
+        /// Value to convert
+        #[arg(allow_negative_numbers = true)]
         value: f64,
-        /// Source unit (m², cm², km², ft², in², ac, ha, mi²)
+        #[arg(help = unit_help("Source", Category::Data))]
         from: String,
-        /// Target unit (m², cm², km², ft², in², ac, ha, mi²)
+        #[arg(
+            help = unit_help("Target", Category::Data),
+            required_unless_present = "best"
+        )]
+        to: Option<String>,
+        /// Print the value in the largest byte unit of a prefix convention
+        /// instead of a chosen target unit
+        #[arg(long, value_enum, conflicts_with = "to")]
+        best: Option<Prefixes>,
+    },
+    /// Convert data rate units (bit/s, Mbit/s, Gbit/s, MB/s, MiB/s, ...)
+    DataRate {
+        /// Value to convert
+        #[arg(allow_negative_numbers = true)]
+        value: f64,
+        #[arg(help = unit_help("Source", Category::DataRate))]
+        from: String,
+        #[arg(help = unit_help("Target", Category::DataRate))]
+        to: String,
+    },
+    /// Convert density units (kg/m³, g/cm³, lb/ft³, lb/gal, SG, °API, °Bé, ...)
+    Density {
+        /// Value to convert
+        #[arg(allow_negative_numbers = true)]
+        value: f64,
+        #[arg(help = unit_help("Source", Category::Density))]
+        from: String,
+        #[arg(help = unit_help("Target", Category::Density))]
+        to: String,
+    },
+    /// Convert volumetric flow rate units (L/s, L/min, m³/h, gpm, CFM, bbl/d, ...)
+    FlowRate {
+        /// Value to convert
+        #[arg(allow_negative_numbers = true)]
+        value: f64,
+        #[arg(help = unit_help("Source", Category::FlowRate))]
+        from: String,
+        #[arg(help = unit_help("Target", Category::FlowRate))]
+        to: String,
+    },
+    /// Convert mass flow rate units (kg/s, kg/h, g/s, t/h, lb/s, lb/h)
+    MassFlowRate {
+        /// Value to convert
+        #[arg(allow_negative_numbers = true)]
+        value: f64,
+        #[arg(help = unit_help("Source", Category::MassFlowRate))]
+        from: String,
+        #[arg(help = unit_help("Target", Category::MassFlowRate))]
+        to: String,
+    },
+    /// Convert voltage units
+    Voltage {
+        /// Value to convert
+        #[arg(allow_negative_numbers = true)]
+        value: f64,
+        #[arg(help = unit_help("Source", Category::Voltage))]
+        from: String,
+        #[arg(help = unit_help("Target", Category::Voltage))]
+        to: String,
+    },
+    /// Convert electrical resistance units
+    Resistance {
+        /// Value to convert
+        #[arg(allow_negative_numbers = true)]
+        value: f64,
+        #[arg(help = unit_help("Source", Category::Resistance))]
+        from: String,
+        #[arg(help = unit_help("Target", Category::Resistance))]
+        to: String,
+    },
+    /// Convert electrical conductance units
+    Conductance {
+        /// Value to convert
+        #[arg(allow_negative_numbers = true)]
+        value: f64,
+        #[arg(help = unit_help("Source", Category::Conductance))]
+        from: String,
+        #[arg(help = unit_help("Target", Category::Conductance))]
+        to: String,
+    },
+    /// Convert electric charge units
+    Charge {
+        /// Value to convert
+        #[arg(allow_negative_numbers = true)]
+        value: f64,
+        #[arg(help = unit_help("Source", Category::Charge))]
+        from: String,
+        #[arg(help = unit_help("Target", Category::Charge))]
+        to: String,
+    },
+    /// Convert capacitance units
+    Capacitance {
+        /// Value to convert
+        #[arg(allow_negative_numbers = true)]
+        value: f64,
+        #[arg(help = unit_help("Source", Category::Capacitance))]
+        from: String,
+        #[arg(help = unit_help("Target", Category::Capacitance))]
+        to: String,
+    },
+    /// Convert inductance units
+    Inductance {
+        /// Value to convert
+        #[arg(allow_negative_numbers = true)]
+        value: f64,
+        #[arg(help = unit_help("Source", Category::Inductance))]
+        from: String,
+        #[arg(help = unit_help("Target", Category::Inductance))]
+        to: String,
+    },
+    /// Convert luminous flux units
+    LuminousFlux {
+        /// Value to convert
+        #[arg(allow_negative_numbers = true)]
+        value: f64,
+        #[arg(help = unit_help("Source", Category::LuminousFlux))]
+        from: String,
+        #[arg(help = unit_help("Target", Category::LuminousFlux))]
+        to: String,
+    },
+    /// Convert illuminance units
+    Illuminance {
+        /// Value to convert
+        #[arg(allow_negative_numbers = true)]
+        value: f64,
+        #[arg(help = unit_help("Source", Category::Illuminance))]
+        from: String,
+        #[arg(help = unit_help("Target", Category::Illuminance))]
+        to: String,
+    },
+    /// Convert luminance units
+    Luminance {
+        /// Value to convert
+        #[arg(allow_negative_numbers = true)]
+        value: f64,
+        #[arg(help = unit_help("Source", Category::Luminance))]
+        from: String,
+        #[arg(help = unit_help("Target", Category::Luminance))]
         to: String,
     },
 }
 
+/// The turn an angle is wrapped into by `angle --normalize`.
+#[derive(Clone, Copy, ValueEnum)]
+enum Normalization {
+    /// [0°, 360°)
+    Positive,
+    /// (-180°, 180°]
+    Signed,
+}
+
+impl From<Normalization> for AngleRange {
+    fn from(normalization: Normalization) -> Self {
+        match normalization {
+            Normalization::Positive => AngleRange::Positive,
+            Normalization::Signed => AngleRange::Signed,
+        }
+    }
+}
+
+/// The prefixes `data --best` picks a unit from.
+#[derive(Clone, Copy, ValueEnum)]
+enum Prefixes {
+    /// kB, MB, GB, ... (powers of 1000)
+    Decimal,
+    /// KiB, MiB, GiB, ... (powers of 1024)
+    Binary,
+}
+
+impl From<Prefixes> for PrefixConvention {
+    fn from(prefixes: Prefixes) -> Self {
+        match prefixes {
+            Prefixes::Decimal => PrefixConvention::Decimal,
+            Prefixes::Binary => PrefixConvention::Binary,
+        }
+    }
+}
+
 fn main() {
     let args = Args::parse();
 
@@ -108,82 +411,230 @@ fn main() {
     }
 }
 
+/// Builds the help text for a unit argument from the units in the registry.
+fn unit_help(role: &str, category: Category) -> String {
+    format!(
+        "{} unit ({})",
+        role,
+        UnitRegistry::global().symbols(category).join(", ")
+    )
+}
+
 fn handle_cli_command(command: Commands) {
-    match command {
-        Commands::Length { value, from, to } => match convert_length(value, &from, &to) {
-            Ok(result) => println!("{} {} = {:.6} {}", value, from, result, to),
-            Err(error) => {
-                eprintln!("❌ Error: {}", error);
-                std::process::exit(1);
-            }
-        },
-        Commands::Weight { value, from, to } => match convert_weight(value, &from, &to) {
-            Ok(result) => println!("{} {} = {:.6} {}", value, from, result, to),
-            Err(error) => {
-                eprintln!("❌ Error: {}", error);
-                std::process::exit(1);
-            }
-        },
-        Commands::Temperature { value, from, to } => match convert_temperature(value, &from, &to) {
-            Ok(result) => println!(
-                "{}°{} = {:.2}°{}",
-                value,
-                from.to_uppercase(),
-                result,
-                to.to_uppercase()
-            ),
-            Err(error) => {
-                eprintln!("❌ Error: {}", error);
-                std::process::exit(1);
-            }
-        },
-        Commands::Volume { value, from, to } => match convert_volume(value, &from, &to) {
-            Ok(result) => println!("{} {} = {:.6} {}", value, from, result, to),
-            Err(error) => {
-                eprintln!("❌ Error: {}", error);
-                std::process::exit(1);
-            }
-        },
-        Commands::Time { value, from, to } => match convert_time(value, &from, &to) {
-            Ok(result) => println!("{} {} = {:.6} {}", value, from, result, to),
-            Err(error) => {
-                eprintln!("❌ Error: {}", error);
-                std::process::exit(1);
-            }
-        },
-        Commands::Current { value, from, to } => match convert_current(value, &from, &to) {
-            Ok(result) => println!("{} {} = {:.6} {}", value, from, result, to),
-            Err(error) => {
-                eprintln!("❌ Error: {}", error);
-                std::process::exit(1);
-            }
-        },
-        Commands::Amount { value, from, to } => match convert_amount(value, &from, &to) {
-            Ok(result) => println!("{} {} = {:.6} {}", value, from, result, to),
-            Err(error) => {
-                eprintln!("❌ Error: {}", error);
-                std::process::exit(1);
-            }
-        },
-        Commands::Luminosity { value, from, to } => {
-            match convert_luminous_intensity(value, &from, &to) {
-                Ok(result) => println!("{} {} = {:.6} {}", value, from, result, to),
-                Err(error) => {
-                    eprintln!("❌ Error: {}", error);
-                    std::process::exit(1);
-                }
+    let (category, value, from, to) = match command {
+        Commands::Convert { value, from, to } => {
+            match UnitRegistry::global().infer_category(&from, &to) {
+                Ok(category) => (category, value, from, to),
+                Err(error) => exit_with_error(&error),
             }
         }
-        Commands::Area { value, from, to } => match convert_area(value, &from, &to) {
-            Ok(result) => println!("{} {} = {:.6} {}", value, from, result, to),
-            Err(error) => {
-                eprintln!("❌ Error: {}", error);
-                std::process::exit(1);
-            }
-        },
+        Commands::Length { value, from, to } => (Category::Length, value, from, to),
+        Commands::Weight { value, from, to } => (Category::Mass, value, from, to),
+        Commands::Temperature {
+            value,
+            from,
+            to,
+            delta,
+            lenient,
+        } => return convert_temperature_command(value, &from, &to, delta, lenient),
+        Commands::Volume { value, from, to } => (Category::Volume, value, from, to),
+        Commands::Time { value, from, to } => (Category::Time, value, from, to),
+        Commands::Current { value, from, to } => (Category::Current, value, from, to),
+        Commands::Amount { value, from, to } => (Category::Amount, value, from, to),
+        Commands::Luminosity { value, from, to } => (Category::LuminousIntensity, value, from, to),
+        Commands::Area { value, from, to } => (Category::Area, value, from, to),
+        Commands::Speed { value, from, to } => (Category::Speed, value, from, to),
+        Commands::Pressure { value, from, to } => (Category::Pressure, value, from, to),
+        Commands::Energy { value, from, to } => (Category::Energy, value, from, to),
+        Commands::Power { value, from, to } => (Category::Power, value, from, to),
+        Commands::Force { value, from, to } => (Category::Force, value, from, to),
+        Commands::Torque { value, from, to } => (Category::Torque, value, from, to),
+        Commands::Frequency { value, from, to } => (Category::Frequency, value, from, to),
+        Commands::Angle {
+            value,
+            from,
+            to,
+            normalize,
+            dms,
+        } => return convert_angle_command(value, &from, &to, normalize, dms),
+        Commands::SolidAngle { value, from, to } => (Category::SolidAngle, value, from, to),
+        Commands::Data {
+            value,
+            from,
+            to: Some(to),
+            ..
+        } => (Category::Data, value, from, to),
+        // clap only allows a missing target unit together with `--best`
+        Commands::Data {
+            value, from, best, ..
+        } => return best_data_unit_command(value, &from, best.unwrap_or(Prefixes::Binary)),
+        Commands::DataRate { value, from, to } => (Category::DataRate, value, from, to),
+        Commands::Density { value, from, to } => (Category::Density, value, from, to),
+        Commands::FlowRate { value, from, to } => (Category::FlowRate, value, from, to),
+        Commands::MassFlowRate { value, from, to } => (Category::MassFlowRate, value, from, to),
+        Commands::Voltage { value, from, to } => (Category::Voltage, value, from, to),
+        Commands::Resistance { value, from, to } => (Category::Resistance, value, from, to),
+        Commands::Conductance { value, from, to } => (Category::Conductance, value, from, to),
+        Commands::Charge { value, from, to } => (Category::Charge, value, from, to),
+        Commands::Capacitance { value, from, to } => (Category::Capacitance, value, from, to),
+        Commands::Inductance { value, from, to } => (Category::Inductance, value, from, to),
+        Commands::LuminousFlux { value, from, to } => (Category::LuminousFlux, value, from, to),
+        Commands::Illuminance { value, from, to } => (Category::Illuminance, value, from, to),
+        Commands::Luminance { value, from, to } => (Category::Luminance, value, from, to),
+    };
+
+    match convert_in(value, &from, &to, category) {
+        Ok(result) => println!("{}", format_result(category, value, &from, result, &to)),
+        Err(error) => exit_with_error(&error),
+    }
+}
+
+/// Runs `angle`, which can wrap the result into a single turn and print it as
+/// degrees, minutes and seconds.
+fn convert_angle_command(
+    value: f64,
+    from: &str,
+    to: &str,
+    normalize: Option<Normalization>,
+    dms: bool,
+) {
+    let converted = convert_angle(value, from, to).and_then(|result| match normalize {
+        Some(range) => normalize_angle(result, to, range.into()),
+        None => Ok(result),
+    });
+    let result = converted.unwrap_or_else(|error| exit_with_error(&error));
+
+    if dms {
+        let degrees =
+            convert_angle(result, to, "°").unwrap_or_else(|error| exit_with_error(&error));
+        println!("{} {} = {}", value, from, format_dms(degrees, 2));
+    } else {
+        println!(
+            "{}",
+            format_result(Category::Angle, value, from, result, to)
+        );
+    }
+}
+
+/// Runs `temperature`, which can convert a difference instead of a reading
+/// (`--delta`) and let readings below absolute zero through (`--lenient`).
+fn convert_temperature_command(value: f64, from: &str, to: &str, delta: bool, lenient: bool) {
+    let (category, converted) = if delta {
+        (
+            Category::TemperatureInterval,
+            convert_temperature_interval(value, from, to),
+        )
+    } else if lenient {
+        (
+            Category::Temperature,
+            convert_temperature_lenient(value, from, to),
+        )
+    } else {
+        (Category::Temperature, convert_temperature(value, from, to))
+    };
+
+    match converted {
+        Ok(result) => println!("{}", format_result(category, value, from, result, to)),
+        Err(error) => exit_with_error(&error),
+    }
+}
+
+/// Runs `data --best`, which prints the value in the largest byte unit of a
+/// prefix convention, e.g. `1536000 B = 1.46 MiB`.
+fn best_data_unit_command(value: f64, from: &str, prefixes: Prefixes) {
+    match format_data(value, from, prefixes.into(), 2) {
+        Ok(formatted) => println!("{} {} = {}", value, from, formatted),
+        Err(error) => exit_with_error(&error),
+    }
+}
+
+/// Formats a successful conversion, e.g. `100 ft = 30.48 m`, `32°F = 0.00°C`
+/// or `10 ΔC = 18.00 ΔF`.
+fn format_result(category: Category, value: f64, from: &str, result: f64, to: &str) -> String {
+    if category == Category::Temperature {
+        format!(
+            "{}{} = {:.2}{}",
+            value,
+            temperature_symbol(from),
+            result,
+            temperature_symbol(to)
+        )
+    } else if category == Category::TemperatureInterval {
+        format!(
+            "{} {} = {:.2} {}",
+            value,
+            interval_spelling(from),
+            result,
+            interval_spelling(to)
+        )
+    } else {
+        format!("{} {} = {} {}", value, from, format_significant(result), to)
     }
 }
 
+/// The number of significant digits conversion results are printed with.
+const SIGNIFICANT_DIGITS: i32 = 10;
+
+/// Formats a result to [`SIGNIFICANT_DIGITS`] significant digits without
+/// trailing zeros, e.g. `30.48` or `0.0254`; values outside 1e-4..1e15 are
+/// written in scientific notation, e.g. `1.602176634e-19`.
+fn format_significant(value: f64) -> String {
+    if value == 0.0 || !value.is_finite() {
+        return format!("{}", value.abs());
+    }
+
+    let magnitude = value.abs();
+    let formatted = if (1e-4..1e15).contains(&magnitude) {
+        let decimals = (SIGNIFICANT_DIGITS - 1 - magnitude.log10().floor() as i32).max(0);
+        format!("{:.*}", decimals as usize, value)
+    } else {
+        format!("{:.*e}", (SIGNIFICANT_DIGITS - 1) as usize, value)
+    };
+
+    match formatted.split_once('e') {
+        Some((mantissa, exponent)) => format!("{}e{}", trim_fraction(mantissa), exponent),
+        None => trim_fraction(&formatted).to_string(),
+    }
+}
+
+/// Drops trailing zeros after the decimal point, and the point itself if
+/// nothing is left after it.
+fn trim_fraction(number: &str) -> &str {
+    if number.contains('.') {
+        number.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        number
+    }
+}
+
+/// Reports a failed conversion on stderr and exits with a code that identifies
+/// the kind of failure, so scripts can tell bad units from bad values.
+fn exit_with_error(error: &ConversionError) -> ! {
+    eprintln!("❌ Error [{}]: {}", error.kind(), error);
+    if let ConversionError::BelowAbsoluteZero { .. } = error {
+        eprintln!("   Use `temperature --lenient` to convert it anyway.");
+    }
+    let code = match error {
+        ConversionError::UnknownUnit { .. }
+        | ConversionError::UnrecognizedUnit { .. }
+        | ConversionError::AmbiguousUnit { .. }
+        | ConversionError::InexactUnit { .. } => 2,
+        ConversionError::IncompatibleDimensions { .. } => 3,
+        ConversionError::NonFiniteInput { .. }
+        | ConversionError::InvalidNumber { .. }
+        | ConversionError::BelowAbsoluteZero { .. }
+        | ConversionError::BelowVacuum { .. }
+        | ConversionError::NonPositivePower { .. }
+        | ConversionError::InvalidAngle { .. }
+        | ConversionError::NonPositiveDensity { .. }
+        | ConversionError::NoReciprocal { .. }
+        | ConversionError::NotSubtractable { .. } => 4,
+        // The error enum is non-exhaustive, so kinds added later fail generically
+        _ => 1,
+    };
+    std::process::exit(code);
+}
+
 fn run_interactive_mode() {
     println!("🔄 Unit Conversion App");
     println!("======================");
@@ -191,27 +642,30 @@ fn run_interactive_mode() {
     loop {
         display_menu();
 
-        print!("\nEnter your choice (1-9): ");
+        print!("\nEnter your choice (1-{}): ", Category::ALL.len());
         io::stdout().flush().unwrap();
 
         let mut input = String::new();
         io::stdin().read_line(&mut input).unwrap();
 
-        match input.trim() {
-            "1" => handle_length_conversion(),
-            "2" => handle_weight_conversion(),
-            "3" => handle_temperature_conversion(),
-            "4" => handle_volume_conversion(),
-            "5" => handle_time_conversion(),
-            "6" => handle_current_conversion(),
-            "7" => handle_amount_conversion(),
-            "8" => handle_luminosity_conversion(),
-            "9" => handle_area_conversion(),
-            "0" => {
-                println!("Thanks for using the Unit Conversion App! 👋");
-                break;
-            }
-            _ => println!("❌ Invalid choice. Please select 0-9."),
+        let choice = input.trim();
+        if choice == "0" {
+            println!("Thanks for using the Unit Conversion App! 👋");
+            break;
+        }
+
+        let category = choice
+            .parse::<usize>()
+            .ok()
+            .and_then(|number| number.checked_sub(1))
+            .and_then(|index| Category::ALL.get(index));
+
+        match category {
+            Some(&category) => handle_conversion(category),
+            None => println!(
+                "❌ Invalid choice. Please select 0-{}.",
+                Category::ALL.len()
+            ),
         }
 
         println!("\n{}", "-".repeat(50));
@@ -220,18 +674,65 @@ fn run_interactive_mode() {
 
 fn display_menu() {
     println!("\nChoose conversion type:");
-    println!("1. 📏 Length");
-    println!("2. ⚖️  Weight/Mass");
-    println!("3. 🌡️  Temperature");
-    println!("4. 🧪 Volume");
-    println!("5. ⏱️  Time");
-    println!("6. ⚡ Electric Current");
-    println!("7. 🧬 Amount of Substance");
-    println!("8. 💡 Luminous Intensity");
-    println!("9. 📐 Area");
+    for (index, category) in Category::ALL.iter().enumerate() {
+        println!("{}. {}", index + 1, menu_label(*category));
+    }
     println!("0. 🚪 Exit");
 }
 
+/// Label shown for a category in the interactive menu.
+fn menu_label(category: Category) -> &'static str {
+    match category {
+        Category::Length => "📏 Length",
+        Category::Mass => "⚖️  Weight/Mass",
+        Category::Temperature => "🌡️  Temperature",
+        Category::Volume => "🧪 Volume",
+        Category::Time => "⏱️  Time",
+        Category::Current => "⚡ Electric Current",
+        Category::Amount => "🧬 Amount of Substance",
+        Category::LuminousIntensity => "💡 Luminous Intensity",
+        Category::Area => "📐 Area",
+        Category::Speed => "🚀 Speed",
+        Category::Pressure => "🧭 Pressure",
+        Category::Energy => "🔋 Energy",
+        Category::Power => "🔌 Power",
+        Category::Force => "💪 Force",
+        Category::Torque => "🔧 Torque",
+        Category::Frequency => "〰️  Frequency",
+        Category::Angle => "🎯 Angle",
+        Category::SolidAngle => "🌐 Solid Angle",
+        Category::Data => "💾 Data",
+        Category::DataRate => "📶 Data Rate",
+        Category::Density => "🛢️  Density",
+        Category::FlowRate => "🚰 Flow Rate",
+        Category::MassFlowRate => "🏭 Mass Flow Rate",
+        Category::Voltage => "🔦 Voltage",
+        Category::Resistance => "🚧 Resistance",
+        Category::Conductance => "🔗 Conductance",
+        Category::Charge => "🎈 Electric Charge",
+        Category::Capacitance => "📥 Capacitance",
+        Category::Inductance => "🧲 Inductance",
+        Category::LuminousFlux => "✨ Luminous Flux",
+        Category::Illuminance => "☀️  Illuminance",
+        Category::Luminance => "📺 Luminance",
+        Category::TemperatureInterval => "📈 Temperature Interval",
+    }
+}
+
+/// Returns how a temperature scale is written after a reading, e.g. `°F`,
+/// `°Rø` or `K`, whichever spelling of the scale was typed.
+fn temperature_symbol(unit: &str) -> &str {
+    match UnitRegistry::global().resolve(unit, Category::Temperature) {
+        Ok(scale) => scale
+            .aliases
+            .iter()
+            .find(|alias| alias.starts_with('°'))
+            .copied()
+            .unwrap_or(scale.symbol),
+        Err(_) => unit,
+    }
+}
+
 fn get_input(prompt: &str) -> String {
     print!("{}", prompt);
     io::stdout().flush().unwrap();
@@ -251,58 +752,38 @@ fn get_number(prompt: &str) -> f64 {
     }
 }
 
-fn handle_length_conversion() {
-    println!("\n📏 Length Conversion");
-    println!("Supported units: m, km, cm, mm, ft, in, yd, mi");
+fn handle_conversion(category: Category) {
+    let registry = UnitRegistry::global();
 
-    let value = get_number("Enter the value to convert: ");
-    let from_unit = get_input("From unit: ");
-    let to_unit = get_input("To unit: ");
+    println!("\n{} Conversion", menu_label(category));
+    println!("Supported units: {}", registry.symbols(category).join(", "));
 
-    match convert_length(value, &from_unit, &to_unit) {
-        Ok(result) => {
-            println!("✅ {} {} = {:.6} {}", value, from_unit, result, to_unit);
-        }
-        Err(error) => {
-            println!("❌ Error: {}", error);
-        }
-    }
-}
-
-fn handle_weight_conversion() {
-    println!("\n⚖️  Weight/Mass Conversion");
-    println!("Supported units: kg, g, lb, oz, t, st");
-
-    let value = get_number("Enter the value to convert: ");
+    let prompt = if category == Category::Temperature {
+        "Enter the temperature to convert: "
+    } else if category == Category::TemperatureInterval {
+        "Enter the temperature difference to convert: "
+    } else {
+        "Enter the value to convert: "
+    };
+    let value = get_number(prompt);
     let from_unit = get_input("From unit: ");
     let to_unit = get_input("To unit: ");
 
-    match convert_weight(value, &from_unit, &to_unit) {
-        Ok(result) => {
-            println!("✅ {} {} = {:.6} {}", value, from_unit, result, to_unit);
-        }
-        Err(error) => {
-            println!("❌ Error: {}", error);
+    let mut converted = convert_in(value, &from_unit, &to_unit, category);
+    if let Err(error @ ConversionError::BelowAbsoluteZero { .. }) = &converted {
+        println!("❌ Error: {}", error);
+        let answer = get_input("Convert it anyway, as a nonphysical value? (y/N): ");
+        if !answer.eq_ignore_ascii_case("y") {
+            return;
         }
+        converted = convert_temperature_lenient(value, &from_unit, &to_unit);
     }
-}
-
-fn handle_temperature_conversion() {
-    println!("\n🌡️  Temperature Conversion");
-    println!("Supported units: C (Celsius), F (Fahrenheit), K (Kelvin)");
 
-    let value = get_number("Enter the temperature to convert: ");
-    let from_unit = get_input("From unit: ");
-    let to_unit = get_input("To unit: ");
-
-    match convert_temperature(value, &from_unit, &to_unit) {
+    match converted {
         Ok(result) => {
             println!(
-                "✅ {}°{} = {:.2}°{}",
-                value,
-                from_unit.to_uppercase(),
-                result,
-                to_unit.to_uppercase()
+                "✅ {}",
+                format_result(category, value, &from_unit, result, &to_unit)
             );
         }
         Err(error) => {
@@ -310,111 +791,3 @@ fn handle_temperature_conversion() {
         }
     }
 }
-
-fn handle_volume_conversion() {
-    println!("\n🧪 Volume Conversion");
-    println!("Supported units: l, ml, gal, gal_uk, fl_oz, fl_oz_uk, cup, pt, qt");
-
-    let value = get_number("Enter the value to convert: ");
-    let from_unit = get_input("From unit: ");
-    let to_unit = get_input("To unit: ");
-
-    match convert_volume(value, &from_unit, &to_unit) {
-        Ok(result) => {
-            println!("✅ {} {} = {:.6} {}", value, from_unit, result, to_unit);
-        }
-        Err(error) => {
-            println!("❌ Error: {}", error);
-        }
-    }
-}
-
-fn handle_time_conversion() {
-    println!("\n⏱️  Time Conversion");
-    println!("Supported units: s, min, h, day, week, year, ms, μs, ns");
-
-    let value = get_number("Enter the value to convert: ");
-    let from_unit = get_input("From unit: ");
-    let to_unit = get_input("To unit: ");
-
-    match convert_time(value, &from_unit, &to_unit) {
-        Ok(result) => {
-            println!("✅ {} {} = {:.6} {}", value, from_unit, result, to_unit);
-        }
-        Err(error) => {
-            println!("❌ Error: {}", error);
-        }
-    }
-}
-
-fn handle_current_conversion() {
-    println!("\n⚡ Electric Current Conversion");
-    println!("Supported units: A, mA, μA, nA, kA");
-
-    let value = get_number("Enter the value to convert: ");
-    let from_unit = get_input("From unit: ");
-    let to_unit = get_input("To unit: ");
-
-    match convert_current(value, &from_unit, &to_unit) {
-        Ok(result) => {
-            println!("✅ {} {} = {:.6} {}", value, from_unit, result, to_unit);
-        }
-        Err(error) => {
-            println!("❌ Error: {}", error);
-        }
-    }
-}
-
-fn handle_amount_conversion() {
-    println!("\n🧬 Amount of Substance Conversion");
-    println!("Supported units: mol, mmol, μmol, nmol, pmol, kmol");
-
-    let value = get_number("Enter the value to convert: ");
-    let from_unit = get_input("From unit: ");
-    let to_unit = get_input("To unit: ");
-
-    match convert_amount(value, &from_unit, &to_unit) {
-        Ok(result) => {
-            println!("✅ {} {} = {:.6} {}", value, from_unit, result, to_unit);
-        }
-        Err(error) => {
-            println!("❌ Error: {}", error);
-        }
-    }
-}
-
-fn handle_luminosity_conversion() {
-    println!("\n💡 Luminous Intensity Conversion");
-    println!("Supported units: cd, mcd, kcd, hk, ic, dc");
-
-    let value = get_number("Enter the value to convert: ");
-    let from_unit = get_input("From unit: ");
-    let to_unit = get_input("To unit: ");
-
-    match convert_luminous_intensity(value, &from_unit, &to_unit) {
-        Ok(result) => {
-            println!("✅ {} {} = {:.6} {}", value, from_unit, result, to_unit);
-        }
-        Err(error) => {
-            println!("❌ Error: {}", error);
-        }
-    }
-}
-
-fn handle_area_conversion() {
-    println!("\n📐 Area Conversion");
-    println!("Supported units: m², cm², km², ft², in², ac, ha, mi²");
-
-    let value = get_number("Enter the value to convert: ");
-    let from_unit = get_input("From unit: ");
-    let to_unit = get_input("To unit: ");
-
-    match convert_area(value, &from_unit, &to_unit) {
-        Ok(result) => {
-            println!("✅ {} {} = {:.6} {}", value, from_unit, result, to_unit);
-        }
-        Err(error) => {
-            println!("❌ Error: {}", error);
-        }
-    }
-}
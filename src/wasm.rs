@@ -29,7 +29,9 @@ macro_rules! console_log {
 pub struct ConversionResult {
     success: bool,
     value: f64,
+    text: Option<String>,
     error: Option<String>,
+    error_kind: Option<String>,
 }
 
 #[cfg(feature = "wasm")]
@@ -45,172 +47,448 @@ impl ConversionResult {
         self.value
     }
 
+    /// The result as text, for bindings that format rather than convert
+    /// (e.g. `format_data_wasm`, whose `value` is always 0)
+    #[wasm_bindgen(getter)]
+    pub fn text(&self) -> Option<String> {
+        self.text.clone()
+    }
+
     #[wasm_bindgen(getter)]
     pub fn error(&self) -> Option<String> {
         self.error.clone()
     }
+
+    /// Machine-readable error kind (e.g. `"unknown_unit"`), if the conversion failed
+    #[wasm_bindgen(getter)]
+    pub fn error_kind(&self) -> Option<String> {
+        self.error_kind.clone()
+    }
 }
 
-/// Convert length units
 #[cfg(feature = "wasm")]
-#[wasm_bindgen]
-pub fn convert_length_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
-    match convert_length(value, from, to) {
-        Ok(result) => ConversionResult {
-            success: true,
-            value: result,
-            error: None,
-        },
-        Err(e) => ConversionResult {
+impl From<Result<f64, ConversionError>> for ConversionResult {
+    fn from(result: Result<f64, ConversionError>) -> Self {
+        match result {
+            Ok(value) => ConversionResult {
+                success: true,
+                value,
+                text: None,
+                error: None,
+                error_kind: None,
+            },
+            Err(e) => ConversionResult::failure(e),
+        }
+    }
+}
+
+#[cfg(feature = "wasm")]
+impl From<Result<String, ConversionError>> for ConversionResult {
+    fn from(result: Result<String, ConversionError>) -> Self {
+        match result {
+            Ok(text) => ConversionResult {
+                success: true,
+                value: 0.0,
+                text: Some(text),
+                error: None,
+                error_kind: None,
+            },
+            Err(e) => ConversionResult::failure(e),
+        }
+    }
+}
+
+#[cfg(feature = "wasm")]
+impl ConversionResult {
+    /// Reports a failed conversion or formatting, with its error kind
+    fn failure(e: ConversionError) -> Self {
+        ConversionResult {
             success: false,
             value: 0.0,
+            text: None,
             error: Some(e.to_string()),
-        },
+            error_kind: Some(e.kind().to_string()),
+        }
     }
 }
 
+/// Convert between any two units, inferring the category from the units
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn convert_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
+    convert(value, from, to).into()
+}
+
+/// Convert length units
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn convert_length_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
+    convert_length(value, from, to).into()
+}
+
 /// Convert weight/mass units
 #[cfg(feature = "wasm")]
 #[wasm_bindgen]
 pub fn convert_weight_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
-    match convert_weight(value, from, to) {
-        Ok(result) => ConversionResult {
-            success: true,
-            value: result,
-            error: None,
-        },
-        Err(e) => ConversionResult {
-            success: false,
-            value: 0.0,
-            error: Some(e.to_string()),
-        },
-    }
+    convert_weight(value, from, to).into()
 }
 
 /// Convert temperature units
 #[cfg(feature = "wasm")]
 #[wasm_bindgen]
 pub fn convert_temperature_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
-    match convert_temperature(value, from, to) {
-        Ok(result) => ConversionResult {
-            success: true,
-            value: result,
-            error: None,
-        },
-        Err(e) => ConversionResult {
-            success: false,
-            value: 0.0,
-            error: Some(e.to_string()),
-        },
-    }
+    convert_temperature(value, from, to).into()
+}
+
+/// Convert temperature units, allowing values below absolute zero
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn convert_temperature_lenient_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
+    convert_temperature_lenient(value, from, to).into()
 }
 
 /// Convert volume units
 #[cfg(feature = "wasm")]
 #[wasm_bindgen]
 pub fn convert_volume_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
-    match convert_volume(value, from, to) {
-        Ok(result) => ConversionResult {
-            success: true,
-            value: result,
-            error: None,
-        },
-        Err(e) => ConversionResult {
-            success: false,
-            value: 0.0,
-            error: Some(e.to_string()),
-        },
-    }
+    convert_volume(value, from, to).into()
 }
 
 /// Convert time units
 #[cfg(feature = "wasm")]
 #[wasm_bindgen]
 pub fn convert_time_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
-    match convert_time(value, from, to) {
-        Ok(result) => ConversionResult {
-            success: true,
-            value: result,
-            error: None,
-        },
-        Err(e) => ConversionResult {
-            success: false,
-            value: 0.0,
-            error: Some(e.to_string()),
-        },
-    }
+    convert_time(value, from, to).into()
 }
 
 /// Convert electric current units
 #[cfg(feature = "wasm")]
 #[wasm_bindgen]
 pub fn convert_current_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
-    match convert_current(value, from, to) {
-        Ok(result) => ConversionResult {
-            success: true,
-            value: result,
-            error: None,
-        },
-        Err(e) => ConversionResult {
-            success: false,
-            value: 0.0,
-            error: Some(e.to_string()),
-        },
-    }
+    convert_current(value, from, to).into()
 }
 
 /// Convert amount of substance units
 #[cfg(feature = "wasm")]
 #[wasm_bindgen]
 pub fn convert_substance_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
-    match convert_amount(value, from, to) {
-        Ok(result) => ConversionResult {
-            success: true,
-            value: result,
-            error: None,
-        },
-        Err(e) => ConversionResult {
-            success: false,
-            value: 0.0,
-            error: Some(e.to_string()),
-        },
-    }
+    convert_amount(value, from, to).into()
 }
 
 /// Convert luminous intensity units
 #[cfg(feature = "wasm")]
 #[wasm_bindgen]
 pub fn convert_luminous_intensity_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
-    match convert_luminous_intensity(value, from, to) {
-        Ok(result) => ConversionResult {
-            success: true,
-            value: result,
-            error: None,
-        },
-        Err(e) => ConversionResult {
-            success: false,
-            value: 0.0,
-            error: Some(e.to_string()),
-        },
-    }
+    convert_luminous_intensity(value, from, to).into()
 }
 
 /// Convert area units
 #[cfg(feature = "wasm")]
 #[wasm_bindgen]
 pub fn convert_area_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
-    match convert_area(value, from, to) {
-        Ok(result) => ConversionResult {
-            success: true,
-            value: result,
-            error: None,
-        },
-        Err(e) => ConversionResult {
-            success: false,
-            value: 0.0,
-            error: Some(e.to_string()),
-        },
-    }
+    convert_area(value, from, to).into()
+}
+
+/// Convert speed units
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn convert_speed_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
+    convert_speed(value, from, to).into()
+}
+
+/// Convert pressure units
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn convert_pressure_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
+    convert_pressure(value, from, to).into()
+}
+
+/// Convert energy units
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn convert_energy_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
+    convert_energy(value, from, to).into()
+}
+
+/// Convert power units
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn convert_power_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
+    convert_power(value, from, to).into()
+}
+
+/// Convert force units
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn convert_force_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
+    convert_force(value, from, to).into()
+}
+
+/// Convert torque units
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn convert_torque_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
+    convert_torque(value, from, to).into()
+}
+
+/// Convert frequency units
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn convert_frequency_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
+    convert_frequency(value, from, to).into()
+}
+
+/// Convert the period of a repeating signal to its frequency
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn period_to_frequency_wasm(
+    period: f64,
+    period_unit: &str,
+    frequency_unit: &str,
+) -> ConversionResult {
+    period_to_frequency(period, period_unit, frequency_unit).into()
+}
+
+/// Convert a frequency to the period of one cycle
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn frequency_to_period_wasm(
+    frequency: f64,
+    frequency_unit: &str,
+    period_unit: &str,
+) -> ConversionResult {
+    frequency_to_period(frequency, frequency_unit, period_unit).into()
+}
+
+/// Convert plane angle units
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn convert_angle_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
+    convert_angle(value, from, to).into()
+}
+
+/// Wrap an angle into [0, 1 turn), or (-½ turn, ½ turn] when `signed` is true
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn normalize_angle_wasm(value: f64, unit: &str, signed: bool) -> ConversionResult {
+    let range = if signed {
+        AngleRange::Signed
+    } else {
+        AngleRange::Positive
+    };
+    normalize_angle(value, unit, range).into()
+}
+
+/// Parse a degrees-minutes-seconds angle (e.g. `12°34'56"`) into decimal degrees
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn parse_dms_wasm(input: &str) -> ConversionResult {
+    parse_dms(input).into()
+}
+
+/// Format decimal degrees as degrees, minutes and seconds, with at most 17
+/// decimal places of seconds
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn format_dms_wasm(degrees: f64, precision: usize) -> String {
+    format_dms(degrees, precision)
+}
+
+/// Convert solid angle units
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn convert_solid_angle_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
+    convert_solid_angle(value, from, to).into()
+}
+
+/// Convert digital storage units
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn convert_data_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
+    convert_data(value, from, to).into()
+}
+
+/// Format an amount of data in its largest decimal (kB, MB) or, when `binary`
+/// is true, binary (KiB, MiB) unit, e.g. `"1.46 MiB"`, returned as `text`;
+/// `precision` is clamped to 17 decimal places
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn format_data_wasm(
+    value: f64,
+    unit: &str,
+    binary: bool,
+    precision: usize,
+) -> ConversionResult {
+    let convention = if binary {
+        PrefixConvention::Binary
+    } else {
+        PrefixConvention::Decimal
+    };
+    format_data(value, unit, convention, precision).into()
+}
+
+/// Convert data rate units
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn convert_data_rate_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
+    convert_data_rate(value, from, to).into()
+}
+
+/// Convert density units
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn convert_density_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
+    convert_density(value, from, to).into()
+}
+
+/// Convert volumetric flow rate units
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn convert_flow_rate_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
+    convert_flow_rate(value, from, to).into()
+}
+
+/// Convert mass flow rate units
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn convert_mass_flow_rate_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
+    convert_mass_flow_rate(value, from, to).into()
+}
+
+/// Convert voltage units
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn convert_voltage_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
+    convert_voltage(value, from, to).into()
+}
+
+/// Convert electrical resistance units
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn convert_resistance_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
+    convert_resistance(value, from, to).into()
+}
+
+/// Convert electrical conductance units
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn convert_conductance_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
+    convert_conductance(value, from, to).into()
+}
+
+/// Convert electric charge units
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn convert_charge_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
+    convert_charge(value, from, to).into()
+}
+
+/// Convert capacitance units
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn convert_capacitance_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
+    convert_capacitance(value, from, to).into()
+}
+
+/// Convert inductance units
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn convert_inductance_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
+    convert_inductance(value, from, to).into()
+}
+
+/// Convert luminous flux units
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn convert_luminous_flux_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
+    convert_luminous_flux(value, from, to).into()
+}
+
+/// Convert a luminous intensity to the flux it sends into a beam
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn intensity_to_flux_wasm(
+    intensity: f64,
+    intensity_unit: &str,
+    beam: f64,
+    beam_unit: &str,
+    flux_unit: &str,
+) -> ConversionResult {
+    intensity_to_flux(intensity, intensity_unit, beam, beam_unit, flux_unit).into()
+}
+
+/// Convert the flux sent into a beam to the source's luminous intensity
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn flux_to_intensity_wasm(
+    flux: f64,
+    flux_unit: &str,
+    beam: f64,
+    beam_unit: &str,
+    intensity_unit: &str,
+) -> ConversionResult {
+    flux_to_intensity(flux, flux_unit, beam, beam_unit, intensity_unit).into()
+}
+
+/// Convert illuminance units
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn convert_illuminance_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
+    convert_illuminance(value, from, to).into()
+}
+
+/// Convert a point source's luminous intensity to the illuminance it casts at a distance
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn intensity_to_illuminance_wasm(
+    intensity: f64,
+    intensity_unit: &str,
+    distance: f64,
+    distance_unit: &str,
+    illuminance_unit: &str,
+) -> ConversionResult {
+    intensity_to_illuminance(
+        intensity,
+        intensity_unit,
+        distance,
+        distance_unit,
+        illuminance_unit,
+    )
+    .into()
+}
+
+/// Convert the illuminance at a distance to the point source's luminous intensity
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn illuminance_to_intensity_wasm(
+    illuminance: f64,
+    illuminance_unit: &str,
+    distance: f64,
+    distance_unit: &str,
+    intensity_unit: &str,
+) -> ConversionResult {
+    illuminance_to_intensity(
+        illuminance,
+        illuminance_unit,
+        distance,
+        distance_unit,
+        intensity_unit,
+    )
+    .into()
+}
+
+/// Convert luminance units
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn convert_luminance_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
+    convert_luminance(value, from, to).into()
+}
+
+/// Convert temperature differences (e.g. a 10°C rise) for WASM
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn convert_temperature_interval_wasm(value: f64, from: &str, to: &str) -> ConversionResult {
+    convert_temperature_interval(value, from, to).into()
 }
 
 /// Initialize the WASM module
@@ -224,84 +502,13 @@ pub fn main() {
 #[cfg(feature = "wasm")]
 #[wasm_bindgen]
 pub fn get_supported_units(conversion_type: &str) -> Vec<String> {
-    match conversion_type.to_lowercase().as_str() {
-        "length" => vec![
-            "m".to_string(),
-            "km".to_string(),
-            "cm".to_string(),
-            "mm".to_string(),
-            "ft".to_string(),
-            "in".to_string(),
-            "yd".to_string(),
-            "mi".to_string(),
-            "nm".to_string(),
-            "μm".to_string(),
-        ],
-        "weight" | "mass" => vec![
-            "kg".to_string(),
-            "g".to_string(),
-            "mg".to_string(),
-            "lb".to_string(),
-            "oz".to_string(),
-            "t".to_string(),
-            "st".to_string(),
-            "grain".to_string(),
-            "carat".to_string(),
-        ],
-        "temperature" => vec!["C".to_string(), "F".to_string(), "K".to_string()],
-        "volume" => vec![
-            "l".to_string(),
-            "ml".to_string(),
-            "gal".to_string(),
-            "gal_uk".to_string(),
-            "fl_oz".to_string(),
-            "fl_oz_uk".to_string(),
-            "cup".to_string(),
-            "pt".to_string(),
-            "qt".to_string(),
-            "tbsp".to_string(),
-            "tsp".to_string(),
-        ],
-        "time" => vec![
-            "s".to_string(),
-            "min".to_string(),
-            "h".to_string(),
-            "d".to_string(),
-            "week".to_string(),
-            "month".to_string(),
-            "year".to_string(),
-            "ms".to_string(),
-            "μs".to_string(),
-            "ns".to_string(),
-        ],
-        "current" => vec![
-            "A".to_string(),
-            "mA".to_string(),
-            "μA".to_string(),
-            "nA".to_string(),
-            "kA".to_string(),
-            "MA".to_string(),
-        ],
-        "substance" => vec![
-            "mol".to_string(),
-            "mmol".to_string(),
-            "μmol".to_string(),
-            "nmol".to_string(),
-            "kmol".to_string(),
-        ],
-        "luminous_intensity" => vec!["cd".to_string(), "mcd".to_string(), "kcd".to_string()],
-        "area" => vec![
-            "m²".to_string(),
-            "km²".to_string(),
-            "cm²".to_string(),
-            "mm²".to_string(),
-            "ha".to_string(),
-            "acre".to_string(),
-            "ft²".to_string(),
-            "in²".to_string(),
-            "yd²".to_string(),
-            "mi²".to_string(),
-        ],
-        _ => vec![],
-    }
+    Category::from_name(conversion_type)
+        .map(|category| {
+            UnitRegistry::global()
+                .symbols(category)
+                .into_iter()
+                .map(String::from)
+                .collect()
+        })
+        .unwrap_or_default()
 }